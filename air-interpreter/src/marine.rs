@@ -30,13 +30,25 @@ mod ast;
 mod logger;
 
 use air::execute_air;
+use air::execute_air_traced;
 use air::InterpreterOutcome;
 use air::RunParameters;
+use air_interpreter_interface::InvokeOutcome;
+use air_interpreter_interface::InvokeRequest;
 use marine_rs_sdk::marine;
 use marine_rs_sdk::module_manifest;
 
+use std::cell::RefCell;
+use std::time::Instant;
+
 module_manifest!();
 
+thread_local! {
+    /// Outcomes of the executions `invoke_multi` has completed so far, they are kept outside
+    /// of the call, so a host can take them with `take_invoke_outcomes` if the module traps.
+    static INVOKE_OUTCOMES: RefCell<Vec<InvokeOutcome>> = const { RefCell::new(Vec::new()) };
+}
+
 pub fn main() {
     logger::init_logger(None);
 }
@@ -52,6 +64,67 @@ pub fn invoke(
     execute_air(air, prev_data, data, params, call_results.into())
 }
 
+/// Executes a batch of requests one by one in a single call, so a host switches into
+/// the module once per batch instead of once per particle.
+#[marine]
+pub fn invoke_multi(requests: Vec<InvokeRequest>) -> Vec<InvokeOutcome> {
+    // outcomes of a trapped batch which weren't taken by a host
+    INVOKE_OUTCOMES.with(|outcomes| outcomes.borrow_mut().clear());
+
+    for request in requests {
+        let InvokeRequest {
+            air,
+            prev_data,
+            data,
+            params,
+            call_results,
+        } = request;
+
+        let execution_start_time = Instant::now();
+        let outcome = execute_air(air, prev_data, data, params, call_results.into());
+        let outcome = InvokeOutcome {
+            outcome,
+            execution_time_us: execution_start_time.elapsed().as_micros() as u64,
+            memory_size: linear_memory_size(),
+        };
+        INVOKE_OUTCOMES.with(|outcomes| outcomes.borrow_mut().push(outcome));
+    }
+
+    take_invoke_outcomes()
+}
+
+/// Returns outcomes of the executions the last `invoke_multi` call completed before
+/// the module trapped.
+#[marine]
+pub fn take_invoke_outcomes() -> Vec<InvokeOutcome> {
+    INVOKE_OUTCOMES.with(|outcomes| std::mem::take(&mut *outcomes.borrow_mut()))
+}
+
+#[cfg(target_arch = "wasm32")]
+fn linear_memory_size() -> u64 {
+    const WASM_PAGE_SIZE: usize = 64 * 1024;
+
+    (core::arch::wasm32::memory_size::<0>() * WASM_PAGE_SIZE) as u64
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn linear_memory_size() -> u64 {
+    0
+}
+
+/// Executes a script like `invoke`, but also puts a tree of executed instructions with time
+/// spent in each of them into the outcome.
+#[marine]
+pub fn invoke_traced(
+    air: String,
+    prev_data: Vec<u8>,
+    data: Vec<u8>,
+    params: RunParameters,
+    call_results: Vec<u8>,
+) -> InterpreterOutcome {
+    execute_air_traced(air, prev_data, data, params, call_results.into())
+}
+
 #[allow(clippy::too_many_arguments)]
 #[marine]
 pub fn invoke_tracing(
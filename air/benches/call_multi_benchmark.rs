@@ -0,0 +1,82 @@
+use air_test_utils::prelude::*;
+
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::BenchmarkId;
+use criterion::Criterion;
+
+use std::cell::RefCell;
+
+const BATCH_SIZES: [usize; 2] = [10, 100];
+
+thread_local!(static VM: RefCell<TestRunner<ReleaseWasmAirRunner>> = RefCell::new(
+    create_custom_avm(unit_call_service(), "test_peer_id"))
+);
+
+const SCRIPT: &str = r#"
+    (call "test_peer_id" ("local_service_id" "local_fn_name") [] result_name)
+"#;
+
+fn requests(batch_size: usize) -> Vec<RunnerRequest> {
+    (0..batch_size)
+        .map(|index| RunnerRequest {
+            air: SCRIPT.to_string(),
+            prev_data: vec![],
+            data: vec![],
+            init_peer_id: "test_peer_id".to_string(),
+            timestamp: 0,
+            ttl: 1,
+            current_peer_id: "test_peer_id".to_string(),
+            call_results: <_>::default(),
+            particle_id: format!("particle_{index}"),
+        })
+        .collect()
+}
+
+fn call_one_by_one(requests: &[RunnerRequest]) {
+    VM.with(|vm| {
+        let vm = &mut *vm.borrow_mut();
+        for request in requests {
+            vm.runner
+                .call(
+                    request.air.clone(),
+                    request.prev_data.clone(),
+                    request.data.clone(),
+                    request.init_peer_id.clone(),
+                    request.timestamp,
+                    request.ttl,
+                    None,
+                    request.call_results.clone(),
+                    &vm.keypair,
+                    request.particle_id.clone(),
+                )
+                .unwrap();
+        }
+    })
+}
+
+fn call_multi(requests: &[RunnerRequest]) {
+    VM.with(|vm| {
+        let vm = &mut *vm.borrow_mut();
+        for outcome in vm.runner.call_multi(requests, &vm.keypair) {
+            outcome.unwrap();
+        }
+    })
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("call_multi");
+    for batch_size in BATCH_SIZES {
+        let requests = requests(batch_size);
+        group.bench_with_input(BenchmarkId::new("one_by_one", batch_size), &requests, |b, requests| {
+            b.iter(|| call_one_by_one(requests))
+        });
+        group.bench_with_input(BenchmarkId::new("batch", batch_size), &requests, |b, requests| {
+            b.iter(|| call_multi(requests))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,63 @@
+use air_test_utils::prelude::*;
+
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+
+const VALUES_COUNT: usize = 1000;
+const PEER_ID: &str = "test_peer_id";
+
+// all values are put into a stream and iterated over, but only the last call result is read
+fn script() -> String {
+    format!(
+        r#"
+        (seq
+            (seq
+                (call "{PEER_ID}" ("" "items") [] items)
+                (fold items item
+                    (seq
+                        (call "{PEER_ID}" ("" "value") [item] $values)
+                        (next item))))
+            (seq
+                (fold $values value
+                    (next value))
+                (seq
+                    (call "{PEER_ID}" ("" "value") [] last)
+                    (call "{PEER_ID}" ("" "last") [last.$.nested]))))
+        "#
+    )
+}
+
+// data where every call is already executed, so the benchmarked run only replays it
+fn executed_data() -> Vec<u8> {
+    let items: Vec<_> = (0..VALUES_COUNT).collect();
+    let call_service: CallServiceClosure =
+        Box::new(move |params: CallRequestParams| match params.function_name.as_str() {
+            "items" => CallServiceResult::ok(json!(items)),
+            // values differ, otherwise they would share one CID and be parsed only once
+            _ => {
+                let records: Vec<_> = (0..64).map(|id| json!({"id": id, "tags": ["a", "b"]})).collect();
+                CallServiceResult::ok(json!({"item": params.arguments, "records": records, "nested": [1, 2, 3]}))
+            }
+        });
+
+    let mut vm = create_custom_avm::<NativeAirRunner>(call_service, PEER_ID);
+    let result = checked_call_vm!(vm, <_>::default(), script(), "", "");
+    result.data
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let script = script();
+    let data = executed_data();
+    let mut vm = create_custom_avm::<NativeAirRunner>(unit_call_service(), PEER_ID);
+
+    c.bench_function("replay_fold_reading_last_value", |b| {
+        b.iter(|| {
+            vm.call(&script, data.clone(), "", TestRunParameters::new(PEER_ID, 0, 1, ""))
+                .unwrap()
+        })
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,77 @@
+use air_interpreter_interface::serialize_call_results;
+use air_interpreter_interface::CallResultsFormat;
+use air_interpreter_interface::RunParameters;
+use air_test_utils::prelude::*;
+
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+
+const ITEMS_COUNT: usize = 1000;
+const PEER_ID: &str = "test_peer_id";
+
+// scripts differ only in the comparison instruction, which is executed once per item
+fn script(instruction: &str, pattern: &str) -> String {
+    format!(
+        r#"
+        (seq
+            (call "{PEER_ID}" ("" "items") [] items)
+            (fold items item
+                (seq
+                    ({instruction} item "{pattern}" (null))
+                    (next item))))
+        "#
+    )
+}
+
+// data where the call is already executed, so the benchmarked run only executes the fold
+fn executed_data(script: &str) -> Vec<u8> {
+    let items = vec![json!("peer-42"); ITEMS_COUNT];
+    let mut vm = create_custom_avm::<NativeAirRunner>(set_variable_call_service(json!(items)), PEER_ID);
+    let result = checked_call_vm!(vm, <_>::default(), script, "", "");
+    result.data
+}
+
+fn run_parameters() -> RunParameters {
+    let keypair = fluence_keypair::KeyPair::generate_ed25519();
+
+    RunParameters::builder()
+        .init_peer_id(PEER_ID)
+        .current_peer_id(PEER_ID)
+        .timestamp(0)
+        .ttl(1)
+        .key_format(keypair.key_format().into())
+        .secret_key_bytes(keypair.secret().unwrap())
+        .build()
+        .unwrap()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let scripts = [
+        ("match", script("match", "peer-42")),
+        ("match-regex", script("match-regex", "^peer-[0-9]+$")),
+    ];
+    let run_parameters = run_parameters();
+    let call_results = serialize_call_results(&into_raw_result(<_>::default()), CallResultsFormat::default()).unwrap();
+
+    let mut group = c.benchmark_group("execute match");
+    for (name, script) in scripts {
+        let data = executed_data(&script);
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                let outcome = air::execute_air(
+                    script.clone(),
+                    vec![],
+                    data.clone(),
+                    run_parameters.clone(),
+                    call_results.clone().into(),
+                );
+                assert_eq!(outcome.ret_code, 0, "{}", outcome.error_message);
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,51 @@
+use air_test_utils::prelude::*;
+
+use criterion::criterion_group;
+use criterion::criterion_main;
+use criterion::Criterion;
+
+const ITERATIONS_COUNT: usize = 10_000;
+
+// a fold over identical values produces an identical call state on every iteration
+fn fold_body_trace() -> ExecutionTrace {
+    let state = scalar!(json!(1), peer = "test_peer_id");
+    vec![state; ITERATIONS_COUNT].into()
+}
+
+fn serialized_size(trace: &ExecutionTrace) -> usize {
+    let data = InterpreterData {
+        trace: trace.clone(),
+        ..<_>::default()
+    };
+    data.serialize().unwrap().len()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let trace = fold_body_trace();
+    let mut compacted_trace = trace.clone();
+    compacted_trace.compact();
+
+    println!(
+        "serialized trace size is {} bytes, {} bytes after compaction",
+        serialized_size(&trace),
+        serialized_size(&compacted_trace)
+    );
+
+    c.bench_function("compact_fold_trace", |b| {
+        b.iter(|| {
+            let mut trace = trace.clone();
+            trace.compact();
+            trace
+        })
+    });
+    c.bench_function("expand_fold_trace", |b| {
+        b.iter(|| {
+            let mut trace = compacted_trace.clone();
+            trace.expand().unwrap();
+            trace
+        })
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
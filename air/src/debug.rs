@@ -0,0 +1,291 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Step-by-step debugging of an AIR script execution.
+
+use crate::execution_step::ValueAggregate;
+use crate::runner::execute_air_impl;
+use crate::runner::ExecutionHooks;
+use crate::utils::instruction_spans;
+use crate::ContextView;
+
+use air_interpreter_interface::CallResults;
+use air_interpreter_interface::CallResultsFormat;
+use air_interpreter_interface::CallResultsRepr;
+use air_interpreter_interface::InterpreterOutcome;
+use air_interpreter_interface::RunParameters;
+use air_interpreter_sede::ToSerialized;
+use air_parser::ast::Instruction;
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+/// Executes an AIR script pausing before each instruction dispatch.
+///
+/// Execution is deterministic for the same inputs, so the script is executed once on construction
+/// and pauses are recorded, `step` and `resume` then walk through them in the dispatch order.
+/// Instructions are identified by their position in the script, the first instruction has id 0.
+pub struct Debugger {
+    events: Vec<PauseEvent>,
+    /// Index of the event `step` returns next.
+    next_event: usize,
+    current: Option<usize>,
+    breakpoints: HashSet<u64>,
+    /// Iterator names of fold instructions by their ids.
+    fold_iterators: HashMap<u64, String>,
+    outcome: InterpreterOutcome,
+}
+
+/// Describes a state of execution right before an instruction dispatch.
+#[derive(Debug, Clone)]
+pub struct PauseEvent {
+    pub instruction_id: u64,
+
+    /// The instruction without its subinstructions, e.g. `seq` or `call`.
+    pub instruction: String,
+
+    /// One-based script line where the instruction starts.
+    pub line: usize,
+
+    /// Scalars and current values of fold iterators in scope of the instruction.
+    pub variables: HashMap<String, ValueAggregate>,
+}
+
+#[derive(Debug, Clone)]
+pub enum StepResult {
+    Paused(PauseEvent),
+    Finished,
+}
+
+impl Debugger {
+    /// Executes the script with empty data and no call results.
+    pub fn new(air: &str, params: RunParameters) -> Self {
+        Self::with_data(air, vec![], params)
+    }
+
+    /// Executes the script with no call results replaying `prev_data`, so services called
+    /// in previous executions return the same results.
+    pub fn with_data(air: &str, prev_data: Vec<u8>, mut params: RunParameters) -> Self {
+        params.call_results_format = CallResultsFormat::MsgPack.into();
+        let call_results = CallResultsRepr
+            .serialize(&CallResults::default())
+            .expect("empty call results are serializable");
+
+        let recorder = PauseRecorder::new(instruction_lines(air));
+        let events = recorder.events.clone();
+        let fold_iterators = recorder.fold_iterators.clone();
+        let outcome = execute_air_impl(
+            air.to_string(),
+            prev_data,
+            vec![],
+            params,
+            call_results,
+            ExecutionHooks {
+                pause_recorder: Some(recorder),
+                ..<_>::default()
+            },
+        )
+        .unwrap_or_else(std::convert::identity);
+        let events = events.take();
+        let fold_iterators = fold_iterators.take();
+
+        Self {
+            events,
+            next_event: 0,
+            current: None,
+            breakpoints: <_>::default(),
+            fold_iterators,
+            outcome,
+        }
+    }
+
+    /// Pauses before the next dispatched instruction.
+    pub fn step(&mut self) -> StepResult {
+        if self.next_event >= self.events.len() {
+            self.current = None;
+            return StepResult::Finished;
+        }
+
+        self.pause_at(self.next_event)
+    }
+
+    /// Moves execution back to the first iteration of the innermost fold with the iterator,
+    /// so the next steps go through its iterations again.
+    ///
+    /// Iterations are replayed as they were executed, so a fold over a stream visits values
+    /// appended to the stream during iteration in the same order as before, and `next` isn't
+    /// affected by the rewind. Returns `None` if execution isn't paused inside such a fold.
+    pub fn rewind(&mut self, iterator: &str) -> Option<StepResult> {
+        let current = self.current?;
+        self.events[current].variables.get(iterator)?;
+
+        // the innermost fold is the latest one dispatched before the current instruction
+        let fold_position = self.events[..=current].iter().rposition(|event| {
+            self.fold_iterators
+                .get(&event.instruction_id)
+                .is_some_and(|name| name == iterator)
+        })?;
+
+        Some(self.pause_at(fold_position + 1))
+    }
+
+    /// Makes `continue_to_breakpoint` pause before dispatches of the instruction.
+    pub fn set_breakpoint(&mut self, instruction_id: u64) {
+        self.breakpoints.insert(instruction_id);
+    }
+
+    /// Continues execution until an instruction with a breakpoint is about to be dispatched.
+    pub fn continue_to_breakpoint(&mut self) -> StepResult {
+        loop {
+            match self.step() {
+                StepResult::Paused(event) if !self.breakpoints.contains(&event.instruction_id) => {}
+                result => return result,
+            }
+        }
+    }
+
+    /// Returns a value of a variable in scope of the instruction execution is paused before.
+    pub fn inspect_variable(&self, name: &str) -> Option<ValueAggregate> {
+        self.events[self.current?].variables.get(name).cloned()
+    }
+
+    /// Finishes execution ignoring breakpoints.
+    pub fn resume(self) -> InterpreterOutcome {
+        self.outcome
+    }
+
+    fn pause_at(&mut self, position: usize) -> StepResult {
+        self.current = Some(position);
+        self.next_event = position + 1;
+        StepResult::Paused(self.events[position].clone())
+    }
+}
+
+/// Records pause events while an instruction tree is executed.
+pub(crate) struct PauseRecorder {
+    events: Rc<RefCell<Vec<PauseEvent>>>,
+
+    /// Start lines of instructions in the script order.
+    lines: Vec<usize>,
+
+    /// Maps instructions to their positions in the script, it's filled on the first dispatch
+    /// which is always the root instruction.
+    ids: HashMap<*const (), u64>,
+
+    /// Iterator names of fold instructions by their ids, it's filled along with `ids`.
+    fold_iterators: Rc<RefCell<HashMap<u64, String>>>,
+}
+
+impl PauseRecorder {
+    fn new(lines: Vec<usize>) -> Self {
+        Self {
+            events: <_>::default(),
+            lines,
+            ids: <_>::default(),
+            fold_iterators: <_>::default(),
+        }
+    }
+
+    pub(crate) fn record_pause(&mut self, instruction: &Instruction<'_>, view: &ContextView<'_>) {
+        if self.ids.is_empty() {
+            let mut next_id = 0;
+            let mut fold_iterators = self.fold_iterators.borrow_mut();
+            index_instructions(instruction, &mut self.ids, &mut fold_iterators, &mut next_id);
+        }
+
+        let Some(&instruction_id) = self.ids.get(&key(instruction)) else {
+            return;
+        };
+
+        self.events.borrow_mut().push(PauseEvent {
+            instruction_id,
+            instruction: instruction.to_string(),
+            line: self.lines.get(instruction_id as usize).copied().unwrap_or_default(),
+            variables: view.visible_values(),
+        });
+    }
+}
+
+fn key(instruction: &Instruction<'_>) -> *const () {
+    instruction as *const Instruction<'_> as *const ()
+}
+
+/// Assigns ids to instructions in the same order they appear in the script.
+fn index_instructions(
+    instruction: &Instruction<'_>,
+    ids: &mut HashMap<*const (), u64>,
+    fold_iterators: &mut HashMap<u64, String>,
+    next_id: &mut u64,
+) {
+    ids.insert(key(instruction), *next_id);
+    let iterator = match instruction {
+        Instruction::FoldScalar(fold) => Some(fold.iterator.name),
+        Instruction::FoldStream(fold) => Some(fold.iterator.name),
+        Instruction::FoldStreamMap(fold) => Some(fold.iterator.name),
+        _ => None,
+    };
+    if let Some(iterator) = iterator {
+        fold_iterators.insert(*next_id, iterator.to_string());
+    }
+    *next_id += 1;
+
+    let mut index = |instruction: &Instruction<'_>| index_instructions(instruction, ids, fold_iterators, next_id);
+    match instruction {
+        Instruction::Seq(seq) => {
+            index(&seq.0);
+            index(&seq.1);
+        }
+        Instruction::Par(par) => {
+            index(&par.0);
+            index(&par.1);
+        }
+        Instruction::Xor(xor) => {
+            index(&xor.0);
+            index(&xor.1);
+        }
+        Instruction::Match(match_) => index(&match_.instruction),
+        Instruction::MisMatch(mismatch) => index(&mismatch.instruction),
+        Instruction::MatchRegex(match_regex) => index(&match_regex.instruction),
+        Instruction::MisMatchRegex(mismatch_regex) => index(&mismatch_regex.instruction),
+        Instruction::New(new) => index(&new.instruction),
+        Instruction::FoldScalar(fold) => {
+            index(&fold.instruction);
+            fold.last_instruction.iter().for_each(|last| index(last));
+        }
+        Instruction::FoldStream(fold) => {
+            index(&fold.instruction);
+            fold.last_instruction.iter().for_each(|last| index(last));
+        }
+        Instruction::FoldStreamMap(fold) => {
+            index(&fold.instruction);
+            fold.last_instruction.iter().for_each(|last| index(last));
+        }
+        _ => {}
+    }
+}
+
+/// Returns start lines of instructions in the script order.
+fn instruction_lines(air: &str) -> Vec<usize> {
+    instruction_spans(air)
+        .into_iter()
+        .map(|span| {
+            let start: usize = span.left.into();
+            air[..start].matches('\n').count() + 1
+        })
+        .collect()
+}
@@ -63,8 +63,8 @@ pub enum CatchableError {
         expected_value_type: &'static str,
     },
 
-    /// A fold instruction must iterate over array value.
-    #[error("expression '{1}' returned non-array value '{0}' for fold iterable")]
+    /// A fold instruction must iterate over array or object value.
+    #[error("expression '{1}' returned neither array nor object value '{0}' for fold iterable")]
     FoldIteratesOverNonArray(JValue, String),
 
     /// This error type is produced by a fail instruction.
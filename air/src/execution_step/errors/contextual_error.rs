@@ -0,0 +1,61 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::ExecutionError;
+use crate::ToErrorCode;
+
+use air_interpreter_interface::ErrorContext;
+use air_interpreter_interface::InstructionTag;
+use air_parser::ast::Span;
+use thiserror::Error as ThisError;
+
+/// An execution error along with the instruction that raised it.
+#[derive(ThisError, Debug)]
+#[error("{error}, raised by {instruction:?} at {}..{}", span.left, span.right)]
+pub struct ContextualExecutionError {
+    pub error: ExecutionError,
+
+    /// Span of the instruction in the script.
+    pub span: Span,
+
+    pub instruction: InstructionTag,
+}
+
+impl ExecutionError {
+    pub fn with_context(self, span: Span, instruction: InstructionTag) -> ContextualExecutionError {
+        ContextualExecutionError {
+            error: self,
+            span,
+            instruction,
+        }
+    }
+}
+
+impl ContextualExecutionError {
+    pub fn error_context(&self) -> ErrorContext {
+        ErrorContext {
+            instruction: self.instruction,
+            span_start: self.span.left.into(),
+            span_end: self.span.right.into(),
+        }
+    }
+}
+
+impl ToErrorCode for ContextualExecutionError {
+    fn to_error_code(&self) -> i64 {
+        self.error.to_error_code()
+    }
+}
@@ -37,6 +37,11 @@ pub enum ExecutionError {
 
     #[error(transparent)]
     Uncatchable(#[from] UncatchableError),
+
+    /// An uncatchable error caused by a malformed call result from data rather than by
+    /// an interpreter bug, xor recovers from it the same way as from a catchable one.
+    #[error(transparent)]
+    Recoverable(UncatchableError),
 }
 
 impl ExecutionError {
@@ -44,6 +49,11 @@ impl ExecutionError {
         matches!(self, ExecutionError::Catchable(_))
     }
 
+    /// Returns true if xor could fall through to its right branch on this error.
+    pub fn is_recoverable(&self) -> bool {
+        matches!(self, ExecutionError::Catchable(_) | ExecutionError::Recoverable(_))
+    }
+
     pub fn is_match_or_mismatch(&self) -> bool {
         match self {
             ExecutionError::Catchable(catchable) => matches!(
@@ -77,7 +87,7 @@ impl ToErrorCode for ExecutionError {
     fn to_error_code(&self) -> i64 {
         match self {
             ExecutionError::Catchable(err) => err.to_error_code(),
-            ExecutionError::Uncatchable(err) => err.to_error_code(),
+            ExecutionError::Uncatchable(err) | ExecutionError::Recoverable(err) => err.to_error_code(),
         }
     }
 }
@@ -86,7 +96,7 @@ impl Joinable for ExecutionError {
     fn is_joinable(&self) -> bool {
         match self {
             ExecutionError::Catchable(err) => err.is_joinable(),
-            ExecutionError::Uncatchable(_) => false,
+            ExecutionError::Uncatchable(_) | ExecutionError::Recoverable(_) => false,
         }
     }
 }
@@ -95,13 +105,14 @@ impl ErrorAffectable for ExecutionError {
     fn affects_last_error(&self) -> bool {
         match self {
             ExecutionError::Catchable(err) => err.affects_last_error(),
+            ExecutionError::Recoverable(_) => true,
             ExecutionError::Uncatchable(_) => false,
         }
     }
 
     fn affects_error(&self) -> bool {
         match self {
-            ExecutionError::Catchable(_) => true,
+            ExecutionError::Catchable(_) | ExecutionError::Recoverable(_) => true,
             ExecutionError::Uncatchable(_) => false,
         }
     }
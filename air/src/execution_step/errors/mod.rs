@@ -15,12 +15,14 @@
  */
 
 mod catchable_errors;
+mod contextual_error;
 mod error_effectable;
 mod execution_errors;
 mod joinable;
 mod uncatchable_errors;
 
 pub use catchable_errors::CatchableError;
+pub use contextual_error::ContextualExecutionError;
 pub use execution_errors::ExecutionError;
 pub use uncatchable_errors::UncatchableError;
 
@@ -140,6 +140,36 @@ pub enum UncatchableError {
 
     #[error("failed to serialize call arguments {0}")]
     CallArgumentsSerializationFailed(<CallArgumentsRepr as Representation>::SerializeError),
+
+    /// Execution consumed all the fuel provided by a host.
+    #[error("fuel limit exhausted after {consumed} instructions executed")]
+    FuelExhausted { consumed: u64 },
+
+    /// Execution was cancelled by a host.
+    #[error("execution was cancelled after {executed} instructions executed")]
+    Cancelled { executed: u64 },
+
+    /// Execution took longer than the time limit provided by a host.
+    #[error("execution time limit exceeded after {elapsed_ms} ms")]
+    ExecutionTimedOut { elapsed_ms: u64 },
+
+    /// A value supplied in data isn't a valid JSON.
+    #[error("failed to parse a value from data: {0}")]
+    MalformedValue(serde_json::Error),
+
+    /// Folds and fold iterations were nested deeper than the limit provided by a host.
+    #[error("fold depth limit exceeded, folds and iterations are nested {depth} levels deep")]
+    FoldDepthExceeded { depth: u32 },
+}
+
+impl UncatchableError {
+    /// Returns true if this error is caused by a malformed call result value in data,
+    /// so it doesn't indicate a bug in the interpreter. The malformed value is still present
+    /// in the CID store, so data stays self-consistent; errors of inconsistent data, such as
+    /// a CID missing from the store, are rejected instead.
+    pub(crate) fn is_caused_by_call_result(&self) -> bool {
+        matches!(self, Self::MalformedCallServiceFailed(_) | Self::MalformedValue(_))
+    }
 }
 
 impl ToErrorCode for UncatchableError {
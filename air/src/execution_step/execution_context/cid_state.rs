@@ -14,21 +14,29 @@
  * limitations under the License.
  */
 
+use crate::execution_step::LazyValue;
 use crate::execution_step::RcSecurityTetraplet;
 use crate::execution_step::ValueAggregate;
 use crate::JValue;
 use crate::UncatchableError;
 
 use air_interpreter_cid::CID;
+use air_interpreter_data::CallResult;
 use air_interpreter_data::CanonCidAggregate;
+use air_interpreter_data::CanonResult;
 use air_interpreter_data::CanonResultCidAggregate;
 use air_interpreter_data::CidInfo;
 use air_interpreter_data::CidTracker;
+use air_interpreter_data::ExecutedState;
+use air_interpreter_data::ExecutionTrace;
+use air_interpreter_data::Provenance;
 use air_interpreter_data::RawValue;
 use air_interpreter_data::ServiceResultCidAggregate;
 use air_interpreter_data::TracePos;
+use air_interpreter_data::ValueRef;
 use polyplets::SecurityTetraplet;
 
+use std::collections::HashSet;
 use std::rc::Rc;
 
 #[derive(Debug, Default, Clone)]
@@ -87,7 +95,7 @@ impl ExecutionCidState {
         &mut self,
         canon_value: &ValueAggregate,
     ) -> Result<CID<CanonCidAggregate>, UncatchableError> {
-        let vm_value = RawValue::from_value(canon_value.get_result().clone());
+        let vm_value = RawValue::from_value(canon_value.get_result()?.clone());
         let value_cid = self.value_tracker.track_raw_value(vm_value);
         let tetraplet = self.tetraplet_tracker.track_value(canon_value.get_tetraplet())?;
 
@@ -104,6 +112,14 @@ impl ExecutionCidState {
             .map(|vm_value| vm_value.get_value())
     }
 
+    /// Returns a value without parsing it, it's parsed only when accessed.
+    pub(crate) fn get_lazy_value_by_cid(&self, cid: &CID<RawValue>) -> Result<LazyValue, UncatchableError> {
+        self.value_tracker
+            .get(cid)
+            .ok_or_else(|| UncatchableError::ValueForCidNotFound("value", cid.get_inner()))
+            .map(LazyValue::from_raw)
+    }
+
     pub(crate) fn get_tetraplet_by_cid(
         &self,
         cid: &CID<SecurityTetraplet>,
@@ -156,7 +172,7 @@ impl ExecutionCidState {
         service_result_agg_cid: &CID<ServiceResultCidAggregate>,
     ) -> Result<ResolvedServiceInfo, UncatchableError> {
         let service_result_aggregate = self.get_service_result_agg_by_cid(service_result_agg_cid)?;
-        let value = self.get_value_by_cid(&service_result_aggregate.value_cid)?;
+        let value = self.get_lazy_value_by_cid(&service_result_aggregate.value_cid)?;
         let tetraplet = self.get_tetraplet_by_cid(&service_result_aggregate.tetraplet_cid)?;
 
         Ok(ResolvedServiceInfo {
@@ -167,8 +183,119 @@ impl ExecutionCidState {
     }
 }
 
+/// Sizes of CID stores measured before and after garbage collection.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct GCStats {
+    /// Total number of values in all CID stores before collection.
+    pub cid_store_size_before: usize,
+
+    /// Total number of values in all CID stores after collection.
+    pub cid_store_size_after: usize,
+}
+
+impl ExecutionCidState {
+    /// Drops values that aren't reachable from the supplied trace.
+    pub(crate) fn collect_garbage(&mut self, trace: &ExecutionTrace) -> GCStats {
+        let cid_store_size_before = self.len();
+
+        let live_cids = LiveCids::from_trace(self, trace);
+        self.value_tracker.retain(|cid| live_cids.values.contains(cid));
+        self.tetraplet_tracker.retain(|cid| live_cids.tetraplets.contains(cid));
+        self.canon_element_tracker
+            .retain(|cid| live_cids.canon_elements.contains(cid));
+        self.canon_result_tracker
+            .retain(|cid| live_cids.canon_results.contains(cid));
+        self.service_result_agg_tracker
+            .retain(|cid| live_cids.service_results.contains(cid));
+
+        GCStats {
+            cid_store_size_before,
+            cid_store_size_after: self.len(),
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.value_tracker.len()
+            + self.tetraplet_tracker.len()
+            + self.canon_element_tracker.len()
+            + self.canon_result_tracker.len()
+            + self.service_result_agg_tracker.len()
+    }
+}
+
+/// CIDs reachable from a trace, grouped by stores they belong to.
+#[derive(Default)]
+struct LiveCids {
+    values: HashSet<CID<RawValue>>,
+    tetraplets: HashSet<CID<SecurityTetraplet>>,
+    canon_elements: HashSet<CID<CanonCidAggregate>>,
+    canon_results: HashSet<CID<CanonResultCidAggregate>>,
+    service_results: HashSet<CID<ServiceResultCidAggregate>>,
+}
+
+impl LiveCids {
+    fn from_trace(cid_state: &ExecutionCidState, trace: &ExecutionTrace) -> Self {
+        let mut live_cids = Self::default();
+
+        for state in trace.iter() {
+            match state {
+                ExecutedState::Call(CallResult::Executed(ValueRef::Scalar(cid)))
+                | ExecutedState::Call(CallResult::Executed(ValueRef::Stream { cid, .. }))
+                | ExecutedState::Call(CallResult::Failed(cid)) => live_cids.mark_service_result(cid_state, cid),
+                ExecutedState::Canon(CanonResult::Executed(cid)) => live_cids.mark_canon_result(cid_state, cid),
+                _ => {}
+            }
+        }
+
+        live_cids
+    }
+
+    fn mark_service_result(&mut self, cid_state: &ExecutionCidState, cid: &CID<ServiceResultCidAggregate>) {
+        if !self.service_results.insert(cid.clone()) {
+            return;
+        }
+
+        // dangling references are left as is, verification reports them on the next run
+        if let Some(service_result) = cid_state.service_result_agg_tracker.get(cid) {
+            self.values.insert(service_result.value_cid.clone());
+            self.tetraplets.insert(service_result.tetraplet_cid.clone());
+        }
+    }
+
+    fn mark_canon_result(&mut self, cid_state: &ExecutionCidState, cid: &CID<CanonResultCidAggregate>) {
+        if !self.canon_results.insert(cid.clone()) {
+            return;
+        }
+
+        let Some(canon_result) = cid_state.canon_result_tracker.get(cid) else {
+            return;
+        };
+        self.tetraplets.insert(canon_result.tetraplet.clone());
+        for element_cid in &canon_result.values {
+            self.mark_canon_element(cid_state, element_cid);
+        }
+    }
+
+    fn mark_canon_element(&mut self, cid_state: &ExecutionCidState, cid: &CID<CanonCidAggregate>) {
+        if !self.canon_elements.insert(cid.clone()) {
+            return;
+        }
+
+        let Some(canon_element) = cid_state.canon_element_tracker.get(cid) else {
+            return;
+        };
+        self.values.insert(canon_element.value.clone());
+        self.tetraplets.insert(canon_element.tetraplet.clone());
+        match &canon_element.provenance {
+            Provenance::Literal => {}
+            Provenance::ServiceResult { cid } => self.mark_service_result(cid_state, cid),
+            Provenance::Canon { cid } => self.mark_canon_result(cid_state, cid),
+        }
+    }
+}
+
 pub(crate) struct ResolvedServiceInfo {
-    pub(crate) value: JValue,
+    pub(crate) value: LazyValue,
     pub(crate) tetraplet: RcSecurityTetraplet,
     pub(crate) service_result_aggregate: Rc<ServiceResultCidAggregate>,
 }
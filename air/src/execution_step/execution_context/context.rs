@@ -14,27 +14,42 @@
  * limitations under the License.
  */
 
+use super::ContextView;
 use super::ErrorDescriptor;
+use super::ErrorOrigin;
 use super::ExecutionCidState;
+use super::GCStats;
 use super::InstructionError;
 use super::LastErrorDescriptor;
 use super::Scalars;
 use super::StreamMaps;
 use super::Streams;
+use crate::debug::PauseRecorder;
+use crate::execution_step::ContextualExecutionError;
 use crate::execution_step::ErrorAffectable;
+use crate::execution_step::ExecutionError;
 use crate::execution_step::RcSecurityTetraplet;
+use crate::observer::ExecutionObserver;
+use crate::replay::ReplayRecorder;
 use crate::ToErrorCode;
+use crate::UncatchableError;
 
 use air_execution_info_collector::InstructionTracker;
 use air_interpreter_cid::CID;
+use air_interpreter_data::CallRequestId;
+use air_interpreter_data::CallRequestIdCounter;
 use air_interpreter_data::CanonResultCidAggregate;
 use air_interpreter_data::CidInfo;
+use air_interpreter_data::ExecutionTrace;
 use air_interpreter_data::ServiceResultCidAggregate;
 use air_interpreter_interface::*;
 use air_interpreter_signatures::PeerCidTracker;
 use air_interpreter_signatures::SignatureStore;
+use air_parser::ast::Instruction;
 
 use std::rc::Rc;
+use std::time::Duration;
+use std::time::Instant;
 
 /// Contains all necessary state needed to execute AIR script.
 pub(crate) struct ExecutionCtx<'i> {
@@ -71,8 +86,8 @@ pub(crate) struct ExecutionCtx<'i> {
     /// Tracker of all met instructions.
     pub(crate) tracker: InstructionTracker,
 
-    /// Last call request id that was used as an id for call request in outcome.
-    pub(crate) last_call_request_id: u32,
+    /// Issues ids for call requests in outcome, it keeps the last used one.
+    pub(crate) call_request_ids: CallRequestIdCounter,
 
     /// Contains all executed results from a host side.
     pub(crate) call_results: CallResults,
@@ -92,6 +107,48 @@ pub(crate) struct ExecutionCtx<'i> {
     ///
     /// It gathers current peer's CIDs (call results and canon results) for further signing.
     pub(crate) peer_cid_tracker: PeerCidTracker,
+
+    /// The maximum number of instructions allowed to be executed.
+    fuel_limit: Option<u64>,
+
+    /// The number of instructions executed so far.
+    fuel_consumed: u64,
+
+    /// The maximum depth of nested folds and fold iterations, it's set only if a host provided the limit.
+    max_fold_depth: Option<u32>,
+
+    /// The number of folds and fold iterations currently being executed one inside another.
+    fold_depth: u32,
+
+    /// Collects dispatched instructions, it's set only when execution is replayed.
+    pub(crate) replay_recorder: Option<ReplayRecorder>,
+
+    /// Records a state before each instruction dispatch, it's set only when execution is debugged.
+    pub(crate) pause_recorder: Option<PauseRecorder>,
+
+    /// The innermost instruction the last execution error was propagated from.
+    error_origin: Option<ErrorOrigin>,
+
+    /// Tells whether a host cancelled execution, it's polled before each instruction.
+    pub(crate) cancellation: Option<Cancellation>,
+
+    /// Receives call and instruction events, it's set only if a host registered an observer.
+    pub(crate) observer: Option<Box<dyn ExecutionObserver>>,
+
+    /// Measures execution time against a limit, it's set only if a host provided the limit.
+    pub(crate) execution_timer: Option<ExecutionTimer>,
+
+    /// Numbers of dispatched instructions of each kind.
+    #[cfg(feature = "metrics")]
+    pub(crate) instruction_counts: InstructionCounts,
+}
+
+pub(crate) type Cancellation = Box<dyn Fn() -> bool>;
+
+/// Wall clock time elapsed since execution started, compared with a host provided limit.
+pub(crate) struct ExecutionTimer {
+    started: Instant,
+    limit: Duration,
 }
 
 impl<'i> ExecutionCtx<'i> {
@@ -102,6 +159,8 @@ impl<'i> ExecutionCtx<'i> {
         signature_store: SignatureStore,
         run_parameters: &RunParameters,
     ) -> Self {
+        let fuel_limit = run_parameters.fuel_limit();
+        let max_fold_depth = run_parameters.max_fold_depth();
         let run_parameters = RcRunParameters::from_run_parameters(run_parameters);
         let streams = Streams::new();
 
@@ -112,7 +171,7 @@ impl<'i> ExecutionCtx<'i> {
         Self {
             run_parameters,
             subgraph_completeness: true,
-            last_call_request_id: prev_ingredients.last_call_request_id,
+            call_request_ids: CallRequestIdCounter::new(prev_ingredients.last_call_request_id),
             call_results,
             streams,
             stream_maps: <_>::default(),
@@ -125,6 +184,18 @@ impl<'i> ExecutionCtx<'i> {
             error_descriptor: <_>::default(),
             tracker: <_>::default(),
             call_requests: <_>::default(),
+            fuel_limit,
+            fuel_consumed: 0,
+            max_fold_depth,
+            fold_depth: 0,
+            replay_recorder: None,
+            pause_recorder: None,
+            error_origin: None,
+            execution_timer: None,
+            cancellation: None,
+            observer: None,
+            #[cfg(feature = "metrics")]
+            instruction_counts: <_>::default(),
         }
     }
 
@@ -136,18 +207,197 @@ impl<'i> ExecutionCtx<'i> {
         self.error_descriptor.error()
     }
 
-    pub(crate) fn next_call_request_id(&mut self) -> u32 {
-        self.last_call_request_id += 1;
-        self.last_call_request_id
+    pub(crate) fn next_call_request_id(&mut self) -> CallRequestId {
+        self.call_request_ids.next()
     }
 
     pub(crate) fn record_call_cid(&mut self, peer_id: &str, cid: &CID<ServiceResultCidAggregate>) {
         self.peer_cid_tracker.register(peer_id, cid);
     }
 
+    /// Consumes a unit of fuel, failing if the limit has been already reached.
+    pub(crate) fn consume_fuel(&mut self) -> Result<(), UncatchableError> {
+        if self.fuel_limit.is_some_and(|limit| self.fuel_consumed >= limit) {
+            return Err(UncatchableError::FuelExhausted {
+                consumed: self.fuel_consumed,
+            });
+        }
+
+        self.fuel_consumed += 1;
+        Ok(())
+    }
+
+    /// Accounts a fold or a fold iteration executed inside the current one, failing if
+    /// the depth limit is exceeded. Every successful call must be paired with `exit_fold`.
+    pub(crate) fn enter_fold(&mut self) -> Result<(), UncatchableError> {
+        let depth = self.fold_depth + 1;
+        if self.max_fold_depth.is_some_and(|limit| depth > limit) {
+            return Err(UncatchableError::FoldDepthExceeded { depth });
+        }
+
+        self.fold_depth = depth;
+        Ok(())
+    }
+
+    pub(crate) fn exit_fold(&mut self) {
+        self.fold_depth -= 1;
+    }
+
+    /// Fails if a host has requested cancellation of execution.
+    pub(crate) fn check_cancellation(&self) -> Result<(), UncatchableError> {
+        match &self.cancellation {
+            Some(is_cancelled) if is_cancelled() => Err(UncatchableError::Cancelled {
+                executed: self.fuel_consumed,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Fails if execution has been running longer than a host allowed.
+    pub(crate) fn check_execution_time(&self) -> Result<(), UncatchableError> {
+        match self.execution_timer.as_ref().and_then(ExecutionTimer::exceeded_by) {
+            Some(elapsed) => Err(UncatchableError::ExecutionTimedOut {
+                elapsed_ms: elapsed.as_millis() as u64,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    #[cfg(feature = "metrics")]
+    pub(crate) fn count_instruction(&mut self, instruction: &Instruction<'_>) {
+        *self.instruction_counts.entry(instruction_tag(instruction)).or_default() += 1;
+    }
+
+    pub(crate) fn fuel_consumed(&self) -> u64 {
+        self.fuel_consumed
+    }
+
+    /// Records an instruction dispatch if execution is replayed and returns a position of the recorded event.
+    pub(crate) fn record_replay_dispatch(&mut self, instruction: &impl ToString) -> Option<usize> {
+        let recorder = self.replay_recorder.as_mut()?;
+        let variables = format!(
+            "{}streams:\n  {}\nstream_maps:\n  {}",
+            self.scalars, self.streams, self.stream_maps
+        );
+
+        Some(recorder.record_dispatch(instruction.to_string(), variables, self.call_request_ids.last()))
+    }
+
+    pub(crate) fn record_replay_completion(&mut self, position: usize) {
+        if let Some(recorder) = self.replay_recorder.as_mut() {
+            recorder.record_completion(position, &self.call_requests);
+        }
+    }
+
+    /// Returns a read-only view of the context for loggers and debugger hooks.
+    pub(crate) fn immutable_view(&self) -> ContextView<'_> {
+        ContextView::new(self)
+    }
+
+    /// Records a pause before an instruction dispatch if execution is debugged.
+    pub(crate) fn record_pause(&mut self, instruction: &Instruction<'i>) {
+        // the recorder is taken out for a while, so it doesn't borrow the context it views
+        if let Some(mut recorder) = self.pause_recorder.take() {
+            recorder.record_pause(instruction, &self.immutable_view());
+            self.pause_recorder = Some(recorder);
+        }
+    }
+
+    /// Passes a read-only view of the context to the observer if a host registered one.
+    pub(crate) fn notify_observer(&mut self, notify: impl FnOnce(&mut dyn ExecutionObserver, &ContextView<'_>)) {
+        // the observer is taken out for a while, so it doesn't borrow the context it views
+        if let Some(mut observer) = self.observer.take() {
+            notify(observer.as_mut(), &self.immutable_view());
+            self.observer = Some(observer);
+        }
+    }
+
+    /// Remembers the instruction as an origin of the error unless the error was propagated from
+    /// a nested instruction, so the innermost instruction an error met is kept.
+    pub(crate) fn record_error_origin(&mut self, instruction: &Instruction<'i>, error: &ExecutionError) {
+        if self
+            .error_origin
+            .as_ref()
+            .is_some_and(|origin| origin.is_origin_of(error))
+        {
+            return;
+        }
+
+        self.error_origin = Some(ErrorOrigin::new(instruction, error));
+    }
+
+    /// Forgets the origin of an error caught by xor.
+    pub(crate) fn forget_error_origin(&mut self) {
+        self.error_origin = None;
+    }
+
+    /// Wraps an error of the script execution with the span and the kind of the instruction raised it.
+    pub(crate) fn with_error_context(
+        &self,
+        error: ExecutionError,
+        root: &Instruction<'i>,
+        air: &str,
+    ) -> ContextualExecutionError {
+        ErrorOrigin::attach_to(self.error_origin.as_ref(), error, root, air)
+    }
+
     pub(crate) fn record_canon_cid(&mut self, peer_id: &str, cid: &CID<CanonResultCidAggregate>) {
         self.peer_cid_tracker.register(peer_id, cid);
     }
+
+    /// Drops CID store entries that aren't referenced from the result trace anymore,
+    /// it should be called when execution is over.
+    pub(crate) fn gc_cid_store(&mut self, result_trace: &ExecutionTrace) -> GCStats {
+        self.cid_state.collect_garbage(result_trace)
+    }
+}
+
+/// Returns the tag instructions are counted and allowed by, variations of an instruction share it.
+pub(crate) fn instruction_tag(instruction: &Instruction<'_>) -> InstructionTag {
+    match instruction {
+        Instruction::Call(_) => InstructionTag::Call,
+        Instruction::Canon(_) | Instruction::CanonMap(_) | Instruction::CanonStreamMapScalar(_) => {
+            InstructionTag::Canon
+        }
+        Instruction::CanonSorted(_) => InstructionTag::CanonSorted,
+        Instruction::Ap(_) | Instruction::ApMap(_) => InstructionTag::Ap,
+        Instruction::Seq(_) => InstructionTag::Seq,
+        Instruction::Par(_) => InstructionTag::Par,
+        Instruction::Xor(_) => InstructionTag::Xor,
+        Instruction::Match(_) => InstructionTag::Match,
+        Instruction::MisMatch(_) => InstructionTag::Mismatch,
+        Instruction::MatchRegex(_) => InstructionTag::MatchRegex,
+        Instruction::MisMatchRegex(_) => InstructionTag::MismatchRegex,
+        Instruction::Fail(_) => InstructionTag::Fail,
+        Instruction::FoldScalar(_) | Instruction::FoldStream(_) | Instruction::FoldStreamMap(_) => InstructionTag::Fold,
+        Instruction::Never(_) => InstructionTag::Never,
+        Instruction::New(_) => InstructionTag::New,
+        Instruction::Next(_) => InstructionTag::Next,
+        Instruction::Null(_) | Instruction::Error => InstructionTag::Null,
+    }
+}
+
+impl ExecutionTimer {
+    /// Starts measuring time if the limit is set.
+    #[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+    pub(crate) fn start(limit: Option<Duration>) -> Option<Self> {
+        limit.map(|limit| Self {
+            started: Instant::now(),
+            limit,
+        })
+    }
+
+    /// There is no clock on this target, so the limit is enforced only by a host.
+    #[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+    pub(crate) fn start(_limit: Option<Duration>) -> Option<Self> {
+        None
+    }
+
+    /// Returns elapsed time if it's over the limit.
+    fn exceeded_by(&self) -> Option<Duration> {
+        let elapsed = self.started.elapsed();
+        (elapsed > self.limit).then_some(elapsed)
+    }
 }
 
 impl ExecutionCtx<'_> {
@@ -208,7 +458,7 @@ impl ExecutionCtx<'_> {
 /// Helper struct for ExecCtx construction.
 #[derive(Debug, Clone)]
 pub(crate) struct ExecCtxIngredients {
-    pub(crate) last_call_request_id: u32,
+    pub(crate) last_call_request_id: CallRequestId,
     pub(crate) cid_info: CidInfo,
 }
 
@@ -260,3 +510,163 @@ impl<'i> Display for ExecutionCtx<'i> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::super::ContextInspector;
+    use super::super::StreamValueDescriptor;
+    use super::ExecCtxIngredients;
+    use super::ExecutionCtx;
+    use crate::execution_step::Generation;
+    use crate::execution_step::ServiceResultAggregate;
+    use crate::execution_step::ValueAggregate;
+    use crate::JValue;
+
+    use air_interpreter_cid::CID;
+    use air_interpreter_interface::RunParameters;
+    use serde_json::json;
+
+    fn create_exec_ctx() -> ExecutionCtx<'static> {
+        let ingredients = ExecCtxIngredients {
+            last_call_request_id: 0.into(),
+            cid_info: <_>::default(),
+        };
+        let run_parameters = RunParameters::builder()
+            .init_peer_id("init_peer_id".to_string())
+            .current_peer_id("current_peer_id".to_string())
+            .timestamp(0)
+            .ttl(0)
+            .key_format(0)
+            .secret_key_bytes(vec![])
+            .particle_id("particle_id".to_string())
+            .air_size_limit(u64::MAX)
+            .particle_size_limit(u64::MAX)
+            .call_result_size_limit(u64::MAX)
+            .build()
+            .unwrap();
+
+        ExecutionCtx::new(
+            ingredients.clone(),
+            ingredients,
+            <_>::default(),
+            <_>::default(),
+            &run_parameters,
+        )
+    }
+
+    fn create_value(value: impl Into<JValue>) -> ValueAggregate {
+        ValueAggregate::from_service_result(
+            ServiceResultAggregate::new(value.into(), <_>::default(), 1.into()),
+            CID::new("some fake cid"),
+        )
+    }
+
+    #[test]
+    fn inspector_reads_scalars_and_streams() {
+        let mut exec_ctx = create_exec_ctx();
+        exec_ctx
+            .scalars
+            .set_scalar_value("scalar", create_value(json!(1)))
+            .unwrap();
+        for value in [json!(2), json!(3)] {
+            let descriptor =
+                StreamValueDescriptor::new(create_value(value), "$stream", Generation::Current(0.into()), 1.into());
+            exec_ctx.streams.add_stream_value(descriptor).unwrap();
+        }
+
+        let scalar = exec_ctx.get_scalar("scalar").unwrap();
+        assert_eq!(scalar.get_result().unwrap(), &JValue::from(json!(1)));
+        assert!(exec_ctx.get_scalar("$stream").is_none());
+
+        let stream = exec_ctx.get_stream("$stream").unwrap();
+        let values: Vec<_> = stream.iter().map(|value| value.get_result().unwrap().clone()).collect();
+        assert_eq!(values, vec![JValue::from(json!(2)), JValue::from(json!(3))]);
+        assert!(exec_ctx.get_stream("$absent").is_none());
+
+        let mut names: Vec<_> = exec_ctx.variable_names().collect();
+        names.sort();
+        assert_eq!(names, vec!["$stream", "scalar"]);
+    }
+
+    #[test]
+    fn immutable_view_reads_context() {
+        let mut exec_ctx = create_exec_ctx();
+        exec_ctx
+            .scalars
+            .set_scalar_value("scalar", create_value(json!(1)))
+            .unwrap();
+
+        let view = exec_ctx.immutable_view();
+        assert_eq!(view.current_peer_id(), "current_peer_id");
+        assert_eq!(
+            view.get_scalar("scalar").unwrap().get_result().unwrap(),
+            &JValue::from(json!(1))
+        );
+        assert!(view.get_stream("$stream").is_none());
+        assert_eq!(view.variable_names().collect::<Vec<_>>(), vec!["scalar"]);
+    }
+
+    #[test]
+    fn export_variables_maps_names_to_values() {
+        let mut exec_ctx = create_exec_ctx();
+        exec_ctx
+            .scalars
+            .set_scalar_value("scalar", create_value(json!({"field": [1, "2"]})))
+            .unwrap();
+        for value in [json!(2), json!("3")] {
+            let descriptor =
+                StreamValueDescriptor::new(create_value(value), "$stream", Generation::Current(0.into()), 1.into());
+            exec_ctx.streams.add_stream_value(descriptor).unwrap();
+        }
+
+        let expected = json!({
+            "$stream": [2, "3"],
+            "scalar": {"field": [1, "2"]},
+        });
+        assert_eq!(exec_ctx.export_variables(), expected);
+        assert_eq!(exec_ctx.immutable_view().export_variables(), expected);
+    }
+
+    #[test]
+    fn diff_since_lists_changed_variables() {
+        let mut exec_ctx = create_exec_ctx();
+        exec_ctx
+            .scalars
+            .set_scalar_value("kept", create_value(json!(1)))
+            .unwrap();
+        let descriptor = StreamValueDescriptor::new(
+            create_value(json!(2)),
+            "$stream",
+            Generation::Current(0.into()),
+            1.into(),
+        );
+        exec_ctx.streams.add_stream_value(descriptor).unwrap();
+
+        let checkpoint = exec_ctx.checkpoint();
+        assert!(exec_ctx.diff_since(&checkpoint).is_empty());
+
+        exec_ctx
+            .scalars
+            .set_scalar_value("added", create_value(json!(3)))
+            .unwrap();
+        let descriptor = StreamValueDescriptor::new(
+            create_value(json!(4)),
+            "$stream",
+            Generation::Current(0.into()),
+            2.into(),
+        );
+        exec_ctx.streams.add_stream_value(descriptor).unwrap();
+
+        let diff = exec_ctx.immutable_view().diff_since(&checkpoint);
+        assert_eq!(
+            diff.added_scalars.into_iter().collect::<Vec<_>>(),
+            vec![("added".to_string(), create_value(json!(3)))]
+        );
+        assert!(diff.modified_scalars.is_empty());
+        assert_eq!(
+            diff.appended_stream_values.into_iter().collect::<Vec<_>>(),
+            vec![("$stream".to_string(), vec![create_value(json!(4))])]
+        );
+        assert!(diff.removed_variables.is_empty());
+    }
+}
@@ -0,0 +1,234 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::ExecutionCtx;
+use crate::execution_step::ValueAggregate;
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+/// Read-only access to variables of a running execution.
+pub trait ContextInspector {
+    /// Returns a value of a non iterable scalar visible in the current scope.
+    fn get_scalar(&self, name: &str) -> Option<&ValueAggregate>;
+
+    /// Returns values of the innermost stream with the given name in the order of generations,
+    /// they are collected because a stream keeps each generation separately.
+    fn get_stream(&self, name: &str) -> Option<Vec<ValueAggregate>>;
+
+    /// Returns names of visible scalars, fold iterators and streams.
+    fn variable_names(&self) -> impl Iterator<Item = &str>;
+}
+
+impl ContextInspector for ExecutionCtx<'_> {
+    fn get_scalar(&self, name: &str) -> Option<&ValueAggregate> {
+        self.scalars.get_non_iterable_scalar(name).ok().flatten()
+    }
+
+    fn get_stream(&self, name: &str) -> Option<Vec<ValueAggregate>> {
+        let stream = self.streams.get_innermost(name)?;
+        Some(stream.iter().cloned().collect())
+    }
+
+    fn variable_names(&self) -> impl Iterator<Item = &str> {
+        self.scalars.visible_names().chain(self.streams.names())
+    }
+}
+
+impl ExecutionCtx<'_> {
+    /// Returns a JSON object that maps names of visible scalars, fold iterators and streams
+    /// to their values, streams are mapped to arrays of values in the order of generations.
+    ///
+    /// Keys are sorted and stream names keep their `$` prefix, so the object is stable
+    /// and could be compared to a fixture.
+    pub(crate) fn export_variables(&self) -> serde_json::Value {
+        let scalars = self
+            .scalars
+            .visible_values()
+            .into_iter()
+            .map(|(name, value)| (name, to_json(&value)));
+        let streams = self.streams.names().map(|name| {
+            let values = self.get_stream(name).unwrap_or_default();
+            let values = values.iter().map(to_json).collect();
+            (name.to_string(), serde_json::Value::Array(values))
+        });
+
+        serde_json::Value::Object(scalars.chain(streams).collect())
+    }
+}
+
+/// Variables visible at some point of an execution, see `ExecutionCtx::checkpoint`.
+#[derive(Debug, Clone, Default)]
+pub struct ContextCheckpoint {
+    scalars: HashMap<String, ValueAggregate>,
+    stream_lens: HashMap<String, usize>,
+}
+
+/// Changes of visible variables between a checkpoint and the current state of an execution.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ContextDiff {
+    /// Scalars and fold iterators that weren't visible at the checkpoint.
+    pub added_scalars: BTreeMap<String, ValueAggregate>,
+    /// Scalars and fold iterators whose values differ, e.g. iterators moved by `next`,
+    /// the old value goes first.
+    pub modified_scalars: BTreeMap<String, (ValueAggregate, ValueAggregate)>,
+    /// Values added to streams since the checkpoint, including all values of new streams.
+    pub appended_stream_values: BTreeMap<String, Vec<ValueAggregate>>,
+    /// Scalars, fold iterators and streams that aren't visible anymore.
+    pub removed_variables: BTreeSet<String>,
+}
+
+impl ContextDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added_scalars.is_empty()
+            && self.modified_scalars.is_empty()
+            && self.appended_stream_values.is_empty()
+            && self.removed_variables.is_empty()
+    }
+}
+
+impl ExecutionCtx<'_> {
+    /// Remembers visible variables, so they could be compared with later ones by `diff_since`.
+    pub(crate) fn checkpoint(&self) -> ContextCheckpoint {
+        let stream_lens = self
+            .streams
+            .names()
+            .map(|name| {
+                let len = self.get_stream(name).map_or(0, |values| values.len());
+                (name.to_string(), len)
+            })
+            .collect();
+
+        ContextCheckpoint {
+            scalars: self.scalars.visible_values(),
+            stream_lens,
+        }
+    }
+
+    /// Returns changes of visible variables since the checkpoint.
+    pub(crate) fn diff_since(&self, checkpoint: &ContextCheckpoint) -> ContextDiff {
+        let mut diff = ContextDiff::default();
+
+        let scalars = self.scalars.visible_values();
+        for (name, value) in &scalars {
+            match checkpoint.scalars.get(name) {
+                None => {
+                    diff.added_scalars.insert(name.clone(), value.clone());
+                }
+                Some(old_value) if old_value != value => {
+                    diff.modified_scalars
+                        .insert(name.clone(), (old_value.clone(), value.clone()));
+                }
+                Some(_) => {}
+            }
+        }
+        let removed_scalars = checkpoint.scalars.keys().filter(|name| !scalars.contains_key(*name));
+        diff.removed_variables.extend(removed_scalars.cloned());
+
+        let mut stream_names = BTreeSet::new();
+        for name in self.streams.names() {
+            stream_names.insert(name);
+            let values = self.get_stream(name).unwrap_or_default();
+            let old_len = checkpoint.stream_lens.get(name).copied().unwrap_or_default();
+            if values.len() > old_len {
+                diff.appended_stream_values
+                    .insert(name.to_string(), values[old_len..].to_vec());
+            }
+        }
+        let removed_streams = checkpoint
+            .stream_lens
+            .keys()
+            .filter(|name| !stream_names.contains(name.as_str()));
+        diff.removed_variables.extend(removed_streams.cloned());
+
+        diff
+    }
+}
+
+fn to_json(value: &ValueAggregate) -> serde_json::Value {
+    match value.get_result() {
+        // JValue contains only JSON compatible values
+        Ok(result) => serde_json::to_value(result).expect("JValue is serializable to JSON"),
+        // a malformed value from data is shown as its parsing error
+        Err(error) => serde_json::Value::String(error.to_string()),
+    }
+}
+
+/// Read-only view of a running execution that is passed to loggers and debugger hooks
+/// instead of the mutable context.
+///
+/// Values share their data with the context via `Rc`, so the view can't be sent to another thread,
+/// values that should outlive the execution have to be cloned out of it.
+#[derive(Clone, Copy)]
+pub struct ContextView<'ctx> {
+    ctx: &'ctx ExecutionCtx<'ctx>,
+}
+
+impl<'ctx> ContextView<'ctx> {
+    pub(crate) fn new(ctx: &'ctx ExecutionCtx<'ctx>) -> Self {
+        Self { ctx }
+    }
+
+    /// Returns id of the peer the script is executed on.
+    pub fn current_peer_id(&self) -> &str {
+        &self.ctx.run_parameters.current_peer_id
+    }
+
+    /// Returns non iterable scalars and current values of fold iterators visible in the current scope.
+    pub(crate) fn visible_values(&self) -> HashMap<String, ValueAggregate> {
+        self.ctx.scalars.visible_values()
+    }
+
+    /// Returns a JSON snapshot of visible variables, see `ExecutionCtx::export_variables`.
+    pub fn export_variables(&self) -> serde_json::Value {
+        self.ctx.export_variables()
+    }
+
+    /// Remembers visible variables, see `ExecutionCtx::checkpoint`.
+    pub fn checkpoint(&self) -> ContextCheckpoint {
+        self.ctx.checkpoint()
+    }
+
+    /// Returns changes of visible variables since the checkpoint, see `ExecutionCtx::diff_since`.
+    pub fn diff_since(&self, checkpoint: &ContextCheckpoint) -> ContextDiff {
+        self.ctx.diff_since(checkpoint)
+    }
+}
+
+impl ContextInspector for ContextView<'_> {
+    fn get_scalar(&self, name: &str) -> Option<&ValueAggregate> {
+        self.ctx.get_scalar(name)
+    }
+
+    fn get_stream(&self, name: &str) -> Option<Vec<ValueAggregate>> {
+        self.ctx.get_stream(name)
+    }
+
+    fn variable_names(&self) -> impl Iterator<Item = &str> {
+        self.ctx.variable_names()
+    }
+}
+
+impl std::fmt::Display for ContextView<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "  scalars:\n    {}\n  streams:\n    {}",
+            self.ctx.scalars, self.ctx.streams
+        )
+    }
+}
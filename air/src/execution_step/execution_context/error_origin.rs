@@ -0,0 +1,124 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::instruction_tag;
+use crate::execution_step::CatchableError;
+use crate::execution_step::ContextualExecutionError;
+use crate::execution_step::ExecutionError;
+use crate::utils::instruction_spans;
+
+use air_interpreter_interface::InstructionTag;
+use air_parser::ast::Instruction;
+use air_parser::ast::Span;
+use air_parser::ast::Visitor;
+
+use std::rc::Rc;
+
+/// The innermost instruction an execution error was propagated from.
+pub(crate) struct ErrorOrigin {
+    error: ErrorIdentity,
+    instruction: *const (),
+    tag: InstructionTag,
+}
+
+/// Tells whether two errors are the same one propagated through several instructions.
+enum ErrorIdentity {
+    /// Catchable errors are shared, the kept pointer can't be reused by another error.
+    Catchable(Rc<CatchableError>),
+    /// Uncatchable errors aren't shared, so they are identified by their messages.
+    Message(String),
+}
+
+impl ErrorOrigin {
+    pub(crate) fn new(instruction: &Instruction<'_>, error: &ExecutionError) -> Self {
+        Self {
+            error: ErrorIdentity::new(error),
+            instruction: key(instruction),
+            tag: instruction_tag(instruction),
+        }
+    }
+
+    pub(crate) fn is_origin_of(&self, error: &ExecutionError) -> bool {
+        match (&self.error, error) {
+            (ErrorIdentity::Catchable(origin_error), ExecutionError::Catchable(error)) => {
+                Rc::ptr_eq(origin_error, error)
+            }
+            (
+                ErrorIdentity::Message(message),
+                ExecutionError::Uncatchable(error) | ExecutionError::Recoverable(error),
+            ) => *message == error.to_string(),
+            _ => false,
+        }
+    }
+
+    /// Wraps the error with the span of the instruction it originates from in the script,
+    /// the whole script is blamed if the origin can't be found in the AST.
+    pub(crate) fn attach_to(
+        origin: Option<&Self>,
+        error: ExecutionError,
+        root: &Instruction<'_>,
+        air: &str,
+    ) -> ContextualExecutionError {
+        let (position, tag) = match origin {
+            Some(origin) if origin.is_origin_of(&error) => (script_position(root, origin.instruction), origin.tag),
+            _ => (Some(0), instruction_tag(root)),
+        };
+
+        let span = position
+            .and_then(|position| instruction_spans(air).get(position).copied())
+            .unwrap_or_else(|| Span::new(0.into(), air.len().into()));
+        error.with_context(span, tag)
+    }
+}
+
+impl ErrorIdentity {
+    fn new(error: &ExecutionError) -> Self {
+        match error {
+            ExecutionError::Catchable(error) => Self::Catchable(error.clone()),
+            ExecutionError::Uncatchable(error) | ExecutionError::Recoverable(error) => Self::Message(error.to_string()),
+        }
+    }
+}
+
+fn key(instruction: &Instruction<'_>) -> *const () {
+    instruction as *const Instruction<'_> as *const ()
+}
+
+/// Returns a position of the instruction in the script order.
+fn script_position(root: &Instruction<'_>, instruction: *const ()) -> Option<usize> {
+    struct PositionFinder {
+        instruction: *const (),
+        visited: usize,
+        position: Option<usize>,
+    }
+
+    impl<'i> Visitor<'i> for PositionFinder {
+        fn visit_instruction(&mut self, instruction: &Instruction<'i>) {
+            if self.position.is_none() && key(instruction) == self.instruction {
+                self.position = Some(self.visited);
+            }
+            self.visited += 1;
+        }
+    }
+
+    let mut finder = PositionFinder {
+        instruction,
+        visited: 0,
+        position: None,
+    };
+    root.walk(&mut finder);
+    finder.position
+}
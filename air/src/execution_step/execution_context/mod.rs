@@ -16,6 +16,8 @@
 
 mod cid_state;
 mod context;
+mod context_inspector;
+mod error_origin;
 mod instruction_error;
 mod scalar_variables;
 mod stream_maps_variables;
@@ -24,8 +26,14 @@ mod streams_variables;
 pub use instruction_error::*;
 
 pub use cid_state::ExecutionCidState;
+pub use cid_state::GCStats;
 pub(crate) use cid_state::ResolvedServiceInfo;
 pub(crate) use context::*;
+pub use context_inspector::ContextCheckpoint;
+pub use context_inspector::ContextDiff;
+pub use context_inspector::ContextInspector;
+pub use context_inspector::ContextView;
+pub(crate) use error_origin::ErrorOrigin;
 pub(crate) use scalar_variables::*;
 pub(crate) use stream_maps_variables::*;
 pub(crate) use streams_variables::*;
@@ -191,6 +191,31 @@ impl<'i> Scalars<'i> {
         }
     }
 
+    /// Returns names of non iterable scalars and fold iterators visible in the current scope.
+    pub(crate) fn visible_names(&self) -> impl Iterator<Item = &str> {
+        self.non_iterable_variables
+            .visible_values()
+            .map(|(name, _)| name)
+            .chain(self.iterable_variables.keys().map(String::as_str))
+    }
+
+    /// Returns non iterable scalars and current values of fold iterators visible in the current scope.
+    pub(crate) fn visible_values(&self) -> HashMap<String, ValueAggregate> {
+        let mut values: HashMap<_, _> = self
+            .non_iterable_variables
+            .visible_values()
+            .map(|(name, value)| (name.to_string(), value.clone()))
+            .collect();
+
+        for (name, fold_state) in self.iterable_variables.iter() {
+            if let Some(item) = fold_state.iterable.peek() {
+                values.insert(name.clone(), item.into_resolved_result());
+            }
+        }
+
+        values
+    }
+
     pub(crate) fn variable_could_be_set(&self, variable_name: &str) -> bool {
         self.non_iterable_variables.variable_could_be_set(variable_name)
             || self.canon_streams.variable_could_be_set(variable_name)
@@ -101,6 +101,18 @@ impl<T> ValuesSparseMatrix<T> {
             .ok_or_else(|| ExecutionError::Catchable(Rc::new(CatchableError::VariableNotFound(name.to_string()))))
     }
 
+    /// Returns values of variables accessible at the current depth.
+    pub(super) fn visible_values(&self) -> impl Iterator<Item = (&str, &T)> {
+        self.cells.iter().filter_map(|(name, values)| {
+            let last_cell = values.last();
+            if !self.allowed_depths.contains(&last_cell.depth) {
+                return None;
+            }
+
+            last_cell.value.as_ref().map(|value| (name.as_str(), value))
+        })
+    }
+
     pub(super) fn meet_fold_start(&mut self) {
         self.current_depth += 1;
         self.allowed_depths.insert(self.current_depth);
@@ -52,7 +52,7 @@ impl StreamMapKey {
     }
 
     pub(crate) fn from_kvpair_owned(value: &ValueAggregate) -> Option<Self> {
-        let object = value.get_result().as_object()?;
+        let object = value.get_result().ok()?.as_object()?;
         let key = object.get(KEY_FIELD_NAME)?.clone();
         StreamMapKey::from_value(key)
     }
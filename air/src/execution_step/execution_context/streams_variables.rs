@@ -58,6 +58,18 @@ impl Streams {
             .and_then(|descriptors| find_closest_mut(descriptors.iter_mut(), position))
     }
 
+    /// Returns a stream with the given name from the innermost scope it's visible in.
+    pub(crate) fn get_innermost(&self, name: &str) -> Option<&Stream> {
+        self.streams
+            .get(name)
+            .and_then(|descriptors| descriptors.last())
+            .map(|descriptor| &descriptor.stream)
+    }
+
+    pub(crate) fn names(&self) -> impl Iterator<Item = &str> {
+        self.streams.keys().map(String::as_str)
+    }
+
     pub(crate) fn add_stream_value(&mut self, value_descriptor: StreamValueDescriptor<'_>) -> ExecutionResult<()> {
         let StreamValueDescriptor {
             value,
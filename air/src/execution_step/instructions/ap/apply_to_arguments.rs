@@ -146,7 +146,7 @@ fn apply_canon_stream(
     use crate::execution_step::value_types::JValuable;
 
     let canon_stream = exec_ctx.scalars.get_canon_stream(ast_stream.name)?;
-    let value = JValuable::as_jvalue(&&canon_stream.canon_stream);
+    let value = JValuable::as_jvalue(&&canon_stream.canon_stream)?;
     let tetraplet = canon_stream.tetraplet().clone();
     let position = trace_ctx.trace_pos().map_err(UncatchableError::from)?;
     let value = CanonResultAggregate::new(value, tetraplet.peer_pk.as_str().into(), &tetraplet.lens, position);
@@ -184,7 +184,7 @@ fn apply_canon_stream_map(
     use crate::execution_step::value_types::JValuable;
 
     let canon_stream_map = exec_ctx.scalars.get_canon_map(ast_canon_stream_map.name)?;
-    let value = JValuable::as_jvalue(&&canon_stream_map.canon_stream_map);
+    let value = JValuable::as_jvalue(&&canon_stream_map.canon_stream_map)?;
     let tetraplet = canon_stream_map.tetraplet();
     let position = trace_ctx.trace_pos().map_err(UncatchableError::from)?;
     let value = CanonResultAggregate::new(value, tetraplet.peer_pk.as_str().into(), &tetraplet.lens, position);
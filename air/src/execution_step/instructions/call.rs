@@ -60,6 +60,15 @@ fn set_errors<'i>(
 
     let catchable_error = match execution_error {
         ExecutionError::Catchable(catchable) => catchable,
+        ExecutionError::Recoverable(_) => {
+            exec_ctx.set_errors(
+                &execution_error,
+                &call.to_string(),
+                tetraplet,
+                call.log_errors_with_peer_id(),
+            );
+            return execution_error;
+        }
         ExecutionError::Uncatchable(_) => return execution_error,
     };
 
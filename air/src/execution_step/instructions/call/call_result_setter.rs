@@ -19,6 +19,7 @@ use crate::execution_step::execution_context::*;
 use crate::execution_step::Generation;
 use crate::execution_step::ServiceResultAggregate;
 use crate::execution_step::ValueAggregate;
+use crate::JValue;
 use crate::UncatchableError;
 
 use air_interpreter_cid::value_to_json_cid;
@@ -39,10 +40,11 @@ pub(crate) fn populate_context_from_peer_service_result<'i>(
     match output {
         CallOutputValue::Scalar(scalar) => {
             let peer_id = tetraplet.peer_pk.clone();
-            let service_result_agg_cid =
-                exec_ctx
-                    .cid_state
-                    .track_service_result(executed_result.result.clone(), tetraplet, argument_hash)?;
+            let service_result_agg_cid = exec_ctx.cid_state.track_service_result(
+                service_result_value(&executed_result)?,
+                tetraplet,
+                argument_hash,
+            )?;
             let executed_result = ValueAggregate::from_service_result(executed_result, service_result_agg_cid.clone());
 
             exec_ctx.scalars.set_scalar_value(scalar.name, executed_result)?;
@@ -51,10 +53,11 @@ pub(crate) fn populate_context_from_peer_service_result<'i>(
         }
         CallOutputValue::Stream(stream) => {
             let peer_id = tetraplet.peer_pk.clone();
-            let service_result_agg_cid =
-                exec_ctx
-                    .cid_state
-                    .track_service_result(executed_result.result.clone(), tetraplet, argument_hash)?;
+            let service_result_agg_cid = exec_ctx.cid_state.track_service_result(
+                service_result_value(&executed_result)?,
+                tetraplet,
+                argument_hash,
+            )?;
 
             let executed_result = ValueAggregate::from_service_result(executed_result, service_result_agg_cid.clone());
 
@@ -65,13 +68,23 @@ pub(crate) fn populate_context_from_peer_service_result<'i>(
             Ok(CallResult::executed_stream_stub(service_result_agg_cid))
         }
         CallOutputValue::None => {
-            let value_cid = value_to_json_cid(&executed_result.result).map_err(UncatchableError::from)?;
+            let value_cid =
+                value_to_json_cid(&service_result_value(&executed_result)?).map_err(UncatchableError::from)?;
 
             Ok(CallResult::executed_unused(value_cid))
         }
     }
 }
 
+// results coming from a host are already parsed, so this doesn't fail for them
+fn service_result_value(executed_result: &ServiceResultAggregate) -> ExecutionResult<JValue> {
+    executed_result
+        .result
+        .get_value()
+        .cloned()
+        .map_err(|e| UncatchableError::MalformedValue(e).into())
+}
+
 pub(crate) fn populate_context_from_data<'i>(
     value: ValueRef,
     argument_hash: &str,
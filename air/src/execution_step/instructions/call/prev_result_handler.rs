@@ -44,6 +44,31 @@ pub(super) fn handle_prev_state<'i>(
     output: &CallOutputValue<'i>,
     exec_ctx: &mut ExecutionCtx<'i>,
     trace_ctx: &mut TraceHandler,
+) -> ExecutionResult<StateDescriptor> {
+    let prev_state = met_result.result.clone();
+
+    match handle_met_state(met_result, tetraplet, argument_hash, output, exec_ctx, trace_ctx) {
+        // the state and its value stay in data as is, so the trace stays consistent
+        // and signed if xor recovers from this error
+        Err(ExecutionError::Uncatchable(error)) if error.is_caused_by_call_result() => {
+            if let Some(cid) = prev_state.get_cid() {
+                exec_ctx.record_call_cid(&tetraplet.peer_pk, cid);
+            }
+            exec_ctx.make_subgraph_incomplete();
+            trace_ctx.meet_call_end(prev_state);
+            Err(ExecutionError::Recoverable(error))
+        }
+        result => result,
+    }
+}
+
+fn handle_met_state<'i>(
+    met_result: MetCallResult,
+    tetraplet: &RcSecurityTetraplet,
+    argument_hash: Option<&Rc<str>>,
+    output: &CallOutputValue<'i>,
+    exec_ctx: &mut ExecutionCtx<'i>,
+    trace_ctx: &mut TraceHandler,
 ) -> ExecutionResult<StateDescriptor> {
     use CallResult::*;
 
@@ -67,6 +92,7 @@ pub(super) fn handle_prev_state<'i>(
                 &current_tetraplet,
             )?;
 
+            let err_value = err_value.get_value().map_err(UncatchableError::MalformedValue)?;
             let call_service_failed: CallServiceFailed =
                 serde_json::from_value(serde_json::to_value(err_value).expect("serde_json serializer shouldn't fail"))
                     .map_err(UncatchableError::MalformedCallServiceFailed)?;
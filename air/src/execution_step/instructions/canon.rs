@@ -61,7 +61,7 @@ impl<'i> super::ExecutableInstruction<'i> for ast::Canon<'i> {
     }
 }
 
-fn epilog_closure(canon_stream_name: &str) -> Box<CanonEpilogClosure<'_>> {
+pub(super) fn epilog_closure(canon_stream_name: &str) -> Box<CanonEpilogClosure<'_>> {
     Box::new(
         move |canon_stream: CanonStream,
               canon_result_cid: CID<CanonResultCidAggregate>,
@@ -0,0 +1,75 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::canon::epilog_closure;
+use super::canon_utils::handle_seen_canon;
+use super::canon_utils::handle_unseen_canon;
+use super::canon_utils::CreateCanonStreamClosure;
+use super::ExecutionCtx;
+use super::ExecutionResult;
+use super::TraceHandler;
+use crate::execution_step::value_types::CanonStream;
+use crate::log_instruction;
+use crate::trace_to_exec_err;
+
+use air_parser::ast;
+use air_parser::AirPos;
+use air_trace_handler::merger::MergerCanonResult;
+
+impl<'i> super::ExecutableInstruction<'i> for ast::CanonSorted<'i> {
+    #[tracing::instrument(level = "debug", skip(exec_ctx, trace_ctx))]
+    fn execute(&self, exec_ctx: &mut ExecutionCtx<'i>, trace_ctx: &mut TraceHandler) -> ExecutionResult<()> {
+        log_instruction!(canon_sorted, exec_ctx, trace_ctx);
+        let epilog = &epilog_closure(self.canon_stream.name);
+        let canon_result = trace_to_exec_err!(trace_ctx.meet_canon_start(), self)?;
+
+        let create_canon_producer = create_sorted_canon_stream_producer(self.stream.name, self.stream.position);
+        match canon_result {
+            MergerCanonResult::CanonResult(canon_result) => handle_seen_canon(
+                &self.peer_id,
+                epilog,
+                &create_canon_producer,
+                canon_result,
+                &self.peer_id,
+                exec_ctx,
+                trace_ctx,
+            ),
+            MergerCanonResult::Empty => {
+                handle_unseen_canon(epilog, &create_canon_producer, &self.peer_id, exec_ctx, trace_ctx)
+            }
+        }
+    }
+}
+
+/// Creates a canon stream like canon does, but with values sorted by their JSON representation,
+/// values equal by it keep the stream order. Peers that meet the canon result later take values
+/// from data in the stored order, so they see the same sorted stream.
+fn create_sorted_canon_stream_producer<'closure, 'name: 'closure>(
+    stream_name: &'name str,
+    position: AirPos,
+) -> Box<CreateCanonStreamClosure<'closure>> {
+    Box::new(move |exec_ctx: &mut ExecutionCtx<'_>, peer_pk: String| -> CanonStream {
+        let mut values = exec_ctx
+            .streams
+            .get(stream_name, position)
+            .map(|stream| stream.iter().cloned().collect::<Vec<_>>())
+            .unwrap_or_default();
+
+        // malformed values go first, they fail execution once they're accessed
+        values.sort_by_cached_key(|value| value.get_result().ok().map(ToString::to_string));
+        CanonStream::from_values(values, peer_pk)
+    })
+}
@@ -82,7 +82,7 @@ fn epilog_closure<'closure, 'name: 'closure>(scalar_name: &'name str) -> Box<Can
                 .into_values()
                 .first()
                 .ok_or(UncatchableError::CanonStreamMapError(NoDataToProduceScalar))?
-                .get_result()
+                .get_result()?
                 .clone();
 
             let position = trace_ctx.trace_pos().map_err(UncatchableError::from)?;
@@ -31,3 +31,20 @@ pub(crate) fn are_matchable_eq<'ctx>(
 
     Ok(left_value == right_value)
 }
+
+/// Matches a value against a regex pattern, strings are matched by their content,
+/// other values by their JSON representation.
+#[tracing::instrument(skip_all)]
+pub(crate) fn is_matchable_regex_match<'ctx>(
+    value: &ast::ImmutableValue<'_>,
+    pattern: &ast::RegexPattern<'_>,
+    exec_ctx: &'ctx ExecutionCtx<'_>,
+) -> ExecutionResult<bool> {
+    let (value, _, _) = value.resolve(exec_ctx)?;
+
+    let is_match = match value.as_str() {
+        Some(value) => pattern.is_match(value),
+        None => pattern.is_match(&value.to_string()),
+    };
+    Ok(is_match)
+}
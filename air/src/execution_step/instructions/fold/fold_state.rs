@@ -15,7 +15,9 @@
  */
 
 use super::Instruction;
+use super::IterableItem;
 use super::IterableValue;
+use super::ValueAggregate;
 
 use std::rc::Rc;
 
@@ -31,6 +33,8 @@ pub(crate) struct FoldState<'i> {
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) enum IterableType {
     Scalar,
+    /// Key-value pairs of a scalar object, it's iterated like a scalar.
+    Map,
     Stream(u32),
 }
 
@@ -50,3 +54,182 @@ impl<'i> FoldState<'i> {
         }
     }
 }
+
+impl<'i> FoldState<'i> {
+    /// Returns an iterator over values this fold is going to visit, starting from the current one.
+    /// The fold cursor isn't moved.
+    // There is no mutable variant: items are produced from values shared with the rest of
+    // the context, and an iterator moving the cursor would get it out of sync with the trace.
+    pub(crate) fn iter(&self) -> FoldStateIter<'_> {
+        FoldStateIter::new(&self.iterable, self.is_exhausted())
+    }
+
+    // a fold over stream is exhausted once it starts iterating back,
+    // while a fold over scalar is bounded only by its iterable
+    fn is_exhausted(&self) -> bool {
+        matches!(self.iterable_type, IterableType::Stream(_)) && self.back_iteration_started
+    }
+}
+
+impl<'i> FoldState<'i> {
+    /// Returns index of the value the fold cursor points to.
+    pub(crate) fn position(&self) -> usize {
+        self.iterable.position()
+    }
+}
+
+pub(crate) struct FoldStateIter<'state> {
+    iterable: &'state IterableValue,
+    offset: usize,
+    exhausted: bool,
+}
+
+impl<'state> FoldStateIter<'state> {
+    fn new(iterable: &'state IterableValue, exhausted: bool) -> Self {
+        Self {
+            iterable,
+            offset: 0,
+            exhausted,
+        }
+    }
+}
+
+impl<'state> Iterator for FoldStateIter<'state> {
+    type Item = IterableItem<'state>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+
+        let item = self.iterable.peek_nth(self.offset);
+        self.offset += 1;
+        self.exhausted = item.is_none();
+        item
+    }
+}
+
+impl<'state, 'i> IntoIterator for &'state FoldState<'i> {
+    type Item = IterableItem<'state>;
+    type IntoIter = FoldStateIter<'state>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Consuming iterator over values left in a fold.
+pub(crate) struct FoldStateIntoIter {
+    iterable: IterableValue,
+    offset: usize,
+    exhausted: bool,
+}
+
+impl Iterator for FoldStateIntoIter {
+    type Item = ValueAggregate;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.exhausted {
+            return None;
+        }
+
+        let value = self
+            .iterable
+            .peek_nth(self.offset)
+            .map(IterableItem::into_resolved_result);
+        self.offset += 1;
+        self.exhausted = value.is_none();
+        value
+    }
+}
+
+impl<'i> IntoIterator for FoldState<'i> {
+    type Item = ValueAggregate;
+    type IntoIter = FoldStateIntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let exhausted = self.is_exhausted();
+
+        FoldStateIntoIter {
+            iterable: self.iterable,
+            offset: 0,
+            exhausted,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FoldState;
+    use super::IterableType;
+    use crate::execution_step::value_types::IterableVecResolvedCall;
+    use crate::execution_step::ServiceResultAggregate;
+    use crate::execution_step::ValueAggregate;
+    use crate::JValue;
+
+    use air_interpreter_cid::CID;
+    use air_parser::ast::Instruction;
+    use air_parser::ast::Null;
+    use serde_json::json;
+
+    use std::rc::Rc;
+
+    fn create_value(value: impl Into<JValue>) -> ValueAggregate {
+        ValueAggregate::from_service_result(
+            ServiceResultAggregate::new(value.into(), <_>::default(), 1.into()),
+            CID::new("some fake cid"),
+        )
+    }
+
+    fn create_fold_state(values: Vec<ValueAggregate>, iterable_type: IterableType) -> FoldState<'static> {
+        let iterable = Box::new(IterableVecResolvedCall::init(values));
+        FoldState::from_iterable(iterable, iterable_type, Rc::new(Instruction::Null(Null)), None)
+    }
+
+    #[test]
+    fn iter_doesnt_move_cursor() {
+        let values = vec![create_value(json!(1)), create_value(json!(2)), create_value(json!(3))];
+        let mut fold_state = create_fold_state(values.clone(), IterableType::Scalar);
+
+        let visited: Vec<_> = fold_state.iter().map(|item| item.into_resolved_result()).collect();
+        assert_eq!(visited, values);
+
+        fold_state.iterable.next();
+        let visited: Vec<_> = fold_state.iter().map(|item| item.into_resolved_result()).collect();
+        assert_eq!(visited, values[1..]);
+        assert_eq!(fold_state.iterable.peek().unwrap().into_resolved_result(), values[1]);
+    }
+
+    #[test]
+    fn position_follows_cursor() {
+        let values = vec![create_value(json!(1)), create_value(json!(2)), create_value(json!(3))];
+        let mut fold_state = create_fold_state(values, IterableType::Scalar);
+        assert_eq!(fold_state.position(), 0);
+
+        fold_state.iterable.next();
+        fold_state.iterable.next();
+        assert_eq!(fold_state.position(), 2);
+
+        fold_state.iterable.prev();
+        assert_eq!(fold_state.position(), 1);
+    }
+
+    #[test]
+    fn into_iter_over_exhausted_stream_fold() {
+        let values = vec![create_value(json!(1)), create_value(json!(2))];
+
+        let fold_state = create_fold_state(values.clone(), IterableType::Stream(0));
+        assert_eq!(fold_state.into_iter().collect::<Vec<_>>(), values);
+
+        let mut fold_state = create_fold_state(values, IterableType::Stream(0));
+        fold_state.back_iteration_started = true;
+        assert_eq!(fold_state.iter().count(), 0);
+        assert_eq!(fold_state.into_iter().next(), None);
+    }
+
+    #[test]
+    fn iter_over_empty_fold() {
+        let fold_state = create_fold_state(vec![], IterableType::Scalar);
+        assert_eq!(fold_state.iter().next(), None);
+    }
+}
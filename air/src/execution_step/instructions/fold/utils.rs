@@ -18,12 +18,14 @@ use super::*;
 use crate::execution_step::value_types::populate_tetraplet_with_lambda;
 use crate::execution_step::value_types::IterableValue;
 use crate::execution_step::CatchableError;
+use crate::execution_step::RcSecurityTetraplet;
 use crate::execution_step::PEEK_ALLOWED_ON_NON_EMPTY;
 use crate::JValue;
 use crate::LambdaAST;
 use crate::SecurityTetraplet;
 
 use air_interpreter_data::Provenance;
+use air_interpreter_data::TracePos;
 use air_parser::ast;
 
 use std::ops::Deref;
@@ -34,6 +36,8 @@ use std::rc::Rc;
 pub(crate) enum FoldIterableScalar {
     Empty,
     ScalarBased(IterableValue),
+    /// Key-value pairs of an object.
+    MapBased(IterableValue),
 }
 
 /// Creates iterable value for given scalar iterable.
@@ -62,7 +66,7 @@ pub(crate) fn create_scalar_wl_iterable<'ctx>(
 
     match exec_ctx.scalars.get_value(scalar_name)? {
         ScalarRef::Value(variable) => {
-            let jvalues = select_by_lambda_from_scalar(variable.get_result(), lambda, exec_ctx)?;
+            let jvalues = select_by_lambda_from_scalar(variable.get_result()?, lambda, exec_ctx)?;
             let tetraplet = variable.get_tetraplet().deref().clone();
             from_jvalue(&jvalues, tetraplet, variable.get_provenance(), lambda)
         }
@@ -136,20 +140,25 @@ pub(crate) fn create_canon_stream_map_wl_iterable_value(
 
 /// Constructs iterable value from resolved call result.
 fn from_value(call_result: ValueAggregate, variable_name: &str) -> ExecutionResult<FoldIterableScalar> {
-    let len = match call_result.get_result() {
+    let values = match call_result.get_result()? {
         JValue::Array(array) => {
             if array.is_empty() {
                 // skip fold if array is empty
                 return Ok(FoldIterableScalar::Empty);
             }
-            array.len()
+            array.clone()
+        }
+        JValue::Object(map) => {
+            let tetraplet = call_result.get_tetraplet();
+            let trace_pos = call_result.get_trace_pos();
+            return Ok(from_map(map, tetraplet, trace_pos, call_result.get_provenance()));
         }
         v => {
             return Err(CatchableError::FoldIteratesOverNonArray((*v).clone(), variable_name.to_string()).into());
         }
     };
 
-    let foldable = IterableResolvedCall::init(call_result, len);
+    let foldable = IterableResolvedCall::init(call_result, values);
     let foldable = Box::new(foldable);
     let iterable = FoldIterableScalar::ScalarBased(foldable);
 
@@ -168,6 +177,7 @@ fn from_jvalue(
 
     let iterable = match jvalue {
         JValue::Array(array) => array,
+        JValue::Object(map) => return Ok(from_map(map, tetraplet, 0.into(), provenance)),
         _ => {
             return Err(CatchableError::FoldIteratesOverNonArray(jvalue.clone(), lambda.to_string()).into());
         }
@@ -183,24 +193,37 @@ fn from_jvalue(
     Ok(iterable)
 }
 
+/// Constructs iterable value over key-value pairs of an object.
+fn from_map(
+    map: &air_interpreter_value::Map<air_interpreter_value::JsonString, JValue>,
+    tetraplet: RcSecurityTetraplet,
+    trace_pos: TracePos,
+    provenance: Provenance,
+) -> FoldIterableScalar {
+    if map.is_empty() {
+        return FoldIterableScalar::Empty;
+    }
+
+    let foldable = IterableMapEntries::init(map, tetraplet, trace_pos, provenance);
+    FoldIterableScalar::MapBased(Box::new(foldable))
+}
+
 fn to_tetraplet(iterable: &IterableItem<'_>) -> SecurityTetraplet {
     use IterableItem::*;
 
     let tetraplet = match iterable {
-        RefValue((_, tetraplet, _, _)) => tetraplet,
-        RcValue((_, tetraplet, _, _)) => tetraplet,
+        RefValue((_, tetraplet, _, _)) => tetraplet.clone(),
+        AggregateRef(value) => value.get_tetraplet(),
     };
 
-    (*tetraplet).deref().clone()
+    tetraplet.deref().clone()
 }
 
 fn to_provenance(iterable: &IterableItem<'_>) -> Provenance {
     use IterableItem::*;
 
-    let provenance = match iterable {
-        RefValue((_, _, _, provenance)) => provenance,
-        RcValue((_, _, _, provenance)) => provenance,
-    };
-
-    provenance.clone()
+    match iterable {
+        RefValue((_, _, _, provenance)) => provenance.clone(),
+        AggregateRef(value) => value.get_provenance(),
+    }
 }
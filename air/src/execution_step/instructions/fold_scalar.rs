@@ -70,6 +70,15 @@ impl<'i> ExecutableInstruction<'i> for FoldScalar<'i> {
                 exec_ctx,
                 trace_ctx,
             ),
+            FoldIterableScalar::MapBased(iterable) => fold(
+                iterable,
+                IterableType::Map,
+                self.iterator.name,
+                self.instruction.clone(),
+                self.last_instruction.clone(),
+                exec_ctx,
+                trace_ctx,
+            ),
         }
     }
 }
@@ -87,10 +96,13 @@ pub(super) fn fold<'i>(
     exec_ctx.scalars.meet_fold_start();
     exec_ctx.scalars.set_iterable_value(iterator, fold_state)?;
 
+    // exceeding the depth is uncatchable and stops execution, so the iterable needs no cleanup
+    exec_ctx.enter_fold()?;
     let result = instruction.execute(exec_ctx, trace_ctx);
 
     exec_ctx.scalars.remove_iterable_value(iterator);
     exec_ctx.scalars.meet_fold_end();
+    exec_ctx.exit_fold();
 
     result
 }
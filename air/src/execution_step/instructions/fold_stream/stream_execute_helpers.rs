@@ -139,7 +139,7 @@ fn execute_iterations<'i>(
 pub(super) fn throw_error_if_not_catchable(result: ExecutionResult<()>) -> ExecutionResult<()> {
     match result {
         Ok(_) => Ok(()),
-        Err(error) if error.is_catchable() => Ok(()),
+        Err(error) if error.is_recoverable() => Ok(()),
         error @ Err(_) => error,
     }
 }
@@ -0,0 +1,160 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Spans of dispatched instructions. Their fields follow OpenTelemetry naming conventions,
+//! so a host subscriber with a `tracing-opentelemetry` layer exports them as nested spans
+//! showing a flame graph of AIR execution.
+
+use crate::execution_step::ExecutionResult;
+use crate::execution_step::TraceHandler;
+
+use air_interpreter_data::CallResult;
+use air_interpreter_data::CanonResult;
+use air_interpreter_data::ExecutedState;
+use air_interpreter_data::ValueRef;
+use air_parser::ast::Instruction;
+use tracing::field::Empty;
+use tracing::span::EnteredSpan;
+
+pub(super) struct InstructionSpan {
+    span: EnteredSpan,
+    trace_len: usize,
+}
+
+impl InstructionSpan {
+    /// Creates and enters a span of the instruction, spans of nested instructions become its children.
+    pub(super) fn enter(instruction: &Instruction<'_>, trace_ctx: &TraceHandler) -> Self {
+        let span = instruction_span(instruction).entered();
+        let trace_len = trace_ctx.as_result_trace().len();
+
+        Self { span, trace_len }
+    }
+
+    /// Records an error and a CID of a produced state, then closes the span.
+    pub(super) fn complete(self, result: &ExecutionResult<()>, trace_ctx: &TraceHandler) {
+        if let Err(error) = result {
+            self.span.record("air.error", tracing::field::display(error));
+        }
+
+        let trace: &[ExecutedState] = trace_ctx.as_result_trace();
+        if trace.len() == self.trace_len + 1 {
+            if let Some(cid) = trace.last().and_then(produced_cid) {
+                self.span.record("air.cid", cid);
+            }
+        }
+    }
+}
+
+fn instruction_span(instruction: &Instruction<'_>) -> tracing::Span {
+    use tracing::info_span;
+
+    match instruction {
+        Instruction::Call(call) => info_span!(
+            "call",
+            air.peer_id = %call.triplet.peer_id,
+            air.service_id = %call.triplet.service_id,
+            air.function_name = %call.triplet.function_name,
+            air.output = %call.output,
+            air.cid = Empty,
+            air.error = Empty,
+        ),
+        Instruction::Canon(canon) => info_span!(
+            "canon",
+            air.peer_id = %canon.peer_id,
+            air.stream = %canon.stream,
+            air.output = %canon.canon_stream,
+            air.cid = Empty,
+            air.error = Empty,
+        ),
+        Instruction::CanonMap(canon) => info_span!(
+            "canon",
+            air.peer_id = %canon.peer_id,
+            air.stream = %canon.stream_map,
+            air.output = %canon.canon_stream_map,
+            air.cid = Empty,
+            air.error = Empty,
+        ),
+        Instruction::CanonStreamMapScalar(canon) => info_span!(
+            "canon",
+            air.peer_id = %canon.peer_id,
+            air.stream = %canon.stream_map,
+            air.output = %canon.scalar,
+            air.cid = Empty,
+            air.error = Empty,
+        ),
+        Instruction::CanonSorted(canon) => info_span!(
+            "canon-sorted",
+            air.peer_id = %canon.peer_id,
+            air.stream = %canon.stream,
+            air.output = %canon.canon_stream,
+            air.cid = Empty,
+            air.error = Empty,
+        ),
+        Instruction::Ap(ap) => info_span!("ap", air.output = %ap.result, air.cid = Empty, air.error = Empty),
+        Instruction::ApMap(ap) => info_span!("ap", air.output = %ap.map, air.cid = Empty, air.error = Empty),
+        Instruction::Fail(_) => info_span!("fail", air.cid = Empty, air.error = Empty),
+        Instruction::FoldScalar(fold) => info_span!(
+            "fold",
+            air.iterable = %fold.iterable,
+            air.iterator = %fold.iterator,
+            air.cid = Empty,
+            air.error = Empty,
+        ),
+        Instruction::FoldStream(fold) => info_span!(
+            "fold",
+            air.iterable = %fold.iterable,
+            air.iterator = %fold.iterator,
+            air.cid = Empty,
+            air.error = Empty,
+        ),
+        Instruction::FoldStreamMap(fold) => info_span!(
+            "fold",
+            air.iterable = %fold.iterable,
+            air.iterator = %fold.iterator,
+            air.cid = Empty,
+            air.error = Empty,
+        ),
+        Instruction::Never(_) => info_span!("never", air.cid = Empty, air.error = Empty),
+        Instruction::New(new) => info_span!("new", air.variable = %new.argument, air.cid = Empty, air.error = Empty),
+        Instruction::Next(next) => {
+            info_span!("next", air.iterator = %next.iterator, air.cid = Empty, air.error = Empty)
+        }
+        Instruction::Null(_) => info_span!("null", air.cid = Empty, air.error = Empty),
+        Instruction::Par(_) => info_span!("par", air.cid = Empty, air.error = Empty),
+        Instruction::Seq(_) => info_span!("seq", air.cid = Empty, air.error = Empty),
+        Instruction::Xor(_) => info_span!("xor", air.cid = Empty, air.error = Empty),
+        Instruction::Match(_) => info_span!("match", air.cid = Empty, air.error = Empty),
+        Instruction::MisMatch(_) => info_span!("mismatch", air.cid = Empty, air.error = Empty),
+        Instruction::MatchRegex(match_regex) => {
+            info_span!("match-regex", air.pattern = %match_regex.pattern, air.cid = Empty, air.error = Empty)
+        }
+        Instruction::MisMatchRegex(mismatch_regex) => {
+            info_span!("mismatch-regex", air.pattern = %mismatch_regex.pattern, air.cid = Empty, air.error = Empty)
+        }
+        Instruction::Error => info_span!("error", air.cid = Empty, air.error = Empty),
+    }
+}
+
+fn produced_cid(state: &ExecutedState) -> Option<&str> {
+    match state {
+        ExecutedState::Call(CallResult::Executed(ValueRef::Scalar(cid)))
+        | ExecutedState::Call(CallResult::Executed(ValueRef::Stream { cid, .. }))
+        | ExecutedState::Call(CallResult::Failed(cid)) => Some(cid.as_ref()),
+        ExecutedState::Call(CallResult::Executed(ValueRef::Unused(cid))) => Some(cid.as_ref()),
+        ExecutedState::Canon(CanonResult::Executed(cid)) => Some(cid.as_ref()),
+        _ => None,
+    }
+}
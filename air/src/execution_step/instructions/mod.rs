@@ -19,6 +19,7 @@ mod ap_map;
 mod call;
 mod canon;
 mod canon_map;
+mod canon_sorted;
 mod canon_stream_map_scalar;
 mod canon_utils;
 mod compare_matchable;
@@ -27,8 +28,12 @@ mod fold;
 mod fold_scalar;
 mod fold_stream;
 mod fold_stream_map;
+#[cfg(feature = "opentelemetry")]
+mod instruction_span;
 mod match_;
+mod match_regex;
 mod mismatch;
+mod mismatch_regex;
 mod never;
 mod new;
 mod next;
@@ -68,34 +73,74 @@ pub(crate) trait ExecutableInstruction<'i> {
 
 impl<'i> ExecutableInstruction<'i> for Instruction<'i> {
     fn execute(&self, exec_ctx: &mut ExecutionCtx<'i>, trace_ctx: &mut TraceHandler) -> ExecutionResult<()> {
-        match self {
-            // call isn't wrapped by the execute macro because
-            // it internally maps some Catchables into %last_error%/:error: using resolved triplet.
-            // Both canons and call set :error:.$.peer_id whilst other instructions do not.
-            Instruction::Call(call) => call.execute(exec_ctx, trace_ctx),
-
-            Instruction::Canon(canon) => execute!(self, canon, exec_ctx, trace_ctx),
-            Instruction::CanonMap(canon_map) => execute!(self, canon_map, exec_ctx, trace_ctx),
-            Instruction::CanonStreamMapScalar(canon) => execute!(self, canon, exec_ctx, trace_ctx),
-            Instruction::Ap(ap) => execute!(self, ap, exec_ctx, trace_ctx),
-            Instruction::ApMap(ap_map) => execute!(self, ap_map, exec_ctx, trace_ctx),
-            Instruction::Fail(fail) => execute!(self, fail, exec_ctx, trace_ctx),
-            Instruction::FoldScalar(fold) => execute!(self, fold, exec_ctx, trace_ctx),
-            Instruction::FoldStream(fold) => execute!(self, fold, exec_ctx, trace_ctx),
-            Instruction::FoldStreamMap(fold) => execute!(self, fold, exec_ctx, trace_ctx),
-            Instruction::Never(never) => execute!(self, never, exec_ctx, trace_ctx),
-            Instruction::New(new) => execute!(self, new, exec_ctx, trace_ctx),
-            Instruction::Next(next) => execute!(self, next, exec_ctx, trace_ctx),
-            Instruction::Null(null) => execute!(self, null, exec_ctx, trace_ctx),
-            Instruction::Par(par) => execute!(self, par, exec_ctx, trace_ctx),
-            Instruction::Seq(seq) => execute!(self, seq, exec_ctx, trace_ctx),
-            Instruction::Xor(xor) => execute!(self, xor, exec_ctx, trace_ctx),
-            Instruction::Match(match_) => execute!(self, match_, exec_ctx, trace_ctx),
-            Instruction::MisMatch(mismatch) => execute!(self, mismatch, exec_ctx, trace_ctx),
-
-            Instruction::Error => unreachable!("should not execute if parsing succeeded. QED."),
+        let result = dispatch(self, exec_ctx, trace_ctx);
+        if let Err(error) = &result {
+            exec_ctx.record_error_origin(self, error);
         }
+
+        result
+    }
+}
+
+fn dispatch<'i>(
+    instruction: &Instruction<'i>,
+    exec_ctx: &mut ExecutionCtx<'i>,
+    trace_ctx: &mut TraceHandler,
+) -> ExecutionResult<()> {
+    exec_ctx.check_cancellation()?;
+    exec_ctx.check_execution_time()?;
+    exec_ctx.consume_fuel()?;
+    #[cfg(feature = "metrics")]
+    exec_ctx.count_instruction(instruction);
+    #[cfg(feature = "opentelemetry")]
+    let span = instruction_span::InstructionSpan::enter(instruction, trace_ctx);
+    exec_ctx.record_pause(instruction);
+    exec_ctx.notify_observer(|observer, ctx| observer.on_instruction_start(instruction, ctx));
+    let replay_position = exec_ctx.record_replay_dispatch(instruction);
+    #[cfg(feature = "flamegraph")]
+    trace_ctx.enter_instruction(instruction.to_string());
+
+    let result = match instruction {
+        // call isn't wrapped by the execute macro because
+        // it internally maps some Catchables into %last_error%/:error: using resolved triplet.
+        // Both canons and call set :error:.$.peer_id whilst other instructions do not.
+        Instruction::Call(call) => call.execute(exec_ctx, trace_ctx),
+
+        Instruction::Canon(canon) => execute!(instruction, canon, exec_ctx, trace_ctx),
+        Instruction::CanonMap(canon_map) => execute!(instruction, canon_map, exec_ctx, trace_ctx),
+        Instruction::CanonStreamMapScalar(canon) => execute!(instruction, canon, exec_ctx, trace_ctx),
+        Instruction::CanonSorted(canon) => execute!(instruction, canon, exec_ctx, trace_ctx),
+        Instruction::Ap(ap) => execute!(instruction, ap, exec_ctx, trace_ctx),
+        Instruction::ApMap(ap_map) => execute!(instruction, ap_map, exec_ctx, trace_ctx),
+        Instruction::Fail(fail) => execute!(instruction, fail, exec_ctx, trace_ctx),
+        Instruction::FoldScalar(fold) => execute!(instruction, fold, exec_ctx, trace_ctx),
+        Instruction::FoldStream(fold) => execute!(instruction, fold, exec_ctx, trace_ctx),
+        Instruction::FoldStreamMap(fold) => execute!(instruction, fold, exec_ctx, trace_ctx),
+        Instruction::Never(never) => execute!(instruction, never, exec_ctx, trace_ctx),
+        Instruction::New(new) => execute!(instruction, new, exec_ctx, trace_ctx),
+        Instruction::Next(next) => execute!(instruction, next, exec_ctx, trace_ctx),
+        Instruction::Null(null) => execute!(instruction, null, exec_ctx, trace_ctx),
+        Instruction::Par(par) => execute!(instruction, par, exec_ctx, trace_ctx),
+        Instruction::Seq(seq) => execute!(instruction, seq, exec_ctx, trace_ctx),
+        Instruction::Xor(xor) => execute!(instruction, xor, exec_ctx, trace_ctx),
+        Instruction::Match(match_) => execute!(instruction, match_, exec_ctx, trace_ctx),
+        Instruction::MisMatch(mismatch) => execute!(instruction, mismatch, exec_ctx, trace_ctx),
+        Instruction::MatchRegex(match_regex) => execute!(instruction, match_regex, exec_ctx, trace_ctx),
+        Instruction::MisMatchRegex(mismatch_regex) => execute!(instruction, mismatch_regex, exec_ctx, trace_ctx),
+
+        Instruction::Error => unreachable!("should not execute if parsing succeeded. QED."),
+    };
+
+    if let Some(position) = replay_position {
+        exec_ctx.record_replay_completion(position);
     }
+    #[cfg(feature = "opentelemetry")]
+    span.complete(&result, trace_ctx);
+    #[cfg(feature = "flamegraph")]
+    trace_ctx.exit_instruction();
+    exec_ctx.notify_observer(|observer, ctx| observer.on_instruction_end(instruction, result.as_ref().err(), ctx));
+
+    result
 }
 
 #[macro_export]
@@ -109,12 +154,8 @@ macro_rules! log_instruction {
 
         log::trace!(
             target: air_log_targets::DATA_CACHE,
-            "  scalars:
-    {}
-  streams:
-    {}",
-            $exec_ctx.scalars,
-            $exec_ctx.streams
+            "{}",
+            $exec_ctx.immutable_view()
         );
         log::trace!(
             target: air_log_targets::NEXT_PEER_PKS,
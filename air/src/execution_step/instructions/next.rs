@@ -21,6 +21,7 @@ use super::FoldState;
 use super::TraceHandler;
 use crate::execution_step::PEEK_ALLOWED_ON_NON_EMPTY;
 use crate::log_instruction;
+use crate::observer::FoldProgress;
 use crate::trace_to_exec_err;
 
 use air_parser::ast::Next;
@@ -31,7 +32,11 @@ impl<'i> super::ExecutableInstruction<'i> for Next<'i> {
 
         let iterator_name = &self.iterator.name;
         let fold_state = exec_ctx.scalars.get_iterable_mut(iterator_name)?;
+        let progress = fold_progress(iterator_name, fold_state);
         maybe_meet_iteration_end(self, fold_state, trace_ctx)?;
+        exec_ctx.notify_observer(|observer, ctx| observer.on_fold_iteration(progress, ctx));
+
+        let fold_state = exec_ctx.scalars.get_iterable_mut(iterator_name)?;
 
         // TODO: refactor a body of this if to reduce LOCs count and improve readability
         if !fold_state.iterable.next() {
@@ -60,10 +65,12 @@ impl<'i> super::ExecutableInstruction<'i> for Next<'i> {
 
         let next_instr = fold_state.instr_head.clone();
         maybe_meet_iteration_start(self, fold_state, trace_ctx)?;
+        exec_ctx.enter_fold()?;
         exec_ctx.scalars.meet_next_before();
 
         let result = next_instr.execute(exec_ctx, trace_ctx);
         exec_ctx.scalars.meet_next_after();
+        exec_ctx.exit_fold();
         result?;
 
         // get the same fold state again because of borrow checker
@@ -75,6 +82,19 @@ impl<'i> super::ExecutableInstruction<'i> for Next<'i> {
     }
 }
 
+fn fold_progress(iterator_name: &str, fold_state: &FoldState<'_>) -> FoldProgress {
+    let total = match fold_state.iterable_type {
+        IterableType::Scalar | IterableType::Map => Some(fold_state.iterable.len()),
+        IterableType::Stream(_) => None,
+    };
+
+    FoldProgress {
+        iterator_name: iterator_name.to_string(),
+        current_index: fold_state.position(),
+        total,
+    }
+}
+
 fn maybe_meet_iteration_start<'i>(
     next: &Next<'i>,
     fold_state: &FoldState<'i>,
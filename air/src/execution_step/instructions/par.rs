@@ -68,7 +68,7 @@ fn execute_subgraph<'i>(
             trace_to_exec_err!(trace_ctx.meet_par_subgraph_end(subgraph_type), par)?;
             SubgraphResult::Succeeded
         }
-        Err(e) if e.is_catchable() => {
+        Err(e) if e.is_recoverable() => {
             exec_ctx.make_subgraph_incomplete();
             trace_to_exec_err!(trace_ctx.meet_par_subgraph_end(subgraph_type), par)?;
             SubgraphResult::Failed(e)
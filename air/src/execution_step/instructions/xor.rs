@@ -28,8 +28,9 @@ impl<'i> super::ExecutableInstruction<'i> for Xor<'i> {
 
         exec_ctx.flush_subgraph_completeness();
         match self.0.execute(exec_ctx, trace_ctx) {
-            Err(e) if e.is_catchable() => {
+            Err(e) if e.is_recoverable() => {
                 print_xor_log(&e);
+                exec_ctx.forget_error_origin();
 
                 exec_ctx.flush_subgraph_completeness();
                 exec_ctx.last_error_descriptor.meet_xor_right_branch();
@@ -22,6 +22,7 @@ use crate::execution_step::CatchableError;
 use crate::execution_step::ExecutionCtx;
 use crate::execution_step::ExecutionResult;
 use crate::execution_step::RcSecurityTetraplet;
+use crate::execution_step::UncatchableError;
 use crate::lambda_to_execution_error;
 use crate::ExecutionError;
 use crate::JValue;
@@ -145,7 +146,7 @@ fn select_by_path_from_canon_map(
         ValueAccessor::FieldAccessByName { field_name } => JsonString::from(*field_name).to_owned().into(),
         ValueAccessor::FieldAccessByScalar { scalar_name } => {
             let scalar = exec_ctx.scalars.get_value(scalar_name)?;
-            lambda_to_execution_error!(try_scalar_ref_as_stream_map_key(scalar))?
+            try_scalar_ref_as_stream_map_key(scalar)?
         }
         ValueAccessor::Error => unreachable!("should not execute if parsing succeeded. QED."),
     };
@@ -156,14 +157,17 @@ fn select_by_path_from_canon_map(
         (Ok(body_part), Some(canon_stream)) => {
             // csm.$.key... case
 
-            let canon_stream_iter = canon_stream.iter().map(|v| (v.get_result().clone(), v.get_tetraplet()));
-            select_by_path_from_canon_map_stream(canon_stream_iter, &body_part, exec_ctx)?
+            let values = canon_stream
+                .iter()
+                .map(|v| Ok((v.get_result()?.clone(), v.get_tetraplet())))
+                .collect::<Result<Vec<_>, UncatchableError>>()?;
+            select_by_path_from_canon_map_stream(values.into_iter(), &body_part, exec_ctx)?
         }
         (Err(..), Some(canon_stream)) => {
             // csm.$.key case
             let prefix_with_path = false;
             let tetraplet = update_tetraplet_with_path(canon_map.tetraplet(), original_lambda, prefix_with_path);
-            let value = canon_stream.as_jvalue();
+            let value = canon_stream.as_jvalue()?;
 
             MapLensResult::new(value, tetraplet)
         }
@@ -171,7 +175,7 @@ fn select_by_path_from_canon_map(
             // csm.$.non_existing_key case
             let prefix_with_path = false;
             let tetraplet = update_tetraplet_with_path(canon_map.tetraplet(), original_lambda, prefix_with_path);
-            let value = CanonStream::new(vec![], tetraplet.clone()).as_jvalue();
+            let value = CanonStream::new(vec![], tetraplet.clone()).as_jvalue()?;
 
             MapLensResult::new(value, tetraplet)
         }
@@ -193,7 +197,7 @@ fn split_to_idx<'lambda>(
         }
         ValueAccessor::FieldAccessByScalar { scalar_name } => {
             let scalar = exec_ctx.scalars.get_value(scalar_name)?;
-            lambda_to_execution_error!(try_scalar_ref_as_idx(scalar))?
+            try_scalar_ref_as_idx(scalar)?
         }
         ValueAccessor::Error => unreachable!("should not execute if parsing succeeded. QED."),
     };
@@ -259,7 +263,7 @@ fn select_by_path_from_scalar<'value, 'accessor>(
             }
             ValueAccessor::FieldAccessByScalar { scalar_name } => {
                 let scalar = exec_ctx.scalars.get_value(scalar_name)?;
-                value = lambda_to_execution_error!(select_by_scalar(value, scalar))?;
+                value = select_by_scalar(value, scalar)?;
             }
             ValueAccessor::Error => unreachable!("should not execute if parsing succeeded. QED."),
         }
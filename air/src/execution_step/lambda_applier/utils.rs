@@ -17,8 +17,10 @@
 use super::LambdaError;
 use super::LambdaResult;
 use crate::execution_step::execution_context::stream_map_key::StreamMapKey;
+use crate::execution_step::ExecutionResult;
 use crate::execution_step::ScalarRef;
 use crate::execution_step::PEEK_ALLOWED_ON_NON_EMPTY;
+use crate::lambda_to_execution_error;
 use crate::JValue;
 
 pub(super) fn try_jvalue_with_idx(jvalue: &JValue, idx: u32) -> LambdaResult<&JValue> {
@@ -57,48 +59,49 @@ pub(super) fn try_jvalue_with_field_name<'value>(
 pub(super) fn select_by_scalar<'value>(
     value: &'value JValue,
     scalar_ref: ScalarRef<'_>,
-) -> LambdaResult<&'value JValue> {
+) -> ExecutionResult<&'value JValue> {
     use ScalarRef::*;
 
     match scalar_ref {
-        Value(lambda_value) => select_by_jvalue(value, lambda_value.get_result()),
+        Value(lambda_value) => lambda_to_execution_error!(select_by_jvalue(value, lambda_value.get_result()?)),
         IterableValue(fold_state) => {
             let accessor = fold_state
                 .iterable
                 .peek()
                 .expect(PEEK_ALLOWED_ON_NON_EMPTY)
                 .into_resolved_result();
-            select_by_jvalue(value, accessor.get_result())
+            lambda_to_execution_error!(select_by_jvalue(value, accessor.get_result()?))
         }
     }
 }
 
-pub(super) fn try_scalar_ref_as_idx(scalar: ScalarRef<'_>) -> LambdaResult<u32> {
+pub(super) fn try_scalar_ref_as_idx(scalar: ScalarRef<'_>) -> ExecutionResult<u32> {
     match scalar {
-        ScalarRef::Value(accessor) => try_jvalue_as_idx(accessor.get_result()),
+        ScalarRef::Value(accessor) => lambda_to_execution_error!(try_jvalue_as_idx(accessor.get_result()?)),
         ScalarRef::IterableValue(accessor) => {
             let accessor = accessor
                 .iterable
                 .peek()
                 .expect(PEEK_ALLOWED_ON_NON_EMPTY)
                 .into_resolved_result();
-            try_jvalue_as_idx(accessor.get_result())
+            lambda_to_execution_error!(try_jvalue_as_idx(accessor.get_result()?))
         }
     }
 }
 
-pub(super) fn try_scalar_ref_as_stream_map_key(scalar: ScalarRef<'_>) -> LambdaResult<StreamMapKey> {
+pub(super) fn try_scalar_ref_as_stream_map_key(scalar: ScalarRef<'_>) -> ExecutionResult<StreamMapKey> {
     match scalar {
         ScalarRef::Value(map_accessor) => {
-            let map_accessor = map_accessor.get_result();
-            let map_key = StreamMapKey::from_value_ref(map_accessor).ok_or(
-                LambdaError::CanonStreamMapAccessorHasInvalidType {
+            let map_accessor = map_accessor.get_result()?;
+            let map_key =
+                StreamMapKey::from_value_ref(map_accessor).ok_or(LambdaError::CanonStreamMapAccessorHasInvalidType {
                     map_accessor: map_accessor.clone(),
-                },
-            )?;
-            Ok(map_key)
+                });
+            lambda_to_execution_error!(map_key)
+        }
+        ScalarRef::IterableValue(_map_accessor) => {
+            lambda_to_execution_error!(Err(LambdaError::CanonStreamMapAccessorMustNotBeIterable))
         }
-        ScalarRef::IterableValue(_map_accessor) => Err(LambdaError::CanonStreamMapAccessorMustNotBeIterable),
     }
 }
 
@@ -27,6 +27,7 @@ const PEEK_ALLOWED_ON_NON_EMPTY: &str = "peek always return elements inside fold
 const TETRAPLET_IDX_CORRECT: &str = "selects always return a correct index inside stream";
 
 pub use errors::CatchableError;
+pub use errors::ContextualExecutionError;
 pub use errors::ExecutionError;
 pub use errors::UncatchableError;
 pub use execution_context::ErrorObjectError;
@@ -41,15 +42,18 @@ pub(super) use self::instructions::ExecutableInstruction;
 pub(super) use self::instructions::FoldState;
 pub(crate) use errors::ErrorAffectable;
 pub(crate) use errors::Joinable;
+pub(crate) use execution_context::Cancellation;
 pub(crate) use execution_context::ExecutionCtx;
+pub(crate) use execution_context::ExecutionTimer;
 pub(crate) use execution_context::InstructionError;
 pub(super) use value_types::CanonResultAggregate;
 pub(super) use value_types::Generation;
+pub use value_types::LazyValue;
 pub(super) use value_types::LiteralAggregate;
 pub(super) use value_types::ScalarRef;
 pub(super) use value_types::ServiceResultAggregate;
 pub(super) use value_types::Stream;
-pub(super) use value_types::ValueAggregate;
+pub use value_types::ValueAggregate;
 use value_types::STREAM_MAX_SIZE;
 
 pub(crate) use air_trace_handler::TraceHandler;
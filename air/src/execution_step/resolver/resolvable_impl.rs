@@ -111,7 +111,7 @@ impl Resolvable for ast::Scalar<'_> {
     fn resolve(&self, ctx: &ExecutionCtx<'_>) -> ExecutionResult<(JValue, RcSecurityTetraplets, Provenance)> {
         let (value, provenance) = ctx.scalars.get_value(self.name)?.into_jvaluable();
         let tetraplets = value.as_tetraplets();
-        Ok((value.as_jvalue(), tetraplets, provenance))
+        Ok((value.as_jvalue()?, tetraplets, provenance))
     }
 }
 
@@ -120,7 +120,7 @@ impl Resolvable for ast::CanonStream<'_> {
         let canon = ctx.scalars.get_canon_stream(self.name)?;
         let value: &dyn JValuable = &&canon.canon_stream;
         let tetraplets = value.as_tetraplets();
-        Ok((value.as_jvalue(), tetraplets, Provenance::canon(canon.cid.clone())))
+        Ok((value.as_jvalue()?, tetraplets, Provenance::canon(canon.cid.clone())))
     }
 }
 
@@ -185,7 +185,7 @@ impl Resolvable for ast::CanonStreamMap<'_> {
         let tetraplets = value.as_tetraplets();
         let provenance = Provenance::canon(canon_stream_map_with_prov.cid.clone());
 
-        Ok((value.as_jvalue(), tetraplets, provenance))
+        Ok((value.as_jvalue()?, tetraplets, provenance))
     }
 }
 
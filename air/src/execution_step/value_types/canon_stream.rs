@@ -15,6 +15,7 @@
  */
 
 use super::ValueAggregate;
+use crate::execution_step::UncatchableError;
 use crate::JValue;
 
 use air_interpreter_cid::CID;
@@ -56,9 +57,13 @@ impl CanonStream {
         self.values.is_empty()
     }
 
-    pub(crate) fn as_jvalue(&self) -> JValue {
-        let jvalue_iter = self.values.iter().map(|r| r.get_result().clone());
-        JValue::array_from_iter(jvalue_iter)
+    pub(crate) fn as_jvalue(&self) -> Result<JValue, UncatchableError> {
+        let values = self
+            .values
+            .iter()
+            .map(ValueAggregate::get_result)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(JValue::array_from_iter(values.into_iter().cloned()))
     }
 
     pub(crate) fn iter(&self) -> impl ExactSizeIterator<Item = &ValueAggregate> {
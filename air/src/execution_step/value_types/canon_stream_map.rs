@@ -79,10 +79,13 @@ impl CanonStreamMap {
         self.map.is_empty()
     }
 
-    pub(crate) fn as_jvalue(&self) -> JValue {
-        let json_map: air_interpreter_value::Map<JsonString, JValue> =
-            self.map.iter().map(|(k, v)| (k.to_key(), v.as_jvalue())).collect();
-        json_map.into()
+    pub(crate) fn as_jvalue(&self) -> Result<JValue, UncatchableError> {
+        let json_map = self
+            .map
+            .iter()
+            .map(|(k, v)| Ok((k.to_key(), v.as_jvalue()?)))
+            .collect::<Result<air_interpreter_value::Map<JsonString, JValue>, UncatchableError>>()?;
+        Ok(json_map.into())
     }
 
     pub(crate) fn iter(&self) -> impl ExactSizeIterator<Item = &ValueAggregate> {
@@ -138,7 +141,7 @@ fn get_value_from_obj(value_aggregate: &ValueAggregate) -> ExecutionResult<Value
     let provenance = value_aggregate.get_provenance();
     let trace_pos = value_aggregate.get_trace_pos();
     let object = value_aggregate
-        .get_result()
+        .get_result()?
         .as_object()
         .ok_or(UncatchableError::StreamMapKeyError(NotAnObject))?;
     let value =
@@ -17,16 +17,19 @@
 mod canon_stream;
 mod canon_stream_map;
 mod lambda_result;
+mod map_entries;
 mod resolved_call;
 mod vec_resolved_call;
 
 pub(crate) use canon_stream::CanonStreamIterableIngredients;
 pub(crate) use canon_stream_map::CanonStreamMapIterableIngredients;
 pub(crate) use lambda_result::IterableLambdaResult;
+pub(crate) use map_entries::IterableMapEntries;
 pub(crate) use resolved_call::IterableResolvedCall;
 pub(crate) use vec_resolved_call::IterableVecResolvedCall;
 
 use super::ValueAggregate;
+use crate::execution_step::value_types::TracePosOperate;
 use crate::execution_step::RcSecurityTetraplet;
 use crate::JValue;
 
@@ -47,8 +50,17 @@ pub(crate) trait Iterable<'ctx> {
     /// does nothing and return false otherwise.
     fn prev(&mut self) -> bool;
 
+    /// Returns index of the current value.
+    fn position(&self) -> usize;
+
     /// Return current iterable value if Iterable value is not empty and None otherwise.
-    fn peek(&'ctx self) -> Option<Self::Item>;
+    fn peek(&'ctx self) -> Option<Self::Item> {
+        self.peek_nth(0)
+    }
+
+    /// Return a value located n positions after the current one if it exists and None otherwise,
+    /// the inner iterator isn't moved.
+    fn peek_nth(&'ctx self, n: usize) -> Option<Self::Item>;
 
     /// Returns length of the current iterator.
     fn len(&self) -> usize;
@@ -61,29 +73,27 @@ pub(crate) trait Iterable<'ctx> {
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) enum IterableItem<'ctx> {
     RefValue((&'ctx JValue, RcSecurityTetraplet, TracePos, Provenance)),
-    RcValue((JValue, RcSecurityTetraplet, TracePos, Provenance)),
+    // a stream or canon value, it's parsed only when it's accessed
+    AggregateRef(&'ctx ValueAggregate),
 }
 
 impl IterableItem<'_> {
     pub(crate) fn pos(&self) -> TracePos {
         use IterableItem::*;
 
-        let pos = match self {
-            RefValue((.., pos, _)) => pos,
-            RcValue((.., pos, _)) => pos,
-        };
-
-        *pos
+        match self {
+            RefValue((.., pos, _)) => *pos,
+            AggregateRef(value) => value.get_trace_pos(),
+        }
     }
 
     pub(crate) fn provenance(&self) -> Provenance {
         use IterableItem::*;
 
         match self {
-            RefValue((.., ref prov)) => prov,
-            RcValue((.., ref prov)) => prov,
+            RefValue((.., ref prov)) => prov.clone(),
+            AggregateRef(value) => value.get_provenance(),
         }
-        .clone()
     }
 
     pub(crate) fn into_resolved_result(self) -> ValueAggregate {
@@ -91,7 +101,7 @@ impl IterableItem<'_> {
 
         let (value, tetraplet, pos, provenance) = match self {
             RefValue((value, tetraplet, pos, prov)) => (value.clone(), tetraplet, pos, prov),
-            RcValue(ingredients) => ingredients,
+            AggregateRef(value) => return value.clone(),
         };
 
         ValueAggregate::new(value, tetraplet, pos, provenance)
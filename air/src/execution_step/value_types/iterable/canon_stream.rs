@@ -17,7 +17,6 @@
 use super::Iterable;
 use super::IterableItem;
 use crate::execution_step::value_types::CanonStream;
-use crate::execution_step::value_types::TracePosOperate;
 use crate::foldable_next;
 use crate::foldable_prev;
 
@@ -48,18 +47,18 @@ impl<'ctx> Iterable<'ctx> for CanonStreamIterableIngredients {
         foldable_prev!(self)
     }
 
-    fn peek(&'ctx self) -> Option<Self::Item> {
-        if self.canon_stream.is_empty() {
+    fn position(&self) -> usize {
+        self.cursor
+    }
+
+    fn peek_nth(&'ctx self, n: usize) -> Option<Self::Item> {
+        let position = self.cursor + n;
+        if position >= self.canon_stream.len() {
             return None;
         }
 
-        let value = self.canon_stream.nth(self.cursor).expect(EXPECT_VALUE_IN_STREAM);
-        let result = IterableItem::RefValue((
-            value.get_result(),
-            value.get_tetraplet(),
-            value.get_trace_pos(),
-            value.get_provenance(),
-        ));
+        let value = self.canon_stream.nth(position).expect(EXPECT_VALUE_IN_STREAM);
+        let result = IterableItem::AggregateRef(value);
         Some(result)
     }
 
@@ -16,7 +16,6 @@
 
 use super::Iterable;
 use super::IterableItem;
-use crate::execution_step::value_types::TracePosOperate;
 use crate::execution_step::ValueAggregate;
 use crate::foldable_next;
 use crate::foldable_prev;
@@ -45,18 +44,18 @@ impl<'ctx> Iterable<'ctx> for CanonStreamMapIterableIngredients {
         foldable_prev!(self)
     }
 
-    fn peek(&'ctx self) -> Option<Self::Item> {
-        if self.values.is_empty() {
+    fn position(&self) -> usize {
+        self.cursor
+    }
+
+    fn peek_nth(&'ctx self, n: usize) -> Option<Self::Item> {
+        let position = self.cursor + n;
+        if position >= self.values.len() {
             return None;
         }
 
-        let value = self.values.get(self.cursor).expect(EXPECT_VALUE_IN_MAP);
-        let result = IterableItem::RefValue((
-            value.get_result(),
-            value.get_tetraplet(),
-            value.get_trace_pos(),
-            value.get_provenance(),
-        ));
+        let value = self.values.get(position).expect(EXPECT_VALUE_IN_MAP);
+        let result = IterableItem::AggregateRef(value);
         Some(result)
     }
 
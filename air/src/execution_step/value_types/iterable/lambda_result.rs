@@ -54,14 +54,19 @@ impl<'ctx> Iterable<'ctx> for IterableLambdaResult {
         foldable_prev!(self)
     }
 
-    fn peek(&'ctx self) -> Option<Self::Item> {
-        if self.jvalues.is_empty() {
+    fn position(&self) -> usize {
+        self.cursor
+    }
+
+    fn peek_nth(&'ctx self, n: usize) -> Option<Self::Item> {
+        let position = self.cursor + n;
+        if position >= self.jvalues.len() {
             return None;
         }
 
-        let jvalue = &self.jvalues[self.cursor];
+        let jvalue = &self.jvalues[position];
         let mut tetraplet = (*self.tetraplet).clone();
-        tetraplet.add_lens(&format!(".$.[{}]", self.cursor));
+        tetraplet.add_lens(&format!(".$.[{position}]"));
         let result = IterableItem::RefValue((jvalue, tetraplet.into(), 0.into(), self.provenance.clone()));
 
         Some(result)
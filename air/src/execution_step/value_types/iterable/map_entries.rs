@@ -0,0 +1,99 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::Iterable;
+use super::IterableItem;
+use crate::execution_step::execution_context::stream_map_key::KEY_FIELD_NAME;
+use crate::execution_step::value_types::stream_map::VALUE_FIELD_NAME;
+use crate::execution_step::RcSecurityTetraplet;
+use crate::foldable_next;
+use crate::foldable_prev;
+use crate::JValue;
+
+use air_interpreter_data::Provenance;
+use air_interpreter_data::TracePos;
+use air_interpreter_value::JsonString;
+use air_interpreter_value::Map;
+
+/// Used for iterating over fields of a JValue of object type, each field is represented
+/// as a {"key": key, "value": value} object. Fields are visited in the lexicographic
+/// order of their keys, so all peers iterate in the same order regardless of how the object
+/// was built. The tetraplet of the object is kept as is, since entries aren't parts of it.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct IterableMapEntries {
+    pub(crate) entries: Vec<JValue>,
+    pub(crate) tetraplet: RcSecurityTetraplet,
+    pub(crate) trace_pos: TracePos,
+    pub(crate) provenance: Provenance,
+    pub(crate) cursor: usize,
+}
+
+impl IterableMapEntries {
+    pub(crate) fn init(
+        map: &Map<JsonString, JValue>,
+        tetraplet: RcSecurityTetraplet,
+        trace_pos: TracePos,
+        provenance: Provenance,
+    ) -> Self {
+        let mut fields = map.iter().collect::<Vec<_>>();
+        fields.sort_unstable_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+
+        let entries = fields
+            .into_iter()
+            .map(|(key, value)| {
+                JValue::object_from_pairs([
+                    (KEY_FIELD_NAME, JValue::string(key.clone())),
+                    (VALUE_FIELD_NAME, value.clone()),
+                ])
+            })
+            .collect();
+
+        Self {
+            entries,
+            tetraplet,
+            trace_pos,
+            provenance,
+            cursor: 0,
+        }
+    }
+}
+
+impl<'ctx> Iterable<'ctx> for IterableMapEntries {
+    type Item = IterableItem<'ctx>;
+
+    fn next(&mut self) -> bool {
+        foldable_next!(self, self.entries.len())
+    }
+
+    fn prev(&mut self) -> bool {
+        foldable_prev!(self)
+    }
+
+    fn position(&self) -> usize {
+        self.cursor
+    }
+
+    fn peek_nth(&'ctx self, n: usize) -> Option<Self::Item> {
+        let entry = self.entries.get(self.cursor + n)?;
+        let result = IterableItem::RefValue((entry, self.tetraplet.clone(), self.trace_pos, self.provenance.clone()));
+
+        Some(result)
+    }
+
+    fn len(&self) -> usize {
+        self.entries.len()
+    }
+}
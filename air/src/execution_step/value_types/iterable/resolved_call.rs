@@ -17,24 +17,28 @@
 use super::Iterable;
 use super::IterableItem;
 use super::ValueAggregate;
+use crate::execution_step::value_types::TracePosOperate;
 use crate::foldable_next;
 use crate::foldable_prev;
 use crate::JValue;
 
+use std::rc::Rc;
+
 /// Used for iterating over JValue of array type.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) struct IterableResolvedCall {
     pub(crate) call_result: ValueAggregate,
+    // the parsed array of call_result
+    pub(crate) values: Rc<[JValue]>,
     pub(crate) cursor: usize,
-    pub(crate) len: usize,
 }
 
 impl IterableResolvedCall {
-    pub(crate) fn init(call_result: ValueAggregate, len: usize) -> Self {
+    pub(crate) fn init(call_result: ValueAggregate, values: Rc<[JValue]>) -> Self {
         Self {
             call_result,
+            values,
             cursor: 0,
-            len,
         }
     }
 }
@@ -43,37 +47,32 @@ impl<'ctx> Iterable<'ctx> for IterableResolvedCall {
     type Item = IterableItem<'ctx>;
 
     fn next(&mut self) -> bool {
-        foldable_next!(self, self.len)
+        foldable_next!(self, self.values.len())
     }
 
     fn prev(&mut self) -> bool {
         foldable_prev!(self)
     }
 
-    fn peek(&'ctx self) -> Option<Self::Item> {
-        if self.len == 0 {
-            return None;
-        }
+    fn position(&self) -> usize {
+        self.cursor
+    }
 
-        let (result, tetraplet, trace_pos) = self.call_result.as_inner_parts();
+    fn peek_nth(&'ctx self, n: usize) -> Option<Self::Item> {
+        let position = self.cursor + n;
+        let jvalue = self.values.get(position)?;
+        let tetraplet = self.call_result.get_tetraplet();
+        let trace_pos = self.call_result.get_trace_pos();
         let provenance = self.call_result.get_provenance();
 
-        let jvalue = match &result {
-            JValue::Array(array) => &array[self.cursor],
-            _ => unimplemented!("this jvalue is set only by fold instruction, so it must have an array type"),
-        };
-
         let mut tetraplet = (*tetraplet).clone();
-        tetraplet.add_lens(&format!(".$.[{}]", self.cursor));
+        tetraplet.add_lens(&format!(".$.[{position}]"));
 
         let result = IterableItem::RefValue((jvalue, tetraplet.into(), trace_pos, provenance));
         Some(result)
     }
 
     fn len(&self) -> usize {
-        match self.call_result.get_result() {
-            JValue::Array(array) => array.len(),
-            _ => unimplemented!("this jvalue is set only by fold instruction, so it must have an array type"),
-        }
+        self.values.len()
     }
 }
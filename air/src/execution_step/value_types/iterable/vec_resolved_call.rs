@@ -47,19 +47,17 @@ impl<'ctx> Iterable<'ctx> for IterableVecResolvedCall {
         foldable_prev!(self)
     }
 
-    fn peek(&'ctx self) -> Option<Self::Item> {
-        if self.call_results.is_empty() {
+    fn position(&self) -> usize {
+        self.cursor
+    }
+
+    fn peek_nth(&'ctx self, n: usize) -> Option<Self::Item> {
+        let position = self.cursor + n;
+        if position >= self.call_results.len() {
             return None;
         }
 
-        let (result, tetraplet, trace_pos) = self.call_results[self.cursor].as_inner_parts();
-
-        let result = IterableItem::RcValue((
-            result.clone(),
-            tetraplet,
-            trace_pos,
-            self.call_results[self.cursor].get_provenance(),
-        ));
+        let result = IterableItem::AggregateRef(&self.call_results[position]);
         Some(result)
     }
 
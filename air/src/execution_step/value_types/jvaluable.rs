@@ -49,7 +49,7 @@ pub(crate) trait JValuable {
     ) -> ExecutionResult<(JValue, SecurityTetraplet, Provenance)>;
 
     /// Return internal value as borrowed if it's possible, owned otherwise.
-    fn as_jvalue(&self) -> JValue;
+    fn as_jvalue(&self) -> ExecutionResult<JValue>;
 
     /// Return tetraplets associating with internal value.
     fn as_tetraplets(&self) -> RcSecurityTetraplets;
@@ -18,6 +18,7 @@ use super::select_by_lambda_from_stream;
 use super::ExecutionResult;
 use super::JValuable;
 use crate::execution_step::value_types::CanonStream;
+use crate::execution_step::value_types::ValueAggregate;
 use crate::execution_step::ExecutionCtx;
 use crate::execution_step::RcSecurityTetraplets;
 use crate::JValue;
@@ -30,8 +31,11 @@ use std::ops::Deref;
 
 impl JValuable for &CanonStream {
     fn apply_lambda(&self, lambda: &LambdaAST<'_>, exec_ctx: &ExecutionCtx<'_>) -> ExecutionResult<JValue> {
-        let iter = self.iter().map(|v| v.get_result());
-        let select_result = select_by_lambda_from_stream(iter, lambda, exec_ctx)?;
+        let values = self
+            .iter()
+            .map(ValueAggregate::get_result)
+            .collect::<Result<Vec<_>, _>>()?;
+        let select_result = select_by_lambda_from_stream(values.into_iter(), lambda, exec_ctx)?;
 
         Ok(select_result.result)
     }
@@ -42,8 +46,11 @@ impl JValuable for &CanonStream {
         exec_ctx: &ExecutionCtx<'_>,
         root_provenance: &Provenance,
     ) -> ExecutionResult<(JValue, SecurityTetraplet, Provenance)> {
-        let iter = self.iter().map(|v| v.get_result());
-        let select_result = select_by_lambda_from_stream(iter, lambda, exec_ctx)?;
+        let values = self
+            .iter()
+            .map(ValueAggregate::get_result)
+            .collect::<Result<Vec<_>, _>>()?;
+        let select_result = select_by_lambda_from_stream(values.into_iter(), lambda, exec_ctx)?;
 
         let (tetraplet, provenance) = match select_result.tetraplet_idx {
             Some(idx) => {
@@ -69,8 +76,8 @@ impl JValuable for &CanonStream {
     }
 
     #[inline]
-    fn as_jvalue(&self) -> JValue {
-        CanonStream::as_jvalue(self)
+    fn as_jvalue(&self) -> ExecutionResult<JValue> {
+        Ok(CanonStream::as_jvalue(self)?)
     }
 
     fn as_tetraplets(&self) -> RcSecurityTetraplets {
@@ -47,8 +47,8 @@ impl JValuable for &CanonStreamMap {
     }
 
     #[inline]
-    fn as_jvalue(&self) -> JValue {
-        CanonStreamMap::as_jvalue(self)
+    fn as_jvalue(&self) -> ExecutionResult<JValue> {
+        Ok(CanonStreamMap::as_jvalue(self)?)
     }
 
     fn as_tetraplets(&self) -> RcSecurityTetraplets {
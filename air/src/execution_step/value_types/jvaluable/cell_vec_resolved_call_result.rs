@@ -29,8 +29,11 @@ use air_interpreter_data::Provenance;
 
 impl JValuable for std::cell::Ref<'_, Vec<ValueAggregate>> {
     fn apply_lambda(&self, lambda: &LambdaAST<'_>, exec_ctx: &ExecutionCtx<'_>) -> ExecutionResult<JValue> {
-        let stream_iter = self.iter().map(|r| r.get_result());
-        let select_result = select_by_lambda_from_stream(stream_iter, lambda, exec_ctx)?;
+        let values = self
+            .iter()
+            .map(ValueAggregate::get_result)
+            .collect::<Result<Vec<_>, _>>()?;
+        let select_result = select_by_lambda_from_stream(values.into_iter(), lambda, exec_ctx)?;
         Ok(select_result.result)
     }
 
@@ -40,8 +43,11 @@ impl JValuable for std::cell::Ref<'_, Vec<ValueAggregate>> {
         exec_ctx: &ExecutionCtx<'_>,
         root_provenance: &Provenance,
     ) -> ExecutionResult<(JValue, SecurityTetraplet, Provenance)> {
-        let stream_iter = self.iter().map(|r| r.get_result());
-        let select_result = select_by_lambda_from_stream(stream_iter, lambda, exec_ctx)?;
+        let values = self
+            .iter()
+            .map(ValueAggregate::get_result)
+            .collect::<Result<Vec<_>, _>>()?;
+        let select_result = select_by_lambda_from_stream(values.into_iter(), lambda, exec_ctx)?;
 
         let tetraplet = match select_result.tetraplet_idx {
             Some(idx) => {
@@ -54,9 +60,12 @@ impl JValuable for std::cell::Ref<'_, Vec<ValueAggregate>> {
         Ok((select_result.result, tetraplet, root_provenance.clone()))
     }
 
-    fn as_jvalue(&self) -> JValue {
-        let jvalue_iter = self.iter().map(|r| r.get_result().clone());
-        JValue::array_from_iter(jvalue_iter)
+    fn as_jvalue(&self) -> ExecutionResult<JValue> {
+        let values = self
+            .iter()
+            .map(ValueAggregate::get_result)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(JValue::array_from_iter(values.into_iter().cloned()))
     }
 
     fn as_tetraplets(&self) -> RcSecurityTetraplets {
@@ -33,7 +33,7 @@ impl<'ctx> JValuable for IterableItem<'ctx> {
 
         let jvalue = match self {
             RefValue((jvalue, ..)) => jvalue,
-            RcValue((jvalue, ..)) => jvalue,
+            AggregateRef(value) => return value.apply_lambda(lambda, exec_ctx),
         };
 
         let selected_value = select_by_lambda_from_scalar(jvalue, lambda, exec_ctx)?;
@@ -44,13 +44,13 @@ impl<'ctx> JValuable for IterableItem<'ctx> {
         &self,
         lambda: &LambdaAST<'_>,
         exec_ctx: &ExecutionCtx<'_>,
-        _root_provenance: &Provenance,
+        root_provenance: &Provenance,
     ) -> ExecutionResult<(JValue, SecurityTetraplet, Provenance)> {
         use super::IterableItem::*;
 
         let (jvalue, tetraplet, provenance) = match self {
             RefValue((jvalue, tetraplet, _, provenance)) => (*jvalue, tetraplet, provenance),
-            RcValue((jvalue, tetraplet, _, provenance)) => (jvalue, tetraplet, provenance),
+            AggregateRef(value) => return value.apply_lambda_with_tetraplets(lambda, exec_ctx, root_provenance),
         };
 
         let selected_value = select_by_lambda_from_scalar(jvalue, lambda, exec_ctx)?;
@@ -60,13 +60,14 @@ impl<'ctx> JValuable for IterableItem<'ctx> {
     }
 
     #[inline]
-    fn as_jvalue(&self) -> JValue {
+    fn as_jvalue(&self) -> ExecutionResult<JValue> {
         use super::IterableItem::*;
 
-        match self {
+        let jvalue = match self {
             RefValue((jvalue, ..)) => (*jvalue).clone(),
-            RcValue((jvalue, ..)) => jvalue.clone(),
-        }
+            AggregateRef(value) => value.get_result()?.clone(),
+        };
+        Ok(jvalue)
     }
 
     fn as_tetraplets(&self) -> RcSecurityTetraplets {
@@ -75,7 +76,7 @@ impl<'ctx> JValuable for IterableItem<'ctx> {
         // these clones are needed because rust-sdk allows passing arguments only by value
         match self {
             RefValue((_, tetraplet, _, _)) => vec![tetraplet.clone()],
-            RcValue((_, tetraplet, _, _)) => vec![tetraplet.clone()],
+            AggregateRef(value) => vec![value.get_tetraplet()],
         }
     }
 }
@@ -29,7 +29,7 @@ use air_interpreter_data::Provenance;
 
 impl JValuable for ValueAggregate {
     fn apply_lambda(&self, lambda: &LambdaAST<'_>, exec_ctx: &ExecutionCtx<'_>) -> ExecutionResult<JValue> {
-        let selected_value = select_by_lambda_from_scalar(self.get_result(), lambda, exec_ctx)?;
+        let selected_value = select_by_lambda_from_scalar(self.get_result()?, lambda, exec_ctx)?;
         Ok(selected_value)
     }
 
@@ -39,15 +39,15 @@ impl JValuable for ValueAggregate {
         exec_ctx: &ExecutionCtx<'_>,
         _root_provenane: &Provenance,
     ) -> ExecutionResult<(JValue, SecurityTetraplet, Provenance)> {
-        let selected_value = select_by_lambda_from_scalar(self.get_result(), lambda, exec_ctx)?;
+        let selected_value = select_by_lambda_from_scalar(self.get_result()?, lambda, exec_ctx)?;
         let tetraplet = populate_tetraplet_with_lambda(self.get_tetraplet().as_ref().clone(), lambda);
 
         Ok((selected_value, tetraplet, self.get_provenance()))
     }
 
     #[inline]
-    fn as_jvalue(&self) -> JValue {
-        self.get_result().clone()
+    fn as_jvalue(&self) -> ExecutionResult<JValue> {
+        Ok(self.get_result()?.clone())
     }
 
     fn as_tetraplets(&self) -> RcSecurityTetraplets {
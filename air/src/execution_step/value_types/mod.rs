@@ -30,11 +30,12 @@ pub(crate) use canon_stream_map::*;
 pub(crate) use iterable::*;
 pub(crate) use jvaluable::*;
 pub(crate) use scalar::CanonResultAggregate;
+pub use scalar::LazyValue;
 pub(crate) use scalar::LiteralAggregate;
 pub(crate) use scalar::ScalarRef;
 pub(crate) use scalar::ServiceResultAggregate;
 pub(crate) use scalar::TracePosOperate;
-pub(crate) use scalar::ValueAggregate;
+pub use scalar::ValueAggregate;
 
 pub(crate) use stream::Generation;
 pub(crate) use stream::IterableValue;
@@ -14,8 +14,11 @@
  * limitations under the License.
  */
 
+mod lazy_value;
 pub mod values;
 
+pub use self::lazy_value::LazyValue;
+
 pub(crate) use self::values::CanonResultAggregate;
 pub(crate) use self::values::LiteralAggregate;
 pub(crate) use self::values::ServiceResultAggregate;
@@ -23,6 +26,7 @@ pub(crate) use self::values::ServiceResultAggregate;
 use super::JValuable;
 use crate::execution_step::FoldState;
 use crate::execution_step::RcSecurityTetraplet;
+use crate::execution_step::UncatchableError;
 use crate::execution_step::PEEK_ALLOWED_ON_NON_EMPTY;
 use crate::JValue;
 
@@ -125,39 +129,22 @@ impl ValueAggregate {
         }
     }
 
-    pub(crate) fn as_inner_parts(&self) -> (&JValue, RcSecurityTetraplet, TracePos) {
-        match self {
-            ValueAggregate::Literal(ref literal) => (&literal.result, literal.get_tetraplet(), literal.trace_pos),
-            ValueAggregate::ServiceResult {
-                result: ref service_result,
-                provenance_cid: _,
-            } => (
-                &service_result.result,
-                service_result.tetraplet.clone(),
-                service_result.trace_pos,
-            ),
-            ValueAggregate::Canon {
-                result: ref canon_result,
-                provenance_cid: _,
-            } => (
-                &canon_result.result,
-                canon_result.get_tetraplet(),
-                canon_result.trace_pos,
-            ),
-        }
-    }
-
-    pub fn get_result(&self) -> &JValue {
+    /// Returns the value, a service result from data is parsed on the first access
+    /// and fails with `UncatchableError::MalformedValue` if it isn't a valid JSON.
+    pub fn get_result(&self) -> Result<&JValue, UncatchableError> {
         match self {
-            ValueAggregate::Literal(literal) => &literal.result,
+            ValueAggregate::Literal(literal) => Ok(&literal.result),
             ValueAggregate::ServiceResult {
                 result: service_result,
                 provenance_cid: _,
-            } => &service_result.result,
+            } => service_result
+                .result
+                .get_value()
+                .map_err(UncatchableError::MalformedValue),
             ValueAggregate::Canon {
                 result: canon_result,
                 provenance_cid: _,
-            } => &canon_result.result,
+            } => Ok(&canon_result.result),
         }
     }
 
@@ -231,13 +218,15 @@ use std::fmt;
 
 impl fmt::Display for ValueAggregate {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let (result, tetraplet, trace_pos) = self.as_inner_parts();
+        match self.get_result() {
+            Ok(result) => write!(f, "value: {result}, ")?,
+            Err(error) => write!(f, "value: <{error}>, ")?,
+        }
         write!(
             f,
-            "value: {}, tetraplet: {}, position: {}, provenance: {:?} ",
-            result,
-            tetraplet,
-            trace_pos,
+            "tetraplet: {}, position: {}, provenance: {:?} ",
+            self.get_tetraplet(),
+            self.get_trace_pos(),
             self.get_provenance(),
         )
     }
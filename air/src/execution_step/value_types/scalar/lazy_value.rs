@@ -0,0 +1,130 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::JValue;
+
+use air_interpreter_data::RawValue;
+use serde::Deserialize;
+use serde::Deserializer;
+use serde::Serialize;
+use serde::Serializer;
+
+use std::fmt;
+use std::rc::Rc;
+
+/// A service result that is kept serialized until it's accessed for the first time.
+///
+/// Values coming from data are often only forwarded through the trace, so they
+/// are parsed only if a script actually looks into them. A parsed value is cached
+/// by the `RawValue` itself.
+#[derive(Clone)]
+pub struct LazyValue {
+    inner: LazyValueInner,
+}
+
+#[derive(Clone)]
+enum LazyValueInner {
+    Parsed(JValue),
+    Raw(Rc<RawValue>),
+}
+
+impl LazyValue {
+    pub(crate) fn from_value(value: JValue) -> Self {
+        Self {
+            inner: LazyValueInner::Parsed(value),
+        }
+    }
+
+    pub(crate) fn from_raw(raw: Rc<RawValue>) -> Self {
+        Self {
+            inner: LazyValueInner::Raw(raw),
+        }
+    }
+
+    /// Returns the value, it's parsed on the first access.
+    pub fn get_value(&self) -> Result<&JValue, serde_json::Error> {
+        match &self.inner {
+            LazyValueInner::Parsed(value) => Ok(value),
+            LazyValueInner::Raw(raw) => raw.try_get_value_ref(),
+        }
+    }
+}
+
+impl From<JValue> for LazyValue {
+    fn from(value: JValue) -> Self {
+        Self::from_value(value)
+    }
+}
+
+impl PartialEq for LazyValue {
+    fn eq(&self, other: &Self) -> bool {
+        matches!((self.get_value(), other.get_value()), (Ok(left), Ok(right)) if left == right)
+    }
+}
+
+impl Eq for LazyValue {}
+
+impl fmt::Debug for LazyValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.inner {
+            LazyValueInner::Parsed(value) => fmt::Debug::fmt(value, f),
+            LazyValueInner::Raw(raw) => f.debug_tuple("LazyValue").field(raw).finish(),
+        }
+    }
+}
+
+impl Serialize for LazyValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get_value()
+            .map_err(serde::ser::Error::custom)?
+            .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for LazyValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        JValue::deserialize(deserializer).map(Self::from_value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+
+    fn raw_value(raw: &str) -> Rc<RawValue> {
+        Rc::new(serde_json::from_value(json!(raw)).unwrap())
+    }
+
+    #[test]
+    fn raw_value_is_parsed_on_access() {
+        let raw = raw_value(r#"{"a": [1, 2]}"#);
+        let value = LazyValue::from_raw(raw.clone());
+
+        let first = value.get_value().unwrap();
+        assert_eq!(first, &JValue::from(json!({"a": [1, 2]})));
+        // the parsed value is cached by the shared raw value
+        assert!(std::ptr::eq(first, raw.try_get_value_ref().unwrap()));
+    }
+
+    #[test]
+    fn malformed_raw_value_is_reported_on_access() {
+        let value = LazyValue::from_raw(raw_value("{not a json"));
+
+        assert!(value.get_value().is_err());
+    }
+}
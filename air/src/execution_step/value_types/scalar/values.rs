@@ -14,6 +14,7 @@
  * limitations under the License.
  */
 
+use super::LazyValue;
 use crate::execution_step::RcSecurityTetraplet;
 use crate::JValue;
 
@@ -36,7 +37,7 @@ pub struct LiteralAggregate {
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 pub struct ServiceResultAggregate {
-    pub result: JValue,
+    pub result: LazyValue,
     pub tetraplet: RcSecurityTetraplet,
     // TODO #[serde(skip)]
     pub trace_pos: TracePos,
@@ -66,9 +67,9 @@ impl LiteralAggregate {
 }
 
 impl ServiceResultAggregate {
-    pub(crate) fn new(result: JValue, tetraplet: RcSecurityTetraplet, trace_pos: TracePos) -> Self {
+    pub(crate) fn new(result: impl Into<LazyValue>, tetraplet: RcSecurityTetraplet, trace_pos: TracePos) -> Self {
         Self {
-            result,
+            result: result.into(),
             tetraplet,
             trace_pos,
         }
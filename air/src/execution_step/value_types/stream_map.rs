@@ -52,7 +52,7 @@ impl StreamMap {
         value: &ValueAggregate,
         generation: Generation,
     ) -> ExecutionResult<()> {
-        let obj = from_key_value(key, value.get_result());
+        let obj = from_key_value(key, value.get_result()?);
         let value = ValueAggregate::new(
             obj,
             value.get_tetraplet(),
@@ -82,9 +82,8 @@ impl StreamMap {
         // 1. There might be key values overlap, given the key value is casted to String, e.g. 42 vs "42".
         // 2. The original kvpair key field has an unsupported type, e.g. float.
         self.stream.iter().filter_map(move |value_aggregate| {
-            let (value, ..) = value_aggregate.as_inner_parts();
-
-            let object = value.as_object()?;
+            // a malformed value can't be a key value pair either
+            let object = value_aggregate.get_result().ok()?.as_object()?;
 
             // This monadic chain casts numeric and string keys to string so that string "42" and
             // number 42 are considered equal.
@@ -139,7 +138,7 @@ mod test {
         value: impl Into<JValue>,
     ) -> bool {
         let value = value.into();
-        let actual_value = iter.next().map(|e| e.get_result()).unwrap();
+        let actual_value = iter.next().map(|e| e.get_result().unwrap()).unwrap();
         let expected_value = from_key_value(key, &value);
 
         actual_value == &expected_value
@@ -22,3 +22,4 @@ pub use errors::FarewellError;
 pub(crate) use outcome::from_execution_error;
 pub(crate) use outcome::from_success_result;
 pub(crate) use outcome::from_uncatchable_error;
+pub(crate) use outcome::from_uncatchable_execution_error;
@@ -15,6 +15,7 @@
  */
 
 use super::FarewellError;
+use crate::execution_step::ContextualExecutionError;
 use crate::execution_step::ExecutionCtx;
 use crate::execution_step::TraceHandler;
 use crate::ExecutionError;
@@ -58,6 +59,7 @@ pub(crate) fn from_success_result(
         error_message,
         keypair,
         soft_limits_triggering,
+        vec![],
     );
     Ok(outcome)
 }
@@ -69,6 +71,7 @@ pub(crate) fn from_uncatchable_error(
     data: impl Into<Vec<u8>> + Debug,
     error: impl ToErrorCode + ToString + Debug,
     soft_limits_triggering: SoftLimitsTriggering,
+    fuel_consumed: u64,
 ) -> InterpreterOutcome {
     let ret_code = error.to_error_code();
     let data = data.into();
@@ -83,16 +86,29 @@ pub(crate) fn from_uncatchable_error(
         vec![],
         call_requests,
         soft_limits_triggering,
+        fuel_consumed,
     )
 }
 
+/// Create InterpreterOutcome from supplied data and an uncatchable execution error,
+/// set ret_code based on the error and point to the instruction raised it.
+pub(crate) fn from_uncatchable_execution_error(
+    data: impl Into<Vec<u8>> + Debug,
+    error: ContextualExecutionError,
+    soft_limits_triggering: SoftLimitsTriggering,
+    fuel_consumed: u64,
+) -> InterpreterOutcome {
+    let error_context = serialize_error_context(&error);
+    from_uncatchable_error(data, error.error, soft_limits_triggering, fuel_consumed).with_error_context(error_context)
+}
+
 /// Create InterpreterOutcome from supplied execution context, trace handler, and error,
-/// set ret_code based on the error.
+/// set ret_code based on the error and point to the instruction raised it.
 #[tracing::instrument(skip(exec_ctx, trace_handler, keypair))]
 pub(crate) fn from_execution_error(
     exec_ctx: ExecutionCtx<'_>,
     trace_handler: TraceHandler,
-    error: impl ToErrorCode + ToString + Debug,
+    error: ContextualExecutionError,
     keypair: &KeyPair,
     soft_limits_triggering: SoftLimitsTriggering,
 ) -> InterpreterOutcome {
@@ -100,9 +116,10 @@ pub(crate) fn from_execution_error(
         exec_ctx,
         trace_handler,
         error.to_error_code(),
-        error.to_string(),
+        error.error.to_string(),
         keypair,
         soft_limits_triggering,
+        serialize_error_context(&error),
     )
 }
 
@@ -114,6 +131,7 @@ fn populate_outcome_from_contexts(
     error_message: String,
     keypair: &KeyPair,
     soft_limits_triggering: SoftLimitsTriggering,
+    error_context: Vec<u8>,
 ) -> InterpreterOutcome {
     match compactify_streams(&mut exec_ctx, &mut trace_handler, soft_limits_triggering) {
         Ok(()) => {}
@@ -125,11 +143,23 @@ fn populate_outcome_from_contexts(
         Err(outcome) => return outcome,
     };
 
+    let gc_stats = exec_ctx.gc_cid_store(trace_handler.as_result_trace());
+    log::debug!(
+        "CID store garbage collected: {} values before, {} after",
+        gc_stats.cid_store_size_before,
+        gc_stats.cid_store_size_after
+    );
+
+    let fuel_consumed = exec_ctx.fuel_consumed();
+    let instruction_counts = serialize_instruction_counts(&exec_ctx);
+    let flamegraph_svg = render_flamegraph(&trace_handler);
+    // positions aren't needed any more, so runs of identical states are compacted
+    trace_handler.compact();
     let data = InterpreterDataEnvelope::from_execution_result(
         trace_handler.into_result_trace(),
         exec_ctx.cid_state.into(),
         exec_ctx.signature_store,
-        exec_ctx.last_call_request_id,
+        exec_ctx.call_request_ids.last(),
         semver::Version::parse(env!("CARGO_PKG_VERSION")).expect("cargo version is valid"),
     );
     let data = measure!(
@@ -153,21 +183,52 @@ fn populate_outcome_from_contexts(
         next_peer_pks,
         call_requests,
         soft_limits_triggering,
+        fuel_consumed,
     )
+    .with_instruction_counts(instruction_counts)
+    .with_flamegraph_svg(flamegraph_svg)
+    .with_error_context(error_context)
 }
 
+fn serialize_error_context(error: &ContextualExecutionError) -> Vec<u8> {
+    serde_json::to_vec(&error.error_context()).expect("default serializer shouldn't fail")
+}
+
+#[cfg(feature = "metrics")]
+fn serialize_instruction_counts(exec_ctx: &ExecutionCtx<'_>) -> Vec<u8> {
+    serde_json::to_vec(&exec_ctx.instruction_counts).expect("default serializer shouldn't fail")
+}
+
+#[cfg(not(feature = "metrics"))]
+fn serialize_instruction_counts(_exec_ctx: &ExecutionCtx<'_>) -> Vec<u8> {
+    vec![]
+}
+
+#[cfg(feature = "flamegraph")]
+fn render_flamegraph(trace_handler: &TraceHandler) -> String {
+    trace_handler.to_flamegraph_svg()
+}
+
+#[cfg(not(feature = "flamegraph"))]
+fn render_flamegraph(_trace_handler: &TraceHandler) -> String {
+    String::new()
+}
+
+#[allow(clippy::result_large_err)]
 fn compactify_streams(
     exec_ctx: &mut ExecutionCtx<'_>,
     trace_ctx: &mut TraceHandler,
     soft_limits_triggering: SoftLimitsTriggering,
 ) -> Result<(), InterpreterOutcome> {
+    let fuel_consumed = exec_ctx.fuel_consumed();
     exec_ctx
         .streams
         .compactify(trace_ctx)
         .and_then(|_| exec_ctx.stream_maps.compactify(trace_ctx))
-        .map_err(|err| execution_error_into_outcome(err, soft_limits_triggering))
+        .map_err(|err| execution_error_into_outcome(err, soft_limits_triggering, fuel_consumed))
 }
 
+#[allow(clippy::result_large_err)]
 fn sign_result(
     exec_ctx: &mut ExecutionCtx<'_>,
     keypair: &KeyPair,
@@ -176,7 +237,7 @@ fn sign_result(
     let current_signature = exec_ctx
         .peer_cid_tracker
         .gen_signature(&exec_ctx.run_parameters.salt, keypair)
-        .map_err(|err| signing_error_into_outcome(err, soft_limits_triggering))?;
+        .map_err(|err| signing_error_into_outcome(err, soft_limits_triggering, exec_ctx.fuel_consumed()))?;
 
     let current_pubkey = keypair.public();
     exec_ctx.signature_store.put(current_pubkey, current_signature);
@@ -189,6 +250,7 @@ fn sign_result(
 fn execution_error_into_outcome(
     error: ExecutionError,
     soft_limits_triggering: SoftLimitsTriggering,
+    fuel_consumed: u64,
 ) -> InterpreterOutcome {
     InterpreterOutcome::new(
         error.to_error_code(),
@@ -197,10 +259,15 @@ fn execution_error_into_outcome(
         vec![],
         <_>::default(),
         soft_limits_triggering,
+        fuel_consumed,
     )
 }
 
-fn signing_error_into_outcome(error: SigningError, soft_limits_triggering: SoftLimitsTriggering) -> InterpreterOutcome {
+fn signing_error_into_outcome(
+    error: SigningError,
+    soft_limits_triggering: SoftLimitsTriggering,
+    fuel_consumed: u64,
+) -> InterpreterOutcome {
     InterpreterOutcome::new(
         error.to_error_code(),
         error.to_string(),
@@ -208,6 +275,7 @@ fn signing_error_into_outcome(error: SigningError, soft_limits_triggering: SoftL
         vec![],
         <_>::default(),
         soft_limits_triggering,
+        fuel_consumed,
     )
 }
 
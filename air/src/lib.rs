@@ -44,7 +44,12 @@ pub use execution_step::execution_context::errors::StreamMapError;
 pub use execution_step::execution_context::errors::StreamMapKeyError;
 pub use execution_step::execution_context::no_error;
 pub use execution_step::execution_context::no_error_object;
+pub use execution_step::execution_context::ContextCheckpoint;
+pub use execution_step::execution_context::ContextDiff;
+pub use execution_step::execution_context::ContextInspector;
+pub use execution_step::execution_context::ContextView;
 pub use execution_step::execution_context::ExecutionCidState;
+pub use execution_step::execution_context::GCStats;
 pub use execution_step::execution_context::InstructionError;
 pub use execution_step::execution_context::ERROR_CODE_FIELD_NAME;
 pub use execution_step::execution_context::INSTRUCTION_FIELD_NAME;
@@ -52,20 +57,39 @@ pub use execution_step::execution_context::MESSAGE_FIELD_NAME;
 pub use execution_step::execution_context::NO_ERROR_ERROR_CODE;
 pub use execution_step::execution_context::NO_ERROR_MESSAGE;
 pub use execution_step::CatchableError;
+pub use execution_step::ContextualExecutionError;
 pub use execution_step::ErrorObjectError;
 pub use execution_step::ExecutionError;
 pub use execution_step::LambdaError;
+pub use execution_step::LazyValue;
 pub use execution_step::UncatchableError;
+pub use execution_step::ValueAggregate;
 pub use farewell_step::FarewellError;
 pub use polyplets::ResolvedTriplet;
 pub use polyplets::SecurityTetraplet;
 pub use preparation_step::interpreter_version;
 pub use preparation_step::min_supported_version;
+pub use preparation_step::AirCache;
+pub use preparation_step::CachedAir;
+pub use preparation_step::ExecutionCost;
+pub use preparation_step::MigrateFn;
+pub use preparation_step::MigrationError;
+pub use preparation_step::Migrator;
+pub use preparation_step::ParsedAir;
 pub use preparation_step::PreparationError;
+pub use preparation_step::RecoveryHint;
+pub use preparation_step::ASSUMED_FOLD_ITERATIONS;
 pub use utils::ToErrorCode;
 
 pub use crate::human_readable_data::to_human_readable_data;
 pub use crate::runner::execute_air;
+pub use crate::runner::execute_air_traced;
+pub use crate::runner::execute_air_with_cancellation;
+pub use crate::runner::execute_air_with_observer;
+
+pub mod debug;
+pub mod observer;
+pub mod replay;
 
 pub mod interpreter_data {
     pub use air_interpreter_data::*;
@@ -73,9 +97,10 @@ pub mod interpreter_data {
 
 pub mod parser {
     pub use air_parser::ast::Instruction;
+    pub use air_parser::AirParseError;
 
     /// Parse an AIR script to AST.
-    pub fn parse(script: &str) -> Result<Instruction<'_>, String> {
+    pub fn parse(script: &str) -> Result<Instruction<'_>, AirParseError> {
         air_parser::parse(script)
     }
 }
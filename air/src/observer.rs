@@ -0,0 +1,223 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Hooks observing an AIR script execution.
+
+use crate::execution_step::execution_context::instruction_tag;
+use crate::ContextView;
+use crate::ExecutionError;
+
+use air_interpreter_interface::ExecutionTree;
+use air_interpreter_interface::InstructionCounts;
+use air_interpreter_interface::TraceNode;
+use air_log_targets::OBSERVER;
+use air_parser::ast::Instruction;
+
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+use std::time::Instant;
+
+/// Receives events of an execution registered with `execute_air_with_observer`, all methods
+/// do nothing by default. Events come with a read-only view of the execution context.
+#[allow(unused_variables)]
+pub trait ExecutionObserver: Send {
+    /// Called once data is prepared, right before the script is executed.
+    fn on_call_start(&mut self, ctx: &ContextView<'_>) {}
+
+    /// Called before an instruction is dispatched, i.e. before its subinstructions.
+    fn on_instruction_start(&mut self, instruction: &Instruction<'_>, ctx: &ContextView<'_>) {}
+
+    /// Called after an instruction and its subinstructions are executed, `error` is set
+    /// if the instruction failed.
+    fn on_instruction_end(
+        &mut self,
+        instruction: &Instruction<'_>,
+        error: Option<&ExecutionError>,
+        ctx: &ContextView<'_>,
+    ) {
+    }
+
+    /// Called after each iteration of a fold, once its body reaches `next`.
+    fn on_fold_iteration(&mut self, progress: FoldProgress, ctx: &ContextView<'_>) {}
+
+    /// Called once the script is executed, before the outcome is produced.
+    fn on_call_end(&mut self, ctx: &ContextView<'_>) {}
+}
+
+/// Position of a fold reported after one of its iterations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FoldProgress {
+    pub iterator_name: String,
+    /// Index of the iteration just finished, it starts from 0.
+    pub current_index: usize,
+    /// Number of iterations, it's `None` for folds over streams, since they could grow.
+    pub total: Option<usize>,
+}
+
+/// Passes fold progress to a closure, ignores other events.
+pub struct FoldProgressCallback {
+    callback: Box<dyn Fn(FoldProgress) + Send>,
+}
+
+impl FoldProgressCallback {
+    pub fn new(callback: impl Fn(FoldProgress) + Send + 'static) -> Self {
+        Self {
+            callback: Box::new(callback),
+        }
+    }
+}
+
+impl ExecutionObserver for FoldProgressCallback {
+    fn on_fold_iteration(&mut self, progress: FoldProgress, _ctx: &ContextView<'_>) {
+        (self.callback)(progress)
+    }
+}
+
+/// Counts dispatched instructions of each kind.
+///
+/// Clones share counts, so a clone kept by a host could read them once execution is over.
+#[derive(Debug, Default, Clone)]
+pub struct MetricsObserver {
+    counts: Arc<Mutex<InstructionCounts>>,
+}
+
+impl MetricsObserver {
+    pub fn new() -> Self {
+        <_>::default()
+    }
+
+    /// Returns numbers of instructions of each kind dispatched so far.
+    pub fn instruction_counts(&self) -> InstructionCounts {
+        self.lock_counts().clone()
+    }
+
+    /// Returns the number of instructions dispatched so far.
+    pub fn total(&self) -> u64 {
+        self.lock_counts().values().sum()
+    }
+
+    fn lock_counts(&self) -> std::sync::MutexGuard<'_, InstructionCounts> {
+        // counts stay consistent even if a thread panicked while holding the lock
+        self.counts.lock().unwrap_or_else(|error| error.into_inner())
+    }
+}
+
+impl ExecutionObserver for MetricsObserver {
+    fn on_instruction_start(&mut self, instruction: &Instruction<'_>, _ctx: &ContextView<'_>) {
+        *self.lock_counts().entry(instruction_tag(instruction)).or_default() += 1;
+    }
+}
+
+/// Builds a tree of dispatched instructions with wall clock time spent in each of them.
+///
+/// Clones share the tree, so a clone kept by a host could read it once execution is over.
+#[derive(Debug, Default, Clone)]
+pub struct ExecutionTreeObserver {
+    state: Arc<Mutex<ExecutionTreeState>>,
+}
+
+#[derive(Debug, Default)]
+struct ExecutionTreeState {
+    roots: ExecutionTree,
+
+    /// Instructions being executed along with times they started, the innermost one is the last.
+    stack: Vec<(TraceNode, Instant)>,
+}
+
+impl ExecutionTreeObserver {
+    pub fn new() -> Self {
+        <_>::default()
+    }
+
+    /// Returns instructions completed so far, instructions still being executed aren't included.
+    pub fn execution_tree(&self) -> ExecutionTree {
+        self.lock_state().roots.clone()
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, ExecutionTreeState> {
+        // the tree stays consistent even if a thread panicked while holding the lock
+        self.state.lock().unwrap_or_else(|error| error.into_inner())
+    }
+}
+
+impl ExecutionObserver for ExecutionTreeObserver {
+    fn on_instruction_start(&mut self, instruction: &Instruction<'_>, _ctx: &ContextView<'_>) {
+        let node = TraceNode {
+            instruction: instruction_tag(instruction),
+            duration: Duration::ZERO,
+            children: vec![],
+        };
+        self.lock_state().stack.push((node, Instant::now()));
+    }
+
+    fn on_instruction_end(
+        &mut self,
+        _instruction: &Instruction<'_>,
+        _error: Option<&ExecutionError>,
+        _ctx: &ContextView<'_>,
+    ) {
+        let mut state = self.lock_state();
+        let Some((mut node, started)) = state.stack.pop() else {
+            return;
+        };
+
+        node.duration = started.elapsed();
+        match state.stack.last_mut() {
+            Some((parent, _)) => parent.children.push(node),
+            None => state.roots.push(node),
+        }
+    }
+}
+
+/// Emits a log line of `key=value` pairs for each event to the `observer` target.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LoggingObserver;
+
+impl ExecutionObserver for LoggingObserver {
+    fn on_call_start(&mut self, ctx: &ContextView<'_>) {
+        log::debug!(target: OBSERVER, "event=call_start peer_id={}", ctx.current_peer_id());
+    }
+
+    fn on_instruction_start(&mut self, instruction: &Instruction<'_>, _ctx: &ContextView<'_>) {
+        log::debug!(
+            target: OBSERVER,
+            "event=instruction_start instruction={:?}",
+            instruction_tag(instruction)
+        );
+    }
+
+    fn on_instruction_end(
+        &mut self,
+        instruction: &Instruction<'_>,
+        error: Option<&ExecutionError>,
+        _ctx: &ContextView<'_>,
+    ) {
+        let tag = instruction_tag(instruction);
+        match error {
+            None => log::debug!(target: OBSERVER, "event=instruction_end instruction={tag:?} status=ok"),
+            Some(error) => log::debug!(
+                target: OBSERVER,
+                "event=instruction_end instruction={tag:?} status=error error={:?}",
+                error.to_string()
+            ),
+        }
+    }
+
+    fn on_call_end(&mut self, ctx: &ContextView<'_>) {
+        log::debug!(target: OBSERVER, "event=call_end peer_id={}", ctx.current_peer_id());
+    }
+}
@@ -0,0 +1,194 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air_parser::ast::Instruction;
+use air_parser::AirParseError;
+use self_cell::self_cell;
+
+use std::cell::OnceCell;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::hash::Hasher;
+use std::rc::Rc;
+
+/// Keeps parsed AIR scripts between interpreter calls, so that rounds of the same particle
+/// don't parse its script again.
+///
+/// The cache never evicts scripts and stops admitting new ones when it's full,
+/// so its memory usage is bounded by the capacity times the AIR size limit.
+#[derive(Debug, Default)]
+pub struct AirCache {
+    scripts: HashMap<u64, Rc<CachedAir>>,
+    capacity: usize,
+}
+
+/// A cached script along with its fingerprint computed on the first request.
+#[derive(Debug)]
+pub struct CachedAir {
+    air: OwnedAir,
+    fingerprint: OnceCell<[u8; 32]>,
+}
+
+self_cell!(
+    /// Script text along with its AST borrowing from it.
+    struct OwnedAir {
+        owner: String,
+
+        #[covariant]
+        dependent: Instruction,
+    }
+
+    impl {Debug}
+);
+
+/// AST of a script either shared with the cache or parsed for a single call.
+#[derive(Debug)]
+pub enum ParsedAir<'i> {
+    Cached(Rc<CachedAir>),
+    Uncached(Instruction<'i>),
+}
+
+impl AirCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            scripts: HashMap::new(),
+            capacity,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.scripts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.scripts.is_empty()
+    }
+
+    /// Returns the cached AST of the script or parses it, caching the result if there is space left.
+    pub fn get_or_parse<'i>(&mut self, air: &'i str) -> Result<ParsedAir<'i>, AirParseError> {
+        let key = script_hash(air);
+        if let Some(cached) = self.scripts.get(&key) {
+            // it's compared on a hit, because different scripts could have the same hash
+            if cached.air.borrow_owner() == air {
+                return Ok(ParsedAir::Cached(cached.clone()));
+            }
+        }
+
+        if self.scripts.len() >= self.capacity || self.scripts.contains_key(&key) {
+            return air_parser::parse(air).map(ParsedAir::Uncached);
+        }
+
+        let cached = Rc::new(CachedAir {
+            air: OwnedAir::try_new(air.to_owned(), |source| air_parser::parse(source))?,
+            fingerprint: OnceCell::new(),
+        });
+        self.scripts.insert(key, cached.clone());
+        Ok(ParsedAir::Cached(cached))
+    }
+}
+
+impl ParsedAir<'_> {
+    pub fn ast(&self) -> &Instruction<'_> {
+        match self {
+            ParsedAir::Cached(cached) => cached.air.borrow_dependent(),
+            ParsedAir::Uncached(ast) => ast,
+        }
+    }
+
+    /// Returns the fingerprint of the script, it's computed once for a cached script.
+    pub fn fingerprint(&self) -> [u8; 32] {
+        match self {
+            ParsedAir::Cached(cached) => cached.fingerprint(),
+            ParsedAir::Uncached(ast) => air_parser::fingerprint(ast),
+        }
+    }
+
+    /// Returns the fingerprint only if the script is cached, so it's cheap to compute.
+    pub fn cached_fingerprint(&self) -> Option<[u8; 32]> {
+        match self {
+            ParsedAir::Cached(cached) => Some(cached.fingerprint()),
+            ParsedAir::Uncached(_) => None,
+        }
+    }
+}
+
+impl CachedAir {
+    pub fn fingerprint(&self) -> [u8; 32] {
+        *self
+            .fingerprint
+            .get_or_init(|| air_parser::fingerprint(self.air.borrow_dependent()))
+    }
+}
+
+fn script_hash(air: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    air.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCRIPT: &str = r#"(seq (call "peer" ("service" "function") [] result) (null))"#;
+
+    #[test]
+    fn cached_script_isnt_parsed_again() {
+        let mut cache = AirCache::new(1);
+
+        let same_script = SCRIPT.to_owned();
+        let first = cache.get_or_parse(SCRIPT).unwrap();
+        let second = cache.get_or_parse(&same_script).unwrap();
+
+        assert!(std::ptr::eq(first.ast(), second.ast()));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn cached_script_outlives_its_source() {
+        let mut cache = AirCache::new(1);
+
+        let script = SCRIPT.to_owned();
+        cache.get_or_parse(&script).unwrap();
+        drop(script);
+        let cached = cache.get_or_parse(SCRIPT).unwrap();
+
+        assert!(matches!(cached, ParsedAir::Cached(_)));
+        assert_eq!(cached.ast().to_string(), air_parser::parse(SCRIPT).unwrap().to_string());
+    }
+
+    #[test]
+    fn full_cache_doesnt_admit_scripts() {
+        let mut cache = AirCache::new(1);
+
+        cache.get_or_parse(SCRIPT).unwrap();
+        let first = cache.get_or_parse("(null)").unwrap();
+        let second = cache.get_or_parse("(null)").unwrap();
+
+        assert!(matches!(first, ParsedAir::Uncached(_)));
+        assert!(matches!(second, ParsedAir::Uncached(_)));
+        assert_eq!(cache.len(), 1);
+    }
+
+    #[test]
+    fn malformed_script_isnt_cached() {
+        let mut cache = AirCache::new(1);
+
+        assert!(cache.get_or_parse("(seq").is_err());
+        assert!(cache.is_empty());
+    }
+}
@@ -14,6 +14,7 @@
  * limitations under the License.
  */
 
+use super::MigrationError;
 use crate::ToErrorCode;
 use air_interpreter_data::data_version;
 use air_interpreter_data::verification::DataVerifierError;
@@ -21,18 +22,27 @@ use air_interpreter_data::CidStoreVerificationError;
 use air_interpreter_data::DataDeserializationError;
 use air_interpreter_data::Versions;
 use air_interpreter_interface::CallResultsDeserializeError;
+use air_interpreter_interface::InstructionTag;
+use air_interpreter_interface::UnsupportedCallResultsFormat;
+use air_interpreter_interface::UnsupportedKeyFormatPolicy;
+use air_interpreter_interface::UnsupportedLintLevel;
+use air_interpreter_interface::UnsupportedSignaturePolicy;
+use air_parser::ast::Span;
+use air_parser::AirParseError;
 use strum::IntoEnumIterator;
 use strum_macros::EnumDiscriminants;
 use strum_macros::EnumIter;
 use thiserror::Error as ThisError;
 
+use std::time::SystemTime;
+
 /// Errors happened during the interpreter preparation step.
 #[derive(Debug, EnumDiscriminants, ThisError)]
 #[strum_discriminants(derive(EnumIter))]
 pub enum PreparationError {
     /// Error occurred while parsing AIR script
     #[error("air can't be parsed:\n{0}")]
-    AIRParseError(String),
+    AIRParseError(AirParseError),
 
     /// Errors occurred on executed trace deserialization.
     #[error(
@@ -94,6 +104,54 @@ pub enum PreparationError {
     /// RAM limits are excedeed.
     #[error(transparent)]
     SizeLimitsExceded(#[from] SizeLimitsExceded),
+
+    /// Call results format passed in run parameters is unknown.
+    #[error(transparent)]
+    UnsupportedCallResultsFormat(#[from] UnsupportedCallResultsFormat),
+
+    /// Lint level passed in run parameters is unknown.
+    #[error(transparent)]
+    UnsupportedLintLevel(#[from] UnsupportedLintLevel),
+
+    /// AIR script has lints denied by the lint level passed in run parameters.
+    #[error("air has denied lints:\n{0}")]
+    LintsDenied(String),
+
+    /// Supplied data couldn't be migrated to the current interpreter version.
+    #[error(transparent)]
+    DataMigrationFailed(#[from] MigrationError),
+
+    /// Signature policy passed in run parameters is unknown.
+    #[error(transparent)]
+    UnsupportedSignaturePolicy(#[from] UnsupportedSignaturePolicy),
+
+    /// Current data is bigger than the limit passed in run parameters.
+    #[error("current data size: {actual} bytes is bigger than the limit allowed: {limit} bytes")]
+    DataTooLarge { actual: usize, limit: usize },
+
+    /// Trace of current data is longer than the limit passed in run parameters.
+    #[error("current data trace length: {actual} is bigger than the limit allowed: {limit}")]
+    TraceTooLong { actual: usize, limit: usize },
+
+    /// Key format policy passed in run parameters is unknown.
+    #[error(transparent)]
+    UnsupportedKeyFormatPolicy(#[from] UnsupportedKeyFormatPolicy),
+
+    /// Particle has been in flight longer than its TTL passed in run parameters.
+    #[error("particle expired at {expired_at:?}")]
+    ParticleExpired { expired_at: SystemTime },
+
+    /// AIR script contains an instruction forbidden by the instruction policy passed in run parameters.
+    #[error("instruction {instruction:?} at {}..{} is forbidden by the instruction policy", span.left, span.right)]
+    InstructionForbidden { instruction: InstructionTag, span: Span },
+
+    /// AIR script contains more instructions than the limit passed in run parameters.
+    #[error("air contains {actual} instructions, it's more than the limit allowed: {limit}")]
+    TooManyInstructions { actual: u64, limit: u64 },
+
+    /// AIR cache capacity passed in run parameters doesn't fit the interpreter address space.
+    #[error("air cache capacity {cache_capacity} is bigger than the interpreter can address")]
+    AirCacheCapacityTooLarge { cache_capacity: u64 },
 }
 
 impl ToErrorCode for PreparationError {
@@ -103,7 +161,38 @@ impl ToErrorCode for PreparationError {
     }
 }
 
+/// Tells a host what to do with a particle whose preparation failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RecoveryHint {
+    /// The failure is transient, e.g. data was corrupted in flight and will be resent,
+    /// so the particle can be put back in the queue.
+    Retry,
+    /// The particle itself is invalid and will fail the same way again, so it should be dropped.
+    Discard,
+    /// The particle requires an interpreter of another version.
+    Upgrade,
+    /// The host passed invalid run parameters or keys, it needs an operator's attention.
+    Fatal,
+}
+
 impl PreparationError {
+    /// Returns a hint whether the particle can be retried after this error.
+    pub fn recovery_hint(&self) -> RecoveryHint {
+        PreparationErrorDiscriminants::from(self).recovery_hint()
+    }
+
+    /// Returns the recovery hint of a preparation error with the given error code,
+    /// e.g. of `ret_code` returned by the interpreter, or `None` for other codes.
+    pub fn recovery_hint_from_error_code(error_code: i64) -> Option<RecoveryHint> {
+        use crate::utils::PREPARATION_ERROR_START_ID;
+
+        let position = error_code.checked_sub(PREPARATION_ERROR_START_ID)?;
+        let position = usize::try_from(position).ok()?;
+        PreparationErrorDiscriminants::iter()
+            .nth(position)
+            .map(PreparationErrorDiscriminants::recovery_hint)
+    }
+
     pub fn data_de_failed(error: DataDeserializationError) -> Self {
         Self::DataDeFailed { error }
     }
@@ -140,6 +229,34 @@ impl PreparationError {
     }
 }
 
+impl PreparationErrorDiscriminants {
+    fn recovery_hint(self) -> RecoveryHint {
+        use PreparationErrorDiscriminants::*;
+
+        match self {
+            DataDeFailed | EnvelopeDeFailed => RecoveryHint::Retry,
+            AIRParseError
+            | CidStoreVerificationError
+            | DataSignatureCheckError
+            | SizeLimitsExceded
+            | LintsDenied
+            | DataTooLarge
+            | TraceTooLong
+            | ParticleExpired
+            | InstructionForbidden
+            | TooManyInstructions => RecoveryHint::Discard,
+            EnvelopeDeFailedWithVersions | UnsupportedInterpreterVersion | DataMigrationFailed => RecoveryHint::Upgrade,
+            CallResultsDeFailed
+            | MalformedKeyPairData
+            | UnsupportedCallResultsFormat
+            | UnsupportedLintLevel
+            | UnsupportedSignaturePolicy
+            | UnsupportedKeyFormatPolicy
+            | AirCacheCapacityTooLarge => RecoveryHint::Fatal,
+        }
+    }
+}
+
 #[derive(Debug, ThisError)]
 pub enum SizeLimitsExceded {
     /// AIR script size is bigger than the allowed limit.
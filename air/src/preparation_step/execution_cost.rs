@@ -0,0 +1,192 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::execution_step::execution_context::instruction_tag;
+
+use air_interpreter_interface::InstructionTag;
+use air_parser::ast::Instruction;
+
+/// Number of iterations each fold is assumed to make, since iterables aren't known before execution.
+pub const ASSUMED_FOLD_ITERATIONS: u64 = 8;
+
+/// Cost of executing a script estimated from its AST before execution.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutionCost {
+    /// Number of `call` instructions in the script.
+    pub call_count: u32,
+
+    /// Maximum number of folds nested one into another.
+    pub fold_depth: u32,
+
+    /// Number of instructions in the script.
+    pub instruction_count: u32,
+
+    /// Fuel the execution is expected to consume, where instructions are weighted by their typical
+    /// CPU cost and fold bodies are repeated `ASSUMED_FOLD_ITERATIONS` times. It's meant for comparing
+    /// particles with each other rather than for predicting the consumed fuel exactly.
+    pub estimated_fuel: u64,
+}
+
+pub(crate) fn estimate_cost(air: &Instruction<'_>) -> ExecutionCost {
+    let mut cost = ExecutionCost::default();
+    visit(air, 0, 1, &mut cost);
+    cost
+}
+
+/// Adds the instruction and its subinstructions to the cost, `repetitions` is how many times
+/// the instruction is expected to be executed.
+fn visit(instruction: &Instruction<'_>, fold_depth: u32, repetitions: u64, cost: &mut ExecutionCost) {
+    // the parser produces error nodes only for scripts that fail parsing
+    if matches!(instruction, Instruction::Error) {
+        return;
+    }
+
+    let tag = instruction_tag(instruction);
+    cost.instruction_count = cost.instruction_count.saturating_add(1);
+    cost.estimated_fuel = cost
+        .estimated_fuel
+        .saturating_add(weight(tag).saturating_mul(repetitions));
+    if tag == InstructionTag::Call {
+        cost.call_count = cost.call_count.saturating_add(1);
+    }
+
+    let mut visit_child = |child: &Instruction<'_>| visit(child, fold_depth, repetitions, cost);
+    match instruction {
+        Instruction::Seq(seq) => {
+            visit_child(&seq.0);
+            visit_child(&seq.1);
+        }
+        Instruction::Par(par) => {
+            visit_child(&par.0);
+            visit_child(&par.1);
+        }
+        Instruction::Xor(xor) => {
+            visit_child(&xor.0);
+            visit_child(&xor.1);
+        }
+        Instruction::Match(match_) => visit_child(&match_.instruction),
+        Instruction::MisMatch(mismatch) => visit_child(&mismatch.instruction),
+        Instruction::MatchRegex(match_regex) => visit_child(&match_regex.instruction),
+        Instruction::MisMatchRegex(mismatch_regex) => visit_child(&mismatch_regex.instruction),
+        Instruction::New(new) => visit_child(&new.instruction),
+        Instruction::FoldScalar(fold) => visit_fold(
+            &fold.instruction,
+            fold.last_instruction.as_deref(),
+            fold_depth,
+            repetitions,
+            cost,
+        ),
+        Instruction::FoldStream(fold) => visit_fold(
+            &fold.instruction,
+            fold.last_instruction.as_deref(),
+            fold_depth,
+            repetitions,
+            cost,
+        ),
+        Instruction::FoldStreamMap(fold) => visit_fold(
+            &fold.instruction,
+            fold.last_instruction.as_deref(),
+            fold_depth,
+            repetitions,
+            cost,
+        ),
+        _ => {}
+    }
+}
+
+fn visit_fold(
+    body: &Instruction<'_>,
+    last_instruction: Option<&Instruction<'_>>,
+    fold_depth: u32,
+    repetitions: u64,
+    cost: &mut ExecutionCost,
+) {
+    let fold_depth = fold_depth + 1;
+    cost.fold_depth = cost.fold_depth.max(fold_depth);
+
+    visit(
+        body,
+        fold_depth,
+        repetitions.saturating_mul(ASSUMED_FOLD_ITERATIONS),
+        cost,
+    );
+    // the last instruction is executed once the iterable is over
+    if let Some(last_instruction) = last_instruction {
+        visit(last_instruction, fold_depth, repetitions, cost);
+    }
+}
+
+/// Typical CPU cost of an instruction relative to the cheapest ones.
+fn weight(tag: InstructionTag) -> u64 {
+    match tag {
+        // a call resolves arguments and either produces a call request or merges a result
+        InstructionTag::Call => 10,
+        // a canon copies a stream
+        InstructionTag::Canon | InstructionTag::CanonSorted => 5,
+        InstructionTag::Ap | InstructionTag::Fold | InstructionTag::Next => 2,
+        _ => 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cost_of(air_script: &str) -> ExecutionCost {
+        let air = air_parser::parse(air_script).expect("script should be parsed");
+        estimate_cost(&air)
+    }
+
+    #[test]
+    fn instructions_are_counted_and_weighted() {
+        let cost = cost_of(r#"(seq (call "peer" ("service" "function") []) (ap 1 $stream))"#);
+
+        let expected = ExecutionCost {
+            call_count: 1,
+            fold_depth: 0,
+            instruction_count: 3,
+            estimated_fuel: 1 + 10 + 2,
+        };
+        assert_eq!(cost, expected);
+    }
+
+    #[test]
+    fn fold_bodies_are_repeated() {
+        let cost = cost_of(
+            r#"
+            (seq
+                (call "peer" ("service" "function") [] iterable)
+                (fold iterable outer
+                    (seq
+                        (fold iterable inner
+                            (seq (call "peer" ("service" "function") [inner]) (next inner))
+                            (null))
+                        (next outer))))"#,
+        );
+
+        // seq, fold and next of the outer body, the inner fold's last null is executed once per outer iteration
+        let outer_body = (1 + 2 + 2 + 1) * ASSUMED_FOLD_ITERATIONS;
+        // seq, call and next of the inner body
+        let inner_body = (1 + 10 + 2) * ASSUMED_FOLD_ITERATIONS * ASSUMED_FOLD_ITERATIONS;
+        let expected = ExecutionCost {
+            call_count: 2,
+            fold_depth: 2,
+            instruction_count: 10,
+            estimated_fuel: 1 + 10 + 2 + outer_body + inner_body,
+        };
+        assert_eq!(cost, expected);
+    }
+}
@@ -0,0 +1,103 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::preparation::PreparationResult;
+use super::PreparationError;
+use crate::execution_step::execution_context::instruction_tag;
+
+use air_interpreter_interface::InstructionPolicy;
+use air_interpreter_interface::InstructionTag;
+use air_parser::ast::Instruction;
+use air_parser::ast::Span;
+use air_parser::ast::Visitor;
+use air_parser::AIRLexer;
+use air_parser::Token;
+
+/// Rejects a script if it contains an instruction the policy doesn't allow,
+/// the first forbidden instruction in the script order is reported.
+pub(super) fn check_instruction_policy(
+    air: &Instruction<'_>,
+    raw_air: &str,
+    policy: &InstructionPolicy,
+) -> PreparationResult<()> {
+    if *policy == InstructionPolicy::allow_all() {
+        return Ok(());
+    }
+
+    let Some(instruction) = find_forbidden(air, policy) else {
+        return Ok(());
+    };
+
+    // the AST doesn't keep spans of most instructions, but every instruction starts with
+    // its keyword, so the first keyword of the forbidden instruction is its first occurrence
+    let span = AIRLexer::new(raw_air)
+        .filter_map(Result::ok)
+        .find(|(_, token, _)| token_tag(token) == Some(instruction))
+        .map(|(left, _, right)| Span::new(left, right))
+        .unwrap_or_else(|| Span::new(0.into(), raw_air.len().into()));
+
+    Err(PreparationError::InstructionForbidden { instruction, span })
+}
+
+/// Walks instructions in the script order and returns the first forbidden one.
+fn find_forbidden(instruction: &Instruction<'_>, policy: &InstructionPolicy) -> Option<InstructionTag> {
+    let mut finder = ForbiddenFinder { policy, found: None };
+    instruction.walk(&mut finder);
+    finder.found
+}
+
+struct ForbiddenFinder<'p> {
+    policy: &'p InstructionPolicy,
+    found: Option<InstructionTag>,
+}
+
+impl<'i> Visitor<'i> for ForbiddenFinder<'_> {
+    fn visit_instruction(&mut self, instruction: &Instruction<'i>) {
+        // the parser produces error nodes only for scripts that fail parsing
+        if self.found.is_some() || matches!(instruction, Instruction::Error) {
+            return;
+        }
+
+        let tag = instruction_tag(instruction);
+        if !self.policy.is_allowed(tag) {
+            self.found = Some(tag);
+        }
+    }
+}
+
+fn token_tag(token: &Token<'_>) -> Option<InstructionTag> {
+    let tag = match token {
+        Token::Call => InstructionTag::Call,
+        Token::Canon => InstructionTag::Canon,
+        Token::CanonSorted => InstructionTag::CanonSorted,
+        Token::Ap => InstructionTag::Ap,
+        Token::Seq => InstructionTag::Seq,
+        Token::Par => InstructionTag::Par,
+        Token::Fail => InstructionTag::Fail,
+        Token::Fold => InstructionTag::Fold,
+        Token::Xor => InstructionTag::Xor,
+        Token::Never => InstructionTag::Never,
+        Token::New => InstructionTag::New,
+        Token::Next => InstructionTag::Next,
+        Token::Null => InstructionTag::Null,
+        Token::Match => InstructionTag::Match,
+        Token::MisMatch => InstructionTag::Mismatch,
+        Token::MatchRegex => InstructionTag::MatchRegex,
+        Token::MisMatchRegex => InstructionTag::MismatchRegex,
+        _ => return None,
+    };
+    Some(tag)
+}
@@ -0,0 +1,171 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air_interpreter_data::InterpreterData;
+use once_cell::sync::Lazy;
+use thiserror::Error as ThisError;
+
+/// Converts data produced by an older interpreter into a form the newer one expects.
+pub type MigrateFn = fn(InterpreterData) -> Result<InterpreterData, String>;
+
+/// Migrations shipped with this interpreter, see [./docs/update-guide.md].
+static BUILTIN_MIGRATOR: Lazy<Migrator> = Lazy::new(Migrator::new);
+
+#[derive(Debug, ThisError)]
+#[error("failed to migrate data from `{from}` to `{to}` version of interpreter: {reason}")]
+pub struct MigrationError {
+    pub from: semver::Version,
+    pub to: semver::Version,
+    pub reason: String,
+}
+
+struct Migration {
+    from: semver::Version,
+    to: semver::Version,
+    migrate: MigrateFn,
+}
+
+/// A registry of migrations that are chained to bring data produced by an old interpreter
+/// up to a newer version.
+#[derive(Default)]
+pub struct Migrator {
+    migrations: Vec<Migration>,
+}
+
+impl Migrator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns migrations shipped with this interpreter.
+    pub fn builtin() -> &'static Self {
+        Lazy::force(&BUILTIN_MIGRATOR)
+    }
+
+    /// Registers a migration of data produced by interpreters of versions in `from..to`.
+    pub fn register(&mut self, from: semver::Version, to: semver::Version, migrate: MigrateFn) -> &mut Self {
+        self.migrations.push(Migration { from, to, migrate });
+        self
+    }
+
+    /// Returns a version the data of the supplied version would have after all applicable migrations.
+    pub fn migrated_version(&self, version: &semver::Version) -> semver::Version {
+        let mut version = version.clone();
+        while let Some(migration) = self.find_migration(&version) {
+            version = migration.to.clone();
+        }
+
+        version
+    }
+
+    /// Applies a chain of migrations to data produced by an interpreter of the supplied version.
+    pub fn migrate(
+        &self,
+        mut data: InterpreterData,
+        version: &semver::Version,
+    ) -> Result<InterpreterData, MigrationError> {
+        let mut version = version.clone();
+        while let Some(migration) = self.find_migration(&version) {
+            data = (migration.migrate)(data).map_err(|reason| MigrationError {
+                from: version.clone(),
+                to: migration.to.clone(),
+                reason,
+            })?;
+            version = migration.to.clone();
+        }
+
+        Ok(data)
+    }
+
+    fn find_migration(&self, version: &semver::Version) -> Option<&Migration> {
+        self.migrations
+            .iter()
+            .find(|migration| &migration.from <= version && version < &migration.to)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use air_interpreter_data::CallRequestIdCounter;
+
+    use std::str::FromStr;
+
+    fn version(version: &str) -> semver::Version {
+        semver::Version::from_str(version).unwrap()
+    }
+
+    fn increment_call_request_id(mut data: InterpreterData) -> Result<InterpreterData, String> {
+        data.last_call_request_id = CallRequestIdCounter::new(data.last_call_request_id).next();
+        Ok(data)
+    }
+
+    fn double_call_request_id(mut data: InterpreterData) -> Result<InterpreterData, String> {
+        data.last_call_request_id = (u32::from(data.last_call_request_id) * 2).into();
+        Ok(data)
+    }
+
+    fn fail(_: InterpreterData) -> Result<InterpreterData, String> {
+        Err("unsupported trace".to_string())
+    }
+
+    #[test]
+    fn migrations_are_chained() {
+        let mut migrator = Migrator::new();
+        migrator
+            .register(version("0.2.0"), version("0.3.0"), double_call_request_id)
+            .register(version("0.1.0"), version("0.2.0"), increment_call_request_id);
+
+        let data = InterpreterData {
+            last_call_request_id: 1.into(),
+            ..<_>::default()
+        };
+        let migrated_data = migrator.migrate(data, &version("0.1.5")).unwrap();
+
+        assert_eq!(migrated_data.last_call_request_id, 4.into());
+        assert_eq!(migrator.migrated_version(&version("0.1.5")), version("0.3.0"));
+    }
+
+    #[test]
+    fn data_of_newer_version_is_left_as_is() {
+        let mut migrator = Migrator::new();
+        migrator.register(version("0.1.0"), version("0.2.0"), increment_call_request_id);
+
+        let data = InterpreterData {
+            last_call_request_id: 1.into(),
+            ..<_>::default()
+        };
+        let migrated_data = migrator.migrate(data, &version("0.2.0")).unwrap();
+
+        assert_eq!(migrated_data.last_call_request_id, 1.into());
+        assert_eq!(migrator.migrated_version(&version("0.2.0")), version("0.2.0"));
+    }
+
+    #[test]
+    fn migration_failure_is_reported() {
+        let mut migrator = Migrator::new();
+        migrator
+            .register(version("0.1.0"), version("0.2.0"), increment_call_request_id)
+            .register(version("0.2.0"), version("0.3.0"), fail);
+
+        let error = migrator.migrate(<_>::default(), &version("0.1.0")).unwrap_err();
+
+        assert_eq!(error.from, version("0.2.0"));
+        assert_eq!(error.to, version("0.3.0"));
+        assert_eq!(error.reason, "unsupported trace");
+    }
+}
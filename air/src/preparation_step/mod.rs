@@ -14,18 +14,33 @@
  * limitations under the License.
  */
 
+mod air_cache;
 mod errors;
+mod execution_cost;
+mod instruction_policy;
 mod interpreter_versions;
+mod migration;
 mod preparation;
 mod sizes_limits_check;
 
+pub use air_cache::AirCache;
+pub use air_cache::CachedAir;
+pub use air_cache::ParsedAir;
 pub use errors::PreparationError;
+pub use errors::RecoveryHint;
+pub use execution_cost::ExecutionCost;
+pub use execution_cost::ASSUMED_FOLD_ITERATIONS;
 pub use interpreter_versions::interpreter_version;
 pub use interpreter_versions::min_supported_version;
+pub use migration::MigrateFn;
+pub use migration::MigrationError;
+pub use migration::Migrator;
 
 pub(crate) use preparation::check_version_compatibility;
+pub(crate) use preparation::key_format_policy;
 pub(crate) use preparation::parse_data;
 pub(crate) use preparation::prepare;
 pub(crate) use preparation::ParsedDataPair;
 pub(crate) use preparation::PreparationDescriptor;
 pub(crate) use sizes_limits_check::check_against_size_limits;
+pub(crate) use sizes_limits_check::DataLimits;
@@ -14,6 +14,13 @@
  * limitations under the License.
  */
 
+use super::execution_cost::estimate_cost;
+use super::instruction_policy::check_instruction_policy;
+use super::AirCache;
+use super::DataLimits;
+use super::ExecutionCost;
+use super::Migrator;
+use super::ParsedAir;
 use super::PreparationError;
 use crate::execution_step::execution_context::ExecCtxIngredients;
 use crate::execution_step::ExecutionCtx;
@@ -23,43 +30,80 @@ use air_interpreter_data::DataDeserializationError;
 use air_interpreter_data::InterpreterData;
 use air_interpreter_data::InterpreterDataEnvelope;
 use air_interpreter_data::Versions;
-use air_interpreter_interface::CallResultsRepr;
+use air_interpreter_interface::deserialize_call_results;
+use air_interpreter_interface::CallResultsFormat;
+use air_interpreter_interface::KeyFormatPolicyKind;
+use air_interpreter_interface::LintLevel;
 use air_interpreter_interface::RunParameters;
 use air_interpreter_interface::SerializedCallResults;
 use air_interpreter_interface::SoftLimitsTriggering;
-use air_interpreter_sede::FromSerialized;
 use air_interpreter_signatures::KeyError;
+use air_interpreter_signatures::KeyFormatPolicy;
 use air_interpreter_signatures::KeyPair;
 use air_interpreter_signatures::SignatureStore;
 use air_parser::ast::Instruction;
+use air_parser::lint::Lint;
 use air_utils::measure;
 use fluence_keypair::KeyFormat;
 
+use std::cell::OnceCell;
+use std::cell::RefCell;
+
 pub(crate) type PreparationResult<T> = Result<T, PreparationError>;
 
 /// Represents result of the preparation_step step.
 pub(crate) struct PreparationDescriptor<'ctx, 'i> {
     pub(crate) exec_ctx: ExecutionCtx<'ctx>,
     pub(crate) trace_handler: TraceHandler,
-    pub(crate) air: Instruction<'i>,
+    pub(crate) air: ParsedAir<'i>,
     pub(crate) keypair: KeyPair,
 }
 
+impl PreparationDescriptor<'_, '_> {
+    /// Estimates the cost of executing the script, e.g. to schedule cheap particles first.
+    pub(crate) fn estimated_cost(&self) -> ExecutionCost {
+        estimate_cost(self.air.ast())
+    }
+
+    /// Fingerprint of the parsed script, it's the same for scripts that differ only in formatting
+    /// and variable names. It's computed on request, since most calls don't need it.
+    pub(crate) fn air_fingerprint(&self) -> [u8; 32] {
+        self.air.fingerprint()
+    }
+}
+
 pub(crate) struct ParsedDataPair {
     pub(crate) prev_data: InterpreterData,
     pub(crate) current_data: InterpreterData,
 }
 
-/// Parse data and check its version.
+/// Parse data, migrate it to the current version and check the version.
 #[tracing::instrument(skip_all)]
-pub(crate) fn parse_data(prev_data: &[u8], current_data: &[u8]) -> PreparationResult<ParsedDataPair> {
+pub(crate) fn parse_data(
+    prev_data: &[u8],
+    current_data: &[u8],
+    data_limits: &DataLimits,
+) -> PreparationResult<ParsedDataPair> {
+    // prev_data is produced by this peer, so only current_data is limited
+    data_limits.check_data_size(current_data)?;
+
     let prev_envelope = try_to_envelope(prev_data)?;
     let current_envelope = try_to_envelope(current_data)?;
 
-    check_version_compatibility(&current_envelope.versions)?;
+    // data that can't be migrated to a supported version is rejected before deserialization
+    let migrated_versions = Versions {
+        data_version: current_envelope.versions.data_version.clone(),
+        interpreter_version: Migrator::builtin().migrated_version(&current_envelope.versions.interpreter_version),
+    };
+    check_version_compatibility(&migrated_versions)?;
 
-    let prev_data = try_to_data(&prev_envelope.inner_data)?;
-    let current_data = try_to_data(&current_envelope.inner_data)?;
+    let mut prev_data = try_to_data(&prev_envelope.inner_data, &prev_envelope.versions)?;
+    let mut current_data = try_to_data(&current_envelope.inner_data, &current_envelope.versions)?;
+    data_limits.check_trace_len(&current_data)?;
+
+    // compacted traces are expanded before verification, so that states are met by their positions
+    prev_data.trace.expand().map_err(to_data_de_error)?;
+    current_data.trace.expand().map_err(to_data_de_error)?;
 
     Ok(ParsedDataPair {
         prev_data,
@@ -78,7 +122,11 @@ pub(crate) fn prepare<'i>(
     signature_store: SignatureStore,
     soft_limits_triggering: &mut SoftLimitsTriggering,
 ) -> PreparationResult<PreparationDescriptor<'static, 'i>> {
-    let air: Instruction<'i> = air_parser::parse(raw_air).map_err(PreparationError::AIRParseError)?;
+    check_particle_expiration(&run_parameters)?;
+    let air = parse_air(raw_air, run_parameters.air_cache_capacity)?;
+    check_lints(air.ast(), run_parameters.lint_level)?;
+    check_instruction_policy(air.ast(), raw_air, &run_parameters.instruction_policy())?;
+    check_instruction_count(air.ast(), run_parameters.max_instruction_count())?;
 
     let prev_ingredients = ExecCtxIngredients {
         last_call_request_id: prev_data.last_call_request_id,
@@ -101,7 +149,8 @@ pub(crate) fn prepare<'i>(
     let trace_handler = TraceHandler::from_trace(prev_data.trace, current_data.trace);
 
     let key_format = KeyFormat::try_from(run_parameters.key_format).map_err(KeyError::from)?;
-    let keypair = KeyPair::from_secret_key(run_parameters.secret_key_bytes, key_format)?;
+    let key_format_policy = key_format_policy(run_parameters.key_format_policy)?;
+    let keypair = KeyPair::from_secret_key_with_policy(run_parameters.secret_key_bytes, key_format, key_format_policy)?;
 
     let result = PreparationDescriptor {
         exec_ctx,
@@ -113,6 +162,91 @@ pub(crate) fn prepare<'i>(
     Ok(result)
 }
 
+thread_local!(static AIR_CACHE: OnceCell<RefCell<AirCache>> = const { OnceCell::new() });
+
+/// Parses a script, the interpreter outlives calls, so parsed scripts are cached between them.
+/// The cache is sized by the first call that enables it, since a host sets the capacity once.
+fn parse_air(raw_air: &str, cache_capacity: u64) -> PreparationResult<ParsedAir<'_>> {
+    let capacity =
+        usize::try_from(cache_capacity).map_err(|_| PreparationError::AirCacheCapacityTooLarge { cache_capacity })?;
+    let result = if capacity == 0 {
+        air_parser::parse(raw_air).map(ParsedAir::Uncached)
+    } else {
+        AIR_CACHE.with(|cache| {
+            cache
+                .get_or_init(|| RefCell::new(AirCache::new(capacity)))
+                .borrow_mut()
+                .get_or_parse(raw_air)
+        })
+    };
+    result.map_err(PreparationError::AIRParseError)
+}
+
+/// Rejects a script with more instructions than the limit, so huge scripts don't start executing.
+fn check_instruction_count(air: &Instruction<'_>, limit: Option<u64>) -> PreparationResult<()> {
+    let Some(limit) = limit else {
+        return Ok(());
+    };
+
+    let actual = air.instruction_count();
+    if actual > limit {
+        return Err(PreparationError::TooManyInstructions { actual, limit });
+    }
+    Ok(())
+}
+
+/// Rejects a particle that has been in flight longer than its TTL.
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+fn check_particle_expiration(run_parameters: &RunParameters) -> PreparationResult<()> {
+    if !run_parameters.reject_expired_particles {
+        return Ok(());
+    }
+    // a particle expiring beyond the representable time never expires
+    let Some(expired_at) = run_parameters.particle_expires_at() else {
+        return Ok(());
+    };
+
+    if expired_at < std::time::SystemTime::now() {
+        return Err(PreparationError::ParticleExpired { expired_at });
+    }
+    Ok(())
+}
+
+/// There is no clock on this target, so the TTL is enforced only by a host.
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+fn check_particle_expiration(_run_parameters: &RunParameters) -> PreparationResult<()> {
+    Ok(())
+}
+
+/// Maps the key format policy passed in run parameters to the one used for key validation.
+pub(crate) fn key_format_policy(key_format_policy: u8) -> PreparationResult<KeyFormatPolicy> {
+    let policy = match KeyFormatPolicyKind::try_from(key_format_policy)? {
+        KeyFormatPolicyKind::Ed25519Only => KeyFormatPolicy::Ed25519Only,
+        KeyFormatPolicyKind::Any => KeyFormatPolicy::Any,
+    };
+    Ok(policy)
+}
+
+/// Rejects a script if it has lints that aren't allowed by the supplied lint level.
+fn check_lints(air: &Instruction<'_>, lint_level: u8) -> PreparationResult<()> {
+    let is_denied: fn(&Lint) -> bool = match LintLevel::try_from(lint_level)? {
+        LintLevel::Allow => return Ok(()),
+        LintLevel::DenyErrors => Lint::is_error,
+        LintLevel::DenyWarnings => |_| true,
+    };
+
+    let denied_lints = air_parser::lint::lint(air)
+        .into_iter()
+        .filter(is_denied)
+        .map(|lint| lint.to_string())
+        .collect::<Vec<_>>();
+    if denied_lints.is_empty() {
+        return Ok(());
+    }
+
+    Err(PreparationError::LintsDenied(denied_lints.join("\n")))
+}
+
 pub(crate) fn try_to_envelope(raw_env_data: &[u8]) -> PreparationResult<InterpreterDataEnvelope<'_>> {
     // treat empty slice as an empty data,
     // it allows abstracting from an internal format for an empty data
@@ -124,8 +258,12 @@ pub(crate) fn try_to_envelope(raw_env_data: &[u8]) -> PreparationResult<Interpre
         .map_err(|de_error| to_envelope_de_error(raw_env_data.to_vec(), de_error))
 }
 
-pub(crate) fn try_to_data(raw_data: &[u8]) -> PreparationResult<InterpreterData> {
-    InterpreterData::try_from_slice(raw_data).map_err(to_data_de_error)
+/// Deserializes data and migrates it from the interpreter version it was produced by.
+pub(crate) fn try_to_data(raw_data: &[u8], versions: &Versions) -> PreparationResult<InterpreterData> {
+    let data = InterpreterData::try_from_slice(raw_data).map_err(to_data_de_error)?;
+    let data = Migrator::builtin().migrate(data, &versions.interpreter_version)?;
+
+    Ok(data)
 }
 
 fn to_envelope_de_error(env_raw_data: Vec<u8>, de_error: DataDeserializationError) -> PreparationError {
@@ -150,12 +288,12 @@ fn make_exec_ctx(
 ) -> PreparationResult<ExecutionCtx<'static>> {
     use crate::preparation_step::sizes_limits_check::handle_limit_exceeding;
 
+    let call_results_format = CallResultsFormat::try_from(run_parameters.call_results_format)?;
     let call_results = measure!(
-        CallResultsRepr
-            .deserialize(call_results)
+        deserialize_call_results(call_results, call_results_format)
             .map_err(PreparationError::call_results_de_failed)?,
         tracing::Level::INFO,
-        "CallResultsRepr.deserialize",
+        "deserialize_call_results",
     );
 
     // This is a part of argument size limit check where we check the size of every call result.
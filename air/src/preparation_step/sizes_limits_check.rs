@@ -17,8 +17,60 @@
 use super::preparation::PreparationResult;
 use crate::PreparationError;
 
+use air_interpreter_data::InterpreterData;
 use air_interpreter_interface::RunParameters;
 use air_interpreter_interface::SoftLimitsTriggering;
+use air_interpreter_interface::UNLIMITED_DATA;
+
+/// Hard limits on incoming data, unlike the particle size limit they fail a particle
+/// regardless of `RunParameters::hard_limit_enabled`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct DataLimits {
+    /// The maximum size of serialized current data in bytes.
+    pub(crate) max_data_bytes: Option<usize>,
+    /// The maximum number of states in the current data trace.
+    pub(crate) max_trace_len: Option<usize>,
+}
+
+impl DataLimits {
+    pub(crate) fn from_run_parameters(run_parameters: &RunParameters) -> Self {
+        Self {
+            max_data_bytes: to_limit(run_parameters.max_data_bytes),
+            max_trace_len: to_limit(run_parameters.max_trace_len),
+        }
+    }
+
+    /// Checks the size of raw data, it's intended to be called before deserialization.
+    pub(crate) fn check_data_size(&self, raw_data: &[u8]) -> PreparationResult<()> {
+        match self.max_data_bytes {
+            Some(limit) if raw_data.len() > limit => Err(PreparationError::DataTooLarge {
+                actual: raw_data.len(),
+                limit,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks the trace length as if the trace was expanded, so that a few repeated states
+    /// can't bypass the limit.
+    pub(crate) fn check_trace_len(&self, data: &InterpreterData) -> PreparationResult<()> {
+        let Some(limit) = self.max_trace_len else {
+            return Ok(());
+        };
+
+        let actual = data.trace.expanded_len();
+        if actual > limit {
+            return Err(PreparationError::TraceTooLong { actual, limit });
+        }
+
+        Ok(())
+    }
+}
+
+// limits that don't fit into usize can't be exceeded anyway
+fn to_limit(limit: u64) -> Option<usize> {
+    (limit != UNLIMITED_DATA).then(|| limit.try_into().ok()).flatten()
+}
 
 pub(crate) fn handle_limit_exceeding(
     run_parameters: &RunParameters,
@@ -0,0 +1,184 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Step-by-step replay of a recorded execution.
+
+use crate::execution_step::execution_context::ExecCtxIngredients;
+use crate::execution_step::ExecutableInstruction;
+use crate::execution_step::ExecutionCtx;
+use crate::execution_step::TraceHandler;
+
+use air_interpreter_data::CallRequestId;
+use air_interpreter_data::InterpreterData;
+use air_interpreter_interface::CallRequests;
+use air_interpreter_interface::RunParameters;
+
+use std::collections::VecDeque;
+
+/// Replays execution of an AIR script against a recorded trace.
+///
+/// The trace is used as the previous data and no call results are supplied, so
+/// every executed call takes its result from the trace and nothing is sent to services.
+/// The whole script is replayed eagerly on construction, `step` then yields the
+/// recorded events in the order instructions were dispatched.
+pub struct Replayer {
+    events: VecDeque<ReplayEvent>,
+    error: Option<String>,
+}
+
+/// Describes a single instruction dispatch met during a replay.
+#[derive(Debug, Clone)]
+pub struct ReplayEvent {
+    instruction: String,
+    variables: String,
+    call_requests: CallRequests,
+    last_call_request_id: CallRequestId,
+}
+
+impl Replayer {
+    /// Replays the script with the empty init and current peer ids.
+    pub fn new(air: &str, data: InterpreterData) -> Self {
+        let run_parameters = RunParameters::builder()
+            .init_peer_id("")
+            .current_peer_id("")
+            .timestamp(0)
+            .ttl(0)
+            .air_size_limit(u64::MAX)
+            .particle_size_limit(u64::MAX)
+            .call_result_size_limit(u64::MAX)
+            .build()
+            .expect("all required run parameters are set");
+
+        Self::with_run_parameters(air, data, &run_parameters)
+    }
+
+    /// Replays the script on behalf of a peer described by the run parameters,
+    /// it allows to reproduce calls whose peer id is resolved from them.
+    pub fn with_run_parameters(air: &str, data: InterpreterData, run_parameters: &RunParameters) -> Self {
+        let instruction = match air_parser::parse(air) {
+            Ok(instruction) => instruction,
+            Err(error) => {
+                return Self {
+                    events: <_>::default(),
+                    error: Some(error.to_string()),
+                }
+            }
+        };
+
+        let mut trace = data.trace;
+        if let Err(error) = trace.expand() {
+            return Self {
+                events: <_>::default(),
+                error: Some(error.to_string()),
+            };
+        }
+
+        let prev_ingredients = ExecCtxIngredients {
+            last_call_request_id: data.last_call_request_id,
+            cid_info: data.cid_info,
+        };
+        let current_ingredients = ExecCtxIngredients {
+            last_call_request_id: <_>::default(),
+            cid_info: <_>::default(),
+        };
+
+        let mut exec_ctx = ExecutionCtx::new(
+            prev_ingredients,
+            current_ingredients,
+            <_>::default(),
+            <_>::default(),
+            run_parameters,
+        );
+        exec_ctx.replay_recorder = Some(<_>::default());
+        let mut trace_handler = TraceHandler::from_trace(trace, <_>::default());
+
+        let error = instruction
+            .execute(&mut exec_ctx, &mut trace_handler)
+            .err()
+            .map(|error| error.to_string());
+        let events = exec_ctx.replay_recorder.take().unwrap_or_default().into_events();
+
+        Self {
+            events: events.into(),
+            error,
+        }
+    }
+
+    /// Returns the next dispatched instruction or None if the replay is over.
+    pub fn step(&mut self) -> Option<ReplayEvent> {
+        self.events.pop_front()
+    }
+
+    /// Returns an error that stopped the replay, it's either a parse error or an execution one.
+    pub fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
+
+impl ReplayEvent {
+    /// Returns the dispatched instruction without its subinstructions, e.g. `seq` or `call`.
+    pub fn instruction(&self) -> &str {
+        &self.instruction
+    }
+
+    /// Returns scalars, streams and stream maps as they were right before the dispatch.
+    pub fn variables(&self) -> &str {
+        &self.variables
+    }
+
+    /// Returns call requests emitted by the instruction, including ones emitted by its subinstructions.
+    pub fn call_requests(&self) -> &CallRequests {
+        &self.call_requests
+    }
+}
+
+/// Collects replay events while an instruction tree is executed.
+#[derive(Debug, Default)]
+pub(crate) struct ReplayRecorder {
+    events: Vec<ReplayEvent>,
+}
+
+impl ReplayRecorder {
+    /// Returns a position of the recorded event to complete it later.
+    pub(crate) fn record_dispatch(
+        &mut self,
+        instruction: String,
+        variables: String,
+        last_call_request_id: CallRequestId,
+    ) -> usize {
+        self.events.push(ReplayEvent {
+            instruction,
+            variables,
+            call_requests: <_>::default(),
+            last_call_request_id,
+        });
+
+        self.events.len() - 1
+    }
+
+    pub(crate) fn record_completion(&mut self, position: usize, call_requests: &CallRequests) {
+        let event = &mut self.events[position];
+        event.call_requests = call_requests
+            .iter()
+            .filter(|(&id, _)| CallRequestId::from(id) > event.last_call_request_id)
+            .map(|(&id, request)| (id, request.clone()))
+            .collect();
+    }
+
+    pub(crate) fn into_events(self) -> Vec<ReplayEvent> {
+        self.events
+    }
+}
@@ -14,10 +14,16 @@
  * limitations under the License.
  */
 
+use crate::debug::PauseRecorder;
+use crate::execution_step::Cancellation;
 use crate::execution_step::ExecutableInstruction;
+use crate::execution_step::ExecutionTimer;
 use crate::farewell_step as farewell;
+use crate::observer::ExecutionObserver;
+use crate::observer::ExecutionTreeObserver;
 use crate::preparation_step::parse_data;
 use crate::preparation_step::prepare;
+use crate::preparation_step::DataLimits;
 use crate::preparation_step::ParsedDataPair;
 use crate::preparation_step::PreparationDescriptor;
 use crate::signing_step::sign_produced_cids;
@@ -41,6 +47,92 @@ pub fn execute_air(
 ) -> InterpreterOutcome {
     use std::convert::identity;
 
+    log_run_parameters(&params);
+    execute_air_impl(air, prev_data, data, params, call_results, <_>::default()).unwrap_or_else(identity)
+}
+
+/// Executes AIR script like `execute_air`, but polls `is_cancelled` at each instruction boundary
+/// and stops execution with `UncatchableError::Cancelled` once it returns true.
+#[tracing::instrument(skip_all)]
+pub fn execute_air_with_cancellation(
+    air: String,
+    prev_data: Vec<u8>,
+    data: Vec<u8>,
+    params: RunParameters,
+    call_results: SerializedCallResults,
+    is_cancelled: impl Fn() -> bool + 'static,
+) -> InterpreterOutcome {
+    use std::convert::identity;
+
+    log_run_parameters(&params);
+    execute_air_impl(
+        air,
+        prev_data,
+        data,
+        params,
+        call_results,
+        ExecutionHooks {
+            cancellation: Some(Box::new(is_cancelled)),
+            ..<_>::default()
+        },
+    )
+    .unwrap_or_else(identity)
+}
+
+/// Executes AIR script like `execute_air`, but passes call and instruction events
+/// to the observer.
+#[tracing::instrument(skip_all)]
+pub fn execute_air_with_observer(
+    air: String,
+    prev_data: Vec<u8>,
+    data: Vec<u8>,
+    params: RunParameters,
+    call_results: SerializedCallResults,
+    observer: Box<dyn ExecutionObserver>,
+) -> InterpreterOutcome {
+    use std::convert::identity;
+
+    log_run_parameters(&params);
+    execute_air_impl(
+        air,
+        prev_data,
+        data,
+        params,
+        call_results,
+        ExecutionHooks {
+            observer: Some(observer),
+            ..<_>::default()
+        },
+    )
+    .unwrap_or_else(identity)
+}
+
+/// Executes AIR script like `execute_air`, but also puts a tree of executed instructions
+/// with time spent in each of them into the outcome.
+#[tracing::instrument(skip_all)]
+pub fn execute_air_traced(
+    air: String,
+    prev_data: Vec<u8>,
+    data: Vec<u8>,
+    params: RunParameters,
+    call_results: SerializedCallResults,
+) -> InterpreterOutcome {
+    let observer = ExecutionTreeObserver::new();
+    let outcome = execute_air_with_observer(air, prev_data, data, params, call_results, Box::new(observer.clone()));
+
+    let execution_tree = serde_json::to_vec(&observer.execution_tree()).expect("default serializer shouldn't fail");
+    outcome.with_execution_tree(execution_tree)
+}
+
+/// Optional host hooks installed into the execution context.
+#[derive(Default)]
+pub(crate) struct ExecutionHooks {
+    pub(crate) cancellation: Option<Cancellation>,
+    pub(crate) pause_recorder: Option<PauseRecorder>,
+    pub(crate) observer: Option<Box<dyn ExecutionObserver>>,
+}
+
+fn log_run_parameters(params: &RunParameters) {
     log::trace!(
         target: RUN_PARAMS,
         "air interpreter version is {}, run parameters:\
@@ -50,28 +142,30 @@ pub fn execute_air(
         params.init_peer_id,
         params.current_peer_id,
     );
-
-    execute_air_impl(air, prev_data, data, params, call_results).unwrap_or_else(identity)
 }
 
 #[allow(clippy::result_large_err)]
-fn execute_air_impl(
-    air: String,
+pub(crate) fn execute_air_impl(
+    raw_air: String,
     raw_prev_data: Vec<u8>,
     raw_current_data: Vec<u8>,
     params: RunParameters,
     call_results: SerializedCallResults,
+    hooks: ExecutionHooks,
 ) -> Result<InterpreterOutcome, InterpreterOutcome> {
     use crate::preparation_step::check_against_size_limits;
 
+    // the timer is started here, so preparation counts towards the time limit too
+    let execution_timer = ExecutionTimer::start(params.max_execution_time());
+
     let mut soft_limits_triggering = farewell_if_fail!(
-        check_against_size_limits(&params, &air, &raw_current_data),
+        check_against_size_limits(&params, &raw_air, &raw_current_data),
         raw_prev_data,
         SoftLimitsTriggering::default()
     );
 
     farewell_if_fail!(
-        check_against_size_limits(&params, &air, &raw_current_data),
+        check_against_size_limits(&params, &raw_air, &raw_current_data),
         raw_prev_data,
         soft_limits_triggering
     );
@@ -80,7 +174,11 @@ fn execute_air_impl(
         prev_data,
         current_data,
     } = farewell_if_fail!(
-        parse_data(&raw_prev_data, &raw_current_data),
+        parse_data(
+            &raw_prev_data,
+            &raw_current_data,
+            &DataLimits::from_run_parameters(&params)
+        ),
         raw_prev_data,
         soft_limits_triggering
     );
@@ -89,21 +187,22 @@ fn execute_air_impl(
     // as partical ID can be equally replayed
     let salt = params.particle_id.clone();
     let signature_store = farewell_if_fail!(
-        verify(&prev_data, &current_data, &salt),
+        verify(
+            &prev_data,
+            &current_data,
+            &salt,
+            params.signature_policy,
+            params.key_format_policy
+        ),
         raw_prev_data,
         soft_limits_triggering
     );
 
-    let PreparationDescriptor {
-        mut exec_ctx,
-        mut trace_handler,
-        air,
-        keypair,
-    } = farewell_if_fail!(
+    let descriptor = farewell_if_fail!(
         prepare(
             prev_data,
             current_data,
-            &air,
+            &raw_air,
             &call_results,
             params,
             signature_store,
@@ -112,15 +211,42 @@ fn execute_air_impl(
         raw_prev_data,
         soft_limits_triggering
     );
+    log::trace!(target: RUN_PARAMS, "estimated cost {:?}", descriptor.estimated_cost());
+    if log::log_enabled!(target: RUN_PARAMS, log::Level::Trace) {
+        log::trace!(
+            target: RUN_PARAMS,
+            "air fingerprint {}",
+            descriptor.air_fingerprint().iter().fold(String::new(), |mut hex, byte| {
+                use std::fmt::Write;
+
+                let _ = write!(hex, "{byte:02x}");
+                hex
+            })
+        );
+    }
+
+    let PreparationDescriptor {
+        mut exec_ctx,
+        mut trace_handler,
+        air,
+        keypair,
+    } = descriptor;
+    exec_ctx.cancellation = hooks.cancellation;
+    exec_ctx.pause_recorder = hooks.pause_recorder;
+    exec_ctx.observer = hooks.observer;
+    exec_ctx.execution_timer = execution_timer;
+    exec_ctx.notify_observer(|observer, ctx| observer.on_call_start(ctx));
 
     // match here is used instead of map_err, because the compiler can't determine that
     // they are exclusive and would treat exec_ctx and trace_handler as moved
     let exec_result = measure!(
-        air.execute(&mut exec_ctx, &mut trace_handler),
+        air.ast().execute(&mut exec_ctx, &mut trace_handler),
         tracing::Level::INFO,
         "execute",
     );
+    exec_ctx.notify_observer(|observer, ctx| observer.on_call_end(ctx));
 
+    let fuel_consumed = exec_ctx.fuel_consumed();
     farewell_if_fail!(
         sign_produced_cids(
             &mut exec_ctx.peer_cid_tracker,
@@ -129,30 +255,38 @@ fn execute_air_impl(
             &keypair,
         ),
         raw_prev_data,
-        soft_limits_triggering
+        soft_limits_triggering,
+        fuel_consumed
     );
 
-    measure!(
+    // a host routes next rounds of a cached script to this interpreter by its fingerprint
+    let air_fingerprint = air.cached_fingerprint().map(Vec::from).unwrap_or_default();
+    let outcome = measure!(
         match exec_result {
             Ok(_) => farewell::from_success_result(exec_ctx, trace_handler, &keypair, soft_limits_triggering),
-            // return new collected trace in case of errors
-            Err(error) if error.is_catchable() => {
-                Err(farewell::from_execution_error(
+            Err(error) => match exec_ctx.with_error_context(error, air.ast(), &raw_air) {
+                // return new collected trace in case of errors
+                error if error.error.is_catchable() => Err(farewell::from_execution_error(
                     exec_ctx,
                     trace_handler,
                     error,
                     &keypair,
                     soft_limits_triggering,
-                ))
-            }
-            // return the prev data in case of any trace errors
-            Err(error) => Err(farewell::from_uncatchable_error(
-                raw_prev_data,
-                error,
-                soft_limits_triggering
-            )),
+                )),
+                // return the prev data in case of any trace errors
+                error => Err(farewell::from_uncatchable_execution_error(
+                    raw_prev_data,
+                    error,
+                    soft_limits_triggering,
+                    fuel_consumed
+                )),
+            },
         },
         tracing::Level::INFO,
         "farewell",
-    )
+    );
+    match outcome {
+        Ok(outcome) => Ok(outcome.with_air_fingerprint(air_fingerprint)),
+        Err(outcome) => Err(outcome.with_air_fingerprint(air_fingerprint)),
+    }
 }
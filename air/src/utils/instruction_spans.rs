@@ -0,0 +1,80 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air_parser::ast::Span;
+use air_parser::AIRLexer;
+use air_parser::Token;
+
+/// Returns spans of instructions in the script order, i.e. in the pre-order of the AST.
+/// Every instruction begins with an opening bracket followed by its keyword and ends with
+/// the matching closing bracket, a script that fails lexing gets only spans met before the error.
+pub(crate) fn instruction_spans(air: &str) -> Vec<Span> {
+    let mut spans: Vec<Span> = vec![];
+    // indices of instruction spans for open brackets, None is for brackets of other kinds
+    let mut open_brackets: Vec<Option<usize>> = vec![];
+    let mut last_bracket_start = None;
+
+    for token in AIRLexer::new(air) {
+        let Ok((start, token, end)) = token else { break };
+
+        match token {
+            Token::OpenRoundBracket => {
+                open_brackets.push(None);
+                last_bracket_start = Some(start);
+                continue;
+            }
+            Token::CloseRoundBracket => {
+                if let Some(Some(span_id)) = open_brackets.pop() {
+                    spans[span_id].right = end;
+                }
+            }
+            token if is_keyword(&token) => {
+                if let (Some(left), Some(bracket)) = (last_bracket_start, open_brackets.last_mut()) {
+                    *bracket = Some(spans.len());
+                    spans.push(Span::new(left, end));
+                }
+            }
+            _ => {}
+        }
+
+        last_bracket_start = None;
+    }
+
+    spans
+}
+
+fn is_keyword(token: &Token<'_>) -> bool {
+    matches!(
+        token,
+        Token::Call
+            | Token::Canon
+            | Token::CanonSorted
+            | Token::Ap
+            | Token::Seq
+            | Token::Par
+            | Token::Fail
+            | Token::Fold
+            | Token::Xor
+            | Token::Never
+            | Token::New
+            | Token::Next
+            | Token::Null
+            | Token::Match
+            | Token::MisMatch
+            | Token::MatchRegex
+            | Token::MisMatchRegex
+    )
+}
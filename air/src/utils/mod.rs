@@ -15,7 +15,9 @@
  */
 
 mod error_codes;
+mod instruction_spans;
 mod to_error_code;
 
 pub(crate) use error_codes::*;
+pub(crate) use instruction_spans::instruction_spans;
 pub use to_error_code::ToErrorCode;
@@ -26,26 +26,62 @@ pub(crate) fn verify(
     prev_data: &InterpreterData,
     current_data: &InterpreterData,
     salt: &str,
+    signature_policy: u8,
+    key_format_policy: u8,
 ) -> Result<SignatureStore, PreparationError> {
+    use crate::preparation_step::key_format_policy as to_key_format_policy;
     use air_interpreter_data::verification;
+    use air_interpreter_interface::SignaturePolicyKind;
+    use air_interpreter_signatures::SignaturePolicy;
+    use air_interpreter_signatures::VerifyAll;
+    use air_interpreter_signatures::VerifyNone;
+
+    let signature_policy: &dyn SignaturePolicy = match SignaturePolicyKind::try_from(signature_policy)? {
+        SignaturePolicyKind::VerifyAll => &VerifyAll,
+        SignaturePolicyKind::VerifyNone => &VerifyNone,
+    };
+
+    let key_format_policy = to_key_format_policy(key_format_policy)?;
 
     current_data.cid_info.verify()?;
 
-    let prev_data_verifier = verification::DataVerifier::new(prev_data, salt)?;
-    let current_data_verifier = verification::DataVerifier::new(current_data, salt)?;
+    let prev_data_verifier = verification::DataVerifier::with_key_format_policy(prev_data, salt, key_format_policy)?;
+    let current_data_verifier =
+        verification::DataVerifier::with_key_format_policy(current_data, salt, key_format_policy)?;
     // prev_data is always correct, check only current_data
-    current_data_verifier.verify()?;
+    verify_current_data(&current_data_verifier, signature_policy)?;
 
     let signature_store = prev_data_verifier.merge(current_data_verifier)?;
     Ok(signature_store)
 }
 
+#[cfg(all(feature = "check_signatures", not(feature = "parallel_verification")))]
+fn verify_current_data(
+    verifier: &air_interpreter_data::verification::DataVerifier<'_>,
+    signature_policy: &dyn air_interpreter_signatures::SignaturePolicy,
+) -> Result<(), PreparationError> {
+    Ok(verifier.verify_with_policy(signature_policy)?)
+}
+
+#[cfg(feature = "parallel_verification")]
+fn verify_current_data(
+    verifier: &air_interpreter_data::verification::DataVerifier<'_>,
+    signature_policy: &dyn air_interpreter_signatures::SignaturePolicy,
+) -> Result<(), PreparationError> {
+    Ok(verifier.verify_parallel_with_policy(signature_policy)?)
+}
+
 #[cfg(not(feature = "check_signatures"))]
 #[tracing::instrument(skip_all)]
 pub(crate) fn verify(
     _prev_data: &InterpreterData,
     _current_data: &InterpreterData,
     _salt: &str,
+    signature_policy: u8,
+    key_format_policy: u8,
 ) -> Result<SignatureStore, PreparationError> {
+    // the policies are validated even if there is nothing to verify
+    air_interpreter_interface::SignaturePolicyKind::try_from(signature_policy)?;
+    crate::preparation_step::key_format_policy(key_format_policy)?;
     Ok(<_>::default())
 }
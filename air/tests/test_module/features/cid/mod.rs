@@ -193,3 +193,70 @@ fn test_unused_cid() {
     assert!(data.cid_info.tetraplet_store.is_empty());
     assert!(data.cid_info.service_result_store.is_empty());
 }
+
+#[test]
+fn test_identical_values_stored_once() {
+    let vm_peer_id = "vm_peer_id";
+    let mut vm = create_avm(set_variable_call_service(json!("same value")), vm_peer_id);
+
+    let air_script = format!(
+        r#"
+       (seq
+          (seq
+             (call "{vm_peer_id}" ("service" "call1") [] x)
+             (call "{vm_peer_id}" ("service" "call2") [] $s))
+          (seq
+             (call "{vm_peer_id}" ("service" "call3") [] $s)
+             (call "{vm_peer_id}" ("service" "call3") [] $s)))"#
+    );
+
+    let result = checked_call_vm!(vm, <_>::default(), &air_script, "", "");
+    let data = data_from_result(&result);
+
+    assert_eq!(data.trace.len(), 4);
+    // all four results refer to the one value stored in the value store
+    assert_eq!(data.cid_info.value_store.len(), 1);
+    // the third and the fourth calls have identical tetraplets and arguments
+    assert_eq!(data.cid_info.tetraplet_store.len(), 3);
+    assert_eq!(data.cid_info.service_result_store.len(), 3);
+
+    // references survive a round trip with the previous data
+    let result = checked_call_vm!(vm, <_>::default(), &air_script, result.data, "");
+    let data = data_from_result(&result);
+    assert_eq!(data.trace.len(), 4);
+    assert_eq!(data.cid_info.value_store.len(), 1);
+}
+
+#[test]
+fn test_unreachable_cids_are_collected() {
+    let peer_id = "peer_id";
+    let mut vm = create_avm(echo_call_service(), peer_id);
+
+    let air_script = r#"(call "peer_id" ("service" "call1") [] x)"#;
+    let mut cid_state = ExecutionCidState::new();
+    let trace = vec![scalar_tracked!(
+        42,
+        cid_state,
+        peer = peer_id,
+        service = "service",
+        function = "call1"
+    )];
+    let expected_cid_state = cid_state.clone();
+    // this result isn't referenced from the trace anymore
+    let _ = scalar_tracked!(43, cid_state, peer = peer_id, service = "service", function = "call2");
+
+    let prev_data = raw_data_from_trace(trace.clone(), cid_state);
+    let result = checked_call_vm!(vm, <_>::default(), air_script, prev_data, "");
+    let data = data_from_result(&result);
+
+    assert_eq!(data.trace, ExecutionTrace::from(trace));
+    assert_eq!(data.cid_info.value_store, expected_cid_state.value_tracker.into());
+    assert_eq!(
+        data.cid_info.tetraplet_store,
+        expected_cid_state.tetraplet_tracker.into()
+    );
+    assert_eq!(
+        data.cid_info.service_result_store,
+        expected_cid_state.service_result_agg_tracker.into(),
+    );
+}
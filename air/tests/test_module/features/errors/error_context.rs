@@ -0,0 +1,104 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air::UncatchableError::FuelExhausted;
+use air_interpreter_interface::ErrorContext;
+use air_interpreter_interface::InstructionTag;
+use air_test_utils::key_utils::derive_dummy_keypair;
+use air_test_utils::prelude::*;
+
+fn error_source<'s>(script: &'s str, error_context: &ErrorContext) -> &'s str {
+    &script[error_context.span_start..error_context.span_end]
+}
+
+#[test]
+fn error_context_points_to_failed_instruction() {
+    let peer_id = "peer_id";
+    let mut peer_vm = create_avm(unit_call_service(), peer_id);
+
+    let script = r#"
+        (seq
+            (call "peer_id" ("" "") [] result)
+            (seq
+                (null)
+                (fail 1337 "some message")
+            )
+        )"#;
+
+    let result = call_vm!(peer_vm, <_>::default(), script, "", "");
+    let error_context = result.error_context.expect("error context should be set");
+    assert_eq!(error_context.instruction, InstructionTag::Fail);
+    assert_eq!(error_source(script, &error_context), r#"(fail 1337 "some message")"#);
+}
+
+#[test]
+fn error_context_ignores_errors_caught_by_xor() {
+    let peer_id = "peer_id";
+    let mut peer_vm = create_avm(unit_call_service(), peer_id);
+
+    let script = r#"
+        (seq
+            (xor
+                (match 1 2 (null))
+                (null)
+            )
+            (seq
+                (null)
+                (mismatch 3 3 (null))
+            )
+        )"#;
+
+    let result = call_vm!(peer_vm, <_>::default(), script, "", "");
+    let error_context = result.error_context.expect("error context should be set");
+    assert_eq!(error_context.instruction, InstructionTag::Mismatch);
+    assert_eq!(error_source(script, &error_context), "(mismatch 3 3 (null))");
+}
+
+#[test]
+fn error_context_of_uncatchable_error() {
+    let vm_peer_id = "vm_peer_id";
+    let (keypair, _) = derive_dummy_keypair(vm_peer_id);
+    let test_init_parameters = TestInitParameters {
+        fuel_limit: Some(2),
+        ..<_>::default()
+    };
+    let mut peer_vm = create_avm_with_key::<DefaultAirRunner>(keypair, unit_call_service(), test_init_parameters);
+
+    let script = r#"
+        (seq
+            (null)
+            (ap 1 scalar)
+        )"#;
+
+    let result = peer_vm.call(script, "", "", <_>::default()).unwrap();
+    let expected_error = FuelExhausted { consumed: 2 };
+    assert!(check_error(&result, expected_error));
+
+    let error_context = result.error_context.expect("error context should be set");
+    assert_eq!(error_context.instruction, InstructionTag::Ap);
+    assert_eq!(error_source(script, &error_context), "(ap 1 scalar)");
+}
+
+#[test]
+fn no_error_context_on_success() {
+    let peer_id = "peer_id";
+    let mut peer_vm = create_avm(unit_call_service(), peer_id);
+
+    let script = r#"(call "peer_id" ("" "") [] result)"#;
+
+    let result = checked_call_vm!(peer_vm, <_>::default(), script, "", "");
+    assert_eq!(result.error_context, None);
+}
@@ -15,5 +15,6 @@
  */
 
 mod error;
+mod error_context;
 mod invalid_air;
 mod last_error;
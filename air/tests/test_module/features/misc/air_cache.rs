@@ -0,0 +1,58 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air_interpreter_interface::CallResultsRepr;
+use air_interpreter_interface::RunParameters;
+use air_interpreter_sede::ToSerialized;
+use air_test_utils::prelude::*;
+
+const SCRIPT: &str = r#"(seq (ap 1 $stream) (null))"#;
+
+fn execute_with_air_cache(script: &str, air_cache_capacity: u64) -> RawAVMOutcome {
+    let keypair = fluence_keypair::KeyPair::generate_ed25519();
+    let run_parameters = RunParameters::builder()
+        .init_peer_id("some_peer_id")
+        .current_peer_id("some_peer_id")
+        .timestamp(0)
+        .ttl(0)
+        .key_format(keypair.key_format().into())
+        .secret_key_bytes(keypair.secret().unwrap())
+        .air_cache_capacity(air_cache_capacity)
+        .build()
+        .unwrap();
+    let call_results = CallResultsRepr.serialize(&into_raw_result(<_>::default())).unwrap();
+
+    let outcome = air::execute_air(script.to_owned(), vec![], vec![], run_parameters, call_results);
+    RawAVMOutcome::from_interpreter_outcome(outcome).unwrap()
+}
+
+#[test]
+fn cached_script_reports_fingerprint() {
+    let expected_fingerprint = air_parser::fingerprint(&air_parser::parse(SCRIPT).unwrap());
+
+    let first_round = execute_with_air_cache(SCRIPT, 1);
+    let second_round = execute_with_air_cache(SCRIPT, 1);
+
+    assert_eq!(first_round.air_fingerprint, Some(expected_fingerprint));
+    assert_eq!(second_round.air_fingerprint, Some(expected_fingerprint));
+}
+
+#[test]
+fn uncached_script_doesnt_report_fingerprint() {
+    let outcome = execute_with_air_cache(SCRIPT, 0);
+
+    assert_eq!(outcome.air_fingerprint, None);
+}
@@ -0,0 +1,68 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air_interpreter_interface::CallResultsFormat;
+use air_test_utils::key_utils::derive_dummy_keypair;
+use air_test_utils::prelude::*;
+
+// call results are passed back to the interpreter in the format, so a result that survives
+// the trip is recorded in the trace as the service returned it
+fn check_call_results_round_trip(call_results_format: CallResultsFormat) {
+    let (keypair, peer_id) = derive_dummy_keypair("some_peer_id");
+    let service_result = json!({"string": "value", "number": 42, "array": [1, null, true]});
+    let test_init_parameters = TestInitParameters {
+        call_results_format,
+        ..TestInitParameters::default()
+    };
+    let mut vm = create_avm_with_key::<NativeAirRunner>(
+        keypair,
+        set_variable_call_service(service_result.clone()),
+        test_init_parameters,
+    );
+
+    let script = format!(
+        r#"
+        (seq
+            (call "{peer_id}" ("" "") [] result)
+            (call "{peer_id}" ("" "") [result])
+        )"#
+    );
+    let test_run_params = TestRunParameters::from_init_peer_id(&peer_id);
+    let result = vm.call(&script, "", "", test_run_params).unwrap();
+    assert_eq!(result.ret_code, 0, "{}", result.error_message);
+
+    let actual_trace = trace_from_result(&result);
+    let expected_trace = vec![
+        scalar!(service_result.clone(), peer = &peer_id),
+        unused!(service_result.clone(), peer = &peer_id, args = [service_result]),
+    ];
+    assert_eq!(actual_trace, expected_trace);
+}
+
+#[test]
+fn call_results_round_trip_json() {
+    check_call_results_round_trip(CallResultsFormat::Json);
+}
+
+#[test]
+fn call_results_round_trip_cbor() {
+    check_call_results_round_trip(CallResultsFormat::Cbor);
+}
+
+#[test]
+fn call_results_round_trip_msgpack() {
+    check_call_results_round_trip(CallResultsFormat::MsgPack);
+}
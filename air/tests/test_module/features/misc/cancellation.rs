@@ -0,0 +1,84 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air::ToErrorCode;
+use air::UncatchableError;
+use air_interpreter_interface::CallResultsRepr;
+use air_interpreter_interface::RunParameters;
+use air_interpreter_interface::INTERPRETER_SUCCESS;
+use air_interpreter_sede::ToSerialized;
+use air_test_utils::prelude::*;
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+fn execute_with_cancellation(script: &str, is_cancelled: impl Fn() -> bool + 'static) -> RawAVMOutcome {
+    let keypair = fluence_keypair::KeyPair::generate_ed25519();
+    let run_parameters = RunParameters::builder()
+        .init_peer_id("some_peer_id")
+        .current_peer_id("some_peer_id")
+        .timestamp(0)
+        .ttl(0)
+        .key_format(keypair.key_format().into())
+        .secret_key_bytes(keypair.secret().unwrap())
+        .build()
+        .unwrap();
+    let call_results = CallResultsRepr.serialize(&into_raw_result(<_>::default())).unwrap();
+
+    let outcome = air::execute_air_with_cancellation(
+        script.to_owned(),
+        vec![],
+        vec![],
+        run_parameters,
+        call_results,
+        is_cancelled,
+    );
+    RawAVMOutcome::from_interpreter_outcome(outcome).unwrap()
+}
+
+#[test]
+fn execution_stops_at_instruction_boundary() {
+    let script = r#"
+        (seq
+            (seq
+                (ap 1 $stream)
+                (ap 2 $stream))
+            (ap 3 $stream))"#;
+
+    let polls = Rc::new(Cell::new(0));
+    let polls_in_check = polls.clone();
+    let result = execute_with_cancellation(script, move || {
+        polls_in_check.set(polls_in_check.get() + 1);
+        polls_in_check.get() > 3
+    });
+
+    let expected_error = UncatchableError::Cancelled { executed: 3 };
+    assert_eq!(result.ret_code, expected_error.to_error_code());
+    assert_eq!(result.error_message, expected_error.to_string());
+    assert_eq!(polls.get(), 4);
+}
+
+#[test]
+fn not_cancelled_execution_completes() {
+    let script = r#"
+        (seq
+            (ap 1 $stream)
+            (ap 2 $stream))"#;
+
+    let result = execute_with_cancellation(script, || false);
+
+    assert_eq!(result.ret_code, INTERPRETER_SUCCESS, "{}", result.error_message);
+}
@@ -0,0 +1,178 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air::debug::Debugger;
+use air::debug::StepResult;
+use air_interpreter_interface::RunParameters;
+use air_interpreter_interface::INTERPRETER_SUCCESS;
+use air_test_utils::prelude::*;
+
+fn run_parameters(peer_id: &str) -> RunParameters {
+    let keypair = fluence_keypair::KeyPair::generate_ed25519();
+    RunParameters::builder()
+        .init_peer_id(peer_id)
+        .current_peer_id(peer_id)
+        .timestamp(0)
+        .ttl(0)
+        .key_format(keypair.key_format().into())
+        .secret_key_bytes(keypair.secret().unwrap())
+        .build()
+        .unwrap()
+}
+
+fn paused(result: StepResult) -> air::debug::PauseEvent {
+    match result {
+        StepResult::Paused(event) => event,
+        StepResult::Finished => panic!("execution is expected to be paused"),
+    }
+}
+
+#[test]
+fn debugger_pauses_before_each_instruction() {
+    let script = r#"
+        (seq
+            (ap 1 scalar)
+            (call "peer_id" ("service" "function") [scalar]))"#;
+
+    let mut debugger = Debugger::new(script, run_parameters("peer_id"));
+
+    let seq = paused(debugger.step());
+    assert_eq!((seq.instruction_id, seq.line, seq.instruction.as_str()), (0, 2, "seq"));
+    assert!(seq.variables.is_empty());
+
+    let ap = paused(debugger.step());
+    assert_eq!((ap.instruction_id, ap.line), (1, 3));
+    assert!(debugger.inspect_variable("scalar").is_none());
+
+    let call = paused(debugger.step());
+    assert_eq!((call.instruction_id, call.line), (2, 4));
+    let scalar = debugger.inspect_variable("scalar").unwrap();
+    assert_eq!(scalar.get_result().unwrap(), &JValue::from(json!(1)));
+
+    assert!(matches!(debugger.step(), StepResult::Finished));
+    assert!(debugger.inspect_variable("scalar").is_none());
+}
+
+#[test]
+fn debugger_stops_at_breakpoints() {
+    let script = r#"
+        (seq
+            (seq
+                (seq
+                    (ap 1 $stream)
+                    (ap 2 $stream))
+                (canon "peer_id" $stream #canon))
+            (fold #canon i
+                (seq
+                    (null)
+                    (next i))))"#;
+
+    let mut debugger = Debugger::new(script, run_parameters("peer_id"));
+    // the null instruction inside the fold
+    debugger.set_breakpoint(8);
+
+    let mut iterator_values = vec![];
+    while let StepResult::Paused(event) = debugger.continue_to_breakpoint() {
+        assert_eq!((event.instruction_id, event.line), (8, 10));
+        iterator_values.push(debugger.inspect_variable("i").unwrap().get_result().unwrap().clone());
+    }
+
+    assert_eq!(iterator_values, vec![JValue::from(json!(1)), JValue::from(json!(2))]);
+}
+
+#[test]
+fn debugger_resume_returns_outcome() {
+    let script = r#"
+        (seq
+            (ap 1 scalar)
+            (call "peer_id" ("service" "function") [scalar]))"#;
+
+    let mut debugger = Debugger::new(script, run_parameters("peer_id"));
+    paused(debugger.step());
+
+    let outcome = debugger.resume();
+    assert_eq!(outcome.ret_code, INTERPRETER_SUCCESS);
+    assert_eq!(outcome.next_peer_pks, Vec::<String>::new());
+    let outcome = RawAVMOutcome::from_interpreter_outcome(outcome).unwrap();
+    assert_eq!(outcome.call_requests.len(), 1);
+}
+
+#[test]
+fn debugger_rewinds_fold() {
+    let script = r#"
+        (seq
+            (seq
+                (seq
+                    (seq
+                        (ap 1 $stream)
+                        (ap 2 $stream))
+                    (ap 3 $stream))
+                (canon "peer_id" $stream #canon))
+            (fold #canon i
+                (seq
+                    (null)
+                    (next i))))"#;
+
+    let mut debugger = Debugger::new(script, run_parameters("peer_id"));
+    assert!(debugger.rewind("i").is_none());
+
+    // the null instruction inside the fold
+    debugger.set_breakpoint(10);
+    paused(debugger.continue_to_breakpoint());
+    paused(debugger.continue_to_breakpoint());
+    assert_eq!(
+        debugger.inspect_variable("i").unwrap().get_result().unwrap(),
+        &JValue::from(json!(2))
+    );
+
+    let event = paused(debugger.rewind("i").unwrap());
+    assert_eq!(event.instruction_id, 9);
+    assert_eq!(
+        debugger.inspect_variable("i").unwrap().get_result().unwrap(),
+        &JValue::from(json!(1))
+    );
+    assert!(debugger.rewind("j").is_none());
+
+    let mut iterator_values = vec![];
+    while let StepResult::Paused(_) = debugger.continue_to_breakpoint() {
+        iterator_values.push(debugger.inspect_variable("i").unwrap().get_result().unwrap().clone());
+    }
+    let expected_values: Vec<_> = [1, 2, 3].into_iter().map(|value| JValue::from(json!(value))).collect();
+    assert_eq!(iterator_values, expected_values);
+}
+
+#[test]
+fn debugger_replays_prev_data() {
+    let script = r#"
+        (seq
+            (call "peer_id" ("service" "function") [] result)
+            (null))"#;
+
+    let mut vm = create_avm(set_variable_call_service(json!("service result")), "peer_id");
+    let prev_data = checked_call_vm!(vm, <_>::default(), script, "", "").data;
+
+    let mut debugger = Debugger::with_data(script, prev_data, run_parameters("peer_id"));
+    debugger.set_breakpoint(2);
+    paused(debugger.continue_to_breakpoint());
+    assert_eq!(
+        debugger.inspect_variable("result").unwrap().get_result().unwrap(),
+        &JValue::from(json!("service result"))
+    );
+
+    let outcome = RawAVMOutcome::from_interpreter_outcome(debugger.resume()).unwrap();
+    assert_eq!(outcome.ret_code, INTERPRETER_SUCCESS, "{}", outcome.error_message);
+    assert!(outcome.call_requests.is_empty());
+}
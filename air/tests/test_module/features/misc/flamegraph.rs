@@ -0,0 +1,50 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air_test_utils::prelude::*;
+
+#[test]
+#[cfg(feature = "flamegraph")]
+fn flamegraph_has_frames_of_instructions() {
+    let vm_peer_id = "vm_peer_id";
+    let mut vm = create_avm(unit_call_service(), vm_peer_id);
+
+    let script = format!(
+        r#"
+        (seq
+            (ap 1 $stream)
+            (call "{vm_peer_id}" ("service" "function") []))"#
+    );
+
+    let result = checked_call_vm!(vm, <_>::default(), &script, "", "");
+
+    let svg = &result.flamegraph_svg;
+    assert!(svg.starts_with("<?xml"), "{svg}");
+    assert!(svg.contains("seq"));
+    assert!(svg.contains("ap 1 $stream"));
+}
+
+#[test]
+#[cfg(not(feature = "flamegraph"))]
+fn flamegraph_is_empty_without_feature() {
+    let vm_peer_id = "vm_peer_id";
+    let mut vm = create_avm(unit_call_service(), vm_peer_id);
+
+    let script = format!(r#"(call "{vm_peer_id}" ("" "") [])"#);
+    let result = checked_call_vm!(vm, <_>::default(), &script, "", "");
+
+    assert!(result.flamegraph_svg.is_empty());
+}
@@ -0,0 +1,94 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#[cfg(feature = "metrics")]
+use air_interpreter_interface::InstructionTag;
+use air_test_utils::prelude::*;
+
+#[test]
+#[cfg(feature = "metrics")]
+fn instructions_are_counted() {
+    let vm_peer_id = "vm_peer_id";
+    let mut vm = create_avm(unit_call_service(), vm_peer_id);
+
+    let script = format!(
+        r#"
+        (seq
+            (seq
+                (ap 1 $stream)
+                (ap 2 $stream))
+            (seq
+                (canon "{vm_peer_id}" $stream #canon)
+                (fold #canon i
+                    (seq
+                        (call "{vm_peer_id}" ("" "") [i])
+                        (next i)))))"#
+    );
+
+    let result = checked_call_vm!(vm, <_>::default(), &script, "", "");
+
+    let expected_counts = maplit::hashmap! {
+        InstructionTag::Seq => 5,
+        InstructionTag::Ap => 2,
+        InstructionTag::Canon => 1,
+        InstructionTag::Fold => 1,
+        InstructionTag::Call => 2,
+        InstructionTag::Next => 2,
+    };
+    assert_eq!(result.instruction_counts, expected_counts);
+}
+
+#[test]
+#[cfg(feature = "metrics")]
+fn regex_and_sorted_variants_are_counted_separately() {
+    let vm_peer_id = "vm_peer_id";
+    let mut vm = create_avm(unit_call_service(), vm_peer_id);
+
+    let script = format!(
+        r#"
+        (seq
+            (seq
+                (ap 1 $stream)
+                (canon-sorted "{vm_peer_id}" $stream #sorted))
+            (seq
+                (match 1 1 (null))
+                (match-regex "peer-1" "^peer-[0-9]+$" (null))))"#
+    );
+
+    let result = checked_call_vm!(vm, <_>::default(), &script, "", "");
+
+    let expected_counts = maplit::hashmap! {
+        InstructionTag::Seq => 3,
+        InstructionTag::Ap => 1,
+        InstructionTag::CanonSorted => 1,
+        InstructionTag::Match => 1,
+        InstructionTag::MatchRegex => 1,
+        InstructionTag::Null => 2,
+    };
+    assert_eq!(result.instruction_counts, expected_counts);
+}
+
+#[test]
+#[cfg(not(feature = "metrics"))]
+fn instructions_are_not_counted_without_metrics() {
+    let vm_peer_id = "vm_peer_id";
+    let mut vm = create_avm(unit_call_service(), vm_peer_id);
+
+    let script = format!(r#"(call "{vm_peer_id}" ("" "") [])"#);
+    let result = checked_call_vm!(vm, <_>::default(), &script, "", "");
+
+    assert!(result.instruction_counts.is_empty());
+}
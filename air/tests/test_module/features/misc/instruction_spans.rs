@@ -0,0 +1,98 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![cfg(feature = "opentelemetry")]
+
+use air_test_utils::prelude::*;
+use tracing::field::Field;
+use tracing::field::Visit;
+use tracing::span::Attributes;
+use tracing::span::Id;
+use tracing::span::Record;
+use tracing::Subscriber;
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::Layer;
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+#[derive(Debug, Default)]
+struct CollectedSpan {
+    name: &'static str,
+    fields: HashMap<&'static str, String>,
+}
+
+#[derive(Clone, Default)]
+struct SpanCollector(Arc<Mutex<Vec<(Id, CollectedSpan)>>>);
+
+impl Visit for CollectedSpan {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.fields.insert(field.name(), format!("{value:?}"));
+    }
+}
+
+impl<S: Subscriber> Layer<S> for SpanCollector {
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, _ctx: Context<'_, S>) {
+        let mut span = CollectedSpan {
+            name: attrs.metadata().name(),
+            ..<_>::default()
+        };
+        attrs.record(&mut span);
+        self.0.lock().unwrap().push((id.clone(), span));
+    }
+
+    fn on_record(&self, id: &Id, values: &Record<'_>, _ctx: Context<'_, S>) {
+        let mut spans = self.0.lock().unwrap();
+        if let Some((_, span)) = spans.iter_mut().rev().find(|(span_id, _)| span_id == id) {
+            values.record(span);
+        }
+    }
+}
+
+#[test]
+fn span_per_dispatched_instruction() {
+    let vm_peer_id = "vm_peer_id";
+    let mut vm = create_avm(echo_call_service(), vm_peer_id);
+    let script = format!(
+        r#"
+        (seq
+            (call "{vm_peer_id}" ("service" "function") ["value"] result)
+            (null))"#
+    );
+
+    let collector = SpanCollector::default();
+    let subscriber = tracing_subscriber::registry().with(collector.clone());
+    tracing::subscriber::with_default(subscriber, || {
+        checked_call_vm!(vm, <_>::default(), &script, "", "");
+    });
+
+    let spans = collector.0.lock().unwrap();
+    let instruction_spans = spans
+        .iter()
+        .map(|(_, span)| span.name)
+        .filter(|name| ["seq", "call", "null"].contains(name))
+        .collect::<Vec<_>>();
+    // the first run sends a call request, the second one receives the call result
+    assert_eq!(instruction_spans, vec!["seq", "call", "seq", "call", "null"]);
+
+    let (_, call_span) = spans.iter().rev().find(|(_, span)| span.name == "call").unwrap();
+    assert_eq!(call_span.fields["air.peer_id"], format!(r#""{vm_peer_id}""#));
+    assert_eq!(call_span.fields["air.output"], "result");
+    assert!(call_span.fields.contains_key("air.cid"));
+}
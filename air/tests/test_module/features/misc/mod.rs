@@ -14,5 +14,15 @@
  * limitations under the License.
  */
 
+mod air_cache;
+mod call_results_format;
+mod cancellation;
+mod debugger;
 mod empty_array;
+mod flamegraph;
+mod instruction_counts;
+mod instruction_spans;
+mod observer;
+mod replay;
+mod trace_compaction;
 mod version_check;
@@ -0,0 +1,242 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air::observer::ExecutionObserver;
+use air::observer::ExecutionTreeObserver;
+use air::observer::FoldProgress;
+use air::observer::FoldProgressCallback;
+use air::observer::MetricsObserver;
+use air::parser::Instruction;
+use air::ContextInspector;
+use air::ContextView;
+use air::ExecutionError;
+use air_interpreter_interface::CallResultsRepr;
+use air_interpreter_interface::ExecutionTree;
+use air_interpreter_interface::InstructionTag;
+use air_interpreter_interface::RunParameters;
+use air_interpreter_interface::TraceNode;
+use air_interpreter_interface::INTERPRETER_SUCCESS;
+use air_interpreter_sede::ToSerialized;
+use air_test_utils::prelude::*;
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+fn run_parameters() -> RunParameters {
+    let keypair = fluence_keypair::KeyPair::generate_ed25519();
+    RunParameters::builder()
+        .init_peer_id("some_peer_id")
+        .current_peer_id("some_peer_id")
+        .timestamp(0)
+        .ttl(0)
+        .key_format(keypair.key_format().into())
+        .secret_key_bytes(keypair.secret().unwrap())
+        .build()
+        .unwrap()
+}
+
+fn execute_with_observer(script: &str, observer: impl ExecutionObserver + 'static) -> RawAVMOutcome {
+    let call_results = CallResultsRepr.serialize(&into_raw_result(<_>::default())).unwrap();
+
+    let outcome = air::execute_air_with_observer(
+        script.to_owned(),
+        vec![],
+        vec![],
+        run_parameters(),
+        call_results,
+        Box::new(observer),
+    );
+    RawAVMOutcome::from_interpreter_outcome(outcome).unwrap()
+}
+
+#[derive(Clone, Default)]
+struct EventRecorder {
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+impl EventRecorder {
+    fn record(&self, event: String) {
+        self.events.lock().unwrap().push(event);
+    }
+}
+
+impl ExecutionObserver for EventRecorder {
+    fn on_call_start(&mut self, ctx: &ContextView<'_>) {
+        self.record(format!("call start on {}", ctx.current_peer_id()));
+    }
+
+    fn on_instruction_start(&mut self, instruction: &Instruction<'_>, ctx: &ContextView<'_>) {
+        let stream_len = ctx.get_stream("$stream").map_or(0, |stream| stream.len());
+        let name = instruction.to_string();
+        let name = name.split_whitespace().next().unwrap_or_default();
+        self.record(format!("{name} start, stream len {stream_len}"));
+    }
+
+    fn on_instruction_end(
+        &mut self,
+        instruction: &Instruction<'_>,
+        error: Option<&ExecutionError>,
+        _ctx: &ContextView<'_>,
+    ) {
+        let name = instruction.to_string();
+        let name = name.split_whitespace().next().unwrap_or_default();
+        self.record(format!("{name} end, failed {}", error.is_some()));
+    }
+
+    fn on_call_end(&mut self, _ctx: &ContextView<'_>) {
+        self.record("call end".to_string());
+    }
+}
+
+#[test]
+fn observer_receives_events_in_execution_order() {
+    let script = r#"
+        (xor
+            (seq
+                (ap 1 $stream)
+                (fail 1 "error"))
+            (null))"#;
+
+    let recorder = EventRecorder::default();
+    let result = execute_with_observer(script, recorder.clone());
+
+    assert_eq!(result.ret_code, INTERPRETER_SUCCESS, "{}", result.error_message);
+    let expected = [
+        "call start on some_peer_id",
+        "xor start, stream len 0",
+        "seq start, stream len 0",
+        "ap start, stream len 0",
+        "ap end, failed false",
+        "fail start, stream len 1",
+        "fail end, failed true",
+        "seq end, failed true",
+        "null start, stream len 1",
+        "null end, failed false",
+        "xor end, failed false",
+        "call end",
+    ];
+    assert_eq!(*recorder.events.lock().unwrap(), expected);
+}
+
+#[test]
+fn metrics_observer_counts_instructions() {
+    let script = r#"
+        (seq
+            (ap 1 $stream)
+            (seq
+                (ap 2 $stream)
+                (null)))"#;
+
+    let observer = MetricsObserver::new();
+    let result = execute_with_observer(script, observer.clone());
+
+    assert_eq!(result.ret_code, INTERPRETER_SUCCESS, "{}", result.error_message);
+    let counts = observer.instruction_counts();
+    assert_eq!(counts[&InstructionTag::Seq], 2);
+    assert_eq!(counts[&InstructionTag::Ap], 2);
+    assert_eq!(counts[&InstructionTag::Null], 1);
+    assert_eq!(observer.total(), 5);
+}
+
+#[test]
+fn fold_progress_callback_reports_iterations() {
+    let script = r#"
+        (seq
+            (seq
+                (ap 1 $stream)
+                (ap 2 $stream))
+            (seq
+                (canon "some_peer_id" $stream #canon)
+                (seq
+                    (fold #canon i
+                        (next i))
+                    (fold $stream s
+                        (next s)))))"#;
+
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let callback = FoldProgressCallback::new({
+        let reports = reports.clone();
+        move |progress| reports.lock().unwrap().push(progress)
+    });
+    let result = execute_with_observer(script, callback);
+
+    assert_eq!(result.ret_code, INTERPRETER_SUCCESS, "{}", result.error_message);
+    let progress = |iterator_name: &str, current_index, total| FoldProgress {
+        iterator_name: iterator_name.to_string(),
+        current_index,
+        total,
+    };
+    let expected = vec![
+        progress("i", 0, Some(2)),
+        progress("i", 1, Some(2)),
+        progress("s", 0, None),
+        progress("s", 1, None),
+    ];
+    assert_eq!(*reports.lock().unwrap(), expected);
+}
+
+/// Drops durations, so trees could be compared.
+fn shape(tree: &[TraceNode]) -> Vec<(InstructionTag, Vec<InstructionTag>)> {
+    tree.iter()
+        .map(|node| {
+            let children = node.children.iter().map(|child| child.instruction).collect();
+            (node.instruction, children)
+        })
+        .collect()
+}
+
+#[test]
+fn execution_tree_observer_builds_tree() {
+    let script = r#"
+        (seq
+            (ap 1 $stream)
+            (xor
+                (fail 1 "error")
+                (null)))"#;
+
+    let observer = ExecutionTreeObserver::new();
+    let result = execute_with_observer(script, observer.clone());
+
+    assert_eq!(result.ret_code, INTERPRETER_SUCCESS, "{}", result.error_message);
+    let tree = observer.execution_tree();
+    assert_eq!(
+        shape(&tree),
+        [(InstructionTag::Seq, vec![InstructionTag::Ap, InstructionTag::Xor])]
+    );
+    assert_eq!(
+        shape(&tree[0].children[1].children),
+        [(InstructionTag::Fail, vec![]), (InstructionTag::Null, vec![])]
+    );
+    assert!(tree[0].duration >= tree[0].children[1].duration);
+}
+
+#[test]
+fn traced_execution_puts_tree_into_outcome() {
+    let script = r#"
+        (seq
+            (null)
+            (null))"#;
+    let call_results = CallResultsRepr.serialize(&into_raw_result(<_>::default())).unwrap();
+
+    let outcome = air::execute_air_traced(script.to_owned(), vec![], vec![], run_parameters(), call_results);
+
+    assert_eq!(outcome.ret_code, INTERPRETER_SUCCESS, "{}", outcome.error_message);
+    let tree: ExecutionTree = serde_json::from_slice(&outcome.execution_tree).unwrap();
+    assert_eq!(
+        shape(&tree),
+        [(InstructionTag::Seq, vec![InstructionTag::Null, InstructionTag::Null])]
+    );
+}
@@ -0,0 +1,103 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air::replay::Replayer;
+use air_interpreter_interface::RunParameters;
+use air_test_utils::prelude::*;
+
+#[test]
+fn replay_yields_dispatched_instructions() {
+    let vm_peer_id = "some_peer_id";
+    let mut vm = create_avm(echo_call_service(), vm_peer_id);
+
+    let script = format!(
+        r#"
+        (seq
+           (call "{vm_peer_id}" ("" "") ["first"] result)
+           (call "{vm_peer_id}" ("" "") [result])
+        )"#
+    );
+
+    let result = checked_call_vm!(vm, <_>::default(), &script, "", "");
+    let data = data_from_result(&result);
+
+    let mut replayer = Replayer::new(&script, data);
+    let mut instructions = vec![];
+    while let Some(event) = replayer.step() {
+        assert!(event.call_requests().is_empty());
+        instructions.push(event.instruction().to_string());
+    }
+
+    assert!(replayer.error().is_none());
+    assert_eq!(instructions.len(), 3);
+    assert_eq!(instructions[0], "seq");
+    assert!(instructions[1].starts_with("call"));
+    assert!(instructions[2].starts_with("call"));
+}
+
+#[test]
+fn replay_reports_variables_and_call_requests() {
+    let vm_peer_id = "some_peer_id";
+    let mut vm = create_avm(echo_call_service(), vm_peer_id);
+
+    let script = format!(
+        r#"
+        (seq
+           (call "{vm_peer_id}" ("" "") ["first"] result)
+           (call "{vm_peer_id}" ("" "") [result])
+        )"#
+    );
+
+    // drop the second call from the trace, so it should be requested again
+    let result = checked_call_vm!(vm, <_>::default(), &script, "", "");
+    let data = data_from_result(&result);
+    let mut truncated_data = data.clone();
+    truncated_data.trace = data.trace.iter().take(1).cloned().collect::<Vec<_>>().into();
+
+    let run_parameters = RunParameters::builder()
+        .init_peer_id(vm_peer_id.to_string())
+        .current_peer_id(vm_peer_id.to_string())
+        .timestamp(0)
+        .ttl(0)
+        .air_size_limit(u64::MAX)
+        .particle_size_limit(u64::MAX)
+        .call_result_size_limit(u64::MAX)
+        .build()
+        .unwrap();
+    let mut replayer = Replayer::with_run_parameters(&script, truncated_data, &run_parameters);
+
+    let seq = replayer.step().unwrap();
+    assert_eq!(seq.call_requests().len(), 1);
+
+    let first_call = replayer.step().unwrap();
+    assert!(first_call.call_requests().is_empty());
+    assert!(!first_call.variables().contains("result"));
+
+    let second_call = replayer.step().unwrap();
+    assert_eq!(second_call.call_requests().len(), 1);
+    assert!(second_call.variables().contains("result"));
+
+    assert!(replayer.step().is_none());
+    assert!(replayer.error().is_none());
+}
+
+#[test]
+fn replay_of_invalid_script() {
+    let mut replayer = Replayer::new("(seq", <_>::default());
+
+    assert!(replayer.step().is_none());
+    assert!(replayer.error().is_some());
+}
@@ -0,0 +1,46 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air_test_utils::prelude::*;
+
+#[test]
+fn result_trace_is_compacted() {
+    let vm_peer_id = "vm_peer_id";
+    let items = vec![json!(1); 20];
+    let mut vm = create_avm(set_variable_call_service(json!(items)), vm_peer_id);
+
+    let script = format!(
+        r#"
+        (seq
+            (call "{vm_peer_id}" ("" "") [] items)
+            (fold items i
+                (seq
+                    (call "{vm_peer_id}" ("" "") [] result)
+                    (next i))))
+        "#
+    );
+
+    let result = checked_call_vm!(vm, <_>::default(), &script, "", "");
+    let compacted_trace = compacted_data_from_result(&result).trace;
+    let expanded_trace = trace_from_result(&result);
+    assert!(compacted_trace.len() < 10);
+    assert_eq!(expanded_trace.len(), 21);
+
+    // the compacted result is expanded back when it's passed as data
+    let result = checked_call_vm!(vm, <_>::default(), &script, "", result.data);
+    assert_eq!(trace_from_result(&result), expanded_trace);
+    assert_eq!(compacted_data_from_result(&result).trace, compacted_trace);
+}
@@ -21,7 +21,10 @@ mod attacks;
 #[cfg(feature = "check_signatures")]
 mod corruption;
 
+mod parallel_verification;
 mod runtime;
 
 #[cfg(feature = "gen_signatures")]
 mod signing;
+
+mod trace_verifier;
@@ -15,11 +15,12 @@
  */
 
 use air::PreparationError;
+use air_interpreter_interface::KeyFormatPolicyKind;
 use air_interpreter_signatures::KeyError;
 use air_test_utils::{
     assert_error_eq,
     prelude::unit_call_service,
-    test_runner::{create_avm_with_key, NativeAirRunner, TestRunParameters},
+    test_runner::{create_avm_with_key, NativeAirRunner, TestInitParameters, TestRunParameters},
 };
 use fluence_keypair::KeyFormat;
 
@@ -109,3 +110,67 @@ fn test_banned_signing_key() {
         PreparationError::MalformedKeyPairData(KeyError::AlgorithmNotWhitelisted(KeyFormat::Secp256k1))
     );
 }
+
+/// Checking that the local key of any format is accepted under the permissive policy.
+#[test]
+fn test_any_key_format_signing_key() {
+    let air_script = "(null)";
+    let secp256k1_keypair = fluence_keypair::KeyPair::generate_secp256k1();
+    let test_init_parameters = TestInitParameters {
+        key_format_policy: KeyFormatPolicyKind::Any,
+        ..<_>::default()
+    };
+
+    let mut avm = create_avm_with_key::<NativeAirRunner>(secp256k1_keypair, unit_call_service(), test_init_parameters);
+    let res = avm
+        .call(air_script, "", "", TestRunParameters::from_init_peer_id("init_peer_id"))
+        .unwrap();
+
+    assert_eq!(res.ret_code, 0, "{}", res.error_message);
+}
+
+/// Checking that a signature store with keys of different formats is verified per key.
+#[test]
+fn test_mixed_key_formats() {
+    use air_interpreter_data::verification::DataVerifierError;
+    use air_interpreter_signatures::PublicKey;
+
+    let alice_keypair = fluence_keypair::KeyPair::generate_secp256k1();
+    let alice_peer_id = alice_keypair.public().to_peer_id().to_string();
+    let alice_public_key = PublicKey::new(alice_keypair.public());
+    let bob_keypair = fluence_keypair::KeyPair::generate_ed25519();
+    let bob_peer_id = bob_keypair.public().to_peer_id().to_string();
+
+    let air_script = format!(
+        r#"(seq
+            (call "{alice_peer_id}" ("" "") [] x)
+            (call "{bob_peer_id}" ("" "") [] y))"#
+    );
+    let any_key_format = TestInitParameters {
+        key_format_policy: KeyFormatPolicyKind::Any,
+        ..<_>::default()
+    };
+    let test_run_params = TestRunParameters::from_init_peer_id(&alice_peer_id);
+
+    let mut alice_avm = create_avm_with_key::<NativeAirRunner>(alice_keypair, unit_call_service(), any_key_format);
+    let alice_res = alice_avm.call(&air_script, "", "", test_run_params.clone()).unwrap();
+    assert_eq!(alice_res.ret_code, 0, "{}", alice_res.error_message);
+
+    let mut bob_avm = create_avm_with_key::<NativeAirRunner>(bob_keypair.clone(), unit_call_service(), any_key_format);
+    let bob_res = bob_avm
+        .call(&air_script, "", alice_res.data.clone(), test_run_params.clone())
+        .unwrap();
+    assert_eq!(bob_res.ret_code, 0, "{}", bob_res.error_message);
+
+    let mut strict_bob_avm = create_avm_with_key::<NativeAirRunner>(bob_keypair, unit_call_service(), <_>::default());
+    let strict_bob_res = strict_bob_avm
+        .call(&air_script, "", alice_res.data, test_run_params)
+        .unwrap();
+    assert_error_eq!(
+        &strict_bob_res,
+        PreparationError::DataSignatureCheckError(DataVerifierError::MalformedKey {
+            error: KeyError::AlgorithmNotWhitelisted(KeyFormat::Secp256k1),
+            key: alice_public_key.to_string(),
+        })
+    );
+}
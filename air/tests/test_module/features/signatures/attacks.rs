@@ -16,6 +16,7 @@
 
 use air::{ExecutionCidState, PreparationError};
 use air_interpreter_cid::CidRef;
+use air_interpreter_interface::SignaturePolicyKind;
 use air_interpreter_signatures::{PeerCidTracker, PublicKey, SignatureStore};
 use air_test_utils::key_utils::derive_dummy_keypair;
 use air_test_utils::prelude::*;
@@ -68,7 +69,7 @@ fn test_attack_injection_current_peer_scalar() {
         alice_trace.into(),
         alice_cid_state.into(),
         alice_signature_store,
-        2,
+        2.into(),
         Version::new(1, 1, 1),
     );
 
@@ -76,7 +77,7 @@ fn test_attack_injection_current_peer_scalar() {
         mallory_trace.into(),
         mallory_cid_state.into(),
         mallory_signature_store,
-        2,
+        2.into(),
         Version::new(1, 1, 1),
     );
 
@@ -90,6 +91,74 @@ fn test_attack_injection_current_peer_scalar() {
     assert_ne!(res.ret_code, 0);
 }
 
+#[test]
+fn test_attack_injection_accepted_without_verification() {
+    // the same injection as above passes when signatures aren't verified
+    let (alice_keypair, alice_peer_id) = derive_dummy_keypair("alice_peer");
+    let (mallory_keypair, mallory_peer_id) = derive_dummy_keypair("mallory_peer");
+
+    let air_script = format!(
+        r#"
+    (seq
+       (seq
+          (call "{alice_peer_id}" ("" "") [] x)
+          (call "{mallory_peer_id}" ("" "") [] y))
+       (call "{alice_peer_id}" ("" "") [] z))
+    "#
+    );
+
+    let mut alice_cid_state = ExecutionCidState::new();
+    let mut alice_signature_tracker = PeerCidTracker::new(alice_peer_id.clone());
+    let mut alice_signature_store = SignatureStore::new();
+
+    let alice_call_1 = scalar_tracked!("good result", &mut alice_cid_state, peer = &alice_peer_id);
+    alice_signature_tracker.register(&alice_peer_id, &extract_service_result_cid(&alice_call_1));
+    let alice_trace = vec![alice_call_1.clone()];
+    let alice_signature = alice_signature_tracker.gen_signature("", &alice_keypair).unwrap();
+    alice_signature_store.put(alice_keypair.public(), alice_signature);
+
+    let mut mallory_cid_state = alice_cid_state.clone();
+    let mut mallory_signature_tracker = PeerCidTracker::new(mallory_peer_id.clone());
+    let mut mallory_signature_store = alice_signature_store.clone();
+
+    let mallory_call_2 = scalar_tracked!("valid result", &mut mallory_cid_state, peer = &mallory_peer_id);
+    let fake_call_3 = scalar_tracked!("fake result", &mut mallory_cid_state, peer = &alice_peer_id);
+    mallory_signature_tracker.register(&mallory_peer_id, &extract_service_result_cid(&mallory_call_2));
+    let mallory_trace = vec![alice_call_1, mallory_call_2, fake_call_3];
+    let mallory_signature = mallory_signature_tracker.gen_signature("", &mallory_keypair).unwrap();
+    mallory_signature_store.put(mallory_keypair.public(), mallory_signature);
+
+    let alice_data = InterpreterDataEnvelope::from_execution_result(
+        alice_trace.into(),
+        alice_cid_state.into(),
+        alice_signature_store,
+        2.into(),
+        Version::new(1, 1, 1),
+    );
+
+    let mallory_data = InterpreterDataEnvelope::from_execution_result(
+        mallory_trace.into(),
+        mallory_cid_state.into(),
+        mallory_signature_store,
+        2.into(),
+        Version::new(1, 1, 1),
+    );
+
+    let test_init_parameters = TestInitParameters {
+        signature_policy: SignaturePolicyKind::VerifyNone,
+        ..<_>::default()
+    };
+    let mut alice_avm =
+        create_avm_with_key::<NativeAirRunner>(alice_keypair, unit_call_service(), test_init_parameters);
+    let test_run_params = TestRunParameters::from_init_peer_id(alice_peer_id);
+    let prev_data = alice_data.serialize().unwrap();
+    let cur_data = mallory_data.serialize().unwrap();
+    let res = alice_avm
+        .call(&air_script, prev_data, cur_data, test_run_params)
+        .unwrap();
+    assert_eq!(res.ret_code, 0, "{}", res.error_message);
+}
+
 #[test]
 fn test_attack_injection_current_peer_stream() {
     // injecting a value that arrives to peer who does the next step
@@ -139,7 +208,7 @@ fn test_attack_injection_current_peer_stream() {
         alice_trace.into(),
         alice_cid_state.into(),
         alice_signature_store,
-        2,
+        2.into(),
         Version::new(1, 1, 1),
     );
 
@@ -147,7 +216,7 @@ fn test_attack_injection_current_peer_stream() {
         mallory_trace.into(),
         mallory_cid_state.into(),
         mallory_signature_store,
-        2,
+        2.into(),
         Version::new(1, 1, 1),
     );
 
@@ -208,7 +277,7 @@ fn test_attack_injection_current_injection_unused() {
         alice_trace.into(),
         alice_cid_state.into(),
         alice_signature_store,
-        2,
+        2.into(),
         Version::new(1, 1, 1),
     );
 
@@ -216,7 +285,7 @@ fn test_attack_injection_current_injection_unused() {
         mallory_trace.into(),
         mallory_cid_state.into(),
         mallory_signature_store,
-        2,
+        2.into(),
         Version::new(1, 1, 1),
     );
 
@@ -277,7 +346,7 @@ fn test_attack_injection_other_peer_scalar() {
         mallory_trace.into(),
         mallory_cid_state.into(),
         signature_store,
-        2,
+        2.into(),
         Version::new(1, 1, 1),
     );
 
@@ -335,7 +404,7 @@ fn test_attack_injection_other_peer_stream() {
         mallory_trace.into(),
         mallory_cid_state.into(),
         signature_store,
-        2,
+        2.into(),
         Version::new(1, 1, 1),
     );
 
@@ -393,7 +462,7 @@ fn test_attack_injection_other_peer_unused() {
         mallory_trace.into(),
         mallory_cid_state.into(),
         signature_store,
-        2,
+        2.into(),
         Version::new(1, 1, 1),
     );
 
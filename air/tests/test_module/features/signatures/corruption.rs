@@ -81,7 +81,7 @@ fn test_attack_replace_value() {
         mallory_trace.into(),
         serde_json::from_value(mallory_cid_info).unwrap(),
         signature_store,
-        0,
+        0.into(),
         Version::new(1, 1, 1),
     );
 
@@ -158,7 +158,7 @@ fn test_attack_replace_tetraplet() {
         mallory_trace.into(),
         serde_json::from_value(mallory_cid_info).unwrap(),
         signature_store,
-        0,
+        0.into(),
         Version::new(1, 1, 1),
     );
 
@@ -241,7 +241,7 @@ fn test_attack_replace_call_result() {
         mallory_trace.into(),
         serde_json::from_value(mallory_cid_info).unwrap(),
         signature_store,
-        0,
+        0.into(),
         Version::new(1, 1, 1),
     );
 
@@ -331,7 +331,7 @@ fn test_attack_replace_canon_value() {
         mallory_trace.into(),
         serde_json::from_value(mallory_cid_info).unwrap(),
         signature_store,
-        0,
+        0.into(),
         Version::new(1, 1, 1),
     );
 
@@ -430,7 +430,7 @@ fn test_attack_replace_canon_result_values() {
         mallory_trace.into(),
         serde_json::from_value(mallory_cid_info).unwrap(),
         signature_store,
-        0,
+        0.into(),
         Version::new(1, 1, 1),
     );
 
@@ -533,7 +533,7 @@ fn test_attack_replace_canon_result_tetraplet() {
         mallory_trace.into(),
         serde_json::from_value(mallory_cid_info).unwrap(),
         signature_store,
-        0,
+        0.into(),
         Version::new(1, 1, 1),
     );
 
@@ -0,0 +1,92 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air::ExecutionCidState;
+use air_interpreter_data::verification::DataVerifier;
+use air_interpreter_data::InterpreterData;
+use air_interpreter_signatures::PeerCidTracker;
+use air_interpreter_signatures::SignatureStore;
+use air_interpreter_signatures::VerifyAll;
+use air_test_utils::key_utils::derive_dummy_keypair;
+use air_test_utils::prelude::*;
+
+const SALT: &str = "particle_id";
+
+// peers sign their own results; with `inject_fake` set, a result nobody signed is attributed to bob
+fn signed_data(inject_fake: bool) -> InterpreterData {
+    let mut cid_state = ExecutionCidState::new();
+    let mut signature_store = SignatureStore::new();
+    let mut trace = vec![];
+
+    for peer_name in ["alice_peer", "bob_peer", "carol_peer"] {
+        let (keypair, peer_id) = derive_dummy_keypair(peer_name);
+        let call = scalar_tracked!(peer_name, cid_state, peer = &peer_id);
+        let mut signature_tracker = PeerCidTracker::new(peer_id.clone());
+        signature_tracker.register(&peer_id, &extract_service_result_cid(&call));
+        signature_store.put(
+            keypair.public(),
+            signature_tracker.gen_signature(SALT, &keypair).unwrap(),
+        );
+        trace.push(call);
+    }
+
+    if inject_fake {
+        let (_, bob_peer_id) = derive_dummy_keypair("bob_peer");
+        trace.push(scalar_tracked!("fake result", cid_state, peer = &bob_peer_id));
+    }
+
+    InterpreterData {
+        trace: trace.into(),
+        last_call_request_id: 0.into(),
+        cid_info: cid_state.into(),
+        signatures: signature_store,
+    }
+}
+
+#[test]
+fn parallel_verification_accepts_valid_data() {
+    let data = signed_data(false);
+    let verifier = DataVerifier::new(&data, SALT).unwrap();
+
+    assert!(verifier.verify().is_ok());
+    assert!(verifier.verify_parallel().is_ok());
+}
+
+#[test]
+fn parallel_verification_rejects_tampered_data() {
+    let data = signed_data(true);
+    let verifier = DataVerifier::new(&data, SALT).unwrap();
+
+    let sequential_error = verifier.verify().unwrap_err();
+    let parallel_error = verifier.verify_parallel().unwrap_err();
+
+    assert_eq!(parallel_error.to_string(), sequential_error.to_string());
+}
+
+#[test]
+fn parallel_verification_runs_on_caller_pool() {
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(2).build().unwrap();
+
+    let valid_data = signed_data(false);
+    let valid_verifier = DataVerifier::new(&valid_data, SALT).unwrap();
+    assert!(valid_verifier.verify_parallel_in(&pool, &VerifyAll).is_ok());
+
+    let tampered_data = signed_data(true);
+    let tampered_verifier = DataVerifier::new(&tampered_data, SALT).unwrap();
+    let sequential_error = tampered_verifier.verify().unwrap_err();
+    let parallel_error = tampered_verifier.verify_parallel_in(&pool, &VerifyAll).unwrap_err();
+    assert_eq!(parallel_error.to_string(), sequential_error.to_string());
+}
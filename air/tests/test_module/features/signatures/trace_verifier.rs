@@ -0,0 +1,108 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air::ExecutionCidState;
+use air_interpreter_data::verification::TraceVerificationError;
+use air_interpreter_data::verification::TraceVerifier;
+use air_interpreter_data::InterpreterData;
+use air_interpreter_signatures::PeerCidTracker;
+use air_interpreter_signatures::SignatureStore;
+use air_test_utils::key_utils::derive_dummy_keypair;
+use air_test_utils::prelude::*;
+
+fn interpreter_data(
+    trace: Vec<ExecutedState>,
+    cid_state: ExecutionCidState,
+    signatures: SignatureStore,
+) -> InterpreterData {
+    InterpreterData {
+        trace: trace.into(),
+        last_call_request_id: 0.into(),
+        cid_info: cid_state.into(),
+        signatures,
+    }
+}
+
+#[test]
+fn signed_trace_is_verified() {
+    let (alice_keypair, alice_peer_id) = derive_dummy_keypair("alice_peer");
+
+    let mut cid_state = ExecutionCidState::new();
+    let alice_call = scalar_tracked!("result", cid_state, peer = &alice_peer_id);
+    let mut signature_tracker = PeerCidTracker::new(alice_peer_id.clone());
+    signature_tracker.register(&alice_peer_id, &extract_service_result_cid(&alice_call));
+    let mut signature_store = SignatureStore::new();
+    signature_store.put(
+        alice_keypair.public(),
+        signature_tracker.gen_signature("particle_id", &alice_keypair).unwrap(),
+    );
+    let data = interpreter_data(vec![alice_call, request_sent_by("alice")], cid_state, signature_store);
+
+    let report = TraceVerifier::report(&data, "particle_id");
+
+    assert!(report.is_valid(), "{report:?}");
+    assert_eq!(report.verified_states, vec![0.into()]);
+    assert!(report.failed_states.is_empty());
+}
+
+#[test]
+fn injected_value_fails_signature_check() {
+    let (alice_keypair, alice_peer_id) = derive_dummy_keypair("alice_peer");
+
+    let mut cid_state = ExecutionCidState::new();
+    let alice_call = scalar_tracked!("result", cid_state, peer = &alice_peer_id);
+    let fake_call = scalar_tracked!("fake result", cid_state, peer = &alice_peer_id);
+    let mut signature_tracker = PeerCidTracker::new(alice_peer_id.clone());
+    signature_tracker.register(&alice_peer_id, &extract_service_result_cid(&alice_call));
+    let mut signature_store = SignatureStore::new();
+    signature_store.put(
+        alice_keypair.public(),
+        signature_tracker.gen_signature("particle_id", &alice_keypair).unwrap(),
+    );
+    let data = interpreter_data(vec![alice_call, fake_call], cid_state, signature_store);
+
+    let report = TraceVerifier::report(&data, "particle_id");
+
+    assert!(!report.is_valid());
+    assert_eq!(report.verified_states, vec![0.into(), 1.into()]);
+    assert!(matches!(report.signatures, Some(Err(_))));
+    assert!(matches!(
+        TraceVerifier::verify(&data, "particle_id"),
+        Err(TraceVerificationError::Signatures(_))
+    ));
+}
+
+#[test]
+fn missing_value_is_reported() {
+    let (_, alice_peer_id) = derive_dummy_keypair("alice_peer");
+
+    let mut cid_state = ExecutionCidState::new();
+    let mut foreign_cid_state = ExecutionCidState::new();
+    let alice_call = scalar_tracked!("result", cid_state, peer = &alice_peer_id);
+    let missing_call = scalar_tracked!("missing result", foreign_cid_state, peer = &alice_peer_id);
+    let missing_cid = extract_service_result_cid(&missing_call);
+    let data = interpreter_data(vec![alice_call, missing_call], cid_state, <_>::default());
+
+    let report = TraceVerifier::report(&data, "particle_id");
+
+    assert_eq!(report.verified_states, vec![0.into()]);
+    assert_eq!(report.failed_states, vec![(1.into(), missing_cid.get_inner())]);
+    assert!(report.signatures.is_none());
+    assert!(matches!(
+        TraceVerifier::verify(&data, "particle_id"),
+        Err(TraceVerificationError::MissingCid { position, .. }) if position == 1.into()
+    ));
+}
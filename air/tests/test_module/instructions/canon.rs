@@ -289,6 +289,84 @@ fn canon_gates() {
     assert_eq!(fold.lore.len(), stop_len_count);
 }
 
+#[test]
+fn canon_sorted() {
+    let mut vm = create_avm(echo_call_service(), "A");
+
+    let script = r#"
+            (seq
+                (seq
+                    (seq
+                        (ap "c" $stream)
+                        (ap "a" $stream))
+                    (ap "b" $stream))
+                (seq
+                    (canon-sorted "A" $stream #canon_stream)
+                    (call "A" ("" "") [#canon_stream] result)))
+                    "#;
+
+    let result = checked_call_vm!(vm, <_>::default(), script, "", "");
+    let trace = trace_from_result(&result);
+
+    let expected_values = json!(["a", "b", "c"]);
+    let expected_state = scalar!(expected_values.clone(), peer = "A", args = [expected_values]);
+    assert_eq!(trace.last(), Some(&expected_state));
+}
+
+#[test]
+fn canon_sorted_compares_json_representations() {
+    let mut vm = create_avm(echo_call_service(), "A");
+
+    // numbers are sorted by their JSON representation too, so 10 goes before 9
+    let script = r#"
+            (seq
+                (seq
+                    (seq
+                        (ap 9 $stream)
+                        (ap 10 $stream))
+                    (ap "9" $stream))
+                (seq
+                    (canon-sorted "A" $stream #canon_stream)
+                    (call "A" ("" "") [#canon_stream] result)))
+                    "#;
+
+    let result = checked_call_vm!(vm, <_>::default(), script, "", "");
+    let trace = trace_from_result(&result);
+
+    let expected_values = json!(["9", 10, 9]);
+    let expected_state = scalar!(expected_values.clone(), peer = "A", args = [expected_values]);
+    assert_eq!(trace.last(), Some(&expected_state));
+}
+
+#[test]
+fn canon_sorted_moves_execution_flow() {
+    let peer_id_1 = "peer_id_1";
+    let peer_id_2 = "peer_id_2";
+    let init_peer_id = "A";
+    let mut vm = create_avm(echo_call_service(), init_peer_id);
+
+    let script = format!(
+        r#"
+            (par
+                (call "{peer_id_1}" ("" "") [] $stream)
+                (canon-sorted "{peer_id_2}" $stream #canon_stream)
+            )"#
+    );
+
+    let result = checked_call_vm!(vm, <_>::default(), script, "", "");
+
+    assert_next_pks!(&result.next_peer_pks, &[peer_id_1, peer_id_2]);
+    let trace = trace_from_result(&result);
+    assert_eq!(
+        &*trace,
+        vec![
+            par(1, 1),
+            executed_state::request_sent_by(init_peer_id),
+            canon_request(init_peer_id),
+        ],
+    )
+}
+
 #[test]
 fn canon_empty_stream() {
     let peer_id_1 = "peer_id_1";
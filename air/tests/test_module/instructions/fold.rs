@@ -61,6 +61,77 @@ fn lfold() {
     }
 }
 
+#[test]
+fn fold_over_object() {
+    let mut vm = create_avm(echo_call_service(), "A");
+    let mut set_variable_vm = create_avm(
+        set_variable_call_service(json!({"b": 2, "c": {"d": 3}, "a": 1})),
+        "set_variable",
+    );
+
+    let script = r#"
+            (seq
+                (call "set_variable" ("" "") [] object)
+                (fold object kv_pair
+                    (seq
+                        (call "A" ("" "") [kv_pair] $acc)
+                        (next kv_pair)
+                    )
+                )
+            )"#;
+
+    let result = checked_call_vm!(set_variable_vm, <_>::default(), script, "", "");
+    let result = checked_call_vm!(vm, <_>::default(), script, "", result.data);
+
+    let actual_trace = trace_from_result(&result);
+    let expected_pairs = [
+        json!({"key": "a", "value": 1}),
+        json!({"key": "b", "value": 2}),
+        json!({"key": "c", "value": {"d": 3}}),
+    ];
+    assert_eq!(actual_trace.len(), 4);
+    for (i, pair) in expected_pairs.into_iter().enumerate() {
+        let expected_state = stream!(pair.clone(), i as u32, peer = "A", args = [pair]);
+        assert_eq!(actual_trace[(i as u32 + 1).into()], expected_state);
+    }
+}
+
+#[test]
+fn fold_over_object_lambda() {
+    let mut vm = create_avm(echo_call_service(), "A");
+    let mut set_variable_vm = create_avm(
+        set_variable_call_service(json!({"inner": {"y": "2", "x": "1"}, "empty": {}})),
+        "set_variable",
+    );
+
+    let script = r#"
+            (seq
+                (call "set_variable" ("" "") [] object)
+                (seq
+                    (fold object.$.empty kv_pair
+                        (seq
+                            (call "A" ("" "") [kv_pair.$.key] $acc)
+                            (next kv_pair)
+                        )
+                    )
+                    (fold object.$.inner kv_pair
+                        (seq
+                            (call "A" ("" "") [kv_pair.$.key] $acc)
+                            (next kv_pair)
+                        )
+                    )
+                )
+            )"#;
+
+    let result = checked_call_vm!(set_variable_vm, <_>::default(), script, "", "");
+    let result = checked_call_vm!(vm, <_>::default(), script, "", result.data);
+
+    let actual_trace = trace_from_result(&result);
+    assert_eq!(actual_trace.len(), 3);
+    assert_eq!(actual_trace[1.into()], stream!("x", 0, peer = "A", args = ["x"]));
+    assert_eq!(actual_trace[2.into()], stream!("y", 1, peer = "A", args = ["y"]));
+}
+
 #[test]
 fn rfold() {
     let mut vm = create_avm(echo_call_service(), "A");
@@ -171,7 +242,8 @@ fn inner_fold_with_same_iterator() {
 
     let result = call_vm!(vm, <_>::default(), script, "", "");
 
-    let expected_error = PreparationError::AIRParseError("".to_string());
+    let parse_error = air_parser::parse(script).expect_err("air parser should fail on this script");
+    let expected_error = PreparationError::AIRParseError(parse_error);
     assert_eq!(result.ret_code, expected_error.to_error_code());
 }
 
@@ -0,0 +1,126 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air::CatchableError;
+use air::PreparationError;
+use air_test_utils::prelude::*;
+
+#[test]
+fn match_regex_matched() {
+    let set_variable_peer_id = "set_variable_peer_id";
+    let mut set_variable_vm = create_avm(echo_call_service(), set_variable_peer_id);
+
+    let local_peer_id = "local_peer_id";
+    let mut vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = format!(
+        r#"
+            (seq
+                (call "{set_variable_peer_id}" ("" "") ["peer-42"] value)
+                (xor
+                    (match-regex value "^peer-[0-9]+$"
+                        (call "{local_peer_id}" ("service_id_2" "local_fn_name") ["result_1"] result_1)
+                    )
+                    (call "{local_peer_id}" ("service_id_2" "local_fn_name") ["result_2"] result_2)
+                )
+            )"#
+    );
+
+    let result = checked_call_vm!(set_variable_vm, <_>::default(), &script, "", "");
+    let result = checked_call_vm!(vm, <_>::default(), script, "", result.data);
+
+    let actual_trace = trace_from_result(&result);
+    let expected_state = scalar!(
+        "result_1",
+        peer = local_peer_id,
+        service = "service_id_2",
+        function = "local_fn_name",
+        args = ["result_1"]
+    );
+
+    assert_eq!(actual_trace.len(), 2);
+    assert_eq!(actual_trace[1.into()], expected_state);
+}
+
+#[test]
+fn match_regex_not_matched() {
+    let local_peer_id = "local_peer_id";
+    let mut vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = r#"
+            (match-regex "relay-42" "^peer-[0-9]+$"
+                (null)
+            )"#;
+
+    let result = call_vm!(vm, <_>::default(), script, "", "");
+
+    let expected_error = CatchableError::MatchValuesNotEqual;
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn mismatch_regex() {
+    let local_peer_id = "local_peer_id";
+    let mut vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = r#"
+            (seq
+                (mismatch-regex "relay-42" "^peer-[0-9]+$"
+                    (null)
+                )
+                (mismatch-regex "peer-42" "^peer-[0-9]+$"
+                    (null)
+                )
+            )"#;
+
+    let result = call_vm!(vm, <_>::default(), script, "", "");
+
+    let expected_error = CatchableError::MismatchValuesEqual;
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn match_regex_with_number() {
+    let local_peer_id = "local_peer_id";
+    let mut vm = create_avm(echo_call_service(), local_peer_id);
+
+    // values other than strings are matched by their JSON representation
+    let script = r#"
+            (match-regex 1337 "^[0-9]{4}$"
+                (null)
+            )"#;
+
+    let result = call_vm!(vm, <_>::default(), script, "", "");
+
+    assert!(is_interpreter_succeded(&result));
+}
+
+#[test]
+fn match_regex_with_invalid_pattern() {
+    let local_peer_id = "local_peer_id";
+    let mut vm = create_avm(echo_call_service(), local_peer_id);
+
+    let script = r#"
+            (match-regex "peer-42" "[0-9"
+                (null)
+            )"#;
+
+    let result = call_vm!(vm, <_>::default(), script, "", "");
+
+    let error_message = air_parser::parse(script).expect_err("air parser should fail on this script");
+    let expected_error = PreparationError::AIRParseError(error_message);
+    assert!(check_error(&result, expected_error));
+}
@@ -20,6 +20,7 @@ mod canon;
 mod fail;
 mod fold;
 mod match_;
+mod match_regex;
 mod mismatch;
 mod never;
 mod new;
@@ -14,6 +14,7 @@
  * limitations under the License.
  */
 
+use air::ExecutionCidState;
 use air::UncatchableError;
 use air_test_utils::prelude::*;
 
@@ -266,3 +267,64 @@ fn error_with_xor() {
 
     assert_eq!(actual_trace[1.into()], expected_state);
 }
+
+#[test]
+fn xor_recovers_from_malformed_call_result() {
+    use air_interpreter_data::verification::DataVerifier;
+    use air_test_utils::executed_state::ExecutedCallBuilder;
+    use air_test_utils::key_utils::derive_dummy_keypair;
+
+    let (keypair, vm_peer_id) = derive_dummy_keypair("vm_peer_id");
+    let mut vm = create_avm_with_key::<NativeAirRunner>(keypair, echo_call_service(), <_>::default());
+
+    let script = format!(
+        r#"
+            (xor
+                (call "{vm_peer_id}" ("" "") [] result)
+                (call "{vm_peer_id}" ("fallback" "") [:error:.$.error_code] result)
+            )"#
+    );
+
+    // the failed state refers to a value in the cid store that isn't a call service failure
+    let mut cid_state = ExecutionCidState::new();
+    let malformed_state = ExecutedCallBuilder::new("not a failure")
+        .peer(&vm_peer_id)
+        .failed_tracked(&mut cid_state);
+    let data = raw_data_from_trace(vec![malformed_state.clone()], cid_state);
+    let result = checked_call_vm!(vm, <_>::default(), &script, "", data);
+
+    let actual_trace = trace_from_result(&result);
+    assert_eq!(actual_trace.len(), 2);
+    assert_eq!(actual_trace[0.into()], malformed_state);
+    assert!(matches!(
+        &actual_trace[1.into()],
+        ExecutedState::Call(CallResult::Executed(_))
+    ));
+
+    let data = data_from_result(&result);
+    let verifier = DataVerifier::new(&data, "").expect("the output data should be self-consistent");
+    verifier.verify().expect("the output data should be signed");
+}
+
+#[test]
+fn xor_does_not_recover_from_missing_call_result() {
+    let vm_peer_id = "vm_peer_id";
+    let mut vm = create_avm(echo_call_service(), vm_peer_id);
+
+    let script = format!(
+        r#"
+            (xor
+                (call "{vm_peer_id}" ("" "") [] result)
+                (call "{vm_peer_id}" ("fallback" "") [] result)
+            )"#
+    );
+
+    // the trace refers to a call result missing in the cid store
+    let wrong_trace = vec![scalar!(42)];
+    let missing_cid = extract_service_result_cid(&wrong_trace[0]).get_inner();
+    let data = raw_data_from_trace(wrong_trace, <_>::default());
+    let result = call_vm!(vm, <_>::default(), &script, "", data);
+
+    let expected_error = UncatchableError::ValueForCidNotFound("service result aggregate", missing_cid);
+    assert_error_eq!(&result, expected_error);
+}
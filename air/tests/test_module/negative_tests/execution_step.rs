@@ -77,7 +77,7 @@ fn fold_iterates_over_non_array_scalar_iterable() {
         )
         "#
     );
-    let unsupported_jvalue = json!({"attr": 42, });
+    let unsupported_jvalue = json!("attr");
     let mut vm_2 = create_avm(set_variable_call_service(unsupported_jvalue.clone()), vm_2_peer_id);
     let result = vm_2.call(&script, "", "", <_>::default()).unwrap();
     let expected_error = CatchableError::FoldIteratesOverNonArray(unsupported_jvalue.into(), var_name);
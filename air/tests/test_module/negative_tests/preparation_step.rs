@@ -15,7 +15,8 @@
  */
 
 use air::PreparationError;
-use air_interpreter_interface::CallResultsFormat;
+use air_interpreter_interface::CallResultsDeserializeError;
+use air_interpreter_interface::CallResultsMultiformat;
 use air_interpreter_interface::CallResultsRepr;
 use air_interpreter_interface::RunParameters;
 use air_interpreter_interface::MAX_AIR_SIZE;
@@ -97,33 +98,33 @@ fn invalid_callresults() {
     let prev_data: Vec<u8> = prev_data.serialize().unwrap();
     let data = Vec::<u8>::new();
     let vec = Vec::<u8>::new();
-    let wrong_call_results = CallResultsFormat::default().to_vec(&vec).unwrap();
+    let wrong_call_results = CallResultsMultiformat::default().to_vec(&vec).unwrap();
     let keypair = fluence_keypair::KeyPair::generate_ed25519();
     let air_size_limit = MAX_AIR_SIZE;
     let particle_size_limit = MAX_PARTICLE_SIZE;
     let call_result_size_limit = MAX_CALL_RESULT_SIZE;
     let hard_limit_enable = false;
 
-    let run_parameters = RunParameters::new(
-        client_peer_id.clone(),
-        client_peer_id.clone(),
-        0,
-        0,
-        keypair.key_format().into(),
-        keypair.secret().unwrap(),
-        "".to_owned(),
-        air_size_limit,
-        particle_size_limit,
-        call_result_size_limit,
-        hard_limit_enable,
-    );
+    let run_parameters = RunParameters::builder()
+        .init_peer_id(client_peer_id.clone())
+        .current_peer_id(client_peer_id.clone())
+        .timestamp(0)
+        .ttl(0)
+        .key_format(keypair.key_format().into())
+        .secret_key_bytes(keypair.secret().unwrap())
+        .air_size_limit(air_size_limit)
+        .particle_size_limit(particle_size_limit)
+        .call_result_size_limit(call_result_size_limit)
+        .hard_limit_enabled(hard_limit_enable)
+        .build()
+        .unwrap();
 
     let result = air::execute_air(air, prev_data, data, run_parameters, wrong_call_results.clone().into());
     let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
 
     let expected_serde_error = CallResultsRepr.deserialize(&wrong_call_results).unwrap_err();
     let expected_error = PreparationError::CallResultsDeFailed {
-        error: expected_serde_error,
+        error: CallResultsDeserializeError::MsgPack(expected_serde_error),
     };
 
     assert!(check_error(&result, expected_error));
@@ -139,19 +140,17 @@ fn air_size_hard_limit() {
     let call_result_size_limit = MAX_CALL_RESULT_SIZE;
     let hard_limit_enable = true;
 
-    let run_parameters = RunParameters::new(
-        peer_id.clone(),
-        peer_id,
-        0,
-        0,
-        <_>::default(),
-        <_>::default(),
-        "".to_owned(),
-        air_size_limit,
-        particle_size_limit,
-        call_result_size_limit,
-        hard_limit_enable,
-    );
+    let run_parameters = RunParameters::builder()
+        .init_peer_id(peer_id.clone())
+        .current_peer_id(peer_id)
+        .timestamp(0)
+        .ttl(0)
+        .air_size_limit(air_size_limit)
+        .particle_size_limit(particle_size_limit)
+        .call_result_size_limit(call_result_size_limit)
+        .hard_limit_enabled(hard_limit_enable)
+        .build()
+        .unwrap();
 
     let result = air::execute_air(script, vec![], vec![], run_parameters, <_>::default());
     let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
@@ -172,19 +171,17 @@ fn particle_size_hard_limit() {
     let call_result_size_limit = MAX_CALL_RESULT_SIZE;
     let hard_limit_enable = true;
 
-    let run_parameters = RunParameters::new(
-        peer_id.clone(),
-        peer_id,
-        0,
-        0,
-        <_>::default(),
-        <_>::default(),
-        "".to_owned(),
-        air_size_limit,
-        particle_size_limit,
-        call_result_size_limit,
-        hard_limit_enable,
-    );
+    let run_parameters = RunParameters::builder()
+        .init_peer_id(peer_id.clone())
+        .current_peer_id(peer_id)
+        .timestamp(0)
+        .ttl(0)
+        .air_size_limit(air_size_limit)
+        .particle_size_limit(particle_size_limit)
+        .call_result_size_limit(call_result_size_limit)
+        .hard_limit_enabled(hard_limit_enable)
+        .build()
+        .unwrap();
 
     let result = air::execute_air(script, vec![], cur_data, run_parameters, <_>::default());
     let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
@@ -217,19 +214,17 @@ fn call_result_size_hard_limit() {
     let call_result_size_limit = MAX_CALL_RESULT_SIZE;
     let hard_limit_enable = true;
 
-    let run_parameters = RunParameters::new(
-        peer_id.clone(),
-        peer_id,
-        0,
-        0,
-        <_>::default(),
-        <_>::default(),
-        "".to_owned(),
-        air_size_limit,
-        particle_size_limit,
-        call_result_size_limit,
-        hard_limit_enable,
-    );
+    let run_parameters = RunParameters::builder()
+        .init_peer_id(peer_id.clone())
+        .current_peer_id(peer_id)
+        .timestamp(0)
+        .ttl(0)
+        .air_size_limit(air_size_limit)
+        .particle_size_limit(particle_size_limit)
+        .call_result_size_limit(call_result_size_limit)
+        .hard_limit_enabled(hard_limit_enable)
+        .build()
+        .unwrap();
 
     let result = air::execute_air(script, vec![], vec![], run_parameters, raw_call_results);
     let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
@@ -238,3 +233,447 @@ fn call_result_size_hard_limit() {
 
     assert_eq!(result.ret_code, expected_error.to_error_code());
 }
+
+#[test]
+fn call_results_format_mismatch() {
+    use air_interpreter_interface::deserialize_call_results;
+    use air_interpreter_interface::CallResultsFormat;
+    use air_interpreter_sede::ToSerialized;
+    use maplit::hashmap;
+
+    let script = "(null)".to_owned();
+    let call_results: CallResults = hashmap! {0 => CallServiceResult::ok("result".into())};
+    let raw_call_results = into_raw_result(call_results);
+    let raw_call_results = CallResultsRepr.serialize(&raw_call_results).unwrap();
+
+    let peer_id = "some_peer_id".to_owned();
+    let run_parameters = RunParameters::builder()
+        .init_peer_id(peer_id.clone())
+        .current_peer_id(peer_id)
+        .timestamp(0)
+        .ttl(0)
+        .air_size_limit(MAX_AIR_SIZE)
+        .particle_size_limit(MAX_PARTICLE_SIZE)
+        .call_result_size_limit(MAX_CALL_RESULT_SIZE)
+        .call_results_format(CallResultsFormat::Json)
+        .build()
+        .unwrap();
+
+    let expected_serde_error = deserialize_call_results(&raw_call_results, CallResultsFormat::Json).unwrap_err();
+    let result = air::execute_air(script, vec![], vec![], run_parameters, raw_call_results);
+    let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
+
+    let expected_error = PreparationError::CallResultsDeFailed {
+        error: expected_serde_error,
+    };
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn unsupported_call_results_format() {
+    use air_interpreter_interface::UnsupportedCallResultsFormat;
+
+    let script = "(null)".to_owned();
+    let peer_id = "some_peer_id".to_owned();
+    let mut run_parameters = RunParameters::builder()
+        .init_peer_id(peer_id.clone())
+        .current_peer_id(peer_id)
+        .timestamp(0)
+        .ttl(0)
+        .air_size_limit(MAX_AIR_SIZE)
+        .particle_size_limit(MAX_PARTICLE_SIZE)
+        .call_result_size_limit(MAX_CALL_RESULT_SIZE)
+        .build()
+        .unwrap();
+    run_parameters.call_results_format = 42;
+
+    let result = air::execute_air(script, vec![], vec![], run_parameters, <_>::default());
+    let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
+
+    let expected_error = PreparationError::UnsupportedCallResultsFormat(UnsupportedCallResultsFormat(42));
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn lint_level_denies_lints() {
+    use air_interpreter_interface::LintLevel;
+    use air_interpreter_sede::ToSerialized;
+
+    // fold without next is only a warning
+    let script = "(new $stream (seq (ap 1 $stream) (fold $stream i (null))))".to_owned();
+    let keypair = fluence_keypair::KeyPair::generate_ed25519();
+    let run_parameters = |lint_level| {
+        RunParameters::builder()
+            .init_peer_id("some_peer_id")
+            .current_peer_id("some_peer_id")
+            .timestamp(0)
+            .ttl(0)
+            .key_format(keypair.key_format().into())
+            .secret_key_bytes(keypair.secret().unwrap())
+            .lint_level(lint_level)
+            .build()
+            .unwrap()
+    };
+
+    let result = air::execute_air(
+        script.clone(),
+        vec![],
+        vec![],
+        run_parameters(LintLevel::DenyErrors),
+        CallResultsRepr.serialize(&into_raw_result(CallResults::new())).unwrap(),
+    );
+    let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
+    assert_eq!(result.ret_code, 0, "{}", result.error_message);
+
+    let result = air::execute_air(
+        script,
+        vec![],
+        vec![],
+        run_parameters(LintLevel::DenyWarnings),
+        <_>::default(),
+    );
+    let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
+
+    let expected_error = PreparationError::LintsDenied(
+        "warning[fold-without-next] at 33..56: fold body doesn't contain `(next i)`, so only the first value is visited"
+            .to_owned(),
+    );
+    assert_error_eq!(&result, expected_error);
+}
+
+#[test]
+fn unsupported_lint_level() {
+    use air_interpreter_interface::UnsupportedLintLevel;
+
+    let script = "(null)".to_owned();
+    let mut run_parameters = RunParameters::builder()
+        .init_peer_id("some_peer_id")
+        .current_peer_id("some_peer_id")
+        .timestamp(0)
+        .ttl(0)
+        .build()
+        .unwrap();
+    run_parameters.lint_level = 42;
+
+    let result = air::execute_air(script, vec![], vec![], run_parameters, <_>::default());
+    let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
+
+    let expected_error = PreparationError::UnsupportedLintLevel(UnsupportedLintLevel(42));
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn unsupported_signature_policy() {
+    use air_interpreter_interface::UnsupportedSignaturePolicy;
+
+    let script = "(null)".to_owned();
+    let mut run_parameters = RunParameters::builder()
+        .init_peer_id("some_peer_id")
+        .current_peer_id("some_peer_id")
+        .timestamp(0)
+        .ttl(0)
+        .build()
+        .unwrap();
+    run_parameters.signature_policy = 42;
+
+    let result = air::execute_air(script, vec![], vec![], run_parameters, <_>::default());
+    let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
+
+    let expected_error = PreparationError::UnsupportedSignaturePolicy(UnsupportedSignaturePolicy(42));
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn unsupported_key_format_policy() {
+    use air_interpreter_interface::UnsupportedKeyFormatPolicy;
+
+    let script = "(null)".to_owned();
+    let mut run_parameters = RunParameters::builder()
+        .init_peer_id("some_peer_id")
+        .current_peer_id("some_peer_id")
+        .timestamp(0)
+        .ttl(0)
+        .build()
+        .unwrap();
+    run_parameters.key_format_policy = 42;
+
+    let result = air::execute_air(script, vec![], vec![], run_parameters, <_>::default());
+    let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
+
+    let expected_error = PreparationError::UnsupportedKeyFormatPolicy(UnsupportedKeyFormatPolicy(42));
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn data_too_large() {
+    let script = "(null)".to_owned();
+    let cur_data = raw_data_from_trace(vec![executed_state::par(0, 0)], <_>::default());
+    let limit = cur_data.len() - 1;
+    let run_parameters = RunParameters::builder()
+        .init_peer_id("some_peer_id")
+        .current_peer_id("some_peer_id")
+        .timestamp(0)
+        .ttl(0)
+        .max_data_bytes(Some(limit))
+        .build()
+        .unwrap();
+
+    let actual = cur_data.len();
+    let result = air::execute_air(script, vec![], cur_data, run_parameters, <_>::default());
+    let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
+
+    let expected_error = PreparationError::DataTooLarge { actual, limit };
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn trace_too_long() {
+    let script = "(null)".to_owned();
+    // the limit applies to the expanded trace, not to the number of compacted states
+    let mut trace = ExecutionTrace::from(vec![executed_state::par(0, 0); 5]);
+    trace.compact();
+    assert_eq!(trace.len(), 2);
+    let cur_data = raw_data_from_trace(trace, <_>::default());
+    let run_parameters = RunParameters::builder()
+        .init_peer_id("some_peer_id")
+        .current_peer_id("some_peer_id")
+        .timestamp(0)
+        .ttl(0)
+        .max_trace_len(Some(4))
+        .build()
+        .unwrap();
+
+    let result = air::execute_air(script, vec![], cur_data, run_parameters, <_>::default());
+    let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
+
+    let expected_error = PreparationError::TraceTooLong { actual: 5, limit: 4 };
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn trace_expansion_is_limited() {
+    use air_interpreter_data::DataDeserializationError;
+    use air_interpreter_data::MAX_EXPANDED_TRACE_LEN;
+
+    let script = "(null)".to_owned();
+    // a couple of states expanding to billions of them, the trace length isn't limited by default
+    let trace = vec![executed_state::par(0, 0), ExecutedState::Repeated(u32::MAX)];
+    let cur_data = raw_data_from_trace(trace, <_>::default());
+    let run_parameters = RunParameters::builder()
+        .init_peer_id("some_peer_id")
+        .current_peer_id("some_peer_id")
+        .timestamp(0)
+        .ttl(0)
+        .build()
+        .unwrap();
+
+    let result = air::execute_air(script, vec![], cur_data, run_parameters, <_>::default());
+    let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
+
+    let expected_error = PreparationError::data_de_failed(DataDeserializationError::TraceTooLong {
+        expanded_len: u32::MAX as usize + 1,
+        limit: MAX_EXPANDED_TRACE_LEN,
+    });
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn too_many_instructions() {
+    use air_interpreter_sede::ToSerialized;
+
+    let script = "(seq (null) (seq (null) (null)))".to_owned();
+    let keypair = fluence_keypair::KeyPair::generate_ed25519();
+    let run_parameters = |max_instruction_count| {
+        RunParameters::builder()
+            .init_peer_id("some_peer_id")
+            .current_peer_id("some_peer_id")
+            .timestamp(0)
+            .ttl(0)
+            .key_format(keypair.key_format().into())
+            .secret_key_bytes(keypair.secret().unwrap())
+            .max_instruction_count(Some(max_instruction_count))
+            .build()
+            .unwrap()
+    };
+
+    let result = air::execute_air(script.clone(), vec![], vec![], run_parameters(4), <_>::default());
+    let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
+
+    let expected_error = PreparationError::TooManyInstructions { actual: 5, limit: 4 };
+    assert!(check_error(&result, expected_error), "{}", result.error_message);
+
+    let call_results = CallResultsRepr.serialize(&into_raw_result(CallResults::new())).unwrap();
+    let result = air::execute_air(script, vec![], vec![], run_parameters(5), call_results);
+    let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
+    assert_eq!(result.ret_code, 0, "{}", result.error_message);
+}
+
+#[test]
+fn recovery_hints() {
+    use air::RecoveryHint;
+    use air::ToErrorCode;
+    use air_interpreter_interface::UnsupportedSignaturePolicy;
+
+    let script = "(null)".to_owned();
+    let run_parameters = RunParameters::builder()
+        .init_peer_id("some_peer_id")
+        .current_peer_id("some_peer_id")
+        .timestamp(0)
+        .ttl(0)
+        .build()
+        .unwrap();
+
+    let result = air::execute_air(script, vec![], vec![1, 2, 3], run_parameters, <_>::default());
+    let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
+    assert_eq!(
+        PreparationError::recovery_hint_from_error_code(result.ret_code),
+        Some(RecoveryHint::Retry)
+    );
+
+    let error = PreparationError::UnsupportedSignaturePolicy(UnsupportedSignaturePolicy(42));
+    assert_eq!(error.recovery_hint(), RecoveryHint::Fatal);
+    assert_eq!(
+        PreparationError::recovery_hint_from_error_code(error.to_error_code()),
+        Some(RecoveryHint::Fatal)
+    );
+
+    let error =
+        PreparationError::unsupported_interpreter_version(semver::Version::new(0, 1, 0), semver::Version::new(1, 0, 0));
+    assert_eq!(error.recovery_hint(), RecoveryHint::Upgrade);
+
+    let error = PreparationError::DataTooLarge { actual: 2, limit: 1 };
+    assert_eq!(error.recovery_hint(), RecoveryHint::Discard);
+
+    assert_eq!(PreparationError::recovery_hint_from_error_code(0), None);
+    assert_eq!(PreparationError::recovery_hint_from_error_code(10000), None);
+}
+
+#[test]
+fn particle_expired() {
+    use air_interpreter_sede::ToSerialized;
+    use std::time::Duration;
+    use std::time::SystemTime;
+    use std::time::UNIX_EPOCH;
+
+    let script = "(null)".to_owned();
+    let keypair = fluence_keypair::KeyPair::generate_ed25519();
+    let run_parameters = RunParameters::builder()
+        .init_peer_id("some_peer_id")
+        .current_peer_id("some_peer_id")
+        .timestamp(1000)
+        .ttl(1000)
+        .reject_expired_particles(true)
+        .build()
+        .unwrap();
+
+    let result = air::execute_air(script.clone(), vec![], vec![], run_parameters, <_>::default());
+    let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
+
+    let expected_error = PreparationError::ParticleExpired {
+        expired_at: UNIX_EPOCH + Duration::from_secs(2),
+    };
+    assert!(check_error(&result, expected_error));
+
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    let run_parameters = RunParameters::builder()
+        .init_peer_id("some_peer_id")
+        .current_peer_id("some_peer_id")
+        .timestamp(now.as_millis() as u64)
+        .ttl(3_600_000)
+        .key_format(keypair.key_format().into())
+        .secret_key_bytes(keypair.secret().unwrap())
+        .reject_expired_particles(true)
+        .build()
+        .unwrap();
+
+    let call_results = CallResultsRepr.serialize(&into_raw_result(CallResults::new())).unwrap();
+    let result = air::execute_air(script, vec![], vec![], run_parameters, call_results);
+    let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
+    assert_eq!(result.ret_code, 0, "{}", result.error_message);
+}
+
+#[test]
+fn instruction_forbidden() {
+    use air_interpreter_interface::InstructionPolicy;
+    use air_interpreter_interface::InstructionTag;
+    use air_interpreter_sede::ToSerialized;
+    use air_parser::ast::Span;
+
+    use std::collections::HashSet;
+
+    let script = r#"(seq (null) (seq (null) (call "peer" ("service" "function") [])))"#.to_owned();
+    let keypair = fluence_keypair::KeyPair::generate_ed25519();
+    let run_parameters = |allowed: &[InstructionTag]| {
+        RunParameters::builder()
+            .init_peer_id("some_peer_id")
+            .current_peer_id("some_peer_id")
+            .timestamp(0)
+            .ttl(0)
+            .key_format(keypair.key_format().into())
+            .secret_key_bytes(keypair.secret().unwrap())
+            .instruction_policy(InstructionPolicy::allow_list(HashSet::from_iter(
+                allowed.iter().copied(),
+            )))
+            .build()
+            .unwrap()
+    };
+
+    let seq_and_null = run_parameters(&[InstructionTag::Seq, InstructionTag::Null]);
+    let result = air::execute_air(script.clone(), vec![], vec![], seq_and_null, <_>::default());
+    let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
+
+    let expected_error = PreparationError::InstructionForbidden {
+        instruction: InstructionTag::Call,
+        span: Span::new(25.into(), 29.into()),
+    };
+    assert!(check_error(&result, expected_error), "{}", result.error_message);
+
+    // the first of forbidden instructions in the script is reported
+    let seq_and_call = run_parameters(&[InstructionTag::Seq, InstructionTag::Call]);
+    let result = air::execute_air(script.clone(), vec![], vec![], seq_and_call, <_>::default());
+    let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
+
+    let expected_error = PreparationError::InstructionForbidden {
+        instruction: InstructionTag::Null,
+        span: Span::new(6.into(), 10.into()),
+    };
+    assert!(check_error(&result, expected_error), "{}", result.error_message);
+
+    let all_used = run_parameters(&[InstructionTag::Seq, InstructionTag::Null, InstructionTag::Call]);
+    let call_results = CallResultsRepr.serialize(&into_raw_result(CallResults::new())).unwrap();
+    let result = air::execute_air(script, vec![], vec![], all_used, call_results);
+    let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
+    assert_eq!(result.ret_code, 0, "{}", result.error_message);
+}
+
+#[test]
+fn regex_match_forbidden_separately() {
+    use air_interpreter_interface::InstructionPolicy;
+    use air_interpreter_interface::InstructionTag;
+    use air_parser::ast::Span;
+
+    use std::collections::HashSet;
+
+    let script = r#"(seq (match 1 1 (null)) (match-regex "a" "^a$" (null)))"#.to_owned();
+    let keypair = fluence_keypair::KeyPair::generate_ed25519();
+    let allowed = [InstructionTag::Seq, InstructionTag::Match, InstructionTag::Null];
+    let run_parameters = RunParameters::builder()
+        .init_peer_id("some_peer_id")
+        .current_peer_id("some_peer_id")
+        .timestamp(0)
+        .ttl(0)
+        .key_format(keypair.key_format().into())
+        .secret_key_bytes(keypair.secret().unwrap())
+        .instruction_policy(InstructionPolicy::allow_list(HashSet::from_iter(allowed)))
+        .build()
+        .unwrap();
+
+    let result = air::execute_air(script, vec![], vec![], run_parameters, <_>::default());
+    let result = RawAVMOutcome::from_interpreter_outcome(result).unwrap();
+
+    let expected_error = PreparationError::InstructionForbidden {
+        instruction: InstructionTag::MatchRegex,
+        span: Span::new(25.into(), 36.into()),
+    };
+    assert!(check_error(&result, expected_error), "{}", result.error_message);
+}
@@ -19,7 +19,9 @@ use air::ExecutionCidState;
 use air::UncatchableError::*;
 use air_interpreter_data::RawValue;
 use air_interpreter_data::ValueRef;
+use air_interpreter_interface::INTERPRETER_SUCCESS;
 use air_test_framework::AirScriptExecutor;
+use air_test_utils::key_utils::derive_dummy_keypair;
 use air_test_utils::prelude::*;
 
 #[test]
@@ -191,3 +193,205 @@ fn recursive_stream_size_limit() {
     let expected_error = StreamSizeLimitExceeded;
     assert!(check_error(&result, expected_error));
 }
+
+#[test]
+fn fuel_exhausted() {
+    let vm_peer_id = "vm_peer_id";
+    let (keypair, _) = derive_dummy_keypair(vm_peer_id);
+    let test_init_parameters = TestInitParameters {
+        fuel_limit: Some(3),
+        ..<_>::default()
+    };
+    let mut peer_vm = create_avm_with_key::<DefaultAirRunner>(keypair, unit_call_service(), test_init_parameters);
+
+    let script = r#"
+        (seq
+            (seq
+                (null)
+                (null)
+            )
+            (null)
+        )"#;
+
+    let result = peer_vm.call(script, "", "", <_>::default()).unwrap();
+    let expected_error = FuelExhausted { consumed: 3 };
+    assert!(check_error(&result, expected_error));
+    assert_eq!(result.fuel_consumed, 3);
+}
+
+#[test]
+fn fuel_enough_for_execution() {
+    let vm_peer_id = "vm_peer_id";
+    let (keypair, _) = derive_dummy_keypair(vm_peer_id);
+    let test_init_parameters = TestInitParameters {
+        fuel_limit: Some(5),
+        ..<_>::default()
+    };
+    let mut peer_vm = create_avm_with_key::<DefaultAirRunner>(keypair, unit_call_service(), test_init_parameters);
+
+    let script = r#"
+        (seq
+            (seq
+                (null)
+                (null)
+            )
+            (null)
+        )"#;
+
+    let result = peer_vm.call(script, "", "", <_>::default()).unwrap();
+    assert_eq!(result.ret_code, INTERPRETER_SUCCESS);
+    assert_eq!(result.fuel_consumed, 5);
+}
+
+#[test]
+fn execution_timed_out() {
+    use air::ToErrorCode;
+
+    let vm_peer_id = "vm_peer_id";
+    let (keypair, _) = derive_dummy_keypair(vm_peer_id);
+    let test_init_parameters = TestInitParameters {
+        max_execution_time: Some(std::time::Duration::ZERO),
+        ..<_>::default()
+    };
+    // the Wasm runner reports a timeout as a host error, so the interpreter is checked natively
+    let mut peer_vm = create_avm_with_key::<NativeAirRunner>(keypair, unit_call_service(), test_init_parameters);
+
+    let script = r#"
+        (seq
+            (null)
+            (null)
+        )"#;
+
+    let result = peer_vm.call(script, "", "", <_>::default()).unwrap();
+    let expected_error = ExecutionTimedOut { elapsed_ms: 0 };
+    assert_eq!(result.ret_code, expected_error.to_error_code());
+    assert!(result.data.is_empty());
+}
+
+#[test]
+fn execution_time_enough() {
+    let vm_peer_id = "vm_peer_id";
+    let (keypair, _) = derive_dummy_keypair(vm_peer_id);
+    let test_init_parameters = TestInitParameters {
+        max_execution_time: Some(std::time::Duration::from_secs(3600)),
+        ..<_>::default()
+    };
+    let mut peer_vm = create_avm_with_key::<DefaultAirRunner>(keypair, unit_call_service(), test_init_parameters);
+
+    let script = r#"
+        (seq
+            (null)
+            (null)
+        )"#;
+
+    let result = peer_vm.call(script, "", "", <_>::default()).unwrap();
+    assert_eq!(result.ret_code, INTERPRETER_SUCCESS);
+}
+
+#[test]
+fn fold_depth_exceeded() {
+    let vm_peer_id = "vm_peer_id";
+    let (keypair, peer_id) = derive_dummy_keypair(vm_peer_id);
+    let test_init_parameters = TestInitParameters {
+        max_fold_depth: Some(3),
+        ..<_>::default()
+    };
+    let call_service = set_variable_call_service(json!([1, 2, 3, 4, 5]));
+    let mut peer_vm = create_avm_with_key::<DefaultAirRunner>(keypair, call_service, test_init_parameters);
+
+    // every iteration is executed inside the previous one, so they nest like folds
+    let script = format!(
+        r#"
+        (seq
+            (call "{peer_id}" ("" "") [] array)
+            (fold array i
+                (seq
+                    (null)
+                    (next i)
+                )
+            )
+        )"#
+    );
+
+    let result = peer_vm.call(&script, "", "", <_>::default()).unwrap();
+    let expected_error = FoldDepthExceeded { depth: 4 };
+    assert!(check_error(&result, expected_error));
+}
+
+#[test]
+fn nested_folds_within_depth_limit() {
+    let vm_peer_id = "vm_peer_id";
+    let (keypair, peer_id) = derive_dummy_keypair(vm_peer_id);
+    let test_init_parameters = TestInitParameters {
+        max_fold_depth: Some(6),
+        ..<_>::default()
+    };
+    let call_service = set_variable_call_service(json!([1, 2]));
+    let mut peer_vm = create_avm_with_key::<DefaultAirRunner>(keypair, call_service, test_init_parameters);
+
+    // the deepest point is the second iteration of the innermost fold executed
+    // inside the second iterations of the outer ones
+    let script = format!(
+        r#"
+        (seq
+            (call "{peer_id}" ("" "") [] array)
+            (fold array i
+                (seq
+                    (fold array j
+                        (seq
+                            (fold array k
+                                (seq
+                                    (null)
+                                    (next k)
+                                )
+                            )
+                            (next j)
+                        )
+                    )
+                    (next i)
+                )
+            )
+        )"#
+    );
+
+    let result = peer_vm.call(&script, "", "", <_>::default()).unwrap();
+    assert_eq!(result.ret_code, INTERPRETER_SUCCESS);
+}
+
+#[test]
+fn malformed_value_is_reported_on_access() {
+    let peer_id = "init_peer_id";
+    let mut cid_state = ExecutionCidState::new();
+
+    // values from data are parsed only when they're accessed
+    let raw_value: RawValue = serde_json::from_value(json!("{not a json")).unwrap();
+    let value_cid = cid_state.value_tracker.track_raw_value(raw_value);
+    let tetraplet = SecurityTetraplet::new(peer_id, "", "", "");
+    let tetraplet_cid = cid_state.tetraplet_tracker.track_value(tetraplet).unwrap();
+    let service_result_agg = ServiceResultCidAggregate {
+        value_cid,
+        argument_hash: "bagaaihra2u6rrqrsclvhwyyalff3rg6omaqy63x7foowfc4myqwt46n32wvq".into(),
+        tetraplet_cid,
+    };
+    let service_result_agg_cid = cid_state
+        .service_result_agg_tracker
+        .track_value(service_result_agg)
+        .unwrap();
+
+    let trace = ExecutionTrace::from(vec![ExecutedState::Call(CallResult::Executed(ValueRef::Scalar(
+        service_result_agg_cid,
+    )))]);
+    let data = raw_data_from_trace(trace, cid_state);
+
+    let mut vm = create_avm(unit_call_service(), peer_id);
+    let air = format!(
+        r#"(seq
+            (call "{peer_id}" ("" "") [] var)
+            (ap var.$.field scalar)
+        )"#
+    );
+    let result = vm.call(&air, vec![], data, TestRunParameters::default()).unwrap();
+    let expected_serde_error = serde_json::from_str::<serde_json::Value>("{not a json").unwrap_err();
+    let expected_error = MalformedValue(expected_serde_error);
+    assert_error_eq!(&result, expected_error);
+}
@@ -28,6 +28,7 @@
 
 mod call_request_parameters;
 mod call_service_result;
+mod memory_stats;
 mod outcome;
 mod particle_parameters;
 pub mod raw_outcome;
@@ -73,12 +74,52 @@ pub enum CallSeDeErrors {
         call_params: air_interpreter_interface::CallRequestParams,
         de_error: TetrapletDeserializeError,
     },
+
+    /// Errors encountered while trying to deserialize instruction counts
+    /// collected by the interpreter as a JSON object.
+    #[error(
+        "error occurred while deserialization of instruction counts `{raw_instruction_counts:?}`: {de_error}"
+    )]
+    InstructionCountsDeFailed {
+        raw_instruction_counts: Vec<u8>,
+        de_error: serde_json::Error,
+    },
+
+    /// Errors encountered while trying to deserialize a context of an execution error
+    /// passed by the interpreter as a JSON object.
+    #[error(
+        "error occurred while deserialization of error context `{raw_error_context:?}`: {de_error}"
+    )]
+    ErrorContextDeFailed {
+        raw_error_context: Vec<u8>,
+        de_error: serde_json::Error,
+    },
+
+    /// Errors encountered while trying to deserialize a tree of executed instructions
+    /// passed by the interpreter as a JSON array.
+    #[error(
+        "error occurred while deserialization of execution tree `{raw_execution_tree:?}`: {de_error}"
+    )]
+    ExecutionTreeDeFailed {
+        raw_execution_tree: Vec<u8>,
+        de_error: serde_json::Error,
+    },
+
+    /// Errors encountered when a script fingerprint passed by the interpreter isn't 32 bytes long.
+    #[error("script fingerprint `{raw_air_fingerprint:?}` isn't 32 bytes long")]
+    AirFingerprintDeFailed { raw_air_fingerprint: Vec<u8> },
 }
 
 type JValue = serde_json::Value;
 
+pub use air_interpreter_interface::ErrorContext;
+pub use air_interpreter_interface::ExecutionTree;
+pub use air_interpreter_interface::InstructionCounts;
+pub use air_interpreter_interface::InstructionTag;
 pub use air_interpreter_interface::SoftLimitsTriggering;
+pub use air_interpreter_interface::TraceNode;
 pub use call_request_parameters::*;
 pub use call_service_result::*;
+pub use memory_stats::*;
 pub use outcome::*;
 pub use particle_parameters::*;
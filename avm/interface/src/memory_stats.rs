@@ -0,0 +1,45 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Size of a Wasm linear memory page in bytes.
+pub const WASM_PAGE_SIZE: u64 = 64 * 1024;
+
+/// Wasm linear memory usage of the interpreter module measured after a call.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryStats {
+    /// Number of Wasm pages the linear memory consists of.
+    pub pages_used: u32,
+
+    /// Size of the linear memory in bytes, it includes not only heap,
+    /// but globals, shadow stack and so on.
+    pub bytes_allocated: u64,
+
+    /// The biggest linear memory size in bytes observed since the interpreter was loaded.
+    pub peak_bytes: u64,
+}
+
+impl MemoryStats {
+    pub fn new(bytes_allocated: u64, peak_bytes: u64) -> Self {
+        Self {
+            pages_used: (bytes_allocated / WASM_PAGE_SIZE) as u32,
+            bytes_allocated,
+            peak_bytes,
+        }
+    }
+}
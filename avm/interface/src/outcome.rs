@@ -15,8 +15,11 @@
  */
 
 use super::CallRequests;
+use super::MemoryStats;
 use crate::raw_outcome::RawAVMOutcome;
 
+use air_interpreter_interface::ErrorContext;
+use air_interpreter_interface::InstructionCounts;
 use air_interpreter_interface::SoftLimitsTriggering;
 use serde::Deserialize;
 use serde::Serialize;
@@ -38,37 +41,63 @@ pub struct AVMOutcome {
     /// Memory in bytes AVM linear heap was extended during execution by.
     pub memory_delta: usize,
 
+    /// Linear memory usage of the interpreter measured right after execution.
+    pub memory_stats: MemoryStats,
+
     /// Time of a particle execution
     /// (it counts only execution time without operations with DataStore and so on)
     pub execution_time: Duration,
 
     /// To store and convey soft limits triggering flags.
     pub soft_limits_triggering: SoftLimitsTriggering,
+
+    /// Amount of fuel consumed during execution, one unit per executed instruction.
+    pub fuel_consumed: u64,
+
+    /// Numbers of dispatched instructions of each kind,
+    /// it's empty unless the interpreter is built with the `metrics` feature.
+    pub instruction_counts: InstructionCounts,
+
+    /// SVG flamegraph of time spent in executed instructions that can be rendered as is,
+    /// it's empty unless the interpreter is built with the `flamegraph` feature.
+    pub flamegraph_svg: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ErrorAVMOutcome {
     pub error_code: i64,
     pub error_message: String,
+    /// The instruction that raised the error and its location in the script,
+    /// it's set only if the error happened during execution of an instruction.
+    pub error_context: Option<ErrorContext>,
     pub outcome: AVMOutcome,
 }
 
 impl AVMOutcome {
+    #[allow(clippy::too_many_arguments)]
     fn new(
         data: Vec<u8>,
         call_requests: CallRequests,
         next_peer_pks: Vec<String>,
         memory_delta: usize,
+        memory_stats: MemoryStats,
         execution_time: Duration,
         soft_limits_triggering: SoftLimitsTriggering,
+        fuel_consumed: u64,
+        instruction_counts: InstructionCounts,
+        flamegraph_svg: String,
     ) -> Self {
         Self {
             data,
             call_requests,
             next_peer_pks,
             memory_delta,
+            memory_stats,
             execution_time,
             soft_limits_triggering,
+            fuel_consumed,
+            instruction_counts,
+            flamegraph_svg,
         }
     }
 
@@ -76,6 +105,7 @@ impl AVMOutcome {
     pub fn from_raw_outcome(
         raw_outcome: RawAVMOutcome,
         memory_delta: usize,
+        memory_stats: MemoryStats,
         execution_time: Duration,
     ) -> Result<Self, ErrorAVMOutcome> {
         use air_interpreter_interface::INTERPRETER_SUCCESS;
@@ -87,6 +117,11 @@ impl AVMOutcome {
             call_requests,
             next_peer_pks,
             soft_limits_triggering,
+            fuel_consumed,
+            instruction_counts,
+            flamegraph_svg,
+            error_context,
+            air_fingerprint: _,
         } = raw_outcome;
 
         let avm_outcome = AVMOutcome::new(
@@ -94,23 +129,38 @@ impl AVMOutcome {
             call_requests,
             next_peer_pks,
             memory_delta,
+            memory_stats,
             execution_time,
             soft_limits_triggering,
+            fuel_consumed,
+            instruction_counts,
+            flamegraph_svg,
         );
 
         if ret_code == INTERPRETER_SUCCESS {
             Ok(avm_outcome)
         } else {
-            Err(ErrorAVMOutcome::new(ret_code, error_message, avm_outcome))
+            Err(ErrorAVMOutcome::new(
+                ret_code,
+                error_message,
+                error_context,
+                avm_outcome,
+            ))
         }
     }
 }
 
 impl ErrorAVMOutcome {
-    fn new(error_code: i64, error_message: String, outcome: AVMOutcome) -> Self {
+    fn new(
+        error_code: i64,
+        error_message: String,
+        error_context: Option<ErrorContext>,
+        outcome: AVMOutcome,
+    ) -> Self {
         Self {
             error_code,
             error_message,
+            error_context,
             outcome,
         }
     }
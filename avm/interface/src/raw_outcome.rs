@@ -18,6 +18,9 @@ use crate::CallSeDeErrors;
 
 use super::CallRequests;
 
+use air_interpreter_interface::ErrorContext;
+use air_interpreter_interface::ExecutionTree;
+use air_interpreter_interface::InstructionCounts;
 use air_interpreter_interface::InterpreterOutcome;
 
 use air_interpreter_interface::SoftLimitsTriggering;
@@ -33,6 +36,15 @@ pub struct RawAVMOutcome {
     pub call_requests: CallRequests,
     pub next_peer_pks: Vec<String>,
     pub soft_limits_triggering: SoftLimitsTriggering,
+    pub fuel_consumed: u64,
+    /// Numbers of dispatched instructions of each kind, it's empty unless the interpreter collects metrics.
+    pub instruction_counts: InstructionCounts,
+    /// SVG flamegraph of the execution, it's empty unless the interpreter renders flamegraphs.
+    pub flamegraph_svg: String,
+    /// The instruction that raised the execution error, it's set only if an instruction failed.
+    pub error_context: Option<ErrorContext>,
+    /// Fingerprint of the executed script, it's set only if the interpreter caches the script.
+    pub air_fingerprint: Option<[u8; 32]>,
 }
 
 impl RawAVMOutcome {
@@ -46,9 +58,19 @@ impl RawAVMOutcome {
             air_size_limit_exceeded,
             particle_size_limit_exceeded,
             call_result_size_limit_exceeded,
+            fuel_consumed,
+            instruction_counts,
+            flamegraph_svg,
+            error_context,
+            // it's returned apart from the outcome by runners invoking the interpreter traced
+            execution_tree: _,
+            air_fingerprint,
         } = outcome;
 
         let call_requests = crate::from_raw_call_requests(call_requests.into())?;
+        let instruction_counts = from_raw_instruction_counts(instruction_counts)?;
+        let error_context = from_raw_error_context(error_context)?;
+        let air_fingerprint = from_raw_air_fingerprint(air_fingerprint)?;
         let soft_limits_triggering = SoftLimitsTriggering::new(
             air_size_limit_exceeded,
             particle_size_limit_exceeded,
@@ -62,8 +84,86 @@ impl RawAVMOutcome {
             call_requests,
             next_peer_pks,
             soft_limits_triggering,
+            fuel_consumed,
+            instruction_counts,
+            flamegraph_svg,
+            error_context,
+            air_fingerprint,
         };
 
         Ok(raw_avm_outcome)
     }
+
+    /// Like `from_interpreter_outcome`, but also returns the tree of executed instructions
+    /// put into the outcome by a traced invocation of the interpreter.
+    #[allow(clippy::result_large_err)]
+    pub fn from_traced_interpreter_outcome(
+        mut outcome: InterpreterOutcome,
+    ) -> Result<(Self, ExecutionTree), CallSeDeErrors> {
+        let execution_tree = from_raw_execution_tree(std::mem::take(&mut outcome.execution_tree))?;
+        let raw_avm_outcome = Self::from_interpreter_outcome(outcome)?;
+
+        Ok((raw_avm_outcome, execution_tree))
+    }
+}
+
+#[allow(clippy::result_large_err)]
+fn from_raw_instruction_counts(
+    raw_instruction_counts: Vec<u8>,
+) -> Result<InstructionCounts, CallSeDeErrors> {
+    if raw_instruction_counts.is_empty() {
+        return Ok(<_>::default());
+    }
+
+    serde_json::from_slice(&raw_instruction_counts).map_err(|de_error| {
+        CallSeDeErrors::InstructionCountsDeFailed {
+            raw_instruction_counts,
+            de_error,
+        }
+    })
+}
+
+#[allow(clippy::result_large_err)]
+fn from_raw_air_fingerprint(
+    raw_air_fingerprint: Vec<u8>,
+) -> Result<Option<[u8; 32]>, CallSeDeErrors> {
+    if raw_air_fingerprint.is_empty() {
+        return Ok(None);
+    }
+
+    <[u8; 32]>::try_from(raw_air_fingerprint.as_slice())
+        .map(Some)
+        .map_err(|_| CallSeDeErrors::AirFingerprintDeFailed {
+            raw_air_fingerprint,
+        })
+}
+
+#[allow(clippy::result_large_err)]
+fn from_raw_error_context(
+    raw_error_context: Vec<u8>,
+) -> Result<Option<ErrorContext>, CallSeDeErrors> {
+    if raw_error_context.is_empty() {
+        return Ok(None);
+    }
+
+    serde_json::from_slice(&raw_error_context)
+        .map(Some)
+        .map_err(|de_error| CallSeDeErrors::ErrorContextDeFailed {
+            raw_error_context,
+            de_error,
+        })
+}
+
+#[allow(clippy::result_large_err)]
+fn from_raw_execution_tree(raw_execution_tree: Vec<u8>) -> Result<ExecutionTree, CallSeDeErrors> {
+    if raw_execution_tree.is_empty() {
+        return Ok(<_>::default());
+    }
+
+    serde_json::from_slice(&raw_execution_tree).map_err(|de_error| {
+        CallSeDeErrors::ExecutionTreeDeFailed {
+            raw_execution_tree,
+            de_error,
+        }
+    })
 }
@@ -0,0 +1,94 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::collections::HashMap;
+
+/// Error codes of `PreparationError::CidStoreVerificationError` and
+/// `PreparationError::DataSignatureCheckError` returned by the interpreter,
+/// they mean that the data carries invalid signatures.
+pub(crate) const INVALID_SIGNATURE_ERROR_CODES: [i64; 2] = [8, 9];
+
+/// Controls how reputations of peers change with outcomes of their particles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReputationConfig {
+    /// Reputation of a peer no outcome was reported for.
+    pub initial_reputation: f64,
+
+    /// Share of the distance to the best or the worst reputation a reputation moves by
+    /// after a successful or a failed execution.
+    pub learning_rate: f64,
+
+    /// Particles of peers with a reputation below this threshold should be delayed.
+    pub delay_threshold: f64,
+}
+
+impl Default for ReputationConfig {
+    fn default() -> Self {
+        Self {
+            initial_reputation: 1.0,
+            learning_rate: 0.1,
+            delay_threshold: 0.5,
+        }
+    }
+}
+
+/// Reputations of peers in the range from 0 to 1, where 1 is the best one.
+#[derive(Debug)]
+pub(crate) struct Reputations {
+    config: ReputationConfig,
+    scores: HashMap<String, f64>,
+}
+
+impl Reputations {
+    pub(crate) fn new(config: ReputationConfig) -> Self {
+        Self {
+            config,
+            scores: <_>::default(),
+        }
+    }
+
+    pub(crate) fn reputation_of(&self, peer_id: &str) -> f64 {
+        self.scores
+            .get(peer_id)
+            .copied()
+            .unwrap_or(self.config.initial_reputation)
+    }
+
+    pub(crate) fn is_low(&self, peer_id: &str) -> bool {
+        self.reputation_of(peer_id) < self.config.delay_threshold
+    }
+
+    pub(crate) fn record_success(&mut self, peer_id: &str) {
+        self.move_towards(peer_id, 1.0);
+    }
+
+    pub(crate) fn record_failure(&mut self, peer_id: &str) {
+        self.move_towards(peer_id, 0.0);
+    }
+
+    /// Forged signatures aren't a transient failure, so the reputation drops to the worst one
+    /// and the peer has to earn it back with successful executions.
+    pub(crate) fn record_invalid_signature(&mut self, peer_id: &str) {
+        self.scores.insert(peer_id.to_owned(), 0.0);
+    }
+
+    fn move_towards(&mut self, peer_id: &str, target: f64) {
+        let reputation = self.reputation_of(peer_id);
+        let reputation = reputation + self.config.learning_rate * (target - reputation);
+        self.scores
+            .insert(peer_id.to_owned(), reputation.clamp(0.0, 1.0));
+    }
+}
@@ -0,0 +1,277 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::reputation::Reputations;
+use crate::reputation::INVALID_SIGNATURE_ERROR_CODES;
+use crate::ReputationConfig;
+
+use avm_server::avm_runner::SendSafeRunner;
+use avm_server::AVMError;
+use avm_server::AVMOutcome;
+use avm_server::AVMResult;
+use avm_server::CallResults;
+use avm_server::CancellationToken;
+use avm_server::InterpreterRunner;
+use avm_server::ParticleParameters;
+use avm_server::AVM;
+use fluence_keypair::KeyPair;
+
+use std::collections::HashMap;
+
+/// Wraps `AVM` and keeps reputations of peers particles are received from.
+///
+/// Outcomes of executions are attributed to the peer a particle was received from,
+/// so a host can delay or deprioritize particles of peers that keep sending data which
+/// fails execution or carries invalid signatures.
+pub struct ReputationAwareAVM<E, R = SendSafeRunner> {
+    avm: AVM<E, R>,
+    reputations: Reputations,
+    /// Peers particles were last received from by particle ids.
+    senders: HashMap<String, String>,
+}
+
+impl<E, R: InterpreterRunner> ReputationAwareAVM<E, R> {
+    pub fn new(avm: AVM<E, R>, config: ReputationConfig) -> Self {
+        Self {
+            avm,
+            reputations: Reputations::new(config),
+            senders: <_>::default(),
+        }
+    }
+
+    /// Executes a particle received from `sender_peer_id` like `AVM::call_with_cancellation`
+    /// and updates the sender reputation with the result. `cancel_token` is checked only
+    /// before execution starts, a running execution isn't interrupted.
+    #[allow(clippy::result_large_err)]
+    #[allow(clippy::too_many_arguments)]
+    pub fn call(
+        &mut self,
+        sender_peer_id: impl Into<String>,
+        air: impl Into<String>,
+        data: impl Into<Vec<u8>>,
+        particle_parameters: ParticleParameters<'_>,
+        call_results: CallResults,
+        keypair: &KeyPair,
+        cancel_token: Option<CancellationToken>,
+    ) -> AVMResult<AVMOutcome, E> {
+        let particle_id = particle_parameters.particle_id.to_string();
+        self.senders
+            .insert(particle_id.clone(), sender_peer_id.into());
+
+        let result = self.avm.call_with_cancellation(
+            air,
+            data,
+            particle_parameters,
+            call_results,
+            keypair,
+            cancel_token,
+        );
+        match &result {
+            Ok(outcome) => self.report_outcome(&particle_id, outcome),
+            Err(error) => self.report_error(&particle_id, error),
+        }
+
+        result
+    }
+
+    /// Raises the reputation of the peer the particle was last received from,
+    /// `call` reports its outcomes itself, so it should be used only for outcomes
+    /// of executions made with `avm_mut`.
+    ///
+    /// A successful outcome means that the data signatures were valid, since the interpreter
+    /// verifies them before execution.
+    pub fn report_outcome(&mut self, particle_id: &str, _outcome: &AVMOutcome) {
+        if let Some(sender) = self.senders.get(particle_id) {
+            self.reputations.record_success(sender);
+        }
+    }
+
+    /// Returns the reputation of a peer in the range from 0 to 1, where 1 is the best one.
+    pub fn reputation_of(&self, peer_id: &str) -> f64 {
+        self.reputations.reputation_of(peer_id)
+    }
+
+    /// Tells whether particles received from the peer should be delayed
+    /// because its reputation is below `ReputationConfig::delay_threshold`.
+    pub fn should_delay(&self, peer_id: &str) -> bool {
+        self.reputations.is_low(peer_id)
+    }
+
+    /// Reorders pending particles, so particles of peers with better reputations go first,
+    /// the order of particles of peers with the same reputation is kept.
+    pub fn prioritize<T>(&self, particles: &mut [T], sender_of: impl Fn(&T) -> &str) {
+        particles.sort_by(|lhs, rhs| {
+            let lhs = self.reputation_of(sender_of(lhs));
+            let rhs = self.reputation_of(sender_of(rhs));
+            rhs.total_cmp(&lhs)
+        });
+    }
+
+    /// Removes data of a particle like `AVM::cleanup_data` and forgets its sender.
+    #[allow(clippy::result_large_err)]
+    pub fn cleanup_data(&mut self, particle_id: &str, current_peer_id: &str) -> AVMResult<(), E> {
+        self.senders.remove(particle_id);
+        self.avm.cleanup_data(particle_id, current_peer_id)
+    }
+
+    pub fn avm(&self) -> &AVM<E, R> {
+        &self.avm
+    }
+
+    pub fn avm_mut(&mut self) -> &mut AVM<E, R> {
+        &mut self.avm
+    }
+
+    /// Lowers the sender reputation if the interpreter rejected its data, failures of
+    /// the host side, e.g. of a data store or a cancellation, don't affect the reputation.
+    fn report_error(&mut self, particle_id: &str, error: &AVMError<E>) {
+        let AVMError::InterpreterFailed(error_outcome) = error else {
+            return;
+        };
+        let Some(sender) = self.senders.get(particle_id) else {
+            return;
+        };
+
+        if INVALID_SIGNATURE_ERROR_CODES.contains(&error_outcome.error_code) {
+            self.reputations.record_invalid_signature(sender);
+        } else {
+            self.reputations.record_failure(sender);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use avm_server::avm_runner::RawAVMOutcome;
+    use avm_server::testing::MockAVMRunner;
+    use avm_server::AVMDataStore;
+    use avm_server::AnomalyData;
+    use avm_server::DataStore;
+
+    use std::convert::Infallible;
+    use std::time::Duration;
+
+    struct NoopDataStore;
+
+    impl DataStore for NoopDataStore {
+        type Error = Infallible;
+
+        fn initialize(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn store_data(&mut self, _: &[u8], _: &str, _: &str) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn read_data(&mut self, _: &str, _: &str) -> Result<Vec<u8>, Self::Error> {
+            Ok(vec![])
+        }
+
+        fn cleanup_data(&mut self, _: &str, _: &str) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn detect_anomaly(&self, _: Duration, _: usize, _: &RawAVMOutcome) -> bool {
+            false
+        }
+
+        fn collect_anomaly_data(
+            &mut self,
+            _: &str,
+            _: &str,
+            _: AnomalyData<'_>,
+        ) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    fn outcome(ret_code: i64) -> RawAVMOutcome {
+        RawAVMOutcome {
+            ret_code,
+            error_message: String::new(),
+            data: vec![],
+            call_requests: <_>::default(),
+            next_peer_pks: vec![],
+            soft_limits_triggering: <_>::default(),
+            fuel_consumed: 0,
+            instruction_counts: <_>::default(),
+            flamegraph_svg: String::new(),
+            error_context: None,
+            air_fingerprint: None,
+        }
+    }
+
+    fn scheduler(ret_codes: &[i64]) -> ReputationAwareAVM<Infallible, MockAVMRunner> {
+        let runner = MockAVMRunner::new();
+        for &ret_code in ret_codes {
+            runner.add_response("(null)", <_>::default(), outcome(ret_code));
+        }
+
+        let data_store: AVMDataStore<Infallible> = Box::new(NoopDataStore);
+        let avm = AVM::with_runner(runner, data_store).unwrap();
+        ReputationAwareAVM::new(avm, ReputationConfig::default())
+    }
+
+    fn call(scheduler: &mut ReputationAwareAVM<Infallible, MockAVMRunner>, sender: &str) {
+        let particle_parameters = ParticleParameters::new(
+            "init_peer_id".into(),
+            "particle_id".into(),
+            0,
+            0,
+            "current_peer_id".into(),
+        );
+        let keypair = KeyPair::generate_ed25519();
+        let _ = scheduler.call(
+            sender,
+            "(null)",
+            vec![],
+            particle_parameters,
+            <_>::default(),
+            &keypair,
+            None,
+        );
+    }
+
+    #[test]
+    fn failures_lower_reputation_until_delayed() {
+        let mut scheduler = scheduler(&[0, 1, 1, 1, 1, 1, 1, 1]);
+
+        call(&mut scheduler, "peer");
+        assert_eq!(scheduler.reputation_of("peer"), 1.0);
+
+        for _ in 0..7 {
+            call(&mut scheduler, "peer");
+        }
+        assert!(scheduler.reputation_of("peer") < 0.5);
+        assert!(scheduler.should_delay("peer"));
+        assert!(!scheduler.should_delay("unknown_peer"));
+    }
+
+    #[test]
+    fn invalid_signatures_drop_reputation() {
+        let mut scheduler = scheduler(&[INVALID_SIGNATURE_ERROR_CODES[1], 1]);
+
+        call(&mut scheduler, "forger");
+        call(&mut scheduler, "unlucky");
+        assert_eq!(scheduler.reputation_of("forger"), 0.0);
+
+        let mut particles = vec!["forger", "unlucky", "unknown_peer"];
+        scheduler.prioritize(&mut particles, |sender| sender);
+        assert_eq!(particles, vec!["unknown_peer", "unlucky", "forger"]);
+    }
+}
@@ -0,0 +1,239 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::avm::SendSafeRunner;
+use super::AVMError;
+use super::AVMMemoryStats;
+use crate::config::AVMConfig;
+use crate::runner::InterpreterRunner;
+use crate::stats::AVMStats;
+use crate::stats::AVMStatsHandle;
+use crate::AVMResult;
+use crate::CancellationToken;
+use crate::AVM;
+
+use avm_interface::AVMOutcome;
+use avm_interface::CallResults;
+use avm_interface::ParticleParameters;
+use fluence_keypair::KeyPair;
+use parking_lot::Mutex;
+
+use std::sync::Arc;
+use std::time::Duration;
+
+/// An AVM counterpart intended to be used inside async runtimes.
+///
+/// It wraps `AVM`, and every call is executed by it on the tokio blocking thread pool,
+/// so neither the interpreter nor data store I/O stalls runtime worker threads. Calls
+/// behave exactly as the `AVM` ones and are counted in the same stats.
+pub struct AsyncAVM<E, R = SendSafeRunner> {
+    avm: Arc<Mutex<AVM<E, R>>>,
+    stats: AVMStatsHandle,
+}
+
+impl<E: Send + 'static> AsyncAVM<E> {
+    /// Create AsyncAVM with provided config, the interpreter module is compiled
+    /// and the data store is initialized on the blocking thread pool.
+    #[allow(clippy::result_large_err)]
+    pub async fn new(config: AVMConfig<E>) -> AVMResult<Self, E> {
+        let avm = tokio::task::spawn_blocking(move || AVM::new(config))
+            .await
+            .map_err(AVMError::RunnerTaskFailed)??;
+
+        Ok(Self::from_avm(avm))
+    }
+}
+
+impl<E, R> AsyncAVM<E, R>
+where
+    E: Send + 'static,
+    R: InterpreterRunner + Send + 'static,
+{
+    /// Create AsyncAVM executing calls with the provided AVM, e.g. one backed by
+    /// `testing::MockAVMRunner`.
+    pub fn from_avm(avm: AVM<E, R>) -> Self {
+        let stats = avm.stats_handle();
+
+        Self {
+            avm: Arc::new(Mutex::new(avm)),
+            stats,
+        }
+    }
+
+    /// Execute AIR script like `AVM::call` on the tokio blocking thread pool and await its result.
+    #[allow(clippy::result_large_err)]
+    pub async fn call(
+        &mut self,
+        air: impl Into<String>,
+        data: impl Into<Vec<u8>>,
+        particle_parameters: ParticleParameters<'_>,
+        call_results: CallResults,
+        keypair: &KeyPair,
+    ) -> AVMResult<AVMOutcome, E> {
+        let (air, data, particle_parameters) = to_owned_args(air, data, particle_parameters);
+        let keypair = keypair.clone();
+
+        self.execute(move |avm| avm.call(air, data, particle_parameters, call_results, &keypair))
+            .await
+    }
+
+    /// Execute AIR script like `AVM::call_with_cancellation`, `cancel_token` is checked
+    /// only before execution starts, so a call still waiting in the blocking thread pool
+    /// queue could be cancelled, but a running one isn't interrupted.
+    #[allow(clippy::result_large_err)]
+    pub async fn call_with_cancellation(
+        &mut self,
+        air: impl Into<String>,
+        data: impl Into<Vec<u8>>,
+        particle_parameters: ParticleParameters<'_>,
+        call_results: CallResults,
+        keypair: &KeyPair,
+        cancel_token: Option<CancellationToken>,
+    ) -> AVMResult<AVMOutcome, E> {
+        let (air, data, particle_parameters) = to_owned_args(air, data, particle_parameters);
+        let keypair = keypair.clone();
+
+        self.execute(move |avm| {
+            avm.call_with_cancellation(
+                air,
+                data,
+                particle_parameters,
+                call_results,
+                &keypair,
+                cancel_token,
+            )
+        })
+        .await
+    }
+
+    /// Execute AIR script like `AVM::call_dry_run`, the resulted data isn't persisted.
+    #[allow(clippy::result_large_err)]
+    pub async fn call_dry_run(
+        &mut self,
+        air: impl Into<String>,
+        data: impl Into<Vec<u8>>,
+        particle_parameters: ParticleParameters<'_>,
+        call_results: CallResults,
+        keypair: &KeyPair,
+    ) -> AVMResult<AVMOutcome, E> {
+        let (air, data, particle_parameters) = to_owned_args(air, data, particle_parameters);
+        let keypair = keypair.clone();
+
+        self.execute(move |avm| {
+            avm.call_dry_run(air, data, particle_parameters, call_results, &keypair)
+        })
+        .await
+    }
+
+    /// Execute AIR script like `AVM::call_with_timeout`. The timeout is counted from the moment
+    /// the call starts on the blocking thread pool, so waiting in the pool queue isn't counted.
+    #[allow(clippy::result_large_err)]
+    pub async fn call_with_timeout(
+        &mut self,
+        air: impl Into<String>,
+        data: impl Into<Vec<u8>>,
+        particle_parameters: ParticleParameters<'_>,
+        call_results: CallResults,
+        keypair: &KeyPair,
+        timeout: Duration,
+    ) -> AVMResult<AVMOutcome, E> {
+        let (air, data, particle_parameters) = to_owned_args(air, data, particle_parameters);
+        let keypair = keypair.clone();
+
+        self.execute(move |avm| {
+            avm.call_with_timeout(
+                air,
+                data,
+                particle_parameters,
+                call_results,
+                &keypair,
+                timeout,
+            )
+        })
+        .await
+    }
+
+    /// Cleanup data that become obsolete.
+    #[allow(clippy::result_large_err)]
+    pub async fn cleanup_data(
+        &mut self,
+        particle_id: &str,
+        current_peer_id: &str,
+    ) -> AVMResult<(), E> {
+        let particle_id = particle_id.to_string();
+        let current_peer_id = current_peer_id.to_string();
+
+        self.execute(move |avm| avm.cleanup_data(&particle_id, &current_peer_id))
+            .await
+    }
+
+    /// Return memory stat of an interpreter heap, it waits for a running call to finish.
+    pub fn memory_stats(&self) -> AVMMemoryStats {
+        self.avm.lock().memory_stats()
+    }
+
+    /// Return cumulative execution statistics of the wrapped AVM.
+    pub fn stats(&self) -> AVMStats {
+        self.stats.stats()
+    }
+
+    /// Zero execution statistics, e.g. at the start of a new metrics window.
+    pub fn reset_stats(&self) {
+        self.stats.reset()
+    }
+
+    /// Return a handle to read statistics from another thread without access to the AVM.
+    pub fn stats_handle(&self) -> AVMStatsHandle {
+        self.stats.clone()
+    }
+
+    /// Runs the closure with the wrapped AVM on the tokio blocking thread pool.
+    #[allow(clippy::result_large_err)]
+    async fn execute<T: Send + 'static>(
+        &self,
+        f: impl FnOnce(&mut AVM<E, R>) -> AVMResult<T, E> + Send + 'static,
+    ) -> AVMResult<T, E> {
+        let avm = self.avm.clone();
+
+        tokio::task::spawn_blocking(move || f(&mut avm.lock()))
+            .await
+            .map_err(AVMError::RunnerTaskFailed)?
+    }
+}
+
+/// Arguments are moved to the blocking thread pool, so they can't borrow from the caller.
+fn to_owned_args(
+    air: impl Into<String>,
+    data: impl Into<Vec<u8>>,
+    particle_parameters: ParticleParameters<'_>,
+) -> (String, Vec<u8>, ParticleParameters<'static>) {
+    let ParticleParameters {
+        init_peer_id,
+        particle_id,
+        timestamp,
+        ttl,
+        current_peer_id,
+    } = particle_parameters;
+    let particle_parameters = ParticleParameters::new(
+        init_peer_id.into_owned().into(),
+        particle_id.into_owned().into(),
+        timestamp,
+        ttl,
+        current_peer_id.into_owned().into(),
+    );
+
+    (air.into(), data.into(), particle_parameters)
+}
@@ -18,23 +18,50 @@ use super::avm_runner::AVMRunner;
 use super::AVMDataStore;
 use super::AVMError;
 use super::AVMMemoryStats;
+use super::DataStoreFactory;
 use crate::config::AVMConfig;
+use crate::runner::AVMRuntimeLimits;
+use crate::runner::BatchedOutcome;
+use crate::runner::ExecutionLimits;
+use crate::runner::InterpreterRunner;
+use crate::runner::RunnerRequest;
+use crate::stats::AVMStats;
+use crate::stats::AVMStatsHandle;
+use crate::trace_sampling::summarize_data;
+use crate::trace_sampling::TraceSamplingPolicy;
 use crate::AVMResult;
+use crate::CancellationToken;
+use crate::RunnerResult;
 
 use avm_data_store::AnomalyData;
 use avm_interface::raw_outcome::RawAVMOutcome;
 use avm_interface::AVMOutcome;
 use avm_interface::CallResults;
+use avm_interface::MemoryStats;
 use avm_interface::ParticleParameters;
 use fluence_keypair::KeyPair;
 
+use air_interpreter_interface::INTERPRETER_SUCCESS;
+
 use std::ops::Deref;
 use std::ops::DerefMut;
 use std::time::Duration;
 use std::time::Instant;
+use std::time::SystemTime;
+
+/// The time the interpreter is assumed to spend on a unit of fuel, i.e. on an instruction,
+/// `AVM::call_with_timeout` uses it to derive a fuel budget from a timeout. It underestimates
+/// the instruction cost on purpose, so the budget is rather exhausted by runaway scripts
+/// than by regular ones.
+pub const NANOS_PER_FUEL_UNIT: u64 = 1_000;
+
+/// The script `AVM::warmup` executes.
+pub const WARMUP_AIR: &str = "(null)";
+
+const WARMUP_PARTICLE_ID: &str = "warmup";
 
 /// A newtype needed to mark it as `unsafe impl Send`
-struct SendSafeRunner(AVMRunner);
+pub struct SendSafeRunner(pub(crate) AVMRunner);
 
 /// Mark runtime as Send, so libp2p on the node (use-site) is happy
 unsafe impl Send for SendSafeRunner {}
@@ -52,9 +79,91 @@ impl DerefMut for SendSafeRunner {
     }
 }
 
-pub struct AVM<E> {
-    runner: SendSafeRunner,
+impl InterpreterRunner for SendSafeRunner {
+    fn call(
+        &mut self,
+        air: String,
+        prev_data: Vec<u8>,
+        data: Vec<u8>,
+        init_peer_id: String,
+        timestamp: u64,
+        ttl: u32,
+        current_peer_id: String,
+        call_results: CallResults,
+        keypair: &KeyPair,
+        particle_id: String,
+    ) -> RunnerResult<RawAVMOutcome> {
+        self.0.call(
+            air,
+            prev_data,
+            data,
+            init_peer_id,
+            timestamp,
+            ttl,
+            current_peer_id,
+            call_results,
+            keypair,
+            particle_id,
+        )
+    }
+
+    fn call_multi(
+        &mut self,
+        requests: &[RunnerRequest],
+        keypair: &KeyPair,
+    ) -> Vec<RunnerResult<BatchedOutcome>> {
+        self.0.call_multi(requests, keypair)
+    }
+
+    fn heap_stats(&self) -> AVMMemoryStats {
+        self.0.heap_stats()
+    }
+
+    fn memory_stats(&self) -> MemoryStats {
+        self.0.memory_stats()
+    }
+
+    fn execution_limits(&self) -> ExecutionLimits {
+        self.0.execution_limits()
+    }
+
+    fn set_execution_limits(&mut self, limits: ExecutionLimits) {
+        self.0.set_execution_limits(limits)
+    }
+}
+
+/// A particle executed in a batch by `AVM::call_multi`.
+#[derive(Debug, Clone)]
+pub struct MultiCallRequest {
+    pub air: String,
+    pub data: Vec<u8>,
+    pub particle_parameters: ParticleParameters<'static>,
+    pub call_results: CallResults,
+}
+
+/// Particles cleaned up by `AVM::drain_expired_particles`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DrainStats {
+    pub particle_ids: Vec<String>,
+
+    /// Size of the data of cleaned up particles.
+    pub bytes_freed: usize,
+}
+
+impl DrainStats {
+    pub fn count(&self) -> usize {
+        self.particle_ids.len()
+    }
+}
+
+/// Executes AIR scripts with the interpreter `R` and persists the resulting data in a data store.
+/// `R` is the Wasm-backed runner unless a mock is provided with `AVM::with_runner`.
+pub struct AVM<E, R = SendSafeRunner> {
+    runner: R,
     data_store: AVMDataStore<E>,
+    data_store_factory: Option<DataStoreFactory<E>>,
+    stats: AVMStatsHandle,
+    trace_sampling: TraceSamplingPolicy,
 }
 
 impl<E> AVM<E> {
@@ -66,18 +175,75 @@ impl<E> AVM<E> {
             max_heap_size,
             logging_mask,
             mut data_store,
+            data_store_factory,
+            air_cache_capacity,
+            signature_policy,
+            key_format_policy,
+            call_results_format,
+            logging_callback,
+            trace_sampling,
         } = config;
 
         data_store.initialize()?;
 
-        let runner = AVMRunner::new(air_wasm_path, max_heap_size, <_>::default(), logging_mask)
-            .map_err(AVMError::RunnerError)?;
+        // the interpreter sizes its cache once, so the capacity is passed with the initial limits
+        let avm_runtime_limits = AVMRuntimeLimits {
+            air_cache_capacity: air_cache_capacity as u64,
+            ..<_>::default()
+        };
+        let runner = match logging_callback {
+            Some(logging_callback) => AVMRunner::with_logging_callback(
+                air_wasm_path,
+                max_heap_size,
+                avm_runtime_limits,
+                logging_mask,
+                logging_callback,
+            ),
+            None => AVMRunner::new(
+                air_wasm_path,
+                max_heap_size,
+                avm_runtime_limits,
+                logging_mask,
+            ),
+        };
+        let mut runner = runner.map_err(AVMError::RunnerError)?;
+        runner.set_signature_policy(signature_policy);
+        runner.set_key_format_policy(key_format_policy);
+        runner.set_call_results_format(call_results_format);
         let runner = SendSafeRunner(runner);
-        let avm = Self { runner, data_store };
+        let avm = Self {
+            runner,
+            data_store,
+            data_store_factory,
+            stats: <_>::default(),
+            trace_sampling,
+        };
+
+        Ok(avm)
+    }
+}
+
+impl<E, R: InterpreterRunner> AVM<E, R> {
+    /// Create AVM backed by the provided runner, e.g. `testing::MockAVMRunner`.
+    #[allow(clippy::result_large_err)]
+    pub fn with_runner(runner: R, mut data_store: AVMDataStore<E>) -> AVMResult<Self, E> {
+        data_store.initialize()?;
+
+        let avm = Self {
+            runner,
+            data_store,
+            data_store_factory: None,
+            stats: <_>::default(),
+            trace_sampling: <_>::default(),
+        };
 
         Ok(avm)
     }
 
+    /// Execute AIR script and persist the resulted data.
+    ///
+    /// A call exceeding the execution time limit returns `AVMError::TimedOut` and leaves
+    /// the stored data intact.
     #[allow(clippy::result_large_err)]
     pub fn call(
         &mut self,
@@ -87,42 +253,392 @@ impl<E> AVM<E> {
         call_results: CallResults,
         keypair: &KeyPair,
     ) -> AVMResult<AVMOutcome, E> {
+        self.call_with_cancellation(air, data, particle_parameters, call_results, keypair, None)
+    }
+
+    /// Execute AIR script like `call`, unless `cancel_token` is cancelled.
+    ///
+    /// The interpreter is a Wasm module that can't observe `cancel_token`, so it's checked
+    /// only before execution starts: before the previous data is read and once more right
+    /// before the interpreter is invoked. A cancelled call returns
+    /// `AVMError::Cancelled` and leaves the stored data intact. Once execution has started,
+    /// the call runs to completion and its result is persisted and returned as usual.
+    #[allow(clippy::result_large_err)]
+    pub fn call_with_cancellation(
+        &mut self,
+        air: impl Into<String>,
+        data: impl Into<Vec<u8>>,
+        particle_parameters: ParticleParameters<'_>,
+        call_results: CallResults,
+        keypair: &KeyPair,
+        cancel_token: Option<CancellationToken>,
+    ) -> AVMResult<AVMOutcome, E> {
+        let result = self.call_impl(
+            air,
+            data,
+            particle_parameters,
+            call_results,
+            keypair,
+            cancel_token,
+            true,
+            None,
+        );
+        self.stats.record_call(result.is_err());
+
+        result
+    }
+
+    /// Execute AIR script like `call`, but without persisting the resulted data, so the stored
+    /// data stays the same and the returned outcome shows what would have been stored.
+    #[allow(clippy::result_large_err)]
+    pub fn call_dry_run(
+        &mut self,
+        air: impl Into<String>,
+        data: impl Into<Vec<u8>>,
+        particle_parameters: ParticleParameters<'_>,
+        call_results: CallResults,
+        keypair: &KeyPair,
+    ) -> AVMResult<AVMOutcome, E> {
+        let result = self.call_impl(
+            air,
+            data,
+            particle_parameters,
+            call_results,
+            keypair,
+            None,
+            false,
+            None,
+        );
+        self.stats.record_call(result.is_err());
+
+        result
+    }
+
+    /// Execute AIR script like `call`, but fail with `AVMError::TimedOut` once the call takes
+    /// longer than `timeout`. The time left after reading the data is both the wall clock limit
+    /// of the interpreter and, divided by `NANOS_PER_FUEL_UNIT`, its fuel budget; these limits
+    /// apply to this call only. Data store I/O can't be interrupted, so a slow data store
+    /// prolongs the call, but data isn't persisted once the timeout is exceeded.
+    #[allow(clippy::result_large_err)]
+    pub fn call_with_timeout(
+        &mut self,
+        air: impl Into<String>,
+        data: impl Into<Vec<u8>>,
+        particle_parameters: ParticleParameters<'_>,
+        call_results: CallResults,
+        keypair: &KeyPair,
+        timeout: Duration,
+    ) -> AVMResult<AVMOutcome, E> {
+        let result = self.call_impl(
+            air,
+            data,
+            particle_parameters,
+            call_results,
+            keypair,
+            None,
+            true,
+            Some(timeout),
+        );
+        self.stats.record_call(result.is_err());
+
+        result
+    }
+
+    /// Execute AIR scripts of several particles like `call`, but in a single interpreter call,
+    /// so the cost of entering the Wasm module is paid once per batch. Results are returned
+    /// in the order of requests.
+    ///
+    /// A particle can occur in a batch only once, since its previous data is read before
+    /// the batch is executed. Time and memory growth of each execution are measured by
+    /// the runner. If the batch stops early, e.g. the module traps on one of the particles,
+    /// only the requests after that particle are executed again in a new batch.
+    #[allow(clippy::result_large_err)]
+    pub fn call_multi(
+        &mut self,
+        requests: Vec<MultiCallRequest>,
+        keypair: &KeyPair,
+    ) -> Vec<AVMResult<AVMOutcome, E>> {
+        let mut results = Vec::with_capacity(requests.len());
+        let mut prepared = vec![];
+        let mut runner_requests = vec![];
+
+        for (index, request) in requests.into_iter().enumerate() {
+            results.push(None);
+            match self.prepare_multi_call(&request, &prepared) {
+                Ok((particle_data_store, runner_request)) => {
+                    prepared.push((index, request, particle_data_store));
+                    runner_requests.push(runner_request);
+                }
+                Err(error) => results[index] = Some(Err(error)),
+            }
+        }
+
+        let mut outcomes = Vec::with_capacity(runner_requests.len());
+        while outcomes.len() < runner_requests.len() {
+            let pending_requests = &runner_requests[outcomes.len()..];
+            let mut batch_outcomes = self.runner.call_multi(pending_requests, keypair);
+            if batch_outcomes.is_empty() {
+                break;
+            }
+            batch_outcomes.truncate(pending_requests.len());
+            outcomes.extend(batch_outcomes);
+        }
+
+        // requests the runner couldn't execute in a batch are executed with `call`
+        let unbatched = prepared.split_off(outcomes.len());
+        let executed = prepared.into_iter().zip(outcomes).zip(&runner_requests);
+        for (((index, request, mut particle_data_store), outcome), runner_request) in executed {
+            let data_bytes_read = runner_request.prev_data.len() + runner_request.data.len();
+            let result = outcome
+                .map_err(AVMError::from_runner_error)
+                .and_then(|outcome| {
+                    self.stats.record_execution(
+                        outcome.execution_time,
+                        outcome.outcome.fuel_consumed,
+                        data_bytes_read,
+                    );
+                    self.complete_call(
+                        &mut particle_data_store,
+                        &request.air,
+                        &request.data,
+                        &request.call_results,
+                        &request.particle_parameters,
+                        outcome.outcome,
+                        outcome.execution_time,
+                        outcome.memory_delta,
+                        true,
+                    )
+                });
+            results[index] = Some(result);
+        }
+
+        self.call_one_by_one(results, unbatched, keypair)
+    }
+
+    /// Reads the previous data of a batched particle.
+    #[allow(clippy::result_large_err, clippy::type_complexity)]
+    fn prepare_multi_call(
+        &mut self,
+        request: &MultiCallRequest,
+        prepared: &[(usize, MultiCallRequest, Option<AVMDataStore<E>>)],
+    ) -> AVMResult<(Option<AVMDataStore<E>>, RunnerRequest), E> {
+        let particle = &request.particle_parameters;
+        let is_duplicate = prepared.iter().any(|(_, prepared, _)| {
+            let prepared = &prepared.particle_parameters;
+            prepared.particle_id == particle.particle_id
+                && prepared.current_peer_id == particle.current_peer_id
+        });
+        if is_duplicate {
+            return Err(AVMError::DuplicateParticle {
+                particle_id: particle.particle_id.to_string(),
+            });
+        }
+
+        let mut particle_data_store = self.particle_data_store(&particle.particle_id)?;
+        let prev_data = self
+            .data_store_mut(&mut particle_data_store)
+            .read_data(&particle.particle_id, &particle.current_peer_id)?;
+
+        let runner_request = RunnerRequest {
+            air: request.air.clone(),
+            prev_data,
+            data: request.data.clone(),
+            init_peer_id: particle.init_peer_id.to_string(),
+            timestamp: particle.timestamp,
+            ttl: particle.ttl,
+            current_peer_id: particle.current_peer_id.to_string(),
+            call_results: request.call_results.clone(),
+            particle_id: particle.particle_id.to_string(),
+        };
+
+        Ok((particle_data_store, runner_request))
+    }
+
+    /// Executes with `call` the prepared requests a runner couldn't execute in a batch
+    /// and collects results of all requests.
+    #[allow(clippy::type_complexity)]
+    fn call_one_by_one(
+        &mut self,
+        mut results: Vec<Option<AVMResult<AVMOutcome, E>>>,
+        prepared: Vec<(usize, MultiCallRequest, Option<AVMDataStore<E>>)>,
+        keypair: &KeyPair,
+    ) -> Vec<AVMResult<AVMOutcome, E>> {
+        for (index, request, _) in prepared {
+            let result = self.call_impl(
+                request.air,
+                request.data,
+                request.particle_parameters,
+                request.call_results,
+                keypair,
+                None,
+                true,
+                None,
+            );
+            results[index] = Some(result);
+        }
+
+        self.collect_multi_results(results)
+    }
+
+    fn collect_multi_results(
+        &self,
+        results: Vec<Option<AVMResult<AVMOutcome, E>>>,
+    ) -> Vec<AVMResult<AVMOutcome, E>> {
+        results
+            .into_iter()
+            .map(|result| {
+                let result = result.expect("every request has a result");
+                self.stats.record_call(result.is_err());
+                result
+            })
+            .collect()
+    }
+
+    /// Execute the no-op `(null)` script through the interpreter, so it's compiled and
+    /// initialization errors are surfaced before the first particle arrives. The script is
+    /// executed on behalf of a generated peer, the data store isn't touched and the call
+    /// isn't counted in stats.
+    #[allow(clippy::result_large_err)]
+    pub fn warmup(&mut self) -> AVMResult<(), E> {
+        let keypair = KeyPair::generate_ed25519();
+        let peer_id = keypair.get_peer_id().to_string();
+
+        let outcome = self
+            .runner
+            .call(
+                WARMUP_AIR.to_string(),
+                vec![],
+                vec![],
+                peer_id.clone(),
+                0,
+                0,
+                peer_id,
+                <_>::default(),
+                &keypair,
+                WARMUP_PARTICLE_ID.to_string(),
+            )
+            .map_err(AVMError::from_runner_error)?;
+        AVMOutcome::from_raw_outcome(outcome, 0, self.runner.memory_stats(), Duration::ZERO)
+            .map_err(AVMError::InterpreterFailed)?;
+
+        Ok(())
+    }
+
+    #[allow(clippy::result_large_err, clippy::too_many_arguments)]
+    fn call_impl(
+        &mut self,
+        air: impl Into<String>,
+        data: impl Into<Vec<u8>>,
+        particle_parameters: ParticleParameters<'_>,
+        call_results: CallResults,
+        keypair: &KeyPair,
+        cancel_token: Option<CancellationToken>,
+        persist_data: bool,
+        timeout: Option<Duration>,
+    ) -> AVMResult<AVMOutcome, E> {
+        let call_start_time = Instant::now();
+        let is_cancelled = || {
+            cancel_token
+                .as_ref()
+                .is_some_and(CancellationToken::is_cancelled)
+        };
+        if is_cancelled() {
+            return Err(AVMError::Cancelled);
+        }
+
         let air = air.into();
-        let prev_data = self.data_store.read_data(
+        let mut particle_data_store = self.particle_data_store(&particle_parameters.particle_id)?;
+        let prev_data = self.data_store_mut(&mut particle_data_store).read_data(
             &particle_parameters.particle_id,
             &particle_parameters.current_peer_id,
         )?;
         let current_data = data.into();
+        let data_bytes_read = prev_data.len() + current_data.len();
+
+        // reading data could take a while, the call could be cancelled meanwhile
+        if is_cancelled() {
+            return Err(AVMError::Cancelled);
+        }
+
+        // limits are narrowed for this call only and restored right after it
+        let timeout_limits = match timeout {
+            Some(timeout) => Some(self.narrow_execution_limits(timeout, call_start_time)?),
+            None => None,
+        };
 
         let execution_start_time = Instant::now();
         let memory_size_before = self.memory_stats().memory_size;
-        let outcome = self
-            .runner
-            .call(
-                air.clone(),
-                prev_data,
-                current_data.clone(),
-                particle_parameters.init_peer_id.clone().into_owned(),
-                particle_parameters.timestamp,
-                particle_parameters.ttl,
-                particle_parameters.current_peer_id.clone(),
-                call_results.clone(),
-                keypair,
-                particle_parameters.particle_id.to_string(),
-            )
-            .map_err(AVMError::RunnerError)?;
-
+        let outcome = self.runner.call(
+            air.clone(),
+            prev_data,
+            current_data.clone(),
+            particle_parameters.init_peer_id.clone().into_owned(),
+            particle_parameters.timestamp,
+            particle_parameters.ttl,
+            particle_parameters.current_peer_id.clone().into_owned(),
+            call_results.clone(),
+            keypair,
+            particle_parameters.particle_id.to_string(),
+        );
+        if let Some((prev_limits, _)) = timeout_limits {
+            self.runner.set_execution_limits(prev_limits);
+        }
+        let outcome = outcome.map_err(AVMError::from_runner_error)?;
         let execution_time = execution_start_time.elapsed();
+        self.stats
+            .record_execution(execution_time, outcome.fuel_consumed, data_bytes_read);
+        if let (Some(timeout), Some((_, fuel_budget))) = (timeout, timeout_limits) {
+            let elapsed = call_start_time.elapsed();
+            let fuel_exhausted =
+                outcome.ret_code != INTERPRETER_SUCCESS && outcome.fuel_consumed >= fuel_budget;
+            if elapsed > timeout || fuel_exhausted {
+                return Err(AVMError::TimedOut {
+                    elapsed,
+                    fuel_consumed: outcome.fuel_consumed,
+                });
+            }
+        }
+
         let memory_delta = self.memory_stats().memory_size - memory_size_before;
-        if self
-            .data_store
-            .detect_anomaly(execution_time, memory_delta, &outcome)
-        {
-            self.save_anomaly_data(
-                &air,
-                &current_data,
-                &call_results,
-                &particle_parameters,
+        self.complete_call(
+            &mut particle_data_store,
+            &air,
+            &current_data,
+            &call_results,
+            &particle_parameters,
+            outcome,
+            execution_time,
+            memory_delta,
+            persist_data,
+        )
+    }
+
+    /// Collects anomaly data, persists the resulted data and converts the outcome.
+    #[allow(clippy::result_large_err, clippy::too_many_arguments)]
+    fn complete_call(
+        &mut self,
+        particle_data_store: &mut Option<AVMDataStore<E>>,
+        air: &str,
+        current_data: &[u8],
+        call_results: &CallResults,
+        particle_parameters: &ParticleParameters<'_>,
+        outcome: RawAVMOutcome,
+        execution_time: Duration,
+        memory_delta: usize,
+        persist_data: bool,
+    ) -> AVMResult<AVMOutcome, E> {
+        let memory_stats = self.runner.memory_stats();
+        if self.data_store_mut(particle_data_store).detect_anomaly(
+            execution_time,
+            memory_delta,
+            &outcome,
+        ) {
+            Self::save_anomaly_data(
+                self.data_store_mut(particle_data_store),
+                air,
+                current_data,
+                call_results,
+                particle_parameters,
                 &outcome,
                 execution_time,
                 memory_delta,
@@ -130,32 +646,171 @@ impl<E> AVM<E> {
         }
 
         // persist resulted data
-        self.data_store.store_data(
-            &outcome.data,
-            &particle_parameters.particle_id,
-            &particle_parameters.current_peer_id,
-        )?;
-        let outcome = AVMOutcome::from_raw_outcome(outcome, memory_delta, execution_time)
-            .map_err(AVMError::InterpreterFailed)?;
+        if persist_data {
+            let summary = self.summarize_unsampled(&outcome, &particle_parameters.particle_id);
+            let data = summary.as_deref().unwrap_or(&outcome.data);
+            self.data_store_mut(particle_data_store).store_data(
+                data,
+                &particle_parameters.particle_id,
+                &particle_parameters.current_peer_id,
+            )?;
+            self.stats.record_write(data.len());
+        }
+        let outcome =
+            AVMOutcome::from_raw_outcome(outcome, memory_delta, memory_stats, execution_time)
+                .map_err(AVMError::InterpreterFailed)?;
 
         Ok(outcome)
     }
 
+    /// Sets the limits derived from the time left until `timeout` and returns the previous
+    /// limits along with the fuel budget.
+    #[allow(clippy::result_large_err)]
+    fn narrow_execution_limits(
+        &mut self,
+        timeout: Duration,
+        call_start_time: Instant,
+    ) -> AVMResult<(ExecutionLimits, u64), E> {
+        let elapsed = call_start_time.elapsed();
+        let time_left = timeout.saturating_sub(elapsed);
+        if time_left.is_zero() {
+            return Err(AVMError::TimedOut {
+                elapsed,
+                fuel_consumed: 0,
+            });
+        }
+
+        let prev_limits = self.runner.execution_limits();
+        let fuel_budget = u64::try_from(time_left.as_nanos() / NANOS_PER_FUEL_UNIT as u128)
+            .unwrap_or(u64::MAX)
+            .max(1);
+        let fuel_limit = prev_limits
+            .fuel_limit
+            .map_or(fuel_budget, |limit| limit.min(fuel_budget));
+        let max_execution_time = prev_limits
+            .max_execution_time
+            .map_or(time_left, |limit| limit.min(time_left));
+        self.runner.set_execution_limits(ExecutionLimits {
+            fuel_limit: Some(fuel_limit),
+            max_execution_time: Some(max_execution_time),
+        });
+
+        Ok((prev_limits, fuel_limit))
+    }
+
+    /// Sets a factory building a data store for each call, see `AVMConfig::with_data_store_factory`.
+    pub fn set_data_store_factory(
+        &mut self,
+        factory: impl Fn(&str) -> AVMDataStore<E> + Send + 'static,
+    ) {
+        self.data_store_factory = Some(Box::new(factory));
+    }
+
+    /// Builds and initializes a data store for the particle if a factory is set.
+    #[allow(clippy::result_large_err)]
+    fn particle_data_store(&self, particle_id: &str) -> AVMResult<Option<AVMDataStore<E>>, E> {
+        let Some(factory) = &self.data_store_factory else {
+            return Ok(None);
+        };
+
+        let mut data_store = factory(particle_id);
+        data_store.initialize()?;
+        Ok(Some(data_store))
+    }
+
+    /// Returns the store built for the particle, or the shared one if there is none.
+    fn data_store_mut<'s>(
+        &'s mut self,
+        particle_data_store: &'s mut Option<AVMDataStore<E>>,
+    ) -> &'s mut AVMDataStore<E> {
+        particle_data_store.as_mut().unwrap_or(&mut self.data_store)
+    }
+
+    /// Sets which particles get their full data persisted.
+    pub fn set_trace_sampling(&mut self, trace_sampling: TraceSamplingPolicy) {
+        self.trace_sampling = trace_sampling;
+    }
+
+    /// Returns a summary to persist instead of the data of a particle that isn't sampled.
+    /// Call results are matched against the persisted trace, so the full data is kept
+    /// while there are pending call requests.
+    fn summarize_unsampled(&self, outcome: &RawAVMOutcome, particle_id: &str) -> Option<Vec<u8>> {
+        if !outcome.call_requests.is_empty() || self.trace_sampling.is_sampled(particle_id) {
+            return None;
+        }
+
+        summarize_data(&outcome.data)
+    }
+
     /// Cleanup data that become obsolete.
     #[allow(clippy::result_large_err)]
     pub fn cleanup_data(&mut self, particle_id: &str, current_peer_id: &str) -> AVMResult<(), E> {
-        self.data_store.cleanup_data(particle_id, current_peer_id)?;
+        let mut particle_data_store = self.particle_data_store(particle_id)?;
+        self.data_store_mut(&mut particle_data_store)
+            .cleanup_data(particle_id, current_peer_id)?;
         Ok(())
     }
 
+    /// Cleans up data of the peer's particles that was stored longer than `max_age` ago.
+    ///
+    /// Particles are listed with `DataStore::list_particles` of the store passed on construction,
+    /// stores built by a data store factory aren't drained.
+    #[allow(clippy::result_large_err)]
+    pub fn drain_expired_particles(
+        &mut self,
+        max_age: Duration,
+        current_peer_id: &str,
+    ) -> AVMResult<DrainStats, E> {
+        let now = SystemTime::now();
+        let particle_ids = self
+            .data_store
+            .list_particles(current_peer_id)?
+            .into_iter()
+            // a modification time in the future means the clock was moved back, data is kept then
+            .filter(|(_, modified)| now.duration_since(*modified).is_ok_and(|age| age > max_age))
+            .map(|(particle_id, _)| particle_id)
+            .collect::<Vec<_>>();
+
+        let particle_id_refs = particle_ids.iter().map(String::as_str).collect::<Vec<_>>();
+        let bytes_freed = self
+            .data_store
+            .read_data_batch(&particle_id_refs, current_peer_id)?
+            .iter()
+            .map(Vec::len)
+            .sum();
+        for particle_id in &particle_ids {
+            self.data_store.cleanup_data(particle_id, current_peer_id)?;
+        }
+
+        Ok(DrainStats {
+            particle_ids,
+            bytes_freed,
+        })
+    }
+
     /// Return memory stat of an interpreter heap.
     pub fn memory_stats(&self) -> AVMMemoryStats {
-        self.runner.memory_stats()
+        self.runner.heap_stats()
+    }
+
+    /// Return cumulative execution statistics of this AVM.
+    pub fn stats(&self) -> AVMStats {
+        self.stats.stats()
+    }
+
+    /// Zero execution statistics, e.g. at the start of a new metrics window.
+    pub fn reset_stats(&self) {
+        self.stats.reset()
+    }
+
+    /// Return a handle to read statistics from another thread without access to the AVM.
+    pub fn stats_handle(&self) -> AVMStatsHandle {
+        self.stats.clone()
     }
 
     #[allow(clippy::result_large_err, clippy::too_many_arguments)]
     fn save_anomaly_data(
-        &mut self,
+        data_store: &mut AVMDataStore<E>,
         air_script: &str,
         current_data: &[u8],
         call_result: &CallResults,
@@ -164,7 +819,7 @@ impl<E> AVM<E> {
         execution_time: Duration,
         memory_delta: usize,
     ) -> AVMResult<(), E> {
-        let prev_data = self.data_store.read_data(
+        let prev_data = data_store.read_data(
             &particle_parameters.particle_id,
             &particle_parameters.current_peer_id,
         )?;
@@ -185,7 +840,7 @@ impl<E> AVM<E> {
             memory_delta,
         );
 
-        self.data_store
+        data_store
             .collect_anomaly_data(
                 &particle_parameters.particle_id,
                 &particle_parameters.current_peer_id,
@@ -14,6 +14,8 @@
  * limitations under the License.
  */
 
+use std::time::Duration;
+
 pub struct AquaVMRuntimeLimits {
     /// AIR script size limit.
     pub air_size_limit: u64,
@@ -23,6 +25,10 @@ pub struct AquaVMRuntimeLimits {
     pub call_result_size_limit: u64,
     /// Knob to enable/disable RAM consumption hard limits in AquaVM.
     pub hard_limit_enabled: bool,
+    /// Maximum number of instructions to execute.
+    pub fuel_limit: Option<u64>,
+    /// The maximum wall clock time of a call.
+    pub max_execution_time: Option<Duration>,
 }
 
 #[derive(Default)]
@@ -31,6 +37,8 @@ pub struct AVMRuntimeLimits {
     pub particle_size_limit: Option<u64>,
     pub call_result_size_limit: Option<u64>,
     pub hard_limit_enabled: bool,
+    pub fuel_limit: Option<u64>,
+    pub max_execution_time: Option<Duration>,
 }
 
 impl AquaVMRuntimeLimits {
@@ -39,12 +47,16 @@ impl AquaVMRuntimeLimits {
         particle_size_limit: u64,
         call_result_size_limit: u64,
         hard_limit_enabled: bool,
+        fuel_limit: Option<u64>,
+        max_execution_time: Option<Duration>,
     ) -> Self {
         Self {
             air_size_limit,
             particle_size_limit,
             call_result_size_limit,
             hard_limit_enabled,
+            fuel_limit,
+            max_execution_time,
         }
     }
 }
@@ -60,6 +72,8 @@ impl From<AVMRuntimeLimits> for AquaVMRuntimeLimits {
             value.particle_size_limit.unwrap_or(MAX_PARTICLE_SIZE),
             value.call_result_size_limit.unwrap_or(MAX_CALL_RESULT_SIZE),
             value.hard_limit_enabled,
+            value.fuel_limit,
+            value.max_execution_time,
         )
     }
 }
@@ -15,7 +15,18 @@
  */
 
 use super::AVMDataStore;
+use super::AVMRuntimeLimits;
+use super::DataStoreFactory;
+
+use super::LogLevel;
+use super::LoggingCallback;
+use super::TraceSamplingPolicy;
+
+use air_interpreter_interface::CallResultsFormat;
+use air_interpreter_interface::KeyFormatPolicyKind;
+use air_interpreter_interface::SignaturePolicyKind;
 use std::path::PathBuf;
+use std::time::Duration;
 
 /// Describes behaviour of the AVM.
 pub struct AVMConfig<E> {
@@ -29,4 +40,106 @@ pub struct AVMConfig<E> {
     pub logging_mask: i32,
 
     pub data_store: AVMDataStore<E>,
+
+    /// Builds a data store for each call given the particle id, `data_store` is used
+    /// for all particles if it's not set.
+    pub data_store_factory: Option<DataStoreFactory<E>>,
+
+    /// The number of parsed AIR scripts the interpreter keeps between calls, 0 disables the cache.
+    pub air_cache_capacity: usize,
+
+    /// Which peers' signatures the interpreter verifies in incoming data.
+    pub signature_policy: SignaturePolicyKind,
+
+    /// Which key formats the interpreter accepts in signature stores and for the peer's keypair.
+    pub key_format_policy: KeyFormatPolicyKind,
+
+    /// A format call results are passed to the interpreter in.
+    pub call_results_format: CallResultsFormat,
+
+    /// A function interpreter log messages are passed to, they go to the `log` facade if it's not set.
+    pub logging_callback: Option<LoggingCallback>,
+
+    /// Particles whose full data is persisted, only summaries are persisted for others.
+    pub trace_sampling: TraceSamplingPolicy,
+}
+
+impl<E> AVMConfig<E> {
+    /// Makes the interpreter keep up to `capacity` parsed scripts, so that subsequent rounds
+    /// of a particle skip parsing. The cache lives inside the interpreter module, since the
+    /// AST can't cross the Wasm boundary.
+    pub fn with_air_cache(mut self, capacity: usize) -> Self {
+        self.air_cache_capacity = capacity;
+        self
+    }
+
+    /// Sets the signature verification policy, `SignaturePolicyKind::VerifyNone` removes
+    /// the crypto overhead on private networks where all peers are trusted.
+    pub fn with_signature_policy(mut self, signature_policy: SignaturePolicyKind) -> Self {
+        self.signature_policy = signature_policy;
+        self
+    }
+
+    /// Sets the accepted key formats, `KeyFormatPolicyKind::Any` allows particles signed
+    /// by peers of networks with different key formats.
+    pub fn with_key_format_policy(mut self, key_format_policy: KeyFormatPolicyKind) -> Self {
+        self.key_format_policy = key_format_policy;
+        self
+    }
+
+    /// Sets a format call results are passed to the interpreter in, MsgPack is used by default.
+    pub fn with_call_results_format(mut self, call_results_format: CallResultsFormat) -> Self {
+        self.call_results_format = call_results_format;
+        self
+    }
+
+    /// Routes interpreter log messages selected by `logging_mask` to the callback
+    /// instead of the global logger, e.g. to store logs of each particle separately.
+    pub fn with_logging_callback(
+        mut self,
+        callback: impl Fn(LogLevel, String) + Send + 'static,
+    ) -> Self {
+        self.logging_callback = Some(LoggingCallback::new(callback));
+        self
+    }
+
+    /// Persists the full data only for particles selected by the policy, it saves
+    /// data store space on nodes where most particles never need to be inspected.
+    pub fn with_trace_sampling(mut self, trace_sampling: TraceSamplingPolicy) -> Self {
+        self.trace_sampling = trace_sampling;
+        self
+    }
+
+    /// Makes each call use a data store built for its particle, e.g. an in-memory store
+    /// for ephemeral particles and a persistent one for others. The factory is called
+    /// and the built store is initialized on every call, stores aren't cached.
+    pub fn with_data_store_factory(
+        mut self,
+        factory: impl Fn(&str) -> AVMDataStore<E> + Send + 'static,
+    ) -> Self {
+        self.data_store_factory = Some(Box::new(factory));
+        self
+    }
+}
+
+/// Describes behaviour of the AVM runner pool.
+pub struct PoolConfig {
+    /// Path to a AIR interpreter Wasm file.
+    pub air_wasm_path: PathBuf,
+
+    /// Maximum heap size in bytes available for each interpreter instance.
+    pub max_heap_size: Option<u64>,
+
+    /// Mask used to filter logs, for details see `log_utf8_string` in fluence-faas.
+    pub logging_mask: i32,
+
+    /// Limits passed to every runner of the pool.
+    pub avm_runtime_limits: AVMRuntimeLimits,
+
+    /// Maximum number of runners the pool can grow up to.
+    pub max_size: usize,
+
+    /// Runners that stay idle for longer than this are dropped,
+    /// unless the pool would become smaller than its initial size.
+    pub idle_timeout: Duration,
 }
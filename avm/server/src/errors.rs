@@ -21,11 +21,14 @@ use marine::MarineError;
 
 use serde_json::Error as SerdeError;
 use thiserror::Error as ThisError;
+use tokio::task::JoinError;
 
 use std::io::Error as IOError;
 use std::path::PathBuf;
+use std::time::Duration;
 
 #[derive(Debug, ThisError)]
+#[allow(clippy::large_enum_variant)]
 pub enum AVMError<E> {
     /// This error contains interpreter outcome in case when execution failed on the interpreter
     /// side. A host should match on this error type explicitly to save provided data.
@@ -43,6 +46,44 @@ pub enum AVMError<E> {
     /// This errors are encountered from serialization of data tracked during an anomaly.
     #[error(transparent)]
     AnomalyDataSeError(SerdeError),
+
+    /// This errors are encountered when a blocking task running the AVM runner
+    /// panicked or was cancelled by an async runtime.
+    #[error("AVM runner task failed: {0}")]
+    RunnerTaskFailed(JoinError),
+
+    /// A call was cancelled by a host before execution, the stored data was left untouched.
+    #[error("AVM call was cancelled")]
+    Cancelled,
+
+    /// A call ran longer than the execution time limit or exhausted the fuel budget of
+    /// `AVM::call_with_timeout`, the stored data was left untouched. Comparing `elapsed`
+    /// with the time the interpreter could spend on `fuel_consumed` instructions shows whether
+    /// the time was spent in the interpreter or in data store I/O.
+    #[error("AVM call timed out after {elapsed:?}, {fuel_consumed} fuel consumed")]
+    TimedOut {
+        elapsed: Duration,
+        fuel_consumed: u64,
+    },
+
+    /// A particle occurs in a batch of `AVM::call_multi` more than once.
+    #[error("particle {particle_id} occurs in a batch more than once")]
+    DuplicateParticle { particle_id: String },
+}
+
+impl<E> AVMError<E> {
+    pub(crate) fn from_runner_error(error: RunnerError) -> Self {
+        match error {
+            RunnerError::TimedOut {
+                elapsed,
+                fuel_consumed,
+            } => Self::TimedOut {
+                elapsed,
+                fuel_consumed,
+            },
+            error => Self::RunnerError(error),
+        }
+    }
 }
 
 #[derive(Debug, ThisError)]
@@ -80,4 +121,15 @@ pub enum RunnerError {
     /// Errors from auxiliary calls.
     #[error("{0}")]
     Aux(String),
+
+    /// A runner pool was requested to pre-warm more runners than it can hold.
+    #[error("runner pool can't pre-warm {count} runners, its max size is {max_size}")]
+    InvalidPoolSize { count: usize, max_size: usize },
+
+    /// The interpreter ran longer than the execution time limit.
+    #[error("interpreter execution timed out after {elapsed:?}, {fuel_consumed} fuel consumed")]
+    TimedOut {
+        elapsed: Duration,
+        fuel_consumed: u64,
+    },
 }
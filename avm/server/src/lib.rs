@@ -24,23 +24,54 @@
     unreachable_patterns
 )]
 
+mod async_avm;
 mod avm;
 mod avm_runtime_limits;
+mod cancellation;
 mod config;
 mod errors;
+mod logging;
 mod runner;
+mod runner_pool;
+mod stats;
+mod trace_sampling;
 
+pub mod testing;
+
+pub use async_avm::AsyncAVM;
+pub use avm::DrainStats;
+pub use avm::MultiCallRequest;
 pub use avm::AVM;
+pub use avm::NANOS_PER_FUEL_UNIT;
+pub use avm::WARMUP_AIR;
+pub use cancellation::CancellationToken;
 pub use config::AVMConfig;
+pub use config::PoolConfig;
 pub use errors::AVMError;
+pub use logging::LogLevel;
+pub use logging::LoggingCallback;
 pub use runner::AVMMemoryStats;
 pub use runner::AVMRuntimeLimits;
 pub use runner::AquaVMRuntimeLimits;
+pub use runner::BatchedOutcome;
+pub use runner::ExecutionLimits;
+pub use runner::InterpreterRunner;
+pub use runner::RunnerRequest;
+pub use stats::AVMStats;
+pub use stats::AVMStatsHandle;
+pub use trace_sampling::TraceSamplingPolicy;
 
 pub use avm_interface::*;
 
+pub use air_interpreter_interface::CallResultsFormat;
+pub use air_interpreter_interface::KeyFormatPolicyKind;
+pub use air_interpreter_interface::SignaturePolicyKind;
+
 pub mod avm_runner {
+    pub use crate::avm::SendSafeRunner;
     pub use crate::runner::AVMRunner;
+    pub use crate::runner_pool::AVMRunnerPool;
+    pub use crate::runner_pool::PoolGuard;
     pub use avm_interface::raw_outcome::RawAVMOutcome;
 }
 
@@ -59,6 +90,9 @@ pub use avm_data_store::DataStore;
 
 pub type AVMDataStore<E> = Box<dyn DataStore<Error = E> + Send + Sync + 'static>;
 
+/// Builds a data store for the particle with the given id.
+pub type DataStoreFactory<E> = Box<dyn Fn(&str) -> AVMDataStore<E> + Send + 'static>;
+
 pub type AVMResult<T, E> = std::result::Result<T, AVMError<E>>;
 
 pub use errors::RunnerError;
@@ -0,0 +1,163 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use marine::HostExportedFunc;
+use marine::HostImportDescriptor;
+use marine::IType;
+use marine::IValue;
+use marine_core::HostAPIVersion;
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+
+/// The name of the host function the interpreter logs messages with.
+const LOG_IMPORT_NAME: &str = "log_utf8_string";
+
+/// Severity of an interpreter log message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    /// Converts a level passed by the interpreter, unknown levels are treated as the most verbose one.
+    fn from_raw(level: i32) -> Self {
+        match level {
+            1 => Self::Error,
+            2 => Self::Warn,
+            3 => Self::Info,
+            4 => Self::Debug,
+            _ => Self::Trace,
+        }
+    }
+}
+
+/// A function interpreter log messages are routed to instead of the `log` facade.
+#[derive(Clone)]
+pub struct LoggingCallback(Arc<Mutex<dyn Fn(LogLevel, String) + Send>>);
+
+impl LoggingCallback {
+    pub fn new(callback: impl Fn(LogLevel, String) + Send + 'static) -> Self {
+        Self(Arc::new(Mutex::new(callback)))
+    }
+
+    /// Makes host imports that replace the Marine logger, messages are filtered
+    /// by the logging mask the same way Marine does it.
+    pub(crate) fn host_imports(
+        &self,
+        logging_mask: i32,
+    ) -> HashMap<HostAPIVersion, HashMap<String, HostImportDescriptor>> {
+        use HostAPIVersion::*;
+
+        // an interpreter module imports the logger from the namespace of its SDK version
+        [V0, V1, V2, V3]
+            .into_iter()
+            .map(|version| {
+                let import = self.host_import(logging_mask);
+                (
+                    version,
+                    maplit::hashmap! { LOG_IMPORT_NAME.to_string() => import },
+                )
+            })
+            .collect()
+    }
+
+    fn host_import(&self, logging_mask: i32) -> HostImportDescriptor {
+        let callback = self.clone();
+        let host_exported_func: HostExportedFunc = Box::new(move |_, arguments| {
+            callback.log(logging_mask, &arguments);
+            None
+        });
+
+        HostImportDescriptor {
+            host_exported_func,
+            argument_types: vec![IType::S32, IType::S32, IType::String],
+            output_type: None,
+            error_handler: None,
+        }
+    }
+
+    fn log(&self, logging_mask: i32, arguments: &[IValue]) {
+        // Marine lifts the arguments according to `argument_types`
+        let [IValue::S32(level), IValue::S32(target), IValue::String(message)] = arguments else {
+            return;
+        };
+        if *target != 0 && target & logging_mask == 0 {
+            return;
+        }
+
+        // a callback that panicked once still receives the next messages
+        let callback = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+        callback(LogLevel::from_raw(*level), message.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(level: i32, target: i32, message: &str) -> Vec<IValue> {
+        vec![
+            IValue::S32(level),
+            IValue::S32(target),
+            IValue::String(message.to_string()),
+        ]
+    }
+
+    #[test]
+    fn callback_receives_messages_selected_by_mask() {
+        let messages = Arc::new(Mutex::new(vec![]));
+        let callback = {
+            let messages = messages.clone();
+            LoggingCallback::new(move |level, message| {
+                messages.lock().unwrap().push((level, message))
+            })
+        };
+
+        let logging_mask = 0b0110;
+        callback.log(logging_mask, &message(1, 0, "untargeted"));
+        callback.log(logging_mask, &message(2, 0b0010, "selected"));
+        callback.log(logging_mask, &message(3, 0b1000, "filtered out"));
+        callback.log(logging_mask, &message(42, 0b0100, "unknown level"));
+        callback.log(logging_mask, &[IValue::S32(1)]);
+
+        assert_eq!(
+            *messages.lock().unwrap(),
+            vec![
+                (LogLevel::Error, "untargeted".to_string()),
+                (LogLevel::Warn, "selected".to_string()),
+                (LogLevel::Trace, "unknown level".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn host_imports_cover_all_api_versions() {
+        let callback = LoggingCallback::new(|_, _| {});
+        let host_imports = callback.host_imports(0);
+
+        assert_eq!(host_imports.len(), 4);
+        assert!(host_imports
+            .values()
+            .all(|imports| imports.contains_key(LOG_IMPORT_NAME)));
+    }
+}
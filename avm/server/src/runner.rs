@@ -14,23 +14,32 @@
  * limitations under the License.
  */
 
+use crate::LoggingCallback;
 use crate::RunnerError;
 use crate::RunnerResult;
 
+use air_interpreter_interface::serialize_call_results;
 use air_interpreter_interface::try_as_string;
-use air_interpreter_interface::CallResultsRepr;
+use air_interpreter_interface::CallResultsFormat;
 use air_interpreter_interface::InterpreterOutcome;
-use air_interpreter_sede::ToSerialized;
+use air_interpreter_interface::InvokeOutcome;
+use air_interpreter_interface::KeyFormatPolicyKind;
+use air_interpreter_interface::SignaturePolicyKind;
 use air_utils::measure;
 use avm_interface::raw_outcome::RawAVMOutcome;
 use avm_interface::CallResults;
+use avm_interface::ExecutionTree;
+use avm_interface::MemoryStats;
 use fluence_keypair::KeyPair;
 use marine::IValue;
 use marine::Marine;
 use marine::MarineConfig;
 use marine::ModuleDescriptor;
 
+use std::collections::HashSet;
 use std::path::PathBuf;
+use std::time::Duration;
+use std::time::Instant;
 
 #[derive(Clone, Copy, Debug)]
 pub struct AquaVMRuntimeLimits {
@@ -41,9 +50,25 @@ pub struct AquaVMRuntimeLimits {
     pub call_result_size_limit: u64,
     /// This knob controls hard RAM limits behavior for AVMRunner.
     pub hard_limit_enabled: bool,
+    /// The maximum number of instructions the interpreter is allowed to execute.
+    pub fuel_limit: Option<u64>,
+    /// The maximum wall clock time of a call.
+    pub max_execution_time: Option<Duration>,
+    /// The number of parsed AIR scripts the interpreter keeps between calls.
+    pub air_cache_capacity: u64,
+    /// Which peers' signatures are verified in incoming data.
+    pub signature_policy: SignaturePolicyKind,
+    /// Which key formats are accepted in signature stores and for the peer's own keypair.
+    pub key_format_policy: KeyFormatPolicyKind,
+    /// The maximum depth of nested folds and fold iterations.
+    pub max_fold_depth: Option<u32>,
+    /// The maximum number of instructions in a script, it's checked before execution.
+    pub max_instruction_count: Option<u64>,
+    /// A format call results are passed to the interpreter in.
+    pub call_results_format: CallResultsFormat,
 }
 
-#[derive(Default)]
+#[derive(Clone, Debug, Default)]
 pub struct AVMRuntimeLimits {
     // The AIR script size limit.
     pub air_size_limit: Option<u64>,
@@ -53,6 +78,22 @@ pub struct AVMRuntimeLimits {
     pub call_result_size_limit: Option<u64>,
     /// This knob controls hard RAM limits behavior for AVMRunner.
     pub hard_limit_enabled: bool,
+    /// The maximum number of instructions the interpreter is allowed to execute.
+    pub fuel_limit: Option<u64>,
+    /// The maximum wall clock time of a call, a call that runs longer fails with a timeout.
+    pub max_execution_time: Option<Duration>,
+    /// The number of parsed AIR scripts the interpreter keeps between calls, 0 disables the cache.
+    pub air_cache_capacity: u64,
+}
+
+/// The fuel and wall clock limits of a call, they could be changed between calls with
+/// `InterpreterRunner::set_execution_limits`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ExecutionLimits {
+    /// The maximum number of instructions the interpreter is allowed to execute.
+    pub fuel_limit: Option<u64>,
+    /// The maximum wall clock time of a call.
+    pub max_execution_time: Option<Duration>,
 }
 
 pub struct AVMRunner {
@@ -63,6 +104,168 @@ pub struct AVMRunner {
     total_memory_limit: Option<u64>,
     /// This struct contains runtime RAM allowance.
     aquavm_runtime_limits: AquaVMRuntimeLimits,
+    /// The biggest linear memory size observed after calls.
+    peak_memory_size: u64,
+    /// Fingerprints of scripts the interpreter reported as cached, the interpreter never evicts
+    /// scripts, so it's bounded by its cache capacity.
+    cached_air_fingerprints: HashSet<[u8; 32]>,
+}
+
+/// Arguments of one execution in a batch passed to `InterpreterRunner::call_multi`.
+#[derive(Debug, Clone)]
+pub struct RunnerRequest {
+    pub air: String,
+    pub prev_data: Vec<u8>,
+    pub data: Vec<u8>,
+    pub init_peer_id: String,
+    pub timestamp: u64,
+    pub ttl: u32,
+    pub current_peer_id: String,
+    pub call_results: CallResults,
+    pub particle_id: String,
+}
+
+/// Outcome of one execution in a batch run by `InterpreterRunner::call_multi`.
+#[derive(Debug, Clone)]
+pub struct BatchedOutcome {
+    pub outcome: RawAVMOutcome,
+
+    /// Time spent on the execution.
+    pub execution_time: Duration,
+
+    /// Growth of the interpreter linear memory during the execution in bytes.
+    pub memory_delta: usize,
+}
+
+/// Interface of an interpreter runner `AVM` drives, it allows replacing the Wasm-backed
+/// `AVMRunner` with a mock in tests.
+pub trait InterpreterRunner {
+    #[allow(clippy::result_large_err, clippy::too_many_arguments)]
+    fn call(
+        &mut self,
+        air: String,
+        prev_data: Vec<u8>,
+        data: Vec<u8>,
+        init_peer_id: String,
+        timestamp: u64,
+        ttl: u32,
+        current_peer_id: String,
+        call_results: CallResults,
+        keypair: &KeyPair,
+        particle_id: String,
+    ) -> RunnerResult<RawAVMOutcome>;
+
+    /// Executes requests one by one and returns their outcomes in the same order. A batch can
+    /// stop early, e.g. when the interpreter traps: then the last outcome is the error of
+    /// the request it stopped at and the following requests aren't executed. No outcomes mean
+    /// the batch failed before any execution. The default implementation calls `call` for each
+    /// request, runners that are expensive to enter should execute a batch in a single call.
+    fn call_multi(
+        &mut self,
+        requests: &[RunnerRequest],
+        keypair: &KeyPair,
+    ) -> Vec<RunnerResult<BatchedOutcome>> {
+        execute_sequentially(self, requests, keypair)
+    }
+
+    /// Return memory stat of an interpreter heap.
+    fn heap_stats(&self) -> AVMMemoryStats;
+
+    /// Return linear memory usage of the interpreter, it's intended to be called after `call`.
+    fn memory_stats(&self) -> MemoryStats;
+
+    /// Return the fuel and wall clock limits applied to calls.
+    fn execution_limits(&self) -> ExecutionLimits;
+
+    /// Set the fuel and wall clock limits applied to next calls.
+    fn set_execution_limits(&mut self, limits: ExecutionLimits);
+}
+
+impl InterpreterRunner for AVMRunner {
+    fn call(
+        &mut self,
+        air: String,
+        prev_data: Vec<u8>,
+        data: Vec<u8>,
+        init_peer_id: String,
+        timestamp: u64,
+        ttl: u32,
+        current_peer_id: String,
+        call_results: CallResults,
+        keypair: &KeyPair,
+        particle_id: String,
+    ) -> RunnerResult<RawAVMOutcome> {
+        AVMRunner::call(
+            self,
+            air,
+            prev_data,
+            data,
+            init_peer_id,
+            timestamp,
+            ttl,
+            current_peer_id,
+            call_results,
+            keypair,
+            particle_id,
+        )
+    }
+
+    fn call_multi(
+        &mut self,
+        requests: &[RunnerRequest],
+        keypair: &KeyPair,
+    ) -> Vec<RunnerResult<BatchedOutcome>> {
+        AVMRunner::call_multi(self, requests, keypair)
+    }
+
+    fn heap_stats(&self) -> AVMMemoryStats {
+        AVMRunner::heap_stats(self)
+    }
+
+    fn memory_stats(&self) -> MemoryStats {
+        AVMRunner::memory_stats(self)
+    }
+
+    fn execution_limits(&self) -> ExecutionLimits {
+        AVMRunner::execution_limits(self)
+    }
+
+    fn set_execution_limits(&mut self, limits: ExecutionLimits) {
+        AVMRunner::set_execution_limits(self, limits)
+    }
+}
+
+/// Executes requests of a batch with `InterpreterRunner::call` one by one.
+pub(crate) fn execute_sequentially<R: InterpreterRunner + ?Sized>(
+    runner: &mut R,
+    requests: &[RunnerRequest],
+    keypair: &KeyPair,
+) -> Vec<RunnerResult<BatchedOutcome>> {
+    requests
+        .iter()
+        .map(|request| {
+            let execution_start_time = Instant::now();
+            let memory_size_before = runner.heap_stats().memory_size;
+            let outcome = runner.call(
+                request.air.clone(),
+                request.prev_data.clone(),
+                request.data.clone(),
+                request.init_peer_id.clone(),
+                request.timestamp,
+                request.ttl,
+                request.current_peer_id.clone(),
+                request.call_results.clone(),
+                keypair,
+                request.particle_id.clone(),
+            )?;
+
+            Ok(BatchedOutcome {
+                outcome,
+                execution_time: execution_start_time.elapsed(),
+                memory_delta: runner.heap_stats().memory_size - memory_size_before,
+            })
+        })
+        .collect()
 }
 
 /// Return statistic of AVM server Wasm module heap footprint.
@@ -84,11 +287,52 @@ impl AVMRunner {
         total_memory_limit: Option<u64>,
         avm_runtime_limits: AVMRuntimeLimits,
         logging_mask: i32,
+    ) -> RunnerResult<Self> {
+        Self::from_path(
+            air_wasm_path,
+            total_memory_limit,
+            avm_runtime_limits,
+            logging_mask,
+            None,
+        )
+    }
+
+    /// Create AVM with provided config, interpreter log messages are passed to `logging_callback`
+    /// instead of the `log` facade.
+    #[allow(clippy::result_large_err)]
+    pub fn with_logging_callback(
+        air_wasm_path: PathBuf,
+        total_memory_limit: Option<u64>,
+        avm_runtime_limits: AVMRuntimeLimits,
+        logging_mask: i32,
+        logging_callback: LoggingCallback,
+    ) -> RunnerResult<Self> {
+        Self::from_path(
+            air_wasm_path,
+            total_memory_limit,
+            avm_runtime_limits,
+            logging_mask,
+            Some(logging_callback),
+        )
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn from_path(
+        air_wasm_path: PathBuf,
+        total_memory_limit: Option<u64>,
+        avm_runtime_limits: AVMRuntimeLimits,
+        logging_mask: i32,
+        logging_callback: Option<LoggingCallback>,
     ) -> RunnerResult<Self> {
         let (wasm_dir, wasm_filename) = split_dirname(air_wasm_path)?;
 
-        let marine_config =
-            make_marine_config(wasm_dir, &wasm_filename, total_memory_limit, logging_mask);
+        let marine_config = make_marine_config(
+            Some(wasm_dir),
+            &wasm_filename,
+            total_memory_limit,
+            logging_mask,
+            logging_callback.as_ref(),
+        );
         let marine = Marine::with_raw_config(marine_config)?;
         let aquavm_runtime_limits = avm_runtime_limits.into();
 
@@ -97,6 +341,35 @@ impl AVMRunner {
             wasm_filename,
             total_memory_limit,
             aquavm_runtime_limits,
+            peak_memory_size: 0,
+            cached_air_fingerprints: <_>::default(),
+        };
+
+        Ok(avm)
+    }
+
+    /// Create AVM from an already loaded interpreter module.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn with_module_bytes(
+        wasm_filename: String,
+        wasm_bytes: Vec<u8>,
+        total_memory_limit: Option<u64>,
+        avm_runtime_limits: AVMRuntimeLimits,
+        logging_mask: i32,
+    ) -> RunnerResult<Self> {
+        let marine_config =
+            make_marine_config(None, &wasm_filename, total_memory_limit, logging_mask, None);
+        let modules = maplit::hashmap! { wasm_filename.clone() => wasm_bytes };
+        let marine = Marine::with_modules(modules, marine_config)?;
+        let aquavm_runtime_limits = avm_runtime_limits.into();
+
+        let avm = Self {
+            marine,
+            wasm_filename,
+            total_memory_limit,
+            aquavm_runtime_limits,
+            peak_memory_size: 0,
+            cached_air_fingerprints: <_>::default(),
         };
 
         Ok(avm)
@@ -117,6 +390,7 @@ impl AVMRunner {
         keypair: &KeyPair,
         particle_id: String,
     ) -> RunnerResult<RawAVMOutcome> {
+        let execution_start_time = Instant::now();
         let key_format = keypair.key_format();
         // we use secret() for compatibility with JS client that doesn't have keypair type,
         // it can serialize a secret key only
@@ -144,13 +418,186 @@ impl AVMRunner {
             "marine.call_with_ivalues",
             method = "invoke",
         );
+        self.update_peak_memory_size();
 
-        let result = try_as_one_value_vec(result)?;
-        let outcome = InterpreterOutcome::from_ivalue(result)
-            .map_err(RunnerError::InterpreterResultDeError)?;
-        let outcome = RawAVMOutcome::from_interpreter_outcome(outcome)?;
+        let outcome = parse_outcome(result);
+        self.remember_cached_air(outcome.as_ref().ok());
+        let fuel_consumed = outcome.as_ref().map_or(0, |outcome| outcome.fuel_consumed);
+        self.check_execution_time(execution_start_time, fuel_consumed)?;
 
-        Ok(outcome)
+        outcome
+    }
+
+    /// Executes a script like `call` through the `invoke_traced` export of the interpreter
+    /// and returns a tree of executed instructions with time spent in each of them as well.
+    #[allow(clippy::too_many_arguments)]
+    #[tracing::instrument(skip_all)]
+    pub fn call_traced(
+        &mut self,
+        air: impl Into<String>,
+        prev_data: impl Into<Vec<u8>>,
+        data: impl Into<Vec<u8>>,
+        init_peer_id: impl Into<String>,
+        timestamp: u64,
+        ttl: u32,
+        current_peer_id: impl Into<String>,
+        call_results: CallResults,
+        keypair: &KeyPair,
+        particle_id: String,
+    ) -> RunnerResult<(RawAVMOutcome, ExecutionTree)> {
+        let execution_start_time = Instant::now();
+        let key_format = keypair.key_format();
+        let secret_key_bytes: Vec<u8> = keypair.secret().map_err(RunnerError::KeyError)?;
+
+        let args = prepare_args(
+            air,
+            prev_data,
+            data,
+            current_peer_id.into(),
+            init_peer_id.into(),
+            timestamp,
+            ttl,
+            self.aquavm_runtime_limits,
+            call_results,
+            key_format.into(),
+            secret_key_bytes,
+            particle_id,
+        );
+
+        let result = measure!(
+            self.marine.call_with_ivalues(
+                &self.wasm_filename,
+                "invoke_traced",
+                &args,
+                <_>::default()
+            )?,
+            tracing::Level::INFO,
+            "marine.call_with_ivalues",
+            method = "invoke_traced",
+        );
+        self.update_peak_memory_size();
+
+        let result = parse_traced_outcome(result);
+        self.remember_cached_air(result.as_ref().ok().map(|(outcome, _)| outcome));
+        let fuel_consumed = result
+            .as_ref()
+            .map_or(0, |(outcome, _)| outcome.fuel_consumed);
+        self.check_execution_time(execution_start_time, fuel_consumed)?;
+
+        result
+    }
+
+    /// Executes a batch of requests through the `invoke_multi` export of the interpreter,
+    /// so the module is entered once per batch. Each execution is measured by the interpreter
+    /// and checked against the execution time limit separately. If the module traps, outcomes
+    /// of the executions completed before the trap are taken from the interpreter and the trap
+    /// error is returned for the next request.
+    #[tracing::instrument(skip_all)]
+    pub fn call_multi(
+        &mut self,
+        requests: &[RunnerRequest],
+        keypair: &KeyPair,
+    ) -> Vec<RunnerResult<BatchedOutcome>> {
+        use marine::ne_vec::NEVec;
+
+        let key_format = keypair.key_format();
+        let secret_key_bytes: Vec<u8> = match keypair.secret() {
+            Ok(secret_key_bytes) => secret_key_bytes,
+            Err(error) => return vec![Err(RunnerError::KeyError(error))],
+        };
+
+        let invoke_requests = requests
+            .iter()
+            .map(|request| {
+                let args = prepare_args(
+                    request.air.clone(),
+                    request.prev_data.clone(),
+                    request.data.clone(),
+                    request.current_peer_id.clone(),
+                    request.init_peer_id.clone(),
+                    request.timestamp,
+                    request.ttl,
+                    self.aquavm_runtime_limits,
+                    request.call_results.clone(),
+                    key_format.into(),
+                    secret_key_bytes.clone(),
+                    request.particle_id.clone(),
+                );
+                // unwrap is safe here because args is non-empty array
+                IValue::Record(NEVec::new(args).unwrap())
+            })
+            .collect();
+
+        let memory_size_before = self.heap_stats().memory_size as u64;
+        let result = measure!(
+            self.marine.call_with_ivalues(
+                &self.wasm_filename,
+                "invoke_multi",
+                &[IValue::Array(invoke_requests)],
+                <_>::default(),
+            ),
+            tracing::Level::INFO,
+            "marine.call_with_ivalues",
+            method = "invoke_multi",
+        );
+        self.update_peak_memory_size();
+
+        let (invoke_outcomes, trap) = match result {
+            Ok(result) => (parse_invoke_outcomes(result), None),
+            Err(error) => (self.take_invoke_outcomes(), Some(error)),
+        };
+        // it isn't known which executions succeeded
+        let Ok(invoke_outcomes) = invoke_outcomes else {
+            return vec![];
+        };
+
+        let mut memory_size = memory_size_before;
+        let mut outcomes = invoke_outcomes
+            .into_iter()
+            .take(requests.len())
+            .map(|invoke_outcome| {
+                let memory_delta = invoke_outcome.memory_size.saturating_sub(memory_size);
+                memory_size = invoke_outcome.memory_size;
+                self.complete_invoke_outcome(invoke_outcome, memory_delta as usize)
+            })
+            .collect::<Vec<_>>();
+        if let Some(trap) = trap.filter(|_| outcomes.len() < requests.len()) {
+            outcomes.push(Err(trap.into()));
+        }
+
+        outcomes
+    }
+
+    /// Takes outcomes of the executions a trapped `invoke_multi` call has completed.
+    #[allow(clippy::result_large_err)]
+    fn take_invoke_outcomes(&mut self) -> RunnerResult<Vec<InvokeOutcome>> {
+        let result = self.marine.call_with_ivalues(
+            &self.wasm_filename,
+            "take_invoke_outcomes",
+            &[],
+            <_>::default(),
+        )?;
+
+        parse_invoke_outcomes(result)
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn complete_invoke_outcome(
+        &mut self,
+        invoke_outcome: InvokeOutcome,
+        memory_delta: usize,
+    ) -> RunnerResult<BatchedOutcome> {
+        let outcome = RawAVMOutcome::from_interpreter_outcome(invoke_outcome.outcome)?;
+        self.remember_cached_air([&outcome]);
+
+        let execution_time = Duration::from_micros(invoke_outcome.execution_time_us);
+        self.check_elapsed_time(execution_time, outcome.fuel_consumed)?;
+
+        Ok(BatchedOutcome {
+            outcome,
+            execution_time,
+            memory_delta,
+        })
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -171,6 +618,7 @@ impl AVMRunner {
         secret_key_bytes: Vec<u8>,
         particle_id: String,
     ) -> RunnerResult<RawAVMOutcome> {
+        let execution_start_time = Instant::now();
         let mut args = prepare_args(
             air,
             prev_data,
@@ -199,13 +647,14 @@ impl AVMRunner {
             "marine.call_with_ivalues",
             method = "invoke_tracing",
         );
+        self.update_peak_memory_size();
 
-        let result = try_as_one_value_vec(result)?;
-        let outcome = InterpreterOutcome::from_ivalue(result)
-            .map_err(RunnerError::InterpreterResultDeError)?;
-        let outcome = RawAVMOutcome::from_interpreter_outcome(outcome)?;
+        let outcome = parse_outcome(result);
+        self.remember_cached_air(outcome.as_ref().ok());
+        let fuel_consumed = outcome.as_ref().map_or(0, |outcome| outcome.fuel_consumed);
+        self.check_execution_time(execution_start_time, fuel_consumed)?;
 
-        Ok(outcome)
+        outcome
     }
 
     pub fn to_human_readable_data(&mut self, data: Vec<u8>) -> RunnerResult<String> {
@@ -222,7 +671,7 @@ impl AVMRunner {
         Ok(outcome)
     }
 
-    pub fn memory_stats(&self) -> AVMMemoryStats {
+    pub fn heap_stats(&self) -> AVMMemoryStats {
         let stats = self.marine.module_memory_stats();
 
         // only the interpreters must be loaded in Marine
@@ -234,6 +683,98 @@ impl AVMRunner {
             allocation_rejects: stats.allocation_stats.map(|stats| stats.allocation_rejects),
         }
     }
+
+    /// Sets which peers' signatures the interpreter verifies in incoming data.
+    pub fn set_signature_policy(&mut self, signature_policy: SignaturePolicyKind) {
+        self.aquavm_runtime_limits.signature_policy = signature_policy;
+    }
+
+    /// Sets which key formats the interpreter accepts in signature stores and for the peer's keypair.
+    pub fn set_key_format_policy(&mut self, key_format_policy: KeyFormatPolicyKind) {
+        self.aquavm_runtime_limits.key_format_policy = key_format_policy;
+    }
+
+    /// Sets a format call results are passed to the interpreter in.
+    pub fn set_call_results_format(&mut self, call_results_format: CallResultsFormat) {
+        self.aquavm_runtime_limits.call_results_format = call_results_format;
+    }
+
+    /// Sets the maximum depth of nested folds and fold iterations, `None` disables the limit.
+    pub fn set_max_fold_depth(&mut self, max_fold_depth: Option<u32>) {
+        self.aquavm_runtime_limits.max_fold_depth = max_fold_depth;
+    }
+
+    /// Sets the maximum number of instructions in a script, `None` disables the limit.
+    pub fn set_max_instruction_count(&mut self, max_instruction_count: Option<u64>) {
+        self.aquavm_runtime_limits.max_instruction_count = max_instruction_count;
+    }
+
+    /// Return the fuel and wall clock limits applied to calls.
+    pub fn execution_limits(&self) -> ExecutionLimits {
+        let AquaVMRuntimeLimits {
+            fuel_limit,
+            max_execution_time,
+            ..
+        } = self.aquavm_runtime_limits;
+
+        ExecutionLimits {
+            fuel_limit,
+            max_execution_time,
+        }
+    }
+
+    /// Sets the fuel and wall clock limits applied to next calls.
+    pub fn set_execution_limits(&mut self, limits: ExecutionLimits) {
+        self.aquavm_runtime_limits.fuel_limit = limits.fuel_limit;
+        self.aquavm_runtime_limits.max_execution_time = limits.max_execution_time;
+    }
+
+    /// Return linear memory usage of the interpreter, it's intended to be called after `call`.
+    pub fn memory_stats(&self) -> MemoryStats {
+        let memory_size = self.heap_stats().memory_size as u64;
+        MemoryStats::new(memory_size, self.peak_memory_size.max(memory_size))
+    }
+
+    /// The interpreter stops itself once the limit is exceeded, this check also covers time
+    /// spent outside of it, e.g. in the Wasm engine. Marine doesn't expose the Wasmtime engine
+    /// and store, so the module can't be interrupted by epoch deadlines instead.
+    #[allow(clippy::result_large_err)]
+    fn check_execution_time(
+        &self,
+        execution_start_time: Instant,
+        fuel_consumed: u64,
+    ) -> RunnerResult<()> {
+        self.check_elapsed_time(execution_start_time.elapsed(), fuel_consumed)
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn check_elapsed_time(&self, elapsed: Duration, fuel_consumed: u64) -> RunnerResult<()> {
+        match self.aquavm_runtime_limits.max_execution_time {
+            Some(limit) if elapsed > limit => Err(RunnerError::TimedOut {
+                elapsed,
+                fuel_consumed,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Tells whether the interpreter has reported a script with this fingerprint as cached,
+    /// so executing the same script again with this runner skips parsing it.
+    pub fn caches_air(&self, air_fingerprint: &[u8; 32]) -> bool {
+        self.cached_air_fingerprints.contains(air_fingerprint)
+    }
+
+    fn remember_cached_air<'o>(&mut self, outcomes: impl IntoIterator<Item = &'o RawAVMOutcome>) {
+        let fingerprints = outcomes
+            .into_iter()
+            .filter_map(|outcome| outcome.air_fingerprint);
+        self.cached_air_fingerprints.extend(fingerprints);
+    }
+
+    fn update_peak_memory_size(&mut self) {
+        let memory_size = self.heap_stats().memory_size as u64;
+        self.peak_memory_size = self.peak_memory_size.max(memory_size);
+    }
 }
 
 #[allow(clippy::too_many_arguments)]
@@ -257,30 +798,46 @@ fn prepare_args(
         particle_size_limit,
         call_result_size_limit,
         hard_limit_enabled,
+        fuel_limit,
+        max_execution_time,
+        air_cache_capacity,
+        signature_policy,
+        key_format_policy,
+        max_fold_depth,
+        max_instruction_count,
+        call_results_format,
     } = aquavm_runtime_limits;
 
-    let run_parameters = air_interpreter_interface::RunParameters::new(
-        init_peer_id,
-        current_peer_id,
-        timestamp,
-        ttl,
-        key_format,
-        secret_key_bytes,
-        particle_id,
-        air_size_limit,
-        particle_size_limit,
-        call_result_size_limit,
-        hard_limit_enabled,
-    )
-    .into_ivalue();
+    let run_parameters = air_interpreter_interface::RunParameters::builder()
+        .init_peer_id(init_peer_id)
+        .current_peer_id(current_peer_id)
+        .timestamp(timestamp)
+        .ttl(ttl)
+        .key_format(key_format)
+        .secret_key_bytes(secret_key_bytes)
+        .particle_id(particle_id)
+        .air_size_limit(air_size_limit)
+        .particle_size_limit(particle_size_limit)
+        .call_result_size_limit(call_result_size_limit)
+        .hard_limit_enabled(hard_limit_enabled)
+        .fuel_limit(fuel_limit)
+        .max_execution_time(max_execution_time)
+        .air_cache_capacity(air_cache_capacity)
+        .signature_policy(signature_policy)
+        .key_format_policy(key_format_policy)
+        .max_fold_depth(max_fold_depth)
+        .max_instruction_count(max_instruction_count)
+        .call_results_format(call_results_format)
+        .build()
+        .expect("all required run parameters are set")
+        .into_ivalue();
 
     let call_results = avm_interface::into_raw_result(call_results);
     let call_results = measure!(
-        CallResultsRepr
-            .serialize(&call_results)
-            .expect("the default serializer shouldn't fail"),
+        serialize_call_results(&call_results, call_results_format)
+            .expect("call results serializers shouldn't fail"),
         tracing::Level::INFO,
-        "CallResultsRepr.serialize"
+        "serialize_call_results"
     );
 
     vec![
@@ -288,7 +845,7 @@ fn prepare_args(
         IValue::ByteArray(prev_data.into()),
         IValue::ByteArray(data.into()),
         run_parameters,
-        IValue::ByteArray(call_results.to_vec()),
+        IValue::ByteArray(call_results),
     ]
 }
 
@@ -296,7 +853,7 @@ fn prepare_args(
 ///
 /// # Example
 /// For path `/path/to/air_interpreter_server.wasm` result will be `Ok(PathBuf(/path/to), "air_interpreter_server.wasm")`
-fn split_dirname(path: PathBuf) -> RunnerResult<(PathBuf, String)> {
+pub(crate) fn split_dirname(path: PathBuf) -> RunnerResult<(PathBuf, String)> {
     use RunnerError::InvalidAIRPath;
 
     let metadata = path.metadata().map_err(|err| InvalidAIRPath {
@@ -326,20 +883,24 @@ fn split_dirname(path: PathBuf) -> RunnerResult<(PathBuf, String)> {
 }
 
 fn make_marine_config(
-    air_wasm_dir: PathBuf,
+    air_wasm_dir: Option<PathBuf>,
     air_wasm_file: &str,
     total_memory_limit: Option<u64>,
     logging_mask: i32,
+    logging_callback: Option<&LoggingCallback>,
 ) -> MarineConfig {
+    // the callback replaces the Marine logger, so it's disabled to not provide the import twice
     let air_module_config = marine::MarineModuleConfig {
-        logger_enabled: true,
-        host_imports: <_>::default(),
+        logger_enabled: logging_callback.is_none(),
+        host_imports: logging_callback
+            .map(|callback| callback.host_imports(logging_mask))
+            .unwrap_or_default(),
         wasi: None,
         logging_mask,
     };
 
     MarineConfig {
-        modules_dir: Some(air_wasm_dir),
+        modules_dir: air_wasm_dir,
         total_memory_limit,
         modules_config: vec![ModuleDescriptor {
             load_from: None,
@@ -361,18 +922,62 @@ fn try_as_one_value_vec(mut ivalues: Vec<IValue>) -> RunnerResult<IValue> {
     Ok(ivalues.remove(0))
 }
 
+#[allow(clippy::result_large_err)]
+fn parse_outcome(result: Vec<IValue>) -> RunnerResult<RawAVMOutcome> {
+    let result = try_as_one_value_vec(result)?;
+    let outcome =
+        InterpreterOutcome::from_ivalue(result).map_err(RunnerError::InterpreterResultDeError)?;
+    let outcome = RawAVMOutcome::from_interpreter_outcome(outcome)?;
+
+    Ok(outcome)
+}
+
+#[allow(clippy::result_large_err)]
+fn parse_traced_outcome(result: Vec<IValue>) -> RunnerResult<(RawAVMOutcome, ExecutionTree)> {
+    let result = try_as_one_value_vec(result)?;
+    let outcome =
+        InterpreterOutcome::from_ivalue(result).map_err(RunnerError::InterpreterResultDeError)?;
+
+    Ok(RawAVMOutcome::from_traced_interpreter_outcome(outcome)?)
+}
+
+#[allow(clippy::result_large_err)]
+fn parse_invoke_outcomes(result: Vec<IValue>) -> RunnerResult<Vec<InvokeOutcome>> {
+    let outcomes = match try_as_one_value_vec(result)? {
+        IValue::Array(outcomes) => outcomes,
+        result => return Err(RunnerError::IncorrectInterpreterResult(vec![result])),
+    };
+
+    outcomes
+        .into_iter()
+        .map(|outcome| {
+            InvokeOutcome::from_ivalue(outcome).map_err(RunnerError::InterpreterResultDeError)
+        })
+        .collect()
+}
+
 impl AquaVMRuntimeLimits {
     pub fn new(
         air_size_limit: u64,
         particle_size_limit: u64,
         call_result_size_limit: u64,
         hard_limit_enabled: bool,
+        fuel_limit: Option<u64>,
+        max_execution_time: Option<Duration>,
     ) -> Self {
         Self {
             air_size_limit,
             particle_size_limit,
             call_result_size_limit,
             hard_limit_enabled,
+            fuel_limit,
+            max_execution_time,
+            air_cache_capacity: 0,
+            signature_policy: <_>::default(),
+            key_format_policy: <_>::default(),
+            max_fold_depth: None,
+            max_instruction_count: None,
+            call_results_format: <_>::default(),
         }
     }
 }
@@ -383,12 +988,17 @@ impl AVMRuntimeLimits {
         particle_size_limit: Option<u64>,
         call_result_size_limit: Option<u64>,
         hard_limit_enabled: bool,
+        fuel_limit: Option<u64>,
+        max_execution_time: Option<Duration>,
     ) -> Self {
         Self {
             air_size_limit,
             particle_size_limit,
             call_result_size_limit,
             hard_limit_enabled,
+            fuel_limit,
+            max_execution_time,
+            air_cache_capacity: 0,
         }
     }
 }
@@ -399,11 +1009,17 @@ impl From<AVMRuntimeLimits> for AquaVMRuntimeLimits {
         use air_interpreter_interface::MAX_CALL_RESULT_SIZE;
         use air_interpreter_interface::MAX_PARTICLE_SIZE;
 
-        AquaVMRuntimeLimits::new(
+        let limits = AquaVMRuntimeLimits::new(
             value.air_size_limit.unwrap_or(MAX_AIR_SIZE),
             value.particle_size_limit.unwrap_or(MAX_PARTICLE_SIZE),
             value.call_result_size_limit.unwrap_or(MAX_CALL_RESULT_SIZE),
             value.hard_limit_enabled,
-        )
+            value.fuel_limit,
+            value.max_execution_time,
+        );
+        AquaVMRuntimeLimits {
+            air_cache_capacity: value.air_cache_capacity,
+            ..limits
+        }
     }
 }
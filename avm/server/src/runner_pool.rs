@@ -0,0 +1,373 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::avm::SendSafeRunner;
+use crate::config::PoolConfig;
+use crate::runner::split_dirname;
+use crate::runner::AVMRunner;
+use crate::RunnerError;
+use crate::RunnerResult;
+
+use parking_lot::Condvar;
+use parking_lot::Mutex;
+
+use std::ops::Deref;
+use std::ops::DerefMut;
+use std::time::Duration;
+use std::time::Instant;
+
+type RunnerFactory<R> = Box<dyn Fn() -> RunnerResult<R> + Send + Sync>;
+
+/// A pool of AVM runners intended to be shared between threads executing particles.
+///
+/// The interpreter file is read from disk only once, but Marine can't share a compiled
+/// module between instances, so every runner compiles its own module from these bytes
+/// when it's created. Runners are pre-warmed on creation and reused afterwards, so
+/// checking out an idle one doesn't involve compiling or instantiating the interpreter.
+pub struct AVMRunnerPool<R = SendSafeRunner> {
+    new_runner: RunnerFactory<R>,
+    min_size: usize,
+    max_size: usize,
+    idle_timeout: Duration,
+    state: Mutex<PoolState<R>>,
+    runner_released: Condvar,
+}
+
+/// A runner checked out from a pool, it's returned to the pool on drop.
+pub struct PoolGuard<'pool, R = SendSafeRunner> {
+    pool: &'pool AVMRunnerPool<R>,
+    // it's always Some until the guard is dropped or discarded
+    runner: Option<R>,
+}
+
+struct PoolState<R> {
+    idle: Vec<IdleRunner<R>>,
+    checked_out: usize,
+}
+
+struct IdleRunner<R> {
+    runner: R,
+    idle_since: Instant,
+}
+
+impl AVMRunnerPool {
+    /// Create a pool with `count` pre-warmed runners.
+    #[allow(clippy::result_large_err)]
+    pub fn new(config: PoolConfig, count: usize) -> RunnerResult<Self> {
+        let PoolConfig {
+            air_wasm_path,
+            max_heap_size,
+            logging_mask,
+            avm_runtime_limits,
+            max_size,
+            idle_timeout,
+        } = config;
+
+        if count > max_size {
+            return Err(RunnerError::InvalidPoolSize { count, max_size });
+        }
+
+        let (_, wasm_filename) = split_dirname(air_wasm_path.clone())?;
+        let wasm_bytes =
+            std::fs::read(&air_wasm_path).map_err(|io_error| RunnerError::InvalidAIRPath {
+                invalid_path: air_wasm_path,
+                io_error: Some(io_error),
+                reason: "failed to read the file",
+            })?;
+
+        let new_runner = move || {
+            AVMRunner::with_module_bytes(
+                wasm_filename.clone(),
+                wasm_bytes.clone(),
+                max_heap_size,
+                avm_runtime_limits.clone(),
+                logging_mask,
+            )
+            .map(SendSafeRunner)
+        };
+
+        Self::with_factory(count, max_size, idle_timeout, Box::new(new_runner))
+    }
+
+    /// Check out a runner like `acquire`, but prefer an idle runner whose interpreter caches
+    /// the script with this fingerprint. The fingerprint is reported in `RawAVMOutcome`
+    /// of a previous round when the pool runners have the AIR cache enabled.
+    #[allow(clippy::result_large_err)]
+    pub fn acquire_for_air(&self, air_fingerprint: &[u8; 32]) -> RunnerResult<PoolGuard<'_>> {
+        self.acquire_preferring(|runner| runner.caches_air(air_fingerprint))
+    }
+}
+
+impl<R> AVMRunnerPool<R> {
+    #[allow(clippy::result_large_err)]
+    fn with_factory(
+        count: usize,
+        max_size: usize,
+        idle_timeout: Duration,
+        new_runner: RunnerFactory<R>,
+    ) -> RunnerResult<Self> {
+        let idle = (0..count)
+            .map(|_| {
+                new_runner().map(|runner| IdleRunner {
+                    runner,
+                    idle_since: Instant::now(),
+                })
+            })
+            .collect::<RunnerResult<_>>()?;
+
+        let pool = Self {
+            new_runner,
+            min_size: count,
+            max_size,
+            idle_timeout,
+            state: Mutex::new(PoolState {
+                idle,
+                checked_out: 0,
+            }),
+            runner_released: <_>::default(),
+        };
+
+        Ok(pool)
+    }
+
+    /// Check out a runner, creating a new one if there are no idle runners and the pool
+    /// hasn't reached its max size yet. Otherwise, blocks until some runner is returned.
+    #[allow(clippy::result_large_err)]
+    pub fn acquire(&self) -> RunnerResult<PoolGuard<'_, R>> {
+        self.acquire_preferring(|_| false)
+    }
+
+    /// Check out a runner like `acquire`, but prefer idle runners matching the predicate.
+    #[allow(clippy::result_large_err)]
+    pub fn acquire_preferring(
+        &self,
+        is_preferred: impl Fn(&R) -> bool,
+    ) -> RunnerResult<PoolGuard<'_, R>> {
+        let mut state = self.state.lock();
+        loop {
+            self.shrink(&mut state);
+
+            // the most recently returned runner is taken by default, so the others could expire
+            let position = state
+                .idle
+                .iter()
+                .rposition(|idle_runner| is_preferred(&idle_runner.runner))
+                .or_else(|| state.idle.len().checked_sub(1));
+            if let Some(position) = position {
+                let IdleRunner { runner, .. } = state.idle.remove(position);
+                state.checked_out += 1;
+                return Ok(PoolGuard::new(self, runner));
+            }
+
+            if state.size() < self.max_size {
+                // reserve a slot, so the runner could be created without holding the lock
+                state.checked_out += 1;
+                drop(state);
+
+                return match (self.new_runner)() {
+                    Ok(runner) => Ok(PoolGuard::new(self, runner)),
+                    Err(error) => {
+                        self.free_slot();
+                        Err(error)
+                    }
+                };
+            }
+
+            self.runner_released.wait(&mut state);
+        }
+    }
+
+    /// Returns the number of runners owned by the pool, both idle and checked out.
+    pub fn size(&self) -> usize {
+        self.state.lock().size()
+    }
+
+    fn release(&self, runner: R) {
+        let mut state = self.state.lock();
+        state.checked_out -= 1;
+        state.idle.push(IdleRunner {
+            runner,
+            idle_since: Instant::now(),
+        });
+        self.shrink(&mut state);
+        drop(state);
+
+        self.runner_released.notify_one();
+    }
+
+    fn free_slot(&self) {
+        self.state.lock().checked_out -= 1;
+        self.runner_released.notify_one();
+    }
+
+    fn shrink(&self, state: &mut PoolState<R>) {
+        // idle runners are pushed to the end, so the longest idle ones are at the beginning
+        let expired_count = state
+            .idle
+            .iter()
+            .take_while(|idle_runner| idle_runner.idle_since.elapsed() > self.idle_timeout)
+            .count();
+        let removable_count = state.size().saturating_sub(self.min_size);
+
+        state.idle.drain(..expired_count.min(removable_count));
+    }
+}
+
+impl<R> PoolState<R> {
+    fn size(&self) -> usize {
+        self.idle.len() + self.checked_out
+    }
+}
+
+impl<'pool, R> PoolGuard<'pool, R> {
+    fn new(pool: &'pool AVMRunnerPool<R>, runner: R) -> Self {
+        Self {
+            pool,
+            runner: Some(runner),
+        }
+    }
+
+    /// Drops the runner instead of returning it to the pool, e.g. after its module trapped
+    /// and its state can't be trusted anymore. The pool creates a new runner in its place
+    /// when it's needed.
+    pub fn discard(mut self) {
+        self.runner.take();
+        self.pool.free_slot();
+    }
+}
+
+impl<R> Deref for PoolGuard<'_, R> {
+    type Target = R;
+
+    fn deref(&self) -> &Self::Target {
+        self.runner.as_ref().expect("runner is taken only on drop")
+    }
+}
+
+impl<R> DerefMut for PoolGuard<'_, R> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.runner.as_mut().expect("runner is taken only on drop")
+    }
+}
+
+impl<R> Drop for PoolGuard<'_, R> {
+    fn drop(&mut self) {
+        if let Some(runner) = self.runner.take() {
+            self.pool.release(runner);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::AtomicUsize;
+    use std::sync::atomic::Ordering;
+    use std::sync::mpsc;
+    use std::sync::Arc;
+
+    const NOT_RETURNED_TIMEOUT: Duration = Duration::from_millis(100);
+    const RETURNED_TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// Runners are numbered in the order they are created.
+    fn pool(count: usize, max_size: usize, idle_timeout: Duration) -> AVMRunnerPool<usize> {
+        let created = Arc::new(AtomicUsize::new(0));
+        let new_runner = move || Ok(created.fetch_add(1, Ordering::SeqCst));
+
+        AVMRunnerPool::with_factory(count, max_size, idle_timeout, Box::new(new_runner)).unwrap()
+    }
+
+    #[test]
+    fn runner_is_returned_on_drop() {
+        let pool = pool(1, 1, Duration::MAX);
+
+        let runner = *pool.acquire().unwrap();
+        let same_runner = *pool.acquire().unwrap();
+
+        assert_eq!(runner, same_runner);
+        assert_eq!(pool.size(), 1);
+    }
+
+    #[test]
+    fn pool_grows_up_to_max_size() {
+        let pool = pool(1, 2, Duration::MAX);
+
+        let first = pool.acquire().unwrap();
+        let second = pool.acquire().unwrap();
+
+        assert_ne!(*first, *second);
+        assert_eq!(pool.size(), 2);
+    }
+
+    #[test]
+    fn exhausted_pool_blocks_until_runner_is_returned() {
+        let pool = pool(1, 1, Duration::MAX);
+        let guard = pool.acquire().unwrap();
+        let runner = *guard;
+
+        let (sender, receiver) = mpsc::channel();
+        std::thread::scope(|scope| {
+            scope.spawn(|| sender.send(*pool.acquire().unwrap()).unwrap());
+
+            assert!(receiver.recv_timeout(NOT_RETURNED_TIMEOUT).is_err());
+            drop(guard);
+            assert_eq!(receiver.recv_timeout(RETURNED_TIMEOUT), Ok(runner));
+        });
+        assert_eq!(pool.size(), 1);
+    }
+
+    #[test]
+    fn runner_discarded_after_trap_is_replaced() {
+        let pool = pool(1, 1, Duration::MAX);
+        let guard = pool.acquire().unwrap();
+        let trapped_runner = *guard;
+
+        let (sender, receiver) = mpsc::channel();
+        std::thread::scope(|scope| {
+            scope.spawn(|| sender.send(*pool.acquire().unwrap()).unwrap());
+
+            assert!(receiver.recv_timeout(NOT_RETURNED_TIMEOUT).is_err());
+            guard.discard();
+            let new_runner = receiver.recv_timeout(RETURNED_TIMEOUT).unwrap();
+            assert_ne!(new_runner, trapped_runner);
+        });
+        assert_eq!(pool.size(), 1);
+    }
+
+    #[test]
+    fn preferred_runner_is_acquired() {
+        let pool = pool(2, 2, Duration::MAX);
+
+        let preferred = pool.acquire_preferring(|&runner| runner == 0).unwrap();
+        let other = pool.acquire_preferring(|&runner| runner == 2).unwrap();
+
+        assert_eq!(*preferred, 0);
+        assert_eq!(*other, 1);
+    }
+
+    #[test]
+    fn idle_runners_above_initial_size_are_dropped() {
+        let pool = pool(1, 2, Duration::ZERO);
+
+        let first = pool.acquire().unwrap();
+        let second = pool.acquire().unwrap();
+        drop(first);
+        drop(second);
+
+        assert_eq!(pool.size(), 1);
+    }
+}
@@ -0,0 +1,127 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Cumulative execution statistics of an AVM instance since its creation or the last reset.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AVMStats {
+    /// The number of `AVM::call` invocations.
+    pub calls_total: u64,
+    /// The number of calls that returned an error, including interpreter errors.
+    pub calls_failed: u64,
+    /// Fuel consumed by the interpreter over all calls.
+    pub fuel_consumed_total: u64,
+    /// Sizes of previous and current data passed to the interpreter.
+    pub data_bytes_read: u64,
+    /// Sizes of resulting data persisted in the data store.
+    pub data_bytes_written: u64,
+    /// The average execution time of the interpreter in nanoseconds.
+    pub avg_execution_ns: u64,
+}
+
+/// A handle to statistics of an AVM, clones share the same state. It could be read from
+/// another thread while the AVM is busy with a call.
+#[derive(Debug, Clone, Default)]
+pub struct AVMStatsHandle {
+    counters: Arc<Counters>,
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    calls_total: AtomicU64,
+    calls_failed: AtomicU64,
+    fuel_consumed_total: AtomicU64,
+    data_bytes_read: AtomicU64,
+    data_bytes_written: AtomicU64,
+    executions_total: AtomicU64,
+    execution_ns_total: AtomicU64,
+}
+
+impl AVMStatsHandle {
+    /// Returns a snapshot of the statistics, counters are read one by one,
+    /// so a snapshot taken during a call could reflect only a part of it.
+    pub fn stats(&self) -> AVMStats {
+        let counters = &self.counters;
+        let executions_total = counters.executions_total.load(Ordering::Relaxed);
+        let execution_ns_total = counters.execution_ns_total.load(Ordering::Relaxed);
+
+        AVMStats {
+            calls_total: counters.calls_total.load(Ordering::Relaxed),
+            calls_failed: counters.calls_failed.load(Ordering::Relaxed),
+            fuel_consumed_total: counters.fuel_consumed_total.load(Ordering::Relaxed),
+            data_bytes_read: counters.data_bytes_read.load(Ordering::Relaxed),
+            data_bytes_written: counters.data_bytes_written.load(Ordering::Relaxed),
+            avg_execution_ns: execution_ns_total
+                .checked_div(executions_total)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Zeroes all counters, it's intended for rolling-window metrics.
+    pub fn reset(&self) {
+        let counters = &self.counters;
+        for counter in [
+            &counters.calls_total,
+            &counters.calls_failed,
+            &counters.fuel_consumed_total,
+            &counters.data_bytes_read,
+            &counters.data_bytes_written,
+            &counters.executions_total,
+            &counters.execution_ns_total,
+        ] {
+            counter.store(0, Ordering::Relaxed);
+        }
+    }
+
+    pub(crate) fn record_call(&self, failed: bool) {
+        self.counters.calls_total.fetch_add(1, Ordering::Relaxed);
+        if failed {
+            self.counters.calls_failed.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub(crate) fn record_execution(
+        &self,
+        execution_time: Duration,
+        fuel_consumed: u64,
+        data_bytes_read: usize,
+    ) {
+        let counters = &self.counters;
+        // durations that don't fit into u64 nanoseconds are hundreds of years long
+        let execution_ns = execution_time.as_nanos().try_into().unwrap_or(u64::MAX);
+
+        counters.executions_total.fetch_add(1, Ordering::Relaxed);
+        counters
+            .execution_ns_total
+            .fetch_add(execution_ns, Ordering::Relaxed);
+        counters
+            .fuel_consumed_total
+            .fetch_add(fuel_consumed, Ordering::Relaxed);
+        counters
+            .data_bytes_read
+            .fetch_add(data_bytes_read as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_write(&self, data_bytes_written: usize) {
+        self.counters
+            .data_bytes_written
+            .fetch_add(data_bytes_written as u64, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,1217 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Test doubles for code that drives `AVM` and can't load the interpreter Wasm module,
+//! and a harness replaying recorded particle histories.
+
+use crate::runner::execute_sequentially;
+use crate::runner::BatchedOutcome;
+use crate::runner::ExecutionLimits;
+use crate::runner::InterpreterRunner;
+use crate::runner::RunnerRequest;
+use crate::AVMMemoryStats;
+use crate::RunnerError;
+use crate::RunnerResult;
+
+use air_interpreter_data::CallRequestId;
+use air_interpreter_data::DataDeserializationError;
+use air_interpreter_data::ExecutedState;
+use air_interpreter_data::InterpreterData;
+use air_interpreter_data::InterpreterDataEnvelope;
+use avm_interface::raw_outcome::RawAVMOutcome;
+use avm_interface::CallResults;
+use avm_interface::MemoryStats;
+use avm_interface::ParticleParameters;
+use fluence_keypair::KeyPair;
+use parking_lot::Mutex;
+use thiserror::Error as ThisError;
+
+use std::sync::Arc;
+
+/// A runner returning registered outcomes instead of executing AIR scripts.
+///
+/// Every registered response is returned once for a call with the same script and call results,
+/// a call nothing was registered for panics. Clones share responses, so a clone kept by a test
+/// can check with `assert_all_calls_made` that `AVM` made all the expected calls.
+#[derive(Debug, Default, Clone)]
+pub struct MockAVMRunner {
+    responses: Arc<Mutex<Vec<MockResponse>>>,
+    execution_limits: ExecutionLimits,
+    memory_growth: u64,
+    memory_size: u64,
+    trapping_scripts: Vec<String>,
+}
+
+#[derive(Debug)]
+struct MockResponse {
+    air: String,
+    call_results: CallResults,
+    outcome: RawAVMOutcome,
+    is_used: bool,
+}
+
+impl MockAVMRunner {
+    pub fn new() -> Self {
+        <_>::default()
+    }
+
+    /// Registers an outcome returned for a call with the provided script and call results.
+    pub fn with_response(
+        self,
+        air: impl Into<String>,
+        call_results: CallResults,
+        outcome: RawAVMOutcome,
+    ) -> Self {
+        self.add_response(air, call_results, outcome);
+        self
+    }
+
+    /// Registers an outcome like `with_response`, but through a shared reference, so responses
+    /// could be added after the runner was moved into `AVM`.
+    pub fn add_response(
+        &self,
+        air: impl Into<String>,
+        call_results: CallResults,
+        outcome: RawAVMOutcome,
+    ) {
+        self.responses.lock().push(MockResponse {
+            air: air.into(),
+            call_results,
+            outcome,
+            is_used: false,
+        });
+    }
+
+    /// Makes every call grow the reported linear memory by `memory_growth` bytes,
+    /// like an interpreter that leaks memory would.
+    pub fn with_memory_growth(mut self, memory_growth: u64) -> Self {
+        self.memory_growth = memory_growth;
+        self
+    }
+
+    /// Makes a batch executed with `call_multi` stop on a request with the provided script,
+    /// like the interpreter trapping on it, the following requests of the batch aren't executed.
+    pub fn with_trap(mut self, air: impl Into<String>) -> Self {
+        self.trapping_scripts.push(air.into());
+        self
+    }
+
+    /// Panics if some registered responses weren't returned.
+    pub fn assert_all_calls_made(&self) {
+        let responses = self.responses.lock();
+        let unused = responses
+            .iter()
+            .filter(|response| !response.is_used)
+            .map(|response| (&response.air, &response.call_results))
+            .collect::<Vec<_>>();
+
+        assert!(unused.is_empty(), "expected calls weren't made: {unused:?}");
+    }
+}
+
+impl InterpreterRunner for MockAVMRunner {
+    fn call(
+        &mut self,
+        air: String,
+        _prev_data: Vec<u8>,
+        _data: Vec<u8>,
+        _init_peer_id: String,
+        _timestamp: u64,
+        _ttl: u32,
+        _current_peer_id: String,
+        call_results: CallResults,
+        _keypair: &KeyPair,
+        _particle_id: String,
+    ) -> RunnerResult<RawAVMOutcome> {
+        let mut responses = self.responses.lock();
+        let response = responses
+            .iter_mut()
+            .find(|response| {
+                !response.is_used && response.air == air && response.call_results == call_results
+            })
+            .unwrap_or_else(|| {
+                panic!("unexpected call with script {air:?} and call results {call_results:?}")
+            });
+        response.is_used = true;
+        self.memory_size += self.memory_growth;
+
+        Ok(response.outcome.clone())
+    }
+
+    fn call_multi(
+        &mut self,
+        requests: &[RunnerRequest],
+        keypair: &KeyPair,
+    ) -> Vec<RunnerResult<BatchedOutcome>> {
+        let trap_position = requests
+            .iter()
+            .position(|request| self.trapping_scripts.contains(&request.air));
+        let executed_count = trap_position.unwrap_or(requests.len());
+
+        let mut outcomes = execute_sequentially(self, &requests[..executed_count], keypair);
+        if let Some(trap_position) = trap_position {
+            let air = &requests[trap_position].air;
+            let error = RunnerError::Aux(format!("interpreter trapped on script {air:?}"));
+            outcomes.push(Err(error));
+        }
+
+        outcomes
+    }
+
+    fn heap_stats(&self) -> AVMMemoryStats {
+        AVMMemoryStats {
+            memory_size: self.memory_size as usize,
+            total_memory_limit: None,
+            allocation_rejects: None,
+        }
+    }
+
+    fn memory_stats(&self) -> MemoryStats {
+        // memory only grows, so the current size is the peak one
+        MemoryStats::new(self.memory_size, self.memory_size)
+    }
+
+    fn execution_limits(&self) -> ExecutionLimits {
+        self.execution_limits
+    }
+
+    fn set_execution_limits(&mut self, limits: ExecutionLimits) {
+        self.execution_limits = limits;
+    }
+}
+
+/// A recorded execution round of a particle on a peer.
+#[derive(Debug, Clone)]
+pub struct ReplayRound {
+    pub air: String,
+    /// Data the particle came with.
+    pub data: Vec<u8>,
+    pub call_results: CallResults,
+    pub particle: ParticleParameters<'static>,
+    /// Data the peer stored after the round.
+    pub expected_data: Vec<u8>,
+}
+
+/// Re-executes recorded rounds of a particle and compares produced data with the recorded one.
+///
+/// Rounds are executed in the order they were added, data recorded after a round is used as
+/// the previous data of the next one, so a mismatch in a round doesn't affect the next rounds.
+/// Signatures are not compared, since they depend on the key of the recording peer.
+pub struct ReplayHarness<R> {
+    runner: R,
+    keypair: KeyPair,
+    rounds: Vec<ReplayRound>,
+}
+
+/// Result of a replayed round, rounds are numbered from zero.
+#[derive(Debug)]
+pub enum ReplayResult {
+    Passed { round: usize },
+    Mismatch { round: usize, diff: DataDiff },
+    Failed { round: usize, error: ReplayError },
+}
+
+#[derive(Debug, ThisError)]
+pub enum ReplayError {
+    #[error("failed to execute the round: {0}")]
+    Runner(#[from] RunnerError),
+
+    #[error("recorded data can't be deserialized: {0}")]
+    InvalidExpectedData(DataDeserializationError),
+
+    #[error("produced data can't be deserialized: {0}")]
+    InvalidActualData(DataDeserializationError),
+}
+
+/// Difference between recorded and produced data.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DataDiff {
+    /// Differing states of expanded traces, a state missing in one of them is `None`.
+    pub trace: Vec<TraceStateDiff>,
+    /// Recorded and produced ids, if they differ.
+    pub last_call_request_id: Option<(CallRequestId, CallRequestId)>,
+    pub cid_info_differs: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceStateDiff {
+    pub position: usize,
+    pub expected: Option<ExecutedState>,
+    pub actual: Option<ExecutedState>,
+}
+
+impl<R: InterpreterRunner> ReplayHarness<R> {
+    pub fn new(runner: R, keypair: KeyPair) -> Self {
+        Self {
+            runner,
+            keypair,
+            rounds: vec![],
+        }
+    }
+
+    pub fn with_round(mut self, round: ReplayRound) -> Self {
+        self.add_round(round);
+        self
+    }
+
+    pub fn add_round(&mut self, round: ReplayRound) {
+        self.rounds.push(round);
+    }
+
+    /// Replays all the rounds, a result is returned for each of them.
+    pub fn run_all(&mut self) -> Vec<ReplayResult> {
+        let mut prev_data = vec![];
+        let mut results = Vec::with_capacity(self.rounds.len());
+
+        for (round, recorded) in self.rounds.iter().enumerate() {
+            let result = match replay_round(&mut self.runner, &self.keypair, recorded, prev_data) {
+                Ok(diff) if diff.is_empty() => ReplayResult::Passed { round },
+                Ok(diff) => ReplayResult::Mismatch { round, diff },
+                Err(error) => ReplayResult::Failed { round, error },
+            };
+            results.push(result);
+            prev_data = recorded.expected_data.clone();
+        }
+
+        results
+    }
+}
+
+impl ReplayResult {
+    pub fn round(&self) -> usize {
+        match self {
+            ReplayResult::Passed { round }
+            | ReplayResult::Mismatch { round, .. }
+            | ReplayResult::Failed { round, .. } => *round,
+        }
+    }
+
+    pub fn is_passed(&self) -> bool {
+        matches!(self, ReplayResult::Passed { .. })
+    }
+}
+
+impl DataDiff {
+    pub fn new(expected: &InterpreterData, actual: &InterpreterData) -> Self {
+        let mut expected_states = expected.trace.iter_expanded();
+        let mut actual_states = actual.trace.iter_expanded();
+        let mut trace = vec![];
+
+        for position in 0.. {
+            let (expected, actual) = match (expected_states.next(), actual_states.next()) {
+                (None, None) => break,
+                (expected, actual) if expected == actual => continue,
+                (expected, actual) => (expected.cloned(), actual.cloned()),
+            };
+            trace.push(TraceStateDiff {
+                position,
+                expected,
+                actual,
+            });
+        }
+
+        let last_call_request_id = (expected.last_call_request_id != actual.last_call_request_id)
+            .then_some((expected.last_call_request_id, actual.last_call_request_id));
+
+        Self {
+            trace,
+            last_call_request_id,
+            cid_info_differs: expected.cid_info != actual.cid_info,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.trace.is_empty() && self.last_call_request_id.is_none() && !self.cid_info_differs
+    }
+}
+
+fn replay_round<R: InterpreterRunner>(
+    runner: &mut R,
+    keypair: &KeyPair,
+    round: &ReplayRound,
+    prev_data: Vec<u8>,
+) -> Result<DataDiff, ReplayError> {
+    let particle = &round.particle;
+    let outcome = runner.call(
+        round.air.clone(),
+        prev_data,
+        round.data.clone(),
+        particle.init_peer_id.to_string(),
+        particle.timestamp,
+        particle.ttl,
+        particle.current_peer_id.to_string(),
+        round.call_results.clone(),
+        keypair,
+        particle.particle_id.to_string(),
+    )?;
+
+    let expected = decode_data(&round.expected_data).map_err(ReplayError::InvalidExpectedData)?;
+    let actual = decode_data(&outcome.data).map_err(ReplayError::InvalidActualData)?;
+
+    Ok(DataDiff::new(&expected, &actual))
+}
+
+fn decode_data(data: &[u8]) -> Result<InterpreterData, DataDeserializationError> {
+    let envelope = InterpreterDataEnvelope::try_from_slice(data)?;
+    InterpreterData::try_from_slice(&envelope.inner_data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AVMDataStore;
+    use crate::AVMError;
+    use crate::AVMStats;
+    use crate::AsyncAVM;
+    use crate::CancellationToken;
+    use crate::DrainStats;
+    use crate::AVM;
+    use crate::WARMUP_AIR;
+
+    use avm_data_store::AnomalyData;
+    use avm_data_store::DataStore;
+
+    use std::collections::HashMap;
+    use std::convert::Infallible;
+    use std::time::Duration;
+    use std::time::SystemTime;
+
+    #[derive(Default)]
+    struct MemoryDataStore {
+        data: HashMap<String, (Vec<u8>, SystemTime)>,
+        /// Cancelled on every data read, e.g. to cancel a call while it reads data.
+        cancel_on_read: Option<CancellationToken>,
+    }
+
+    impl DataStore for MemoryDataStore {
+        type Error = Infallible;
+
+        fn initialize(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn store_data(
+            &mut self,
+            data: &[u8],
+            particle_id: &str,
+            _current_peer_id: &str,
+        ) -> Result<(), Self::Error> {
+            self.data
+                .insert(particle_id.to_owned(), (data.to_vec(), SystemTime::now()));
+            Ok(())
+        }
+
+        fn read_data(
+            &mut self,
+            particle_id: &str,
+            _current_peer_id: &str,
+        ) -> Result<Vec<u8>, Self::Error> {
+            if let Some(cancel_token) = &self.cancel_on_read {
+                cancel_token.cancel();
+            }
+
+            Ok(self
+                .data
+                .get(particle_id)
+                .map(|(data, _)| data.clone())
+                .unwrap_or_default())
+        }
+
+        fn cleanup_data(
+            &mut self,
+            particle_id: &str,
+            _current_peer_id: &str,
+        ) -> Result<(), Self::Error> {
+            self.data.remove(particle_id);
+            Ok(())
+        }
+
+        fn list_particles(
+            &mut self,
+            _current_peer_id: &str,
+        ) -> Result<Vec<(String, SystemTime)>, Self::Error> {
+            Ok(self
+                .data
+                .iter()
+                .map(|(particle_id, (_, modified))| (particle_id.clone(), *modified))
+                .collect())
+        }
+
+        fn detect_anomaly(&self, _: Duration, _: usize, _: &RawAVMOutcome) -> bool {
+            false
+        }
+
+        fn collect_anomaly_data(
+            &mut self,
+            _particle_id: &str,
+            _current_peer_id: &str,
+            _anomaly_data: AnomalyData<'_>,
+        ) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    fn outcome(data: &[u8]) -> RawAVMOutcome {
+        RawAVMOutcome {
+            ret_code: 0,
+            error_message: String::new(),
+            data: data.to_vec(),
+            call_requests: <_>::default(),
+            next_peer_pks: vec![],
+            soft_limits_triggering: <_>::default(),
+            fuel_consumed: 0,
+            instruction_counts: <_>::default(),
+            flamegraph_svg: String::new(),
+            error_context: None,
+            air_fingerprint: None,
+        }
+    }
+
+    fn mock_avm(runner: &MockAVMRunner) -> AVM<Infallible, MockAVMRunner> {
+        let data_store: AVMDataStore<Infallible> = Box::<MemoryDataStore>::default();
+        AVM::with_runner(runner.clone(), data_store).unwrap()
+    }
+
+    fn particle_parameters() -> ParticleParameters<'static> {
+        ParticleParameters::new(
+            "init_peer_id".into(),
+            "particle_id".into(),
+            0,
+            0,
+            "current_peer_id".into(),
+        )
+    }
+
+    fn call(avm: &mut AVM<Infallible, MockAVMRunner>, air: &str) {
+        let keypair = KeyPair::generate_ed25519();
+        avm.call(air, vec![], particle_parameters(), <_>::default(), &keypair)
+            .unwrap();
+    }
+
+    #[test]
+    fn registered_outcome_is_returned_and_persisted() {
+        let runner = MockAVMRunner::new().with_response("(null)", <_>::default(), outcome(b"data"));
+        let mut avm = mock_avm(&runner);
+
+        call(&mut avm, "(null)");
+
+        runner.assert_all_calls_made();
+        assert_eq!(avm.stats().data_bytes_written, 4);
+    }
+
+    #[test]
+    fn dry_run_does_not_persist_data() {
+        let runner = MockAVMRunner::new().with_response("(null)", <_>::default(), outcome(b"data"));
+        let mut avm = mock_avm(&runner);
+
+        let keypair = KeyPair::generate_ed25519();
+        let outcome = avm
+            .call_dry_run(
+                "(null)",
+                vec![],
+                particle_parameters(),
+                <_>::default(),
+                &keypair,
+            )
+            .unwrap();
+
+        assert_eq!(outcome.data, b"data");
+        assert_eq!(avm.stats().data_bytes_written, 0);
+    }
+
+    #[test]
+    fn cancelled_call_is_not_executed() {
+        let runner = MockAVMRunner::new();
+        let mut avm = mock_avm(&runner);
+        let cancel_token = CancellationToken::new();
+        cancel_token.cancel();
+
+        let keypair = KeyPair::generate_ed25519();
+        let result = avm.call_with_cancellation(
+            "(null)",
+            vec![],
+            particle_parameters(),
+            <_>::default(),
+            &keypair,
+            Some(cancel_token),
+        );
+
+        assert!(matches!(result, Err(AVMError::Cancelled)));
+        assert_eq!(avm.stats().data_bytes_written, 0);
+    }
+
+    #[test]
+    fn call_cancelled_while_reading_data_is_not_executed() {
+        let runner = MockAVMRunner::new().with_response("(null)", <_>::default(), outcome(b"data"));
+        let cancel_token = CancellationToken::new();
+        let data_store: AVMDataStore<Infallible> = Box::new(MemoryDataStore {
+            cancel_on_read: Some(cancel_token.clone()),
+            ..<_>::default()
+        });
+        let mut avm = AVM::with_runner(runner.clone(), data_store).unwrap();
+
+        let keypair = KeyPair::generate_ed25519();
+        let result = avm.call_with_cancellation(
+            "(null)",
+            vec![],
+            particle_parameters(),
+            <_>::default(),
+            &keypair,
+            Some(cancel_token),
+        );
+
+        assert!(matches!(result, Err(AVMError::Cancelled)));
+        assert_eq!(avm.stats().data_bytes_written, 0);
+    }
+
+    #[test]
+    fn not_cancelled_call_persists_data() {
+        let runner = MockAVMRunner::new().with_response("(null)", <_>::default(), outcome(b"data"));
+        let mut avm = mock_avm(&runner);
+
+        let keypair = KeyPair::generate_ed25519();
+        let outcome = avm
+            .call_with_cancellation(
+                "(null)",
+                vec![],
+                particle_parameters(),
+                <_>::default(),
+                &keypair,
+                Some(CancellationToken::new()),
+            )
+            .unwrap();
+
+        assert_eq!(outcome.data, b"data");
+        assert_eq!(avm.stats().data_bytes_written, 4);
+    }
+
+    #[test]
+    fn call_with_timeout_persists_data_in_time() {
+        let runner = MockAVMRunner::new().with_response("(null)", <_>::default(), outcome(b"data"));
+        let mut avm = mock_avm(&runner);
+
+        let keypair = KeyPair::generate_ed25519();
+        let outcome = avm
+            .call_with_timeout(
+                "(null)",
+                vec![],
+                particle_parameters(),
+                <_>::default(),
+                &keypair,
+                Duration::from_secs(10),
+            )
+            .unwrap();
+
+        assert_eq!(outcome.data, b"data");
+        assert_eq!(avm.stats().data_bytes_written, 4);
+    }
+
+    #[test]
+    fn call_with_timeout_reports_exhausted_fuel() {
+        let exhausted = RawAVMOutcome {
+            ret_code: 1,
+            fuel_consumed: u64::MAX,
+            ..outcome(b"data")
+        };
+        let runner = MockAVMRunner::new().with_response("(null)", <_>::default(), exhausted);
+        let mut avm = mock_avm(&runner);
+
+        let keypair = KeyPair::generate_ed25519();
+        let result = avm.call_with_timeout(
+            "(null)",
+            vec![],
+            particle_parameters(),
+            <_>::default(),
+            &keypair,
+            Duration::from_secs(10),
+        );
+
+        assert!(matches!(
+            result,
+            Err(AVMError::TimedOut {
+                fuel_consumed: u64::MAX,
+                ..
+            })
+        ));
+        assert_eq!(avm.stats().data_bytes_written, 0);
+        assert_eq!(avm.stats().calls_failed, 1);
+    }
+
+    #[test]
+    fn stats_count_successful_calls() {
+        let runner = MockAVMRunner::new()
+            .with_response(
+                "(null)",
+                <_>::default(),
+                RawAVMOutcome {
+                    fuel_consumed: 10,
+                    ..outcome(b"data")
+                },
+            )
+            .with_response(
+                "(null)",
+                <_>::default(),
+                RawAVMOutcome {
+                    fuel_consumed: 5,
+                    ..outcome(b"longer data")
+                },
+            );
+        let mut avm = mock_avm(&runner);
+        let keypair = KeyPair::generate_ed25519();
+
+        let calls_start_time = std::time::Instant::now();
+        for _ in 0..2 {
+            avm.call(
+                "(null)",
+                b"abc".to_vec(),
+                particle_parameters(),
+                <_>::default(),
+                &keypair,
+            )
+            .unwrap();
+        }
+        let calls_time = calls_start_time.elapsed();
+
+        let stats = avm.stats();
+        assert_eq!(stats.calls_total, 2);
+        assert_eq!(stats.calls_failed, 0);
+        assert_eq!(stats.fuel_consumed_total, 15);
+        // the second call reads data persisted by the first one
+        assert_eq!(stats.data_bytes_read, 3 + (4 + 3));
+        assert_eq!(stats.data_bytes_written, 4 + 11);
+        assert!(u128::from(stats.avg_execution_ns) <= calls_time.as_nanos() / 2);
+    }
+
+    #[test]
+    fn stats_count_failed_calls() {
+        let failed = RawAVMOutcome {
+            ret_code: 1,
+            error_message: "failed".to_string(),
+            fuel_consumed: 7,
+            ..outcome(b"data")
+        };
+        let runner = MockAVMRunner::new()
+            .with_response("(null)", <_>::default(), outcome(b""))
+            .with_response("(fail)", <_>::default(), failed);
+        let mut avm = mock_avm(&runner);
+        let keypair = KeyPair::generate_ed25519();
+
+        call(&mut avm, "(null)");
+        let result = avm.call(
+            "(fail)",
+            vec![],
+            particle_parameters(),
+            <_>::default(),
+            &keypair,
+        );
+        let cancel_token = CancellationToken::new();
+        cancel_token.cancel();
+        let cancelled = avm.call_with_cancellation(
+            "(null)",
+            vec![],
+            particle_parameters(),
+            <_>::default(),
+            &keypair,
+            Some(cancel_token),
+        );
+
+        assert!(matches!(result, Err(AVMError::InterpreterFailed(_))));
+        assert!(matches!(cancelled, Err(AVMError::Cancelled)));
+        let stats = avm.stats();
+        assert_eq!(stats.calls_total, 3);
+        assert_eq!(stats.calls_failed, 2);
+        // a cancelled call isn't executed, so it doesn't add to execution stats
+        assert_eq!(stats.fuel_consumed_total, 7);
+        // data of a failed execution is still persisted
+        assert_eq!(stats.data_bytes_written, 4);
+    }
+
+    #[test]
+    fn reset_stats_zeroes_counters_of_all_handles() {
+        let runner = MockAVMRunner::new()
+            .with_response(
+                "(null)",
+                <_>::default(),
+                RawAVMOutcome {
+                    fuel_consumed: 10,
+                    ..outcome(b"data")
+                },
+            )
+            .with_response("(null)", <_>::default(), outcome(b"data"));
+        let mut avm = mock_avm(&runner);
+        let stats_handle = avm.stats_handle();
+
+        call(&mut avm, "(null)");
+        assert_eq!(stats_handle.stats(), avm.stats());
+        assert_eq!(stats_handle.stats().calls_total, 1);
+
+        avm.reset_stats();
+        assert_eq!(stats_handle.stats(), AVMStats::default());
+
+        call(&mut avm, "(null)");
+        let stats = stats_handle.stats();
+        assert_eq!(stats.calls_total, 1);
+        assert_eq!(stats.fuel_consumed_total, 0);
+        assert_eq!(stats.data_bytes_read, 4);
+    }
+
+    #[test]
+    fn memory_stats_are_reported_after_each_call() {
+        use avm_interface::WASM_PAGE_SIZE;
+
+        let memory_growth = 2 * WASM_PAGE_SIZE;
+        let runner = MockAVMRunner::new()
+            .with_response("(null)", <_>::default(), outcome(b"data"))
+            .with_response("(null)", <_>::default(), outcome(b"data"))
+            .with_memory_growth(memory_growth);
+        let mut avm = mock_avm(&runner);
+        let keypair = KeyPair::generate_ed25519();
+
+        let first = avm
+            .call(
+                "(null)",
+                vec![],
+                particle_parameters(),
+                <_>::default(),
+                &keypair,
+            )
+            .unwrap();
+        let second = avm
+            .call(
+                "(null)",
+                vec![],
+                particle_parameters(),
+                <_>::default(),
+                &keypair,
+            )
+            .unwrap();
+
+        assert_eq!(first.memory_delta, memory_growth as usize);
+        assert_eq!(
+            first.memory_stats,
+            MemoryStats::new(memory_growth, memory_growth)
+        );
+        assert_eq!(first.memory_stats.pages_used, 2);
+
+        assert_eq!(second.memory_delta, memory_growth as usize);
+        assert_eq!(second.memory_stats.bytes_allocated, 2 * memory_growth);
+        assert_eq!(second.memory_stats.pages_used, 4);
+        assert!(second.memory_stats.peak_bytes > first.memory_stats.peak_bytes);
+        assert_eq!(avm.memory_stats().memory_size, 2 * memory_growth as usize);
+    }
+
+    fn block_on<T>(future: impl std::future::Future<Output = T>) -> T {
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[test]
+    fn async_call_returns_outcome_and_counts_stats() {
+        let runner = MockAVMRunner::new().with_response("(null)", <_>::default(), outcome(b"data"));
+        let mut avm = AsyncAVM::from_avm(mock_avm(&runner));
+        let keypair = KeyPair::generate_ed25519();
+
+        let outcome = block_on(avm.call(
+            "(null)",
+            vec![],
+            particle_parameters(),
+            <_>::default(),
+            &keypair,
+        ))
+        .unwrap();
+
+        runner.assert_all_calls_made();
+        assert_eq!(outcome.data, b"data");
+        let stats = avm.stats();
+        assert_eq!(stats.calls_total, 1);
+        assert_eq!(stats.calls_failed, 0);
+        assert_eq!(stats.data_bytes_written, 4);
+    }
+
+    #[test]
+    fn async_call_reports_errors() {
+        let failed = RawAVMOutcome {
+            ret_code: 1,
+            error_message: "failed".to_string(),
+            ..outcome(b"data")
+        };
+        let runner = MockAVMRunner::new().with_response("(fail)", <_>::default(), failed);
+        let mut avm = AsyncAVM::from_avm(mock_avm(&runner));
+        let keypair = KeyPair::generate_ed25519();
+        let cancel_token = CancellationToken::new();
+        cancel_token.cancel();
+
+        let result = block_on(avm.call(
+            "(fail)",
+            vec![],
+            particle_parameters(),
+            <_>::default(),
+            &keypair,
+        ));
+        let cancelled = block_on(avm.call_with_cancellation(
+            "(null)",
+            vec![],
+            particle_parameters(),
+            <_>::default(),
+            &keypair,
+            Some(cancel_token),
+        ));
+
+        assert!(matches!(result, Err(AVMError::InterpreterFailed(_))));
+        assert!(matches!(cancelled, Err(AVMError::Cancelled)));
+        assert_eq!(avm.stats().calls_failed, 2);
+    }
+
+    #[test]
+    fn async_call_reads_data_persisted_by_previous_call() {
+        let runner = MockAVMRunner::new()
+            .with_response("(null)", <_>::default(), outcome(b"data"))
+            .with_response("(null)", <_>::default(), outcome(b"new data"))
+            .with_response("(null)", <_>::default(), outcome(b""));
+        let mut avm = AsyncAVM::from_avm(mock_avm(&runner));
+        let keypair = KeyPair::generate_ed25519();
+
+        block_on(async {
+            avm.call(
+                "(null)",
+                vec![],
+                particle_parameters(),
+                <_>::default(),
+                &keypair,
+            )
+            .await
+            .unwrap();
+            avm.call(
+                "(null)",
+                b"abc".to_vec(),
+                particle_parameters(),
+                <_>::default(),
+                &keypair,
+            )
+            .await
+            .unwrap();
+        });
+        let stats_before_cleanup = avm.stats();
+        block_on(async {
+            avm.cleanup_data("particle_id", "current_peer_id")
+                .await
+                .unwrap();
+            avm.call_dry_run(
+                "(null)",
+                vec![],
+                particle_parameters(),
+                <_>::default(),
+                &keypair,
+            )
+            .await
+            .unwrap();
+        });
+
+        // the second call reads data persisted by the first one along with the current data
+        assert_eq!(stats_before_cleanup.data_bytes_read, 4 + 3);
+        assert_eq!(stats_before_cleanup.data_bytes_written, 4 + 8);
+        // cleaned up data isn't read any more
+        assert_eq!(avm.stats().data_bytes_read, 4 + 3);
+    }
+
+    #[test]
+    fn unsampled_particle_persists_summary() {
+        let data = InterpreterDataEnvelope::from_execution_result(
+            vec![ExecutedState::par(0, 0); 100].into(),
+            <_>::default(),
+            <_>::default(),
+            1.into(),
+            semver::Version::new(1, 0, 0),
+        )
+        .serialize()
+        .unwrap();
+        let runner = MockAVMRunner::new().with_response("(null)", <_>::default(), outcome(&data));
+        let mut avm = mock_avm(&runner);
+        avm.set_trace_sampling(crate::TraceSamplingPolicy::Never);
+
+        call(&mut avm, "(null)");
+
+        let data_bytes_written = avm.stats().data_bytes_written;
+        assert!(0 < data_bytes_written && data_bytes_written < data.len() as u64);
+    }
+
+    #[test]
+    fn data_store_factory_is_called_on_each_call() {
+        use std::sync::Arc;
+        use std::sync::Mutex;
+
+        let runner = MockAVMRunner::new()
+            .with_response("(null)", <_>::default(), outcome(b"data"))
+            .with_response("(null)", <_>::default(), outcome(b"data"));
+        let mut avm = mock_avm(&runner);
+        let particle_ids = Arc::new(Mutex::new(vec![]));
+        avm.set_data_store_factory({
+            let particle_ids = particle_ids.clone();
+            move |particle_id| {
+                particle_ids.lock().unwrap().push(particle_id.to_owned());
+                Box::<MemoryDataStore>::default()
+            }
+        });
+
+        call(&mut avm, "(null)");
+        call(&mut avm, "(null)");
+
+        runner.assert_all_calls_made();
+        assert_eq!(
+            *particle_ids.lock().unwrap(),
+            ["particle_id", "particle_id"]
+        );
+    }
+
+    #[test]
+    fn drain_expired_particles_cleans_up_only_old_data() {
+        let data_store = MemoryDataStore {
+            data: HashMap::from([
+                (
+                    "old".to_owned(),
+                    (b"old data".to_vec(), SystemTime::UNIX_EPOCH),
+                ),
+                ("fresh".to_owned(), (b"data".to_vec(), SystemTime::now())),
+            ]),
+            ..<_>::default()
+        };
+        let mut avm = AVM::with_runner(MockAVMRunner::new(), Box::new(data_store)).unwrap();
+        let max_age = Duration::from_secs(3600);
+
+        let stats = avm
+            .drain_expired_particles(max_age, "current_peer_id")
+            .unwrap();
+        assert_eq!(stats.particle_ids, ["old"]);
+        assert_eq!(stats.count(), 1);
+        assert_eq!(stats.bytes_freed, 8);
+
+        let stats = avm
+            .drain_expired_particles(max_age, "current_peer_id")
+            .unwrap();
+        assert_eq!(stats, DrainStats::default());
+    }
+
+    #[test]
+    fn call_multi_returns_results_in_order() {
+        use crate::MultiCallRequest;
+
+        let runner = MockAVMRunner::new()
+            .with_response("(first)", <_>::default(), outcome(b"first"))
+            .with_response("(second)", <_>::default(), outcome(b"second"));
+        let mut avm = mock_avm(&runner);
+        let request = |air: &str, particle_id: &str| MultiCallRequest {
+            air: air.to_owned(),
+            data: vec![],
+            particle_parameters: ParticleParameters {
+                particle_id: particle_id.to_owned().into(),
+                ..particle_parameters()
+            },
+            call_results: <_>::default(),
+        };
+
+        let keypair = KeyPair::generate_ed25519();
+        let results = avm.call_multi(
+            vec![
+                request("(first)", "first_particle"),
+                request("(first)", "first_particle"),
+                request("(second)", "second_particle"),
+            ],
+            &keypair,
+        );
+
+        runner.assert_all_calls_made();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().data, b"first");
+        assert!(matches!(
+            &results[1],
+            Err(AVMError::DuplicateParticle { particle_id }) if particle_id == "first_particle"
+        ));
+        assert_eq!(results[2].as_ref().unwrap().data, b"second");
+        assert_eq!(avm.stats().calls_total, 3);
+        assert_eq!(avm.stats().calls_failed, 1);
+        assert_eq!(avm.stats().data_bytes_written, 11);
+    }
+
+    #[test]
+    fn call_multi_executes_requests_after_trap_in_new_batch() {
+        use crate::MultiCallRequest;
+        use avm_interface::WASM_PAGE_SIZE;
+
+        let runner = MockAVMRunner::new()
+            .with_response("(first)", <_>::default(), outcome(b"first"))
+            .with_response("(second)", <_>::default(), outcome(b"second"))
+            .with_trap("(trap)")
+            .with_memory_growth(WASM_PAGE_SIZE);
+        let mut avm = mock_avm(&runner);
+        let request = |air: &str, particle_id: &str| MultiCallRequest {
+            air: air.to_owned(),
+            data: vec![],
+            particle_parameters: ParticleParameters {
+                particle_id: particle_id.to_owned().into(),
+                ..particle_parameters()
+            },
+            call_results: <_>::default(),
+        };
+
+        // the first request would panic if it was executed again
+        let keypair = KeyPair::generate_ed25519();
+        let results = avm.call_multi(
+            vec![
+                request("(first)", "first_particle"),
+                request("(trap)", "trapped_particle"),
+                request("(second)", "second_particle"),
+            ],
+            &keypair,
+        );
+
+        runner.assert_all_calls_made();
+        assert_eq!(results.len(), 3);
+        let first = results[0].as_ref().unwrap();
+        assert_eq!(first.data, b"first");
+        assert_eq!(first.memory_delta, WASM_PAGE_SIZE as usize);
+        assert!(matches!(
+            &results[1],
+            Err(AVMError::RunnerError(RunnerError::Aux(_)))
+        ));
+        let second = results[2].as_ref().unwrap();
+        assert_eq!(second.data, b"second");
+        assert_eq!(second.memory_delta, WASM_PAGE_SIZE as usize);
+        assert_eq!(avm.stats().calls_total, 3);
+        assert_eq!(avm.stats().calls_failed, 1);
+    }
+
+    #[test]
+    fn warmup_executes_script_without_touching_data() {
+        let runner =
+            MockAVMRunner::new().with_response(WARMUP_AIR, <_>::default(), outcome(b"data"));
+        let mut avm = mock_avm(&runner);
+
+        avm.warmup().unwrap();
+
+        runner.assert_all_calls_made();
+        assert_eq!(avm.stats().calls_total, 0);
+        assert_eq!(avm.stats().data_bytes_written, 0);
+    }
+
+    #[test]
+    fn warmup_reports_failed_script() {
+        let failed = RawAVMOutcome {
+            ret_code: 1,
+            ..outcome(b"")
+        };
+        let runner = MockAVMRunner::new().with_response(WARMUP_AIR, <_>::default(), failed);
+        let mut avm = mock_avm(&runner);
+
+        assert!(matches!(avm.warmup(), Err(AVMError::InterpreterFailed(_))));
+    }
+
+    #[test]
+    #[should_panic(expected = "unexpected call")]
+    fn unregistered_call_panics() {
+        let runner = MockAVMRunner::new();
+        let mut avm = mock_avm(&runner);
+
+        call(&mut avm, "(null)");
+    }
+
+    #[test]
+    #[should_panic(expected = "expected calls weren't made")]
+    fn missing_call_is_reported() {
+        let runner = MockAVMRunner::new().with_response("(null)", <_>::default(), outcome(b""));
+        let _avm = mock_avm(&runner);
+
+        runner.assert_all_calls_made();
+    }
+
+    fn envelope(trace: Vec<ExecutedState>) -> Vec<u8> {
+        InterpreterDataEnvelope::from_execution_result(
+            trace.into(),
+            <_>::default(),
+            <_>::default(),
+            1.into(),
+            semver::Version::new(1, 0, 0),
+        )
+        .serialize()
+        .unwrap()
+    }
+
+    fn replay_round(air: &str, expected_data: Vec<u8>) -> ReplayRound {
+        ReplayRound {
+            air: air.to_owned(),
+            data: vec![],
+            call_results: <_>::default(),
+            particle: particle_parameters(),
+            expected_data,
+        }
+    }
+
+    #[test]
+    fn replay_reports_result_per_round() {
+        let first = envelope(vec![ExecutedState::par(1, 0)]);
+        let second = envelope(vec![ExecutedState::par(1, 0), ExecutedState::par(0, 0)]);
+        let diverged = envelope(vec![ExecutedState::par(1, 0), ExecutedState::par(0, 1)]);
+        let runner = MockAVMRunner::new()
+            .with_response("(first)", <_>::default(), outcome(&first))
+            .with_response("(second)", <_>::default(), outcome(&diverged))
+            .with_response("(third)", <_>::default(), outcome(&second));
+
+        let mut harness = ReplayHarness::new(runner.clone(), KeyPair::generate_ed25519())
+            .with_round(replay_round("(first)", first))
+            .with_round(replay_round("(second)", second.clone()))
+            .with_round(replay_round("(third)", second));
+        let results = harness.run_all();
+
+        runner.assert_all_calls_made();
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_passed());
+        match &results[1] {
+            ReplayResult::Mismatch { round: 1, diff } => {
+                assert_eq!(
+                    diff.trace,
+                    vec![TraceStateDiff {
+                        position: 1,
+                        expected: Some(ExecutedState::par(0, 0)),
+                        actual: Some(ExecutedState::par(0, 1)),
+                    }]
+                );
+                assert!(diff.last_call_request_id.is_none());
+                assert!(!diff.cid_info_differs);
+            }
+            result => panic!("unexpected result {result:?}"),
+        }
+        assert!(results[2].is_passed());
+    }
+
+    #[test]
+    fn replay_reports_invalid_recorded_data() {
+        let data = envelope(vec![]);
+        let runner = MockAVMRunner::new().with_response("(null)", <_>::default(), outcome(&data));
+
+        let mut harness = ReplayHarness::new(runner, KeyPair::generate_ed25519())
+            .with_round(replay_round("(null)", b"garbage".to_vec()));
+        let results = harness.run_all();
+
+        assert!(matches!(
+            results[..],
+            [ReplayResult::Failed {
+                round: 0,
+                error: ReplayError::InvalidExpectedData(_)
+            }]
+        ));
+    }
+}
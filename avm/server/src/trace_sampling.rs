@@ -0,0 +1,121 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air_interpreter_data::InterpreterData;
+use air_interpreter_data::InterpreterDataEnvelope;
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+/// Decides for which particles `AVM` persists the full data, the data of other particles
+/// is replaced with a summary keeping only versions and the last call request id.
+///
+/// The summary is only persisted, the outcome returned to a host and sent to other peers
+/// always contains the full data. A particle that comes back to the peer is merged with
+/// an empty previous trace then, so only local debugging and replay lose the trace.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum TraceSamplingPolicy {
+    /// The full data of every particle is persisted.
+    #[default]
+    Always,
+
+    /// Only summaries are persisted.
+    Never,
+
+    /// The full data is persisted for the given fraction of particles, from 0.0 to 1.0.
+    /// The decision depends only on a particle id, so all calls of a particle are sampled alike.
+    RateSample(f64),
+}
+
+impl TraceSamplingPolicy {
+    pub fn is_sampled(&self, particle_id: &str) -> bool {
+        match *self {
+            Self::Always => true,
+            Self::Never => false,
+            Self::RateSample(rate) => {
+                // DefaultHasher::new uses fixed keys, so the hash is stable between calls
+                let mut hasher = DefaultHasher::new();
+                particle_id.hash(&mut hasher);
+                (hasher.finish() as f64 / u64::MAX as f64) < rate
+            }
+        }
+    }
+}
+
+/// Returns a serialized envelope with the versions and the last call request id of data,
+/// or `None` if data can't be deserialized, so the full data should be persisted.
+pub(crate) fn summarize_data(data: &[u8]) -> Option<Vec<u8>> {
+    let envelope = InterpreterDataEnvelope::try_from_slice(data).ok()?;
+    let inner_data = InterpreterData::try_from_slice(&envelope.inner_data).ok()?;
+
+    let summary = InterpreterDataEnvelope::from_execution_result(
+        <_>::default(),
+        <_>::default(),
+        <_>::default(),
+        inner_data.last_call_request_id,
+        envelope.versions.interpreter_version,
+    );
+    summary.serialize().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rate_sample_bounds() {
+        let particle_ids = (0..100)
+            .map(|id| format!("particle_{id}"))
+            .collect::<Vec<_>>();
+
+        assert!(particle_ids
+            .iter()
+            .all(|id| TraceSamplingPolicy::RateSample(1.0).is_sampled(id)));
+        assert!(particle_ids
+            .iter()
+            .all(|id| !TraceSamplingPolicy::RateSample(0.0).is_sampled(id)));
+
+        let sampled = particle_ids
+            .iter()
+            .filter(|id| TraceSamplingPolicy::RateSample(0.5).is_sampled(id))
+            .count();
+        assert!((20..80).contains(&sampled), "{sampled} particles sampled");
+    }
+
+    #[test]
+    fn summary_keeps_last_call_request_id() {
+        let version = semver::Version::new(1, 2, 3);
+        let data = InterpreterDataEnvelope::from_execution_result(
+            vec![air_interpreter_data::ExecutedState::par(1, 0)].into(),
+            <_>::default(),
+            <_>::default(),
+            42.into(),
+            version.clone(),
+        )
+        .serialize()
+        .unwrap();
+
+        let summary = summarize_data(&data).unwrap();
+        let envelope = InterpreterDataEnvelope::try_from_slice(&summary).unwrap();
+        let inner_data = InterpreterData::try_from_slice(&envelope.inner_data).unwrap();
+
+        assert_eq!(envelope.versions.interpreter_version, version);
+        assert_eq!(inner_data.last_call_request_id, 42.into());
+        assert!(inner_data.trace.is_empty());
+        assert_eq!(summarize_data(b"not data"), None);
+    }
+}
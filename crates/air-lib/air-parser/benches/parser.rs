@@ -137,6 +137,37 @@ fn parse_deep(c: &mut Criterion) {
     }
 }
 
+fn parse_match_regex(c: &mut Criterion) {
+    let parser = Rc::new(AIRParser::new());
+    // the same script with match and match-regex shows the cost of compiling the regex
+    let source_codes = [
+        (
+            "match",
+            r#"(seq (call "peer" ("" "") [] value) (match value "peer-42" (null)))"#,
+        ),
+        (
+            "match-regex",
+            r#"(seq (call "peer" ("" "") [] value) (match-regex value "^peer-[0-9]+$" (null)))"#,
+        ),
+    ];
+
+    let mut group = c.benchmark_group("parse match");
+    for (name, code) in source_codes {
+        group.bench_function(name, |b| {
+            let parser = parser.clone();
+            b.iter(move || {
+                let mut validator = VariableValidator::new();
+                let lexer = AIRLexer::new(code);
+
+                parser
+                    .clone()
+                    .parse("", &mut Vec::new(), &mut validator, lexer)
+                    .expect("success")
+            });
+        });
+    }
+}
+
 fn parse_dashboard_script(c: &mut Criterion) {
     let parser = Rc::new(AIRParser::new());
     const DASHBOARD_SCRIPT: &str =
@@ -166,5 +197,6 @@ criterion_group!(
     parse_to_fail,
     parse_dashboard_script,
     parse_deep,
+    parse_match_regex,
 );
 criterion_main!(parser);
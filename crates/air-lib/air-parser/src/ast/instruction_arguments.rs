@@ -164,3 +164,11 @@ pub enum NewArgument<'i> {
     #[serde(borrow)]
     CanonStreamMap(CanonStreamMap<'i>),
 }
+
+/// Regular expression of a match-regex or mismatch-regex instruction,
+/// it's compiled once at parse time.
+#[derive(Debug, Clone)]
+pub struct RegexPattern<'i> {
+    source: &'i str,
+    regex: regex::Regex,
+}
@@ -17,6 +17,7 @@
 use super::ApResult;
 use super::CallOutputValue;
 use super::NewArgument;
+use super::RegexPattern;
 use super::Scalar;
 use super::Stream;
 use crate::parser::lexer::AirPos;
@@ -59,3 +60,18 @@ impl<'i> CallOutputValue<'i> {
         Self::Stream(Stream { name, position })
     }
 }
+
+impl<'i> RegexPattern<'i> {
+    pub fn new(source: &'i str) -> Result<Self, regex::Error> {
+        let regex = regex::Regex::new(source)?;
+        Ok(Self { source, regex })
+    }
+
+    pub fn source(&self) -> &'i str {
+        self.source
+    }
+
+    pub fn is_match(&self, haystack: &str) -> bool {
+        self.regex.is_match(haystack)
+    }
+}
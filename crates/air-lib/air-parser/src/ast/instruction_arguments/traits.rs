@@ -219,3 +219,22 @@ fn display_error(f: &mut fmt::Formatter, error: &InstructionErrorAST) -> fmt::Re
         None => write!(f, "{ERROR}"),
     }
 }
+
+impl fmt::Display for RegexPattern<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, r#""{}""#, self.source)
+    }
+}
+
+// patterns are compared by their sources, since compiled regexes aren't comparable
+impl PartialEq for RegexPattern<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.source == other.source
+    }
+}
+
+impl Serialize for RegexPattern<'_> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.source)
+    }
+}
@@ -14,8 +14,13 @@
  * limitations under the License.
  */
 
+mod dataflow;
 mod impls;
 mod traits;
+mod visitor;
+
+pub use dataflow::VariableName;
+pub use visitor::Visitor;
 
 use super::*;
 
@@ -33,11 +38,14 @@ pub enum Instruction<'i> {
     Canon(Box<Canon<'i>>),
     CanonMap(Box<CanonMap<'i>>),
     CanonStreamMapScalar(Box<CanonStreamMapScalar<'i>>),
+    CanonSorted(Box<CanonSorted<'i>>),
     Seq(Box<Seq<'i>>),
     Par(Box<Par<'i>>),
     Xor(Box<Xor<'i>>),
     Match(Box<Match<'i>>),
     MisMatch(Box<MisMatch<'i>>),
+    MatchRegex(Box<MatchRegex<'i>>),
+    MisMatchRegex(Box<MisMatchRegex<'i>>),
     Fail(Box<Fail<'i>>),
     FoldScalar(Box<FoldScalar<'i>>),
     FoldStream(Box<FoldStream<'i>>),
@@ -96,6 +104,14 @@ pub struct CanonStreamMapScalar<'i> {
     pub scalar: Scalar<'i>,
 }
 
+/// (canon-sorted peer_id $stream #canon_stream)
+#[derive(Serialize, Debug, PartialEq, Eq)]
+pub struct CanonSorted<'i> {
+    pub peer_id: ResolvableToPeerIdVariable<'i>,
+    pub stream: Stream<'i>,
+    pub canon_stream: CanonStream<'i>,
+}
+
 /// (seq instruction instruction)
 #[derive(Serialize, Debug, PartialEq)]
 pub struct Seq<'i>(pub Instruction<'i>, pub Instruction<'i>);
@@ -124,6 +140,22 @@ pub struct MisMatch<'i> {
     pub instruction: Instruction<'i>,
 }
 
+/// (match-regex value "pattern" instruction)
+#[derive(Serialize, Debug, PartialEq)]
+pub struct MatchRegex<'i> {
+    pub value: ImmutableValue<'i>,
+    pub pattern: RegexPattern<'i>,
+    pub instruction: Instruction<'i>,
+}
+
+/// (mismatch-regex value "pattern" instruction)
+#[derive(Serialize, Debug, PartialEq)]
+pub struct MisMatchRegex<'i> {
+    pub value: ImmutableValue<'i>,
+    pub pattern: RegexPattern<'i>,
+    pub instruction: Instruction<'i>,
+}
+
 /// (fail 1337 "error message")
 /// (fail %last_error%)
 /// (fail value)
@@ -0,0 +1,245 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+use crate::lint::reads;
+use crate::parser::lexer::AirPos;
+
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+
+/// Name of a variable as written in a script, including its sigil, so that
+/// `stream`, `$stream` and `#stream` are different variables.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct VariableName(Arc<str>);
+
+impl VariableName {
+    pub fn new(name: impl Into<Arc<str>>) -> Self {
+        Self(name.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for VariableName {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+impl fmt::Display for VariableName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::borrow::Borrow<str> for VariableName {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Instruction<'_> {
+    /// Returns variables written anywhere in this AST: outputs of `call` and `canon`,
+    /// destinations of `ap`, fold iterators and variables restricted by `new`.
+    /// Branches of `seq`, `par` and `xor` contribute regardless of which of them is executed.
+    pub fn variables_defined(&self) -> HashSet<VariableName> {
+        let mut collector = DefinitionCollector::default();
+        self.walk(&mut collector);
+        collector.variables
+    }
+
+    /// Returns variables read anywhere in this AST, including ones defined inside it,
+    /// e.g. a fold iterator read by the fold body.
+    pub fn variables_used(&self) -> HashSet<VariableName> {
+        let mut collector = UseCollector::default();
+        self.walk(&mut collector);
+        collector.variables
+    }
+}
+
+#[derive(Default)]
+struct DefinitionCollector {
+    variables: HashSet<VariableName>,
+}
+
+impl DefinitionCollector {
+    fn define(&mut self, name: &str) {
+        self.variables.insert(name.into());
+    }
+}
+
+impl<'i> Visitor<'i> for DefinitionCollector {
+    fn visit_call(&mut self, call: &Call<'i>) {
+        match &call.output {
+            CallOutputValue::Scalar(scalar) => self.define(scalar.name),
+            CallOutputValue::Stream(stream) => self.define(stream.name),
+            CallOutputValue::None => {}
+        }
+    }
+
+    fn visit_ap(&mut self, ap: &Ap<'i>) {
+        match &ap.result {
+            ApResult::Scalar(scalar) => self.define(scalar.name),
+            ApResult::Stream(stream) => self.define(stream.name),
+        }
+    }
+
+    fn visit_ap_map(&mut self, ap_map: &ApMap<'i>) {
+        self.define(ap_map.map.name);
+    }
+
+    fn visit_canon(&mut self, canon: &Canon<'i>) {
+        self.define(canon.canon_stream.name);
+    }
+
+    fn visit_canon_map(&mut self, canon_map: &CanonMap<'i>) {
+        self.define(canon_map.canon_stream_map.name);
+    }
+
+    fn visit_canon_stream_map_scalar(&mut self, canon: &CanonStreamMapScalar<'i>) {
+        self.define(canon.scalar.name);
+    }
+
+    fn visit_canon_sorted(&mut self, canon: &CanonSorted<'i>) {
+        self.define(canon.canon_stream.name);
+    }
+
+    fn visit_fold_scalar(&mut self, fold: &FoldScalar<'i>) {
+        self.define(fold.iterator.name);
+    }
+
+    fn visit_fold_stream(&mut self, fold: &FoldStream<'i>) {
+        self.define(fold.iterator.name);
+    }
+
+    fn visit_fold_stream_map(&mut self, fold: &FoldStreamMap<'i>) {
+        self.define(fold.iterator.name);
+    }
+
+    fn visit_new(&mut self, new: &New<'i>) {
+        let name = match &new.argument {
+            NewArgument::Scalar(scalar) => scalar.name,
+            NewArgument::Stream(stream) => stream.name,
+            NewArgument::StreamMap(stream_map) => stream_map.name,
+            NewArgument::CanonStream(canon) => canon.name,
+            NewArgument::CanonStreamMap(canon) => canon.name,
+        };
+        self.define(name);
+    }
+}
+
+#[derive(Default)]
+struct UseCollector {
+    variables: HashSet<VariableName>,
+}
+
+impl UseCollector {
+    fn read(&mut self, name: &str) {
+        self.variables.insert(name.into());
+    }
+
+    fn reader(&mut self) -> impl FnMut(&str, AirPos) + '_ {
+        |name, _| self.read(name)
+    }
+}
+
+impl<'i> Visitor<'i> for UseCollector {
+    fn visit_call(&mut self, call: &Call<'i>) {
+        let mut read = self.reader();
+        reads::triplet(&call.triplet, &mut read);
+        for arg in call.args.iter() {
+            reads::value(arg, &mut read);
+        }
+    }
+
+    fn visit_ap(&mut self, ap: &Ap<'i>) {
+        reads::ap_argument(&ap.argument, &mut self.reader());
+    }
+
+    fn visit_ap_map(&mut self, ap_map: &ApMap<'i>) {
+        let mut read = self.reader();
+        reads::map_key(&ap_map.key, &mut read);
+        reads::ap_argument(&ap_map.value, &mut read);
+    }
+
+    fn visit_canon(&mut self, canon: &Canon<'i>) {
+        let mut read = self.reader();
+        reads::peer_id(&canon.peer_id, &mut read);
+        read(canon.stream.name, canon.stream.position);
+    }
+
+    fn visit_canon_map(&mut self, canon_map: &CanonMap<'i>) {
+        let mut read = self.reader();
+        reads::peer_id(&canon_map.peer_id, &mut read);
+        read(canon_map.stream_map.name, canon_map.stream_map.position);
+    }
+
+    fn visit_canon_stream_map_scalar(&mut self, canon: &CanonStreamMapScalar<'i>) {
+        let mut read = self.reader();
+        reads::peer_id(&canon.peer_id, &mut read);
+        read(canon.stream_map.name, canon.stream_map.position);
+    }
+
+    fn visit_canon_sorted(&mut self, canon: &CanonSorted<'i>) {
+        let mut read = self.reader();
+        reads::peer_id(&canon.peer_id, &mut read);
+        read(canon.stream.name, canon.stream.position);
+    }
+
+    fn visit_match(&mut self, match_: &Match<'i>) {
+        let mut read = self.reader();
+        reads::value(&match_.left_value, &mut read);
+        reads::value(&match_.right_value, &mut read);
+    }
+
+    fn visit_mismatch(&mut self, mismatch: &MisMatch<'i>) {
+        let mut read = self.reader();
+        reads::value(&mismatch.left_value, &mut read);
+        reads::value(&mismatch.right_value, &mut read);
+    }
+
+    fn visit_match_regex(&mut self, match_regex: &MatchRegex<'i>) {
+        reads::value(&match_regex.value, &mut self.reader());
+    }
+
+    fn visit_mismatch_regex(&mut self, mismatch_regex: &MisMatchRegex<'i>) {
+        reads::value(&mismatch_regex.value, &mut self.reader());
+    }
+
+    fn visit_fail(&mut self, fail: &Fail<'i>) {
+        reads::fail(fail, &mut self.reader());
+    }
+
+    fn visit_fold_scalar(&mut self, fold: &FoldScalar<'i>) {
+        reads::fold_scalar_iterable(&fold.iterable, &mut self.reader());
+    }
+
+    fn visit_fold_stream(&mut self, fold: &FoldStream<'i>) {
+        self.read(fold.iterable.name);
+    }
+
+    fn visit_fold_stream_map(&mut self, fold: &FoldStreamMap<'i>) {
+        self.read(fold.iterable.name);
+    }
+
+    fn visit_next(&mut self, next: &Next<'i>) {
+        self.read(next.iterator.name);
+    }
+}
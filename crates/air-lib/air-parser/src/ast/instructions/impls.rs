@@ -56,6 +56,20 @@ impl<'i> Canon<'i> {
     }
 }
 
+impl<'i> CanonSorted<'i> {
+    pub fn new(
+        peer_id: ResolvableToPeerIdVariable<'i>,
+        stream: Stream<'i>,
+        canon_stream: CanonStream<'i>,
+    ) -> Self {
+        Self {
+            peer_id,
+            stream,
+            canon_stream,
+        }
+    }
+}
+
 impl<'i> CanonMap<'i> {
     pub fn new(
         peer_id: ResolvableToPeerIdVariable<'i>,
@@ -130,6 +144,34 @@ impl<'i> MisMatch<'i> {
     }
 }
 
+impl<'i> MatchRegex<'i> {
+    pub fn new(
+        value: ImmutableValue<'i>,
+        pattern: RegexPattern<'i>,
+        instruction: Instruction<'i>,
+    ) -> Self {
+        Self {
+            value,
+            pattern,
+            instruction,
+        }
+    }
+}
+
+impl<'i> MisMatchRegex<'i> {
+    pub fn new(
+        value: ImmutableValue<'i>,
+        pattern: RegexPattern<'i>,
+        instruction: Instruction<'i>,
+    ) -> Self {
+        Self {
+            value,
+            pattern,
+            instruction,
+        }
+    }
+}
+
 impl<'i> FoldScalar<'i> {
     pub fn new(
         iterable: FoldScalarIterable<'i>,
@@ -25,6 +25,7 @@ impl fmt::Display for Instruction<'_> {
         match self {
             Call(call) => write!(f, "{call}"),
             Canon(canon) => write!(f, "{canon}"),
+            CanonSorted(canon) => write!(f, "{canon}"),
             CanonMap(canon_map) => write!(f, "{canon_map}"),
             CanonStreamMapScalar(canon_stream_map_scalar) => write!(f, "{canon_stream_map_scalar}"),
             Ap(ap) => write!(f, "{ap}"),
@@ -34,6 +35,8 @@ impl fmt::Display for Instruction<'_> {
             Xor(xor) => write!(f, "{xor}"),
             Match(match_) => write!(f, "{match_}"),
             MisMatch(mismatch) => write!(f, "{mismatch}"),
+            MatchRegex(match_regex) => write!(f, "{match_regex}"),
+            MisMatchRegex(mismatch_regex) => write!(f, "{mismatch_regex}"),
             Fail(fail) => write!(f, "{fail}"),
             FoldScalar(fold) => write!(f, "{fold}"),
             FoldStream(fold) => write!(f, "{fold}"),
@@ -66,6 +69,16 @@ impl fmt::Display for Canon<'_> {
     }
 }
 
+impl fmt::Display for CanonSorted<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "canon-sorted {} {} {}",
+            self.peer_id, self.stream, self.canon_stream
+        )
+    }
+}
+
 impl fmt::Display for CanonMap<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -170,6 +183,18 @@ impl fmt::Display for MisMatch<'_> {
     }
 }
 
+impl fmt::Display for MatchRegex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "match-regex {} {}", self.value, self.pattern)
+    }
+}
+
+impl fmt::Display for MisMatchRegex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "mismatch-regex {} {}", self.value, self.pattern)
+    }
+}
+
 impl fmt::Display for Never {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "never")
@@ -214,7 +239,13 @@ macro_rules! no_peer_id_error_logable {
     };
 }
 
-peer_id_error_logable!(Call<'_>, Canon<'_>, CanonMap<'_>, CanonStreamMapScalar<'_>);
+peer_id_error_logable!(
+    Call<'_>,
+    Canon<'_>,
+    CanonMap<'_>,
+    CanonStreamMapScalar<'_>,
+    CanonSorted<'_>
+);
 
 no_peer_id_error_logable!(
     Ap<'_>,
@@ -228,6 +259,8 @@ no_peer_id_error_logable!(
     Xor<'_>,
     Match<'_>,
     MisMatch<'_>,
+    MatchRegex<'_>,
+    MisMatchRegex<'_>,
     Never,
     Next<'_>,
     New<'_>,
@@ -0,0 +1,192 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::*;
+
+/// Visits instructions of an AST passed to `Instruction::walk`. Each method is called
+/// for the corresponding instruction before walking its nested instructions, so a visitor
+/// only needs to override methods for instructions it's interested in.
+#[allow(unused_variables)]
+pub trait Visitor<'i> {
+    /// Called for every instruction before the method of its kind.
+    fn visit_instruction(&mut self, instruction: &Instruction<'i>) {}
+
+    fn visit_call(&mut self, call: &Call<'i>) {}
+
+    fn visit_ap(&mut self, ap: &Ap<'i>) {}
+
+    fn visit_ap_map(&mut self, ap_map: &ApMap<'i>) {}
+
+    fn visit_canon(&mut self, canon: &Canon<'i>) {}
+
+    fn visit_canon_map(&mut self, canon_map: &CanonMap<'i>) {}
+
+    fn visit_canon_stream_map_scalar(&mut self, canon: &CanonStreamMapScalar<'i>) {}
+
+    fn visit_canon_sorted(&mut self, canon: &CanonSorted<'i>) {}
+
+    fn visit_seq(&mut self, seq: &Seq<'i>) {}
+
+    fn visit_par(&mut self, par: &Par<'i>) {}
+
+    fn visit_xor(&mut self, xor: &Xor<'i>) {}
+
+    fn visit_match(&mut self, match_: &Match<'i>) {}
+
+    fn visit_mismatch(&mut self, mismatch: &MisMatch<'i>) {}
+
+    fn visit_match_regex(&mut self, match_regex: &MatchRegex<'i>) {}
+
+    fn visit_mismatch_regex(&mut self, mismatch_regex: &MisMatchRegex<'i>) {}
+
+    fn visit_fail(&mut self, fail: &Fail<'i>) {}
+
+    fn visit_fold_scalar(&mut self, fold: &FoldScalar<'i>) {}
+
+    fn visit_fold_stream(&mut self, fold: &FoldStream<'i>) {}
+
+    fn visit_fold_stream_map(&mut self, fold: &FoldStreamMap<'i>) {}
+
+    fn visit_never(&mut self, never: &Never) {}
+
+    fn visit_new(&mut self, new: &New<'i>) {}
+
+    fn visit_next(&mut self, next: &Next<'i>) {}
+
+    fn visit_null(&mut self, null: &Null) {}
+
+    fn visit_error(&mut self) {}
+}
+
+impl<'i> Instruction<'i> {
+    /// Walks this instruction and all nested ones in the pre-order, i.e. in the order they appear
+    /// in a script, calling the corresponding visitor method for each of them.
+    pub fn walk<V: Visitor<'i>>(&self, visitor: &mut V) {
+        use Instruction::*;
+
+        visitor.visit_instruction(self);
+        match self {
+            Call(call) => visitor.visit_call(call),
+            Ap(ap) => visitor.visit_ap(ap),
+            ApMap(ap_map) => visitor.visit_ap_map(ap_map),
+            Canon(canon) => visitor.visit_canon(canon),
+            CanonMap(canon_map) => visitor.visit_canon_map(canon_map),
+            CanonStreamMapScalar(canon) => visitor.visit_canon_stream_map_scalar(canon),
+            CanonSorted(canon) => visitor.visit_canon_sorted(canon),
+            Seq(seq) => {
+                visitor.visit_seq(seq);
+                seq.0.walk(visitor);
+                seq.1.walk(visitor);
+            }
+            Par(par) => {
+                visitor.visit_par(par);
+                par.0.walk(visitor);
+                par.1.walk(visitor);
+            }
+            Xor(xor) => {
+                visitor.visit_xor(xor);
+                xor.0.walk(visitor);
+                xor.1.walk(visitor);
+            }
+            Match(match_) => {
+                visitor.visit_match(match_);
+                match_.instruction.walk(visitor);
+            }
+            MisMatch(mismatch) => {
+                visitor.visit_mismatch(mismatch);
+                mismatch.instruction.walk(visitor);
+            }
+            MatchRegex(match_regex) => {
+                visitor.visit_match_regex(match_regex);
+                match_regex.instruction.walk(visitor);
+            }
+            MisMatchRegex(mismatch_regex) => {
+                visitor.visit_mismatch_regex(mismatch_regex);
+                mismatch_regex.instruction.walk(visitor);
+            }
+            Fail(fail) => visitor.visit_fail(fail),
+            FoldScalar(fold) => {
+                visitor.visit_fold_scalar(fold);
+                walk_fold_body(&fold.instruction, &fold.last_instruction, visitor);
+            }
+            FoldStream(fold) => {
+                visitor.visit_fold_stream(fold);
+                walk_fold_body(&fold.instruction, &fold.last_instruction, visitor);
+            }
+            FoldStreamMap(fold) => {
+                visitor.visit_fold_stream_map(fold);
+                walk_fold_body(&fold.instruction, &fold.last_instruction, visitor);
+            }
+            Never(never) => visitor.visit_never(never),
+            New(new) => {
+                visitor.visit_new(new);
+                new.instruction.walk(visitor);
+            }
+            Next(next) => visitor.visit_next(next),
+            Null(null) => visitor.visit_null(null),
+            Error => visitor.visit_error(),
+        }
+    }
+}
+
+impl Instruction<'_> {
+    /// Returns the number of instructions in this AST including this one, e.g. as a rough
+    /// bound of the script complexity. Fold bodies are counted once.
+    pub fn instruction_count(&self) -> u64 {
+        self.count().instructions
+    }
+
+    /// Returns the number of `call` instructions in this AST.
+    pub fn call_count(&self) -> u64 {
+        self.count().calls
+    }
+
+    fn count(&self) -> InstructionCounter {
+        let mut counter = InstructionCounter::default();
+        self.walk(&mut counter);
+        counter
+    }
+}
+
+#[derive(Default)]
+struct InstructionCounter {
+    instructions: u64,
+    calls: u64,
+}
+
+impl<'i> Visitor<'i> for InstructionCounter {
+    fn visit_instruction(&mut self, instruction: &Instruction<'i>) {
+        // the parser produces error nodes only for scripts that fail parsing
+        if !matches!(instruction, Instruction::Error) {
+            self.instructions += 1;
+        }
+    }
+
+    fn visit_call(&mut self, _call: &Call<'i>) {
+        self.calls += 1;
+    }
+}
+
+fn walk_fold_body<'i, V: Visitor<'i>>(
+    instruction: &Instruction<'i>,
+    last_instruction: &Option<std::rc::Rc<Instruction<'i>>>,
+    visitor: &mut V,
+) {
+    instruction.walk(visitor);
+    if let Some(last_instruction) = last_instruction {
+        last_instruction.walk(visitor);
+    }
+}
@@ -31,3 +31,242 @@ fn display_fail_last_error() {
     let ast = crate::parse("(fail %last_error%)").unwrap();
     assert_eq!(ast.to_string(), "fail %last_error%");
 }
+
+#[test]
+fn walk_visits_nested_instructions() {
+    use crate::ast::*;
+
+    #[derive(Default)]
+    struct CallAndNextCounter {
+        calls: usize,
+        nexts: usize,
+    }
+
+    impl<'i> Visitor<'i> for CallAndNextCounter {
+        fn visit_call(&mut self, _call: &Call<'i>) {
+            self.calls += 1;
+        }
+
+        fn visit_next(&mut self, _next: &Next<'i>) {
+            self.nexts += 1;
+        }
+    }
+
+    let ast = crate::parse(
+        r#"
+        (seq
+            (call "peer" ("service" "function") [] $stream)
+            (fold $stream iterator
+                (seq
+                    (xor
+                        (call "peer" ("service" "function") [iterator])
+                        (null))
+                    (next iterator))
+                (new $other
+                    (call "peer" ("service" "function") []))))
+        "#,
+    )
+    .unwrap();
+
+    let mut counter = CallAndNextCounter::default();
+    ast.walk(&mut counter);
+    assert_eq!(counter.calls, 3);
+    assert_eq!(counter.nexts, 1);
+}
+
+#[test]
+fn instructions_and_calls_are_counted() {
+    let ast = crate::parse(
+        r#"
+        (seq
+            (call "peer" ("service" "function") [] $stream)
+            (fold $stream iterator
+                (seq
+                    (call "peer" ("service" "function") [iterator])
+                    (next iterator))
+                (null)))
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(ast.instruction_count(), 7);
+    assert_eq!(ast.call_count(), 2);
+}
+
+fn variable_names(names: &[&str]) -> std::collections::HashSet<crate::ast::VariableName> {
+    names.iter().copied().map(Into::into).collect()
+}
+
+#[test]
+fn seq_par_and_xor_define_variables_of_both_branches() {
+    let ast = crate::parse(
+        r#"
+        (seq
+            (par
+                (call "peer" ("service" "function") [] left)
+                (call "peer" ("service" "function") [] $stream))
+            (xor
+                (call left ("service" "function") [left] right)
+                (canon left $stream #canon)))
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        ast.variables_defined(),
+        variable_names(&["left", "$stream", "right", "#canon"])
+    );
+    assert_eq!(ast.variables_used(), variable_names(&["left", "$stream"]));
+}
+
+#[test]
+fn ap_defines_its_destination() {
+    let ast = crate::parse(
+        r#"
+        (seq
+            (call "peer" ("service" "function") [] value)
+            (seq
+                (ap value.$.field scalar)
+                (seq
+                    (ap scalar $stream)
+                    (ap (value scalar) %map))))
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        ast.variables_defined(),
+        variable_names(&["value", "scalar", "$stream", "%map"])
+    );
+    assert_eq!(ast.variables_used(), variable_names(&["value", "scalar"]));
+}
+
+#[test]
+fn canon_defines_its_output() {
+    let ast = crate::parse(
+        r#"
+        (seq
+            (call "peer" ("service" "function") [] peer)
+            (seq
+                (seq
+                    (canon peer $stream #canon)
+                    (canon-sorted %init_peer_id% $other #sorted))
+                (seq
+                    (canon peer %map #%canon_map)
+                    (canon "peer" %map scalar))))
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        ast.variables_defined(),
+        variable_names(&["peer", "#canon", "#sorted", "#%canon_map", "scalar"])
+    );
+    assert_eq!(
+        ast.variables_used(),
+        variable_names(&["peer", "$stream", "$other", "%map"])
+    );
+}
+
+#[test]
+fn fold_defines_its_iterator() {
+    let ast = crate::parse(
+        r#"
+        (seq
+            (seq
+                (call "peer" ("service" "function") [] array)
+                (seq
+                    (ap array $stream)
+                    (ap ("key" array) %map)))
+            (seq
+                (fold array scalar_iterator
+                    (seq
+                        (call "peer" ("service" "function") [scalar_iterator])
+                        (next scalar_iterator)))
+                (seq
+                    (fold $stream stream_iterator
+                        (next stream_iterator)
+                        (null))
+                    (fold %map map_iterator
+                        (never)))))
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        ast.variables_defined(),
+        variable_names(&[
+            "array",
+            "$stream",
+            "%map",
+            "scalar_iterator",
+            "stream_iterator",
+            "map_iterator"
+        ])
+    );
+    assert_eq!(
+        ast.variables_used(),
+        variable_names(&[
+            "array",
+            "$stream",
+            "%map",
+            "scalar_iterator",
+            "$stream",
+            "stream_iterator",
+            "%map"
+        ])
+    );
+}
+
+#[test]
+fn match_and_fail_use_their_values() {
+    let ast = crate::parse(
+        r#"
+        (seq
+            (call "peer" ("service" "function") [] left)
+            (seq
+                (seq
+                    (match left "value"
+                        (mismatch "value" left.$.[0]
+                            (null)))
+                    (match-regex left "^peer-[0-9]+$"
+                        (mismatch-regex %init_peer_id% "^peer-[0-9]+$"
+                            (fail left))))
+                (fail 1 "error")))
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(ast.variables_defined(), variable_names(&["left"]));
+    assert_eq!(ast.variables_used(), variable_names(&["left"]));
+}
+
+#[test]
+fn new_defines_restricted_variable() {
+    let ast = crate::parse(
+        r#"
+        (new $stream
+            (new scalar
+                (seq
+                    (call "peer" ("service" "function") [] scalar)
+                    (ap scalar $stream))))
+        "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+        ast.variables_defined(),
+        variable_names(&["$stream", "scalar"])
+    );
+    assert_eq!(ast.variables_used(), variable_names(&["scalar"]));
+}
+
+#[test]
+fn null_and_never_define_and_use_nothing() {
+    for script in ["(null)", "(never)"] {
+        let ast = crate::parse(script).unwrap();
+
+        assert!(ast.variables_defined().is_empty());
+        assert!(ast.variables_used().is_empty());
+    }
+}
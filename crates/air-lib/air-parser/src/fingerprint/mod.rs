@@ -0,0 +1,152 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Fingerprints of parsed AIR scripts that don't depend on formatting and variable names.
+
+#[cfg(test)]
+mod tests;
+
+use crate::ast::Instruction;
+use crate::fmt::format;
+use crate::fmt::FormatConfig;
+use crate::AIRLexer;
+use crate::AirPos;
+use crate::LambdaAST;
+use crate::Token;
+
+use air_lambda_ast::ValueAccessor;
+use fluence_blake3 as blake3;
+
+use std::collections::HashMap;
+
+/// Computes a BLAKE3 hash of a parsed script, so that scripts that differ only in whitespace,
+/// comments and names of variables have the same fingerprint.
+///
+/// Variables are renamed by the order of their first occurrence in the script, literals,
+/// e.g. service and function names, are left as is.
+pub fn fingerprint(ast: &Instruction<'_>) -> [u8; 32] {
+    // the formatted script is lexed, so only tokens are hashed and their positions are dropped
+    let script = format(ast, FormatConfig::default());
+    let mut renamer = Renamer::default();
+    let mut hasher = blake3::Hasher::new();
+
+    // the formatted script is always lexed successfully, errors are skipped just in case
+    for (_, token, _) in AIRLexer::new(&script).flatten() {
+        let canonical = renamer.canonical_token(token);
+        let serialized = serde_json::to_vec(&canonical).expect("tokens are serializable to JSON");
+        hasher.update(&serialized);
+    }
+
+    *hasher.finalize().as_bytes()
+}
+
+/// Replaces variable names with indices of their first occurrence.
+#[derive(Default)]
+struct Renamer<'i> {
+    indices: HashMap<&'i str, usize>,
+}
+
+impl<'i> Renamer<'i> {
+    /// Returns the token without its names and position along with indices of the names.
+    fn canonical_token(&mut self, token: Token<'i>) -> (Token<'i>, Vec<usize>) {
+        let position = AirPos::default();
+        let mut indices = vec![];
+        let mut rename = |name| {
+            indices.push(self.index(name));
+            ""
+        };
+
+        let token = match token {
+            Token::Scalar { name, .. } => Token::Scalar {
+                name: rename(name),
+                position,
+            },
+            Token::ScalarWithLambda { name, lambda, .. } => Token::ScalarWithLambda {
+                name: rename(name),
+                lambda: canonical_lambda(lambda, &mut rename),
+                position,
+            },
+            Token::Stream { name, .. } => Token::Stream {
+                name: rename(name),
+                position,
+            },
+            Token::StreamWithLambda { name, lambda, .. } => Token::StreamWithLambda {
+                name: rename(name),
+                lambda: canonical_lambda(lambda, &mut rename),
+                position,
+            },
+            Token::StreamMapWithLambda { name, lambda, .. } => Token::StreamMapWithLambda {
+                name: rename(name),
+                lambda: canonical_lambda(lambda, &mut rename),
+                position,
+            },
+            Token::CanonStream { name, .. } => Token::CanonStream {
+                name: rename(name),
+                position,
+            },
+            Token::CanonStreamWithLambda { name, lambda, .. } => Token::CanonStreamWithLambda {
+                name: rename(name),
+                lambda: canonical_lambda(lambda, &mut rename),
+                position,
+            },
+            Token::StreamMap { name, .. } => Token::StreamMap {
+                name: rename(name),
+                position,
+            },
+            Token::CanonStreamMap { name, .. } => Token::CanonStreamMap {
+                name: rename(name),
+                position,
+            },
+            Token::CanonStreamMapWithLambda { name, lambda, .. } => {
+                Token::CanonStreamMapWithLambda {
+                    name: rename(name),
+                    lambda: canonical_lambda(lambda, &mut rename),
+                    position,
+                }
+            }
+            Token::LastErrorWithLambda(lambda) => {
+                Token::LastErrorWithLambda(canonical_lambda(lambda, &mut rename))
+            }
+            Token::ErrorWithLambda(lambda) => {
+                Token::ErrorWithLambda(canonical_lambda(lambda, &mut rename))
+            }
+            token => token,
+        };
+
+        (token, indices)
+    }
+
+    fn index(&mut self, name: &'i str) -> usize {
+        let next_index = self.indices.len();
+        *self.indices.entry(name).or_insert(next_index)
+    }
+}
+
+/// Renames scalars used to access fields, field names are left as is.
+fn canonical_lambda<'i>(
+    mut lambda: LambdaAST<'i>,
+    rename: &mut impl FnMut(&'i str) -> &'i str,
+) -> LambdaAST<'i> {
+    if let LambdaAST::ValuePath(accessors) = &mut lambda {
+        for accessor in accessors.iter_mut() {
+            if let ValueAccessor::FieldAccessByScalar { scalar_name } = accessor {
+                *scalar_name = rename(scalar_name);
+            }
+        }
+    }
+
+    lambda
+}
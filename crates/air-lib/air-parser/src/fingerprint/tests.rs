@@ -0,0 +1,70 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::fingerprint;
+use crate::parse;
+
+fn script_fingerprint(air_script: &str) -> [u8; 32] {
+    let ast = parse(air_script).expect("script should be parsed");
+    fingerprint(&ast)
+}
+
+#[test]
+fn formatting_and_comments_are_ignored() {
+    let compact = r#"(seq (call "peer" ("service" "function") [] result) (null))"#;
+    let spread = r#"
+        ;; a comment
+        (seq
+            (call "peer"   ("service" "function") []   result)
+            (null)
+        )"#;
+
+    assert_eq!(script_fingerprint(compact), script_fingerprint(spread));
+}
+
+#[test]
+fn variables_are_renamed() {
+    let original = r#"
+        (seq
+            (call "peer" ("service" "function") [] result)
+            (fold result.$.array iterator
+                (seq (ap iterator.$.[result] $stream) (next iterator))))"#;
+    let renamed = r#"
+        (seq
+            (call "peer" ("service" "function") [] value)
+            (fold value.$.array item
+                (seq (ap item.$.[value] $acc) (next item))))"#;
+
+    assert_eq!(script_fingerprint(original), script_fingerprint(renamed));
+}
+
+#[test]
+fn literals_and_variable_bindings_are_distinguished() {
+    let script = r#"(seq (par (ap 1 a) (ap 2 b)) (call "peer" ("service" "function") [a] c))"#;
+    let other_literal = r#"(seq (par (ap 1 a) (ap 2 b)) (call "peer" ("service" "other") [a] c))"#;
+    let other_binding =
+        r#"(seq (par (ap 1 a) (ap 2 b)) (call "peer" ("service" "function") [b] c))"#;
+    let other_field = r#"(seq (call "peer" ("service" "function") [] a) (call "peer" ("service" "function") [a.$.field] b))"#;
+    let another_field = r#"(seq (call "peer" ("service" "function") [] a) (call "peer" ("service" "function") [a.$.name] b))"#;
+
+    let fingerprint = script_fingerprint(script);
+    assert_ne!(fingerprint, script_fingerprint(other_literal));
+    assert_ne!(fingerprint, script_fingerprint(other_binding));
+    assert_ne!(
+        script_fingerprint(other_field),
+        script_fingerprint(another_field)
+    );
+}
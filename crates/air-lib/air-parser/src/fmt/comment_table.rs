@@ -0,0 +1,116 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::parser::lexer::Token;
+use crate::parser::AIRLexer;
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+/// Comments of an AIR script, each one is attached to the instruction following it.
+///
+/// Instructions are identified by their index in the pre-order traversal of the AST,
+/// which matches the order they appear in the source.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CommentTable {
+    leading: HashMap<usize, Vec<String>>,
+    trailing: Vec<String>,
+}
+
+impl CommentTable {
+    /// Collects comments from the script source. Collection stops at the first lexer error,
+    /// such a script can't be parsed anyway.
+    pub fn from_script(air_script: &str) -> Self {
+        let mut table = Self::default();
+        let mut pending_comments = Vec::new();
+        let mut gap_start = 0;
+        let mut open_bracket_met = false;
+        let mut instruction_idx = 0;
+
+        for token in AIRLexer::new(air_script) {
+            let Ok((start, token, end)) = token else {
+                break;
+            };
+
+            collect_comments(&air_script[gap_start..start.into()], &mut pending_comments);
+            if open_bracket_met && is_instruction_keyword(&token) {
+                if !pending_comments.is_empty() {
+                    table
+                        .leading
+                        .insert(instruction_idx, std::mem::take(&mut pending_comments));
+                }
+                instruction_idx += 1;
+            }
+
+            open_bracket_met = matches!(token, Token::OpenRoundBracket);
+            gap_start = end.into();
+        }
+
+        collect_comments(&air_script[gap_start..], &mut pending_comments);
+        table.trailing = pending_comments;
+
+        table
+    }
+
+    /// Returns comments placed before the instruction with the provided pre-order index.
+    pub fn leading_comments(&self, instruction_idx: usize) -> &[String] {
+        self.leading
+            .get(&instruction_idx)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns comments placed after the last instruction.
+    pub fn trailing_comments(&self) -> &[String] {
+        &self.trailing
+    }
+
+    pub(super) fn has_leading_comments_in(&self, instructions: Range<usize>) -> bool {
+        self.leading.keys().any(|idx| instructions.contains(idx))
+    }
+}
+
+fn collect_comments(gap: &str, comments: &mut Vec<String>) {
+    // gaps between tokens contain only whitespaces and comments
+    let gap_comments = gap
+        .lines()
+        .filter_map(|line| line.find(';').map(|pos| line[pos..].trim_end().to_string()));
+
+    comments.extend(gap_comments);
+}
+
+fn is_instruction_keyword(token: &Token<'_>) -> bool {
+    matches!(
+        token,
+        Token::Call
+            | Token::Canon
+            | Token::CanonSorted
+            | Token::Ap
+            | Token::Seq
+            | Token::Par
+            | Token::Fail
+            | Token::Fold
+            | Token::Xor
+            | Token::Never
+            | Token::New
+            | Token::Next
+            | Token::Null
+            | Token::Match
+            | Token::MisMatch
+            | Token::MatchRegex
+            | Token::MisMatchRegex
+    )
+}
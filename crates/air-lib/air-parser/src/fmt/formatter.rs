@@ -0,0 +1,149 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::FormatConfig;
+use crate::ast::Instruction;
+
+pub(super) struct Formatter<'c> {
+    config: FormatConfig<'c>,
+    output: String,
+    // pre-order index of the next instruction to be written
+    next_instruction_idx: usize,
+}
+
+impl<'c> Formatter<'c> {
+    pub(super) fn new(config: FormatConfig<'c>) -> Self {
+        Self {
+            config,
+            output: String::new(),
+            next_instruction_idx: 0,
+        }
+    }
+
+    pub(super) fn format(mut self, ast: &Instruction<'_>) -> String {
+        self.write_instruction(ast, 0);
+
+        if let Some(comments) = self.config.comments {
+            for comment in comments.trailing_comments() {
+                self.write_line(0, comment);
+            }
+        }
+
+        self.output
+    }
+
+    fn write_instruction(&mut self, instruction: &Instruction<'_>, depth: usize) {
+        let instruction_idx = self.next_instruction_idx;
+        let subtree_size = subtree_size(instruction);
+        let indent = depth * self.config.indent_size;
+
+        if let Some(comments) = self.config.comments {
+            for comment in comments.leading_comments(instruction_idx) {
+                self.write_line(indent, comment);
+            }
+        }
+
+        let children = children(instruction);
+        let inner_comments_met = self.config.comments.map_or(false, |comments| {
+            comments.has_leading_comments_in(instruction_idx + 1..instruction_idx + subtree_size)
+        });
+
+        if !inner_comments_met {
+            let inline = inline(instruction);
+            if children.is_empty() || indent + inline.len() <= self.config.max_line_width {
+                self.write_line(indent, &inline);
+                self.next_instruction_idx += subtree_size;
+                return;
+            }
+        }
+
+        self.next_instruction_idx += 1;
+        self.write_line(indent, &format!("({}", header(instruction)));
+        for child in children {
+            self.write_instruction(child, depth + 1);
+        }
+        self.write_line(indent, ")");
+    }
+
+    fn write_line(&mut self, indent: usize, line: &str) {
+        self.output.extend(std::iter::repeat(' ').take(indent));
+        self.output.push_str(line);
+        self.output.push('\n');
+    }
+}
+
+fn children<'a, 'i>(instruction: &'a Instruction<'i>) -> Vec<&'a Instruction<'i>> {
+    use Instruction::*;
+
+    match instruction {
+        Seq(seq) => vec![&seq.0, &seq.1],
+        Par(par) => vec![&par.0, &par.1],
+        Xor(xor) => vec![&xor.0, &xor.1],
+        Match(match_) => vec![&match_.instruction],
+        MisMatch(mismatch) => vec![&mismatch.instruction],
+        MatchRegex(match_regex) => vec![&match_regex.instruction],
+        MisMatchRegex(mismatch_regex) => vec![&mismatch_regex.instruction],
+        New(new) => vec![&new.instruction],
+        FoldScalar(fold) => fold_children(&fold.instruction, &fold.last_instruction),
+        FoldStream(fold) => fold_children(&fold.instruction, &fold.last_instruction),
+        FoldStreamMap(fold) => fold_children(&fold.instruction, &fold.last_instruction),
+        Call(_)
+        | Ap(_)
+        | ApMap(_)
+        | Canon(_)
+        | CanonSorted(_)
+        | CanonMap(_)
+        | CanonStreamMapScalar(_)
+        | Fail(_)
+        | Never(_)
+        | Next(_)
+        | Null(_)
+        | Error => vec![],
+    }
+}
+
+fn fold_children<'a, 'i>(
+    instruction: &'a Instruction<'i>,
+    last_instruction: &'a Option<std::rc::Rc<Instruction<'i>>>,
+) -> Vec<&'a Instruction<'i>> {
+    std::iter::once(instruction)
+        .chain(last_instruction.as_deref())
+        .collect()
+}
+
+fn subtree_size(instruction: &Instruction<'_>) -> usize {
+    1 + children(instruction)
+        .into_iter()
+        .map(subtree_size)
+        .sum::<usize>()
+}
+
+/// Instruction keyword with its arguments, but without nested instructions.
+fn header(instruction: &Instruction<'_>) -> String {
+    // a call without output value is displayed with a trailing space
+    instruction.to_string().trim_end().to_string()
+}
+
+fn inline(instruction: &Instruction<'_>) -> String {
+    let mut result = format!("({}", header(instruction));
+    for child in children(instruction) {
+        result.push(' ');
+        result.push_str(&inline(child));
+    }
+    result.push(')');
+
+    result
+}
@@ -0,0 +1,59 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Formatting of parsed AIR scripts back into canonical AIR source.
+
+mod comment_table;
+mod formatter;
+
+#[cfg(test)]
+mod tests;
+
+pub use comment_table::CommentTable;
+
+use crate::ast::Instruction;
+
+pub const DEFAULT_INDENT_SIZE: usize = 4;
+pub const DEFAULT_MAX_LINE_WIDTH: usize = 100;
+
+/// Controls how `format` lays out an AIR script.
+#[derive(Debug, Clone, Copy)]
+pub struct FormatConfig<'c> {
+    /// Number of spaces added for each nesting level.
+    pub indent_size: usize,
+
+    /// Instructions that fit into this width together with their indent are
+    /// printed on one line, others are split into several lines.
+    pub max_line_width: usize,
+
+    /// Comments to put back into the formatted script, they are dropped if it's `None`.
+    pub comments: Option<&'c CommentTable>,
+}
+
+impl Default for FormatConfig<'_> {
+    fn default() -> Self {
+        Self {
+            indent_size: DEFAULT_INDENT_SIZE,
+            max_line_width: DEFAULT_MAX_LINE_WIDTH,
+            comments: None,
+        }
+    }
+}
+
+/// Formats a parsed AIR script into canonical indented AIR source.
+pub fn format(ast: &Instruction<'_>, config: FormatConfig<'_>) -> String {
+    formatter::Formatter::new(config).format(ast)
+}
@@ -0,0 +1,139 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::format;
+use super::CommentTable;
+use super::FormatConfig;
+use crate::parse;
+
+fn format_script(air_script: &str, config: FormatConfig<'_>) -> String {
+    let ast = parse(air_script).expect("script should be parsed");
+    format(&ast, config)
+}
+
+#[test]
+fn short_script_is_inlined() {
+    let air_script = r#"
+        (seq
+            (call "peer_id" ("service_id" "function_name") ["arg" %init_peer_id%] result)
+            (null))"#;
+
+    let actual = format_script(air_script, <_>::default());
+
+    let expected = "(seq (call \"peer_id\" (\"service_id\" \"function_name\") [\"arg\" %init_peer_id%] result) (null))\n";
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn long_script_is_split() {
+    let air_script = r#"
+        (xor (par (call %init_peer_id% ("service" "function") []) (ap 1 $stream))
+        (new $stream (fold $stream i (seq (null) (next i)) (never))))"#;
+    let config = FormatConfig {
+        indent_size: 2,
+        max_line_width: 30,
+        comments: None,
+    };
+
+    let actual = format_script(air_script, config);
+
+    let expected = r#"(xor
+  (par
+    (call %init_peer_id% ("service" "function") [])
+    (ap 1 $stream)
+  )
+  (new $stream
+    (fold $stream i
+      (seq (null) (next i))
+      (never)
+    )
+  )
+)
+"#;
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn comments_are_preserved() {
+    let air_script = r#"
+        ;; the first comment
+        (seq
+            (null) ; after null
+            ;; before never
+            (never)
+        )
+        ;; the last comment
+        "#;
+    let comments = CommentTable::from_script(air_script);
+    let config = FormatConfig {
+        comments: Some(&comments),
+        ..<_>::default()
+    };
+
+    let actual = format_script(air_script, config);
+
+    let expected = r#";; the first comment
+(seq
+    (null)
+    ; after null
+    ;; before never
+    (never)
+)
+;; the last comment
+"#;
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn comments_are_dropped_without_table() {
+    let air_script = r#"
+        (seq
+            ;; comment
+            (null)
+            (null))"#;
+
+    let actual = format_script(air_script, <_>::default());
+
+    assert_eq!(actual, "(seq (null) (null))\n");
+}
+
+#[test]
+fn format_round_trip() {
+    let air_script = r#"
+        (seq
+            (seq
+                (canon "peer" $stream #canon)
+                (ap ("key" #canon.$.[0]) %map))
+            (xor
+                (match 1 1
+                    (fail 1 "error message"))
+                (mismatch [] %last_error%.$.message
+                    (fold %map iterator
+                        (call iterator.$.key ("s" "f") [:error:.$.error_code] $result)))))"#;
+    let config = FormatConfig {
+        max_line_width: 40,
+        ..<_>::default()
+    };
+
+    let formatted = format_script(air_script, config);
+    let reformatted = format_script(&formatted, config);
+
+    assert_eq!(formatted, reformatted);
+    assert_eq!(
+        format_script(air_script, <_>::default()),
+        format_script(&formatted, <_>::default())
+    );
+}
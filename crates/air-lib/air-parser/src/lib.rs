@@ -26,13 +26,29 @@
 )]
 
 pub mod ast;
+mod fingerprint;
+pub mod fmt;
+pub mod lint;
+pub mod optimize;
 mod parser;
+mod streaming;
 
+pub use fingerprint::fingerprint;
 pub use parser::lexer::AirPos;
+pub use parser::lexer::Token;
 pub use parser::parse;
+pub use parser::validate;
 pub use parser::AIRLexer;
 pub use parser::AIRParser;
+pub use parser::AirParseError;
+pub use parser::ValidationError;
+pub use parser::ValidationReport;
+pub use parser::ValidationWarning;
 pub use parser::VariableValidator;
+pub use streaming::parse_streaming;
+pub use streaming::ScriptChunk;
+pub use streaming::StreamingParseError;
+pub use streaming::StreamingParser;
 
 use air_lambda_parser::parse as parse_lambda;
 use air_lambda_parser::LambdaAST;
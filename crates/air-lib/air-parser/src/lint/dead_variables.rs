@@ -0,0 +1,369 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::reads;
+use super::Lint;
+use super::LintSeverity;
+use crate::ast::*;
+use crate::parser::lexer::AirPos;
+use crate::parser::Span;
+
+use std::collections::HashSet;
+
+pub(super) const DEAD_VARIABLE: &str = "dead-variable";
+
+type Live<'i> = HashSet<&'i str>;
+
+/// Finds scalars written by `ap` or `call` whose values are never read afterwards.
+///
+/// It's a backward live-variable analysis: every instruction maps the set of variables live
+/// after it to the set of variables live before it. A `par` branch could observe writes of
+/// the other one and `next` repeats the fold body, in these cases variables read anywhere in
+/// the branch or the body are considered live instead of iterating to a fixpoint, so that
+/// nested instructions are visited once. Writes to streams are never reported, they are
+/// visible outside the script.
+#[derive(Default)]
+pub(super) struct DeadVariableLint<'i> {
+    // variables read in bodies of enclosing folds, `next` continues with the body of its fold
+    folds: Vec<(&'i str, Live<'i>)>,
+    // variables restricted by enclosing new instructions
+    restricted: Vec<&'i str>,
+    lints: Vec<Lint>,
+}
+
+impl<'i> DeadVariableLint<'i> {
+    pub(super) fn lint(mut self, ast: &Instruction<'i>) -> Vec<Lint> {
+        self.live_in(ast, &Live::new());
+        self.lints
+    }
+
+    fn live_in(&mut self, instruction: &Instruction<'i>, live_out: &Live<'i>) -> Live<'i> {
+        match instruction {
+            Instruction::Call(call) => {
+                let mut live = live_out.clone();
+                if let CallOutputValue::Scalar(scalar) = &call.output {
+                    self.write(&mut live, scalar.name, scalar.position);
+                }
+                reads::triplet(&call.triplet, &mut |name, _| {
+                    live.insert(name);
+                });
+                for arg in call.args.iter() {
+                    reads::value(arg, &mut |name, _| {
+                        live.insert(name);
+                    });
+                }
+                live
+            }
+            Instruction::Ap(ap) => {
+                let mut live = live_out.clone();
+                if let ApResult::Scalar(scalar) = &ap.result {
+                    self.write(&mut live, scalar.name, scalar.position);
+                }
+                reads::ap_argument(&ap.argument, &mut |name, _| {
+                    live.insert(name);
+                });
+                live
+            }
+            Instruction::ApMap(ap_map) => {
+                let mut live = live_out.clone();
+                reads::map_key(&ap_map.key, &mut |name, _| {
+                    live.insert(name);
+                });
+                reads::ap_argument(&ap_map.value, &mut |name, _| {
+                    live.insert(name);
+                });
+                live
+            }
+            Instruction::Canon(canon) => {
+                let mut live = live_out.clone();
+                live.remove(canon.canon_stream.name);
+                reads::peer_id(&canon.peer_id, &mut |name, _| {
+                    live.insert(name);
+                });
+                live
+            }
+            Instruction::CanonSorted(canon) => {
+                let mut live = live_out.clone();
+                live.remove(canon.canon_stream.name);
+                reads::peer_id(&canon.peer_id, &mut |name, _| {
+                    live.insert(name);
+                });
+                live
+            }
+            Instruction::CanonMap(canon_map) => {
+                let mut live = live_out.clone();
+                live.remove(canon_map.canon_stream_map.name);
+                reads::peer_id(&canon_map.peer_id, &mut |name, _| {
+                    live.insert(name);
+                });
+                live
+            }
+            Instruction::CanonStreamMapScalar(canon) => {
+                let mut live = live_out.clone();
+                live.remove(canon.scalar.name);
+                reads::peer_id(&canon.peer_id, &mut |name, _| {
+                    live.insert(name);
+                });
+                live
+            }
+            Instruction::Seq(seq) => {
+                let live = self.live_in(&seq.1, live_out);
+                self.live_in(&seq.0, &live)
+            }
+            Instruction::Par(par) => self.live_in_par(&par.0, &par.1, live_out),
+            Instruction::Xor(xor) => {
+                // the right branch is executed after a failure somewhere in the left one
+                let right_live = self.live_in(&xor.1, live_out);
+                let mut left_out = live_out.clone();
+                left_out.extend(right_live.iter().copied());
+                let mut live = self.live_in(&xor.0, &left_out);
+                live.extend(right_live);
+                live
+            }
+            Instruction::Match(match_) => self.live_in_match(
+                &[&match_.left_value, &match_.right_value],
+                &match_.instruction,
+                live_out,
+            ),
+            Instruction::MisMatch(mismatch) => self.live_in_match(
+                &[&mismatch.left_value, &mismatch.right_value],
+                &mismatch.instruction,
+                live_out,
+            ),
+            Instruction::MatchRegex(match_regex) => {
+                self.live_in_match(&[&match_regex.value], &match_regex.instruction, live_out)
+            }
+            Instruction::MisMatchRegex(mismatch_regex) => self.live_in_match(
+                &[&mismatch_regex.value],
+                &mismatch_regex.instruction,
+                live_out,
+            ),
+            Instruction::Fail(fail) => {
+                // a failure could be caught by xor, so everything live after it stays live
+                let mut live = live_out.clone();
+                reads::fail(fail, &mut |name, _| {
+                    live.insert(name);
+                });
+                live
+            }
+            Instruction::FoldScalar(fold) => {
+                let mut live = self.live_in_fold(
+                    fold.iterator.name,
+                    &fold.instruction,
+                    fold.last_instruction.as_deref(),
+                    live_out,
+                );
+                reads::fold_scalar_iterable(&fold.iterable, &mut |name, _| {
+                    live.insert(name);
+                });
+                live
+            }
+            Instruction::FoldStream(fold) => self.live_in_fold(
+                fold.iterator.name,
+                &fold.instruction,
+                fold.last_instruction.as_deref(),
+                live_out,
+            ),
+            Instruction::FoldStreamMap(fold) => self.live_in_fold(
+                fold.iterator.name,
+                &fold.instruction,
+                fold.last_instruction.as_deref(),
+                live_out,
+            ),
+            Instruction::New(new) => self.live_in_new(new, live_out),
+            Instruction::Next(next) => {
+                let mut live = live_out.clone();
+                if let Some((_, body_live)) = self
+                    .folds
+                    .iter()
+                    .rev()
+                    .find(|(iterator, _)| *iterator == next.iterator.name)
+                {
+                    live.extend(body_live.iter().copied());
+                }
+                live
+            }
+            Instruction::Never(_) | Instruction::Null(_) | Instruction::Error => live_out.clone(),
+        }
+    }
+
+    fn live_in_par(
+        &mut self,
+        left: &Instruction<'i>,
+        right: &Instruction<'i>,
+        live_out: &Live<'i>,
+    ) -> Live<'i> {
+        let mut left_out = live_out.clone();
+        collect_reads(right, &mut left_out);
+        let mut right_out = live_out.clone();
+        collect_reads(left, &mut right_out);
+
+        let mut live = self.live_in(left, &left_out);
+        live.extend(self.live_in(right, &right_out));
+        live
+    }
+
+    fn live_in_match(
+        &mut self,
+        values: &[&ImmutableValue<'i>],
+        instruction: &Instruction<'i>,
+        live_out: &Live<'i>,
+    ) -> Live<'i> {
+        // the body could be skipped
+        let mut live = self.live_in(instruction, live_out);
+        live.extend(live_out.iter().copied());
+        for value in values {
+            reads::value(value, &mut |name, _| {
+                live.insert(name);
+            });
+        }
+        live
+    }
+
+    fn live_in_fold(
+        &mut self,
+        iterator: &'i str,
+        instruction: &Instruction<'i>,
+        last_instruction: Option<&Instruction<'i>>,
+        live_out: &Live<'i>,
+    ) -> Live<'i> {
+        let after_body = match last_instruction {
+            Some(last_instruction) => self.live_in(last_instruction, live_out),
+            None => live_out.clone(),
+        };
+
+        let mut body_reads = Live::new();
+        collect_reads(instruction, &mut body_reads);
+        self.folds.push((iterator, body_reads));
+        let mut body_live = self.live_in(instruction, &after_body);
+        self.folds.pop();
+
+        // the body isn't executed for an empty iterable
+        body_live.extend(after_body);
+        body_live
+    }
+
+    fn live_in_new(&mut self, new: &New<'i>, live_out: &Live<'i>) -> Live<'i> {
+        let name = match &new.argument {
+            NewArgument::Scalar(scalar) => scalar.name,
+            NewArgument::Stream(stream) => stream.name,
+            NewArgument::StreamMap(stream_map) => stream_map.name,
+            NewArgument::CanonStream(canon) => canon.name,
+            NewArgument::CanonStreamMap(canon) => canon.name,
+        };
+
+        // it's hard to tell by name whether a read refers to the restricted variable or
+        // to the outer one, so reads flow through new and inner writes aren't reported
+        self.restricted.push(name);
+        let live = self.live_in(&new.instruction, live_out);
+        self.restricted.pop();
+        live
+    }
+
+    fn write(&mut self, live: &mut Live<'i>, name: &'i str, position: AirPos) {
+        // a dead write of a restricted variable could be a write of the outer one
+        if !live.remove(name) && !self.restricted.contains(&name) {
+            self.lints.push(Lint::new(
+                LintSeverity::Warning,
+                DEAD_VARIABLE,
+                Span::new(position, position + name.len()),
+                format!("scalar `{name}` is written, but its value is never read afterwards"),
+            ));
+        }
+    }
+}
+
+/// Collects variables read anywhere inside the instruction regardless of writes.
+fn collect_reads<'i>(instruction: &Instruction<'i>, live: &mut Live<'i>) {
+    let mut read = |name, _| {
+        live.insert(name);
+    };
+
+    match instruction {
+        Instruction::Call(call) => {
+            reads::triplet(&call.triplet, &mut read);
+            for arg in call.args.iter() {
+                reads::value(arg, &mut read);
+            }
+        }
+        Instruction::Ap(ap) => reads::ap_argument(&ap.argument, &mut read),
+        Instruction::ApMap(ap_map) => {
+            reads::map_key(&ap_map.key, &mut read);
+            reads::ap_argument(&ap_map.value, &mut read);
+        }
+        Instruction::Canon(canon) => reads::peer_id(&canon.peer_id, &mut read),
+        Instruction::CanonSorted(canon) => reads::peer_id(&canon.peer_id, &mut read),
+        Instruction::CanonMap(canon_map) => reads::peer_id(&canon_map.peer_id, &mut read),
+        Instruction::CanonStreamMapScalar(canon) => reads::peer_id(&canon.peer_id, &mut read),
+        Instruction::Seq(seq) => {
+            collect_reads(&seq.0, live);
+            collect_reads(&seq.1, live);
+        }
+        Instruction::Par(par) => {
+            collect_reads(&par.0, live);
+            collect_reads(&par.1, live);
+        }
+        Instruction::Xor(xor) => {
+            collect_reads(&xor.0, live);
+            collect_reads(&xor.1, live);
+        }
+        Instruction::Match(match_) => {
+            reads::value(&match_.left_value, &mut read);
+            reads::value(&match_.right_value, &mut read);
+            collect_reads(&match_.instruction, live);
+        }
+        Instruction::MisMatch(mismatch) => {
+            reads::value(&mismatch.left_value, &mut read);
+            reads::value(&mismatch.right_value, &mut read);
+            collect_reads(&mismatch.instruction, live);
+        }
+        Instruction::MatchRegex(match_regex) => {
+            reads::value(&match_regex.value, &mut read);
+            collect_reads(&match_regex.instruction, live);
+        }
+        Instruction::MisMatchRegex(mismatch_regex) => {
+            reads::value(&mismatch_regex.value, &mut read);
+            collect_reads(&mismatch_regex.instruction, live);
+        }
+        Instruction::Fail(fail) => reads::fail(fail, &mut read),
+        Instruction::FoldScalar(fold) => {
+            reads::fold_scalar_iterable(&fold.iterable, &mut read);
+            collect_fold_reads(&fold.instruction, fold.last_instruction.as_deref(), live);
+        }
+        Instruction::FoldStream(fold) => {
+            collect_fold_reads(&fold.instruction, fold.last_instruction.as_deref(), live)
+        }
+        Instruction::FoldStreamMap(fold) => {
+            collect_fold_reads(&fold.instruction, fold.last_instruction.as_deref(), live)
+        }
+        Instruction::New(new) => collect_reads(&new.instruction, live),
+        Instruction::Next(_)
+        | Instruction::Never(_)
+        | Instruction::Null(_)
+        | Instruction::Error => {}
+    }
+}
+
+fn collect_fold_reads<'i>(
+    instruction: &Instruction<'i>,
+    last_instruction: Option<&Instruction<'i>>,
+    live: &mut Live<'i>,
+) {
+    collect_reads(instruction, live);
+    if let Some(last_instruction) = last_instruction {
+        collect_reads(last_instruction, live);
+    }
+}
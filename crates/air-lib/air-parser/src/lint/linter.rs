@@ -0,0 +1,429 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::reads;
+use super::Lint;
+use super::LintSeverity;
+use crate::ast::*;
+use crate::parser::lexer::AirPos;
+use crate::parser::Span;
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+const NEXT_OUTSIDE_FOLD: &str = "next-outside-fold";
+const FOLD_WITHOUT_NEXT: &str = "fold-without-next";
+const FOLD_OVER_EMPTY_ARRAY: &str = "fold-over-empty-array";
+const ITERATOR_SHADOWING: &str = "iterator-shadowing";
+const ITERATOR_OVERWRITE: &str = "iterator-overwrite";
+const SCALAR_OVERWRITE: &str = "scalar-overwrite";
+const UNUSED_SCALAR: &str = "unused-scalar";
+const UNUSED_CANON: &str = "unused-canon";
+const UNUSED_NEW: &str = "unused-new";
+const STREAM_NEVER_WRITTEN: &str = "stream-never-written";
+const STREAM_MAP_NEVER_WRITTEN: &str = "stream-map-never-written";
+
+/// Walks a script once collecting lints of rules checked on the fly and information
+/// needed by rules checked after the whole script has been seen.
+#[derive(Default)]
+pub(super) struct Linter<'i> {
+    lints: Vec<Lint>,
+    // scalars written in each scope, fold and new open a scope
+    scopes: Vec<HashSet<&'i str>>,
+    folds: Vec<FoldFrame<'i>>,
+    // names of all variables mentioned so far together with the number of mentions
+    mentions: HashMap<&'i str, usize>,
+    read_variables: HashSet<&'i str>,
+    written_variables: HashSet<&'i str>,
+    // writes and reads that could be checked only when the whole script is visited
+    pending_writes: Vec<(&'i str, Span)>,
+    pending_reads: Vec<(&'i str, Span)>,
+}
+
+struct FoldFrame<'i> {
+    iterator: &'i str,
+    next_met: bool,
+}
+
+impl<'i> Linter<'i> {
+    pub(super) fn lint(mut self, ast: &Instruction<'i>) -> Vec<Lint> {
+        self.scopes.push(<_>::default());
+        self.visit(ast);
+        self.check_pending();
+
+        self.lints
+    }
+
+    fn visit(&mut self, instruction: &Instruction<'i>) {
+        match instruction {
+            Instruction::Call(call) => {
+                self.read_triplet(&call.triplet);
+                for arg in call.args.iter() {
+                    self.read_value(arg);
+                }
+                match &call.output {
+                    CallOutputValue::Scalar(scalar) => {
+                        self.write_scalar(scalar.name, scalar.position)
+                    }
+                    CallOutputValue::Stream(stream) => self.write(stream.name),
+                    CallOutputValue::None => {}
+                }
+            }
+            Instruction::Ap(ap) => {
+                self.read_ap_argument(&ap.argument);
+                match &ap.result {
+                    ApResult::Scalar(scalar) => self.write_scalar(scalar.name, scalar.position),
+                    ApResult::Stream(stream) => self.write(stream.name),
+                }
+            }
+            Instruction::ApMap(ap_map) => {
+                self.read_map_key(&ap_map.key);
+                self.read_ap_argument(&ap_map.value);
+                self.write(ap_map.map.name);
+            }
+            Instruction::Canon(canon) => {
+                self.read_peer_id(&canon.peer_id);
+                self.read_written_elsewhere(canon.stream.name, canon.stream.position);
+                self.write_checked_for_reads(canon.canon_stream.name, canon.canon_stream.position);
+            }
+            Instruction::CanonSorted(canon) => {
+                self.read_peer_id(&canon.peer_id);
+                self.read_written_elsewhere(canon.stream.name, canon.stream.position);
+                self.write_checked_for_reads(canon.canon_stream.name, canon.canon_stream.position);
+            }
+            Instruction::CanonMap(canon_map) => {
+                self.read_peer_id(&canon_map.peer_id);
+                self.read_written_elsewhere(
+                    canon_map.stream_map.name,
+                    canon_map.stream_map.position,
+                );
+                self.write_checked_for_reads(
+                    canon_map.canon_stream_map.name,
+                    canon_map.canon_stream_map.position,
+                );
+            }
+            Instruction::CanonStreamMapScalar(canon) => {
+                self.read_peer_id(&canon.peer_id);
+                self.read_written_elsewhere(canon.stream_map.name, canon.stream_map.position);
+                self.write_scalar(canon.scalar.name, canon.scalar.position);
+            }
+            Instruction::Seq(seq) => {
+                self.visit(&seq.0);
+                self.visit(&seq.1);
+            }
+            Instruction::Par(par) => {
+                self.visit(&par.0);
+                self.visit(&par.1);
+            }
+            Instruction::Xor(xor) => {
+                // the right branch is executed only if the left one failed,
+                // so they could write the same scalars
+                let scope_before = self.current_scope().clone();
+                self.visit(&xor.0);
+                let left_scope = std::mem::replace(self.current_scope(), scope_before);
+                self.visit(&xor.1);
+                self.current_scope().extend(left_scope);
+            }
+            Instruction::Match(match_) => {
+                self.read_value(&match_.left_value);
+                self.read_value(&match_.right_value);
+                self.visit(&match_.instruction);
+            }
+            Instruction::MisMatch(mismatch) => {
+                self.read_value(&mismatch.left_value);
+                self.read_value(&mismatch.right_value);
+                self.visit(&mismatch.instruction);
+            }
+            Instruction::MatchRegex(match_regex) => {
+                self.read_value(&match_regex.value);
+                self.visit(&match_regex.instruction);
+            }
+            Instruction::MisMatchRegex(mismatch_regex) => {
+                self.read_value(&mismatch_regex.value);
+                self.visit(&mismatch_regex.instruction);
+            }
+            Instruction::Fail(fail) => reads::fail(fail, &mut |name, _| self.read(name)),
+            Instruction::FoldScalar(fold) => {
+                reads::fold_scalar_iterable(&fold.iterable, &mut |name, _| self.read(name));
+                if let FoldScalarIterable::EmptyArray = fold.iterable {
+                    self.add_lint(
+                        LintSeverity::Warning,
+                        FOLD_OVER_EMPTY_ARRAY,
+                        fold.span,
+                        "fold over an empty array never executes its body",
+                    );
+                }
+                self.visit_fold(
+                    &fold.iterator,
+                    &fold.instruction,
+                    fold.last_instruction.as_deref(),
+                    fold.span,
+                );
+            }
+            Instruction::FoldStream(fold) => {
+                self.read_written_elsewhere(fold.iterable.name, fold.iterable.position);
+                self.visit_fold(
+                    &fold.iterator,
+                    &fold.instruction,
+                    fold.last_instruction.as_deref(),
+                    fold.span,
+                );
+            }
+            Instruction::FoldStreamMap(fold) => {
+                self.read_written_elsewhere(fold.iterable.name, fold.iterable.position);
+                self.visit_fold(
+                    &fold.iterator,
+                    &fold.instruction,
+                    fold.last_instruction.as_deref(),
+                    fold.span,
+                );
+            }
+            Instruction::New(new) => self.visit_new(new),
+            Instruction::Next(next) => self.visit_next(next),
+            Instruction::Never(_) | Instruction::Null(_) | Instruction::Error => {}
+        }
+    }
+
+    fn visit_fold(
+        &mut self,
+        iterator: &Scalar<'i>,
+        instruction: &Instruction<'i>,
+        last_instruction: Option<&Instruction<'i>>,
+        span: Span,
+    ) {
+        if self
+            .scopes
+            .iter()
+            .any(|scope| scope.contains(iterator.name))
+        {
+            self.add_lint(
+                LintSeverity::Warning,
+                ITERATOR_SHADOWING,
+                variable_span(iterator.name, iterator.position),
+                format!(
+                    "fold iterator `{}` shadows a scalar with the same name",
+                    iterator.name
+                ),
+            );
+        }
+
+        self.mention(iterator.name);
+        self.scopes.push(<_>::default());
+        self.folds.push(FoldFrame {
+            iterator: iterator.name,
+            next_met: false,
+        });
+
+        self.visit(instruction);
+
+        let fold_frame = self.folds.pop().expect("fold frame was pushed above");
+        self.scopes.pop();
+
+        if let Some(last_instruction) = last_instruction {
+            self.visit(last_instruction);
+        }
+
+        if !fold_frame.next_met {
+            self.add_lint(
+                LintSeverity::Warning,
+                FOLD_WITHOUT_NEXT,
+                span,
+                format!(
+                    "fold body doesn't contain `(next {})`, so only the first value is visited",
+                    iterator.name
+                ),
+            );
+        }
+    }
+
+    fn visit_new(&mut self, new: &New<'i>) {
+        let name = match &new.argument {
+            NewArgument::Scalar(scalar) => scalar.name,
+            NewArgument::Stream(stream) => stream.name,
+            NewArgument::StreamMap(stream_map) => stream_map.name,
+            NewArgument::CanonStream(canon) => canon.name,
+            NewArgument::CanonStreamMap(canon) => canon.name,
+        };
+
+        let mentions_before = self.mentions_count(name);
+        self.scopes.push(<_>::default());
+        self.visit(&new.instruction);
+        self.scopes.pop();
+
+        if self.mentions_count(name) == mentions_before {
+            self.add_lint(
+                LintSeverity::Warning,
+                UNUSED_NEW,
+                new.span,
+                format!("`{name}` is restricted by new, but never used inside it"),
+            );
+        }
+    }
+
+    fn visit_next(&mut self, next: &Next<'i>) {
+        let iterator = next.iterator.name;
+        self.read(iterator);
+
+        match self
+            .folds
+            .iter_mut()
+            .rev()
+            .find(|frame| frame.iterator == iterator)
+        {
+            Some(frame) => frame.next_met = true,
+            None => self.add_lint(
+                LintSeverity::Error,
+                NEXT_OUTSIDE_FOLD,
+                variable_span(iterator, next.iterator.position),
+                format!("`(next {iterator})` is met outside of a fold over `{iterator}`"),
+            ),
+        }
+    }
+
+    fn write_scalar(&mut self, name: &'i str, position: AirPos) {
+        let span = variable_span(name, position);
+
+        if self.folds.iter().any(|frame| frame.iterator == name) {
+            self.add_lint(
+                LintSeverity::Error,
+                ITERATOR_OVERWRITE,
+                span,
+                format!("fold iterator `{name}` can't be overwritten"),
+            );
+        } else if !self.current_scope().insert(name) {
+            self.add_lint(
+                LintSeverity::Error,
+                SCALAR_OVERWRITE,
+                span,
+                format!(
+                    "scalar `{name}` is already written in this scope and can't be overwritten"
+                ),
+            );
+        }
+
+        self.write_checked_for_reads(name, position);
+    }
+
+    fn write_checked_for_reads(&mut self, name: &'i str, position: AirPos) {
+        self.write(name);
+        self.pending_writes
+            .push((name, variable_span(name, position)));
+    }
+
+    fn write(&mut self, name: &'i str) {
+        self.mention(name);
+        self.written_variables.insert(name);
+    }
+
+    fn read_written_elsewhere(&mut self, name: &'i str, position: AirPos) {
+        self.read(name);
+        self.pending_reads
+            .push((name, variable_span(name, position)));
+    }
+
+    fn read(&mut self, name: &'i str) {
+        self.mention(name);
+        self.read_variables.insert(name);
+    }
+
+    fn mention(&mut self, name: &'i str) {
+        *self.mentions.entry(name).or_default() += 1;
+    }
+
+    fn mentions_count(&self, name: &str) -> usize {
+        self.mentions.get(name).copied().unwrap_or_default()
+    }
+
+    fn read_triplet(&mut self, triplet: &Triplet<'i>) {
+        reads::triplet(triplet, &mut |name, _| self.read(name));
+    }
+
+    fn read_peer_id(&mut self, peer_id: &ResolvableToPeerIdVariable<'i>) {
+        reads::peer_id(peer_id, &mut |name, _| self.read(name));
+    }
+
+    fn read_value(&mut self, value: &ImmutableValue<'i>) {
+        reads::value(value, &mut |name, _| self.read(name));
+    }
+
+    fn read_ap_argument(&mut self, argument: &ApArgument<'i>) {
+        reads::ap_argument(argument, &mut |name, _| self.read(name));
+    }
+
+    fn read_map_key(&mut self, key: &StreamMapKeyClause<'i>) {
+        reads::map_key(key, &mut |name, _| self.read(name));
+    }
+
+    fn check_pending(&mut self) {
+        let pending_writes = std::mem::take(&mut self.pending_writes);
+        for (name, span) in pending_writes {
+            if self.read_variables.contains(name) {
+                continue;
+            }
+
+            let (rule, kind) = if name.starts_with('#') {
+                (UNUSED_CANON, "canon stream")
+            } else {
+                (UNUSED_SCALAR, "scalar")
+            };
+            self.add_lint(
+                LintSeverity::Warning,
+                rule,
+                span,
+                format!("{kind} `{name}` is written, but never read"),
+            );
+        }
+
+        let pending_reads = std::mem::take(&mut self.pending_reads);
+        for (name, span) in pending_reads {
+            if self.written_variables.contains(name) {
+                continue;
+            }
+
+            let (rule, kind) = if name.starts_with('%') {
+                (STREAM_MAP_NEVER_WRITTEN, "stream map")
+            } else {
+                (STREAM_NEVER_WRITTEN, "stream")
+            };
+            self.add_lint(
+                LintSeverity::Warning,
+                rule,
+                span,
+                format!("{kind} `{name}` is never written by the script, so it's always empty"),
+            );
+        }
+    }
+
+    fn current_scope(&mut self) -> &mut HashSet<&'i str> {
+        self.scopes
+            .last_mut()
+            .expect("the root scope is always present")
+    }
+
+    fn add_lint(
+        &mut self,
+        severity: LintSeverity,
+        rule: &'static str,
+        span: Span,
+        message: impl Into<String>,
+    ) {
+        self.lints.push(Lint::new(severity, rule, span, message));
+    }
+}
+
+fn variable_span(name: &str, position: AirPos) -> Span {
+    Span::new(position, position + name.len())
+}
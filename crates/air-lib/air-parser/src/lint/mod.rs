@@ -0,0 +1,105 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Detection of mistakes that are syntactically valid, but almost certainly wrong.
+
+mod dead_variables;
+mod linter;
+pub(crate) mod reads;
+
+#[cfg(test)]
+mod tests;
+
+use crate::ast::Instruction;
+use crate::parser::Span;
+
+use std::collections::HashSet;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LintSeverity {
+    /// The script is likely to behave not as intended.
+    Warning,
+    /// The script is going to fail on execution.
+    Error,
+}
+
+/// A diagnostic produced by one of the lint rules.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lint {
+    pub severity: LintSeverity,
+    /// Name of the lint rule, e.g. `scalar-overwrite`.
+    pub rule: &'static str,
+    /// Byte offsets of the offending part of a script.
+    pub span: Span,
+    pub message: String,
+}
+
+/// Runs all lint rules over a parsed script, lints are sorted by their spans.
+pub fn lint(ast: &Instruction<'_>) -> Vec<Lint> {
+    let mut lints = linter::Linter::default().lint(ast);
+
+    // a write already reported, e.g. as an unused scalar, isn't reported as dead once more
+    let reported_spans: HashSet<Span> = lints.iter().map(|lint| lint.span).collect();
+    let dead_variables = dead_variables::DeadVariableLint::default().lint(ast);
+    lints.extend(
+        dead_variables
+            .into_iter()
+            .filter(|lint| !reported_spans.contains(&lint.span)),
+    );
+
+    lints.sort_by_key(|lint| lint.span);
+    lints
+}
+
+impl Lint {
+    pub(crate) fn new(
+        severity: LintSeverity,
+        rule: &'static str,
+        span: Span,
+        message: impl Into<String>,
+    ) -> Self {
+        Self {
+            severity,
+            rule,
+            span,
+            message: message.into(),
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == LintSeverity::Error
+    }
+}
+
+impl fmt::Display for LintSeverity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Warning => write!(f, "warning"),
+            Self::Error => write!(f, "error"),
+        }
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}[{}] at {}..{}: {}",
+            self.severity, self.rule, self.span.left, self.span.right, self.message
+        )
+    }
+}
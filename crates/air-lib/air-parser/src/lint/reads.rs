@@ -0,0 +1,137 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Names and positions of variables read by instruction arguments, shared by the lint rules,
+//! the validator scope checks and `Instruction::variables_used`.
+
+use crate::ast::*;
+use crate::parser::lexer::AirPos;
+
+pub(crate) fn triplet<'i>(triplet: &Triplet<'i>, read: &mut impl FnMut(&'i str, AirPos)) {
+    peer_id(&triplet.peer_id, read);
+    string(&triplet.service_id, read);
+    string(&triplet.function_name, read);
+}
+
+pub(crate) fn peer_id<'i>(
+    peer_id: &ResolvableToPeerIdVariable<'i>,
+    read: &mut impl FnMut(&'i str, AirPos),
+) {
+    match peer_id {
+        ResolvableToPeerIdVariable::Scalar(scalar) => read(scalar.name, scalar.position),
+        ResolvableToPeerIdVariable::ScalarWithLambda(scalar) => read(scalar.name, scalar.position),
+        ResolvableToPeerIdVariable::CanonStreamWithLambda(canon) => {
+            read(canon.name, canon.position)
+        }
+        ResolvableToPeerIdVariable::CanonStreamMapWithLambda(canon) => {
+            read(canon.name, canon.position)
+        }
+        ResolvableToPeerIdVariable::InitPeerId | ResolvableToPeerIdVariable::Literal(_) => {}
+    }
+}
+
+pub(crate) fn string<'i>(
+    value: &ResolvableToStringVariable<'i>,
+    read: &mut impl FnMut(&'i str, AirPos),
+) {
+    match value {
+        ResolvableToStringVariable::Scalar(scalar) => read(scalar.name, scalar.position),
+        ResolvableToStringVariable::ScalarWithLambda(scalar) => read(scalar.name, scalar.position),
+        ResolvableToStringVariable::CanonStreamWithLambda(canon) => {
+            read(canon.name, canon.position)
+        }
+        ResolvableToStringVariable::CanonStreamMapWithLambda(canon) => {
+            read(canon.name, canon.position)
+        }
+        ResolvableToStringVariable::Literal(_) => {}
+    }
+}
+
+pub(crate) fn value<'i>(value: &ImmutableValue<'i>, read: &mut impl FnMut(&'i str, AirPos)) {
+    match value {
+        ImmutableValue::Variable(variable) => match variable {
+            ImmutableVariable::Scalar(scalar) => read(scalar.name, scalar.position),
+            ImmutableVariable::CanonStream(canon) => read(canon.name, canon.position),
+            ImmutableVariable::CanonStreamMap(canon) => read(canon.name, canon.position),
+        },
+        ImmutableValue::VariableWithLambda(variable) => match variable {
+            ImmutableVariableWithLambda::Scalar(scalar) => read(scalar.name, scalar.position),
+            ImmutableVariableWithLambda::CanonStream(canon) => read(canon.name, canon.position),
+            ImmutableVariableWithLambda::CanonStreamMap(canon) => read(canon.name, canon.position),
+        },
+        ImmutableValue::InitPeerId
+        | ImmutableValue::Error(_)
+        | ImmutableValue::LastError(_)
+        | ImmutableValue::Timestamp
+        | ImmutableValue::TTL
+        | ImmutableValue::Literal(_)
+        | ImmutableValue::Number(_)
+        | ImmutableValue::Boolean(_)
+        | ImmutableValue::EmptyArray => {}
+    }
+}
+
+pub(crate) fn ap_argument<'i>(argument: &ApArgument<'i>, read: &mut impl FnMut(&'i str, AirPos)) {
+    match argument {
+        ApArgument::Scalar(scalar) => read(scalar.name, scalar.position),
+        ApArgument::ScalarWithLambda(scalar) => read(scalar.name, scalar.position),
+        ApArgument::CanonStream(canon) => read(canon.name, canon.position),
+        ApArgument::CanonStreamMap(canon) => read(canon.name, canon.position),
+        ApArgument::CanonStreamWithLambda(canon) => read(canon.name, canon.position),
+        ApArgument::CanonStreamMapWithLambda(canon) => read(canon.name, canon.position),
+        ApArgument::InitPeerId
+        | ApArgument::Timestamp
+        | ApArgument::TTL
+        | ApArgument::Error(_)
+        | ApArgument::LastError(_)
+        | ApArgument::Literal(_)
+        | ApArgument::Number(_)
+        | ApArgument::Boolean(_)
+        | ApArgument::EmptyArray => {}
+    }
+}
+
+pub(crate) fn map_key<'i>(key: &StreamMapKeyClause<'i>, read: &mut impl FnMut(&'i str, AirPos)) {
+    match key {
+        StreamMapKeyClause::Scalar(scalar) => read(scalar.name, scalar.position),
+        StreamMapKeyClause::ScalarWithLambda(scalar) => read(scalar.name, scalar.position),
+        StreamMapKeyClause::CanonStreamWithLambda(canon) => read(canon.name, canon.position),
+        StreamMapKeyClause::Literal(_) | StreamMapKeyClause::Int(_) => {}
+    }
+}
+
+pub(crate) fn fail<'i>(fail: &Fail<'i>, read: &mut impl FnMut(&'i str, AirPos)) {
+    match fail {
+        Fail::Scalar(scalar) => read(scalar.name, scalar.position),
+        Fail::ScalarWithLambda(scalar) => read(scalar.name, scalar.position),
+        Fail::CanonStreamWithLambda(canon) => read(canon.name, canon.position),
+        Fail::Literal { .. } | Fail::LastError | Fail::Error => {}
+    }
+}
+
+pub(crate) fn fold_scalar_iterable<'i>(
+    iterable: &FoldScalarIterable<'i>,
+    read: &mut impl FnMut(&'i str, AirPos),
+) {
+    match iterable {
+        FoldScalarIterable::Scalar(scalar) => read(scalar.name, scalar.position),
+        FoldScalarIterable::ScalarWithLambda(scalar) => read(scalar.name, scalar.position),
+        FoldScalarIterable::CanonStream(canon) => read(canon.name, canon.position),
+        FoldScalarIterable::CanonStreamMap(canon) => read(canon.name, canon.position),
+        FoldScalarIterable::CanonStreamMapWithLambda(canon) => read(canon.name, canon.position),
+        FoldScalarIterable::EmptyArray => {}
+    }
+}
@@ -0,0 +1,270 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::lint;
+use super::Lint;
+use super::LintSeverity;
+use crate::parse;
+
+fn lint_script(air_script: &str) -> Vec<Lint> {
+    let ast = parse(air_script).expect("script should be parsed");
+    lint(&ast)
+}
+
+fn rules(lints: &[Lint]) -> Vec<&'static str> {
+    lints.iter().map(|lint| lint.rule).collect()
+}
+
+#[test]
+fn correct_script_has_no_lints() {
+    let air_script = r#"
+        (seq
+            (call %init_peer_id% ("service" "function") [] $stream)
+            (seq
+                (canon %init_peer_id% $stream #canon)
+                (fold #canon i
+                    (seq
+                        (call %init_peer_id% ("service" "function") [i] result)
+                        (seq
+                            (ap result $results)
+                            (next i))))))"#;
+
+    let lints = lint_script(air_script);
+
+    assert!(lints.is_empty(), "unexpected lints: {lints:?}");
+}
+
+#[test]
+fn scalar_overwrite() {
+    let air_script = r#"
+        (seq
+            (call %init_peer_id% ("service" "function") [] result)
+            (call %init_peer_id% ("service" "function") [result] result))"#;
+
+    let lints = lint_script(air_script);
+
+    assert_eq!(rules(&lints), vec!["scalar-overwrite"]);
+    let lint = &lints[0];
+    assert_eq!(lint.severity, LintSeverity::Error);
+    assert_eq!(
+        &air_script[lint.span.left.into()..lint.span.right.into()],
+        "result"
+    );
+    assert!(lint.span.left > 80.into());
+}
+
+#[test]
+fn xor_branches_could_write_same_scalar() {
+    let air_script = r#"
+        (seq
+            (xor
+                (call %init_peer_id% ("service" "function") [] result)
+                (call %init_peer_id% ("service" "fallback") [] result))
+            (call %init_peer_id% ("service" "function") [result]))"#;
+
+    let lints = lint_script(air_script);
+
+    assert!(lints.is_empty(), "unexpected lints: {lints:?}");
+}
+
+#[test]
+fn new_scope_allows_same_scalar() {
+    let air_script = r#"
+        (seq
+            (call %init_peer_id% ("service" "function") [] result)
+            (new result
+                (call %init_peer_id% ("service" "function") [result] result)))"#;
+
+    let lints = lint_script(air_script);
+
+    assert!(lints.is_empty(), "unexpected lints: {lints:?}");
+}
+
+#[test]
+fn iterator_overwrite() {
+    let air_script = r#"
+        (seq
+            (call %init_peer_id% ("service" "function") [] array)
+            (fold array i
+                (seq
+                    (ap 1 i)
+                    (next i))))"#;
+
+    let lints = lint_script(air_script);
+
+    assert_eq!(rules(&lints), vec!["iterator-overwrite"]);
+    assert!(lints[0].is_error());
+}
+
+#[test]
+fn iterator_shadowing() {
+    let air_script = r#"
+        (seq
+            (call %init_peer_id% ("service" "function") [] i)
+            (fold i i
+                (seq
+                    (call %init_peer_id% ("service" "function") [i])
+                    (next i))))"#;
+
+    let lints = lint_script(air_script);
+
+    assert_eq!(rules(&lints), vec!["iterator-shadowing"]);
+}
+
+#[test]
+fn fold_without_next() {
+    let air_script = r#"
+        (seq
+            (call %init_peer_id% ("service" "function") [] array)
+            (fold array i
+                (call %init_peer_id% ("service" "function") [i])))"#;
+
+    let lints = lint_script(air_script);
+
+    assert_eq!(rules(&lints), vec!["fold-without-next"]);
+    assert_eq!(lints[0].severity, LintSeverity::Warning);
+}
+
+#[test]
+fn fold_over_empty_array() {
+    let air_script = r#"
+        (fold [] i
+            (seq
+                (call %init_peer_id% ("service" "function") [i])
+                (next i)))"#;
+
+    let lints = lint_script(air_script);
+
+    assert_eq!(rules(&lints), vec!["fold-over-empty-array"]);
+}
+
+#[test]
+fn unused_scalar_and_canon() {
+    let air_script = r#"
+        (seq
+            (call %init_peer_id% ("service" "function") [] $stream)
+            (seq
+                (canon %init_peer_id% $stream #canon)
+                (call %init_peer_id% ("service" "function") [] result)))"#;
+
+    let lints = lint_script(air_script);
+
+    assert_eq!(rules(&lints), vec!["unused-canon", "unused-scalar"]);
+}
+
+#[test]
+fn stream_never_written() {
+    let air_script = r#"
+        (seq
+            (canon %init_peer_id% $stream #canon)
+            (seq
+                (canon %init_peer_id% %map #%canon_map)
+                (seq
+                    (call %init_peer_id% ("service" "function") [#canon])
+                    (call %init_peer_id% ("service" "function") [#%canon_map]))))"#;
+
+    let lints = lint_script(air_script);
+
+    assert_eq!(
+        rules(&lints),
+        vec!["stream-never-written", "stream-map-never-written"]
+    );
+}
+
+#[test]
+fn unused_new() {
+    let air_script = r#"
+        (new $stream
+            (call %init_peer_id% ("service" "function") []))"#;
+
+    let lints = lint_script(air_script);
+
+    assert_eq!(rules(&lints), vec!["unused-new"]);
+}
+
+#[test]
+fn lints_are_displayed() {
+    let air_script = r#"(new $stream (fold $stream i (null)))"#;
+
+    let lints = lint_script(air_script);
+
+    assert_eq!(
+        lints[0].to_string(),
+        "warning[fold-without-next] at 13..36: fold body doesn't contain `(next i)`, so only the first value is visited"
+    );
+}
+
+#[test]
+fn dead_variable() {
+    let air_script = r#"
+        (seq
+            (seq
+                (call %init_peer_id% ("service" "function") [] $stream)
+                (canon %init_peer_id% $stream #canon))
+            (seq
+                (fold #canon i
+                    (seq
+                        (call %init_peer_id% ("service" "function") [i] result)
+                        (seq
+                            (call %init_peer_id% ("service" "log") [result])
+                            (next i))))
+                (fold #canon j
+                    (seq
+                        (call %init_peer_id% ("service" "function") [j] result)
+                        (seq
+                            (ap j $results)
+                            (next j))))))"#;
+
+    let lints = lint_script(air_script);
+
+    assert_eq!(rules(&lints), vec!["dead-variable"]);
+    let lint = &lints[0];
+    assert_eq!(lint.severity, LintSeverity::Warning);
+    assert_eq!(
+        &air_script[lint.span.left.into()..lint.span.right.into()],
+        "result"
+    );
+    assert!(lint.span.left > 500.into());
+    assert!(lint.message.contains("`result`"), "{}", lint.message);
+}
+
+#[test]
+fn dead_variable_is_not_reported_twice() {
+    let air_script = r#"
+        (seq
+            (call %init_peer_id% ("service" "function") [] result)
+            (seq
+                (call %init_peer_id% ("service" "log") [result])
+                (call %init_peer_id% ("service" "function") [] result)))"#;
+
+    let lints = lint_script(air_script);
+
+    assert_eq!(rules(&lints), vec!["scalar-overwrite"]);
+}
+
+#[test]
+fn par_branches_read_each_others_writes() {
+    let air_script = r#"
+        (seq
+            (call %init_peer_id% ("service" "function") [] $stream)
+            (par
+                (call %init_peer_id% ("service" "function") [] result)
+                (call %init_peer_id% ("service" "function") [result] $stream)))"#;
+
+    let lints = lint_script(air_script);
+
+    assert!(lints.is_empty(), "unexpected lints: {lints:?}");
+}
@@ -0,0 +1,340 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Removal of script parts that can be statically proven to never execute
+//! and folding of comparisons known before execution.
+
+#[cfg(test)]
+mod tests;
+
+use crate::ast::*;
+use crate::fmt::format;
+use crate::fmt::FormatConfig;
+
+use std::fmt;
+use std::rc::Rc;
+
+/// Describes instructions removed by `eliminate_dead_code`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct EliminationReport {
+    pub eliminations: Vec<Elimination>,
+}
+
+/// A removed instruction along with the reason it was removed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Elimination {
+    /// The removed instruction printed as one line AIR.
+    pub instruction: String,
+    pub reason: EliminationReason,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EliminationReason {
+    /// The instruction follows `never` in `seq` or is the right branch of `xor` with `never`.
+    AfterNever,
+    /// The instruction follows a literal `fail` in `seq`.
+    AfterFail,
+    /// The instruction is the right branch of `xor` whose left branch can't fail.
+    XorLeftNeverFails,
+    /// The instruction is the body of `match` or `mismatch` on literals that never hold.
+    UnsatisfiableMatch,
+    /// `match` or `mismatch` on literals that always hold is replaced with its body.
+    TautologicalMatch,
+}
+
+/// Removes parts of a script that never execute. `par` branches are never removed,
+/// because a completed branch makes the whole `par` complete.
+///
+/// Removed instructions don't produce states, so the optimized script produces the same trace
+/// as the original one, however all peers should execute the same version of a script.
+pub fn eliminate_dead_code(ast: Instruction<'_>) -> (Instruction<'_>, EliminationReport) {
+    let mut report = EliminationReport::default();
+    let ast = eliminate(ast, &mut report);
+
+    (ast, report)
+}
+
+fn eliminate<'i>(instruction: Instruction<'i>, report: &mut EliminationReport) -> Instruction<'i> {
+    match instruction {
+        Instruction::Seq(seq) => eliminate_in_seq(*seq, report),
+        Instruction::Xor(xor) => eliminate_in_xor(*xor, report),
+        Instruction::Match(match_) => {
+            let holds = literals_equal(&match_.left_value, &match_.right_value);
+            let Match {
+                left_value,
+                right_value,
+                instruction,
+            } = *match_;
+            match holds {
+                Some(true) => {
+                    report.add(
+                        format!("(match {left_value} {right_value})"),
+                        EliminationReason::TautologicalMatch,
+                    );
+                    eliminate(instruction, report)
+                }
+                Some(false) => Instruction::Match(Box::new(Match {
+                    left_value,
+                    right_value,
+                    instruction: remove_body(instruction, report),
+                })),
+                None => Instruction::Match(Box::new(Match {
+                    left_value,
+                    right_value,
+                    instruction: eliminate(instruction, report),
+                })),
+            }
+        }
+        Instruction::MisMatch(mismatch) => {
+            let holds =
+                literals_equal(&mismatch.left_value, &mismatch.right_value).map(|equal| !equal);
+            let MisMatch {
+                left_value,
+                right_value,
+                instruction,
+            } = *mismatch;
+            match holds {
+                Some(true) => {
+                    report.add(
+                        format!("(mismatch {left_value} {right_value})"),
+                        EliminationReason::TautologicalMatch,
+                    );
+                    eliminate(instruction, report)
+                }
+                Some(false) => Instruction::MisMatch(Box::new(MisMatch {
+                    left_value,
+                    right_value,
+                    instruction: remove_body(instruction, report),
+                })),
+                None => Instruction::MisMatch(Box::new(MisMatch {
+                    left_value,
+                    right_value,
+                    instruction: eliminate(instruction, report),
+                })),
+            }
+        }
+        instruction => map_children(instruction, &mut |child| eliminate(child, report)),
+    }
+}
+
+/// Replaces `match` and `mismatch` on literals that always hold with their bodies and drops
+/// bodies of those that never hold, nested instructions are folded as well.
+///
+/// A comparison that never holds fails with a catchable error, which could switch `xor` to
+/// its right branch, so it is kept with the `(null)` body instead of being replaced with `(null)`.
+pub fn constant_fold(ast: Instruction<'_>) -> Instruction<'_> {
+    let holds = match &ast {
+        Instruction::Match(match_) => literals_equal(&match_.left_value, &match_.right_value),
+        Instruction::MisMatch(mismatch) => {
+            literals_equal(&mismatch.left_value, &mismatch.right_value).map(|equal| !equal)
+        }
+        _ => None,
+    };
+
+    match (ast, holds) {
+        (Instruction::Match(match_), Some(true)) => constant_fold(match_.instruction),
+        (Instruction::MisMatch(mismatch), Some(true)) => constant_fold(mismatch.instruction),
+        (comparison, Some(false)) => map_children(comparison, &mut |_| Instruction::Null(Null)),
+        (instruction, _) => map_children(instruction, &mut constant_fold),
+    }
+}
+
+/// Applies `f` to every direct child of an instruction.
+fn map_children<'i>(
+    instruction: Instruction<'i>,
+    f: &mut impl FnMut(Instruction<'i>) -> Instruction<'i>,
+) -> Instruction<'i> {
+    match instruction {
+        Instruction::Seq(seq) => {
+            let Seq(left, right) = *seq;
+            Instruction::Seq(Box::new(Seq(f(left), f(right))))
+        }
+        Instruction::Par(par) => {
+            let Par(left, right) = *par;
+            Instruction::Par(Box::new(Par(f(left), f(right))))
+        }
+        Instruction::Xor(xor) => {
+            let Xor(left, right) = *xor;
+            Instruction::Xor(Box::new(Xor(f(left), f(right))))
+        }
+        Instruction::Match(mut match_) => {
+            let body = std::mem::replace(&mut match_.instruction, Instruction::Null(Null));
+            match_.instruction = f(body);
+            Instruction::Match(match_)
+        }
+        Instruction::MisMatch(mut mismatch) => {
+            let body = std::mem::replace(&mut mismatch.instruction, Instruction::Null(Null));
+            mismatch.instruction = f(body);
+            Instruction::MisMatch(mismatch)
+        }
+        Instruction::MatchRegex(mut match_regex) => {
+            let body = std::mem::replace(&mut match_regex.instruction, Instruction::Null(Null));
+            match_regex.instruction = f(body);
+            Instruction::MatchRegex(match_regex)
+        }
+        Instruction::MisMatchRegex(mut mismatch_regex) => {
+            let body = std::mem::replace(&mut mismatch_regex.instruction, Instruction::Null(Null));
+            mismatch_regex.instruction = f(body);
+            Instruction::MisMatchRegex(mismatch_regex)
+        }
+        Instruction::New(mut new) => {
+            let body = std::mem::replace(&mut new.instruction, Instruction::Null(Null));
+            new.instruction = f(body);
+            Instruction::New(new)
+        }
+        Instruction::FoldScalar(mut fold) => {
+            fold.instruction = map_shared(fold.instruction, f);
+            fold.last_instruction = fold.last_instruction.map(|last| map_shared(last, f));
+            Instruction::FoldScalar(fold)
+        }
+        Instruction::FoldStream(mut fold) => {
+            fold.instruction = map_shared(fold.instruction, f);
+            fold.last_instruction = fold.last_instruction.map(|last| map_shared(last, f));
+            Instruction::FoldStream(fold)
+        }
+        Instruction::FoldStreamMap(mut fold) => {
+            fold.instruction = map_shared(fold.instruction, f);
+            fold.last_instruction = fold.last_instruction.map(|last| map_shared(last, f));
+            Instruction::FoldStreamMap(fold)
+        }
+        instruction => instruction,
+    }
+}
+
+fn eliminate_in_seq<'i>(seq: Seq<'i>, report: &mut EliminationReport) -> Instruction<'i> {
+    let Seq(left, right) = seq;
+    let left = eliminate(left, report);
+
+    let reason = match &left {
+        Instruction::Never(_) => EliminationReason::AfterNever,
+        Instruction::Fail(fail) if matches!(**fail, Fail::Literal { .. }) => {
+            EliminationReason::AfterFail
+        }
+        _ => return Instruction::Seq(Box::new(Seq(left, eliminate(right, report)))),
+    };
+
+    report.add(print(&right), reason);
+    left
+}
+
+fn eliminate_in_xor<'i>(xor: Xor<'i>, report: &mut EliminationReport) -> Instruction<'i> {
+    let Xor(left, right) = xor;
+    let left = eliminate(left, report);
+
+    let reason = match &left {
+        Instruction::Never(_) => EliminationReason::AfterNever,
+        left if never_fails(left) => EliminationReason::XorLeftNeverFails,
+        _ => return Instruction::Xor(Box::new(Xor(left, eliminate(right, report)))),
+    };
+
+    report.add(print(&right), reason);
+    left
+}
+
+/// A fold body is shared with the fold state, so it's optimized only if it isn't shared yet.
+fn map_shared<'i>(
+    instruction: Rc<Instruction<'i>>,
+    f: &mut impl FnMut(Instruction<'i>) -> Instruction<'i>,
+) -> Rc<Instruction<'i>> {
+    match Rc::try_unwrap(instruction) {
+        Ok(instruction) => Rc::new(f(instruction)),
+        Err(instruction) => instruction,
+    }
+}
+
+fn remove_body<'i>(body: Instruction<'i>, report: &mut EliminationReport) -> Instruction<'i> {
+    if matches!(body, Instruction::Null(_)) {
+        return body;
+    }
+
+    report.add(print(&body), EliminationReason::UnsatisfiableMatch);
+    Instruction::Null(Null)
+}
+
+fn print(instruction: &Instruction<'_>) -> String {
+    let config = FormatConfig {
+        max_line_width: usize::MAX,
+        ..<_>::default()
+    };
+
+    format(instruction, config).trim_end().to_string()
+}
+
+/// Returns true if an instruction always finishes without an error or never finishes.
+fn never_fails(instruction: &Instruction<'_>) -> bool {
+    match instruction {
+        Instruction::Null(_) | Instruction::Never(_) => true,
+        Instruction::Seq(seq) => never_fails(&seq.0) && never_fails(&seq.1),
+        Instruction::Par(par) => never_fails(&par.0) && never_fails(&par.1),
+        Instruction::Xor(xor) => never_fails(&xor.0) || never_fails(&xor.1),
+        Instruction::New(new) => never_fails(&new.instruction),
+        _ => false,
+    }
+}
+
+/// Compares values known before execution, returns None if the result depends on execution.
+fn literals_equal(left: &ImmutableValue<'_>, right: &ImmutableValue<'_>) -> Option<bool> {
+    use ImmutableValue::*;
+
+    match (left, right) {
+        (Literal(left), Literal(right)) => Some(left == right),
+        (Boolean(left), Boolean(right)) => Some(left == right),
+        (EmptyArray, EmptyArray) => Some(true),
+        (Number(crate::ast::Number::Int(left)), Number(crate::ast::Number::Int(right))) => {
+            Some(left == right)
+        }
+        // integers and floats are compared by a host value representation
+        (Number(_), Number(_)) => None,
+        (
+            Literal(_) | Boolean(_) | EmptyArray | Number(_),
+            Literal(_) | Boolean(_) | EmptyArray | Number(_),
+        ) => Some(false),
+        _ => None,
+    }
+}
+
+impl EliminationReport {
+    fn add(&mut self, instruction: String, reason: EliminationReason) {
+        self.eliminations.push(Elimination {
+            instruction,
+            reason,
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.eliminations.is_empty()
+    }
+}
+
+impl fmt::Display for EliminationReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let reason = match self {
+            Self::AfterNever => "unreachable after never",
+            Self::AfterFail => "unreachable after fail",
+            Self::XorLeftNeverFails => "left branch of xor never fails",
+            Self::UnsatisfiableMatch => "match condition never holds",
+            Self::TautologicalMatch => "match condition always holds",
+        };
+
+        write!(f, "{reason}")
+    }
+}
+
+impl fmt::Display for Elimination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.reason, self.instruction)
+    }
+}
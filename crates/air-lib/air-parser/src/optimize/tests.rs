@@ -0,0 +1,245 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::constant_fold;
+use super::eliminate_dead_code;
+use super::EliminationReason;
+use super::EliminationReport;
+use crate::fmt::format;
+use crate::parse;
+
+fn optimize(air_script: &str) -> (String, EliminationReport) {
+    let ast = parse(air_script).expect("script should be parsed");
+    let (ast, report) = eliminate_dead_code(ast);
+
+    (format(&ast, <_>::default()), report)
+}
+
+fn reasons(report: &EliminationReport) -> Vec<EliminationReason> {
+    report
+        .eliminations
+        .iter()
+        .map(|elimination| elimination.reason)
+        .collect()
+}
+
+#[test]
+fn script_without_dead_code_is_unchanged() {
+    let air_script = r#"
+        (xor
+            (seq
+                (call %init_peer_id% ("service" "function") [] result)
+                (match %init_peer_id% "value"
+                    (call %init_peer_id% ("service" "function") [result])))
+            (fail :error:))"#;
+
+    let expected = format(&parse(air_script).unwrap(), <_>::default());
+    let (actual, report) = optimize(air_script);
+
+    assert!(report.is_empty(), "unexpected eliminations: {report:?}");
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn seq_after_never() {
+    let air_script = r#"
+        (seq
+            (never)
+            (call %init_peer_id% ("service" "function") []))"#;
+
+    let (actual, report) = optimize(air_script);
+
+    assert_eq!(actual, "(never)\n");
+    assert_eq!(reasons(&report), vec![EliminationReason::AfterNever]);
+    assert_eq!(
+        report.eliminations[0].instruction,
+        r#"(call %init_peer_id% ("service" "function") [])"#
+    );
+}
+
+#[test]
+fn seq_after_literal_fail() {
+    let air_script = r#"
+        (seq
+            (fail 1 "error")
+            (call %init_peer_id% ("service" "function") []))"#;
+
+    let (actual, report) = optimize(air_script);
+
+    assert_eq!(actual, "(fail 1 \"error\")\n");
+    assert_eq!(reasons(&report), vec![EliminationReason::AfterFail]);
+}
+
+#[test]
+fn seq_after_fail_last_error_is_kept() {
+    let air_script = r#"
+        (xor
+            (call %init_peer_id% ("service" "function") [])
+            (seq
+                (fail :error:)
+                (call %init_peer_id% ("service" "function") [])))"#;
+
+    let (_, report) = optimize(air_script);
+
+    assert!(report.is_empty(), "unexpected eliminations: {report:?}");
+}
+
+#[test]
+fn xor_right_branch_after_never_failing_left() {
+    let air_script = r#"
+        (xor
+            (seq
+                (null)
+                (par (null) (never)))
+            (call %init_peer_id% ("service" "function") []))"#;
+
+    let (_, report) = optimize(air_script);
+
+    assert_eq!(reasons(&report), vec![EliminationReason::XorLeftNeverFails]);
+}
+
+#[test]
+fn par_branches_are_kept() {
+    let air_script = r#"
+        (par
+            (never)
+            (call %init_peer_id% ("service" "function") []))"#;
+
+    let expected = format(&parse(air_script).unwrap(), <_>::default());
+    let (actual, report) = optimize(air_script);
+
+    assert!(report.is_empty(), "unexpected eliminations: {report:?}");
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn unsatisfiable_match_body_is_removed() {
+    let air_script = r#"
+        (xor
+            (match "a" "b"
+                (call %init_peer_id% ("service" "function") []))
+            (mismatch 1 1
+                (call %init_peer_id% ("service" "function") [])))"#;
+
+    let (actual, report) = optimize(air_script);
+
+    assert_eq!(
+        reasons(&report),
+        vec![
+            EliminationReason::UnsatisfiableMatch,
+            EliminationReason::UnsatisfiableMatch
+        ]
+    );
+    assert_eq!(
+        actual,
+        "(xor (match \"a\" \"b\" (null)) (mismatch 1 1 (null)))\n"
+    );
+}
+
+#[test]
+fn tautological_match_is_unwrapped() {
+    let air_script = r#"
+        (mismatch "a" true
+            (match [] []
+                (call %init_peer_id% ("service" "function") [])))"#;
+
+    let (actual, report) = optimize(air_script);
+
+    assert_eq!(
+        reasons(&report),
+        vec![
+            EliminationReason::TautologicalMatch,
+            EliminationReason::TautologicalMatch
+        ]
+    );
+    assert_eq!(
+        actual,
+        "(call %init_peer_id% (\"service\" \"function\") [])\n"
+    );
+}
+
+#[test]
+fn fold_body_is_optimized() {
+    let air_script = r#"
+        (seq
+            (call %init_peer_id% ("service" "function") [] iterable)
+            (fold iterable i
+                (seq
+                    (seq
+                        (never)
+                        (call %init_peer_id% ("service" "function") [i]))
+                    (next i))))"#;
+
+    let (_, report) = optimize(air_script);
+
+    assert_eq!(
+        reasons(&report),
+        vec![EliminationReason::AfterNever, EliminationReason::AfterNever]
+    );
+    assert_eq!(report.eliminations[1].instruction, "(next i)");
+}
+
+#[test]
+fn constant_fold_unwraps_nested_matches() {
+    let air_script = r#"
+        (seq
+            (match "a" "a"
+                (mismatch 1 2
+                    (call %init_peer_id% ("service" "function") [] iterable)))
+            (fold iterable i
+                (seq
+                    (match 1 1
+                        (call %init_peer_id% ("service" "function") [i]))
+                    (next i))))"#;
+
+    let ast = constant_fold(parse(air_script).unwrap());
+
+    let expected = r#"
+        (seq
+            (call %init_peer_id% ("service" "function") [] iterable)
+            (fold iterable i
+                (seq
+                    (call %init_peer_id% ("service" "function") [i])
+                    (next i))))"#;
+    assert_eq!(
+        format(&ast, <_>::default()),
+        format(&parse(expected).unwrap(), <_>::default())
+    );
+}
+
+#[test]
+fn constant_fold_keeps_failing_comparison() {
+    let air_script = r#"
+        (xor
+            (mismatch "a" "a"
+                (call %init_peer_id% ("service" "function") []))
+            (match %init_peer_id% "value"
+                (match "b" "b"
+                    (call %init_peer_id% ("service" "fallback") []))))"#;
+
+    let ast = constant_fold(parse(air_script).unwrap());
+
+    // the failing mismatch switches xor to the right branch, so it can't be replaced with null
+    let expected = r#"
+        (xor
+            (mismatch "a" "a" (null))
+            (match %init_peer_id% "value"
+                (call %init_peer_id% ("service" "fallback") [])))"#;
+    assert_eq!(
+        format(&ast, <_>::default()),
+        format(&parse(expected).unwrap(), <_>::default())
+    );
+}
@@ -1,5 +1,5 @@
 // auto-generated: "lalrpop 0.20.0"
-// sha3: 074d8a0fdc394c3f63b6ccce743bbf683f56b3746be671af5294a676960dc2ac
+// sha3: 676db4fbd84eac1137798cfa40152987d15d66a1423005957d8ca80b59ec8e8c
 use crate::ast::*;
 use crate::parser::ParserError;
 use crate::parser::VariableValidator;
@@ -71,370 +71,400 @@ mod __parse__AIR {
     }
     const __ACTION: &[i16] = &[
         // State 0
-        41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42,
+        48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49,
         // State 1
-        14, 0, 47, 0, 48, 49, 0, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 0, 0, 62, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        17, 0, 54, 0, 55, 56, 0, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 0, 0, 69, 70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 2
-        0, 0, 0, 0, 0, 0, 0, 66, 67, 0, 0, 0, 0, 68, 0, 0, 69, 70, 71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 73, 74, 0, 0, 0, 0, 75, 0, 0, 76, 77, 78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 3
-        0, 0, 0, 0, 0, 0, 0, 66, 67, 0, 0, 0, 0, 68, 0, 0, 69, 70, 71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 73, 74, 0, 0, 0, 0, 75, 0, 0, 76, 77, 78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 4
-        0, 0, 0, 0, 0, 0, 0, 0, 73, 74, 0, 0, 75, 0, 76, 0, 0, 77, 78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 73, 74, 0, 0, 0, 0, 75, 0, 0, 76, 77, 78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 5
-        0, 0, 80, 0, 0, 81, 82, 83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 85, 86, 87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 80, 81, 0, 0, 82, 0, 83, 0, 0, 84, 85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 6
-        0, 0, 89, 0, 90, 91, 92, 93, 94, 95, 96, 54, 55, 97, 98, 99, 100, 101, 102, 0, 0, 103, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 87, 0, 0, 88, 89, 90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 92, 93, 94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 7
-        0, 0, 89, 0, 90, 91, 92, 93, 94, 95, 96, 54, 55, 97, 98, 99, 100, 101, 102, 0, 0, 103, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 96, 0, 97, 98, 99, 100, 101, 102, 103, 61, 62, 104, 105, 106, 107, 108, 109, 0, 0, 110, 111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 8
-        0, 0, 0, 0, 0, 106, 107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 108, 0, 109, 110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 96, 0, 97, 98, 99, 100, 101, 102, 103, 61, 62, 104, 105, 106, 107, 108, 109, 0, 0, 110, 111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 9
-        41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42,
+        0, 0, 96, 0, 97, 98, 99, 100, 101, 102, 103, 61, 62, 104, 105, 106, 107, 108, 109, 0, 0, 110, 111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 10
-        41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42,
+        0, 0, 96, 0, 97, 98, 99, 100, 101, 102, 103, 61, 62, 104, 105, 106, 107, 108, 109, 0, 0, 110, 111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 11
-        41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42,
+        0, 0, 0, 0, 0, 115, 116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 117, 0, 118, 119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 12
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 114, 0, 115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49,
         // State 13
-        0, 0, 0, 0, 0, 0, 0, 0, 116, 0, 0, 0, 117, 0, 0, 0, 118, 119, 120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49,
         // State 14
-        0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49,
         // State 15
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 122, 123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 16
-        0, 0, 89, 0, 90, 91, 92, 93, 94, 95, 96, 54, 55, 97, 98, 99, 100, 101, 102, 0, 0, 103, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 126, 0, 0, 0, 127, 128, 129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 17
-        0, 0, 89, 0, 90, 91, 92, 93, 94, 95, 96, 54, 55, 97, 98, 99, 100, 101, 102, 0, 0, 103, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 18
-        41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 131, 132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 19
-        41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 20
-        41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42,
+        0, 0, 96, 0, 97, 98, 99, 100, 101, 102, 103, 61, 62, 104, 105, 106, 107, 108, 109, 0, 0, 110, 111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 21
-        41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42,
+        0, 0, 96, 0, 97, 98, 99, 100, 101, 102, 103, 61, 62, 104, 105, 106, 107, 108, 109, 0, 0, 110, 111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 22
-        0, 0, 47, 0, 48, 49, 0, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 0, 0, 62, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49,
         // State 23
-        0, 0, 0, 0, 0, 0, 0, 136, 137, 0, 0, 0, 0, 0, 0, 0, 138, 139, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49,
         // State 24
-        0, 142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49,
         // State 25
-        0, 0, 89, 147, 90, 91, 92, 93, 94, 95, 96, 54, 55, 97, 98, 99, 100, 101, 102, 0, 0, 103, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49,
         // State 26
-        0, 0, 0, 0, 0, 149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 54, 0, 55, 56, 0, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67, 68, 0, 0, 69, 70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 27
-        0, 0, 0, 0, 0, 0, 151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 0, 0, 0, 0, 147, 148, 149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 28
-        41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42,
+        0, 151, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 152, 0, 153, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 29
-        41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42,
+        0, 0, 96, 156, 97, 98, 99, 100, 101, 102, 103, 61, 62, 104, 105, 106, 107, 108, 109, 0, 0, 110, 111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 30
-        41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42,
+        0, 0, 0, 0, 0, 158, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 31
-        41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42,
+        0, 0, 0, 0, 0, 0, 160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 32
-        41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42,
+        0, 0, 0, 0, 0, 158, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 33
-        0, 0, 0, 0, 0, 0, 0, 136, 137, 0, 0, 0, 0, 0, 0, 0, 138, 139, 140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49,
         // State 34
-        0, 0, 89, 164, 90, 91, 92, 93, 94, 95, 96, 54, 55, 97, 98, 99, 100, 101, 102, 0, 0, 103, 104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49,
         // State 35
-        41, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42,
+        48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49,
         // State 36
-        41, 171, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42,
+        48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49,
         // State 37
-        41, 173, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42,
+        48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49,
         // State 38
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49,
         // State 39
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49,
         // State 40
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 5, 6, 7, 8, 43, 9, 44, 45, 10, 11, 12, 0,
+        0, 0, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 0, 0, 0, 0, 147, 148, 149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 41
-        -72, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72,
+        0, 0, 96, 176, 97, 98, 99, 100, 101, 102, 103, 61, 62, 104, 105, 106, 107, 108, 109, 0, 0, 110, 111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 42
-        0, 105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        48, 182, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49,
         // State 43
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        48, 184, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49,
         // State 44
-        0, 112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        48, 186, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49,
         // State 45
-        0, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -17, 0, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 46
-        0, 0, 0, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 47
-        0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 50, 12, 51, 52, 13, 14, 15, 0,
         // State 48
-        0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -75, -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75,
         // State 49
-        0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -24, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 50
-        0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -23, 0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 51
-        0, -12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -12, 0, -12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 52
-        0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -13, 0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -17, 0, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 53
-        -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, -81, 0, -81, -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81,
+        0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 54
-        -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, -80, 0, -80, -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80,
+        0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 55
-        0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 56
-        0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -24, 0, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 57
-        0, -11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -11, 0, -11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -23, 0, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 58
-        0, -16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -16, 0, -16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -12, 0, -12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 59
-        0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -13, 0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 60
-        0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, -84, 0, -84, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84,
         // State 61
-        0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, -83, 0, -83, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83,
         // State 62
-        0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 63
-        24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10, 0, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 64
-        -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -11, 0, -11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 65
-        -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -16, 0, -16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 66
-        -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20, 0, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 67
-        -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, -83, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 68
-        -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84, -84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15, 0, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 69
-        -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 70
-        -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 71
-        0, 124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 72
-        0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 73
-        0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 74
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, -86, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 75
-        0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87, -87, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 76
-        0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88, -88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 77
-        0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 78
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 79
-        0, 0, 0, 126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 80
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 81
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 82
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 83
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 84
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 85
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 86
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 87
-        -111, 0, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, 0, 0, -111, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 88
-        0, 0, 0, 127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 89
-        -112, 0, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, 0, 0, -112, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 90
-        -116, 0, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, 0, 0, -116, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 91
-        -118, 0, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, 0, 0, -118, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 92
-        -119, 0, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, 0, 0, -119, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -119,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 93
-        -117, 0, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, 0, 0, -117, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 94
-        -106, 0, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, 0, 0, -106, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106,
+        -114, 0, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, 0, 0, -114, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -114,
         // State 95
-        -107, 0, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, 0, 0, -107, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107,
+        0, 0, 0, 136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 96
-        -103, 0, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, 0, 0, -103, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103,
+        -115, 0, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, 0, 0, -115, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115,
         // State 97
-        -104, 0, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, 0, 0, -104, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -104,
+        -119, 0, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, -119, 0, 0, -119, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -119,
         // State 98
-        -105, 0, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, -105, 0, 0, -105, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105,
+        -121, 0, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, -121, 0, 0, -121, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -121,
         // State 99
-        -108, 0, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, 0, 0, -108, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108,
+        -122, 0, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, -122, 0, 0, -122, -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -122,
         // State 100
-        -114, 0, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, -114, 0, 0, -114, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -114,
+        -120, 0, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, -120, 0, 0, -120, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -120,
         // State 101
-        -115, 0, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, -115, 0, 0, -115, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115,
+        -109, 0, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, 0, 0, -109, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109,
         // State 102
-        -110, 0, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, 0, 0, -110, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110,
+        -110, 0, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, -110, 0, 0, -110, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110,
         // State 103
-        -109, 0, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, -109, 0, 0, -109, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109,
+        -106, 0, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, -106, 0, 0, -106, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106,
         // State 104
-        -58, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58,
+        -107, 0, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, -107, 0, 0, -107, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107,
         // State 105
-        -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78,
+        -108, 0, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, -108, 0, 0, -108, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108,
         // State 106
-        -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79,
+        -111, 0, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, -111, 0, 0, -111, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111,
         // State 107
-        -75, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -75,
+        -117, 0, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, -117, 0, 0, -117, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117,
         // State 108
-        -76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -76,
+        -118, 0, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, -118, 0, 0, -118, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118,
         // State 109
-        -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77,
+        -113, 0, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, 0, 0, -113, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -113,
         // State 110
-        0, 129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -112, 0, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, -112, 0, 0, -112, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112,
         // State 111
-        -59, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -59,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 112
-        0, 133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 113
-        0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -59, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -59,
         // State 114
-        0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81,
         // State 115
-        0, 0, -101, 0, -101, -101, 0, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, 0, 0, -101, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82,
         // State 116
-        0, 0, -98, 0, -98, -98, 0, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, -98, 0, 0, -98, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78,
         // State 117
-        0, 0, -97, 0, -97, -97, 0, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, -97, 0, 0, -97, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79,
         // State 118
-        0, 0, -99, 0, -99, -99, 0, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, -99, 0, 0, -99, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -80,
         // State 119
-        0, 0, -100, 0, -100, -100, 0, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, 0, 0, -100, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 120
-        0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -60, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60,
         // State 121
-        0, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 122
-        0, 0, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 123
-        -61, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -61,
+        0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 124
-        0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, -104, 0, -104, -104, 0, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, -104, 0, 0, -104, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 125
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, -101, 0, -101, -101, 0, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, -101, 0, 0, -101, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 126
-        -113, 0, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, -113, 0, 0, -113, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -113,
+        0, 0, -100, 0, -100, -100, 0, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, -100, 0, 0, -100, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 127
-        0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, -102, 0, -102, -102, 0, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, -102, 0, 0, -102, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 128
-        -68, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68,
+        0, 0, -103, 0, -103, -103, 0, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, -103, 0, 0, -103, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 129
-        0, 156, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 130
-        0, 157, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 131
-        0, 158, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 132
-        -54, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54,
+        -62, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62,
         // State 133
-        0, 159, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 134
-        0, 0, 0, 0, 0, 0, 0, -94, -94, 0, 0, 0, 0, 0, 0, 0, -94, -94, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 135
-        0, -93, 0, 0, 0, 0, 0, -93, -93, 0, 0, 0, 0, 0, 0, 0, -93, -93, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -116, 0, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, -116, 0, 0, -116, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116,
         // State 136
-        0, -92, 0, 0, 0, 0, 0, -92, -92, 0, 0, 0, 0, 0, 0, 0, -92, -92, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 167, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 137
-        0, -89, 0, 0, 0, 0, 0, -89, -89, 0, 0, 0, 0, 0, 0, 0, -89, -89, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -69, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69,
         // State 138
-        0, -90, 0, 0, 0, 0, 0, -90, -90, 0, 0, 0, 0, 0, 0, 0, -90, -90, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 139
-        0, -91, 0, 0, 0, 0, 0, -91, -91, 0, 0, 0, 0, 0, 0, 0, -91, -91, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 140
-        0, 162, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 170, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 141
-        -50, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50,
+        -55, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55,
         // State 142
-        0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 171, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 143
-        0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, -97, -97, 0, 0, 0, 0, 0, 0, 0, -97, -97, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 144
-        0, 0, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, 0, 0, -4, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -96, 0, 0, 0, 0, 0, -96, -96, 0, 0, 0, 0, 0, 0, 0, -96, -96, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 145
-        0, 0, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, 0, 0, -27, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -95, 0, 0, 0, 0, 0, -95, -95, 0, 0, 0, 0, 0, 0, 0, -95, -95, -95, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 146
-        0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -92, 0, 0, 0, 0, 0, -92, -92, 0, 0, 0, 0, 0, 0, 0, -92, -92, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 147
-        0, 165, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -93, 0, 0, 0, 0, 0, -93, -93, 0, 0, 0, 0, 0, 0, 0, -93, -93, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 148
-        0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -94, 0, 0, 0, 0, 0, -94, -94, 0, 0, 0, 0, 0, 0, 0, -94, -94, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 149
-        0, 166, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 174, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 150
-        0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -50, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50,
         // State 151
-        0, 167, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 152
-        0, 174, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 153
-        0, 175, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, 0, 0, -4, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 154
-        -60, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60,
+        0, 0, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, 0, 0, -27, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 155
-        -57, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57,
+        0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 156
-        -56, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56,
+        0, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 157
-        -69, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69,
+        0, -34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 158
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 176, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 178, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 159
-        0, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 160
-        0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 179, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 161
-        -49, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49,
+        0, 180, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 162
-        0, 0, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, 0, 0, -5, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 187, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 163
-        0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 164
-        -51, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51,
+        0, 189, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 165
-        -52, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52,
+        0, 190, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 166
-        -53, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53,
+        -61, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -61,
         // State 167
-        0, 178, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -58, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58,
         // State 168
-        -63, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63,
+        -57, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57,
         // State 169
-        0, 179, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -70, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70,
         // State 170
-        -65, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 191, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 171
-        0, 180, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 192, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 172
-        -67, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -67,
+        0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 173
-        -70, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70,
+        -49, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49,
         // State 174
-        -71, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71,
+        0, 0, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, 0, 0, -5, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 175
-        0, 181, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29, 0, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 176
-        0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -51, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51,
         // State 177
-        -62, -62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62,
+        -53, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53,
         // State 178
-        -64, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64,
+        -54, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54,
         // State 179
-        -66, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66,
+        -52, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52,
         // State 180
-        -55, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55,
+        0, 193, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 181
+        -64, -64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64,
+        // State 182
+        0, 194, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 183
+        -66, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66,
+        // State 184
+        0, 195, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 185
+        -68, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68,
+        // State 186
+        -71, -71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71,
+        // State 187
+        -73, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73,
+        // State 188
+        -72, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72,
+        // State 189
+        -74, -74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -74,
+        // State 190
+        0, 196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 191
+        0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 192
+        -63, -63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63,
+        // State 193
+        -65, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65,
+        // State 194
+        -67, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -67,
+        // State 195
+        -56, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56,
     ];
     fn __action(state: i16, integer: usize) -> i16 {
-        __ACTION[(state as usize) * 38 + integer]
+        __ACTION[(state as usize) * 41 + integer]
     }
     const __EOF_ACTION: &[i16] = &[
         // State 0
@@ -514,13 +544,13 @@ mod __parse__AIR {
         // State 37
         0,
         // State 38
-        -120,
+        0,
         // State 39
-        -8,
+        0,
         // State 40
         0,
         // State 41
-        -72,
+        0,
         // State 42
         0,
         // State 43
@@ -528,13 +558,13 @@ mod __parse__AIR {
         // State 44
         0,
         // State 45
-        0,
+        -123,
         // State 46
-        0,
+        -8,
         // State 47
         0,
         // State 48
-        0,
+        -75,
         // State 49
         0,
         // State 50
@@ -646,7 +676,7 @@ mod __parse__AIR {
         // State 103
         0,
         // State 104
-        -58,
+        0,
         // State 105
         0,
         // State 106
@@ -660,11 +690,11 @@ mod __parse__AIR {
         // State 110
         0,
         // State 111
-        -59,
+        0,
         // State 112
         0,
         // State 113
-        0,
+        -59,
         // State 114
         0,
         // State 115
@@ -678,13 +708,13 @@ mod __parse__AIR {
         // State 119
         0,
         // State 120
-        0,
+        -60,
         // State 121
         0,
         // State 122
         0,
         // State 123
-        -61,
+        0,
         // State 124
         0,
         // State 125
@@ -694,7 +724,7 @@ mod __parse__AIR {
         // State 127
         0,
         // State 128
-        -68,
+        0,
         // State 129
         0,
         // State 130
@@ -702,7 +732,7 @@ mod __parse__AIR {
         // State 131
         0,
         // State 132
-        -54,
+        -62,
         // State 133
         0,
         // State 134
@@ -712,7 +742,7 @@ mod __parse__AIR {
         // State 136
         0,
         // State 137
-        0,
+        -69,
         // State 138
         0,
         // State 139
@@ -720,7 +750,7 @@ mod __parse__AIR {
         // State 140
         0,
         // State 141
-        -50,
+        -55,
         // State 142
         0,
         // State 143
@@ -738,7 +768,7 @@ mod __parse__AIR {
         // State 149
         0,
         // State 150
-        0,
+        -50,
         // State 151
         0,
         // State 152
@@ -746,13 +776,13 @@ mod __parse__AIR {
         // State 153
         0,
         // State 154
-        -60,
+        0,
         // State 155
-        -57,
+        0,
         // State 156
-        -56,
+        0,
         // State 157
-        -69,
+        0,
         // State 158
         0,
         // State 159
@@ -760,109 +790,150 @@ mod __parse__AIR {
         // State 160
         0,
         // State 161
-        -49,
+        0,
         // State 162
         0,
         // State 163
         0,
         // State 164
-        -51,
+        0,
         // State 165
-        -52,
+        0,
         // State 166
-        -53,
+        -61,
         // State 167
-        0,
+        -58,
         // State 168
-        -63,
+        -57,
         // State 169
-        0,
+        -70,
         // State 170
-        -65,
+        0,
         // State 171
         0,
         // State 172
-        -67,
+        0,
         // State 173
-        -70,
+        -49,
         // State 174
-        -71,
+        0,
         // State 175
         0,
         // State 176
-        0,
+        -51,
         // State 177
-        -62,
+        -53,
         // State 178
-        -64,
+        -54,
         // State 179
-        -66,
+        -52,
         // State 180
-        -55,
+        0,
+        // State 181
+        -64,
+        // State 182
+        0,
+        // State 183
+        -66,
+        // State 184
+        0,
+        // State 185
+        -68,
+        // State 186
+        -71,
+        // State 187
+        -73,
+        // State 188
+        -72,
+        // State 189
+        -74,
+        // State 190
+        0,
+        // State 191
+        0,
+        // State 192
+        -63,
+        // State 193
+        -65,
+        // State 194
+        -67,
+        // State 195
+        -56,
     ];
     fn __goto(state: i16, nt: usize) -> i16 {
         match nt {
-            2 => 34,
-            5 => 38,
+            2 => 41,
+            5 => 45,
             6 => match state {
-                22 => 133,
-                _ => 12,
+                26 => 142,
+                _ => 15,
             },
-            7 => 112,
+            7 => 121,
             8 => match state {
-                34 => 162,
-                _ => 144,
+                41 => 174,
+                _ => 153,
+            },
+            9 => 28,
+            10 => 149,
+            12 => match state {
+                32 => 161,
+                _ => 156,
             },
-            9 => 24,
-            10 => 140,
-            12 => 147,
-            13 => 149,
-            14 => 71,
-            15 => 78,
-            16 => 159,
+            13 => 158,
+            14 => 78,
+            15 => 85,
+            16 => 171,
             17 => match state {
-                10 => 20,
-                11 => 21,
-                28 => 35,
-                29 => 36,
-                30 => 37,
-                0 => 39,
-                18 => 127,
-                19 => 129,
-                20 => 130,
-                21 => 131,
-                31 => 152,
-                32 => 153,
-                35 => 167,
-                36 => 169,
-                37 => 171,
-                _ => 19,
+                13 => 24,
+                14 => 25,
+                33 => 42,
+                34 => 43,
+                35 => 44,
+                0 => 46,
+                22 => 136,
+                23 => 138,
+                24 => 139,
+                25 => 140,
+                36 => 162,
+                37 => 163,
+                38 => 164,
+                39 => 165,
+                42 => 180,
+                43 => 182,
+                44 => 184,
+                _ => 23,
             },
-            19 => 18,
+            19 => 22,
             20 => match state {
-                1 | 22 => 45,
-                _ => 87,
+                1 | 26 => 52,
+                _ => 94,
             },
-            21 => 63,
+            21 => 70,
             22 => match state {
-                2 => 64,
-                _ => 15,
+                4 => 19,
+                2 => 71,
+                _ => 18,
             },
             23 => match state {
-                33 => 160,
-                _ => 134,
+                40 => 172,
+                _ => 143,
+            },
+            24 => 40,
+            25 => match state {
+                19 => 32,
+                _ => 30,
             },
-            24 => 33,
-            25 => 26,
-            26 => 27,
-            27 => 22,
-            28 => 14,
+            26 => 31,
+            27 => 26,
+            28 => 17,
             29 => match state {
-                6 => 16,
-                7 => 17,
-                16 => 31,
-                17 => 32,
-                _ => 145,
+                7 => 20,
+                9 => 21,
+                20 => 36,
+                21 => 38,
+                8 => 111,
+                10 => 112,
+                _ => 154,
             },
             _ => 0,
         }
@@ -894,10 +965,13 @@ mod __parse__AIR {
         r###"ap"###,
         r###"call"###,
         r###"canon"###,
+        r###"canon_sorted"###,
         r###"fail"###,
         r###"fold"###,
         r###"match_"###,
+        r###"match_regex"###,
         r###"mismatch"###,
+        r###"mismatch_regex"###,
         r###"never"###,
         r###"new"###,
         r###"next"###,
@@ -980,7 +1054,7 @@ mod __parse__AIR {
 
         #[inline]
         fn error_action(&self, state: i16) -> i16 {
-            __action(state, 38 - 1)
+            __action(state, 41 - 1)
         }
 
         #[inline]
@@ -1077,17 +1151,20 @@ mod __parse__AIR {
             Token::Ap if true => Some(23),
             Token::Call if true => Some(24),
             Token::Canon if true => Some(25),
-            Token::Fail if true => Some(26),
-            Token::Fold if true => Some(27),
-            Token::Match if true => Some(28),
-            Token::MisMatch if true => Some(29),
-            Token::Never if true => Some(30),
-            Token::New if true => Some(31),
-            Token::Next if true => Some(32),
-            Token::Null if true => Some(33),
-            Token::Par if true => Some(34),
-            Token::Seq if true => Some(35),
-            Token::Xor if true => Some(36),
+            Token::CanonSorted if true => Some(26),
+            Token::Fail if true => Some(27),
+            Token::Fold if true => Some(28),
+            Token::Match if true => Some(29),
+            Token::MatchRegex if true => Some(30),
+            Token::MisMatch if true => Some(31),
+            Token::MisMatchRegex if true => Some(32),
+            Token::Never if true => Some(33),
+            Token::New if true => Some(34),
+            Token::Next if true => Some(35),
+            Token::Null if true => Some(36),
+            Token::Par if true => Some(37),
+            Token::Seq if true => Some(38),
+            Token::Xor if true => Some(39),
             _ => None,
         }
     }
@@ -1102,7 +1179,7 @@ mod __parse__AIR {
     ) -> __Symbol<'input>
     {
         match __token_index {
-            0 | 1 | 2 | 3 | 9 | 13 | 14 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 => __Symbol::Variant0(__token),
+            0 | 1 | 2 | 3 | 9 | 13 | 14 | 21 | 22 | 23 | 24 | 25 | 26 | 27 | 28 | 29 | 30 | 31 | 32 | 33 | 34 | 35 | 36 | 37 | 38 | 39 => __Symbol::Variant0(__token),
             4 => match __token {
                 Token::Boolean(__tok0) if true => __Symbol::Variant1(__tok0),
                 _ => unreachable!(),
@@ -1467,19 +1544,19 @@ mod __parse__AIR {
             }
             53 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
+                    states_to_pop: 6,
                     nonterminal_produced: 17,
                 }
             }
             54 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 8,
+                    states_to_pop: 5,
                     nonterminal_produced: 17,
                 }
             }
             55 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
+                    states_to_pop: 8,
                     nonterminal_produced: 17,
                 }
             }
@@ -1491,7 +1568,7 @@ mod __parse__AIR {
             }
             57 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 3,
+                    states_to_pop: 5,
                     nonterminal_produced: 17,
                 }
             }
@@ -1503,67 +1580,67 @@ mod __parse__AIR {
             }
             59 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
+                    states_to_pop: 3,
                     nonterminal_produced: 17,
                 }
             }
             60 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
+                    states_to_pop: 5,
                     nonterminal_produced: 17,
                 }
             }
             61 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 7,
+                    states_to_pop: 4,
                     nonterminal_produced: 17,
                 }
             }
             62 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
+                    states_to_pop: 7,
                     nonterminal_produced: 17,
                 }
             }
             63 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 7,
+                    states_to_pop: 6,
                     nonterminal_produced: 17,
                 }
             }
             64 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
+                    states_to_pop: 7,
                     nonterminal_produced: 17,
                 }
             }
             65 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 7,
+                    states_to_pop: 6,
                     nonterminal_produced: 17,
                 }
             }
             66 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
+                    states_to_pop: 7,
                     nonterminal_produced: 17,
                 }
             }
             67 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 4,
+                    states_to_pop: 6,
                     nonterminal_produced: 17,
                 }
             }
             68 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
+                    states_to_pop: 4,
                     nonterminal_produced: 17,
                 }
             }
             69 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 6,
+                    states_to_pop: 5,
                     nonterminal_produced: 17,
                 }
             }
@@ -1575,38 +1652,38 @@ mod __parse__AIR {
             }
             71 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 6,
                     nonterminal_produced: 17,
                 }
             }
             72 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 18,
+                    states_to_pop: 6,
+                    nonterminal_produced: 17,
                 }
             }
             73 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 0,
-                    nonterminal_produced: 18,
+                    states_to_pop: 6,
+                    nonterminal_produced: 17,
                 }
             }
             74 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 19,
+                    nonterminal_produced: 17,
                 }
             }
             75 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 19,
+                    nonterminal_produced: 18,
                 }
             }
             76 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 19,
+                    states_to_pop: 0,
+                    nonterminal_produced: 18,
                 }
             }
             77 => {
@@ -1624,37 +1701,37 @@ mod __parse__AIR {
             79 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 19,
                 }
             }
             80 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 20,
+                    nonterminal_produced: 19,
                 }
             }
             81 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 21,
+                    nonterminal_produced: 19,
                 }
             }
             82 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 22,
+                    nonterminal_produced: 20,
                 }
             }
             83 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 22,
+                    nonterminal_produced: 20,
                 }
             }
             84 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 22,
+                    nonterminal_produced: 21,
                 }
             }
             85 => {
@@ -1678,19 +1755,19 @@ mod __parse__AIR {
             88 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 23,
+                    nonterminal_produced: 22,
                 }
             }
             89 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 23,
+                    nonterminal_produced: 22,
                 }
             }
             90 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 23,
+                    nonterminal_produced: 22,
                 }
             }
             91 => {
@@ -1708,37 +1785,37 @@ mod __parse__AIR {
             93 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 24,
+                    nonterminal_produced: 23,
                 }
             }
             94 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 25,
+                    nonterminal_produced: 23,
                 }
             }
             95 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 26,
+                    nonterminal_produced: 23,
                 }
             }
             96 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 27,
+                    nonterminal_produced: 24,
                 }
             }
             97 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 27,
+                    nonterminal_produced: 25,
                 }
             }
             98 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 27,
+                    nonterminal_produced: 26,
                 }
             }
             99 => {
@@ -1755,26 +1832,26 @@ mod __parse__AIR {
             }
             101 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 5,
-                    nonterminal_produced: 28,
+                    states_to_pop: 1,
+                    nonterminal_produced: 27,
                 }
             }
             102 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 27,
                 }
             }
             103 => {
                 __state_machine::SimulatedReduce::Reduce {
                     states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    nonterminal_produced: 27,
                 }
             }
             104 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
-                    nonterminal_produced: 29,
+                    states_to_pop: 5,
+                    nonterminal_produced: 28,
                 }
             }
             105 => {
@@ -1821,7 +1898,7 @@ mod __parse__AIR {
             }
             112 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 2,
+                    states_to_pop: 1,
                     nonterminal_produced: 29,
                 }
             }
@@ -1839,7 +1916,7 @@ mod __parse__AIR {
             }
             115 => {
                 __state_machine::SimulatedReduce::Reduce {
-                    states_to_pop: 1,
+                    states_to_pop: 2,
                     nonterminal_produced: 29,
                 }
             }
@@ -1861,7 +1938,25 @@ mod __parse__AIR {
                     nonterminal_produced: 29,
                 }
             }
-            119 => __state_machine::SimulatedReduce::Accept,
+            119 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 29,
+                }
+            }
+            120 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 29,
+                }
+            }
+            121 => {
+                __state_machine::SimulatedReduce::Reduce {
+                    states_to_pop: 1,
+                    nonterminal_produced: 29,
+                }
+            }
+            122 => __state_machine::SimulatedReduce::Accept,
             _ => panic!("invalid reduction index {}", __reduce_index)
         }
     }
@@ -2316,6 +2411,15 @@ mod __parse__AIR {
                 __reduce118(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
             }
             119 => {
+                __reduce119(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            120 => {
+                __reduce120(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            121 => {
+                __reduce121(input, errors, validator, __lookahead_start, __symbols, core::marker::PhantomData::<(&(), &(), &())>)
+            }
+            122 => {
                 // __AIR = AIR => ActionFn(0);
                 let __sym0 = __pop_Variant12(__symbols);
                 let __start = __sym0.0;
@@ -2690,11 +2794,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // (<Arg>) = Arg => ActionFn(106);
+        // (<Arg>) = Arg => ActionFn(109);
         let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action106::<>(input, errors, validator, __sym0);
+        let __nt = super::__action109::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (1, 0)
     }
@@ -2711,10 +2815,10 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // (<Arg>)* =  => ActionFn(104);
+        // (<Arg>)* =  => ActionFn(107);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action104::<>(input, errors, validator, &__start, &__end);
+        let __nt = super::__action107::<>(input, errors, validator, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant10(__nt), __end));
         (0, 1)
     }
@@ -2731,11 +2835,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // (<Arg>)* = (<Arg>)+ => ActionFn(105);
+        // (<Arg>)* = (<Arg>)+ => ActionFn(108);
         let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action105::<>(input, errors, validator, __sym0);
+        let __nt = super::__action108::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant10(__nt), __end));
         (1, 1)
     }
@@ -2752,11 +2856,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // (<Arg>)+ = Arg => ActionFn(115);
+        // (<Arg>)+ = Arg => ActionFn(118);
         let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action115::<>(input, errors, validator, __sym0);
+        let __nt = super::__action118::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant10(__nt), __end));
         (1, 2)
     }
@@ -2773,13 +2877,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // (<Arg>)+ = (<Arg>)+, Arg => ActionFn(116);
+        // (<Arg>)+ = (<Arg>)+, Arg => ActionFn(119);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant9(__symbols);
         let __sym0 = __pop_Variant10(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action116::<>(input, errors, validator, __sym0, __sym1);
+        let __nt = super::__action119::<>(input, errors, validator, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant10(__nt), __end));
         (2, 2)
     }
@@ -2796,10 +2900,10 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // @L =  => ActionFn(112);
+        // @L =  => ActionFn(115);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action112::<>(input, errors, validator, &__start, &__end);
+        let __nt = super::__action115::<>(input, errors, validator, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant11(__nt), __end));
         (0, 3)
     }
@@ -2816,10 +2920,10 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // @R =  => ActionFn(109);
+        // @R =  => ActionFn(112);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action109::<>(input, errors, validator, &__start, &__end);
+        let __nt = super::__action112::<>(input, errors, validator, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant11(__nt), __end));
         (0, 4)
     }
@@ -2857,11 +2961,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = InitPeerId => ActionFn(84);
+        // ApArgument = InitPeerId => ActionFn(87);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action84::<>(input, errors, validator, __sym0);
+        let __nt = super::__action87::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -2878,11 +2982,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = LastError => ActionFn(85);
+        // ApArgument = LastError => ActionFn(88);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action85::<>(input, errors, validator, __sym0);
+        let __nt = super::__action88::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -2899,11 +3003,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = LastErrorWithLambda => ActionFn(86);
+        // ApArgument = LastErrorWithLambda => ActionFn(89);
         let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action86::<>(input, errors, validator, __sym0);
+        let __nt = super::__action89::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -2920,11 +3024,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = Error => ActionFn(87);
+        // ApArgument = Error => ActionFn(90);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action87::<>(input, errors, validator, __sym0);
+        let __nt = super::__action90::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -2941,11 +3045,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = ErrorWithLambda => ActionFn(88);
+        // ApArgument = ErrorWithLambda => ActionFn(91);
         let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action88::<>(input, errors, validator, __sym0);
+        let __nt = super::__action91::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -2962,11 +3066,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = Timestamp => ActionFn(89);
+        // ApArgument = Timestamp => ActionFn(92);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action89::<>(input, errors, validator, __sym0);
+        let __nt = super::__action92::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -2983,11 +3087,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = TTL => ActionFn(90);
+        // ApArgument = TTL => ActionFn(93);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action90::<>(input, errors, validator, __sym0);
+        let __nt = super::__action93::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -3004,11 +3108,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = Literal => ActionFn(91);
+        // ApArgument = Literal => ActionFn(94);
         let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action91::<>(input, errors, validator, __sym0);
+        let __nt = super::__action94::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -3025,11 +3129,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = Number => ActionFn(92);
+        // ApArgument = Number => ActionFn(95);
         let __sym0 = __pop_Variant25(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action92::<>(input, errors, validator, __sym0);
+        let __nt = super::__action95::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -3046,11 +3150,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = Boolean => ActionFn(93);
+        // ApArgument = Boolean => ActionFn(96);
         let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action93::<>(input, errors, validator, __sym0);
+        let __nt = super::__action96::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -3067,13 +3171,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = "[", "]" => ActionFn(94);
+        // ApArgument = "[", "]" => ActionFn(97);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action94::<>(input, errors, validator, __sym0, __sym1);
+        let __nt = super::__action97::<>(input, errors, validator, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (2, 6)
     }
@@ -3090,11 +3194,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = Scalar => ActionFn(95);
+        // ApArgument = Scalar => ActionFn(98);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action95::<>(input, errors, validator, __sym0);
+        let __nt = super::__action98::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -3111,11 +3215,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = ScalarWithLambda => ActionFn(96);
+        // ApArgument = ScalarWithLambda => ActionFn(99);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action96::<>(input, errors, validator, __sym0);
+        let __nt = super::__action99::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -3132,11 +3236,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = CanonStream => ActionFn(97);
+        // ApArgument = CanonStream => ActionFn(100);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action97::<>(input, errors, validator, __sym0);
+        let __nt = super::__action100::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -3153,11 +3257,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = CanonStreamWithLambda => ActionFn(98);
+        // ApArgument = CanonStreamWithLambda => ActionFn(101);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action98::<>(input, errors, validator, __sym0);
+        let __nt = super::__action101::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -3174,11 +3278,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApArgument = CanonStreamMapWithLambda => ActionFn(99);
+        // ApArgument = CanonStreamMapWithLambda => ActionFn(102);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action99::<>(input, errors, validator, __sym0);
+        let __nt = super::__action102::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant13(__nt), __end));
         (1, 6)
     }
@@ -3195,11 +3299,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApResult = Scalar => ActionFn(24);
+        // ApResult = Scalar => ActionFn(27);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action24::<>(input, errors, validator, __sym0);
+        let __nt = super::__action27::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant14(__nt), __end));
         (1, 7)
     }
@@ -3216,11 +3320,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ApResult = Stream => ActionFn(25);
+        // ApResult = Stream => ActionFn(28);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action25::<>(input, errors, validator, __sym0);
+        let __nt = super::__action28::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant14(__nt), __end));
         (1, 7)
     }
@@ -3237,11 +3341,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Arg = Value => ActionFn(66);
+        // Arg = Value => ActionFn(69);
         let __sym0 = __pop_Variant9(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action66::<>(input, errors, validator, __sym0);
+        let __nt = super::__action69::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (1, 8)
     }
@@ -3258,13 +3362,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Args = "[", "]" => ActionFn(117);
+        // Args = "[", "]" => ActionFn(120);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action117::<>(input, errors, validator, __sym0, __sym1);
+        let __nt = super::__action120::<>(input, errors, validator, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant15(__nt), __end));
         (2, 9)
     }
@@ -3281,14 +3385,14 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Args = "[", (<Arg>)+, "]" => ActionFn(118);
+        // Args = "[", (<Arg>)+, "]" => ActionFn(121);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant10(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action118::<>(input, errors, validator, __sym0, __sym1, __sym2);
+        let __nt = super::__action121::<>(input, errors, validator, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant15(__nt), __end));
         (3, 9)
     }
@@ -3305,11 +3409,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // CallOutput = Scalar => ActionFn(31);
+        // CallOutput = Scalar => ActionFn(34);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action31::<>(input, errors, validator, __sym0);
+        let __nt = super::__action34::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant16(__nt), __end));
         (1, 10)
     }
@@ -3326,11 +3430,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // CallOutput = Stream => ActionFn(32);
+        // CallOutput = Stream => ActionFn(35);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action32::<>(input, errors, validator, __sym0);
+        let __nt = super::__action35::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant16(__nt), __end));
         (1, 10)
     }
@@ -3347,11 +3451,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // CallOutput? = CallOutput => ActionFn(110);
+        // CallOutput? = CallOutput => ActionFn(113);
         let __sym0 = __pop_Variant16(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action110::<>(input, errors, validator, __sym0);
+        let __nt = super::__action113::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant17(__nt), __end));
         (1, 11)
     }
@@ -3368,10 +3472,10 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // CallOutput? =  => ActionFn(111);
+        // CallOutput? =  => ActionFn(114);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action111::<>(input, errors, validator, &__start, &__end);
+        let __nt = super::__action114::<>(input, errors, validator, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant17(__nt), __end));
         (0, 11)
     }
@@ -3388,11 +3492,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // CanonStreamArgument = CanonStream => ActionFn(102);
+        // CanonStreamArgument = CanonStream => ActionFn(105);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action102::<>(input, errors, validator, __sym0);
+        let __nt = super::__action105::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant18(__nt), __end));
         (1, 12)
     }
@@ -3409,11 +3513,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // CanonStreamMapArgument = CanonStreamMap => ActionFn(103);
+        // CanonStreamMapArgument = CanonStreamMap => ActionFn(106);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action103::<>(input, errors, validator, __sym0);
+        let __nt = super::__action106::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant19(__nt), __end));
         (1, 13)
     }
@@ -3430,11 +3534,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // FailBody = Scalar => ActionFn(33);
+        // FailBody = Scalar => ActionFn(36);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action33::<>(input, errors, validator, __sym0);
+        let __nt = super::__action36::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant20(__nt), __end));
         (1, 14)
     }
@@ -3451,11 +3555,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // FailBody = ScalarWithLambda => ActionFn(34);
+        // FailBody = ScalarWithLambda => ActionFn(37);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action34::<>(input, errors, validator, __sym0);
+        let __nt = super::__action37::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant20(__nt), __end));
         (1, 14)
     }
@@ -3472,13 +3576,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // FailBody = I64, Literal => ActionFn(35);
+        // FailBody = I64, Literal => ActionFn(38);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant7(__symbols);
         let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action35::<>(input, errors, validator, __sym0, __sym1);
+        let __nt = super::__action38::<>(input, errors, validator, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant20(__nt), __end));
         (2, 14)
     }
@@ -3495,11 +3599,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // FailBody = CanonStreamWithLambda => ActionFn(36);
+        // FailBody = CanonStreamWithLambda => ActionFn(39);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action36::<>(input, errors, validator, __sym0);
+        let __nt = super::__action39::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant20(__nt), __end));
         (1, 14)
     }
@@ -3516,11 +3620,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // FailBody = LastError => ActionFn(140);
+        // FailBody = LastError => ActionFn(146);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action140::<>(input, errors, validator, __sym0);
+        let __nt = super::__action146::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant20(__nt), __end));
         (1, 14)
     }
@@ -3537,11 +3641,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // FailBody = Error => ActionFn(141);
+        // FailBody = Error => ActionFn(147);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action141::<>(input, errors, validator, __sym0);
+        let __nt = super::__action147::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant20(__nt), __end));
         (1, 14)
     }
@@ -3558,11 +3662,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // FoldScalarIterable = Scalar => ActionFn(39);
+        // FoldScalarIterable = Scalar => ActionFn(42);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action39::<>(input, errors, validator, __sym0);
+        let __nt = super::__action42::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant21(__nt), __end));
         (1, 15)
     }
@@ -3579,11 +3683,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // FoldScalarIterable = ScalarWithLambda => ActionFn(40);
+        // FoldScalarIterable = ScalarWithLambda => ActionFn(43);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action40::<>(input, errors, validator, __sym0);
+        let __nt = super::__action43::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant21(__nt), __end));
         (1, 15)
     }
@@ -3600,11 +3704,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // FoldScalarIterable = CanonStream => ActionFn(41);
+        // FoldScalarIterable = CanonStream => ActionFn(44);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action41::<>(input, errors, validator, __sym0);
+        let __nt = super::__action44::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant21(__nt), __end));
         (1, 15)
     }
@@ -3621,11 +3725,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // FoldScalarIterable = CanonStreamMap => ActionFn(42);
+        // FoldScalarIterable = CanonStreamMap => ActionFn(45);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action42::<>(input, errors, validator, __sym0);
+        let __nt = super::__action45::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant21(__nt), __end));
         (1, 15)
     }
@@ -3642,11 +3746,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // FoldScalarIterable = CanonStreamMapWithLambda => ActionFn(43);
+        // FoldScalarIterable = CanonStreamMapWithLambda => ActionFn(46);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action43::<>(input, errors, validator, __sym0);
+        let __nt = super::__action46::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant21(__nt), __end));
         (1, 15)
     }
@@ -3663,13 +3767,13 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // FoldScalarIterable = "[", "]" => ActionFn(44);
+        // FoldScalarIterable = "[", "]" => ActionFn(47);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action44::<>(input, errors, validator, __sym0, __sym1);
+        let __nt = super::__action47::<>(input, errors, validator, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant21(__nt), __end));
         (2, 15)
     }
@@ -3686,11 +3790,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Function = ResolvableToStringVariable => ActionFn(46);
+        // Function = ResolvableToStringVariable => ActionFn(49);
         let __sym0 = __pop_Variant22(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action46::<>(input, errors, validator, __sym0);
+        let __nt = super::__action49::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant22(__nt), __end));
         (1, 16)
     }
@@ -3707,7 +3811,7 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", call, Triplet, Args, CallOutput, ")" => ActionFn(161);
+        // Instr = "(", call, Triplet, Args, CallOutput, ")" => ActionFn(170);
         assert!(__symbols.len() >= 6);
         let __sym5 = __pop_Variant0(__symbols);
         let __sym4 = __pop_Variant16(__symbols);
@@ -3717,7 +3821,7 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym5.2;
-        let __nt = super::__action161::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        let __nt = super::__action170::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (6, 17)
     }
@@ -3734,7 +3838,7 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", call, Triplet, Args, ")" => ActionFn(162);
+        // Instr = "(", call, Triplet, Args, ")" => ActionFn(171);
         assert!(__symbols.len() >= 5);
         let __sym4 = __pop_Variant0(__symbols);
         let __sym3 = __pop_Variant15(__symbols);
@@ -3743,7 +3847,7 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym4.2;
-        let __nt = super::__action162::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
+        let __nt = super::__action171::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (5, 17)
     }
@@ -3760,7 +3864,7 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", canon, ResolvableToPeerIdVariable, StreamArgument, CanonStreamArgument, ")" => ActionFn(143);
+        // Instr = "(", canon, ResolvableToPeerIdVariable, StreamArgument, CanonStreamArgument, ")" => ActionFn(149);
         assert!(__symbols.len() >= 6);
         let __sym5 = __pop_Variant0(__symbols);
         let __sym4 = __pop_Variant18(__symbols);
@@ -3770,7 +3874,7 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym5.2;
-        let __nt = super::__action143::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        let __nt = super::__action149::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (6, 17)
     }
@@ -3787,7 +3891,34 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", canon, ResolvableToPeerIdVariable, StreamMapArgument, CanonStreamMapArgument, ")" => ActionFn(144);
+        // Instr = "(", canon_sorted, ResolvableToPeerIdVariable, StreamArgument, CanonStreamArgument, ")" => ActionFn(150);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant18(__symbols);
+        let __sym3 = __pop_Variant27(__symbols);
+        let __sym2 = __pop_Variant26(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym5.2;
+        let __nt = super::__action150::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (6, 17)
+    }
+    pub(crate) fn __reduce52<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Instr = "(", canon, ResolvableToPeerIdVariable, StreamMapArgument, CanonStreamMapArgument, ")" => ActionFn(151);
         assert!(__symbols.len() >= 6);
         let __sym5 = __pop_Variant0(__symbols);
         let __sym4 = __pop_Variant19(__symbols);
@@ -3797,11 +3928,11 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym5.2;
-        let __nt = super::__action144::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        let __nt = super::__action151::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (6, 17)
     }
-    pub(crate) fn __reduce52<
+    pub(crate) fn __reduce53<
         'err,
         'input,
         'v,
@@ -3814,7 +3945,7 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", canon, ResolvableToPeerIdVariable, StreamMapArgument, Scalar, ")" => ActionFn(145);
+        // Instr = "(", canon, ResolvableToPeerIdVariable, StreamMapArgument, Scalar, ")" => ActionFn(152);
         assert!(__symbols.len() >= 6);
         let __sym5 = __pop_Variant0(__symbols);
         let __sym4 = __pop_Variant2(__symbols);
@@ -3824,11 +3955,11 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym5.2;
-        let __nt = super::__action145::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        let __nt = super::__action152::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (6, 17)
     }
-    pub(crate) fn __reduce53<
+    pub(crate) fn __reduce54<
         'err,
         'input,
         'v,
@@ -3841,7 +3972,7 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", ap, ApArgument, ApResult, ")" => ActionFn(146);
+        // Instr = "(", ap, ApArgument, ApResult, ")" => ActionFn(153);
         assert!(__symbols.len() >= 5);
         let __sym4 = __pop_Variant0(__symbols);
         let __sym3 = __pop_Variant14(__symbols);
@@ -3850,11 +3981,11 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym4.2;
-        let __nt = super::__action146::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
+        let __nt = super::__action153::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (5, 17)
     }
-    pub(crate) fn __reduce54<
+    pub(crate) fn __reduce55<
         'err,
         'input,
         'v,
@@ -3867,7 +3998,7 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", ap, "(", StreamMapKeyClause, ApArgument, ")", StreamMap, ")" => ActionFn(147);
+        // Instr = "(", ap, "(", StreamMapKeyClause, ApArgument, ")", StreamMap, ")" => ActionFn(154);
         assert!(__symbols.len() >= 8);
         let __sym7 = __pop_Variant0(__symbols);
         let __sym6 = __pop_Variant2(__symbols);
@@ -3879,11 +4010,11 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym7.2;
-        let __nt = super::__action147::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        let __nt = super::__action154::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (8, 17)
     }
-    pub(crate) fn __reduce55<
+    pub(crate) fn __reduce56<
         'err,
         'input,
         'v,
@@ -3896,7 +4027,7 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", seq, Instr, Instr, ")" => ActionFn(148);
+        // Instr = "(", seq, Instr, Instr, ")" => ActionFn(155);
         assert!(__symbols.len() >= 5);
         let __sym4 = __pop_Variant0(__symbols);
         let __sym3 = __pop_Variant12(__symbols);
@@ -3905,11 +4036,11 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym4.2;
-        let __nt = super::__action148::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
+        let __nt = super::__action155::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (5, 17)
     }
-    pub(crate) fn __reduce56<
+    pub(crate) fn __reduce57<
         'err,
         'input,
         'v,
@@ -3922,7 +4053,7 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", par, Instr, Instr, ")" => ActionFn(149);
+        // Instr = "(", par, Instr, Instr, ")" => ActionFn(156);
         assert!(__symbols.len() >= 5);
         let __sym4 = __pop_Variant0(__symbols);
         let __sym3 = __pop_Variant12(__symbols);
@@ -3931,11 +4062,11 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym4.2;
-        let __nt = super::__action149::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
+        let __nt = super::__action156::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (5, 17)
     }
-    pub(crate) fn __reduce57<
+    pub(crate) fn __reduce58<
         'err,
         'input,
         'v,
@@ -3948,18 +4079,18 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", never, ")" => ActionFn(150);
+        // Instr = "(", never, ")" => ActionFn(157);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action150::<>(input, errors, validator, __sym0, __sym1, __sym2);
+        let __nt = super::__action157::<>(input, errors, validator, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (3, 17)
     }
-    pub(crate) fn __reduce58<
+    pub(crate) fn __reduce59<
         'err,
         'input,
         'v,
@@ -3972,18 +4103,18 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", null, ")" => ActionFn(151);
+        // Instr = "(", null, ")" => ActionFn(158);
         assert!(__symbols.len() >= 3);
         let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym2.2;
-        let __nt = super::__action151::<>(input, errors, validator, __sym0, __sym1, __sym2);
+        let __nt = super::__action158::<>(input, errors, validator, __sym0, __sym1, __sym2);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (3, 17)
     }
-    pub(crate) fn __reduce59<
+    pub(crate) fn __reduce60<
         'err,
         'input,
         'v,
@@ -3996,7 +4127,7 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", new, NewArgument, Instr, ")" => ActionFn(152);
+        // Instr = "(", new, NewArgument, Instr, ")" => ActionFn(159);
         assert!(__symbols.len() >= 5);
         let __sym4 = __pop_Variant0(__symbols);
         let __sym3 = __pop_Variant12(__symbols);
@@ -4005,11 +4136,11 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym4.2;
-        let __nt = super::__action152::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
+        let __nt = super::__action159::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (5, 17)
     }
-    pub(crate) fn __reduce60<
+    pub(crate) fn __reduce61<
         'err,
         'input,
         'v,
@@ -4022,7 +4153,7 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", fail, FailBody, ")" => ActionFn(153);
+        // Instr = "(", fail, FailBody, ")" => ActionFn(160);
         assert!(__symbols.len() >= 4);
         let __sym3 = __pop_Variant0(__symbols);
         let __sym2 = __pop_Variant20(__symbols);
@@ -4030,11 +4161,11 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym3.2;
-        let __nt = super::__action153::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3);
+        let __nt = super::__action160::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (4, 17)
     }
-    pub(crate) fn __reduce61<
+    pub(crate) fn __reduce62<
         'err,
         'input,
         'v,
@@ -4047,7 +4178,7 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", fold, FoldScalarIterable, Scalar, Instr, Instr, ")" => ActionFn(163);
+        // Instr = "(", fold, FoldScalarIterable, Scalar, Instr, Instr, ")" => ActionFn(172);
         assert!(__symbols.len() >= 7);
         let __sym6 = __pop_Variant0(__symbols);
         let __sym5 = __pop_Variant12(__symbols);
@@ -4058,11 +4189,11 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym6.2;
-        let __nt = super::__action163::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        let __nt = super::__action172::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (7, 17)
     }
-    pub(crate) fn __reduce62<
+    pub(crate) fn __reduce63<
         'err,
         'input,
         'v,
@@ -4075,7 +4206,7 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", fold, FoldScalarIterable, Scalar, Instr, ")" => ActionFn(164);
+        // Instr = "(", fold, FoldScalarIterable, Scalar, Instr, ")" => ActionFn(173);
         assert!(__symbols.len() >= 6);
         let __sym5 = __pop_Variant0(__symbols);
         let __sym4 = __pop_Variant12(__symbols);
@@ -4085,11 +4216,11 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym5.2;
-        let __nt = super::__action164::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        let __nt = super::__action173::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (6, 17)
     }
-    pub(crate) fn __reduce63<
+    pub(crate) fn __reduce64<
         'err,
         'input,
         'v,
@@ -4102,7 +4233,7 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", fold, Stream, Scalar, Instr, Instr, ")" => ActionFn(165);
+        // Instr = "(", fold, Stream, Scalar, Instr, Instr, ")" => ActionFn(174);
         assert!(__symbols.len() >= 7);
         let __sym6 = __pop_Variant0(__symbols);
         let __sym5 = __pop_Variant12(__symbols);
@@ -4113,11 +4244,11 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym6.2;
-        let __nt = super::__action165::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        let __nt = super::__action174::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (7, 17)
     }
-    pub(crate) fn __reduce64<
+    pub(crate) fn __reduce65<
         'err,
         'input,
         'v,
@@ -4130,7 +4261,7 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", fold, Stream, Scalar, Instr, ")" => ActionFn(166);
+        // Instr = "(", fold, Stream, Scalar, Instr, ")" => ActionFn(175);
         assert!(__symbols.len() >= 6);
         let __sym5 = __pop_Variant0(__symbols);
         let __sym4 = __pop_Variant12(__symbols);
@@ -4140,11 +4271,11 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym5.2;
-        let __nt = super::__action166::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        let __nt = super::__action175::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (6, 17)
     }
-    pub(crate) fn __reduce65<
+    pub(crate) fn __reduce66<
         'err,
         'input,
         'v,
@@ -4157,7 +4288,7 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", fold, StreamMap, Scalar, Instr, Instr, ")" => ActionFn(167);
+        // Instr = "(", fold, StreamMap, Scalar, Instr, Instr, ")" => ActionFn(176);
         assert!(__symbols.len() >= 7);
         let __sym6 = __pop_Variant0(__symbols);
         let __sym5 = __pop_Variant12(__symbols);
@@ -4168,11 +4299,11 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym6.2;
-        let __nt = super::__action167::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        let __nt = super::__action176::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (7, 17)
     }
-    pub(crate) fn __reduce66<
+    pub(crate) fn __reduce67<
         'err,
         'input,
         'v,
@@ -4185,7 +4316,7 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", fold, StreamMap, Scalar, Instr, ")" => ActionFn(168);
+        // Instr = "(", fold, StreamMap, Scalar, Instr, ")" => ActionFn(177);
         assert!(__symbols.len() >= 6);
         let __sym5 = __pop_Variant0(__symbols);
         let __sym4 = __pop_Variant12(__symbols);
@@ -4195,11 +4326,11 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym5.2;
-        let __nt = super::__action168::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        let __nt = super::__action177::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (6, 17)
     }
-    pub(crate) fn __reduce67<
+    pub(crate) fn __reduce68<
         'err,
         'input,
         'v,
@@ -4212,7 +4343,7 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", next, Scalar, ")" => ActionFn(157);
+        // Instr = "(", next, Scalar, ")" => ActionFn(164);
         assert!(__symbols.len() >= 4);
         let __sym3 = __pop_Variant0(__symbols);
         let __sym2 = __pop_Variant2(__symbols);
@@ -4220,11 +4351,11 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym3.2;
-        let __nt = super::__action157::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3);
+        let __nt = super::__action164::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (4, 17)
     }
-    pub(crate) fn __reduce68<
+    pub(crate) fn __reduce69<
         'err,
         'input,
         'v,
@@ -4237,7 +4368,7 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", xor, Instr, Instr, ")" => ActionFn(158);
+        // Instr = "(", xor, Instr, Instr, ")" => ActionFn(165);
         assert!(__symbols.len() >= 5);
         let __sym4 = __pop_Variant0(__symbols);
         let __sym3 = __pop_Variant12(__symbols);
@@ -4246,11 +4377,11 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym4.2;
-        let __nt = super::__action158::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
+        let __nt = super::__action165::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (5, 17)
     }
-    pub(crate) fn __reduce69<
+    pub(crate) fn __reduce70<
         'err,
         'input,
         'v,
@@ -4263,7 +4394,7 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", match_, Value, Value, Instr, ")" => ActionFn(159);
+        // Instr = "(", match_, Value, Value, Instr, ")" => ActionFn(166);
         assert!(__symbols.len() >= 6);
         let __sym5 = __pop_Variant0(__symbols);
         let __sym4 = __pop_Variant12(__symbols);
@@ -4273,11 +4404,11 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym5.2;
-        let __nt = super::__action159::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        let __nt = super::__action166::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (6, 17)
     }
-    pub(crate) fn __reduce70<
+    pub(crate) fn __reduce71<
         'err,
         'input,
         'v,
@@ -4290,7 +4421,7 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = "(", mismatch, Value, Value, Instr, ")" => ActionFn(160);
+        // Instr = "(", mismatch, Value, Value, Instr, ")" => ActionFn(167);
         assert!(__symbols.len() >= 6);
         let __sym5 = __pop_Variant0(__symbols);
         let __sym4 = __pop_Variant12(__symbols);
@@ -4300,11 +4431,38 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym5.2;
-        let __nt = super::__action160::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        let __nt = super::__action167::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (6, 17)
     }
-    pub(crate) fn __reduce71<
+    pub(crate) fn __reduce72<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Instr = "(", match_regex, Value, Literal, Instr, ")" => ActionFn(168);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant12(__symbols);
+        let __sym3 = __pop_Variant7(__symbols);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym5.2;
+        let __nt = super::__action168::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (6, 17)
+    }
+    pub(crate) fn __reduce73<
         'err,
         'input,
         'v,
@@ -4317,15 +4475,42 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr = error => ActionFn(21);
+        // Instr = "(", mismatch_regex, Value, Literal, Instr, ")" => ActionFn(169);
+        assert!(__symbols.len() >= 6);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant12(__symbols);
+        let __sym3 = __pop_Variant7(__symbols);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0;
+        let __end = __sym5.2;
+        let __nt = super::__action169::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        __symbols.push((__start, __Symbol::Variant12(__nt), __end));
+        (6, 17)
+    }
+    pub(crate) fn __reduce74<
+        'err,
+        'input,
+        'v,
+    >(
+        input: &'input str,
+        errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+        validator: &'v mut VariableValidator<'input>,
+        __lookahead_start: Option<&AirPos>,
+        __symbols: &mut alloc::vec::Vec<(AirPos,__Symbol<'input>,AirPos)>,
+        _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
+    ) -> (usize, usize)
+    {
+        // Instr = error => ActionFn(24);
         let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action21::<>(input, errors, validator, __sym0);
+        let __nt = super::__action24::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant12(__nt), __end));
         (1, 17)
     }
-    pub(crate) fn __reduce72<
+    pub(crate) fn __reduce75<
         'err,
         'input,
         'v,
@@ -4338,15 +4523,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr? = Instr => ActionFn(107);
+        // Instr? = Instr => ActionFn(110);
         let __sym0 = __pop_Variant12(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action107::<>(input, errors, validator, __sym0);
+        let __nt = super::__action110::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant23(__nt), __end));
         (1, 18)
     }
-    pub(crate) fn __reduce73<
+    pub(crate) fn __reduce76<
         'err,
         'input,
         'v,
@@ -4359,14 +4544,14 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Instr? =  => ActionFn(108);
+        // Instr? =  => ActionFn(111);
         let __start = __lookahead_start.cloned().or_else(|| __symbols.last().map(|s| s.2.clone())).unwrap_or_default();
         let __end = __start.clone();
-        let __nt = super::__action108::<>(input, errors, validator, &__start, &__end);
+        let __nt = super::__action111::<>(input, errors, validator, &__start, &__end);
         __symbols.push((__start, __Symbol::Variant23(__nt), __end));
         (0, 18)
     }
-    pub(crate) fn __reduce74<
+    pub(crate) fn __reduce77<
         'err,
         'input,
         'v,
@@ -4379,15 +4564,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // NewArgument = Scalar => ActionFn(59);
+        // NewArgument = Scalar => ActionFn(62);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action59::<>(input, errors, validator, __sym0);
+        let __nt = super::__action62::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant24(__nt), __end));
         (1, 19)
     }
-    pub(crate) fn __reduce75<
+    pub(crate) fn __reduce78<
         'err,
         'input,
         'v,
@@ -4400,15 +4585,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // NewArgument = Stream => ActionFn(60);
+        // NewArgument = Stream => ActionFn(63);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action60::<>(input, errors, validator, __sym0);
+        let __nt = super::__action63::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant24(__nt), __end));
         (1, 19)
     }
-    pub(crate) fn __reduce76<
+    pub(crate) fn __reduce79<
         'err,
         'input,
         'v,
@@ -4421,15 +4606,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // NewArgument = StreamMap => ActionFn(61);
+        // NewArgument = StreamMap => ActionFn(64);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action61::<>(input, errors, validator, __sym0);
+        let __nt = super::__action64::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant24(__nt), __end));
         (1, 19)
     }
-    pub(crate) fn __reduce77<
+    pub(crate) fn __reduce80<
         'err,
         'input,
         'v,
@@ -4442,15 +4627,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // NewArgument = CanonStream => ActionFn(62);
+        // NewArgument = CanonStream => ActionFn(65);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action62::<>(input, errors, validator, __sym0);
+        let __nt = super::__action65::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant24(__nt), __end));
         (1, 19)
     }
-    pub(crate) fn __reduce78<
+    pub(crate) fn __reduce81<
         'err,
         'input,
         'v,
@@ -4463,15 +4648,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // NewArgument = CanonStreamMap => ActionFn(63);
+        // NewArgument = CanonStreamMap => ActionFn(66);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action63::<>(input, errors, validator, __sym0);
+        let __nt = super::__action66::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant24(__nt), __end));
         (1, 19)
     }
-    pub(crate) fn __reduce79<
+    pub(crate) fn __reduce82<
         'err,
         'input,
         'v,
@@ -4484,15 +4669,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Number = I64 => ActionFn(64);
+        // Number = I64 => ActionFn(67);
         let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action64::<>(input, errors, validator, __sym0);
+        let __nt = super::__action67::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant25(__nt), __end));
         (1, 20)
     }
-    pub(crate) fn __reduce80<
+    pub(crate) fn __reduce83<
         'err,
         'input,
         'v,
@@ -4505,15 +4690,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Number = F64 => ActionFn(65);
+        // Number = F64 => ActionFn(68);
         let __sym0 = __pop_Variant5(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action65::<>(input, errors, validator, __sym0);
+        let __nt = super::__action68::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant25(__nt), __end));
         (1, 20)
     }
-    pub(crate) fn __reduce81<
+    pub(crate) fn __reduce84<
         'err,
         'input,
         'v,
@@ -4526,15 +4711,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // PeerId = ResolvableToPeerIdVariable => ActionFn(45);
+        // PeerId = ResolvableToPeerIdVariable => ActionFn(48);
         let __sym0 = __pop_Variant26(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action45::<>(input, errors, validator, __sym0);
+        let __nt = super::__action48::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant26(__nt), __end));
         (1, 21)
     }
-    pub(crate) fn __reduce82<
+    pub(crate) fn __reduce85<
         'err,
         'input,
         'v,
@@ -4547,15 +4732,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToPeerIdVariable = InitPeerId => ActionFn(48);
+        // ResolvableToPeerIdVariable = InitPeerId => ActionFn(51);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action48::<>(input, errors, validator, __sym0);
+        let __nt = super::__action51::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant26(__nt), __end));
         (1, 22)
     }
-    pub(crate) fn __reduce83<
+    pub(crate) fn __reduce86<
         'err,
         'input,
         'v,
@@ -4568,15 +4753,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToPeerIdVariable = Literal => ActionFn(49);
+        // ResolvableToPeerIdVariable = Literal => ActionFn(52);
         let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action49::<>(input, errors, validator, __sym0);
+        let __nt = super::__action52::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant26(__nt), __end));
         (1, 22)
     }
-    pub(crate) fn __reduce84<
+    pub(crate) fn __reduce87<
         'err,
         'input,
         'v,
@@ -4589,15 +4774,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToPeerIdVariable = Scalar => ActionFn(50);
+        // ResolvableToPeerIdVariable = Scalar => ActionFn(53);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action50::<>(input, errors, validator, __sym0);
+        let __nt = super::__action53::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant26(__nt), __end));
         (1, 22)
     }
-    pub(crate) fn __reduce85<
+    pub(crate) fn __reduce88<
         'err,
         'input,
         'v,
@@ -4610,15 +4795,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToPeerIdVariable = ScalarWithLambda => ActionFn(51);
+        // ResolvableToPeerIdVariable = ScalarWithLambda => ActionFn(54);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action51::<>(input, errors, validator, __sym0);
+        let __nt = super::__action54::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant26(__nt), __end));
         (1, 22)
     }
-    pub(crate) fn __reduce86<
+    pub(crate) fn __reduce89<
         'err,
         'input,
         'v,
@@ -4631,15 +4816,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToPeerIdVariable = CanonStreamWithLambda => ActionFn(52);
+        // ResolvableToPeerIdVariable = CanonStreamWithLambda => ActionFn(55);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action52::<>(input, errors, validator, __sym0);
+        let __nt = super::__action55::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant26(__nt), __end));
         (1, 22)
     }
-    pub(crate) fn __reduce87<
+    pub(crate) fn __reduce90<
         'err,
         'input,
         'v,
@@ -4652,15 +4837,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToPeerIdVariable = CanonStreamMapWithLambda => ActionFn(53);
+        // ResolvableToPeerIdVariable = CanonStreamMapWithLambda => ActionFn(56);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action53::<>(input, errors, validator, __sym0);
+        let __nt = super::__action56::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant26(__nt), __end));
         (1, 22)
     }
-    pub(crate) fn __reduce88<
+    pub(crate) fn __reduce91<
         'err,
         'input,
         'v,
@@ -4673,15 +4858,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToStringVariable = Literal => ActionFn(54);
+        // ResolvableToStringVariable = Literal => ActionFn(57);
         let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action54::<>(input, errors, validator, __sym0);
+        let __nt = super::__action57::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant22(__nt), __end));
         (1, 23)
     }
-    pub(crate) fn __reduce89<
+    pub(crate) fn __reduce92<
         'err,
         'input,
         'v,
@@ -4694,15 +4879,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToStringVariable = Scalar => ActionFn(55);
+        // ResolvableToStringVariable = Scalar => ActionFn(58);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action55::<>(input, errors, validator, __sym0);
+        let __nt = super::__action58::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant22(__nt), __end));
         (1, 23)
     }
-    pub(crate) fn __reduce90<
+    pub(crate) fn __reduce93<
         'err,
         'input,
         'v,
@@ -4715,15 +4900,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToStringVariable = ScalarWithLambda => ActionFn(56);
+        // ResolvableToStringVariable = ScalarWithLambda => ActionFn(59);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action56::<>(input, errors, validator, __sym0);
+        let __nt = super::__action59::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant22(__nt), __end));
         (1, 23)
     }
-    pub(crate) fn __reduce91<
+    pub(crate) fn __reduce94<
         'err,
         'input,
         'v,
@@ -4736,15 +4921,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToStringVariable = CanonStreamWithLambda => ActionFn(57);
+        // ResolvableToStringVariable = CanonStreamWithLambda => ActionFn(60);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action57::<>(input, errors, validator, __sym0);
+        let __nt = super::__action60::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant22(__nt), __end));
         (1, 23)
     }
-    pub(crate) fn __reduce92<
+    pub(crate) fn __reduce95<
         'err,
         'input,
         'v,
@@ -4757,15 +4942,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ResolvableToStringVariable = CanonStreamMapWithLambda => ActionFn(58);
+        // ResolvableToStringVariable = CanonStreamMapWithLambda => ActionFn(61);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action58::<>(input, errors, validator, __sym0);
+        let __nt = super::__action61::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant22(__nt), __end));
         (1, 23)
     }
-    pub(crate) fn __reduce93<
+    pub(crate) fn __reduce96<
         'err,
         'input,
         'v,
@@ -4778,15 +4963,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // ServiceId = ResolvableToStringVariable => ActionFn(47);
+        // ServiceId = ResolvableToStringVariable => ActionFn(50);
         let __sym0 = __pop_Variant22(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action47::<>(input, errors, validator, __sym0);
+        let __nt = super::__action50::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant22(__nt), __end));
         (1, 24)
     }
-    pub(crate) fn __reduce94<
+    pub(crate) fn __reduce97<
         'err,
         'input,
         'v,
@@ -4799,15 +4984,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // StreamArgument = Stream => ActionFn(100);
+        // StreamArgument = Stream => ActionFn(103);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action100::<>(input, errors, validator, __sym0);
+        let __nt = super::__action103::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant27(__nt), __end));
         (1, 25)
     }
-    pub(crate) fn __reduce95<
+    pub(crate) fn __reduce98<
         'err,
         'input,
         'v,
@@ -4820,15 +5005,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // StreamMapArgument = StreamMap => ActionFn(101);
+        // StreamMapArgument = StreamMap => ActionFn(104);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action101::<>(input, errors, validator, __sym0);
+        let __nt = super::__action104::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant28(__nt), __end));
         (1, 26)
     }
-    pub(crate) fn __reduce96<
+    pub(crate) fn __reduce99<
         'err,
         'input,
         'v,
@@ -4841,15 +5026,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // StreamMapKeyClause = Literal => ActionFn(26);
+        // StreamMapKeyClause = Literal => ActionFn(29);
         let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action26::<>(input, errors, validator, __sym0);
+        let __nt = super::__action29::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant29(__nt), __end));
         (1, 27)
     }
-    pub(crate) fn __reduce97<
+    pub(crate) fn __reduce100<
         'err,
         'input,
         'v,
@@ -4862,15 +5047,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // StreamMapKeyClause = I64 => ActionFn(27);
+        // StreamMapKeyClause = I64 => ActionFn(30);
         let __sym0 = __pop_Variant6(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action27::<>(input, errors, validator, __sym0);
+        let __nt = super::__action30::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant29(__nt), __end));
         (1, 27)
     }
-    pub(crate) fn __reduce98<
+    pub(crate) fn __reduce101<
         'err,
         'input,
         'v,
@@ -4883,15 +5068,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // StreamMapKeyClause = Scalar => ActionFn(28);
+        // StreamMapKeyClause = Scalar => ActionFn(31);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action28::<>(input, errors, validator, __sym0);
+        let __nt = super::__action31::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant29(__nt), __end));
         (1, 27)
     }
-    pub(crate) fn __reduce99<
+    pub(crate) fn __reduce102<
         'err,
         'input,
         'v,
@@ -4904,15 +5089,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // StreamMapKeyClause = ScalarWithLambda => ActionFn(29);
+        // StreamMapKeyClause = ScalarWithLambda => ActionFn(32);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action29::<>(input, errors, validator, __sym0);
+        let __nt = super::__action32::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant29(__nt), __end));
         (1, 27)
     }
-    pub(crate) fn __reduce100<
+    pub(crate) fn __reduce103<
         'err,
         'input,
         'v,
@@ -4925,15 +5110,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // StreamMapKeyClause = CanonStreamWithLambda => ActionFn(30);
+        // StreamMapKeyClause = CanonStreamWithLambda => ActionFn(33);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action30::<>(input, errors, validator, __sym0);
+        let __nt = super::__action33::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant29(__nt), __end));
         (1, 27)
     }
-    pub(crate) fn __reduce101<
+    pub(crate) fn __reduce104<
         'err,
         'input,
         'v,
@@ -4946,7 +5131,7 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Triplet = PeerId, "(", ServiceId, Function, ")" => ActionFn(23);
+        // Triplet = PeerId, "(", ServiceId, Function, ")" => ActionFn(26);
         assert!(__symbols.len() >= 5);
         let __sym4 = __pop_Variant0(__symbols);
         let __sym3 = __pop_Variant22(__symbols);
@@ -4955,11 +5140,11 @@ mod __parse__AIR {
         let __sym0 = __pop_Variant26(__symbols);
         let __start = __sym0.0;
         let __end = __sym4.2;
-        let __nt = super::__action23::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
+        let __nt = super::__action26::<>(input, errors, validator, __sym0, __sym1, __sym2, __sym3, __sym4);
         __symbols.push((__start, __Symbol::Variant30(__nt), __end));
         (5, 28)
     }
-    pub(crate) fn __reduce102<
+    pub(crate) fn __reduce105<
         'err,
         'input,
         'v,
@@ -4972,15 +5157,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = InitPeerId => ActionFn(67);
+        // Value = InitPeerId => ActionFn(70);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action67::<>(input, errors, validator, __sym0);
+        let __nt = super::__action70::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (1, 29)
     }
-    pub(crate) fn __reduce103<
+    pub(crate) fn __reduce106<
         'err,
         'input,
         'v,
@@ -4993,15 +5178,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = LastError => ActionFn(68);
+        // Value = LastError => ActionFn(71);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action68::<>(input, errors, validator, __sym0);
+        let __nt = super::__action71::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (1, 29)
     }
-    pub(crate) fn __reduce104<
+    pub(crate) fn __reduce107<
         'err,
         'input,
         'v,
@@ -5014,15 +5199,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = LastErrorWithLambda => ActionFn(69);
+        // Value = LastErrorWithLambda => ActionFn(72);
         let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action69::<>(input, errors, validator, __sym0);
+        let __nt = super::__action72::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (1, 29)
     }
-    pub(crate) fn __reduce105<
+    pub(crate) fn __reduce108<
         'err,
         'input,
         'v,
@@ -5035,15 +5220,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = Error => ActionFn(70);
+        // Value = Error => ActionFn(73);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action70::<>(input, errors, validator, __sym0);
+        let __nt = super::__action73::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (1, 29)
     }
-    pub(crate) fn __reduce106<
+    pub(crate) fn __reduce109<
         'err,
         'input,
         'v,
@@ -5056,15 +5241,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = ErrorWithLambda => ActionFn(71);
+        // Value = ErrorWithLambda => ActionFn(74);
         let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action71::<>(input, errors, validator, __sym0);
+        let __nt = super::__action74::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (1, 29)
     }
-    pub(crate) fn __reduce107<
+    pub(crate) fn __reduce110<
         'err,
         'input,
         'v,
@@ -5077,15 +5262,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = Literal => ActionFn(72);
+        // Value = Literal => ActionFn(75);
         let __sym0 = __pop_Variant7(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action72::<>(input, errors, validator, __sym0);
+        let __nt = super::__action75::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (1, 29)
     }
-    pub(crate) fn __reduce108<
+    pub(crate) fn __reduce111<
         'err,
         'input,
         'v,
@@ -5098,15 +5283,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = Timestamp => ActionFn(73);
+        // Value = Timestamp => ActionFn(76);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action73::<>(input, errors, validator, __sym0);
+        let __nt = super::__action76::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (1, 29)
     }
-    pub(crate) fn __reduce109<
+    pub(crate) fn __reduce112<
         'err,
         'input,
         'v,
@@ -5119,15 +5304,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = TTL => ActionFn(74);
+        // Value = TTL => ActionFn(77);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action74::<>(input, errors, validator, __sym0);
+        let __nt = super::__action77::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (1, 29)
     }
-    pub(crate) fn __reduce110<
+    pub(crate) fn __reduce113<
         'err,
         'input,
         'v,
@@ -5140,15 +5325,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = Number => ActionFn(75);
+        // Value = Number => ActionFn(78);
         let __sym0 = __pop_Variant25(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action75::<>(input, errors, validator, __sym0);
+        let __nt = super::__action78::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (1, 29)
     }
-    pub(crate) fn __reduce111<
+    pub(crate) fn __reduce114<
         'err,
         'input,
         'v,
@@ -5161,15 +5346,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = Boolean => ActionFn(76);
+        // Value = Boolean => ActionFn(79);
         let __sym0 = __pop_Variant1(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action76::<>(input, errors, validator, __sym0);
+        let __nt = super::__action79::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (1, 29)
     }
-    pub(crate) fn __reduce112<
+    pub(crate) fn __reduce115<
         'err,
         'input,
         'v,
@@ -5182,17 +5367,17 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = "[", "]" => ActionFn(77);
+        // Value = "[", "]" => ActionFn(80);
         assert!(__symbols.len() >= 2);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0;
         let __end = __sym1.2;
-        let __nt = super::__action77::<>(input, errors, validator, __sym0, __sym1);
+        let __nt = super::__action80::<>(input, errors, validator, __sym0, __sym1);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (2, 29)
     }
-    pub(crate) fn __reduce113<
+    pub(crate) fn __reduce116<
         'err,
         'input,
         'v,
@@ -5205,15 +5390,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = Scalar => ActionFn(78);
+        // Value = Scalar => ActionFn(81);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action78::<>(input, errors, validator, __sym0);
+        let __nt = super::__action81::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (1, 29)
     }
-    pub(crate) fn __reduce114<
+    pub(crate) fn __reduce117<
         'err,
         'input,
         'v,
@@ -5226,15 +5411,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = ScalarWithLambda => ActionFn(79);
+        // Value = ScalarWithLambda => ActionFn(82);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action79::<>(input, errors, validator, __sym0);
+        let __nt = super::__action82::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (1, 29)
     }
-    pub(crate) fn __reduce115<
+    pub(crate) fn __reduce118<
         'err,
         'input,
         'v,
@@ -5247,15 +5432,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = CanonStream => ActionFn(80);
+        // Value = CanonStream => ActionFn(83);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action80::<>(input, errors, validator, __sym0);
+        let __nt = super::__action83::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (1, 29)
     }
-    pub(crate) fn __reduce116<
+    pub(crate) fn __reduce119<
         'err,
         'input,
         'v,
@@ -5268,15 +5453,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = CanonStreamWithLambda => ActionFn(81);
+        // Value = CanonStreamWithLambda => ActionFn(84);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action81::<>(input, errors, validator, __sym0);
+        let __nt = super::__action84::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (1, 29)
     }
-    pub(crate) fn __reduce117<
+    pub(crate) fn __reduce120<
         'err,
         'input,
         'v,
@@ -5289,15 +5474,15 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = CanonStreamMap => ActionFn(82);
+        // Value = CanonStreamMap => ActionFn(85);
         let __sym0 = __pop_Variant2(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action82::<>(input, errors, validator, __sym0);
+        let __nt = super::__action85::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (1, 29)
     }
-    pub(crate) fn __reduce118<
+    pub(crate) fn __reduce121<
         'err,
         'input,
         'v,
@@ -5310,11 +5495,11 @@ mod __parse__AIR {
         _: core::marker::PhantomData<(&'err (), &'input (), &'v ())>,
     ) -> (usize, usize)
     {
-        // Value = CanonStreamMapWithLambda => ActionFn(83);
+        // Value = CanonStreamMapWithLambda => ActionFn(86);
         let __sym0 = __pop_Variant3(__symbols);
         let __start = __sym0.0;
         let __end = __sym0.2;
-        let __nt = super::__action83::<>(input, errors, validator, __sym0);
+        let __nt = super::__action86::<>(input, errors, validator, __sym0);
         __symbols.push((__start, __Symbol::Variant9(__nt), __end));
         (1, 29)
     }
@@ -5421,6 +5606,36 @@ fn __action4<
     'err,
     'input,
     'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, peer_id, _): (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
+    (_, stream, _): (AirPos, Stream<'input>, AirPos),
+    (_, canon_stream, _): (AirPos, CanonStream<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Instruction<'input>
+{
+    {
+        let canon_sorted = CanonSorted::new(peer_id, stream, canon_stream);
+
+        let span = Span::new(left, right);
+        validator.met_canon_sorted(&canon_sorted, span);
+
+        Instruction::CanonSorted(canon_sorted.into())
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action5<
+    'err,
+    'input,
+    'v,
 >(
     input: &'input str,
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
@@ -5447,7 +5662,7 @@ fn __action4<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action5<
+fn __action6<
     'err,
     'input,
     'v,
@@ -5478,7 +5693,7 @@ fn __action5<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action6<
+fn __action7<
     'err,
     'input,
     'v,
@@ -5507,7 +5722,7 @@ fn __action6<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action7<
+fn __action8<
     'err,
     'input,
     'v,
@@ -5540,7 +5755,7 @@ fn __action7<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action8<
+fn __action9<
     'err,
     'input,
     'v,
@@ -5559,7 +5774,7 @@ fn __action8<
 {
     {
         let span = Span::new(left, right);
-        validator.met_merging_instr(span);
+        validator.met_seq(span);
 
         Instruction::Seq(Seq::new(l, r).into())
     }
@@ -5567,7 +5782,7 @@ fn __action8<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action9<
+fn __action10<
     'err,
     'input,
     'v,
@@ -5586,7 +5801,7 @@ fn __action9<
 {
     {
         let span = Span::new(left, right);
-        validator.met_merging_instr(span);
+        validator.met_par(span);
 
         Instruction::Par(Par::new(l, r).into())
     }
@@ -5594,7 +5809,7 @@ fn __action9<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action10<
+fn __action11<
     'err,
     'input,
     'v,
@@ -5611,7 +5826,7 @@ fn __action10<
 {
     {
         let span = Span::new(left, right);
-        validator.met_simple_instr(span);
+        validator.met_never(span);
 
         Instruction::Never(Never)
     }
@@ -5619,7 +5834,7 @@ fn __action10<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action11<
+fn __action12<
     'err,
     'input,
     'v,
@@ -5636,7 +5851,7 @@ fn __action11<
 {
     {
         let span = Span::new(left, right);
-        validator.met_simple_instr(span);
+        validator.met_null(span);
 
         Instruction::Null(Null)
     }
@@ -5644,7 +5859,7 @@ fn __action11<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action12<
+fn __action13<
     'err,
     'input,
     'v,
@@ -5673,7 +5888,7 @@ fn __action12<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action13<
+fn __action14<
     'err,
     'input,
     'v,
@@ -5699,7 +5914,7 @@ fn __action13<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action14<
+fn __action15<
     'err,
     'input,
     'v,
@@ -5731,7 +5946,7 @@ fn __action14<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action15<
+fn __action16<
     'err,
     'input,
     'v,
@@ -5764,7 +5979,7 @@ fn __action15<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action16<
+fn __action17<
     'err,
     'input,
     'v,
@@ -5795,7 +6010,7 @@ fn __action16<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action17<
+fn __action18<
     'err,
     'input,
     'v,
@@ -5823,7 +6038,7 @@ fn __action17<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action18<
+fn __action19<
     'err,
     'input,
     'v,
@@ -5850,7 +6065,7 @@ fn __action18<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action19<
+fn __action20<
     'err,
     'input,
     'v,
@@ -5879,7 +6094,7 @@ fn __action19<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action20<
+fn __action21<
     'err,
     'input,
     'v,
@@ -5908,7 +6123,65 @@ fn __action20<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action21<
+fn __action22<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, v, _): (AirPos, ImmutableValue<'input>, AirPos),
+    (_, p, _): (AirPos, &'input str, AirPos),
+    (_, i, _): (AirPos, Instruction<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Instruction<'input>
+{
+    {
+        let span = Span::new(left, right);
+        match validator.met_regex_match(&v, p, span) {
+            Some(pattern) => Instruction::MatchRegex(MatchRegex::new(v, pattern, i).into()),
+            None => Instruction::Error,
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action23<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    (_, left, _): (AirPos, AirPos, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, v, _): (AirPos, ImmutableValue<'input>, AirPos),
+    (_, p, _): (AirPos, &'input str, AirPos),
+    (_, i, _): (AirPos, Instruction<'input>, AirPos),
+    (_, _, _): (AirPos, Token<'input>, AirPos),
+    (_, right, _): (AirPos, AirPos, AirPos),
+) -> Instruction<'input>
+{
+    {
+        let span = Span::new(left, right);
+        match validator.met_regex_match(&v, p, span) {
+            Some(pattern) => Instruction::MisMatchRegex(MisMatchRegex::new(v, pattern, i).into()),
+            None => Instruction::Error,
+        }
+    }
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action24<
     'err,
     'input,
     'v,
@@ -5924,7 +6197,7 @@ fn __action21<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action22<
+fn __action25<
     'err,
     'input,
     'v,
@@ -5942,7 +6215,7 @@ fn __action22<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action23<
+fn __action26<
     'err,
     'input,
     'v,
@@ -5966,7 +6239,7 @@ fn __action23<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action24<
+fn __action27<
     'err,
     'input,
     'v,
@@ -5982,7 +6255,7 @@ fn __action24<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action25<
+fn __action28<
     'err,
     'input,
     'v,
@@ -5998,7 +6271,7 @@ fn __action25<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action26<
+fn __action29<
     'err,
     'input,
     'v,
@@ -6014,7 +6287,7 @@ fn __action26<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action27<
+fn __action30<
     'err,
     'input,
     'v,
@@ -6030,7 +6303,7 @@ fn __action27<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action28<
+fn __action31<
     'err,
     'input,
     'v,
@@ -6046,7 +6319,7 @@ fn __action28<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action29<
+fn __action32<
     'err,
     'input,
     'v,
@@ -6062,7 +6335,7 @@ fn __action29<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action30<
+fn __action33<
     'err,
     'input,
     'v,
@@ -6078,7 +6351,7 @@ fn __action30<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action31<
+fn __action34<
     'err,
     'input,
     'v,
@@ -6094,7 +6367,7 @@ fn __action31<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action32<
+fn __action35<
     'err,
     'input,
     'v,
@@ -6110,7 +6383,7 @@ fn __action32<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action33<
+fn __action36<
     'err,
     'input,
     'v,
@@ -6126,7 +6399,7 @@ fn __action33<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action34<
+fn __action37<
     'err,
     'input,
     'v,
@@ -6142,7 +6415,7 @@ fn __action34<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action35<
+fn __action38<
     'err,
     'input,
     'v,
@@ -6162,7 +6435,7 @@ fn __action35<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action36<
+fn __action39<
     'err,
     'input,
     'v,
@@ -6178,7 +6451,7 @@ fn __action36<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action37<
+fn __action40<
     'err,
     'input,
     'v,
@@ -6198,7 +6471,7 @@ fn __action37<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action38<
+fn __action41<
     'err,
     'input,
     'v,
@@ -6218,7 +6491,7 @@ fn __action38<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action39<
+fn __action42<
     'err,
     'input,
     'v,
@@ -6234,7 +6507,7 @@ fn __action39<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action40<
+fn __action43<
     'err,
     'input,
     'v,
@@ -6250,7 +6523,7 @@ fn __action40<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action41<
+fn __action44<
     'err,
     'input,
     'v,
@@ -6266,7 +6539,7 @@ fn __action41<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action42<
+fn __action45<
     'err,
     'input,
     'v,
@@ -6282,7 +6555,7 @@ fn __action42<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action43<
+fn __action46<
     'err,
     'input,
     'v,
@@ -6298,7 +6571,7 @@ fn __action43<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action44<
+fn __action47<
     'err,
     'input,
     'v,
@@ -6315,7 +6588,7 @@ fn __action44<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action45<
+fn __action48<
     'err,
     'input,
     'v,
@@ -6331,7 +6604,7 @@ fn __action45<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action46<
+fn __action49<
     'err,
     'input,
     'v,
@@ -6347,7 +6620,7 @@ fn __action46<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action47<
+fn __action50<
     'err,
     'input,
     'v,
@@ -6363,7 +6636,7 @@ fn __action47<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action48<
+fn __action51<
     'err,
     'input,
     'v,
@@ -6379,7 +6652,7 @@ fn __action48<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action49<
+fn __action52<
     'err,
     'input,
     'v,
@@ -6395,7 +6668,7 @@ fn __action49<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action50<
+fn __action53<
     'err,
     'input,
     'v,
@@ -6411,7 +6684,7 @@ fn __action50<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action51<
+fn __action54<
     'err,
     'input,
     'v,
@@ -6427,7 +6700,7 @@ fn __action51<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action52<
+fn __action55<
     'err,
     'input,
     'v,
@@ -6443,7 +6716,7 @@ fn __action52<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action53<
+fn __action56<
     'err,
     'input,
     'v,
@@ -6459,7 +6732,7 @@ fn __action53<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action54<
+fn __action57<
     'err,
     'input,
     'v,
@@ -6475,7 +6748,7 @@ fn __action54<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action55<
+fn __action58<
     'err,
     'input,
     'v,
@@ -6491,7 +6764,7 @@ fn __action55<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action56<
+fn __action59<
     'err,
     'input,
     'v,
@@ -6507,7 +6780,7 @@ fn __action56<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action57<
+fn __action60<
     'err,
     'input,
     'v,
@@ -6523,7 +6796,7 @@ fn __action57<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action58<
+fn __action61<
     'err,
     'input,
     'v,
@@ -6539,7 +6812,7 @@ fn __action58<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action59<
+fn __action62<
     'err,
     'input,
     'v,
@@ -6555,7 +6828,7 @@ fn __action59<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action60<
+fn __action63<
     'err,
     'input,
     'v,
@@ -6571,7 +6844,7 @@ fn __action60<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action61<
+fn __action64<
     'err,
     'input,
     'v,
@@ -6587,7 +6860,7 @@ fn __action61<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action62<
+fn __action65<
     'err,
     'input,
     'v,
@@ -6603,7 +6876,7 @@ fn __action62<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action63<
+fn __action66<
     'err,
     'input,
     'v,
@@ -6619,7 +6892,7 @@ fn __action63<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action64<
+fn __action67<
     'err,
     'input,
     'v,
@@ -6635,7 +6908,7 @@ fn __action64<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action65<
+fn __action68<
     'err,
     'input,
     'v,
@@ -6651,7 +6924,7 @@ fn __action65<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action66<
+fn __action69<
     'err,
     'input,
     'v,
@@ -6667,7 +6940,7 @@ fn __action66<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action67<
+fn __action70<
     'err,
     'input,
     'v,
@@ -6683,7 +6956,7 @@ fn __action67<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action68<
+fn __action71<
     'err,
     'input,
     'v,
@@ -6699,7 +6972,7 @@ fn __action68<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action69<
+fn __action72<
     'err,
     'input,
     'v,
@@ -6715,7 +6988,7 @@ fn __action69<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action70<
+fn __action73<
     'err,
     'input,
     'v,
@@ -6731,7 +7004,7 @@ fn __action70<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action71<
+fn __action74<
     'err,
     'input,
     'v,
@@ -6747,7 +7020,7 @@ fn __action71<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action72<
+fn __action75<
     'err,
     'input,
     'v,
@@ -6763,7 +7036,7 @@ fn __action72<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action73<
+fn __action76<
     'err,
     'input,
     'v,
@@ -6779,7 +7052,7 @@ fn __action73<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action74<
+fn __action77<
     'err,
     'input,
     'v,
@@ -6795,7 +7068,7 @@ fn __action74<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action75<
+fn __action78<
     'err,
     'input,
     'v,
@@ -6811,7 +7084,7 @@ fn __action75<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action76<
+fn __action79<
     'err,
     'input,
     'v,
@@ -6827,7 +7100,7 @@ fn __action76<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action77<
+fn __action80<
     'err,
     'input,
     'v,
@@ -6844,7 +7117,7 @@ fn __action77<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action78<
+fn __action81<
     'err,
     'input,
     'v,
@@ -6860,7 +7133,7 @@ fn __action78<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action79<
+fn __action82<
     'err,
     'input,
     'v,
@@ -6876,7 +7149,7 @@ fn __action79<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action80<
+fn __action83<
     'err,
     'input,
     'v,
@@ -6892,7 +7165,7 @@ fn __action80<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action81<
+fn __action84<
     'err,
     'input,
     'v,
@@ -6908,7 +7181,7 @@ fn __action81<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action82<
+fn __action85<
     'err,
     'input,
     'v,
@@ -6924,7 +7197,7 @@ fn __action82<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action83<
+fn __action86<
     'err,
     'input,
     'v,
@@ -6940,7 +7213,7 @@ fn __action83<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action84<
+fn __action87<
     'err,
     'input,
     'v,
@@ -6956,7 +7229,7 @@ fn __action84<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action85<
+fn __action88<
     'err,
     'input,
     'v,
@@ -6972,7 +7245,7 @@ fn __action85<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action86<
+fn __action89<
     'err,
     'input,
     'v,
@@ -6988,7 +7261,7 @@ fn __action86<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action87<
+fn __action90<
     'err,
     'input,
     'v,
@@ -7004,7 +7277,7 @@ fn __action87<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action88<
+fn __action91<
     'err,
     'input,
     'v,
@@ -7020,7 +7293,7 @@ fn __action88<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action89<
+fn __action92<
     'err,
     'input,
     'v,
@@ -7036,7 +7309,7 @@ fn __action89<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action90<
+fn __action93<
     'err,
     'input,
     'v,
@@ -7052,7 +7325,7 @@ fn __action90<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action91<
+fn __action94<
     'err,
     'input,
     'v,
@@ -7068,7 +7341,7 @@ fn __action91<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action92<
+fn __action95<
     'err,
     'input,
     'v,
@@ -7084,7 +7357,7 @@ fn __action92<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action93<
+fn __action96<
     'err,
     'input,
     'v,
@@ -7100,7 +7373,7 @@ fn __action93<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action94<
+fn __action97<
     'err,
     'input,
     'v,
@@ -7117,7 +7390,7 @@ fn __action94<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action95<
+fn __action98<
     'err,
     'input,
     'v,
@@ -7133,7 +7406,7 @@ fn __action95<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action96<
+fn __action99<
     'err,
     'input,
     'v,
@@ -7149,7 +7422,7 @@ fn __action96<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action97<
+fn __action100<
     'err,
     'input,
     'v,
@@ -7165,7 +7438,7 @@ fn __action97<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action98<
+fn __action101<
     'err,
     'input,
     'v,
@@ -7181,7 +7454,7 @@ fn __action98<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action99<
+fn __action102<
     'err,
     'input,
     'v,
@@ -7197,7 +7470,7 @@ fn __action99<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action100<
+fn __action103<
     'err,
     'input,
     'v,
@@ -7213,7 +7486,7 @@ fn __action100<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action101<
+fn __action104<
     'err,
     'input,
     'v,
@@ -7229,7 +7502,7 @@ fn __action101<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action102<
+fn __action105<
     'err,
     'input,
     'v,
@@ -7245,7 +7518,7 @@ fn __action102<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action103<
+fn __action106<
     'err,
     'input,
     'v,
@@ -7261,7 +7534,7 @@ fn __action103<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action104<
+fn __action107<
     'err,
     'input,
     'v,
@@ -7278,7 +7551,7 @@ fn __action104<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action105<
+fn __action108<
     'err,
     'input,
     'v,
@@ -7294,7 +7567,7 @@ fn __action105<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action106<
+fn __action109<
     'err,
     'input,
     'v,
@@ -7310,7 +7583,7 @@ fn __action106<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action107<
+fn __action110<
     'err,
     'input,
     'v,
@@ -7326,7 +7599,7 @@ fn __action107<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action108<
+fn __action111<
     'err,
     'input,
     'v,
@@ -7342,7 +7615,7 @@ fn __action108<
 }
 
 #[allow(unused_variables)]
-fn __action109<
+fn __action112<
     'err,
     'input,
     'v,
@@ -7359,7 +7632,7 @@ fn __action109<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action110<
+fn __action113<
     'err,
     'input,
     'v,
@@ -7375,7 +7648,7 @@ fn __action110<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action111<
+fn __action114<
     'err,
     'input,
     'v,
@@ -7391,7 +7664,7 @@ fn __action111<
 }
 
 #[allow(unused_variables)]
-fn __action112<
+fn __action115<
     'err,
     'input,
     'v,
@@ -7408,7 +7681,7 @@ fn __action112<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action113<
+fn __action116<
     'err,
     'input,
     'v,
@@ -7424,7 +7697,7 @@ fn __action113<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action114<
+fn __action117<
     'err,
     'input,
     'v,
@@ -7441,7 +7714,7 @@ fn __action114<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action115<
+fn __action118<
     'err,
     'input,
     'v,
@@ -7454,14 +7727,14 @@ fn __action115<
 {
     let __start0 = __0.0;
     let __end0 = __0.2;
-    let __temp0 = __action106(
+    let __temp0 = __action109(
         input,
         errors,
         validator,
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action113(
+    __action116(
         input,
         errors,
         validator,
@@ -7471,7 +7744,7 @@ fn __action115<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action116<
+fn __action119<
     'err,
     'input,
     'v,
@@ -7485,14 +7758,14 @@ fn __action116<
 {
     let __start0 = __1.0;
     let __end0 = __1.2;
-    let __temp0 = __action106(
+    let __temp0 = __action109(
         input,
         errors,
         validator,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action114(
+    __action117(
         input,
         errors,
         validator,
@@ -7503,7 +7776,7 @@ fn __action116<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action117<
+fn __action120<
     'err,
     'input,
     'v,
@@ -7517,7 +7790,7 @@ fn __action117<
 {
     let __start0 = __0.2;
     let __end0 = __1.0;
-    let __temp0 = __action104(
+    let __temp0 = __action107(
         input,
         errors,
         validator,
@@ -7525,7 +7798,7 @@ fn __action117<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action22(
+    __action25(
         input,
         errors,
         validator,
@@ -7537,7 +7810,7 @@ fn __action117<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action118<
+fn __action121<
     'err,
     'input,
     'v,
@@ -7552,14 +7825,14 @@ fn __action118<
 {
     let __start0 = __1.0;
     let __end0 = __1.2;
-    let __temp0 = __action105(
+    let __temp0 = __action108(
         input,
         errors,
         validator,
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action22(
+    __action25(
         input,
         errors,
         validator,
@@ -7571,7 +7844,7 @@ fn __action118<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action119<
+fn __action122<
     'err,
     'input,
     'v,
@@ -7585,7 +7858,7 @@ fn __action119<
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action112(
+    let __temp0 = __action115(
         input,
         errors,
         validator,
@@ -7593,7 +7866,7 @@ fn __action119<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action37(
+    __action40(
         input,
         errors,
         validator,
@@ -7605,7 +7878,7 @@ fn __action119<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action120<
+fn __action123<
     'err,
     'input,
     'v,
@@ -7619,7 +7892,7 @@ fn __action120<
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action112(
+    let __temp0 = __action115(
         input,
         errors,
         validator,
@@ -7627,7 +7900,7 @@ fn __action120<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action38(
+    __action41(
         input,
         errors,
         validator,
@@ -7639,7 +7912,7 @@ fn __action120<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action121<
+fn __action124<
     'err,
     'input,
     'v,
@@ -7658,7 +7931,7 @@ fn __action121<
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action112(
+    let __temp0 = __action115(
         input,
         errors,
         validator,
@@ -7683,7 +7956,7 @@ fn __action121<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action122<
+fn __action125<
     'err,
     'input,
     'v,
@@ -7702,7 +7975,7 @@ fn __action122<
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action112(
+    let __temp0 = __action115(
         input,
         errors,
         validator,
@@ -7727,7 +8000,51 @@ fn __action122<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action123<
+fn __action126<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
+    __3: (AirPos, Stream<'input>, AirPos),
+    __4: (AirPos, CanonStream<'input>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+    __6: (AirPos, AirPos, AirPos),
+) -> Instruction<'input>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action115(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action4(
+        input,
+        errors,
+        validator,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action127<
     'err,
     'input,
     'v,
@@ -7746,7 +8063,7 @@ fn __action123<
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action112(
+    let __temp0 = __action115(
         input,
         errors,
         validator,
@@ -7754,7 +8071,7 @@ fn __action123<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action4(
+    __action5(
         input,
         errors,
         validator,
@@ -7771,7 +8088,7 @@ fn __action123<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action124<
+fn __action128<
     'err,
     'input,
     'v,
@@ -7790,7 +8107,7 @@ fn __action124<
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action112(
+    let __temp0 = __action115(
         input,
         errors,
         validator,
@@ -7798,7 +8115,7 @@ fn __action124<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action5(
+    __action6(
         input,
         errors,
         validator,
@@ -7815,7 +8132,7 @@ fn __action124<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action125<
+fn __action129<
     'err,
     'input,
     'v,
@@ -7833,7 +8150,7 @@ fn __action125<
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action112(
+    let __temp0 = __action115(
         input,
         errors,
         validator,
@@ -7841,7 +8158,7 @@ fn __action125<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action6(
+    __action7(
         input,
         errors,
         validator,
@@ -7857,7 +8174,7 @@ fn __action125<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action126<
+fn __action130<
     'err,
     'input,
     'v,
@@ -7878,7 +8195,7 @@ fn __action126<
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action112(
+    let __temp0 = __action115(
         input,
         errors,
         validator,
@@ -7886,7 +8203,7 @@ fn __action126<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action7(
+    __action8(
         input,
         errors,
         validator,
@@ -7905,7 +8222,7 @@ fn __action126<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action127<
+fn __action131<
     'err,
     'input,
     'v,
@@ -7923,7 +8240,7 @@ fn __action127<
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action112(
+    let __temp0 = __action115(
         input,
         errors,
         validator,
@@ -7931,7 +8248,7 @@ fn __action127<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action8(
+    __action9(
         input,
         errors,
         validator,
@@ -7947,7 +8264,7 @@ fn __action127<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action128<
+fn __action132<
     'err,
     'input,
     'v,
@@ -7965,7 +8282,7 @@ fn __action128<
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action112(
+    let __temp0 = __action115(
         input,
         errors,
         validator,
@@ -7973,7 +8290,7 @@ fn __action128<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action9(
+    __action10(
         input,
         errors,
         validator,
@@ -7989,7 +8306,7 @@ fn __action128<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action129<
+fn __action133<
     'err,
     'input,
     'v,
@@ -8005,7 +8322,7 @@ fn __action129<
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action112(
+    let __temp0 = __action115(
         input,
         errors,
         validator,
@@ -8013,7 +8330,7 @@ fn __action129<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action10(
+    __action11(
         input,
         errors,
         validator,
@@ -8027,7 +8344,7 @@ fn __action129<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action130<
+fn __action134<
     'err,
     'input,
     'v,
@@ -8043,7 +8360,7 @@ fn __action130<
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action112(
+    let __temp0 = __action115(
         input,
         errors,
         validator,
@@ -8051,7 +8368,7 @@ fn __action130<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action11(
+    __action12(
         input,
         errors,
         validator,
@@ -8065,7 +8382,7 @@ fn __action130<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action131<
+fn __action135<
     'err,
     'input,
     'v,
@@ -8083,7 +8400,7 @@ fn __action131<
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action112(
+    let __temp0 = __action115(
         input,
         errors,
         validator,
@@ -8091,7 +8408,7 @@ fn __action131<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action12(
+    __action13(
         input,
         errors,
         validator,
@@ -8107,7 +8424,7 @@ fn __action131<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action132<
+fn __action136<
     'err,
     'input,
     'v,
@@ -8124,7 +8441,7 @@ fn __action132<
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action112(
+    let __temp0 = __action115(
         input,
         errors,
         validator,
@@ -8132,7 +8449,7 @@ fn __action132<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action13(
+    __action14(
         input,
         errors,
         validator,
@@ -8147,7 +8464,7 @@ fn __action132<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action133<
+fn __action137<
     'err,
     'input,
     'v,
@@ -8167,7 +8484,7 @@ fn __action133<
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action112(
+    let __temp0 = __action115(
         input,
         errors,
         validator,
@@ -8175,7 +8492,7 @@ fn __action133<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action14(
+    __action15(
         input,
         errors,
         validator,
@@ -8193,7 +8510,7 @@ fn __action133<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action134<
+fn __action138<
     'err,
     'input,
     'v,
@@ -8213,7 +8530,7 @@ fn __action134<
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action112(
+    let __temp0 = __action115(
         input,
         errors,
         validator,
@@ -8221,7 +8538,7 @@ fn __action134<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action15(
+    __action16(
         input,
         errors,
         validator,
@@ -8239,7 +8556,7 @@ fn __action134<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action135<
+fn __action139<
     'err,
     'input,
     'v,
@@ -8259,7 +8576,7 @@ fn __action135<
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action112(
+    let __temp0 = __action115(
         input,
         errors,
         validator,
@@ -8267,7 +8584,7 @@ fn __action135<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action16(
+    __action17(
         input,
         errors,
         validator,
@@ -8285,7 +8602,7 @@ fn __action135<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action136<
+fn __action140<
     'err,
     'input,
     'v,
@@ -8302,7 +8619,7 @@ fn __action136<
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action112(
+    let __temp0 = __action115(
         input,
         errors,
         validator,
@@ -8310,7 +8627,7 @@ fn __action136<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action17(
+    __action18(
         input,
         errors,
         validator,
@@ -8325,7 +8642,7 @@ fn __action136<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action137<
+fn __action141<
     'err,
     'input,
     'v,
@@ -8343,7 +8660,7 @@ fn __action137<
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action112(
+    let __temp0 = __action115(
         input,
         errors,
         validator,
@@ -8351,7 +8668,7 @@ fn __action137<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action18(
+    __action19(
         input,
         errors,
         validator,
@@ -8367,7 +8684,7 @@ fn __action137<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action138<
+fn __action142<
     'err,
     'input,
     'v,
@@ -8386,7 +8703,7 @@ fn __action138<
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action112(
+    let __temp0 = __action115(
         input,
         errors,
         validator,
@@ -8394,7 +8711,7 @@ fn __action138<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action19(
+    __action20(
         input,
         errors,
         validator,
@@ -8411,7 +8728,7 @@ fn __action138<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action139<
+fn __action143<
     'err,
     'input,
     'v,
@@ -8430,7 +8747,7 @@ fn __action139<
 {
     let __start0 = __0.0;
     let __end0 = __0.0;
-    let __temp0 = __action112(
+    let __temp0 = __action115(
         input,
         errors,
         validator,
@@ -8438,7 +8755,7 @@ fn __action139<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action20(
+    __action21(
         input,
         errors,
         validator,
@@ -8455,7 +8772,7 @@ fn __action139<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action140<
+fn __action144<
     'err,
     'input,
     'v,
@@ -8464,11 +8781,17 @@ fn __action140<
     errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
     validator: &'v mut VariableValidator<'input>,
     __0: (AirPos, Token<'input>, AirPos),
-) -> Fail<'input>
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ImmutableValue<'input>, AirPos),
+    __3: (AirPos, &'input str, AirPos),
+    __4: (AirPos, Instruction<'input>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+    __6: (AirPos, AirPos, AirPos),
+) -> Instruction<'input>
 {
-    let __start0 = __0.2;
-    let __end0 = __0.2;
-    let __temp0 = __action109(
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action115(
         input,
         errors,
         validator,
@@ -8476,18 +8799,68 @@ fn __action140<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action119(
+    __action22(
         input,
         errors,
         validator,
-        __0,
         __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
     )
 }
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action141<
+fn __action145<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ImmutableValue<'input>, AirPos),
+    __3: (AirPos, &'input str, AirPos),
+    __4: (AirPos, Instruction<'input>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+    __6: (AirPos, AirPos, AirPos),
+) -> Instruction<'input>
+{
+    let __start0 = __0.0;
+    let __end0 = __0.0;
+    let __temp0 = __action115(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action23(
+        input,
+        errors,
+        validator,
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action146<
     'err,
     'input,
     'v,
@@ -8500,7 +8873,7 @@ fn __action141<
 {
     let __start0 = __0.2;
     let __end0 = __0.2;
-    let __temp0 = __action109(
+    let __temp0 = __action112(
         input,
         errors,
         validator,
@@ -8508,7 +8881,7 @@ fn __action141<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action120(
+    __action122(
         input,
         errors,
         validator,
@@ -8519,7 +8892,39 @@ fn __action141<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action142<
+fn __action147<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+) -> Fail<'input>
+{
+    let __start0 = __0.2;
+    let __end0 = __0.2;
+    let __temp0 = __action112(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action123(
+        input,
+        errors,
+        validator,
+        __0,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action148<
     'err,
     'input,
     'v,
@@ -8537,7 +8942,7 @@ fn __action142<
 {
     let __start0 = __5.2;
     let __end0 = __5.2;
-    let __temp0 = __action109(
+    let __temp0 = __action112(
         input,
         errors,
         validator,
@@ -8545,7 +8950,7 @@ fn __action142<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action121(
+    __action124(
         input,
         errors,
         validator,
@@ -8561,7 +8966,7 @@ fn __action142<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action143<
+fn __action149<
     'err,
     'input,
     'v,
@@ -8579,7 +8984,7 @@ fn __action143<
 {
     let __start0 = __5.2;
     let __end0 = __5.2;
-    let __temp0 = __action109(
+    let __temp0 = __action112(
         input,
         errors,
         validator,
@@ -8587,7 +8992,7 @@ fn __action143<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action122(
+    __action125(
         input,
         errors,
         validator,
@@ -8603,7 +9008,49 @@ fn __action143<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action144<
+fn __action150<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ResolvableToPeerIdVariable<'input>, AirPos),
+    __3: (AirPos, Stream<'input>, AirPos),
+    __4: (AirPos, CanonStream<'input>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+) -> Instruction<'input>
+{
+    let __start0 = __5.2;
+    let __end0 = __5.2;
+    let __temp0 = __action112(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action126(
+        input,
+        errors,
+        validator,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action151<
     'err,
     'input,
     'v,
@@ -8621,7 +9068,7 @@ fn __action144<
 {
     let __start0 = __5.2;
     let __end0 = __5.2;
-    let __temp0 = __action109(
+    let __temp0 = __action112(
         input,
         errors,
         validator,
@@ -8629,7 +9076,7 @@ fn __action144<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action123(
+    __action127(
         input,
         errors,
         validator,
@@ -8645,7 +9092,7 @@ fn __action144<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action145<
+fn __action152<
     'err,
     'input,
     'v,
@@ -8663,7 +9110,7 @@ fn __action145<
 {
     let __start0 = __5.2;
     let __end0 = __5.2;
-    let __temp0 = __action109(
+    let __temp0 = __action112(
         input,
         errors,
         validator,
@@ -8671,7 +9118,7 @@ fn __action145<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action124(
+    __action128(
         input,
         errors,
         validator,
@@ -8687,7 +9134,7 @@ fn __action145<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action146<
+fn __action153<
     'err,
     'input,
     'v,
@@ -8704,7 +9151,7 @@ fn __action146<
 {
     let __start0 = __4.2;
     let __end0 = __4.2;
-    let __temp0 = __action109(
+    let __temp0 = __action112(
         input,
         errors,
         validator,
@@ -8712,7 +9159,7 @@ fn __action146<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action125(
+    __action129(
         input,
         errors,
         validator,
@@ -8727,7 +9174,7 @@ fn __action146<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action147<
+fn __action154<
     'err,
     'input,
     'v,
@@ -8747,7 +9194,7 @@ fn __action147<
 {
     let __start0 = __7.2;
     let __end0 = __7.2;
-    let __temp0 = __action109(
+    let __temp0 = __action112(
         input,
         errors,
         validator,
@@ -8755,7 +9202,7 @@ fn __action147<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action126(
+    __action130(
         input,
         errors,
         validator,
@@ -8773,7 +9220,7 @@ fn __action147<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action148<
+fn __action155<
     'err,
     'input,
     'v,
@@ -8790,7 +9237,7 @@ fn __action148<
 {
     let __start0 = __4.2;
     let __end0 = __4.2;
-    let __temp0 = __action109(
+    let __temp0 = __action112(
         input,
         errors,
         validator,
@@ -8798,7 +9245,7 @@ fn __action148<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action127(
+    __action131(
         input,
         errors,
         validator,
@@ -8813,7 +9260,7 @@ fn __action148<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action149<
+fn __action156<
     'err,
     'input,
     'v,
@@ -8830,7 +9277,7 @@ fn __action149<
 {
     let __start0 = __4.2;
     let __end0 = __4.2;
-    let __temp0 = __action109(
+    let __temp0 = __action112(
         input,
         errors,
         validator,
@@ -8838,7 +9285,7 @@ fn __action149<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action128(
+    __action132(
         input,
         errors,
         validator,
@@ -8853,7 +9300,7 @@ fn __action149<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action150<
+fn __action157<
     'err,
     'input,
     'v,
@@ -8868,7 +9315,7 @@ fn __action150<
 {
     let __start0 = __2.2;
     let __end0 = __2.2;
-    let __temp0 = __action109(
+    let __temp0 = __action112(
         input,
         errors,
         validator,
@@ -8876,7 +9323,7 @@ fn __action150<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action129(
+    __action133(
         input,
         errors,
         validator,
@@ -8889,7 +9336,7 @@ fn __action150<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action151<
+fn __action158<
     'err,
     'input,
     'v,
@@ -8904,7 +9351,7 @@ fn __action151<
 {
     let __start0 = __2.2;
     let __end0 = __2.2;
-    let __temp0 = __action109(
+    let __temp0 = __action112(
         input,
         errors,
         validator,
@@ -8912,7 +9359,7 @@ fn __action151<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action130(
+    __action134(
         input,
         errors,
         validator,
@@ -8925,7 +9372,7 @@ fn __action151<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action152<
+fn __action159<
     'err,
     'input,
     'v,
@@ -8942,7 +9389,7 @@ fn __action152<
 {
     let __start0 = __4.2;
     let __end0 = __4.2;
-    let __temp0 = __action109(
+    let __temp0 = __action112(
         input,
         errors,
         validator,
@@ -8950,7 +9397,7 @@ fn __action152<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action131(
+    __action135(
         input,
         errors,
         validator,
@@ -8965,7 +9412,7 @@ fn __action152<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action153<
+fn __action160<
     'err,
     'input,
     'v,
@@ -8981,7 +9428,7 @@ fn __action153<
 {
     let __start0 = __3.2;
     let __end0 = __3.2;
-    let __temp0 = __action109(
+    let __temp0 = __action112(
         input,
         errors,
         validator,
@@ -8989,7 +9436,7 @@ fn __action153<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action132(
+    __action136(
         input,
         errors,
         validator,
@@ -9003,7 +9450,7 @@ fn __action153<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action154<
+fn __action161<
     'err,
     'input,
     'v,
@@ -9022,7 +9469,7 @@ fn __action154<
 {
     let __start0 = __6.2;
     let __end0 = __6.2;
-    let __temp0 = __action109(
+    let __temp0 = __action112(
         input,
         errors,
         validator,
@@ -9030,7 +9477,7 @@ fn __action154<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action133(
+    __action137(
         input,
         errors,
         validator,
@@ -9047,7 +9494,7 @@ fn __action154<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action155<
+fn __action162<
     'err,
     'input,
     'v,
@@ -9066,7 +9513,7 @@ fn __action155<
 {
     let __start0 = __6.2;
     let __end0 = __6.2;
-    let __temp0 = __action109(
+    let __temp0 = __action112(
         input,
         errors,
         validator,
@@ -9074,7 +9521,7 @@ fn __action155<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action134(
+    __action138(
         input,
         errors,
         validator,
@@ -9091,7 +9538,7 @@ fn __action155<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action156<
+fn __action163<
     'err,
     'input,
     'v,
@@ -9110,7 +9557,7 @@ fn __action156<
 {
     let __start0 = __6.2;
     let __end0 = __6.2;
-    let __temp0 = __action109(
+    let __temp0 = __action112(
         input,
         errors,
         validator,
@@ -9118,7 +9565,7 @@ fn __action156<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action135(
+    __action139(
         input,
         errors,
         validator,
@@ -9135,7 +9582,7 @@ fn __action156<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action157<
+fn __action164<
     'err,
     'input,
     'v,
@@ -9151,7 +9598,7 @@ fn __action157<
 {
     let __start0 = __3.2;
     let __end0 = __3.2;
-    let __temp0 = __action109(
+    let __temp0 = __action112(
         input,
         errors,
         validator,
@@ -9159,7 +9606,7 @@ fn __action157<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action136(
+    __action140(
         input,
         errors,
         validator,
@@ -9173,7 +9620,7 @@ fn __action157<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action158<
+fn __action165<
     'err,
     'input,
     'v,
@@ -9190,7 +9637,7 @@ fn __action158<
 {
     let __start0 = __4.2;
     let __end0 = __4.2;
-    let __temp0 = __action109(
+    let __temp0 = __action112(
         input,
         errors,
         validator,
@@ -9198,7 +9645,7 @@ fn __action158<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action137(
+    __action141(
         input,
         errors,
         validator,
@@ -9213,7 +9660,7 @@ fn __action158<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action159<
+fn __action166<
     'err,
     'input,
     'v,
@@ -9231,7 +9678,7 @@ fn __action159<
 {
     let __start0 = __5.2;
     let __end0 = __5.2;
-    let __temp0 = __action109(
+    let __temp0 = __action112(
         input,
         errors,
         validator,
@@ -9239,7 +9686,7 @@ fn __action159<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action138(
+    __action142(
         input,
         errors,
         validator,
@@ -9255,7 +9702,7 @@ fn __action159<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action160<
+fn __action167<
     'err,
     'input,
     'v,
@@ -9273,7 +9720,7 @@ fn __action160<
 {
     let __start0 = __5.2;
     let __end0 = __5.2;
-    let __temp0 = __action109(
+    let __temp0 = __action112(
         input,
         errors,
         validator,
@@ -9281,7 +9728,7 @@ fn __action160<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action139(
+    __action143(
         input,
         errors,
         validator,
@@ -9297,7 +9744,91 @@ fn __action160<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action161<
+fn __action168<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ImmutableValue<'input>, AirPos),
+    __3: (AirPos, &'input str, AirPos),
+    __4: (AirPos, Instruction<'input>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+) -> Instruction<'input>
+{
+    let __start0 = __5.2;
+    let __end0 = __5.2;
+    let __temp0 = __action112(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action144(
+        input,
+        errors,
+        validator,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action169<
+    'err,
+    'input,
+    'v,
+>(
+    input: &'input str,
+    errors: &'err mut Vec<ErrorRecovery<AirPos, Token<'input>, ParserError>>,
+    validator: &'v mut VariableValidator<'input>,
+    __0: (AirPos, Token<'input>, AirPos),
+    __1: (AirPos, Token<'input>, AirPos),
+    __2: (AirPos, ImmutableValue<'input>, AirPos),
+    __3: (AirPos, &'input str, AirPos),
+    __4: (AirPos, Instruction<'input>, AirPos),
+    __5: (AirPos, Token<'input>, AirPos),
+) -> Instruction<'input>
+{
+    let __start0 = __5.2;
+    let __end0 = __5.2;
+    let __temp0 = __action112(
+        input,
+        errors,
+        validator,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action145(
+        input,
+        errors,
+        validator,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __temp0,
+    )
+}
+
+#[allow(unused_variables)]
+#[allow(clippy::too_many_arguments)]
+fn __action170<
     'err,
     'input,
     'v,
@@ -9315,14 +9846,14 @@ fn __action161<
 {
     let __start0 = __4.0;
     let __end0 = __4.2;
-    let __temp0 = __action110(
+    let __temp0 = __action113(
         input,
         errors,
         validator,
         __4,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action142(
+    __action148(
         input,
         errors,
         validator,
@@ -9337,7 +9868,7 @@ fn __action161<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action162<
+fn __action171<
     'err,
     'input,
     'v,
@@ -9354,7 +9885,7 @@ fn __action162<
 {
     let __start0 = __3.2;
     let __end0 = __4.0;
-    let __temp0 = __action111(
+    let __temp0 = __action114(
         input,
         errors,
         validator,
@@ -9362,7 +9893,7 @@ fn __action162<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action142(
+    __action148(
         input,
         errors,
         validator,
@@ -9377,7 +9908,7 @@ fn __action162<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action163<
+fn __action172<
     'err,
     'input,
     'v,
@@ -9396,14 +9927,14 @@ fn __action163<
 {
     let __start0 = __5.0;
     let __end0 = __5.2;
-    let __temp0 = __action107(
+    let __temp0 = __action110(
         input,
         errors,
         validator,
         __5,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action154(
+    __action161(
         input,
         errors,
         validator,
@@ -9419,7 +9950,7 @@ fn __action163<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action164<
+fn __action173<
     'err,
     'input,
     'v,
@@ -9437,7 +9968,7 @@ fn __action164<
 {
     let __start0 = __4.2;
     let __end0 = __5.0;
-    let __temp0 = __action108(
+    let __temp0 = __action111(
         input,
         errors,
         validator,
@@ -9445,7 +9976,7 @@ fn __action164<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action154(
+    __action161(
         input,
         errors,
         validator,
@@ -9461,7 +9992,7 @@ fn __action164<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action165<
+fn __action174<
     'err,
     'input,
     'v,
@@ -9480,14 +10011,14 @@ fn __action165<
 {
     let __start0 = __5.0;
     let __end0 = __5.2;
-    let __temp0 = __action107(
+    let __temp0 = __action110(
         input,
         errors,
         validator,
         __5,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action155(
+    __action162(
         input,
         errors,
         validator,
@@ -9503,7 +10034,7 @@ fn __action165<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action166<
+fn __action175<
     'err,
     'input,
     'v,
@@ -9521,7 +10052,7 @@ fn __action166<
 {
     let __start0 = __4.2;
     let __end0 = __5.0;
-    let __temp0 = __action108(
+    let __temp0 = __action111(
         input,
         errors,
         validator,
@@ -9529,7 +10060,7 @@ fn __action166<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action155(
+    __action162(
         input,
         errors,
         validator,
@@ -9545,7 +10076,7 @@ fn __action166<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action167<
+fn __action176<
     'err,
     'input,
     'v,
@@ -9564,14 +10095,14 @@ fn __action167<
 {
     let __start0 = __5.0;
     let __end0 = __5.2;
-    let __temp0 = __action107(
+    let __temp0 = __action110(
         input,
         errors,
         validator,
         __5,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action156(
+    __action163(
         input,
         errors,
         validator,
@@ -9587,7 +10118,7 @@ fn __action167<
 
 #[allow(unused_variables)]
 #[allow(clippy::too_many_arguments)]
-fn __action168<
+fn __action177<
     'err,
     'input,
     'v,
@@ -9605,7 +10136,7 @@ fn __action168<
 {
     let __start0 = __4.2;
     let __end0 = __5.0;
-    let __temp0 = __action108(
+    let __temp0 = __action111(
         input,
         errors,
         validator,
@@ -9613,7 +10144,7 @@ fn __action168<
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action156(
+    __action163(
         input,
         errors,
         validator,
@@ -18,7 +18,13 @@ use super::air;
 use super::lexer::AIRLexer;
 use super::lexer::AirPos;
 use super::lexer::Token;
+use super::scope_checker::check_scopes;
+use super::AirParseError;
 use super::ParserError;
+use super::Span;
+use super::ValidationError;
+use super::ValidationReport;
+use super::ValidationWarning;
 use crate::ast::Instruction;
 use crate::parser::VariableValidator;
 use air::AIRParser;
@@ -34,7 +40,7 @@ thread_local!(static PARSER: AIRParser = AIRParser::new());
 
 /// Parse AIR `source_code` to `Box<Instruction>`
 #[tracing::instrument(skip_all)]
-pub fn parse(air_script: &str) -> Result<Instruction<'_>, String> {
+pub fn parse(air_script: &str) -> Result<Instruction<'_>, AirParseError> {
     let mut files = SimpleFiles::new();
     let file_id = files.add("script.air", air_script);
 
@@ -47,27 +53,75 @@ pub fn parse(air_script: &str) -> Result<Instruction<'_>, String> {
         let validator_errors = validator.finalize();
         errors.extend(validator_errors);
 
+        let errors = match result {
+            Ok(r) if errors.is_empty() => return Ok(r),
+            Ok(_) => errors,
+            Err(error) => vec![ErrorRecovery {
+                error,
+                dropped_tokens: vec![],
+            }],
+        };
+        Err(report_errors(air_script, file_id, files, errors))
+    })
+}
+
+/// Statically validate AIR `air_script` without executing it.
+///
+/// Performs the same checks as `parse` and additionally looks for unreachable branches
+/// and reads of variables outside of the fold or new they're defined in, which are reported
+/// as warnings.
+pub fn validate(air_script: &str) -> Result<ValidationReport, Vec<ValidationError>> {
+    PARSER.with(|parser| {
+        let mut errors: Vec<ErrorRecovery<AirPos, Token<'_>, ParserError>> = Vec::new();
+        let lexer = AIRLexer::new(air_script);
+        let mut validator = VariableValidator::new();
+        let result = parser.parse(air_script, &mut errors, &mut validator, lexer);
+
+        let mut warnings = validator.warnings();
+        let validator_errors = validator.finalize();
+        errors.extend(validator_errors);
+
         match result {
-            Ok(r) if errors.is_empty() => Ok(r),
-            Ok(_) => Err(report_errors(file_id, files, errors)),
-            Err(error) => Err(report_errors(
-                file_id,
-                files,
-                vec![ErrorRecovery {
-                    error,
-                    dropped_tokens: vec![],
-                }],
-            )),
+            Ok(ast) if errors.is_empty() => {
+                warnings.extend(check_scopes(&ast));
+                warnings.sort_by_key(ValidationWarning::span);
+                Ok(ValidationReport::new(warnings))
+            }
+            Ok(_) => Err(errors
+                .into_iter()
+                .map(|err| error_to_validation_error(err.error))
+                .collect()),
+            Err(error) => Err(vec![error_to_validation_error(error)]),
         }
     })
 }
 
+fn error_to_validation_error(error: ParseError<AirPos, Token<'_>, ParserError>) -> ValidationError {
+    let (span, message) = error_span_and_message(error);
+    ValidationError::new(span, message)
+}
+
 fn report_errors(
+    air_script: &str,
     file_id: usize,
     files: SimpleFiles<&str, &str>,
     errors: Vec<ErrorRecovery<AirPos, Token<'_>, ParserError>>,
-) -> String {
-    let labels = errors_to_labels(file_id, errors);
+) -> AirParseError {
+    let spans_and_messages = errors
+        .into_iter()
+        .map(|err| error_span_and_message(err.error))
+        .collect::<Vec<_>>();
+    // there is always at least one error here
+    let first_span = spans_and_messages
+        .first()
+        .map_or(Span::new(0.into(), 0.into()), |(span, _)| *span);
+
+    let labels = spans_and_messages
+        .into_iter()
+        .map(|(span, message)| {
+            Label::primary(file_id, span.left.into()..span.right.into()).with_message(message)
+        })
+        .collect::<Vec<_>>();
     let diagnostic = Diagnostic::error().with_labels(labels);
 
     // Write to stderr
@@ -78,37 +132,35 @@ fn report_errors(
     // Return as a string
     let mut buffer = Buffer::no_color();
     term::emit(&mut buffer, &config, &files, &diagnostic).expect("term emit to buffer");
-    String::from_utf8_lossy(buffer.as_slice())
+    let report = String::from_utf8_lossy(buffer.as_slice())
         .as_ref()
-        .to_string()
+        .to_string();
+
+    AirParseError::new(air_script, first_span, report)
 }
 
-fn errors_to_labels(
-    file_id: usize,
-    errors: Vec<ErrorRecovery<AirPos, Token<'_>, ParserError>>,
-) -> Vec<Label<usize>> {
-    errors
-        .into_iter()
-        .map(|err| match err.error {
-            ParseError::UnrecognizedToken {
-                token: (start, _, end),
-                expected,
-            } => Label::primary(file_id, start.into()..end.into())
-                .with_message(format!("expected {}", pretty_expected(expected))),
-            ParseError::InvalidToken { location } => {
-                Label::primary(file_id, location.into()..(location + 1).into())
-                    .with_message("unexpected token")
-            }
-            ParseError::ExtraToken {
-                token: (start, _, end),
-            } => Label::primary(file_id, start.into()..end.into()).with_message("extra token"),
-            ParseError::UnrecognizedEof { location, expected } => {
-                Label::primary(file_id, location.into()..(location + 1).into())
-                    .with_message(format!("expected {}", pretty_expected(expected)))
-            }
-            ParseError::User { error } => parser_error_to_label(file_id, error),
-        })
-        .collect()
+fn error_span_and_message(error: ParseError<AirPos, Token<'_>, ParserError>) -> (Span, String) {
+    match error {
+        ParseError::UnrecognizedToken {
+            token: (start, _, end),
+            expected,
+        } => (
+            Span::new(start, end),
+            format!("expected {}", pretty_expected(expected)),
+        ),
+        ParseError::InvalidToken { location } => (
+            Span::new(location, location + 1),
+            "unexpected token".to_string(),
+        ),
+        ParseError::ExtraToken {
+            token: (start, _, end),
+        } => (Span::new(start, end), "extra token".to_string()),
+        ParseError::UnrecognizedEof { location, expected } => (
+            Span::new(location, location + 1),
+            format!("expected {}", pretty_expected(expected)),
+        ),
+        ParseError::User { error } => (error.span(), error.to_string()),
+    }
 }
 
 fn pretty_expected(expected: Vec<String>) -> String {
@@ -118,8 +170,3 @@ fn pretty_expected(expected: Vec<String>) -> String {
         expected.join(" or ")
     }
 }
-
-fn parser_error_to_label(file_id: usize, error: ParserError) -> Label<usize> {
-    let span = error.span();
-    Label::primary(file_id, span.left.into()..span.right.into()).with_message(error.to_string())
-}
@@ -59,6 +59,13 @@ pub enum ParserError {
 
     #[error("fold can not have instructions after next")]
     FoldHasInstructionAfterNext(Span),
+
+    #[error("pattern '{pattern}' isn't a valid regex: {reason}")]
+    InvalidRegexPattern {
+        span: Span,
+        pattern: String,
+        reason: String,
+    },
 }
 
 impl ParserError {
@@ -75,6 +82,7 @@ impl ParserError {
             Self::UnsupportedMapKeyType { span, .. } => *span,
             Self::UnsupportedLiteralErrCodes { span } => *span,
             Self::FoldHasInstructionAfterNext(span) => *span,
+            Self::InvalidRegexPattern { span, .. } => *span,
         }
     }
 
@@ -132,6 +140,18 @@ impl ParserError {
     pub fn fold_has_instruction_after_next(span: Span) -> Self {
         Self::FoldHasInstructionAfterNext(span)
     }
+
+    pub fn invalid_regex_pattern(
+        span: Span,
+        pattern: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self::InvalidRegexPattern {
+            span,
+            pattern: pattern.into(),
+            reason: reason.into(),
+        }
+    }
 }
 
 impl From<std::convert::Infallible> for ParserError {
@@ -181,6 +181,7 @@ fn string_to_token(input: &str, start_pos: AirPos) -> LexerResult<Token> {
 
         CALL_INSTR => Ok(Token::Call),
         CANON_INSTR => Ok(Token::Canon),
+        CANON_SORTED_INSTR => Ok(Token::CanonSorted),
         AP_INSTR => Ok(Token::Ap),
         SEQ_INSTR => Ok(Token::Seq),
         PAR_INSTR => Ok(Token::Par),
@@ -193,6 +194,8 @@ fn string_to_token(input: &str, start_pos: AirPos) -> LexerResult<Token> {
         NULL_INSTR => Ok(Token::Null),
         MATCH_INSTR => Ok(Token::Match),
         MISMATCH_INSTR => Ok(Token::MisMatch),
+        MATCH_REGEX_INSTR => Ok(Token::MatchRegex),
+        MISMATCH_REGEX_INSTR => Ok(Token::MisMatchRegex),
 
         INIT_PEER_ID => Ok(Token::InitPeerId),
         _ if input.starts_with(ERROR) => parse_error(input, start_pos, ERROR, Token::Error),
@@ -244,6 +247,7 @@ fn parse_error<'input>(
 
 const CALL_INSTR: &str = "call";
 const CANON_INSTR: &str = "canon";
+const CANON_SORTED_INSTR: &str = "canon-sorted";
 const AP_INSTR: &str = "ap";
 const SEQ_INSTR: &str = "seq";
 const PAR_INSTR: &str = "par";
@@ -256,6 +260,8 @@ const NEXT_INSTR: &str = "next";
 const NULL_INSTR: &str = "null";
 const MATCH_INSTR: &str = "match";
 const MISMATCH_INSTR: &str = "mismatch";
+const MATCH_REGEX_INSTR: &str = "match-regex";
+const MISMATCH_REGEX_INSTR: &str = "mismatch-regex";
 
 const INIT_PEER_ID: &str = "%init_peer_id%";
 pub(crate) const LAST_ERROR: &str = "%last_error%";
@@ -150,6 +150,16 @@ fn air_instructions() {
             Ok((9.into(), Token::CloseRoundBracket, 10.into())),
         ]),
     );
+
+    lexer_test(
+        "match-regex",
+        Single(Ok((0.into(), Token::MatchRegex, 11.into()))),
+    );
+
+    lexer_test(
+        "mismatch-regex",
+        Single(Ok((0.into(), Token::MisMatchRegex, 14.into()))),
+    );
 }
 
 #[test]
@@ -89,6 +89,7 @@ pub enum Token<'input> {
 
     Call,
     Canon,
+    CanonSorted,
     Ap,
     Seq,
     Par,
@@ -101,4 +102,6 @@ pub enum Token<'input> {
     Null,
     Match,
     MisMatch,
+    MatchRegex,
+    MisMatchRegex,
 }
@@ -25,17 +25,25 @@ mod span;
 mod air;
 
 mod errors;
+mod parse_error;
+mod scope_checker;
+mod validation_report;
 mod validator;
 
 #[cfg(test)]
 pub mod tests;
 
 pub use self::air_parser::parse;
+pub use self::air_parser::validate;
 pub use air::AIRParser;
 pub use lexer::AIRLexer;
 pub(crate) use lexer::ERROR;
 pub(crate) use lexer::LAST_ERROR;
+pub use parse_error::AirParseError;
 pub use span::Span;
+pub use validation_report::ValidationError;
+pub use validation_report::ValidationReport;
+pub use validation_report::ValidationWarning;
 pub use validator::VariableValidator;
 
 use errors::ParserError;
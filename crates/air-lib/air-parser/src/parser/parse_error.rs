@@ -0,0 +1,54 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::parser::Span;
+
+use thiserror::Error as ThisError;
+
+/// An error of parsing an AIR script.
+///
+/// It's displayed as a human-readable report of all errors found in a script, and keeps
+/// the location of the first one for tools that point at it, e.g. language servers.
+#[derive(ThisError, Debug, Clone, PartialEq, Eq)]
+#[error("{report}")]
+pub struct AirParseError {
+    /// Byte offsets of the first erroneous part of a script.
+    pub span: Span,
+
+    /// One-based line of the span start.
+    pub line: u32,
+
+    /// One-based column of the span start, counted in characters.
+    pub column: u32,
+
+    pub report: String,
+}
+
+impl AirParseError {
+    pub(crate) fn new(air_script: &str, span: Span, report: String) -> Self {
+        let start = usize::from(span.left).min(air_script.len());
+        // spans are produced by the lexer, so they are on char boundaries
+        let before_span = air_script.get(..start).unwrap_or(air_script);
+        let line_start = before_span.rfind('\n').map_or(0, |position| position + 1);
+
+        Self {
+            span,
+            line: before_span.matches('\n').count() as u32 + 1,
+            column: before_span[line_start..].chars().count() as u32 + 1,
+            report,
+        }
+    }
+}
@@ -0,0 +1,215 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::lexer::AirPos;
+use super::Span;
+use super::ValidationWarning;
+use crate::ast::*;
+use crate::lint::reads;
+
+use std::collections::HashSet;
+
+/// Reports reads of scalars and canon streams that are out of scope at the place of the read.
+///
+/// The validator checks only that a variable is defined somewhere before its use in the script
+/// text, but scalars and canon streams written inside a fold or restricted by new are dropped
+/// once it's over, and so are fold iterators. Such reads wait for a value that never comes.
+pub(super) fn check_scopes(ast: &Instruction<'_>) -> Vec<ValidationWarning> {
+    let mut checker = ScopeChecker::default();
+    checker.scopes.push(<_>::default());
+    checker.visit(ast);
+
+    checker.warnings
+}
+
+#[derive(Default)]
+struct ScopeChecker<'i> {
+    warnings: Vec<ValidationWarning>,
+    // variables written in each scope, fold and new open a scope
+    scopes: Vec<HashSet<&'i str>>,
+    // iterators of folds enclosing the current instruction
+    iterators: Vec<&'i str>,
+    // variables written only in scopes that are already closed
+    closed_variables: HashSet<&'i str>,
+    closed_iterators: HashSet<&'i str>,
+}
+
+impl<'i> ScopeChecker<'i> {
+    fn visit(&mut self, instruction: &Instruction<'i>) {
+        match instruction {
+            Instruction::Call(call) => {
+                reads::triplet(&call.triplet, &mut self.reader());
+                for arg in call.args.iter() {
+                    reads::value(arg, &mut self.reader());
+                }
+                if let CallOutputValue::Scalar(scalar) = &call.output {
+                    self.write(scalar.name);
+                }
+            }
+            Instruction::Ap(ap) => {
+                reads::ap_argument(&ap.argument, &mut self.reader());
+                if let ApResult::Scalar(scalar) = &ap.result {
+                    self.write(scalar.name);
+                }
+            }
+            Instruction::ApMap(ap_map) => {
+                reads::map_key(&ap_map.key, &mut self.reader());
+                reads::ap_argument(&ap_map.value, &mut self.reader());
+            }
+            Instruction::Canon(canon) => {
+                reads::peer_id(&canon.peer_id, &mut self.reader());
+                self.write(canon.canon_stream.name);
+            }
+            Instruction::CanonSorted(canon) => {
+                reads::peer_id(&canon.peer_id, &mut self.reader());
+                self.write(canon.canon_stream.name);
+            }
+            Instruction::CanonMap(canon_map) => {
+                reads::peer_id(&canon_map.peer_id, &mut self.reader());
+                self.write(canon_map.canon_stream_map.name);
+            }
+            Instruction::CanonStreamMapScalar(canon) => {
+                reads::peer_id(&canon.peer_id, &mut self.reader());
+                self.write(canon.scalar.name);
+            }
+            Instruction::Seq(seq) => {
+                self.visit(&seq.0);
+                self.visit(&seq.1);
+            }
+            Instruction::Par(par) => {
+                self.visit(&par.0);
+                self.visit(&par.1);
+            }
+            Instruction::Xor(xor) => {
+                self.visit(&xor.0);
+                self.visit(&xor.1);
+            }
+            Instruction::Match(match_) => {
+                reads::value(&match_.left_value, &mut self.reader());
+                reads::value(&match_.right_value, &mut self.reader());
+                self.visit(&match_.instruction);
+            }
+            Instruction::MisMatch(mismatch) => {
+                reads::value(&mismatch.left_value, &mut self.reader());
+                reads::value(&mismatch.right_value, &mut self.reader());
+                self.visit(&mismatch.instruction);
+            }
+            Instruction::MatchRegex(match_regex) => {
+                reads::value(&match_regex.value, &mut self.reader());
+                self.visit(&match_regex.instruction);
+            }
+            Instruction::MisMatchRegex(mismatch_regex) => {
+                reads::value(&mismatch_regex.value, &mut self.reader());
+                self.visit(&mismatch_regex.instruction);
+            }
+            Instruction::Fail(fail) => reads::fail(fail, &mut self.reader()),
+            Instruction::FoldScalar(fold) => {
+                reads::fold_scalar_iterable(&fold.iterable, &mut self.reader());
+                self.visit_fold(
+                    fold.iterator.name,
+                    &fold.instruction,
+                    fold.last_instruction.as_deref(),
+                );
+            }
+            Instruction::FoldStream(fold) => self.visit_fold(
+                fold.iterator.name,
+                &fold.instruction,
+                fold.last_instruction.as_deref(),
+            ),
+            Instruction::FoldStreamMap(fold) => self.visit_fold(
+                fold.iterator.name,
+                &fold.instruction,
+                fold.last_instruction.as_deref(),
+            ),
+            Instruction::New(new) => self.visit_new(new),
+            Instruction::Next(next) => self.read(next.iterator.name, next.iterator.position),
+            Instruction::Never(_) | Instruction::Null(_) | Instruction::Error => {}
+        }
+    }
+
+    fn visit_fold(
+        &mut self,
+        iterator: &'i str,
+        instruction: &Instruction<'i>,
+        last_instruction: Option<&Instruction<'i>>,
+    ) {
+        self.scopes.push(<_>::default());
+        self.iterators.push(iterator);
+
+        self.visit(instruction);
+
+        self.iterators.pop();
+        self.closed_iterators.insert(iterator);
+        // variables written in a fold are local to it
+        let scope = self.scopes.pop().expect("fold scope was pushed above");
+        self.closed_variables.extend(scope);
+
+        if let Some(last_instruction) = last_instruction {
+            self.visit(last_instruction);
+        }
+    }
+
+    fn visit_new(&mut self, new: &New<'i>) {
+        let restricted = match &new.argument {
+            NewArgument::Scalar(scalar) => Some(scalar.name),
+            NewArgument::CanonStream(canon) => Some(canon.name),
+            NewArgument::CanonStreamMap(canon) => Some(canon.name),
+            // streams are always readable, they're just empty outside of new
+            NewArgument::Stream(_) | NewArgument::StreamMap(_) => None,
+        };
+
+        self.scopes.push(<_>::default());
+        self.visit(&new.instruction);
+
+        // only the restricted variable is local to new, other ones are written to the outer scope
+        let mut scope = self.scopes.pop().expect("new scope was pushed above");
+        if let Some(name) = restricted.filter(|name| scope.remove(name)) {
+            self.closed_variables.insert(name);
+        }
+        self.current_scope().extend(scope);
+    }
+
+    fn write(&mut self, name: &'i str) {
+        self.current_scope().insert(name);
+    }
+
+    fn read(&mut self, name: &'i str, position: AirPos) {
+        let is_visible =
+            self.iterators.contains(&name) || self.scopes.iter().any(|scope| scope.contains(name));
+        if is_visible {
+            return;
+        }
+
+        // reads of variables that are never written are reported by the validator as errors
+        let span = Span::new(position, position + name.len());
+        if self.closed_iterators.contains(name) {
+            self.warnings.push(ValidationWarning::LeakedIterator(span));
+        } else if self.closed_variables.contains(name) {
+            self.warnings
+                .push(ValidationWarning::UseBeforeDefinition(span));
+        }
+    }
+
+    fn reader(&mut self) -> impl FnMut(&'i str, AirPos) + '_ {
+        |name, position| self.read(name, position)
+    }
+
+    fn current_scope(&mut self) -> &mut HashSet<&'i str> {
+        self.scopes
+            .last_mut()
+            .expect("the root scope is always present")
+    }
+}
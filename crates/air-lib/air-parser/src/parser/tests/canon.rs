@@ -39,6 +39,27 @@ fn canon_with_literal_peer_id() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn canon_sorted_with_literal_peer_id() {
+    let peer_id = "peer_id";
+    let stream = "$stream";
+    let canon_stream = "#canon_stream";
+    let source_code = format!(
+        r#"
+        (canon-sorted "{peer_id}" {stream} {canon_stream})
+    "#
+    );
+
+    let actual = parse(&source_code);
+    let expected = canon_sorted(
+        ResolvableToPeerIdVariable::Literal(peer_id),
+        Stream::new(stream, 33.into()),
+        CanonStream::new(canon_stream, 41.into()),
+    );
+
+    assert_eq!(actual, expected);
+}
+
 #[test]
 fn canon_with_variable_peer_id() {
     let peer_id = "peer_id";
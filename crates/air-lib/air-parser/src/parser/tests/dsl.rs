@@ -248,6 +248,24 @@ pub(super) fn mismatch<'i>(
     )
 }
 
+pub(super) fn match_regex<'i>(
+    value: ImmutableValue<'i>,
+    pattern: &'i str,
+    instruction: Instruction<'i>,
+) -> Instruction<'i> {
+    let pattern = RegexPattern::new(pattern).unwrap();
+    Instruction::MatchRegex(MatchRegex::new(value, pattern, instruction).into())
+}
+
+pub(super) fn mismatch_regex<'i>(
+    value: ImmutableValue<'i>,
+    pattern: &'i str,
+    instruction: Instruction<'i>,
+) -> Instruction<'i> {
+    let pattern = RegexPattern::new(pattern).unwrap();
+    Instruction::MisMatchRegex(MisMatchRegex::new(value, pattern, instruction).into())
+}
+
 pub(super) fn ap<'i>(argument: ApArgument<'i>, result: ApResult<'i>) -> Instruction<'i> {
     Instruction::Ap(Ap { argument, result }.into())
 }
@@ -282,6 +300,14 @@ pub(super) fn canon<'i>(
     )
 }
 
+pub(super) fn canon_sorted<'i>(
+    peer_pk: ResolvableToPeerIdVariable<'i>,
+    stream: Stream<'i>,
+    canon_stream: CanonStream<'i>,
+) -> Instruction<'i> {
+    Instruction::CanonSorted(CanonSorted::new(peer_pk, stream, canon_stream).into())
+}
+
 pub(super) fn canon_stream_map_scalar<'i>(
     peer_pk: ResolvableToPeerIdVariable<'i>,
     stream_map: StreamMap<'i>,
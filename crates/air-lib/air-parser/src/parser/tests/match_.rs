@@ -298,3 +298,66 @@ fn mismatch_with_canon_stream_wl() {
         instruction, expected
     );
 }
+
+#[test]
+fn parse_match_regex() {
+    let source_code = r#"
+        (match-regex v1 "^peer-[0-9]+$"
+            (null)
+        )
+        "#;
+    let instruction = parse(source_code);
+    let expected = match_regex(
+        ImmutableValue::Variable(ImmutableVariable::scalar("v1", 22.into())),
+        "^peer-[0-9]+$",
+        null(),
+    );
+    assert_eq!(instruction, expected);
+}
+
+#[test]
+fn parse_mismatch_regex() {
+    let source_code = r#"
+        (mismatch-regex %init_peer_id% "^peer-[0-9]+$"
+            (null)
+        )
+        "#;
+    let instruction = parse(source_code);
+    let expected = mismatch_regex(ImmutableValue::InitPeerId, "^peer-[0-9]+$", null());
+    assert_eq!(instruction, expected);
+}
+
+#[test]
+fn parse_match_regex_invalid_pattern() {
+    use crate::parser::errors::ParserError;
+    use lalrpop_util::ParseError;
+
+    let source_code = r#"
+        (match-regex v1 "[0-9"
+            (null)
+        )
+        "#;
+
+    let lexer = crate::AIRLexer::new(source_code);
+
+    let parser = crate::AIRParser::new();
+    let mut errors = Vec::new();
+    let mut validator = crate::parser::VariableValidator::new();
+    parser
+        .parse(source_code, &mut errors, &mut validator, lexer)
+        .expect("parser shouldn't fail");
+
+    let errors = validator.finalize();
+    let parser_errors = errors
+        .iter()
+        .filter_map(|error| match &error.error {
+            ParseError::User { error } => Some(error),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert!(parser_errors.iter().any(|error| matches!(
+        error,
+        ParserError::InvalidRegexPattern { pattern, .. } if pattern == "[0-9"
+    )));
+}
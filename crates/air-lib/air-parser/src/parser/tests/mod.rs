@@ -25,7 +25,9 @@ mod never;
 mod new;
 mod null;
 mod par;
+mod parse_error;
 mod seq;
+mod validate;
 
 use crate::ast::Instruction;
 use crate::parser::AIRParser;
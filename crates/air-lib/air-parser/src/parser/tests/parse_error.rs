@@ -0,0 +1,42 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::parser::parse;
+use crate::parser::Span;
+
+#[test]
+fn parse_error_points_at_first_error() {
+    let source_code =
+        "(seq\n    (null)\n    (call \"peer\" (\"service\" \"function\") [undefined]))";
+
+    let error = parse(source_code).unwrap_err();
+    assert_eq!(error.span, Span::new(20.into(), 68.into()));
+    assert_eq!((error.line, error.column), (3, 5));
+    assert!(
+        error.report.contains("variable 'undefined' wasn't defined"),
+        "{}",
+        error.report
+    );
+    assert_eq!(error.to_string(), error.report);
+}
+
+#[test]
+fn parse_error_column_counts_characters() {
+    let source_code = r#"(seq (ap "ü" scalar) ("#;
+
+    let error = parse(source_code).unwrap_err();
+    assert_eq!((error.line, error.column), (1, 23));
+}
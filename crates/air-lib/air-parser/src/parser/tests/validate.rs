@@ -0,0 +1,196 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::parser::validate;
+use crate::parser::Span;
+use crate::parser::ValidationWarning;
+
+#[test]
+fn validate_correct_script() {
+    let source_code = r#"
+        (xor
+            (call "peer" ("service" "function") [] result)
+            (call "peer" ("service" "log") [result])
+        )
+        "#;
+
+    let report = validate(source_code).expect("script should be valid");
+    assert!(report.warnings.is_empty(), "{:?}", report.warnings);
+}
+
+#[test]
+fn validate_undefined_variable() {
+    let source_code = r#"(call "peer" ("service" "function") [undefined])"#;
+
+    let errors = validate(source_code).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].span, Span::new(0.into(), 48.into()));
+    assert_eq!(errors[0].message, "variable 'undefined' wasn't defined");
+}
+
+#[test]
+fn validate_syntax_error() {
+    let source_code = r#"(seq (null))"#;
+
+    let errors = validate(source_code).unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].span, Span::new(11.into(), 12.into()));
+}
+
+#[test]
+fn validate_xor_after_null() {
+    let source_code = r#"(xor (new $stream (null)) (call "peer" ("service" "function") []))"#;
+
+    let report = validate(source_code).expect("script should be valid");
+    let expected_span = Span::new(26.into(), 65.into());
+    assert_eq!(
+        report.warnings,
+        vec![ValidationWarning::UnreachableXorBranch(expected_span)]
+    );
+}
+
+#[test]
+fn validate_seq_after_never() {
+    let source_code = r#"(seq (never) (xor (never) (null)))"#;
+
+    let report = validate(source_code).expect("script should be valid");
+    assert_eq!(
+        report.warnings,
+        vec![
+            ValidationWarning::UnreachableAfterNever(Span::new(13.into(), 33.into())),
+            ValidationWarning::UnreachableXorBranch(Span::new(26.into(), 32.into())),
+        ]
+    );
+}
+
+#[test]
+fn validate_xor_after_fallible_instruction() {
+    let source_code = r#"
+        (xor
+            (par
+                (null)
+                (call "peer" ("service" "function") [])
+            )
+            (seq
+                (match 1 1 (null))
+                (null)
+            )
+        )
+        "#;
+
+    let report = validate(source_code).expect("script should be valid");
+    assert!(report.warnings.is_empty(), "{:?}", report.warnings);
+}
+
+#[test]
+fn validate_scalar_used_after_fold() {
+    let source_code = r#"
+        (seq
+            (call "peer" ("service" "items") [] items)
+            (seq
+                (fold items i
+                    (seq
+                        (call "peer" ("service" "function") [i] local)
+                        (next i)
+                    )
+                )
+                (call "peer" ("service" "log") [local])
+            )
+        )
+        "#;
+
+    let report = validate(source_code).expect("script should be valid");
+    let position = source_code.rfind("local").unwrap();
+    let expected_span = Span::new(position.into(), (position + "local".len()).into());
+    assert_eq!(
+        report.warnings,
+        vec![ValidationWarning::UseBeforeDefinition(expected_span)]
+    );
+}
+
+#[test]
+fn validate_scalar_used_after_new() {
+    let source_code = r#"
+        (seq
+            (new scalar
+                (seq
+                    (call "peer" ("service" "function") [] scalar)
+                    (call "peer" ("service" "function") [] other)
+                )
+            )
+            (call "peer" ("service" "log") [scalar other])
+        )
+        "#;
+
+    let report = validate(source_code).expect("script should be valid");
+    let position = source_code.rfind("scalar").unwrap();
+    let expected_span = Span::new(position.into(), (position + "scalar".len()).into());
+    assert_eq!(
+        report.warnings,
+        vec![ValidationWarning::UseBeforeDefinition(expected_span)]
+    );
+}
+
+#[test]
+fn validate_leaked_iterator() {
+    let source_code = r#"
+        (seq
+            (call "peer" ("service" "items") [] items)
+            (seq
+                (fold items i
+                    (next i)
+                )
+                (call "peer" ("service" "log") [i])
+            )
+        )
+        "#;
+
+    let report = validate(source_code).expect("script should be valid");
+    let position = source_code.rfind('i').unwrap();
+    assert_eq!(
+        report.warnings,
+        vec![ValidationWarning::LeakedIterator(Span::new(
+            position.into(),
+            (position + 1).into()
+        ))]
+    );
+}
+
+#[test]
+fn validate_variables_in_scope() {
+    let source_code = r#"
+        (seq
+            (call "peer" ("service" "function") [] items)
+            (fold items i
+                (seq
+                    (seq
+                        (call "peer" ("service" "function") [i items] local)
+                        (new $stream
+                            (call "peer" ("service" "function") [local] inner)
+                        )
+                    )
+                    (seq
+                        (call "peer" ("service" "log") [inner])
+                        (next i)
+                    )
+                )
+            )
+        )
+        "#;
+
+    let report = validate(source_code).expect("script should be valid");
+    assert!(report.warnings.is_empty(), "{:?}", report.warnings);
+}
@@ -0,0 +1,75 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::parser::Span;
+
+use thiserror::Error as ThisError;
+
+/// Result of a successful static validation of an AIR script.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ValidationReport {
+    /// Diagnostics that don't prevent a script from being executed.
+    pub warnings: Vec<ValidationWarning>,
+}
+
+/// An error that prevents a script from being executed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidationError {
+    /// Byte offsets of the erroneous part of a script.
+    pub span: Span,
+    pub message: String,
+}
+
+#[derive(ThisError, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationWarning {
+    #[error("xor branch is unreachable, because the previous one never fails")]
+    UnreachableXorBranch(Span),
+
+    #[error("instruction is unreachable, because the previous one never completes")]
+    UnreachableAfterNever(Span),
+
+    #[error("variable is used before it's defined, it's written only inside a fold or new that is already over")]
+    UseBeforeDefinition(Span),
+
+    #[error("fold iterator is used outside of its fold")]
+    LeakedIterator(Span),
+}
+
+impl ValidationReport {
+    pub fn new(warnings: Vec<ValidationWarning>) -> Self {
+        Self { warnings }
+    }
+}
+
+impl ValidationError {
+    pub fn new(span: Span, message: impl Into<String>) -> Self {
+        Self {
+            span,
+            message: message.into(),
+        }
+    }
+}
+
+impl ValidationWarning {
+    pub fn span(&self) -> Span {
+        match self {
+            Self::UnreachableXorBranch(span) => *span,
+            Self::UnreachableAfterNever(span) => *span,
+            Self::UseBeforeDefinition(span) => *span,
+            Self::LeakedIterator(span) => *span,
+        }
+    }
+}
@@ -20,6 +20,7 @@ use super::lexer::AirPos;
 use crate::parser::lexer::Token;
 use crate::parser::ParserError;
 use crate::parser::Span;
+use crate::parser::ValidationWarning;
 
 use air_lambda_ast::LambdaAST;
 use air_lambda_ast::ValueAccessor;
@@ -241,6 +242,131 @@ impl<'name> AfterNextCheckMachine<'name> {
     }
 }
 
+/// Describes how a subgraph could finish its execution, it is used to find unreachable branches.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Completion {
+    /// Subgraph always completes without an error, e.g. (null).
+    Always,
+    /// Subgraph never completes and never fails, e.g. (never).
+    Never,
+    /// Completion depends on the runtime.
+    Unknown,
+}
+
+/// This machine is used to find branches that couldn't be executed, e.g.
+/// (xor
+///   (null)
+///   (call ...) <- unreachable, because null never fails
+/// )
+#[derive(Clone, Debug)]
+struct ReachabilityCheckMachine {
+    stack: Vec<(Completion, Span)>,
+
+    warnings: Vec<ValidationWarning>,
+
+    /// This flag disables the machine if invariants are broken, e.g. after parser error recovery.
+    is_enabled: bool,
+}
+
+impl Default for ReachabilityCheckMachine {
+    fn default() -> Self {
+        Self {
+            stack: Default::default(),
+            warnings: Default::default(),
+            is_enabled: true,
+        }
+    }
+}
+
+impl ReachabilityCheckMachine {
+    fn met_leaf(&mut self, completion: Completion, span: Span) {
+        if self.is_enabled {
+            self.stack.push((completion, span));
+        }
+    }
+
+    fn met_seq(&mut self, span: Span) {
+        use Completion::*;
+
+        let Some(((left, _), (right, right_span))) = self.pop_pair() else {
+            return;
+        };
+        if left == Never {
+            self.warnings
+                .push(ValidationWarning::UnreachableAfterNever(right_span));
+        }
+
+        let completion = match left {
+            Always => right,
+            Never => Never,
+            Unknown => Unknown,
+        };
+        self.stack.push((completion, span));
+    }
+
+    fn met_par(&mut self, span: Span) {
+        let Some(((left, _), (right, _))) = self.pop_pair() else {
+            return;
+        };
+
+        let completion = if left == right {
+            left
+        } else {
+            Completion::Unknown
+        };
+        self.stack.push((completion, span));
+    }
+
+    fn met_xor(&mut self, span: Span) {
+        let Some(((left, _), (_, right_span))) = self.pop_pair() else {
+            return;
+        };
+        if left != Completion::Unknown {
+            self.warnings
+                .push(ValidationWarning::UnreachableXorBranch(right_span));
+        }
+
+        self.stack.push((left, span));
+    }
+
+    /// Replaces children of a compound instruction with its own completion.
+    fn met_compound(&mut self, children_count: usize, completion: Option<Completion>, span: Span) {
+        if !self.is_enabled {
+            return;
+        }
+        if self.stack.len() < children_count {
+            self.disable();
+            return;
+        }
+
+        let children = self.stack.split_off(self.stack.len() - children_count);
+        // take the completion of the only child, if it's not specified
+        let completion = completion
+            .or_else(|| children.first().map(|(completion, _)| *completion))
+            .unwrap_or(Completion::Unknown);
+        self.stack.push((completion, span));
+    }
+
+    fn pop_pair(&mut self) -> Option<((Completion, Span), (Completion, Span))> {
+        if !self.is_enabled {
+            return None;
+        }
+
+        let right = self.stack.pop();
+        let left = self.stack.pop();
+        let pair = left.zip(right);
+        if pair.is_none() {
+            self.disable();
+        }
+        pair
+    }
+
+    fn disable(&mut self) {
+        self.stack.clear();
+        self.is_enabled = false;
+    }
+}
+
 /// Intermediate implementation of variable validator.
 ///
 /// It is intended to track variables (i.e., those that were defined as
@@ -277,8 +403,14 @@ pub struct VariableValidator<'i> {
     /// This vector contains all literal error codes used with fail.
     unsupported_literal_errcodes: Vec<(i64, Span)>,
 
+    /// This vector contains patterns of match-regex and mismatch-regex that aren't valid regexes.
+    invalid_regex_patterns: Vec<(&'i str, String, Span)>,
+
     /// This machine is for after next instruction check.
     after_next_machine: AfterNextCheckMachine<'i>,
+
+    /// This machine is for unreachable branches check.
+    reachability_machine: ReachabilityCheckMachine,
 }
 
 impl<'i> VariableValidator<'i> {
@@ -309,6 +441,11 @@ impl<'i> VariableValidator<'i> {
         self.met_simple_instr(span);
     }
 
+    pub(super) fn met_canon_sorted(&mut self, canon_sorted: &CanonSorted<'i>, span: Span) {
+        self.met_variable_name_definition(canon_sorted.canon_stream.name, span);
+        self.met_simple_instr(span);
+    }
+
     pub(super) fn met_canon_map(&mut self, canon_map: &CanonMap<'i>, span: Span) {
         self.met_variable_name_definition(canon_map.canon_stream_map.name, span);
         self.met_simple_instr(span);
@@ -328,12 +465,41 @@ impl<'i> VariableValidator<'i> {
         self.met_matchable(&match_.left_value, span);
         self.met_matchable(&match_.right_value, span);
         self.met_replacing_instr(span);
+        self.reachability_machine
+            .met_compound(1, Some(Completion::Unknown), span);
     }
 
     pub(super) fn met_mismatch(&mut self, mismatch: &MisMatch<'i>, span: Span) {
         self.met_matchable(&mismatch.left_value, span);
         self.met_matchable(&mismatch.right_value, span);
         self.met_replacing_instr(span);
+        self.reachability_machine
+            .met_compound(1, Some(Completion::Unknown), span);
+    }
+
+    /// Works for both match-regex and mismatch-regex, returns the compiled pattern
+    /// or None if it isn't a valid regex.
+    pub(super) fn met_regex_match(
+        &mut self,
+        value: &ImmutableValue<'i>,
+        pattern: &'i str,
+        span: Span,
+    ) -> Option<RegexPattern<'i>> {
+        self.met_matchable(value, span);
+        self.met_replacing_instr(span);
+        self.reachability_machine
+            .met_compound(1, Some(Completion::Unknown), span);
+
+        RegexPattern::new(pattern)
+            .map_err(|error| {
+                // a regex error spans several lines pointing into the pattern,
+                // the last one contains the reason
+                let error = error.to_string();
+                let reason = error.lines().last().unwrap_or_default();
+                let reason = reason.trim_start_matches("error: ").to_string();
+                self.invalid_regex_patterns.push((pattern, reason, span))
+            })
+            .ok()
     }
 
     pub(super) fn met_fold_scalar(&mut self, fold: &FoldScalar<'i>, span: Span) {
@@ -351,6 +517,7 @@ impl<'i> VariableValidator<'i> {
         };
         self.met_iterator_definition(&fold.iterator, span);
         self.met_popstack_instr(fold, span);
+        self.met_fold_reachability(fold.last_instruction.is_some(), span);
     }
 
     pub(super) fn meet_fold_stream(&mut self, fold: &FoldStream<'i>, span: Span) {
@@ -361,6 +528,7 @@ impl<'i> VariableValidator<'i> {
             Some(_) => self.met_popstack_replacing_with_check_instr(fold.iterator.name, span),
             None => self.met_replacing_with_check_instr(fold.iterator.name, span),
         }
+        self.met_fold_reachability(fold.last_instruction.is_some(), span);
     }
 
     pub(super) fn meet_fold_stream_map(&mut self, fold: &FoldStreamMap<'i>, span: Span) {
@@ -371,6 +539,7 @@ impl<'i> VariableValidator<'i> {
             Some(_) => self.met_popstack_replacing_with_check_instr(fold.iterator.name, span),
             None => self.met_replacing_with_check_instr(fold.iterator.name, span),
         }
+        self.met_fold_reachability(fold.last_instruction.is_some(), span);
     }
 
     pub(super) fn met_new(&mut self, new: &New<'i>, span: Span) {
@@ -379,6 +548,8 @@ impl<'i> VariableValidator<'i> {
         // new defines a new variable
         self.met_variable_name_definition(new.argument.name(), span);
         self.met_replacing_instr(span);
+        // new completes in the same way as its body
+        self.reachability_machine.met_compound(1, None, span);
     }
 
     pub(super) fn met_next(&mut self, next: &Next<'i>, span: Span) {
@@ -447,7 +618,29 @@ impl<'i> VariableValidator<'i> {
         self.met_simple_instr(span);
     }
 
-    pub(super) fn met_merging_instr(&mut self, span: Span) {
+    pub(super) fn met_seq(&mut self, span: Span) {
+        self.met_merging_instr(span);
+        self.reachability_machine.met_seq(span);
+    }
+
+    pub(super) fn met_par(&mut self, span: Span) {
+        self.met_merging_instr(span);
+        self.reachability_machine.met_par(span);
+    }
+
+    pub(super) fn met_null(&mut self, span: Span) {
+        self.after_next_machine
+            .met_instruction_kind(CheckInstructionKind::Simple, span);
+        self.reachability_machine.met_leaf(Completion::Always, span);
+    }
+
+    pub(super) fn met_never(&mut self, span: Span) {
+        self.after_next_machine
+            .met_instruction_kind(CheckInstructionKind::Simple, span);
+        self.reachability_machine.met_leaf(Completion::Never, span);
+    }
+
+    fn met_merging_instr(&mut self, span: Span) {
         self.after_next_machine
             .met_instruction_kind(CheckInstructionKind::Merging, span);
     }
@@ -455,6 +648,14 @@ impl<'i> VariableValidator<'i> {
     pub(super) fn met_pivotalnext_instr(&mut self, iterable_name: &'i str, span: Span) {
         self.after_next_machine
             .met_instruction_kind(CheckInstructionKind::PivotalNext(iterable_name), span);
+        self.reachability_machine
+            .met_leaf(Completion::Unknown, span);
+    }
+
+    fn met_fold_reachability(&mut self, has_last_instruction: bool, span: Span) {
+        let children_count = if has_last_instruction { 2 } else { 1 };
+        self.reachability_machine
+            .met_compound(children_count, Some(Completion::Unknown), span);
     }
 
     fn met_popstack_instr(&mut self, fold: &FoldScalar<'i>, span: Span) {
@@ -488,11 +689,21 @@ impl<'i> VariableValidator<'i> {
     pub(super) fn met_xoring_instr(&mut self, span: Span) {
         self.after_next_machine
             .met_instruction_kind(CheckInstructionKind::Xoring, span);
+        self.reachability_machine.met_xor(span);
     }
 
     pub(super) fn met_simple_instr(&mut self, span: Span) {
         self.after_next_machine
             .met_instruction_kind(CheckInstructionKind::Simple, span);
+        self.reachability_machine
+            .met_leaf(Completion::Unknown, span);
+    }
+
+    /// Returns diagnostics that don't prevent a script from being executed.
+    pub(super) fn warnings(&self) -> Vec<ValidationWarning> {
+        let mut warnings = self.reachability_machine.warnings.clone();
+        warnings.sort_by_key(ValidationWarning::span);
+        warnings
     }
 
     pub(super) fn finalize(self) -> Vec<ErrorRecovery<AirPos, Token<'i>, ParserError>> {
@@ -504,6 +715,7 @@ impl<'i> VariableValidator<'i> {
             .check_iterator_for_multiple_definitions()
             .check_for_unsupported_map_keys()
             .check_for_unsupported_literal_errcodes()
+            .check_for_invalid_regex_patterns()
             .check_after_next_instr()
             .build()
     }
@@ -804,6 +1016,19 @@ impl<'i> ValidatorErrorBuilder<'i> {
         self
     }
 
+    fn check_for_invalid_regex_patterns(mut self) -> Self {
+        for (pattern, reason, span) in self.validator.invalid_regex_patterns.iter() {
+            let error = ParserError::invalid_regex_pattern(*span, *pattern, reason.as_str());
+            add_to_errors(
+                &mut self.errors,
+                *span,
+                Token::StringLiteral(pattern),
+                error,
+            );
+        }
+        self
+    }
+
     fn check_after_next_instr(mut self) -> Self {
         for span in self.validator.after_next_machine.malformed_spans_iter() {
             let error = ParserError::fold_has_instruction_after_next(*span);
@@ -0,0 +1,219 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Incremental splitting of sources with many top-level AIR instructions.
+
+#[cfg(test)]
+mod tests;
+
+use crate::ast::Instruction;
+use crate::parse;
+use crate::AirParseError;
+
+use thiserror::Error as ThisError;
+
+use std::io::BufRead;
+
+/// Reads top-level instructions from `reader` one by one, so only the instruction being read
+/// is kept in memory.
+///
+/// Instructions are split by balanced round brackets, string literals and comments are taken
+/// into account the same way the lexer does. Each one is returned as an owned `ScriptChunk`,
+/// because `Instruction` borrows the source it was parsed from.
+pub fn parse_streaming<R: BufRead>(reader: R) -> StreamingParser<R> {
+    StreamingParser::new(reader)
+}
+
+/// Source of a single top-level instruction.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScriptChunk {
+    source: String,
+    offset: usize,
+}
+
+impl ScriptChunk {
+    /// Source code of the instruction, without surrounding whitespace and comments.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// Byte offset of the instruction in the whole input.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Parses the instruction, spans of errors are relative to the chunk source.
+    pub fn parse(&self) -> Result<Instruction<'_>, AirParseError> {
+        parse(&self.source)
+    }
+}
+
+#[derive(ThisError, Debug)]
+pub enum StreamingParseError {
+    #[error("failed to read AIR source: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("instruction started at {offset} isn't closed before the end of input")]
+    UnclosedInstruction { offset: usize },
+
+    #[error("unexpected '{found}' at {offset} outside of an instruction")]
+    UnexpectedText { found: char, offset: usize },
+}
+
+/// Iterator returned by `parse_streaming`, it stops after the first error.
+pub struct StreamingParser<R> {
+    reader: R,
+    // the rest of the last read line that isn't scanned yet
+    pending: String,
+    // offset of the pending part in the whole input
+    pending_offset: usize,
+    chunk: String,
+    chunk_offset: usize,
+    depth: usize,
+    in_string: bool,
+    in_comment: bool,
+    // comments and string literals start only at the token start, as in the lexer
+    in_token: bool,
+    finished: bool,
+}
+
+impl<R: BufRead> StreamingParser<R> {
+    fn new(reader: R) -> Self {
+        Self {
+            reader,
+            pending: String::new(),
+            pending_offset: 0,
+            chunk: String::new(),
+            chunk_offset: 0,
+            depth: 0,
+            in_string: false,
+            in_comment: false,
+            in_token: false,
+            finished: false,
+        }
+    }
+
+    fn next_chunk(&mut self) -> Result<Option<ScriptChunk>, StreamingParseError> {
+        loop {
+            if self.pending.is_empty() && !self.read_line()? {
+                return match self.depth {
+                    0 => Ok(None),
+                    _ => Err(StreamingParseError::UnclosedInstruction {
+                        offset: self.chunk_offset,
+                    }),
+                };
+            }
+
+            if let Some(chunk) = self.scan_pending()? {
+                return Ok(Some(chunk));
+            }
+        }
+    }
+
+    /// Returns false at the end of input.
+    fn read_line(&mut self) -> Result<bool, StreamingParseError> {
+        let read = self.reader.read_line(&mut self.pending)?;
+        Ok(read != 0)
+    }
+
+    /// Scans the pending part until the end of the current instruction, the scanned part
+    /// of the current instruction is moved into the chunk.
+    fn scan_pending(&mut self) -> Result<Option<ScriptChunk>, StreamingParseError> {
+        let mut chunk_start = 0;
+
+        for (position, ch) in self.pending.char_indices() {
+            if self.in_comment {
+                self.in_comment = ch != '\n';
+                continue;
+            }
+            if self.in_string {
+                self.in_string = ch != '"';
+                continue;
+            }
+
+            let token_start = !self.in_token;
+            self.in_token = !(ch.is_whitespace() || "()[]".contains(ch));
+
+            match ch {
+                '(' => {
+                    if self.depth == 0 {
+                        chunk_start = position;
+                        self.chunk_offset = self.pending_offset + position;
+                    }
+                    self.depth += 1;
+                }
+                ')' if self.depth > 0 => {
+                    self.depth -= 1;
+                    if self.depth == 0 {
+                        let end = position + ch.len_utf8();
+                        return Ok(Some(self.split_chunk(chunk_start, end)));
+                    }
+                }
+                ';' if token_start => {
+                    self.in_comment = true;
+                    self.in_token = false;
+                }
+                '"' if token_start && self.depth > 0 => {
+                    self.in_string = true;
+                    self.in_token = false;
+                }
+                ch if ch.is_whitespace() || self.depth > 0 => {}
+                found => {
+                    return Err(StreamingParseError::UnexpectedText {
+                        found,
+                        offset: self.pending_offset + position,
+                    })
+                }
+            }
+        }
+
+        if self.depth > 0 {
+            self.chunk.push_str(&self.pending[chunk_start..]);
+        }
+        self.pending_offset += self.pending.len();
+        self.pending.clear();
+
+        Ok(None)
+    }
+
+    fn split_chunk(&mut self, start: usize, end: usize) -> ScriptChunk {
+        self.chunk.push_str(&self.pending[start..end]);
+        self.pending.drain(..end);
+        self.pending_offset += end;
+
+        ScriptChunk {
+            source: std::mem::take(&mut self.chunk),
+            offset: self.chunk_offset,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for StreamingParser<R> {
+    type Item = Result<ScriptChunk, StreamingParseError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+
+        let result = self.next_chunk();
+        if !matches!(result, Ok(Some(_))) {
+            self.finished = true;
+        }
+
+        result.transpose()
+    }
+}
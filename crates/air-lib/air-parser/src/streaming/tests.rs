@@ -0,0 +1,114 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::parse_streaming;
+use super::StreamingParseError;
+use crate::ast::Instruction;
+
+use std::io::BufReader;
+
+fn chunk_sources(input: &str) -> Vec<String> {
+    parse_streaming(input.as_bytes())
+        .map(|chunk| chunk.expect("chunk should be read").source().to_owned())
+        .collect()
+}
+
+#[test]
+fn top_level_instructions_are_split() {
+    let input = r#"
+        ;; first script
+        (seq
+            (call "peer" ("service" "function ) ;") [] result)
+            (null)) (never)
+        ; a ) comment (
+        (ap "(" $stream)
+    "#;
+
+    let sources = chunk_sources(input);
+    assert_eq!(
+        sources,
+        vec![
+            r#"(seq
+            (call "peer" ("service" "function ) ;") [] result)
+            (null))"#,
+            "(never)",
+            r#"(ap "(" $stream)"#,
+        ]
+    );
+}
+
+#[test]
+fn chunks_are_parsed() {
+    let input = "(null)\n(seq (ap 1 scalar) (call \"peer\" (\"service\" \"function\") [scalar]))";
+
+    let chunks = parse_streaming(input.as_bytes())
+        .collect::<Result<Vec<_>, _>>()
+        .expect("chunks should be read");
+    assert_eq!(chunks[0].offset(), 0);
+    assert_eq!(chunks[1].offset(), 7);
+
+    let instructions = chunks
+        .iter()
+        .map(|chunk| chunk.parse().expect("chunk should be parsed"))
+        .collect::<Vec<_>>();
+    assert!(matches!(instructions[0], Instruction::Null(_)));
+    assert!(matches!(instructions[1], Instruction::Seq(_)));
+}
+
+#[test]
+fn input_is_read_incrementally() {
+    let script = r#"(call "peer" ("service" "function") [] result)"#;
+    let input = format!("{script}\n").repeat(1000);
+
+    // a small buffer makes instructions span several reads
+    let reader = BufReader::with_capacity(8, input.as_bytes());
+    let count = parse_streaming(reader)
+        .map(|chunk| {
+            let chunk = chunk.expect("chunk should be read");
+            assert_eq!(chunk.source(), script);
+        })
+        .count();
+    assert_eq!(count, 1000);
+}
+
+#[test]
+fn unclosed_instruction_is_reported() {
+    let input = "(null)\n  (seq (null)\n (null)";
+
+    let mut parser = parse_streaming(input.as_bytes());
+    assert!(parser.next().unwrap().is_ok());
+    assert!(matches!(
+        parser.next(),
+        Some(Err(StreamingParseError::UnclosedInstruction { offset: 9 }))
+    ));
+    assert!(parser.next().is_none());
+}
+
+#[test]
+fn text_outside_instructions_is_reported() {
+    let input = "(null) null (never)";
+
+    let mut parser = parse_streaming(input.as_bytes());
+    assert!(parser.next().unwrap().is_ok());
+    assert!(matches!(
+        parser.next(),
+        Some(Err(StreamingParseError::UnexpectedText {
+            found: 'n',
+            offset: 7
+        }))
+    ));
+    assert!(parser.next().is_none());
+}
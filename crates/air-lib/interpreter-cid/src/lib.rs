@@ -48,6 +48,7 @@ pub type CidRef = str;
 
 // there is no Rust multicodec crate with appropriate constants
 const JSON_CODEC: u64 = 0x0200;
+const RAW_CODEC: u64 = 0x55;
 
 #[derive(Serialize, Deserialize)]
 #[serde(transparent)]
@@ -177,6 +178,20 @@ pub fn raw_value_to_json_cid<Val>(raw_value: impl AsRef<[u8]>) -> CID<Val> {
     CID::new(cid.to_string())
 }
 
+/// Calculate a CID of raw bytes hashed with SHA-256, the way content addressed stores, e.g. IPFS, address blobs.
+pub fn raw_bytes_to_sha256_cid(raw_value: impl AsRef<[u8]>) -> CID<[u8]> {
+    use cid::Cid;
+    use multihash_codetable::{Code, MultihashDigest};
+
+    let hash = raw_value_hash::<sha2::Sha256>(raw_value);
+    let digest = Code::Sha2_256
+        .wrap(&hash)
+        .expect("can't happen: incorrect hash length");
+
+    let cid = Cid::new_v1(RAW_CODEC, digest);
+    CID::new(cid.to_string())
+}
+
 pub(crate) fn raw_value_hash<D: digest::Digest>(raw_value: impl AsRef<[u8]>) -> Vec<u8> {
     let mut hasher = D::new();
     hasher.update(raw_value);
@@ -209,4 +224,13 @@ mod tests {
             CID::new("bagaaihracpzxhsrpviexa7k6glwdhyh3a4kvy6j7qlcqokzqbs3q424cmxyq"),
         );
     }
+
+    #[test]
+    fn test_raw_bytes_sha256_cid() {
+        // the CID IPFS gives to an empty file
+        assert_eq!(
+            &*raw_bytes_to_sha256_cid(b"").get_inner(),
+            "bafkreihdwdcefgh4dqkjv67uzcmw7ojee6xedzdetojuzjevtenxquvyku",
+        );
+    }
 }
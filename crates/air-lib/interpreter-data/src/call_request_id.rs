@@ -0,0 +1,97 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use std::fmt::Debug;
+use std::fmt::Display;
+
+// call request ids are keys of call requests passed to a host, so they have the same width
+type CallRequestIdType = u32;
+
+/// An id of a call request exposed to a host, call results are matched to calls by it.
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+#[repr(transparent)]
+#[derive(::rkyv::Archive, ::rkyv::Serialize, ::rkyv::Deserialize)]
+#[archive(check_bytes)]
+pub struct CallRequestId(CallRequestIdType);
+
+/// Issues call request ids of a particle, every next id is bigger than the previous ones.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct CallRequestIdCounter {
+    last: CallRequestId,
+}
+
+impl CallRequestIdCounter {
+    /// Creates a counter issuing ids bigger than `last`, i.e. the last id recorded in data.
+    pub fn new(last: CallRequestId) -> Self {
+        Self { last }
+    }
+
+    // it isn't an iterator, since the counter never runs out of ids
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> CallRequestId {
+        // TODO: check for overflow
+        self.last = CallRequestId(self.last.0 + 1);
+        self.last
+    }
+
+    /// Returns the last issued id.
+    pub fn last(&self) -> CallRequestId {
+        self.last
+    }
+}
+
+impl From<CallRequestIdType> for CallRequestId {
+    fn from(value: CallRequestIdType) -> Self {
+        Self(value)
+    }
+}
+
+impl From<CallRequestId> for CallRequestIdType {
+    fn from(value: CallRequestId) -> Self {
+        value.0
+    }
+}
+
+impl Debug for CallRequestId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl Display for CallRequestId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counter_issues_ids_after_the_last_one() {
+        let mut counter = CallRequestIdCounter::new(CallRequestId::from(41));
+
+        assert_eq!(counter.next(), CallRequestId::from(42));
+        assert_eq!(counter.next(), CallRequestId::from(43));
+        assert_eq!(counter.last(), CallRequestId::from(43));
+        assert_eq!(serde_json::to_string(&counter.last()).unwrap(), "43");
+    }
+}
@@ -59,6 +59,14 @@ impl<Val> CidStore<Val> {
         self.0.iter()
     }
 
+    pub(crate) fn insert(&mut self, cid: CID<Val>, value: Rc<Val>) {
+        self.0.insert(cid, value);
+    }
+
+    pub(crate) fn remove(&mut self, cid: &CID<Val>) -> Option<Rc<Val>> {
+        self.0.remove(cid)
+    }
+
     pub fn check_reference<Src>(
         &self,
         _source_cid: &CID<Src>,
@@ -134,6 +142,19 @@ impl<Val> CidTracker<Val> {
     pub fn get(&self, cid: &CID<Val>) -> Option<Rc<Val>> {
         self.cids.get(cid).cloned()
     }
+
+    pub fn len(&self) -> usize {
+        self.cids.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cids.is_empty()
+    }
+
+    /// Drops all values whose CIDs are not accepted by the supplied predicate.
+    pub fn retain(&mut self, mut is_live: impl FnMut(&CID<Val>) -> bool) {
+        self.cids.retain(|cid, _| is_live(cid));
+    }
 }
 
 impl<Val: Serialize> CidTracker<Val> {
@@ -278,4 +299,17 @@ mod tests {
 
         assert_eq!(store.get(&CID::new("loremimpsumdolorsitament")), None);
     }
+
+    #[test]
+    fn test_retain() {
+        let mut tracker = CidTracker::new();
+        let live_cid = tracker.track_value(json!("live")).unwrap();
+        let dead_cid = tracker.track_value(json!("dead")).unwrap();
+
+        tracker.retain(|cid| cid == &live_cid);
+
+        assert_eq!(tracker.len(), 1);
+        assert_eq!(&*tracker.get(&live_cid).unwrap(), &json!("live"));
+        assert_eq!(tracker.get(&dead_cid), None);
+    }
 }
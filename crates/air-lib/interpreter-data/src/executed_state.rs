@@ -17,6 +17,7 @@
 mod impls;
 mod se_de;
 
+use crate::CallRequestId;
 use crate::GenerationIdx;
 use crate::JValue;
 use crate::RawValue;
@@ -62,7 +63,10 @@ pub struct ParResult {
 #[archive(check_bytes)]
 pub enum Sender {
     PeerId(Rc<String>),
-    PeerIdWithCallId { peer_id: Rc<String>, call_id: u32 },
+    PeerIdWithCallId {
+        peer_id: Rc<String>,
+        call_id: CallRequestId,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -300,4 +304,7 @@ pub enum ExecutedState {
     Fold(FoldResult),
     Ap(ApResult),
     Canon(CanonResult),
+    /// The previous state repeated the specified number of times more, it's produced by
+    /// `ExecutionTrace::compact` and expanded back before execution.
+    Repeated(u32),
 }
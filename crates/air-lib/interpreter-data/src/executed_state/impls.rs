@@ -36,7 +36,7 @@ impl CallResult {
         CallResult::RequestSentBy(Sender::PeerId(peer_id))
     }
 
-    pub fn sent_peer_id_with_call_id(peer_id: Rc<String>, call_id: u32) -> CallResult {
+    pub fn sent_peer_id_with_call_id(peer_id: Rc<String>, call_id: CallRequestId) -> CallResult {
         CallResult::RequestSentBy(Sender::PeerIdWithCallId { peer_id, call_id })
     }
 
@@ -204,6 +204,9 @@ impl std::fmt::Display for ExecutedState {
             Canon(_) => {
                 write!(f, "canon [<object>]")
             }
+            Repeated(count) => {
+                write!(f, "repeated({count})")
+            }
         }
     }
 }
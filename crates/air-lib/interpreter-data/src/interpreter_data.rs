@@ -14,14 +14,41 @@
  * limitations under the License.
  */
 
+#[cfg(feature = "zstd")]
+pub(crate) mod compression;
+pub(crate) mod diff;
+pub(crate) mod display;
 pub(crate) mod errors;
+pub(crate) mod patch;
 pub(crate) mod repr;
+pub(crate) mod schema;
+pub(crate) mod trace_verifier;
 pub mod verification;
 
+#[cfg(feature = "zstd")]
+pub use self::compression::is_compressed_data;
+#[cfg(feature = "zstd")]
+pub use self::compression::DataCompressionError;
+pub use self::diff::CidInfoDiff;
+pub use self::diff::CidStoreDiff;
+pub use self::diff::DataDiff;
+pub use self::diff::DataDiffError;
+pub use self::diff::TraceChange;
+pub use self::display::DisplayConfig;
+pub use self::display::DisplayMode;
+pub use self::patch::DataPatch;
+pub use self::patch::PatchError;
+pub use self::patch::TracePatch;
 pub use self::repr::InterpreterDataEnvelopeFormat;
 pub use self::repr::InterpreterDataEnvelopeRepr;
+pub use self::schema::supported_data_versions;
+pub use self::schema::FieldKind;
+pub use self::schema::SchemaError;
+pub use self::schema::SchemaVersion;
+use crate::CallRequestId;
 use crate::CidInfo;
 use crate::ExecutionTrace;
+use crate::MerkleHash;
 
 use air_interpreter_sede::FromSerialized;
 use air_interpreter_sede::Representation;
@@ -32,12 +59,19 @@ use serde::Serialize;
 
 use std::borrow::Cow;
 
+#[cfg(feature = "async")]
+const READ_CHUNK_SIZE: usize = 64 * 1024;
+
 #[derive(Debug, thiserror::Error)]
 pub enum DataDeserializationError {
     #[error("failed to deserialize envelope: {0}")]
     Envelope(rmp_serde::decode::Error),
     #[error("failed to deserialize data: {0}")]
     Data(crate::rkyv::RkyvDeserializeError),
+    #[error("failed to read data: {0}")]
+    Read(std::io::Error),
+    #[error("compacted trace expands to {expanded_len} states, but at most {limit} are allowed")]
+    TraceTooLong { expanded_len: usize, limit: usize },
 }
 
 /// An envelope for the AIR interpreter data that makes AIR data version info accessible in a stable way.
@@ -48,6 +82,10 @@ pub struct InterpreterDataEnvelope<'a> {
     pub versions: Versions,
     #[serde(with = "serde_bytes", borrow)]
     pub inner_data: Cow<'a, [u8]>,
+    /// Merkle root of the inner data trace, see `ExecutionTrace::merkle_root`. It's absent
+    /// in data of older interpreters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trace_merkle_root: Option<MerkleHash>,
 }
 
 /// The AIR interpreter could be considered as a function
@@ -72,7 +110,7 @@ pub struct InterpreterData {
     /// Last exposed to a peer call request id. All next call request ids will be bigger than this.
     #[serde(default)]
     #[serde(rename = "lcid")]
-    pub last_call_request_id: u32,
+    pub last_call_request_id: CallRequestId,
 
     /// CID-to-somethings mappings.
     pub cid_info: CidInfo,
@@ -93,6 +131,44 @@ impl InterpreterData {
         crate::rkyv::from_aligned_slice(&aligned_data).map_err(DataDeserializationError::Data)
     }
 
+    /// A convenience reader for data kept in files or sockets, it doesn't stream: the whole
+    /// input is buffered before deserialization, since the rkyv archive is validated as a whole.
+    /// The input is read straight into an aligned buffer, so, unlike `try_from_slice`,
+    /// the whole blob doesn't have to be kept in memory twice.
+    #[tracing::instrument(skip_all, level = "info")]
+    pub fn from_reader<R: std::io::Read>(mut reader: R) -> Result<Self, DataDeserializationError> {
+        let mut aligned_data = rkyv::AlignedVec::new();
+        aligned_data
+            .extend_from_reader(&mut reader)
+            .map_err(DataDeserializationError::Read)?;
+
+        crate::rkyv::from_aligned_slice(&aligned_data).map_err(DataDeserializationError::Data)
+    }
+
+    /// An async version of `from_reader`, it also buffers the whole input.
+    #[cfg(feature = "async")]
+    #[tracing::instrument(skip_all, level = "info")]
+    pub async fn from_async_reader<R: tokio::io::AsyncRead + Unpin>(
+        mut reader: R,
+    ) -> Result<Self, DataDeserializationError> {
+        use tokio::io::AsyncReadExt;
+
+        let mut aligned_data = rkyv::AlignedVec::new();
+        let mut chunk = vec![0u8; READ_CHUNK_SIZE];
+        loop {
+            let read_bytes = reader
+                .read(&mut chunk)
+                .await
+                .map_err(DataDeserializationError::Read)?;
+            if read_bytes == 0 {
+                break;
+            }
+            aligned_data.extend_from_slice(&chunk[..read_bytes]);
+        }
+
+        crate::rkyv::from_aligned_slice(&aligned_data).map_err(DataDeserializationError::Data)
+    }
+
     #[tracing::instrument(skip_all, level = "info")]
     pub fn serialize(&self) -> Result<Vec<u8>, crate::rkyv::RkyvSerializeError> {
         crate::rkyv::to_vec(self)
@@ -113,7 +189,9 @@ impl InterpreterDataEnvelope<'_> {
     pub fn new(interpreter_version: semver::Version) -> Self {
         let versions = Versions::new(interpreter_version);
 
-        let inner_data = InterpreterData::default()
+        let inner_data = InterpreterData::default();
+        let trace_merkle_root = Some(inner_data.trace.merkle_root());
+        let inner_data = inner_data
             .serialize()
             .expect("shouldn't fail on empty data")
             .into();
@@ -121,6 +199,7 @@ impl InterpreterDataEnvelope<'_> {
         Self {
             versions,
             inner_data,
+            trace_merkle_root,
         }
     }
 
@@ -129,10 +208,11 @@ impl InterpreterDataEnvelope<'_> {
         trace: ExecutionTrace,
         cid_info: CidInfo,
         signatures: SignatureStore,
-        last_call_request_id: u32,
+        last_call_request_id: CallRequestId,
         interpreter_version: semver::Version,
     ) -> Self {
         let versions = Versions::new(interpreter_version);
+        let trace_merkle_root = Some(trace.merkle_root());
 
         let inner_data = InterpreterData {
             trace,
@@ -149,6 +229,7 @@ impl InterpreterDataEnvelope<'_> {
         Self {
             versions,
             inner_data,
+            trace_merkle_root,
         }
     }
 
@@ -183,3 +264,54 @@ impl Versions {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::InterpreterData;
+    use crate::ExecutedState;
+
+    #[test]
+    fn from_reader_matches_from_slice() {
+        let data = InterpreterData {
+            trace: vec![ExecutedState::par(1, 0)].into(),
+            last_call_request_id: 42.into(),
+            ..<_>::default()
+        };
+        let serialized = data.serialize().unwrap();
+
+        let from_slice = InterpreterData::try_from_slice(&serialized).unwrap();
+        let from_reader = InterpreterData::from_reader(serialized.as_slice()).unwrap();
+
+        assert_eq!(from_reader.trace, from_slice.trace);
+        assert_eq!(from_reader.last_call_request_id, 42.into());
+    }
+
+    #[test]
+    fn envelope_stores_trace_merkle_root() {
+        use crate::InterpreterDataEnvelope;
+
+        let trace: crate::ExecutionTrace =
+            vec![ExecutedState::par(1, 0), ExecutedState::par(0, 0)].into();
+        let envelope = InterpreterDataEnvelope::from_execution_result(
+            trace.clone(),
+            <_>::default(),
+            <_>::default(),
+            0.into(),
+            semver::Version::new(1, 0, 0),
+        );
+        let serialized = envelope.serialize().unwrap();
+
+        let envelope = InterpreterDataEnvelope::try_from_slice(&serialized).unwrap();
+        assert_eq!(envelope.trace_merkle_root, Some(trace.merkle_root()));
+        let proof = trace.inclusion_proof(1).unwrap();
+        assert!(proof.verify(envelope.trace_merkle_root.unwrap()));
+    }
+
+    #[test]
+    fn from_reader_truncated_data() {
+        let serialized = InterpreterData::default().serialize().unwrap();
+        let truncated = &serialized[..serialized.len() / 2];
+
+        assert!(InterpreterData::from_reader(truncated).is_err());
+    }
+}
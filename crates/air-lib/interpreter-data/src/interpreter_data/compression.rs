@@ -0,0 +1,104 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::DataDeserializationError;
+use super::InterpreterData;
+
+/// Level 3 is the zstd default, it's a reasonable trade-off between speed and ratio.
+const COMPRESSION_LEVEL: i32 = 3;
+
+/// Each zstd frame starts with these bytes.
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xB5, 0x2F, 0xFD];
+
+#[derive(Debug, thiserror::Error)]
+pub enum DataCompressionError {
+    #[error("failed to serialize data: {0}")]
+    Serialize(crate::rkyv::RkyvSerializeError),
+    #[error("failed to compress data: {0}")]
+    Compress(std::io::Error),
+}
+
+impl InterpreterData {
+    /// Serializes data like `serialize` and compresses the result with Zstandard.
+    #[tracing::instrument(skip_all, level = "info")]
+    pub fn to_compressed_bytes(&self) -> Result<Vec<u8>, DataCompressionError> {
+        let data = self.serialize().map_err(DataCompressionError::Serialize)?;
+        zstd::encode_all(data.as_slice(), COMPRESSION_LEVEL).map_err(DataCompressionError::Compress)
+    }
+
+    /// Deserializes data produced by `to_compressed_bytes`.
+    #[tracing::instrument(skip_all, level = "info")]
+    pub fn from_compressed_bytes(bytes: &[u8]) -> Result<Self, DataDeserializationError> {
+        let decoder = zstd::Decoder::new(bytes).map_err(DataDeserializationError::Read)?;
+        Self::from_reader(decoder)
+    }
+}
+
+/// Checks whether bytes are a Zstandard payload, e.g. produced by `to_compressed_bytes`.
+/// Neither serialized `InterpreterData` envelopes, which are msgpack maps, nor empty data
+/// start with this magic, so data stores can use it to pick a payload format for stored bytes.
+pub fn is_compressed_data(bytes: &[u8]) -> bool {
+    bytes.starts_with(&ZSTD_MAGIC)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CidInfo;
+    use crate::CidTracker;
+    use crate::ExecutedState;
+    use crate::InterpreterDataEnvelope;
+    use crate::RawValue;
+
+    fn data_with_large_value() -> InterpreterData {
+        let mut tracker = CidTracker::<RawValue>::new();
+        tracker.track_raw_value(RawValue::from_value("value ".repeat(10_000)));
+
+        InterpreterData {
+            trace: vec![ExecutedState::par(1, 0), ExecutedState::par(0, 0)].into(),
+            last_call_request_id: 1.into(),
+            cid_info: CidInfo {
+                value_store: tracker.into(),
+                ..<_>::default()
+            },
+            ..<_>::default()
+        }
+    }
+
+    #[test]
+    fn compressed_data_roundtrip() {
+        let data = data_with_large_value();
+
+        let compressed = data.to_compressed_bytes().unwrap();
+        let decompressed = InterpreterData::from_compressed_bytes(&compressed).unwrap();
+
+        assert!(compressed.len() * 5 < data.serialize().unwrap().len());
+        assert_eq!(decompressed.trace, data.trace);
+        assert_eq!(decompressed.cid_info, data.cid_info);
+    }
+
+    #[test]
+    fn compressed_data_is_detected() {
+        let data = data_with_large_value();
+        let envelope = InterpreterDataEnvelope::new(semver::Version::new(1, 0, 0))
+            .serialize()
+            .unwrap();
+
+        assert!(is_compressed_data(&data.to_compressed_bytes().unwrap()));
+        assert!(!is_compressed_data(&envelope));
+        assert!(!is_compressed_data(&[]));
+    }
+}
@@ -0,0 +1,497 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::InterpreterData;
+use crate::CallRequestId;
+use crate::CidInfo;
+use crate::CidStore;
+use crate::ExecutedState;
+use crate::TracePos;
+
+use air_interpreter_cid::CID;
+use air_interpreter_signatures::SignatureStore;
+use serde::Deserialize;
+use serde::Serialize;
+use thiserror::Error as ThisError;
+
+use std::fmt;
+
+/// A delta between two data snapshots produced by `InterpreterData::diff`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataDiff {
+    /// Trace states compared position by position.
+    pub trace: Vec<TraceChange>,
+
+    /// Added and removed CID mappings.
+    pub cid_info: CidInfoDiff,
+
+    /// Before and after values of the last call request id, if it was bumped.
+    pub last_call_request_id: Option<(CallRequestId, CallRequestId)>,
+
+    /// Signatures of the after snapshot. Peers sign all their values together,
+    /// so signatures are carried as they are instead of being diffed.
+    pub signatures: SignatureStore,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TraceChange {
+    Added {
+        position: TracePos,
+        state: ExecutedState,
+    },
+    Removed {
+        position: TracePos,
+        state: ExecutedState,
+    },
+    Changed {
+        position: TracePos,
+        before: ExecutedState,
+        after: ExecutedState,
+    },
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CidInfoDiff {
+    pub value_store: CidStoreDiff<crate::RawValue>,
+    pub tetraplet_store: CidStoreDiff<polyplets::SecurityTetraplet>,
+    pub canon_element_store: CidStoreDiff<crate::CanonCidAggregate>,
+    pub canon_result_store: CidStoreDiff<crate::CanonResultCidAggregate>,
+    pub service_result_store: CidStoreDiff<crate::ServiceResultCidAggregate>,
+}
+
+/// CIDs are content-addressed, so a mapping could be only added or removed, but never changed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CidStoreDiff<Val> {
+    pub added: CidStore<Val>,
+    pub removed: Vec<CID<Val>>,
+}
+
+#[derive(Debug, ThisError)]
+pub enum DataDiffError {
+    #[error("trace state at position {position} doesn't match the diff")]
+    StateMismatch { position: TracePos },
+
+    #[error("state added at position {position}, but the trace length is {trace_len}")]
+    NonContiguousAddition {
+        position: TracePos,
+        trace_len: usize,
+    },
+
+    #[error("removed CID {0:?} isn't present in the base data")]
+    MissingCid(String),
+
+    #[error("last call request id of the base data is {actual}, but the diff expects {expected}")]
+    LastCallRequestIdMismatch {
+        expected: CallRequestId,
+        actual: CallRequestId,
+    },
+}
+
+impl InterpreterData {
+    /// Computes a delta that turns the `before` snapshot into the `after` one.
+    pub fn diff(before: &InterpreterData, after: &InterpreterData) -> DataDiff {
+        let last_call_request_id = (before.last_call_request_id != after.last_call_request_id)
+            .then_some((before.last_call_request_id, after.last_call_request_id));
+
+        DataDiff {
+            trace: diff_traces(&before.trace, &after.trace),
+            cid_info: CidInfoDiff::new(&before.cid_info, &after.cid_info),
+            last_call_request_id,
+            signatures: after.signatures.clone(),
+        }
+    }
+
+    /// Reconstructs a snapshot from a base and a diff, the base has to be the `before`
+    /// snapshot the diff was computed from.
+    pub fn apply_diff(
+        base: &InterpreterData,
+        diff: &DataDiff,
+    ) -> Result<InterpreterData, DataDiffError> {
+        let trace = apply_trace_diff(&base.trace, &diff.trace)?;
+        let cid_info = diff.cid_info.apply(&base.cid_info)?;
+
+        let last_call_request_id = match diff.last_call_request_id {
+            Some((before, after)) if before == base.last_call_request_id => after,
+            Some((before, _)) => {
+                return Err(DataDiffError::LastCallRequestIdMismatch {
+                    expected: before,
+                    actual: base.last_call_request_id,
+                })
+            }
+            None => base.last_call_request_id,
+        };
+
+        Ok(InterpreterData {
+            trace: trace.into(),
+            last_call_request_id,
+            cid_info,
+            signatures: diff.signatures.clone(),
+        })
+    }
+}
+
+impl DataDiff {
+    pub fn is_empty(&self) -> bool {
+        self.trace.is_empty() && self.cid_info.is_empty() && self.last_call_request_id.is_none()
+    }
+}
+
+fn diff_traces(before: &[ExecutedState], after: &[ExecutedState]) -> Vec<TraceChange> {
+    let position =
+        |index: usize| TracePos::try_from(index).expect("trace length fits into TracePos");
+
+    let changed = before
+        .iter()
+        .zip(after)
+        .enumerate()
+        .filter(|(_, (before, after))| before != after)
+        .map(|(index, (before, after))| TraceChange::Changed {
+            position: position(index),
+            before: before.clone(),
+            after: after.clone(),
+        });
+
+    let common_len = before.len().min(after.len());
+    let removed = before[common_len..]
+        .iter()
+        .enumerate()
+        .map(|(index, state)| TraceChange::Removed {
+            position: position(common_len + index),
+            state: state.clone(),
+        });
+    let added = after[common_len..]
+        .iter()
+        .enumerate()
+        .map(|(index, state)| TraceChange::Added {
+            position: position(common_len + index),
+            state: state.clone(),
+        });
+
+    changed.chain(removed).chain(added).collect()
+}
+
+fn apply_trace_diff(
+    base: &[ExecutedState],
+    changes: &[TraceChange],
+) -> Result<Vec<ExecutedState>, DataDiffError> {
+    let mut trace = base.to_vec();
+    let mut new_len = trace.len();
+
+    for change in changes {
+        match change {
+            TraceChange::Changed {
+                position,
+                before,
+                after,
+            } => {
+                let state = trace
+                    .get_mut(usize::from(*position))
+                    .filter(|state| *state == before)
+                    .ok_or(DataDiffError::StateMismatch {
+                        position: *position,
+                    })?;
+                *state = after.clone();
+            }
+            TraceChange::Removed { position, state } => {
+                if trace.get(usize::from(*position)) != Some(state) {
+                    return Err(DataDiffError::StateMismatch {
+                        position: *position,
+                    });
+                }
+                new_len = new_len.min(usize::from(*position));
+            }
+            TraceChange::Added { .. } => {}
+        }
+    }
+    trace.truncate(new_len);
+
+    for change in changes {
+        if let TraceChange::Added { position, state } = change {
+            if usize::from(*position) != trace.len() {
+                return Err(DataDiffError::NonContiguousAddition {
+                    position: *position,
+                    trace_len: trace.len(),
+                });
+            }
+            trace.push(state.clone());
+        }
+    }
+
+    Ok(trace)
+}
+
+impl CidInfoDiff {
+    pub(super) fn new(before: &CidInfo, after: &CidInfo) -> Self {
+        Self {
+            value_store: CidStoreDiff::new(&before.value_store, &after.value_store),
+            tetraplet_store: CidStoreDiff::new(&before.tetraplet_store, &after.tetraplet_store),
+            canon_element_store: CidStoreDiff::new(
+                &before.canon_element_store,
+                &after.canon_element_store,
+            ),
+            canon_result_store: CidStoreDiff::new(
+                &before.canon_result_store,
+                &after.canon_result_store,
+            ),
+            service_result_store: CidStoreDiff::new(
+                &before.service_result_store,
+                &after.service_result_store,
+            ),
+        }
+    }
+
+    pub(super) fn apply(&self, base: &CidInfo) -> Result<CidInfo, DataDiffError> {
+        Ok(CidInfo {
+            value_store: self.value_store.apply(&base.value_store)?,
+            tetraplet_store: self.tetraplet_store.apply(&base.tetraplet_store)?,
+            canon_element_store: self.canon_element_store.apply(&base.canon_element_store)?,
+            canon_result_store: self.canon_result_store.apply(&base.canon_result_store)?,
+            service_result_store: self
+                .service_result_store
+                .apply(&base.service_result_store)?,
+        })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.value_store.is_empty()
+            && self.tetraplet_store.is_empty()
+            && self.canon_element_store.is_empty()
+            && self.canon_result_store.is_empty()
+            && self.service_result_store.is_empty()
+    }
+}
+
+impl<Val> CidStoreDiff<Val> {
+    fn new(before: &CidStore<Val>, after: &CidStore<Val>) -> Self {
+        let mut added = CidStore::new();
+        for (cid, value) in after.iter() {
+            if before.get(cid).is_none() {
+                added.insert(cid.clone(), value.clone());
+            }
+        }
+
+        let removed = before
+            .iter()
+            .filter(|(cid, _)| after.get(cid).is_none())
+            .map(|(cid, _)| cid.clone())
+            .collect();
+
+        Self { added, removed }
+    }
+
+    fn apply(&self, base: &CidStore<Val>) -> Result<CidStore<Val>, DataDiffError> {
+        let mut store = CidStore::new();
+        for (cid, value) in base.iter() {
+            store.insert(cid.clone(), value.clone());
+        }
+
+        for cid in &self.removed {
+            store
+                .remove(cid)
+                .ok_or_else(|| DataDiffError::MissingCid(cid.as_ref().to_owned()))?;
+        }
+        for (cid, value) in self.added.iter() {
+            store.insert(cid.clone(), value.clone());
+        }
+
+        Ok(store)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+impl<Val> Default for CidStoreDiff<Val> {
+    fn default() -> Self {
+        Self {
+            added: <_>::default(),
+            removed: <_>::default(),
+        }
+    }
+}
+
+impl fmt::Display for DataDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "trace:")?;
+        for change in &self.trace {
+            writeln!(f, "  {change}")?;
+        }
+
+        writeln!(f, "cid_info:")?;
+        write!(f, "{}", self.cid_info)?;
+
+        if let Some((before, after)) = self.last_call_request_id {
+            writeln!(f, "last_call_request_id: {before} => {after}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for TraceChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TraceChange::Added { position, state } => write!(f, "+ {position}: {state}"),
+            TraceChange::Removed { position, state } => write!(f, "- {position}: {state}"),
+            TraceChange::Changed {
+                position,
+                before,
+                after,
+            } => write!(f, "~ {position}: {before} => {after}"),
+        }
+    }
+}
+
+impl fmt::Display for CidInfoDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_cid_store_diff(f, "value_store", &self.value_store)?;
+        write_cid_store_diff(f, "tetraplet_store", &self.tetraplet_store)?;
+        write_cid_store_diff(f, "canon_element_store", &self.canon_element_store)?;
+        write_cid_store_diff(f, "canon_result_store", &self.canon_result_store)?;
+        write_cid_store_diff(f, "service_result_store", &self.service_result_store)
+    }
+}
+
+fn write_cid_store_diff<Val>(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    diff: &CidStoreDiff<Val>,
+) -> fmt::Result {
+    for (cid, _) in diff.added.iter() {
+        writeln!(f, "  + {name}: {}", cid.as_ref())?;
+    }
+    for cid in &diff.removed {
+        writeln!(f, "  - {name}: {}", cid.as_ref())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CallResult;
+    use crate::CidTracker;
+    use crate::RawValue;
+
+    use std::rc::Rc;
+
+    fn data_with(
+        trace: Vec<ExecutedState>,
+        values: &[&str],
+        last_call_request_id: u32,
+    ) -> InterpreterData {
+        let mut tracker = CidTracker::<RawValue>::new();
+        for value in values {
+            tracker.track_raw_value(RawValue::from_value(*value));
+        }
+
+        InterpreterData {
+            trace: trace.into(),
+            last_call_request_id: last_call_request_id.into(),
+            cid_info: CidInfo {
+                value_store: tracker.into(),
+                ..<_>::default()
+            },
+            ..<_>::default()
+        }
+    }
+
+    #[test]
+    fn diff_lists_trace_and_cid_changes() {
+        let before = data_with(
+            vec![ExecutedState::par(1, 0), ExecutedState::par(0, 0)],
+            &["a", "b"],
+            1,
+        );
+        let after = data_with(
+            vec![
+                ExecutedState::par(1, 1),
+                ExecutedState::par(0, 0),
+                ExecutedState::par(0, 0),
+            ],
+            &["b", "c"],
+            3,
+        );
+
+        let diff = InterpreterData::diff(&before, &after);
+
+        assert_eq!(
+            diff.trace,
+            vec![
+                TraceChange::Changed {
+                    position: 0.into(),
+                    before: ExecutedState::par(1, 0),
+                    after: ExecutedState::par(1, 1),
+                },
+                TraceChange::Added {
+                    position: 2.into(),
+                    state: ExecutedState::par(0, 0),
+                },
+            ]
+        );
+        assert_eq!(diff.cid_info.value_store.added.len(), 1);
+        assert_eq!(diff.cid_info.value_store.removed.len(), 1);
+        assert_eq!(diff.last_call_request_id, Some((1.into(), 3.into())));
+        assert!(InterpreterData::diff(&after, &after).is_empty());
+    }
+
+    #[test]
+    fn apply_diff_reconstructs_snapshot() {
+        let before = data_with(
+            vec![
+                ExecutedState::par(1, 0),
+                ExecutedState::par(0, 0),
+                ExecutedState::par(0, 0),
+            ],
+            &["a"],
+            0,
+        );
+        let after = data_with(
+            vec![
+                ExecutedState::par(0, 1),
+                ExecutedState::Call(CallResult::sent_peer_id(Rc::new("peer".to_owned()))),
+            ],
+            &["a", "b"],
+            0,
+        );
+
+        let diff = InterpreterData::diff(&before, &after);
+        let serialized = serde_json::to_vec(&diff).unwrap();
+        let diff: DataDiff = serde_json::from_slice(&serialized).unwrap();
+        let reconstructed = InterpreterData::apply_diff(&before, &diff).unwrap();
+
+        assert_eq!(reconstructed.trace, after.trace);
+        assert_eq!(reconstructed.cid_info, after.cid_info);
+        assert_eq!(
+            reconstructed.last_call_request_id,
+            after.last_call_request_id
+        );
+    }
+
+    #[test]
+    fn apply_diff_to_wrong_base() {
+        let before = data_with(vec![ExecutedState::par(1, 0)], &[], 0);
+        let after = data_with(vec![ExecutedState::par(1, 1)], &[], 0);
+        let diff = InterpreterData::diff(&before, &after);
+
+        let result = InterpreterData::apply_diff(&after, &diff);
+
+        assert!(matches!(result, Err(DataDiffError::StateMismatch { .. })));
+    }
+}
@@ -0,0 +1,256 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::InterpreterData;
+use crate::CidStore;
+use crate::RawValue;
+
+use serde::Serialize;
+
+use std::fmt;
+
+/// How much detail `InterpreterData::to_human_readable` prints for every entry.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayMode {
+    /// Every trace state and CID mapping is printed as a single line of JSON.
+    #[default]
+    Compact,
+
+    /// Every trace state and CID mapping is printed as indented pretty-printed JSON.
+    Verbose,
+}
+
+/// Config of the human-readable representation of `InterpreterData`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DisplayConfig {
+    pub mode: DisplayMode,
+}
+
+impl DisplayConfig {
+    pub fn compact() -> Self {
+        Self {
+            mode: DisplayMode::Compact,
+        }
+    }
+
+    pub fn verbose() -> Self {
+        Self {
+            mode: DisplayMode::Verbose,
+        }
+    }
+}
+
+impl InterpreterData {
+    /// Formats the trace, CID mappings, signatures and the last call request id for debugging.
+    /// Versions aren't a part of the data, they are available in `InterpreterDataEnvelope`.
+    pub fn to_human_readable(&self, config: DisplayConfig) -> String {
+        HumanReadable { data: self, config }.to_string()
+    }
+}
+
+impl fmt::Display for InterpreterData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        HumanReadable {
+            data: self,
+            config: DisplayConfig::default(),
+        }
+        .fmt(f)
+    }
+}
+
+struct HumanReadable<'data> {
+    data: &'data InterpreterData,
+    config: DisplayConfig,
+}
+
+impl fmt::Display for HumanReadable<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { data, config } = self;
+        let mode = config.mode;
+
+        writeln!(f, "last call request id: {}", data.last_call_request_id)?;
+
+        writeln!(f, "trace ({} states):", data.trace.len())?;
+        for (pos, state) in data.trace.iter().enumerate() {
+            write_entry(f, &pos.to_string(), state, mode)?;
+        }
+
+        let cid_info = &data.cid_info;
+        write_cid_store(
+            f,
+            "value_store",
+            &cid_info.value_store,
+            mode,
+            raw_value_to_json,
+        )?;
+        write_cid_store(
+            f,
+            "tetraplet_store",
+            &cid_info.tetraplet_store,
+            mode,
+            Clone::clone,
+        )?;
+        write_cid_store(
+            f,
+            "canon_element_store",
+            &cid_info.canon_element_store,
+            mode,
+            Clone::clone,
+        )?;
+        write_cid_store(
+            f,
+            "canon_result_store",
+            &cid_info.canon_result_store,
+            mode,
+            Clone::clone,
+        )?;
+        write_cid_store(
+            f,
+            "service_result_store",
+            &cid_info.service_result_store,
+            mode,
+            Clone::clone,
+        )?;
+
+        let mut signatures = data
+            .signatures
+            .iter()
+            .map(|(public_key, signature)| (public_key.to_string(), signature))
+            .collect::<Vec<_>>();
+        signatures.sort_by(|(lhs, _), (rhs, _)| lhs.cmp(rhs));
+
+        writeln!(f, "signatures ({} peers):", signatures.len())?;
+        for (public_key, signature) in signatures {
+            write_entry(f, &public_key, signature, mode)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_cid_store<Val, Repr: Serialize>(
+    f: &mut fmt::Formatter<'_>,
+    name: &str,
+    store: &CidStore<Val>,
+    mode: DisplayMode,
+    to_repr: impl Fn(&Val) -> Repr,
+) -> fmt::Result {
+    // the store is a hash map, so entries are sorted to get a stable output
+    let mut entries = store.iter().collect::<Vec<_>>();
+    entries.sort_by(|(lhs, _), (rhs, _)| lhs.as_ref().cmp(rhs.as_ref()));
+
+    writeln!(f, "{name} ({} entries):", entries.len())?;
+    for (cid, value) in entries {
+        write_entry(f, cid.as_ref(), &to_repr(value), mode)?;
+    }
+
+    Ok(())
+}
+
+fn write_entry(
+    f: &mut fmt::Formatter<'_>,
+    key: &str,
+    value: &impl Serialize,
+    mode: DisplayMode,
+) -> fmt::Result {
+    match mode {
+        DisplayMode::Compact => {
+            let value = serde_json::to_string(value).map_err(|_| fmt::Error)?;
+            writeln!(f, "  {key}: {value}")
+        }
+        DisplayMode::Verbose => {
+            let value = serde_json::to_string_pretty(value).map_err(|_| fmt::Error)?;
+            writeln!(f, "  {key}:")?;
+            for line in value.lines() {
+                writeln!(f, "    {line}")?;
+            }
+            Ok(())
+        }
+    }
+}
+
+// a value that can't be parsed is still worth showing, so it falls back to the raw string
+fn raw_value_to_json(value: &RawValue) -> serde_json::Value {
+    value
+        .as_inner()
+        .parse()
+        .unwrap_or_else(|_| serde_json::Value::String(value.as_inner().to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CidInfo;
+    use crate::CidTracker;
+    use crate::ExecutedState;
+
+    fn data_with(trace: Vec<ExecutedState>, values: &[&str]) -> InterpreterData {
+        let mut tracker = CidTracker::<RawValue>::new();
+        for value in values {
+            tracker.track_raw_value(RawValue::from_value(*value));
+        }
+
+        InterpreterData {
+            trace: trace.into(),
+            last_call_request_id: 3.into(),
+            cid_info: CidInfo {
+                value_store: tracker.into(),
+                ..<_>::default()
+            },
+            ..<_>::default()
+        }
+    }
+
+    #[test]
+    fn compact_prints_entry_per_line() {
+        let data = data_with(
+            vec![ExecutedState::par(1, 0), ExecutedState::par(0, 0)],
+            &["a", "b"],
+        );
+
+        let output = data.to_human_readable(DisplayConfig::compact());
+        let lines = output.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines[0], "last call request id: 3");
+        assert_eq!(lines[1], "trace (2 states):");
+        assert!(lines[2].starts_with("  0: "));
+        assert!(lines[3].starts_with("  1: "));
+        assert_eq!(lines[4], "value_store (2 entries):");
+        assert!(lines[5].ends_with(": \"a\"") || lines[5].ends_with(": \"b\""));
+        assert_eq!(lines.last(), Some(&"signatures (0 peers):"));
+    }
+
+    #[test]
+    fn verbose_expands_entries() {
+        let data = data_with(vec![ExecutedState::par(1, 0)], &[]);
+
+        let compact = data.to_human_readable(DisplayConfig::compact());
+        let verbose = data.to_human_readable(DisplayConfig::verbose());
+
+        assert!(verbose.lines().count() > compact.lines().count());
+        assert!(verbose.contains("\n  0:\n    "));
+    }
+
+    #[test]
+    fn display_uses_default_config() {
+        let data = data_with(vec![ExecutedState::par(1, 0)], &["a"]);
+
+        assert_eq!(
+            data.to_string(),
+            data.to_human_readable(DisplayConfig::default())
+        );
+    }
+}
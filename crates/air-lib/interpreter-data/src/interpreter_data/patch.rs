@@ -0,0 +1,275 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::CidInfoDiff;
+use super::DataDiffError;
+use super::InterpreterData;
+use crate::CallRequestId;
+use crate::ExecutedState;
+use crate::TracePos;
+
+use air_interpreter_signatures::SignatureStore;
+use serde::Deserialize;
+use serde::Serialize;
+use thiserror::Error as ThisError;
+
+/// A delta between two data snapshots produced by `InterpreterData::compute_patch`, intended
+/// to be sent instead of the whole data to a peer that has the old snapshot. Unlike `DataDiff`,
+/// it keeps only the resulting states, so its size depends on the amount of new states,
+/// not on the trace length.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataPatch {
+    #[serde(flatten)]
+    pub trace: TracePatch,
+
+    /// Added and removed CID mappings.
+    pub cid_info: CidInfoDiff,
+
+    pub last_call_request_id: CallRequestId,
+
+    /// Signatures of the new snapshot. Peers sign all their values together,
+    /// so signatures are carried as they are.
+    pub signatures: SignatureStore,
+}
+
+/// A delta between two traces produced by `InterpreterData::diff_trace`.
+///
+/// A trace grows with execution, but par and fold states of the base trace are updated in place
+/// when their subtraces grow, so these states are carried along with the appended ones.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TracePatch {
+    /// Length of the trace the patch could be applied to.
+    pub base_trace_len: usize,
+
+    /// Length of the resulting trace.
+    pub trace_len: usize,
+
+    /// New values of the states changed in place, e.g. par states with grown subtraces.
+    pub changed_states: Vec<(TracePos, ExecutedState)>,
+
+    /// States appended to the end of the trace.
+    pub new_states: Vec<ExecutedState>,
+}
+
+#[derive(Debug, ThisError)]
+pub enum PatchError {
+    #[error(
+        "patch is computed for a trace of length {expected}, but the base trace length is {actual}"
+    )]
+    TraceLenMismatch { expected: usize, actual: usize },
+
+    #[error("patch changes state at position {position}, but the trace length is {trace_len}")]
+    PositionOutOfTrace {
+        position: TracePos,
+        trace_len: usize,
+    },
+
+    #[error(transparent)]
+    CidInfo(#[from] DataDiffError),
+}
+
+impl InterpreterData {
+    /// Computes a patch that turns the `old` snapshot into the `new` one.
+    pub fn compute_patch(old: &InterpreterData, new: &InterpreterData) -> DataPatch {
+        DataPatch {
+            trace: Self::diff_trace(&old.trace, &new.trace),
+            cid_info: CidInfoDiff::new(&old.cid_info, &new.cid_info),
+            last_call_request_id: new.last_call_request_id,
+            signatures: new.signatures.clone(),
+        }
+    }
+
+    /// Reconstructs the new snapshot a patch was computed for, `self` has to be the old one.
+    pub fn apply_patch(&self, patch: &DataPatch) -> Result<InterpreterData, PatchError> {
+        Ok(InterpreterData {
+            trace: patch.trace.apply(&self.trace)?.into(),
+            last_call_request_id: patch.last_call_request_id,
+            cid_info: patch.cid_info.apply(&self.cid_info)?,
+            signatures: patch.signatures.clone(),
+        })
+    }
+
+    /// Computes a patch that turns the `base` trace into the `updated` one.
+    pub fn diff_trace(base: &[ExecutedState], updated: &[ExecutedState]) -> TracePatch {
+        let common_len = base.len().min(updated.len());
+        let changed_states = base
+            .iter()
+            .zip(updated)
+            .enumerate()
+            .filter(|(_, (base, updated))| base != updated)
+            .map(|(index, (_, updated))| {
+                let position = TracePos::try_from(index).expect("trace length fits into TracePos");
+                (position, updated.clone())
+            })
+            .collect();
+
+        TracePatch {
+            base_trace_len: base.len(),
+            trace_len: updated.len(),
+            changed_states,
+            new_states: updated[common_len..].to_vec(),
+        }
+    }
+
+    /// Applies a trace patch to the trace of `base`, other fields are kept as they are,
+    /// so the values new states refer to have to be known to `base` already.
+    /// `apply_patch` should be used otherwise.
+    pub fn apply_trace_patch(base: &Self, patch: &TracePatch) -> Result<Self, PatchError> {
+        Ok(InterpreterData {
+            trace: patch.apply(&base.trace)?.into(),
+            ..base.clone()
+        })
+    }
+}
+
+impl TracePatch {
+    fn apply(&self, base_trace: &[ExecutedState]) -> Result<Vec<ExecutedState>, PatchError> {
+        if base_trace.len() != self.base_trace_len {
+            return Err(PatchError::TraceLenMismatch {
+                expected: self.base_trace_len,
+                actual: base_trace.len(),
+            });
+        }
+
+        let mut trace = base_trace[..self.trace_len.min(base_trace.len())].to_vec();
+        for (position, state) in &self.changed_states {
+            let trace_len = trace.len();
+            let old_state =
+                trace
+                    .get_mut(usize::from(*position))
+                    .ok_or(PatchError::PositionOutOfTrace {
+                        position: *position,
+                        trace_len,
+                    })?;
+            *old_state = state.clone();
+        }
+        trace.extend_from_slice(&self.new_states);
+
+        Ok(trace)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CallResult;
+    use crate::CidInfo;
+    use crate::CidTracker;
+    use crate::RawValue;
+
+    use air_interpreter_cid::CID;
+
+    fn executed(value: &str) -> ExecutedState {
+        ExecutedState::Call(CallResult::executed_scalar(CID::new(value)))
+    }
+
+    fn data_with(trace: Vec<ExecutedState>, values: &[&str]) -> InterpreterData {
+        let mut tracker = CidTracker::<RawValue>::new();
+        for value in values {
+            tracker.track_raw_value(RawValue::from_value(*value));
+        }
+
+        InterpreterData {
+            trace: trace.into(),
+            cid_info: CidInfo {
+                value_store: tracker.into(),
+                ..<_>::default()
+            },
+            ..<_>::default()
+        }
+    }
+
+    #[test]
+    fn patch_keeps_only_new_states() {
+        let old_trace = (0..100).map(|id| executed(&id.to_string()));
+        let old = data_with(
+            std::iter::once(ExecutedState::par(100, 0))
+                .chain(old_trace.clone())
+                .collect(),
+            &["a"],
+        );
+        let new = data_with(
+            std::iter::once(ExecutedState::par(100, 1))
+                .chain(old_trace)
+                .chain(std::iter::once(executed("new")))
+                .collect(),
+            &["a", "b"],
+        );
+
+        let patch = InterpreterData::compute_patch(&old, &new);
+        let serialized_patch = serde_json::to_vec(&patch).unwrap();
+        let patch: DataPatch = serde_json::from_slice(&serialized_patch).unwrap();
+        let patched = old.apply_patch(&patch).unwrap();
+
+        assert_eq!(
+            patch.trace.changed_states,
+            vec![(0.into(), ExecutedState::par(100, 1))]
+        );
+        assert_eq!(patch.trace.new_states, vec![executed("new")]);
+        assert!(serialized_patch.len() < serde_json::to_vec(&new).unwrap().len() / 5);
+        assert_eq!(patched.trace, new.trace);
+        assert_eq!(patched.cid_info, new.cid_info);
+    }
+
+    #[test]
+    fn patch_shrinking_trace() {
+        let old = data_with(vec![ExecutedState::par(1, 0), executed("a")], &[]);
+        let new = data_with(vec![ExecutedState::par(0, 0)], &[]);
+
+        let patch = InterpreterData::compute_patch(&old, &new);
+        let patched = old.apply_patch(&patch).unwrap();
+
+        assert_eq!(patched.trace, new.trace);
+    }
+
+    #[test]
+    fn patch_applied_to_wrong_base() {
+        let old = data_with(vec![ExecutedState::par(0, 0)], &[]);
+        let new = data_with(vec![ExecutedState::par(1, 0), executed("a")], &[]);
+        let patch = InterpreterData::compute_patch(&old, &new);
+
+        let result = new.apply_patch(&patch);
+
+        assert!(matches!(
+            result,
+            Err(PatchError::TraceLenMismatch {
+                expected: 1,
+                actual: 2
+            })
+        ));
+    }
+
+    #[test]
+    fn trace_patch_keeps_other_fields_of_base() {
+        let base = InterpreterData {
+            last_call_request_id: 1.into(),
+            ..data_with(vec![ExecutedState::par(1, 0), executed("a")], &["a"])
+        };
+        let updated_trace = vec![ExecutedState::par(2, 0), executed("a"), executed("a")];
+
+        let patch = InterpreterData::diff_trace(&base.trace, &updated_trace);
+        let patched = InterpreterData::apply_trace_patch(&base, &patch).unwrap();
+
+        assert_eq!(
+            patch.changed_states,
+            vec![(0.into(), ExecutedState::par(2, 0))]
+        );
+        assert_eq!(patch.new_states, vec![executed("a")]);
+        assert_eq!(&*patched.trace, updated_trace.as_slice());
+        assert_eq!(patched.cid_info, base.cid_info);
+        assert_eq!(patched.last_call_request_id, base.last_call_request_id);
+    }
+}
@@ -0,0 +1,411 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Checks of raw interpreter data against the shape this crate version expects.
+//!
+//! The envelope is a self-describing MessagePack map, so its fields are checked against
+//! a schema of the data version. The inner data is an rkyv archive that has no field names,
+//! so it's checked by rkyv validation of the archive of the matching version.
+
+use super::InterpreterData;
+use crate::rkyv::RkyvDeserializeError;
+
+use serde::de::Visitor;
+use serde::Deserialize;
+use serde::Deserializer;
+use thiserror::Error as ThisError;
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Version of the data format the raw data conforms to.
+pub type SchemaVersion = semver::Version;
+
+const DATA_VERSION_FIELD: &str = "version";
+const INNER_DATA_FIELD: &str = "inner_data";
+
+/// Envelope fields of the data versions supported by this crate.
+const ENVELOPE_FIELDS: &[FieldSchema] = &[
+    FieldSchema::required(DATA_VERSION_FIELD, FieldKind::String),
+    FieldSchema::required("interpreter_version", FieldKind::String),
+    FieldSchema::required(INNER_DATA_FIELD, FieldKind::Binary),
+    FieldSchema::optional("trace_merkle_root", FieldKind::Array),
+];
+
+#[derive(ThisError, Debug)]
+pub enum SchemaError {
+    #[error("envelope isn't a MessagePack map: {0}")]
+    MalformedEnvelope(rmp_serde::decode::Error),
+
+    #[error("data version '{version}' isn't a valid semver: {error}")]
+    InvalidVersion {
+        version: String,
+        error: semver::Error,
+    },
+
+    #[error("data version {actual} isn't supported, supported versions are {supported}")]
+    UnsupportedVersion {
+        actual: SchemaVersion,
+        supported: semver::VersionReq,
+    },
+
+    #[error("envelope contains unknown fields: {0:?}")]
+    UnknownFields(Vec<String>),
+
+    #[error("envelope lacks required fields: {0:?}")]
+    MissingFields(Vec<&'static str>),
+
+    #[error("envelope field '{field}' is expected to be {expected}, but it's {actual}")]
+    InvalidFieldType {
+        field: &'static str,
+        expected: FieldKind,
+        actual: FieldKind,
+    },
+
+    #[error("inner data doesn't match the data version: {0}")]
+    InvalidInnerData(RkyvDeserializeError),
+}
+
+/// Kind of a MessagePack value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    Nil,
+    Bool,
+    Number,
+    String,
+    Binary,
+    Array,
+    Map,
+}
+
+impl fmt::Display for FieldKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            FieldKind::Nil => "nil",
+            FieldKind::Bool => "a bool",
+            FieldKind::Number => "a number",
+            FieldKind::String => "a string",
+            FieldKind::Binary => "a binary",
+            FieldKind::Array => "an array",
+            FieldKind::Map => "a map",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl InterpreterData {
+    /// Checks raw data, i.e. a serialized envelope, against the schema of its data version
+    /// without deserializing it. It makes possible to detect data produced by a newer
+    /// interpreter before deserialization fails on it.
+    pub fn validate_schema(raw: &[u8]) -> Result<SchemaVersion, SchemaError> {
+        let fields: BTreeMap<String, FieldValue<'_>> =
+            rmp_serde::from_slice(raw).map_err(SchemaError::MalformedEnvelope)?;
+
+        // the version goes first, since other mismatches are expected for unsupported versions
+        let version = match fields.get(DATA_VERSION_FIELD) {
+            Some(FieldValue::String(version)) => Some(parse_version(version)?),
+            _ => None,
+        };
+        check_fields(&fields)?;
+
+        let (Some(version), FieldValue::Binary(inner_data)) = (version, &fields[INNER_DATA_FIELD])
+        else {
+            unreachable!("field types are checked above");
+        };
+        let mut aligned_data = rkyv::AlignedVec::with_capacity(inner_data.len());
+        aligned_data.extend_from_slice(inner_data);
+        crate::rkyv::check_aligned_slice::<InterpreterData>(&aligned_data)
+            .map_err(SchemaError::InvalidInnerData)?;
+
+        Ok(version)
+    }
+}
+
+/// The oldest data version this crate is able to deserialize. Data of 0.18.0 differs from 0.17
+/// only by the `ExecutedState::Repeated` state, so older data is still readable as is.
+const OLDEST_SUPPORTED_DATA_VERSION: &str = "0.17.2";
+
+/// Returns versions of data this crate is able to deserialize.
+pub fn supported_data_versions() -> semver::VersionReq {
+    let version = crate::data_version();
+    let next_breaking = if version.major == 0 {
+        semver::Version::new(0, version.minor + 1, 0)
+    } else {
+        semver::Version::new(version.major + 1, 0, 0)
+    };
+    semver::VersionReq::parse(&format!(
+        ">={OLDEST_SUPPORTED_DATA_VERSION}, <{next_breaking}"
+    ))
+    .expect("data version is a valid semver")
+}
+
+fn parse_version(version: &str) -> Result<SchemaVersion, SchemaError> {
+    let actual = semver::Version::parse(version).map_err(|error| SchemaError::InvalidVersion {
+        version: version.to_string(),
+        error,
+    })?;
+
+    let supported = supported_data_versions();
+    if !supported.matches(&actual) {
+        return Err(SchemaError::UnsupportedVersion { actual, supported });
+    }
+
+    Ok(actual)
+}
+
+fn check_fields(fields: &BTreeMap<String, FieldValue<'_>>) -> Result<(), SchemaError> {
+    let unknown_fields: Vec<_> = fields
+        .keys()
+        .filter(|name| {
+            !ENVELOPE_FIELDS
+                .iter()
+                .any(|field| field.name == name.as_str())
+        })
+        .cloned()
+        .collect();
+    if !unknown_fields.is_empty() {
+        return Err(SchemaError::UnknownFields(unknown_fields));
+    }
+
+    let missing_fields: Vec<_> = ENVELOPE_FIELDS
+        .iter()
+        .filter(|field| field.required && !fields.contains_key(field.name))
+        .map(|field| field.name)
+        .collect();
+    if !missing_fields.is_empty() {
+        return Err(SchemaError::MissingFields(missing_fields));
+    }
+
+    for field in ENVELOPE_FIELDS {
+        if let Some(value) = fields.get(field.name) {
+            let actual = value.kind();
+            if actual != field.kind {
+                return Err(SchemaError::InvalidFieldType {
+                    field: field.name,
+                    expected: field.kind,
+                    actual,
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+struct FieldSchema {
+    name: &'static str,
+    kind: FieldKind,
+    required: bool,
+}
+
+impl FieldSchema {
+    const fn required(name: &'static str, kind: FieldKind) -> Self {
+        Self {
+            name,
+            kind,
+            required: true,
+        }
+    }
+
+    const fn optional(name: &'static str, kind: FieldKind) -> Self {
+        Self {
+            name,
+            kind,
+            required: false,
+        }
+    }
+}
+
+/// A top-level envelope value, only strings and binaries are kept.
+enum FieldValue<'de> {
+    String(Cow<'de, str>),
+    Binary(Cow<'de, [u8]>),
+    Other(FieldKind),
+}
+
+impl FieldValue<'_> {
+    fn kind(&self) -> FieldKind {
+        match self {
+            FieldValue::String(_) => FieldKind::String,
+            FieldValue::Binary(_) => FieldKind::Binary,
+            FieldValue::Other(kind) => *kind,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for FieldValue<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(FieldValueVisitor)
+    }
+}
+
+struct FieldValueVisitor;
+
+impl<'de> Visitor<'de> for FieldValueVisitor {
+    type Value = FieldValue<'de>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a MessagePack value")
+    }
+
+    fn visit_unit<E>(self) -> Result<Self::Value, E> {
+        Ok(FieldValue::Other(FieldKind::Nil))
+    }
+
+    fn visit_none<E>(self) -> Result<Self::Value, E> {
+        Ok(FieldValue::Other(FieldKind::Nil))
+    }
+
+    fn visit_bool<E>(self, _: bool) -> Result<Self::Value, E> {
+        Ok(FieldValue::Other(FieldKind::Bool))
+    }
+
+    fn visit_i64<E>(self, _: i64) -> Result<Self::Value, E> {
+        Ok(FieldValue::Other(FieldKind::Number))
+    }
+
+    fn visit_u64<E>(self, _: u64) -> Result<Self::Value, E> {
+        Ok(FieldValue::Other(FieldKind::Number))
+    }
+
+    fn visit_f64<E>(self, _: f64) -> Result<Self::Value, E> {
+        Ok(FieldValue::Other(FieldKind::Number))
+    }
+
+    fn visit_borrowed_str<E>(self, value: &'de str) -> Result<Self::Value, E> {
+        Ok(FieldValue::String(Cow::Borrowed(value)))
+    }
+
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(FieldValue::String(Cow::Owned(value.to_string())))
+    }
+
+    fn visit_borrowed_bytes<E>(self, value: &'de [u8]) -> Result<Self::Value, E> {
+        Ok(FieldValue::Binary(Cow::Borrowed(value)))
+    }
+
+    fn visit_bytes<E>(self, value: &[u8]) -> Result<Self::Value, E> {
+        Ok(FieldValue::Binary(Cow::Owned(value.to_vec())))
+    }
+
+    fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+        Ok(FieldValue::Other(FieldKind::Array))
+    }
+
+    fn visit_map<A: serde::de::MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        while map
+            .next_entry::<serde::de::IgnoredAny, serde::de::IgnoredAny>()?
+            .is_some()
+        {}
+        Ok(FieldValue::Other(FieldKind::Map))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::InterpreterDataEnvelope;
+
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    struct ExtendedEnvelope<'a> {
+        #[serde(flatten)]
+        envelope: InterpreterDataEnvelope<'a>,
+        new_field: u32,
+    }
+
+    fn envelope() -> InterpreterDataEnvelope<'static> {
+        InterpreterDataEnvelope::new(semver::Version::new(1, 2, 3))
+    }
+
+    #[test]
+    fn valid_envelope() {
+        let raw = envelope().serialize().unwrap();
+
+        let version = InterpreterData::validate_schema(&raw).unwrap();
+        assert_eq!(&version, crate::data_version());
+    }
+
+    #[test]
+    fn unknown_field() {
+        let extended = ExtendedEnvelope {
+            envelope: envelope(),
+            new_field: 42,
+        };
+        let raw = rmp_serde::to_vec_named(&extended).unwrap();
+
+        let error = InterpreterData::validate_schema(&raw).unwrap_err();
+        assert!(
+            matches!(&error, SchemaError::UnknownFields(fields) if fields == &["new_field"]),
+            "{error:?}"
+        );
+    }
+
+    #[test]
+    fn missing_field() {
+        let raw = rmp_serde::to_vec_named(&envelope().versions).unwrap();
+
+        let error = InterpreterData::validate_schema(&raw).unwrap_err();
+        assert!(
+            matches!(&error, SchemaError::MissingFields(fields) if fields == &[INNER_DATA_FIELD]),
+            "{error:?}"
+        );
+    }
+
+    #[test]
+    fn newer_version() {
+        let mut extended = ExtendedEnvelope {
+            envelope: envelope(),
+            new_field: 42,
+        };
+        let data_version = &mut extended.envelope.versions.data_version;
+        data_version.major += 1;
+        let raw = rmp_serde::to_vec_named(&extended).unwrap();
+
+        let error = InterpreterData::validate_schema(&raw).unwrap_err();
+        assert!(
+            matches!(&error, SchemaError::UnsupportedVersion { actual, .. } if actual.major == crate::data_version().major + 1),
+            "{error:?}"
+        );
+    }
+
+    #[test]
+    fn older_readable_version() {
+        let mut envelope = envelope();
+        envelope.versions.data_version =
+            semver::Version::parse(OLDEST_SUPPORTED_DATA_VERSION).unwrap();
+        let raw = envelope.serialize().unwrap();
+
+        let version = InterpreterData::validate_schema(&raw).unwrap();
+        assert_eq!(version, envelope.versions.data_version);
+    }
+
+    #[test]
+    fn malformed_inner_data() {
+        let mut envelope = envelope();
+        envelope.inner_data = vec![1, 2, 3].into();
+        let raw = envelope.serialize().unwrap();
+
+        let error = InterpreterData::validate_schema(&raw).unwrap_err();
+        assert!(
+            matches!(error, SchemaError::InvalidInnerData(_)),
+            "{error:?}"
+        );
+    }
+}
@@ -0,0 +1,138 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::verification::DataVerifier;
+use super::verification::DataVerifierError;
+use crate::CanonResult;
+use crate::CidStoreVerificationError;
+use crate::ExecutedState;
+use crate::InterpreterData;
+use crate::TracePos;
+
+use air_interpreter_cid::CidRef;
+use thiserror::Error as ThisError;
+
+use std::rc::Rc;
+
+/// Checks data received from another peer before it's executed: trace states refer to values
+/// present in the CID stores, the values match their CIDs, and signatures of the peers that
+/// produced the values are valid.
+pub struct TraceVerifier;
+
+/// Result of checking data with `TraceVerifier::report`.
+#[derive(Debug, Default)]
+pub struct VerificationReport {
+    /// Positions of expanded trace states referring to values present in the CID stores.
+    pub verified_states: Vec<TracePos>,
+
+    /// Positions of expanded trace states referring to missing values along with their CIDs.
+    pub failed_states: Vec<(TracePos, Rc<CidRef>)>,
+
+    /// An error of a value not matching its CID or a dangling reference inside the CID stores.
+    pub cid_store_error: Option<CidStoreVerificationError>,
+
+    /// Result of signature verification, it's `None` if signatures weren't checked,
+    /// because signed values couldn't be resolved.
+    pub signatures: Option<Result<(), DataVerifierError>>,
+}
+
+#[derive(Debug, ThisError)]
+pub enum TraceVerificationError {
+    #[error(transparent)]
+    CidStore(#[from] CidStoreVerificationError),
+
+    #[error("trace state at position {position} refers to missing CID {cid:?}")]
+    MissingCid { position: TracePos, cid: Rc<CidRef> },
+
+    #[error(transparent)]
+    Signatures(#[from] DataVerifierError),
+}
+
+impl TraceVerifier {
+    /// Checks the data and returns the first error found.
+    pub fn verify(data: &InterpreterData, salt: &str) -> Result<(), TraceVerificationError> {
+        Self::report(data, salt).into_result()
+    }
+
+    /// Checks the data and lists states that were verified and that failed.
+    pub fn report(data: &InterpreterData, salt: &str) -> VerificationReport {
+        let mut report = VerificationReport {
+            cid_store_error: data.cid_info.verify().err(),
+            ..<_>::default()
+        };
+
+        for (position, state) in (0u32..).zip(data.trace.iter_expanded()) {
+            let Some((cid, is_present)) = referred_cid(data, state) else {
+                continue;
+            };
+
+            if is_present {
+                report.verified_states.push(position.into());
+            } else {
+                report.failed_states.push((position.into(), cid));
+            }
+        }
+
+        // signed values are resolved through the CID stores, so they should be consistent first
+        if report.cid_store_error.is_none() && report.failed_states.is_empty() {
+            let signatures = DataVerifier::new(data, salt).and_then(|verifier| verifier.verify());
+            report.signatures = Some(signatures);
+        }
+
+        report
+    }
+}
+
+impl VerificationReport {
+    pub fn is_valid(&self) -> bool {
+        matches!(self.signatures, Some(Ok(())))
+    }
+
+    pub fn into_result(self) -> Result<(), TraceVerificationError> {
+        if let Some(error) = self.cid_store_error {
+            return Err(error.into());
+        }
+
+        if let Some((position, cid)) = self.failed_states.into_iter().next() {
+            return Err(TraceVerificationError::MissingCid { position, cid });
+        }
+
+        match self.signatures {
+            Some(result) => result.map_err(Into::into),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Returns a CID the state refers to and whether it's present in the CID store.
+fn referred_cid(data: &InterpreterData, state: &ExecutedState) -> Option<(Rc<CidRef>, bool)> {
+    let cid_info = &data.cid_info;
+
+    match state {
+        ExecutedState::Call(call) => {
+            let cid = call.get_cid()?;
+            Some((
+                cid.get_inner(),
+                cid_info.service_result_store.get(cid).is_some(),
+            ))
+        }
+        ExecutedState::Canon(CanonResult::Executed(cid)) => Some((
+            cid.get_inner(),
+            cid_info.canon_result_store.get(cid).is_some(),
+        )),
+        _ => None,
+    }
+}
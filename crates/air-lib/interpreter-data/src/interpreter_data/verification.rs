@@ -15,6 +15,9 @@
  */
 
 pub use super::errors::DataVerifierError;
+pub use super::trace_verifier::TraceVerificationError;
+pub use super::trace_verifier::TraceVerifier;
+pub use super::trace_verifier::VerificationReport;
 use crate::CanonResult;
 use crate::CidInfo;
 use crate::ExecutedState;
@@ -22,9 +25,13 @@ use crate::ExecutionTrace;
 use crate::InterpreterData;
 
 use air_interpreter_cid::{CidRef, CID};
+use air_interpreter_signatures::KeyFormatPolicy;
 use air_interpreter_signatures::PublicKey;
 use air_interpreter_signatures::Signature;
+use air_interpreter_signatures::SignaturePolicy;
 use air_interpreter_signatures::SignatureStore;
+use air_interpreter_signatures::VerificationError;
+use air_interpreter_signatures::VerifyAll;
 
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -42,10 +49,20 @@ impl<'data> DataVerifier<'data> {
     // it can be further optimized if only required parts are passed;
     // SignatureStore is not used elsewhere
     pub fn new(data: &'data InterpreterData, salt: &'data str) -> Result<Self, DataVerifierError> {
+        Self::with_key_format_policy(data, salt, KeyFormatPolicy::default())
+    }
+
+    /// Like `new`, but accepts public keys of the formats allowed by the policy; each signature
+    /// is verified with the algorithm of its own key.
+    pub fn with_key_format_policy(
+        data: &'data InterpreterData,
+        salt: &'data str,
+        key_format_policy: KeyFormatPolicy,
+    ) -> Result<Self, DataVerifierError> {
         // validate key algoritms
         for (public_key, _) in data.signatures.iter() {
             public_key
-                .validate()
+                .validate_with_policy(key_format_policy)
                 .map_err(|error| DataVerifierError::MalformedKey {
                     error,
                     key: public_key.to_string(),
@@ -81,23 +98,98 @@ impl<'data> DataVerifier<'data> {
 
     /// Verify each peers' signatures.
     pub fn verify(&self) -> Result<(), DataVerifierError> {
-        for peer_info in self.grouped_cids.values() {
+        self.verify_with_policy(&VerifyAll)
+    }
+
+    /// Verify signatures of the peers selected by the policy.
+    pub fn verify_with_policy(
+        &self,
+        policy: &dyn SignaturePolicy,
+    ) -> Result<(), DataVerifierError> {
+        for (peer_id, peer_info) in &self.grouped_cids {
+            if !should_verify(policy, peer_id, peer_info) {
+                continue;
+            }
+
             peer_info
                 .public_key
                 .verify(&peer_info.cids, self.salt, peer_info.signature)
-                .map_err(|error| DataVerifierError::SignatureMismatch {
-                    error: error.into(),
-                    cids: peer_info.cids.clone(),
-                    peer_id: peer_info
-                        .public_key
-                        .to_peer_id()
-                        .expect("cannot happen, was verified before")
-                        .to_string(),
-                })?;
+                .map_err(|error| signature_mismatch(peer_info, error))?;
         }
         Ok(())
     }
 
+    /// Verify each peers' signatures concurrently on the global rayon thread pool,
+    /// returning the first error encountered.
+    #[cfg(feature = "parallel")]
+    pub fn verify_parallel(&self) -> Result<(), DataVerifierError> {
+        self.verify_parallel_with_policy(&VerifyAll)
+    }
+
+    /// Like `verify_parallel`, but verifies signatures only of the peers selected by the policy.
+    #[cfg(feature = "parallel")]
+    pub fn verify_parallel_with_policy(
+        &self,
+        policy: &dyn SignaturePolicy,
+    ) -> Result<(), DataVerifierError> {
+        self.verify_parallel_on(None, policy)
+    }
+
+    /// Like `verify_parallel_with_policy`, but runs on the provided thread pool.
+    #[cfg(feature = "parallel")]
+    pub fn verify_parallel_in(
+        &self,
+        pool: &rayon::ThreadPool,
+        policy: &dyn SignaturePolicy,
+    ) -> Result<(), DataVerifierError> {
+        self.verify_parallel_on(Some(pool), policy)
+    }
+
+    #[cfg(feature = "parallel")]
+    fn verify_parallel_on(
+        &self,
+        pool: Option<&rayon::ThreadPool>,
+        policy: &dyn SignaturePolicy,
+    ) -> Result<(), DataVerifierError> {
+        use rayon::prelude::*;
+
+        let peer_infos: Vec<_> = self
+            .grouped_cids
+            .iter()
+            .filter(|(peer_id, peer_info)| should_verify(policy, peer_id, peer_info))
+            .map(|(_, peer_info)| peer_info)
+            .collect();
+        // Rc is not Send, so workers get CIDs as plain slices; they are serialized the same way
+        let tasks: Vec<(&PublicKey, &Signature, Vec<&CidRef>)> = peer_infos
+            .iter()
+            .map(|peer_info| {
+                let cids = peer_info.cids.iter().map(|cid| &**cid).collect();
+                (peer_info.public_key, peer_info.signature, cids)
+            })
+            .collect();
+
+        let salt = self.salt;
+        let find_failed = || {
+            tasks.par_iter().enumerate().find_map_first(
+                |(peer_idx, (public_key, signature, cids))| {
+                    public_key
+                        .verify(cids, salt, signature)
+                        .err()
+                        .map(|error| (peer_idx, error))
+                },
+            )
+        };
+        let failed = match pool {
+            Some(pool) => pool.install(find_failed),
+            None => find_failed(),
+        };
+
+        match failed {
+            Some((peer_idx, error)) => Err(signature_mismatch(peer_infos[peer_idx], error)),
+            None => Ok(()),
+        }
+    }
+
     /// For each peer, merge previous and current CID multisets by determining the largest set.
     ///
     /// This code uses an invariant: peer's multiset of produced CIDs is always a superset of
@@ -150,12 +242,31 @@ impl<'data> DataVerifier<'data> {
     }
 }
 
+fn should_verify(policy: &dyn SignaturePolicy, peer_id: &str, peer_info: &PeerInfo<'_>) -> bool {
+    // a peer may have a signature without any signed states in the trace
+    let call_id = peer_info.first_call_id.unwrap_or_default();
+    policy.should_verify(peer_id, call_id)
+}
+
+fn signature_mismatch(peer_info: &PeerInfo<'_>, error: VerificationError) -> DataVerifierError {
+    DataVerifierError::SignatureMismatch {
+        error: error.into(),
+        cids: peer_info.cids.clone(),
+        peer_id: peer_info
+            .public_key
+            .to_peer_id()
+            .expect("cannot happen, was verified before")
+            .to_string(),
+    }
+}
+
 fn collect_peers_cids_from_trace<'data>(
     trace: &'data ExecutionTrace,
     cid_info: &'data CidInfo,
     grouped_cids: &mut HashMap<Box<str>, PeerInfo<'data>>,
 ) -> Result<(), DataVerifierError> {
-    for elt in trace {
+    // peers sign every produced CID, including repeated ones
+    for (position, elt) in (0u64..).zip(trace.iter_expanded()) {
         match elt {
             ExecutedState::Call(ref call) => {
                 let cid = call.get_cid();
@@ -171,7 +282,7 @@ fn collect_peers_cids_from_trace<'data>(
                         .expect(CANNOT_HAPPEN_IN_VERIFIED_CID_STORE);
 
                     let peer_pk = tetraplet.peer_pk.as_str();
-                    try_push_cid(grouped_cids, peer_pk, cid)?;
+                    try_push_cid(grouped_cids, peer_pk, cid, position)?;
                 }
             }
             ExecutedState::Canon(CanonResult::Executed(ref cid)) => {
@@ -186,7 +297,7 @@ fn collect_peers_cids_from_trace<'data>(
                     .expect(CANNOT_HAPPEN_IN_VERIFIED_CID_STORE);
 
                 let peer_pk = tetraplet.peer_pk.as_str();
-                try_push_cid(grouped_cids, peer_pk, cid)?;
+                try_push_cid(grouped_cids, peer_pk, cid, position)?;
             }
             _ => {}
         };
@@ -198,9 +309,11 @@ fn try_push_cid<T>(
     grouped_cids: &mut HashMap<Box<str>, PeerInfo<'_>>,
     peer_pk: &str,
     cid: &CID<T>,
+    position: u64,
 ) -> Result<(), DataVerifierError> {
     match grouped_cids.get_mut(peer_pk) {
         Some(peer_info) => {
+            peer_info.first_call_id.get_or_insert(position);
             peer_info.cids.push(cid.get_inner());
             Ok(())
         }
@@ -267,6 +380,8 @@ struct PeerInfo<'data> {
     signature: &'data Signature,
     /// Sorted vector of CIDs that belong to the peer.
     cids: Vec<Rc<CidRef>>,
+    /// Position of the first state in the expanded trace that belongs to the peer.
+    first_call_id: Option<u64>,
 }
 
 impl<'data> PeerInfo<'data> {
@@ -275,6 +390,7 @@ impl<'data> PeerInfo<'data> {
             public_key,
             signature,
             cids: vec![],
+            first_call_id: None,
         }
     }
 }
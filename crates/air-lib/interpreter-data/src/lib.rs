@@ -26,21 +26,25 @@
     unreachable_patterns
 )]
 
+mod call_request_id;
 mod cid_info;
 mod cid_store;
 mod executed_state;
 mod generation_idx;
 mod interpreter_data;
+mod merkle;
 mod raw_value;
 mod rkyv;
 mod trace;
 mod trace_pos;
 
+pub use call_request_id::*;
 pub use cid_info::*;
 pub use cid_store::*;
 pub use executed_state::*;
 pub use generation_idx::*;
 pub use interpreter_data::*;
+pub use merkle::*;
 pub use raw_value::*;
 pub use trace::*;
 pub use trace_pos::*;
@@ -0,0 +1,184 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! A binary Merkle tree over trace states, it allows checking that a state belongs
+//! to a trace with a known root without the rest of the trace.
+//!
+//! Leaves are Blake3 hashes of JSON-serialized states, nodes are hashes of their children.
+//! Leaves and nodes are hashed with different prefixes, so a node can't pass for a leaf.
+//! A node without a sibling is moved a level up as is.
+
+use crate::ExecutedState;
+use crate::ExecutionTrace;
+
+use fluence_blake3 as blake3;
+use serde::Deserialize;
+use serde::Serialize;
+
+pub type MerkleHash = [u8; 32];
+
+const LEAF_PREFIX: u8 = 0;
+const NODE_PREFIX: u8 = 1;
+
+/// A path from a trace state to the Merkle root of the trace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerkleProof {
+    pub entry_index: usize,
+    pub entry_hash: MerkleHash,
+    /// Siblings of nodes on the path, starting from the leaf level.
+    pub siblings: Vec<MerkleSibling>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MerkleSibling {
+    Left(MerkleHash),
+    Right(MerkleHash),
+}
+
+impl MerkleProof {
+    /// Returns true if the proven entry belongs to a trace with this root.
+    pub fn verify(&self, root: MerkleHash) -> bool {
+        let computed_root =
+            self.siblings
+                .iter()
+                .fold(self.entry_hash, |hash, sibling| match sibling {
+                    MerkleSibling::Left(left) => node_hash(left, &hash),
+                    MerkleSibling::Right(right) => node_hash(&hash, right),
+                });
+
+        computed_root == root
+    }
+
+    /// Returns true if the proof is made for this state and the state belongs
+    /// to a trace with this root.
+    pub fn verify_entry(&self, state: &ExecutedState, root: MerkleHash) -> bool {
+        entry_hash(state) == self.entry_hash && self.verify(root)
+    }
+}
+
+impl ExecutionTrace {
+    /// Returns the root of a Merkle tree over states of the trace as they're stored,
+    /// i.e. a compacted trace has another root than the same trace expanded.
+    pub fn merkle_root(&self) -> MerkleHash {
+        let mut level = leaf_hashes(self);
+        if level.is_empty() {
+            return blake3::hash(&[]).into();
+        }
+
+        while level.len() > 1 {
+            level = next_level(&level);
+        }
+        level[0]
+    }
+
+    /// Returns a proof that the state at `entry_index` belongs to the trace,
+    /// or `None` if there is no such state.
+    pub fn inclusion_proof(&self, entry_index: usize) -> Option<MerkleProof> {
+        let mut level = leaf_hashes(self);
+        let entry_hash = *level.get(entry_index)?;
+
+        let mut siblings = vec![];
+        let mut index = entry_index;
+        while level.len() > 1 {
+            let sibling = if index % 2 == 0 {
+                level.get(index + 1).copied().map(MerkleSibling::Right)
+            } else {
+                Some(MerkleSibling::Left(level[index - 1]))
+            };
+            siblings.extend(sibling);
+
+            level = next_level(&level);
+            index /= 2;
+        }
+
+        Some(MerkleProof {
+            entry_index,
+            entry_hash,
+            siblings,
+        })
+    }
+}
+
+/// Returns the leaf hash of a state.
+pub fn entry_hash(state: &ExecutedState) -> MerkleHash {
+    let serialized = serde_json::to_vec(state).expect("executed state is serializable to JSON");
+
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&[LEAF_PREFIX]);
+    hasher.update(&serialized);
+    hasher.finalize().into()
+}
+
+fn node_hash(left: &MerkleHash, right: &MerkleHash) -> MerkleHash {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&[NODE_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+fn leaf_hashes(trace: &ExecutionTrace) -> Vec<MerkleHash> {
+    trace.iter().map(entry_hash).collect()
+}
+
+fn next_level(level: &[MerkleHash]) -> Vec<MerkleHash> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => node_hash(left, right),
+            [single] => *single,
+            _ => unreachable!("chunks are of size 1 or 2"),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trace(len: usize) -> ExecutionTrace {
+        (0..len)
+            .map(|index| ExecutedState::par(index, 0))
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    #[test]
+    fn proofs_of_all_entries_are_verified() {
+        for len in 1..=9 {
+            let trace = trace(len);
+            let root = trace.merkle_root();
+
+            for (index, state) in trace.iter().enumerate() {
+                let proof = trace.inclusion_proof(index).unwrap();
+                assert!(proof.verify_entry(state, root), "len {len}, index {index}");
+            }
+            assert_eq!(trace.inclusion_proof(len), None);
+        }
+    }
+
+    #[test]
+    fn proof_fails_for_another_trace() {
+        let trace = trace(5);
+        let mut changed_trace = trace.clone();
+        changed_trace.push(ExecutedState::par(0, 0));
+
+        let proof = trace.inclusion_proof(2).unwrap();
+        assert!(proof.verify(trace.merkle_root()));
+        assert!(!proof.verify(changed_trace.merkle_root()));
+        assert!(!proof.verify_entry(&trace[3.into()], trace.merkle_root()));
+    }
+}
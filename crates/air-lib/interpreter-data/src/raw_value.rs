@@ -19,7 +19,7 @@ use crate::JValue;
 use serde::Deserialize;
 use serde::Serialize;
 
-use std::cell::RefCell;
+use std::cell::OnceCell;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(transparent)]
@@ -30,7 +30,7 @@ pub struct RawValue {
 
     #[serde(skip)]
     #[with(::rkyv::with::Skip)]
-    parsed: RefCell<Option<JValue>>,
+    parsed: OnceCell<JValue>,
 }
 
 impl RawValue {
@@ -39,16 +39,26 @@ impl RawValue {
         let raw = value.to_string().into();
         Self {
             raw,
-            parsed: Some(value).into(),
+            parsed: OnceCell::from(value),
         }
     }
 
     pub fn get_value(&self) -> JValue {
-        let mut parsed_guard = self.parsed.borrow_mut();
+        self.try_get_value().expect("TODO handle error")
+    }
+
+    /// Parses the value on the first call and returns the cached one afterwards.
+    pub fn try_get_value(&self) -> Result<JValue, serde_json::Error> {
+        self.try_get_value_ref().cloned()
+    }
 
-        let parsed_value = parsed_guard
-            .get_or_insert_with(|| serde_json::from_str(&self.raw).expect("TODO handle error"));
-        parsed_value.clone()
+    /// Returns a reference to the value like `try_get_value`.
+    pub fn try_get_value_ref(&self) -> Result<&JValue, serde_json::Error> {
+        if let Some(parsed_value) = self.parsed.get() {
+            return Ok(parsed_value);
+        }
+        let parsed_value: JValue = serde_json::from_str(&self.raw)?;
+        Ok(self.parsed.get_or_init(|| parsed_value))
     }
 
     pub(crate) fn as_inner(&self) -> &str {
@@ -79,6 +79,22 @@ where
         .map_err(RkyvDeserializeError::Deserialize)
 }
 
+/// Checks that the slice is a valid archive of `Value` without deserializing it.
+pub(crate) fn check_aligned_slice<'a, Value>(slice: &'a [u8]) -> Result<(), RkyvDeserializeError>
+where
+    Value: rkyv::Archive,
+    <Value as rkyv::Archive>::Archived: rkyv::CheckBytes<DefaultValidator<'a>>,
+{
+    let mut validator = rkyv::validation::validators::DefaultValidator::with_capacity(
+        slice,
+        DEFAULT_VALIDATION_CAPACITY,
+    );
+    rkyv::check_archived_root_with_context::<Value, _>(slice, &mut validator)
+        .map_err(|e| RkyvDeserializeError::Validation(Box::new(e)))?;
+
+    Ok(())
+}
+
 #[allow(dead_code)]
 pub(crate) fn to_writer<'a, Value, W: std::io::Write>(
     write: &'a mut W,
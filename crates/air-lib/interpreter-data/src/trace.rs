@@ -15,6 +15,7 @@
  */
 
 use super::ExecutedState;
+use crate::DataDeserializationError;
 use crate::TracePos;
 
 use serde::Deserialize;
@@ -32,7 +33,84 @@ pub type TraceLen = u32;
 #[archive(check_bytes)]
 pub struct ExecutionTrace(Vec<ExecutedState>);
 
+/// The maximum number of states a compacted trace is allowed to expand to. Every state is
+/// cloned on expansion, so a few bytes of `ExecutedState::Repeated` could otherwise exhaust memory.
+pub const MAX_EXPANDED_TRACE_LEN: usize = 1 << 22;
+
 impl ExecutionTrace {
+    /// Applies run-length encoding to the trace: a run of identical states is replaced
+    /// with the first of them followed by `ExecutedState::Repeated`.
+    pub fn compact(&mut self) {
+        // states are taken from the expanded view, so an already compacted trace is
+        // compacted again without being expanded in memory
+        let mut compacted: Vec<ExecutedState> = Vec::with_capacity(self.0.len());
+        {
+            let mut states = self.iter_expanded().peekable();
+            while let Some(state) = states.next() {
+                let mut repeats: u32 = 0;
+                while repeats < u32::MAX && states.next_if(|next| *next == state).is_some() {
+                    repeats += 1;
+                }
+
+                compacted.push(state.clone());
+                if repeats > 0 {
+                    compacted.push(ExecutedState::Repeated(repeats));
+                }
+            }
+        }
+
+        self.0 = compacted;
+    }
+
+    /// Reverts `compact`. A repeat without preceding state is malformed and left as is,
+    /// so it would be rejected by the trace handler. The expanded length is checked against
+    /// `MAX_EXPANDED_TRACE_LEN` before anything is allocated.
+    pub fn expand(&mut self) -> Result<(), DataDeserializationError> {
+        if !self
+            .0
+            .iter()
+            .any(|state| matches!(state, ExecutedState::Repeated(_)))
+        {
+            return Ok(());
+        }
+
+        let expanded_len = self.expanded_len();
+        if expanded_len > MAX_EXPANDED_TRACE_LEN {
+            return Err(DataDeserializationError::TraceTooLong {
+                expanded_len,
+                limit: MAX_EXPANDED_TRACE_LEN,
+            });
+        }
+
+        self.0 = self.iter_expanded().cloned().collect();
+        Ok(())
+    }
+
+    /// Returns the number of states the trace would have after expansion.
+    pub fn expanded_len(&self) -> usize {
+        self.0.iter().fold(0usize, |len, state| match state {
+            ExecutedState::Repeated(count) => len.saturating_add(*count as usize),
+            _ => len.saturating_add(1),
+        })
+    }
+
+    /// Iterates over states as if the trace was expanded.
+    pub fn iter_expanded(&self) -> impl Iterator<Item = &ExecutedState> {
+        let mut previous = None;
+
+        self.0
+            .iter()
+            .flat_map(move |state| match (state, previous) {
+                (ExecutedState::Repeated(count), Some(previous)) => {
+                    std::iter::repeat(previous).take(*count as usize)
+                }
+                (state, _) => {
+                    previous = Some(state);
+                    std::iter::repeat(state).take(1)
+                }
+            })
+    }
+
     pub fn get(&self, index: TracePos) -> Option<&ExecutedState> {
         self.0.get(usize::from(index))
     }
@@ -100,3 +178,56 @@ impl<'trace> IntoIterator for &'trace ExecutionTrace {
         self.0.iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compact_and_expand_roundtrip() {
+        let par = ExecutedState::par(1, 0);
+        let other_par = ExecutedState::par(0, 1);
+        let original: ExecutionTrace = vec![
+            par.clone(),
+            par.clone(),
+            par.clone(),
+            other_par.clone(),
+            par.clone(),
+        ]
+        .into();
+
+        let mut trace = original.clone();
+        trace.compact();
+        assert_eq!(
+            trace,
+            vec![par.clone(), ExecutedState::Repeated(2), other_par, par]
+        );
+
+        trace.expand().unwrap();
+        assert_eq!(trace, original);
+    }
+
+    #[test]
+    fn repeat_without_preceding_state_is_kept() {
+        let mut trace: ExecutionTrace = vec![ExecutedState::Repeated(3)].into();
+        trace.expand().unwrap();
+
+        assert_eq!(trace, vec![ExecutedState::Repeated(3)]);
+    }
+
+    #[test]
+    fn expansion_is_limited() {
+        let mut trace: ExecutionTrace =
+            vec![ExecutedState::par(0, 0), ExecutedState::Repeated(u32::MAX)].into();
+
+        let error = trace.expand().unwrap_err();
+        assert!(matches!(
+            error,
+            DataDeserializationError::TraceTooLong {
+                expanded_len,
+                limit: MAX_EXPANDED_TRACE_LEN,
+            } if expanded_len == u32::MAX as usize + 1
+        ));
+        assert_eq!(trace.len(), 2);
+    }
+}
@@ -16,11 +16,16 @@
 
 use air_interpreter_sede::define_simple_representation;
 use air_interpreter_sede::derive_serialized_type;
+use air_interpreter_sede::CborMultiformat;
+use air_interpreter_sede::Format;
+use air_interpreter_sede::JsonMultiformat;
 use air_interpreter_sede::MsgPackMultiformat;
 use air_interpreter_sede::Representation;
 use serde::Deserialize;
 use serde::Serialize;
 use serde_json::Value as JValue;
+use thiserror::Error as ThisError;
+
 use std::collections::HashMap;
 
 /// This is a map from a String to a service result for compatibility with JavaScript.
@@ -28,20 +33,115 @@ use std::collections::HashMap;
 pub type CallResults = HashMap<String, CallServiceResult>;
 pub const CALL_SERVICE_SUCCESS: i32 = 0;
 
-pub type CallResultsFormat = MsgPackMultiformat;
+pub type CallResultsMultiformat = MsgPackMultiformat;
 
 derive_serialized_type!(SerializedCallResults);
 
 define_simple_representation! {
     CallResultsRepr,
     CallResults,
-    CallResultsFormat,
+    CallResultsMultiformat,
     SerializedCallResults
 }
 
-pub type CallResultsDeserializeError = <CallResultsRepr as Representation>::DeserializeError;
 pub type CallResultsSerializeError = <CallResultsRepr as Representation>::SerializeError;
 
+/// Formats a host could serialize call results with.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CallResultsFormat {
+    #[default]
+    MsgPack,
+    Json,
+    Cbor,
+}
+
+impl From<CallResultsFormat> for u8 {
+    fn from(format: CallResultsFormat) -> Self {
+        match format {
+            CallResultsFormat::MsgPack => 0,
+            CallResultsFormat::Json => 1,
+            CallResultsFormat::Cbor => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for CallResultsFormat {
+    type Error = UnsupportedCallResultsFormat;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::MsgPack),
+            1 => Ok(Self::Json),
+            2 => Ok(Self::Cbor),
+            _ => Err(UnsupportedCallResultsFormat(value)),
+        }
+    }
+}
+
+#[derive(Debug, ThisError)]
+#[error("unsupported call results format: {0}")]
+pub struct UnsupportedCallResultsFormat(pub u8);
+
+#[derive(Debug, ThisError)]
+pub enum CallResultsDeserializeError {
+    #[error(transparent)]
+    MsgPack(<MsgPackMultiformat as Format<CallResults>>::DeserializationError),
+
+    #[error(transparent)]
+    Json(<JsonMultiformat as Format<CallResults>>::DeserializationError),
+
+    #[error(transparent)]
+    Cbor(<CborMultiformat as Format<CallResults>>::DeserializationError),
+}
+
+#[derive(Debug, ThisError)]
+pub enum CallResultsFormatSerializeError {
+    #[error(transparent)]
+    MsgPack(<MsgPackMultiformat as Format<CallResults>>::SerializationError),
+
+    #[error(transparent)]
+    Json(<JsonMultiformat as Format<CallResults>>::SerializationError),
+
+    #[error(transparent)]
+    Cbor(<CborMultiformat as Format<CallResults>>::SerializationError),
+}
+
+/// Serializes call results in the specified format, it's the counterpart of `deserialize_call_results`.
+pub fn serialize_call_results(
+    call_results: &CallResults,
+    format: CallResultsFormat,
+) -> Result<Vec<u8>, CallResultsFormatSerializeError> {
+    match format {
+        CallResultsFormat::MsgPack => MsgPackMultiformat
+            .to_vec(call_results)
+            .map_err(CallResultsFormatSerializeError::MsgPack),
+        CallResultsFormat::Json => JsonMultiformat
+            .to_vec(call_results)
+            .map_err(CallResultsFormatSerializeError::Json),
+        CallResultsFormat::Cbor => CborMultiformat
+            .to_vec(call_results)
+            .map_err(CallResultsFormatSerializeError::Cbor),
+    }
+}
+
+/// Deserializes call results passed by a host in the specified format.
+pub fn deserialize_call_results(
+    data: &[u8],
+    format: CallResultsFormat,
+) -> Result<CallResults, CallResultsDeserializeError> {
+    match format {
+        CallResultsFormat::MsgPack => MsgPackMultiformat
+            .from_slice(data)
+            .map_err(CallResultsDeserializeError::MsgPack),
+        CallResultsFormat::Json => JsonMultiformat
+            .from_slice(data)
+            .map_err(CallResultsDeserializeError::Json),
+        CallResultsFormat::Cbor => CborMultiformat
+            .from_slice(data)
+            .map_err(CallResultsDeserializeError::Cbor),
+    }
+}
+
 /// Represents an executed host function result.
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct CallServiceResult {
@@ -0,0 +1,307 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::RunParameters;
+
+use thiserror::Error as ThisError;
+
+use std::env;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Peer id of a current peer, it's the only required variable.
+pub const AIR_PEER_ID: &str = "AIR_PEER_ID";
+/// Peer id of a peer that start a particle, the current peer is used if it isn't set.
+pub const AIR_INIT_PEER_ID: &str = "AIR_INIT_PEER_ID";
+/// Unique particle ID.
+pub const AIR_PARTICLE_ID: &str = "AIR_PARTICLE_ID";
+/// Unix timestamp from a particle in milliseconds.
+pub const AIR_TIMESTAMP: &str = "AIR_TIMESTAMP";
+/// TTL set by init peer id in milliseconds.
+pub const AIR_TTL: &str = "AIR_TTL";
+/// One of `ed25519`, `rsa` and `secp256k1`, Ed25519 is used if it isn't set.
+pub const AIR_KEY_FORMAT: &str = "AIR_KEY_FORMAT";
+/// A secret key material encoded with the standard base64 alphabet.
+pub const AIR_SECRET_KEY_BASE64: &str = "AIR_SECRET_KEY_BASE64";
+/// The AIR script size limit in bytes.
+pub const AIR_SIZE_LIMIT: &str = "AIR_SIZE_LIMIT";
+/// The particle data size limit in bytes.
+pub const AIR_PARTICLE_SIZE_LIMIT: &str = "AIR_PARTICLE_SIZE_LIMIT";
+/// The service call result size limit in bytes.
+pub const AIR_CALL_RESULT_SIZE_LIMIT: &str = "AIR_CALL_RESULT_SIZE_LIMIT";
+/// Either `true` or `false`.
+pub const AIR_HARD_LIMIT_ENABLED: &str = "AIR_HARD_LIMIT_ENABLED";
+/// The maximum number of instructions the interpreter is allowed to execute.
+pub const AIR_FUEL_LIMIT: &str = "AIR_FUEL_LIMIT";
+/// The maximum wall clock time of execution in milliseconds.
+pub const AIR_MAX_EXECUTION_TIME_MS: &str = "AIR_MAX_EXECUTION_TIME_MS";
+
+#[derive(Debug, Clone, PartialEq, Eq, ThisError)]
+pub enum EnvConfigError {
+    #[error("environment variable `{0}` is required, but it isn't set")]
+    MissingVariable(&'static str),
+
+    // values aren't included, since they might be secret
+    #[error("environment variable `{variable}` is invalid: {reason}")]
+    InvalidVariable {
+        variable: &'static str,
+        reason: String,
+    },
+}
+
+impl RunParameters {
+    /// Reads run parameters from the `AIR_*` environment variables of the process.
+    ///
+    /// Only `AIR_PEER_ID` is required, particle fields are empty unless set, since
+    /// they're usually assigned per particle by a host, and limits are the default ones.
+    pub fn from_env() -> Result<Self, EnvConfigError> {
+        Self::from_variables(env::var)
+    }
+
+    fn from_variables(
+        variable: impl Fn(&'static str) -> Result<String, env::VarError>,
+    ) -> Result<Self, EnvConfigError> {
+        let lookup = |name: &'static str| match variable(name) {
+            Ok(value) => Ok(Some(value)),
+            Err(env::VarError::NotPresent) => Ok(None),
+            Err(error @ env::VarError::NotUnicode(_)) => Err(invalid_variable(name, error)),
+        };
+        let parse = |name: &'static str| -> Result<Option<u64>, EnvConfigError> {
+            lookup(name)?
+                .map(|value| parse_value(name, &value))
+                .transpose()
+        };
+
+        let current_peer_id =
+            lookup(AIR_PEER_ID)?.ok_or(EnvConfigError::MissingVariable(AIR_PEER_ID))?;
+        let init_peer_id = lookup(AIR_INIT_PEER_ID)?.unwrap_or_else(|| current_peer_id.clone());
+        let ttl = lookup(AIR_TTL)?
+            .map(|value| parse_value(AIR_TTL, &value))
+            .transpose()?;
+        let key_format = lookup(AIR_KEY_FORMAT)?
+            .map(|value| parse_key_format(&value))
+            .transpose()?;
+        let secret_key_bytes = lookup(AIR_SECRET_KEY_BASE64)?
+            .map(|value| decode_secret_key(&value))
+            .transpose()?;
+        let hard_limit_enabled = lookup(AIR_HARD_LIMIT_ENABLED)?
+            .map(|value| parse_value(AIR_HARD_LIMIT_ENABLED, &value))
+            .transpose()?;
+
+        let mut builder = RunParameters::builder()
+            .init_peer_id(init_peer_id)
+            .current_peer_id(current_peer_id)
+            .timestamp(parse(AIR_TIMESTAMP)?.unwrap_or_default())
+            .ttl(ttl.unwrap_or_default())
+            .key_format(key_format.unwrap_or_default())
+            .secret_key_bytes(secret_key_bytes.unwrap_or_default())
+            .particle_id(lookup(AIR_PARTICLE_ID)?.unwrap_or_default())
+            .hard_limit_enabled(hard_limit_enabled.unwrap_or_default());
+        if let Some(air_size_limit) = parse(AIR_SIZE_LIMIT)? {
+            builder = builder.air_size_limit(air_size_limit);
+        }
+        if let Some(particle_size_limit) = parse(AIR_PARTICLE_SIZE_LIMIT)? {
+            builder = builder.particle_size_limit(particle_size_limit);
+        }
+        if let Some(call_result_size_limit) = parse(AIR_CALL_RESULT_SIZE_LIMIT)? {
+            builder = builder.call_result_size_limit(call_result_size_limit);
+        }
+        if let Some(fuel_limit) = parse(AIR_FUEL_LIMIT)? {
+            builder = builder.fuel_limit(Some(fuel_limit));
+        }
+        if let Some(max_execution_time_ms) = parse(AIR_MAX_EXECUTION_TIME_MS)? {
+            builder =
+                builder.max_execution_time(Some(Duration::from_millis(max_execution_time_ms)));
+        }
+
+        // all required fields are set above
+        Ok(builder.build().expect("required run parameters are set"))
+    }
+}
+
+fn parse_value<T>(variable: &'static str, value: &str) -> Result<T, EnvConfigError>
+where
+    T: FromStr,
+    T::Err: ToString,
+{
+    value
+        .trim()
+        .parse()
+        .map_err(|error: T::Err| invalid_variable(variable, error))
+}
+
+/// Maps key format names to values of `fluence_keypair::KeyFormat::into`.
+fn parse_key_format(value: &str) -> Result<u8, EnvConfigError> {
+    match value.trim().to_ascii_lowercase().as_str() {
+        "ed25519" => Ok(0),
+        "rsa" => Ok(1),
+        "secp256k1" => Ok(2),
+        _ => Err(invalid_variable(
+            AIR_KEY_FORMAT,
+            "expected one of `ed25519`, `rsa` and `secp256k1`",
+        )),
+    }
+}
+
+fn decode_secret_key(value: &str) -> Result<Vec<u8>, EnvConfigError> {
+    use base64::Engine;
+
+    base64::engine::general_purpose::STANDARD
+        .decode(value.trim())
+        .map_err(|error| invalid_variable(AIR_SECRET_KEY_BASE64, error))
+}
+
+fn invalid_variable(variable: &'static str, reason: impl ToString) -> EnvConfigError {
+    EnvConfigError::InvalidVariable {
+        variable,
+        reason: reason.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::MAX_AIR_SIZE;
+    use crate::MAX_CALL_RESULT_SIZE;
+    use crate::MAX_PARTICLE_SIZE;
+
+    use std::collections::HashMap;
+
+    fn from_variables(variables: &[(&'static str, &str)]) -> Result<RunParameters, EnvConfigError> {
+        let variables: HashMap<_, _> = variables.iter().copied().collect();
+        RunParameters::from_variables(|name| {
+            variables
+                .get(name)
+                .map(|value| value.to_string())
+                .ok_or(env::VarError::NotPresent)
+        })
+    }
+
+    #[test]
+    fn all_variables_are_read() {
+        let run_parameters = from_variables(&[
+            (AIR_PEER_ID, "current_peer"),
+            (AIR_INIT_PEER_ID, "init_peer"),
+            (AIR_PARTICLE_ID, "particle"),
+            (AIR_TIMESTAMP, "1700000000000"),
+            (AIR_TTL, " 60000 "),
+            (AIR_KEY_FORMAT, "Secp256k1"),
+            (AIR_SECRET_KEY_BASE64, "AQID"),
+            (AIR_SIZE_LIMIT, "1"),
+            (AIR_PARTICLE_SIZE_LIMIT, "2"),
+            (AIR_CALL_RESULT_SIZE_LIMIT, "3"),
+            (AIR_HARD_LIMIT_ENABLED, "true"),
+            (AIR_FUEL_LIMIT, "4"),
+            (AIR_MAX_EXECUTION_TIME_MS, "5"),
+        ])
+        .unwrap();
+
+        assert_eq!(run_parameters.current_peer_id, "current_peer");
+        assert_eq!(run_parameters.init_peer_id, "init_peer");
+        assert_eq!(run_parameters.particle_id, "particle");
+        assert_eq!(run_parameters.timestamp, 1_700_000_000_000);
+        assert_eq!(run_parameters.ttl, 60_000);
+        assert_eq!(run_parameters.key_format, 2);
+        assert_eq!(run_parameters.secret_key_bytes, vec![1, 2, 3]);
+        assert_eq!(run_parameters.air_size_limit, 1);
+        assert_eq!(run_parameters.particle_size_limit, 2);
+        assert_eq!(run_parameters.call_result_size_limit, 3);
+        assert!(run_parameters.hard_limit_enabled);
+        assert_eq!(run_parameters.fuel_limit(), Some(4));
+        assert_eq!(
+            run_parameters.max_execution_time(),
+            Some(Duration::from_millis(5))
+        );
+    }
+
+    #[test]
+    fn unset_variables_keep_defaults() {
+        let run_parameters = from_variables(&[(AIR_PEER_ID, "current_peer")]).unwrap();
+
+        let expected = RunParameters::builder()
+            .init_peer_id("current_peer")
+            .current_peer_id("current_peer")
+            .timestamp(0)
+            .ttl(0)
+            .build()
+            .unwrap();
+        assert_eq!(run_parameters, expected);
+        assert_eq!(run_parameters.air_size_limit, MAX_AIR_SIZE);
+        assert_eq!(run_parameters.particle_size_limit, MAX_PARTICLE_SIZE);
+        assert_eq!(run_parameters.call_result_size_limit, MAX_CALL_RESULT_SIZE);
+        assert_eq!(run_parameters.fuel_limit(), None);
+        assert_eq!(run_parameters.max_execution_time(), None);
+    }
+
+    #[test]
+    fn missing_peer_id_is_reported() {
+        let result = from_variables(&[(AIR_INIT_PEER_ID, "init_peer")]);
+
+        assert_eq!(result, Err(EnvConfigError::MissingVariable(AIR_PEER_ID)));
+    }
+
+    #[test]
+    fn invalid_values_are_reported() {
+        let invalid_values = [
+            (AIR_TIMESTAMP, "yesterday"),
+            (AIR_TTL, "-1"),
+            (AIR_KEY_FORMAT, "dsa"),
+            (AIR_SECRET_KEY_BASE64, "not base64!"),
+            (AIR_SIZE_LIMIT, "1.5"),
+            (AIR_HARD_LIMIT_ENABLED, "yes"),
+            (AIR_MAX_EXECUTION_TIME_MS, ""),
+        ];
+
+        for (variable, value) in invalid_values {
+            let result = from_variables(&[(AIR_PEER_ID, "current_peer"), (variable, value)]);
+
+            assert!(
+                matches!(
+                    result,
+                    Err(EnvConfigError::InvalidVariable { variable: actual, .. }) if actual == variable
+                ),
+                "{variable}={value:?} gave {result:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn non_unicode_value_is_reported() {
+        let result = RunParameters::from_variables(|name| match name {
+            AIR_PEER_ID => Ok("current_peer".to_string()),
+            AIR_PARTICLE_ID => Err(env::VarError::NotUnicode(<_>::default())),
+            _ => Err(env::VarError::NotPresent),
+        });
+
+        assert!(matches!(
+            result,
+            Err(EnvConfigError::InvalidVariable {
+                variable: AIR_PARTICLE_ID,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn process_environment_is_read() {
+        // no other test reads the process environment, so setting the variable doesn't race
+        env::set_var(AIR_PEER_ID, "env_peer");
+        let result = RunParameters::from_env();
+        env::remove_var(AIR_PEER_ID);
+
+        assert_eq!(result.unwrap().current_peer_id, "env_peer");
+    }
+}
@@ -0,0 +1,38 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::InstructionTag;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use std::time::Duration;
+
+/// An executed instruction along with instructions executed inside it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceNode {
+    pub instruction: InstructionTag,
+
+    /// Wall clock time spent in the instruction including its children.
+    pub duration: Duration,
+
+    /// Instructions executed inside this one in the order they were dispatched.
+    pub children: Vec<TraceNode>,
+}
+
+/// Instructions executed by an interpreter call, it's a single root instruction
+/// unless execution failed before the script was executed.
+pub type ExecutionTree = Vec<TraceNode>;
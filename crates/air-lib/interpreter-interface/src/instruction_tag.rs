@@ -0,0 +1,153 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+/// Kinds of instructions counted separately in execution metrics,
+/// variations of an instruction like `fold` over a stream share the same tag,
+/// while instructions with their own keyword like `match-regex` have separate ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum InstructionTag {
+    Call,
+    Fold,
+    Par,
+    Seq,
+    Ap,
+    Xor,
+    Match,
+    Mismatch,
+    Next,
+    Null,
+    Fail,
+    Never,
+    Canon,
+    New,
+    MatchRegex,
+    MismatchRegex,
+    CanonSorted,
+}
+
+/// Numbers of dispatched instructions of each kind.
+pub type InstructionCounts = HashMap<InstructionTag, u64>;
+
+/// The instruction policy value that allows all instructions.
+pub const ALL_INSTRUCTIONS_ALLOWED: u64 = u64::MAX;
+
+/// Restricts instructions a script may contain, a script with a forbidden instruction
+/// is rejected before execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InstructionPolicy {
+    /// Allowed instructions, all instructions are allowed if it's `None`.
+    allowed: Option<HashSet<InstructionTag>>,
+}
+
+impl InstructionPolicy {
+    pub fn allow_all() -> Self {
+        Self { allowed: None }
+    }
+
+    pub fn allow_list(instructions: HashSet<InstructionTag>) -> Self {
+        Self {
+            allowed: Some(instructions),
+        }
+    }
+
+    pub fn is_allowed(&self, instruction: InstructionTag) -> bool {
+        self.allowed
+            .as_ref()
+            .map_or(true, |allowed| allowed.contains(&instruction))
+    }
+}
+
+impl Default for InstructionPolicy {
+    fn default() -> Self {
+        Self::allow_all()
+    }
+}
+
+impl InstructionTag {
+    const ALL: [InstructionTag; 17] = [
+        Self::Call,
+        Self::Fold,
+        Self::Par,
+        Self::Seq,
+        Self::Ap,
+        Self::Xor,
+        Self::Match,
+        Self::Mismatch,
+        Self::Next,
+        Self::Null,
+        Self::Fail,
+        Self::Never,
+        Self::Canon,
+        Self::New,
+        Self::MatchRegex,
+        Self::MismatchRegex,
+        Self::CanonSorted,
+    ];
+
+    /// A bit of the tag in the encoded policy, it's a part of the interface and must not change.
+    fn policy_bit(self) -> u64 {
+        let position = match self {
+            Self::Call => 0,
+            Self::Fold => 1,
+            Self::Par => 2,
+            Self::Seq => 3,
+            Self::Ap => 4,
+            Self::Xor => 5,
+            Self::Match => 6,
+            Self::Mismatch => 7,
+            Self::Next => 8,
+            Self::Null => 9,
+            Self::Fail => 10,
+            Self::Never => 11,
+            Self::Canon => 12,
+            Self::New => 13,
+            Self::MatchRegex => 14,
+            Self::MismatchRegex => 15,
+            Self::CanonSorted => 16,
+        };
+        1 << position
+    }
+}
+
+impl From<&InstructionPolicy> for u64 {
+    fn from(policy: &InstructionPolicy) -> Self {
+        match &policy.allowed {
+            None => ALL_INSTRUCTIONS_ALLOWED,
+            Some(allowed) => allowed.iter().fold(0, |mask, tag| mask | tag.policy_bit()),
+        }
+    }
+}
+
+impl From<u64> for InstructionPolicy {
+    fn from(mask: u64) -> Self {
+        if mask == ALL_INSTRUCTIONS_ALLOWED {
+            return Self::allow_all();
+        }
+
+        let allowed = InstructionTag::ALL
+            .into_iter()
+            .filter(|tag| mask & tag.policy_bit() != 0)
+            .collect();
+        Self::allow_list(allowed)
+    }
+}
@@ -17,8 +17,10 @@
 #[cfg(feature = "marine")]
 use marine_rs_sdk::marine;
 
+use crate::InstructionTag;
 #[cfg(feature = "marine")]
 use fluence_it_types::IValue;
+
 use serde::Deserialize;
 use serde::Serialize;
 
@@ -32,6 +34,18 @@ pub struct SoftLimitsTriggering {
     pub call_result_size_limit_exceeded: bool,
 }
 
+/// Points to the instruction that raised an execution error.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorContext {
+    pub instruction: InstructionTag,
+
+    /// Byte offset of the opening bracket of the instruction in the script.
+    pub span_start: usize,
+
+    /// Byte offset right after the closing bracket of the instruction in the script.
+    pub span_end: usize,
+}
+
 /// Describes a result returned at the end of the interpreter execution_step.
 #[cfg_attr(feature = "marine", marine)]
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -60,6 +74,28 @@ pub struct InterpreterOutcome {
 
     /// This flag signals that call result size exceeds the limit.
     pub call_result_size_limit_exceeded: bool,
+
+    /// Amount of fuel consumed during execution, one unit per executed instruction.
+    pub fuel_consumed: u64,
+
+    /// Numbers of dispatched instructions of each kind serialized as a JSON object.
+    /// It's empty unless the interpreter is built with the `metrics` feature.
+    pub instruction_counts: Vec<u8>,
+
+    /// SVG flamegraph of time spent in executed instructions.
+    /// It's empty unless the interpreter is built with the `flamegraph` feature.
+    pub flamegraph_svg: String,
+
+    /// The instruction that raised the execution error serialized as a JSON object.
+    /// It's empty if execution succeeded or failed before any instruction was executed.
+    pub error_context: Vec<u8>,
+
+    /// Tree of executed instructions with time spent in them serialized as a JSON array.
+    /// It's empty unless the interpreter is invoked through the `invoke_traced` export.
+    pub execution_tree: Vec<u8>,
+
+    /// Fingerprint of the executed script, it's empty unless the interpreter caches the script.
+    pub air_fingerprint: Vec<u8>,
 }
 
 impl SoftLimitsTriggering {
@@ -90,6 +126,7 @@ impl InterpreterOutcome {
         next_peer_pks: Vec<String>,
         call_requests: SerializedCallRequests,
         soft_limits_triggering: SoftLimitsTriggering,
+        fuel_consumed: u64,
     ) -> Self {
         let call_requests = call_requests.into();
         Self {
@@ -101,14 +138,45 @@ impl InterpreterOutcome {
             air_size_limit_exceeded: soft_limits_triggering.air_size_limit_exceeded,
             particle_size_limit_exceeded: soft_limits_triggering.particle_size_limit_exceeded,
             call_result_size_limit_exceeded: soft_limits_triggering.call_result_size_limit_exceeded,
+            fuel_consumed,
+            instruction_counts: vec![],
+            flamegraph_svg: String::new(),
+            error_context: vec![],
+            execution_tree: vec![],
+            air_fingerprint: vec![],
         }
     }
+
+    pub fn with_instruction_counts(mut self, instruction_counts: Vec<u8>) -> Self {
+        self.instruction_counts = instruction_counts;
+        self
+    }
+
+    pub fn with_flamegraph_svg(mut self, flamegraph_svg: String) -> Self {
+        self.flamegraph_svg = flamegraph_svg;
+        self
+    }
+
+    pub fn with_error_context(mut self, error_context: Vec<u8>) -> Self {
+        self.error_context = error_context;
+        self
+    }
+
+    pub fn with_execution_tree(mut self, execution_tree: Vec<u8>) -> Self {
+        self.execution_tree = execution_tree;
+        self
+    }
+
+    pub fn with_air_fingerprint(mut self, air_fingerprint: Vec<u8>) -> Self {
+        self.air_fingerprint = air_fingerprint;
+        self
+    }
 }
 
 #[cfg(feature = "marine")]
 impl InterpreterOutcome {
     pub fn from_ivalue(ivalue: IValue) -> Result<Self, String> {
-        const OUTCOME_FIELDS_COUNT: usize = 8;
+        const OUTCOME_FIELDS_COUNT: usize = 14;
 
         let mut record_values = try_as_record(ivalue)?.into_vec();
         if record_values.len() != OUTCOME_FIELDS_COUNT {
@@ -117,6 +185,13 @@ impl InterpreterOutcome {
             ));
         }
 
+        let air_fingerprint = try_as_byte_vec(record_values.pop().unwrap(), "air_fingerprint")?;
+        let execution_tree = try_as_byte_vec(record_values.pop().unwrap(), "execution_tree")?;
+        let error_context = try_as_byte_vec(record_values.pop().unwrap(), "error_context")?;
+        let flamegraph_svg = try_as_string(record_values.pop().unwrap(), "flamegraph_svg")?;
+        let instruction_counts =
+            try_as_byte_vec(record_values.pop().unwrap(), "instruction_counts")?;
+        let fuel_consumed = try_as_u64(record_values.pop().unwrap(), "fuel_consumed")?;
         let call_result_size_limit_exceeded = try_as_boolean(
             record_values.pop().unwrap(),
             "call_result_size_limit_exceeded",
@@ -144,12 +219,59 @@ impl InterpreterOutcome {
             next_peer_pks,
             call_requests.into(),
             soft_limits_triggering,
-        );
+            fuel_consumed,
+        )
+        .with_instruction_counts(instruction_counts)
+        .with_flamegraph_svg(flamegraph_svg)
+        .with_error_context(error_context)
+        .with_execution_tree(execution_tree)
+        .with_air_fingerprint(air_fingerprint);
 
         Ok(outcome)
     }
 }
 
+/// Outcome of one execution of the `invoke_multi` interpreter export, the interpreter
+/// measures each execution of a batch, since a host sees only the whole batch.
+#[cfg_attr(feature = "marine", marine)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InvokeOutcome {
+    pub outcome: InterpreterOutcome,
+
+    /// Time spent on the execution in microseconds.
+    pub execution_time_us: u64,
+
+    /// Size of the interpreter linear memory right after the execution in bytes.
+    pub memory_size: u64,
+}
+
+#[cfg(feature = "marine")]
+impl InvokeOutcome {
+    pub fn from_ivalue(ivalue: IValue) -> Result<Self, String> {
+        const OUTCOME_FIELDS_COUNT: usize = 3;
+
+        let mut record_values = match ivalue {
+            IValue::Record(record_values) => record_values.into_vec(),
+            v => return Err(format!("expected record for InvokeOutcome, got {v:?}")),
+        };
+        if record_values.len() != OUTCOME_FIELDS_COUNT {
+            return Err(format!(
+                "expected InvokeOutcome struct with {OUTCOME_FIELDS_COUNT} fields, got {record_values:?}"
+            ));
+        }
+
+        let memory_size = try_as_u64(record_values.pop().unwrap(), "memory_size")?;
+        let execution_time_us = try_as_u64(record_values.pop().unwrap(), "execution_time_us")?;
+        let outcome = InterpreterOutcome::from_ivalue(record_values.pop().unwrap())?;
+
+        Ok(Self {
+            outcome,
+            execution_time_us,
+            memory_size,
+        })
+    }
+}
+
 #[cfg(feature = "marine")]
 use fluence_it_types::ne_vec::NEVec;
 
@@ -171,6 +293,14 @@ fn try_as_i64(ivalue: IValue, field_name: &str) -> Result<i64, String> {
     }
 }
 
+#[cfg(feature = "marine")]
+fn try_as_u64(ivalue: IValue, field_name: &str) -> Result<u64, String> {
+    match ivalue {
+        IValue::U64(value) => Ok(value),
+        v => Err(format!("expected an u64 for {field_name}, got {v:?}")),
+    }
+}
+
 #[cfg(feature = "marine")]
 pub fn try_as_string(ivalue: IValue, field_name: &str) -> Result<String, String> {
     match ivalue {
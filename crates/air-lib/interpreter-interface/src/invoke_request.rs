@@ -0,0 +1,39 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::RunParameters;
+#[cfg(feature = "marine")]
+use marine_rs_sdk::marine;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Arguments of one execution in a batch passed to the `invoke_multi` interpreter export,
+/// they are the same as the arguments of `invoke`.
+#[cfg_attr(feature = "marine", marine)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+pub struct InvokeRequest {
+    pub air: String,
+
+    pub prev_data: Vec<u8>,
+
+    pub data: Vec<u8>,
+
+    pub params: RunParameters,
+
+    /// Call results serialized with `CallResultsRepr`.
+    pub call_results: Vec<u8>,
+}
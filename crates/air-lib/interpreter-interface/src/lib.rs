@@ -28,12 +28,20 @@
 
 mod call_request_parameters;
 mod call_service_result;
+mod env_config;
+mod execution_tree;
+mod instruction_tag;
 mod interpreter_outcome;
+mod invoke_request;
 mod run_args_memory_limits;
 mod run_parameters;
 
 pub use call_request_parameters::*;
 pub use call_service_result::*;
+pub use env_config::*;
+pub use execution_tree::*;
+pub use instruction_tag::*;
 pub use interpreter_outcome::*;
+pub use invoke_request::*;
 pub use run_args_memory_limits::*;
 pub use run_parameters::*;
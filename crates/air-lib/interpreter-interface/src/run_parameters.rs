@@ -14,14 +14,26 @@
  * limitations under the License.
  */
 
+use crate::CallResultsFormat;
+use crate::InstructionPolicy;
+use crate::ALL_INSTRUCTIONS_ALLOWED;
+use crate::MAX_AIR_SIZE;
+use crate::MAX_CALL_RESULT_SIZE;
+use crate::MAX_PARTICLE_SIZE;
 #[cfg(feature = "marine")]
 use fluence_it_types::ne_vec::NEVec;
 #[cfg(feature = "marine")]
 use fluence_it_types::IValue;
 #[cfg(feature = "marine")]
 use marine_rs_sdk::marine;
+
 use serde::Deserialize;
 use serde::Serialize;
+use thiserror::Error as ThisError;
+
+use std::time::Duration;
+use std::time::SystemTime;
+use std::time::UNIX_EPOCH;
 
 /// Parameters that a host side should pass to an interpreter and that necessary for execution.
 #[cfg_attr(feature = "marine", marine)]
@@ -65,10 +77,195 @@ pub struct RunParameters {
 
     /// This knob controls hard RAM limits behavior for AVMRunner.
     pub hard_limit_enabled: bool,
+
+    /// The maximum number of instructions the interpreter is allowed to execute,
+    /// every instruction dispatch consumes one unit of fuel.
+    /// `UNLIMITED_FUEL` disables the limit, `RunParameters::fuel_limit` returns it as an option.
+    pub fuel_limit: u64,
+
+    /// A format call results are serialized with.
+    ///
+    /// This value is the result of `CallResultsFormat::into`, MsgPack is used by default.
+    pub call_results_format: u8,
+
+    /// Lints of which severity make the interpreter reject a script before execution.
+    ///
+    /// This value is the result of `LintLevel::into`, lints are allowed by default.
+    pub lint_level: u8,
+
+    /// The maximum wall clock time of execution in milliseconds, it's checked before
+    /// every instruction. `UNLIMITED_EXECUTION_TIME` disables the limit.
+    pub max_execution_time_ms: u64,
+
+    /// The number of parsed scripts the interpreter keeps between calls, 0 disables the cache.
+    /// The cache is sized by the first call that enables it, later calls can't resize it.
+    pub air_cache_capacity: u64,
+
+    /// Which peers' signatures are verified in incoming data.
+    ///
+    /// This value is the result of `SignaturePolicyKind::into`, all signatures are verified by default.
+    pub signature_policy: u8,
+
+    /// The maximum size of incoming data in bytes, it's checked before deserialization.
+    /// `UNLIMITED_DATA` disables the limit.
+    pub max_data_bytes: u64,
+
+    /// The maximum number of states in the trace of incoming data.
+    /// `UNLIMITED_DATA` disables the limit.
+    pub max_trace_len: u64,
+
+    /// Which key formats are accepted in signature stores and for the peer's own keypair.
+    ///
+    /// This value is the result of `KeyFormatPolicyKind::into`, only Ed25519 keys are accepted by default.
+    pub key_format_policy: u8,
+
+    /// Whether a particle is rejected once `timestamp + ttl` is past.
+    pub reject_expired_particles: bool,
+
+    /// The maximum depth of nested folds and fold iterations, every iteration is executed
+    /// inside the previous one. `UNLIMITED_FOLD_DEPTH` disables the limit.
+    pub max_fold_depth: u32,
+
+    /// Instructions a script may contain, it's a bitmask produced from `InstructionPolicy`.
+    /// `ALL_INSTRUCTIONS_ALLOWED` allows all instructions.
+    pub allowed_instructions: u64,
+
+    /// The maximum number of instructions in a script, it's checked before execution.
+    /// `UNLIMITED_INSTRUCTION_COUNT` disables the limit.
+    pub max_instruction_count: u64,
+}
+
+/// The fuel limit value that doesn't bound execution.
+pub const UNLIMITED_FUEL: u64 = u64::MAX;
+
+/// The execution time limit value that doesn't bound execution.
+pub const UNLIMITED_EXECUTION_TIME: u64 = u64::MAX;
+
+/// The data limit value that doesn't bound incoming data.
+pub const UNLIMITED_DATA: u64 = u64::MAX;
+
+/// The fold depth limit value that doesn't bound execution.
+pub const UNLIMITED_FOLD_DEPTH: u32 = u32::MAX;
+
+/// The instruction count limit value that doesn't bound scripts.
+pub const UNLIMITED_INSTRUCTION_COUNT: u64 = u64::MAX;
+
+/// Controls which script lints fail a particle before its execution starts.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LintLevel {
+    /// Scripts are not linted.
+    #[default]
+    Allow,
+    /// Scripts with lints of the error severity are rejected.
+    DenyErrors,
+    /// Scripts with any lints are rejected.
+    DenyWarnings,
 }
 
+impl From<LintLevel> for u8 {
+    fn from(level: LintLevel) -> Self {
+        match level {
+            LintLevel::Allow => 0,
+            LintLevel::DenyErrors => 1,
+            LintLevel::DenyWarnings => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for LintLevel {
+    type Error = UnsupportedLintLevel;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Allow),
+            1 => Ok(Self::DenyErrors),
+            2 => Ok(Self::DenyWarnings),
+            _ => Err(UnsupportedLintLevel(value)),
+        }
+    }
+}
+
+#[derive(Debug, ThisError)]
+#[error("unsupported lint level: {0}")]
+pub struct UnsupportedLintLevel(pub u8);
+
+/// Selects one of the built-in signature verification policies.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SignaturePolicyKind {
+    /// Signatures of all peers are verified.
+    #[default]
+    VerifyAll,
+    /// Signatures aren't verified, it's intended for trusted networks and testing.
+    VerifyNone,
+}
+
+impl From<SignaturePolicyKind> for u8 {
+    fn from(policy: SignaturePolicyKind) -> Self {
+        match policy {
+            SignaturePolicyKind::VerifyAll => 0,
+            SignaturePolicyKind::VerifyNone => 1,
+        }
+    }
+}
+
+impl TryFrom<u8> for SignaturePolicyKind {
+    type Error = UnsupportedSignaturePolicy;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::VerifyAll),
+            1 => Ok(Self::VerifyNone),
+            _ => Err(UnsupportedSignaturePolicy(value)),
+        }
+    }
+}
+
+#[derive(Debug, ThisError)]
+#[error("unsupported signature policy: {0}")]
+pub struct UnsupportedSignaturePolicy(pub u8);
+
+/// Selects the key formats accepted in signature stores and for the peer's own keypair.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyFormatPolicyKind {
+    /// Only Ed25519 keys are accepted.
+    #[default]
+    Ed25519Only,
+    /// Keys of any supported format are accepted, it's intended for cross-network routing.
+    Any,
+}
+
+impl From<KeyFormatPolicyKind> for u8 {
+    fn from(policy: KeyFormatPolicyKind) -> Self {
+        match policy {
+            KeyFormatPolicyKind::Ed25519Only => 0,
+            KeyFormatPolicyKind::Any => 1,
+        }
+    }
+}
+
+impl TryFrom<u8> for KeyFormatPolicyKind {
+    type Error = UnsupportedKeyFormatPolicy;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Ed25519Only),
+            1 => Ok(Self::Any),
+            _ => Err(UnsupportedKeyFormatPolicy(value)),
+        }
+    }
+}
+
+#[derive(Debug, ThisError)]
+#[error("unsupported key format policy: {0}")]
+pub struct UnsupportedKeyFormatPolicy(pub u8);
+
 impl RunParameters {
     #![allow(clippy::too_many_arguments)]
+    pub fn builder() -> RunParametersBuilder {
+        RunParametersBuilder::default()
+    }
+
+    #[deprecated(note = "use RunParameters::builder instead, it doesn't break on new fields")]
     pub fn new(
         init_peer_id: String,
         current_peer_id: String,
@@ -81,6 +278,7 @@ impl RunParameters {
         particle_size_limit: u64,
         call_result_size_limit: u64,
         hard_limit_enabled: bool,
+        fuel_limit: Option<u64>,
     ) -> Self {
         Self {
             init_peer_id,
@@ -94,9 +292,56 @@ impl RunParameters {
             particle_size_limit,
             call_result_size_limit,
             hard_limit_enabled,
+            fuel_limit: fuel_limit.unwrap_or(UNLIMITED_FUEL),
+            call_results_format: CallResultsFormat::default().into(),
+            lint_level: LintLevel::default().into(),
+            max_execution_time_ms: UNLIMITED_EXECUTION_TIME,
+            air_cache_capacity: 0,
+            signature_policy: SignaturePolicyKind::default().into(),
+            max_data_bytes: UNLIMITED_DATA,
+            max_trace_len: UNLIMITED_DATA,
+            key_format_policy: KeyFormatPolicyKind::default().into(),
+            reject_expired_particles: false,
+            max_fold_depth: UNLIMITED_FOLD_DEPTH,
+            allowed_instructions: ALL_INSTRUCTIONS_ALLOWED,
+            max_instruction_count: UNLIMITED_INSTRUCTION_COUNT,
         }
     }
 
+    /// Returns the fuel limit if it's set.
+    pub fn fuel_limit(&self) -> Option<u64> {
+        (self.fuel_limit != UNLIMITED_FUEL).then_some(self.fuel_limit)
+    }
+
+    /// Returns the execution time limit if it's set.
+    pub fn max_execution_time(&self) -> Option<Duration> {
+        (self.max_execution_time_ms != UNLIMITED_EXECUTION_TIME)
+            .then(|| Duration::from_millis(self.max_execution_time_ms))
+    }
+
+    /// Returns the time the particle expires at, `None` if it's beyond the representable time.
+    pub fn particle_expires_at(&self) -> Option<SystemTime> {
+        UNIX_EPOCH
+            .checked_add(Duration::from_millis(self.timestamp))?
+            .checked_add(Duration::from_millis(self.ttl as u64))
+    }
+
+    /// Returns the fold depth limit if it's set.
+    pub fn max_fold_depth(&self) -> Option<u32> {
+        (self.max_fold_depth != UNLIMITED_FOLD_DEPTH).then_some(self.max_fold_depth)
+    }
+
+    /// Returns the instruction count limit if it's set.
+    pub fn max_instruction_count(&self) -> Option<u64> {
+        (self.max_instruction_count != UNLIMITED_INSTRUCTION_COUNT)
+            .then_some(self.max_instruction_count)
+    }
+
+    /// Returns the policy of instructions a script may contain.
+    pub fn instruction_policy(&self) -> InstructionPolicy {
+        self.allowed_instructions.into()
+    }
+
     #[cfg(feature = "marine")]
     pub fn into_ivalue(self) -> IValue {
         let run_parameters = vec![
@@ -111,9 +356,256 @@ impl RunParameters {
             IValue::U64(self.particle_size_limit),
             IValue::U64(self.call_result_size_limit),
             IValue::Boolean(self.hard_limit_enabled),
+            IValue::U64(self.fuel_limit),
+            IValue::U8(self.call_results_format),
+            IValue::U8(self.lint_level),
+            IValue::U64(self.max_execution_time_ms),
+            IValue::U64(self.air_cache_capacity),
+            IValue::U8(self.signature_policy),
+            IValue::U64(self.max_data_bytes),
+            IValue::U64(self.max_trace_len),
+            IValue::U8(self.key_format_policy),
+            IValue::Boolean(self.reject_expired_particles),
+            IValue::U32(self.max_fold_depth),
+            IValue::U64(self.allowed_instructions),
+            IValue::U64(self.max_instruction_count),
         ];
         // unwrap is safe here because run_parameters is non-empty array
         let run_parameters = NEVec::new(run_parameters).unwrap();
         IValue::Record(run_parameters)
     }
 }
+
+/// Builds `RunParameters`, peer ids, timestamp and ttl are required,
+/// keys are empty and limits are the default ones unless set.
+#[derive(Debug, Clone)]
+pub struct RunParametersBuilder {
+    init_peer_id: Option<String>,
+    current_peer_id: Option<String>,
+    timestamp: Option<u64>,
+    ttl: Option<u32>,
+    key_format: u8,
+    secret_key_bytes: Vec<u8>,
+    particle_id: String,
+    air_size_limit: u64,
+    particle_size_limit: u64,
+    call_result_size_limit: u64,
+    hard_limit_enabled: bool,
+    fuel_limit: Option<u64>,
+    call_results_format: CallResultsFormat,
+    lint_level: LintLevel,
+    max_execution_time: Option<Duration>,
+    air_cache_capacity: u64,
+    signature_policy: SignaturePolicyKind,
+    max_data_bytes: Option<usize>,
+    max_trace_len: Option<usize>,
+    key_format_policy: KeyFormatPolicyKind,
+    reject_expired_particles: bool,
+    max_fold_depth: Option<u32>,
+    instruction_policy: InstructionPolicy,
+    max_instruction_count: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, ThisError)]
+pub enum BuildError {
+    #[error("run parameters field `{0}` is required, but it wasn't set")]
+    MissingField(&'static str),
+}
+
+impl Default for RunParametersBuilder {
+    fn default() -> Self {
+        Self {
+            init_peer_id: None,
+            current_peer_id: None,
+            timestamp: None,
+            ttl: None,
+            key_format: <_>::default(),
+            secret_key_bytes: <_>::default(),
+            particle_id: <_>::default(),
+            air_size_limit: MAX_AIR_SIZE,
+            particle_size_limit: MAX_PARTICLE_SIZE,
+            call_result_size_limit: MAX_CALL_RESULT_SIZE,
+            hard_limit_enabled: false,
+            fuel_limit: None,
+            call_results_format: <_>::default(),
+            lint_level: <_>::default(),
+            max_execution_time: None,
+            air_cache_capacity: 0,
+            signature_policy: <_>::default(),
+            max_data_bytes: None,
+            max_trace_len: None,
+            key_format_policy: <_>::default(),
+            reject_expired_particles: false,
+            max_fold_depth: None,
+            instruction_policy: <_>::default(),
+            max_instruction_count: None,
+        }
+    }
+}
+
+impl RunParametersBuilder {
+    pub fn init_peer_id(mut self, init_peer_id: impl Into<String>) -> Self {
+        self.init_peer_id = Some(init_peer_id.into());
+        self
+    }
+
+    pub fn current_peer_id(mut self, current_peer_id: impl Into<String>) -> Self {
+        self.current_peer_id = Some(current_peer_id.into());
+        self
+    }
+
+    pub fn timestamp(mut self, timestamp: u64) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+
+    pub fn ttl(mut self, ttl: u32) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+
+    pub fn key_format(mut self, key_format: u8) -> Self {
+        self.key_format = key_format;
+        self
+    }
+
+    pub fn secret_key_bytes(mut self, secret_key_bytes: Vec<u8>) -> Self {
+        self.secret_key_bytes = secret_key_bytes;
+        self
+    }
+
+    pub fn particle_id(mut self, particle_id: impl Into<String>) -> Self {
+        self.particle_id = particle_id.into();
+        self
+    }
+
+    pub fn air_size_limit(mut self, air_size_limit: u64) -> Self {
+        self.air_size_limit = air_size_limit;
+        self
+    }
+
+    pub fn particle_size_limit(mut self, particle_size_limit: u64) -> Self {
+        self.particle_size_limit = particle_size_limit;
+        self
+    }
+
+    pub fn call_result_size_limit(mut self, call_result_size_limit: u64) -> Self {
+        self.call_result_size_limit = call_result_size_limit;
+        self
+    }
+
+    pub fn hard_limit_enabled(mut self, hard_limit_enabled: bool) -> Self {
+        self.hard_limit_enabled = hard_limit_enabled;
+        self
+    }
+
+    pub fn fuel_limit(mut self, fuel_limit: Option<u64>) -> Self {
+        self.fuel_limit = fuel_limit;
+        self
+    }
+
+    pub fn call_results_format(mut self, call_results_format: CallResultsFormat) -> Self {
+        self.call_results_format = call_results_format;
+        self
+    }
+
+    pub fn lint_level(mut self, lint_level: LintLevel) -> Self {
+        self.lint_level = lint_level;
+        self
+    }
+
+    pub fn max_execution_time(mut self, max_execution_time: Option<Duration>) -> Self {
+        self.max_execution_time = max_execution_time;
+        self
+    }
+
+    pub fn air_cache_capacity(mut self, air_cache_capacity: u64) -> Self {
+        self.air_cache_capacity = air_cache_capacity;
+        self
+    }
+
+    pub fn signature_policy(mut self, signature_policy: SignaturePolicyKind) -> Self {
+        self.signature_policy = signature_policy;
+        self
+    }
+
+    pub fn max_data_bytes(mut self, max_data_bytes: Option<usize>) -> Self {
+        self.max_data_bytes = max_data_bytes;
+        self
+    }
+
+    pub fn max_trace_len(mut self, max_trace_len: Option<usize>) -> Self {
+        self.max_trace_len = max_trace_len;
+        self
+    }
+
+    pub fn key_format_policy(mut self, key_format_policy: KeyFormatPolicyKind) -> Self {
+        self.key_format_policy = key_format_policy;
+        self
+    }
+
+    pub fn reject_expired_particles(mut self, reject_expired_particles: bool) -> Self {
+        self.reject_expired_particles = reject_expired_particles;
+        self
+    }
+
+    pub fn max_fold_depth(mut self, max_fold_depth: Option<u32>) -> Self {
+        self.max_fold_depth = max_fold_depth;
+        self
+    }
+
+    pub fn instruction_policy(mut self, instruction_policy: InstructionPolicy) -> Self {
+        self.instruction_policy = instruction_policy;
+        self
+    }
+
+    pub fn max_instruction_count(mut self, max_instruction_count: Option<u64>) -> Self {
+        self.max_instruction_count = max_instruction_count;
+        self
+    }
+
+    pub fn build(self) -> Result<RunParameters, BuildError> {
+        Ok(RunParameters {
+            init_peer_id: self
+                .init_peer_id
+                .ok_or(BuildError::MissingField("init_peer_id"))?,
+            current_peer_id: self
+                .current_peer_id
+                .ok_or(BuildError::MissingField("current_peer_id"))?,
+            timestamp: self
+                .timestamp
+                .ok_or(BuildError::MissingField("timestamp"))?,
+            ttl: self.ttl.ok_or(BuildError::MissingField("ttl"))?,
+            key_format: self.key_format,
+            secret_key_bytes: self.secret_key_bytes,
+            particle_id: self.particle_id,
+            air_size_limit: self.air_size_limit,
+            particle_size_limit: self.particle_size_limit,
+            call_result_size_limit: self.call_result_size_limit,
+            hard_limit_enabled: self.hard_limit_enabled,
+            fuel_limit: self.fuel_limit.unwrap_or(UNLIMITED_FUEL),
+            call_results_format: self.call_results_format.into(),
+            lint_level: self.lint_level.into(),
+            // limits that don't fit into milliseconds are effectively unlimited
+            max_execution_time_ms: self
+                .max_execution_time
+                .and_then(|time| time.as_millis().try_into().ok())
+                .unwrap_or(UNLIMITED_EXECUTION_TIME),
+            air_cache_capacity: self.air_cache_capacity,
+            signature_policy: self.signature_policy.into(),
+            max_data_bytes: self
+                .max_data_bytes
+                .map_or(UNLIMITED_DATA, |limit| limit as u64),
+            max_trace_len: self
+                .max_trace_len
+                .map_or(UNLIMITED_DATA, |limit| limit as u64),
+            key_format_policy: self.key_format_policy.into(),
+            reject_expired_particles: self.reject_expired_particles,
+            max_fold_depth: self.max_fold_depth.unwrap_or(UNLIMITED_FOLD_DEPTH),
+            allowed_instructions: (&self.instruction_policy).into(),
+            max_instruction_count: self
+                .max_instruction_count
+                .unwrap_or(UNLIMITED_INSTRUCTION_COUNT),
+        })
+    }
+}
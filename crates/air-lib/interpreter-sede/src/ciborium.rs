@@ -0,0 +1,86 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::multiformat::SerializationCodec;
+use crate::Format;
+
+// https://github.com/multiformats/multicodec/blob/master/table.csv
+const MULTIFORMAT_CBOR: SerializationCodec = 0x51;
+
+#[derive(Copy, Clone, Default)]
+pub struct CiboriumFormat;
+
+impl<Value> Format<Value> for CiboriumFormat
+where
+    Value: serde::Serialize + serde::de::DeserializeOwned,
+{
+    type SerializationError = ciborium::ser::Error<std::io::Error>;
+    type DeserializationError = ciborium::de::Error<std::io::Error>;
+    type WriteError = ciborium::ser::Error<std::io::Error>;
+
+    #[inline]
+    fn to_vec(&self, value: &Value) -> Result<Vec<u8>, Self::SerializationError> {
+        let mut buffer = Vec::new();
+        ciborium::into_writer(value, &mut buffer)?;
+        Ok(buffer)
+    }
+
+    #[inline]
+    fn from_slice(&self, slice: &[u8]) -> Result<Value, Self::DeserializationError> {
+        ciborium::from_reader(slice)
+    }
+
+    #[inline]
+    fn to_writer<W: std::io::Write>(
+        &self,
+        value: &Value,
+        write: &mut W,
+    ) -> Result<(), Self::WriteError> {
+        ciborium::into_writer(value, write)
+    }
+}
+
+#[derive(Copy, Clone, Default)]
+pub struct CiboriumMultiformat;
+
+impl<Value> Format<Value> for CiboriumMultiformat
+where
+    Value: serde::Serialize + serde::de::DeserializeOwned,
+{
+    type SerializationError = crate::multiformat::EncodeError<ciborium::ser::Error<std::io::Error>>;
+    type DeserializationError =
+        crate::multiformat::DecodeError<ciborium::de::Error<std::io::Error>>;
+    type WriteError = crate::multiformat::EncodeError<ciborium::ser::Error<std::io::Error>>;
+
+    #[inline]
+    fn to_vec(&self, value: &Value) -> Result<Vec<u8>, Self::SerializationError> {
+        crate::multiformat::encode_multiformat(value, MULTIFORMAT_CBOR, &CiboriumFormat)
+    }
+
+    #[inline]
+    fn from_slice(&self, slice: &[u8]) -> Result<Value, Self::DeserializationError> {
+        crate::multiformat::decode_multiformat(slice, MULTIFORMAT_CBOR, &CiboriumFormat)
+    }
+
+    #[inline]
+    fn to_writer<W: std::io::Write>(
+        &self,
+        value: &Value,
+        write: &mut W,
+    ) -> Result<(), Self::WriteError> {
+        crate::multiformat::write_multiformat(value, MULTIFORMAT_CBOR, &CiboriumFormat, write)
+    }
+}
@@ -50,3 +50,15 @@ pub use crate::serde_json::SerdeJsonMultiformat;
 pub use crate::serde_json::SerdeJsonFormat as JsonFormat;
 #[cfg(feature = "json")]
 pub use crate::serde_json::SerdeJsonMultiformat as JsonMultiformat;
+
+#[cfg(feature = "ciborium")]
+pub(crate) mod ciborium;
+#[cfg(feature = "ciborium")]
+pub use crate::ciborium::CiboriumFormat;
+#[cfg(feature = "ciborium")]
+pub use crate::ciborium::CiboriumMultiformat;
+
+#[cfg(feature = "cbor")]
+pub use crate::ciborium::CiboriumFormat as CborFormat;
+#[cfg(feature = "cbor")]
+pub use crate::ciborium::CiboriumMultiformat as CborMultiformat;
@@ -26,10 +26,14 @@
     unreachable_patterns
 )]
 
+mod pem;
+mod policy;
 mod sede;
 mod stores;
 mod trackers;
 
+pub use crate::pem::PemError;
+pub use crate::policy::*;
 pub use crate::stores::*;
 pub use crate::trackers::*;
 
@@ -48,6 +52,8 @@ pub enum KeyError {
     AlgorithmNotWhitelisted(fluence_keypair::KeyFormat),
     #[error("invalid key data: {0}")]
     InvalidKeyData(#[from] DecodingError),
+    #[error("invalid PEM key: {0}")]
+    InvalidPem(#[from] PemError),
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -105,10 +111,19 @@ impl PublicKey {
         Ok(pk.to_peer_id().to_string())
     }
 
-    pub fn validate(&self) -> Result<(), KeyError> {
+    /// Returns the format of the key, it's a part of the key's encoded representation.
+    pub fn key_format(&self) -> Result<KeyFormat, KeyError> {
         let pk = fluence_keypair::PublicKey::decode(&self.0)?;
-        let key_format = pk.get_key_format();
-        validate_with_key_format((), key_format)
+        Ok(pk.get_key_format())
+    }
+
+    pub fn validate(&self) -> Result<(), KeyError> {
+        self.validate_with_policy(KeyFormatPolicy::default())
+    }
+
+    pub fn validate_with_policy(&self, policy: KeyFormatPolicy) -> Result<(), KeyError> {
+        let key_format = self.key_format()?;
+        validate_with_key_format((), key_format, policy)
     }
 }
 
@@ -123,15 +138,46 @@ pub struct KeyPair(fluence_keypair::KeyPair);
 
 impl KeyPair {
     pub fn new(inner: fluence_keypair::KeyPair) -> Result<Self, KeyError> {
+        Self::with_policy(inner, KeyFormatPolicy::default())
+    }
+
+    pub fn with_policy(
+        inner: fluence_keypair::KeyPair,
+        policy: KeyFormatPolicy,
+    ) -> Result<Self, KeyError> {
         let key_format = inner.key_format();
-        validate_with_key_format((), key_format)?;
+        validate_with_key_format((), key_format, policy)?;
 
         Ok(Self(inner))
     }
 
     pub fn from_secret_key(secret_key: Vec<u8>, key_format: KeyFormat) -> Result<Self, KeyError> {
+        Self::from_secret_key_with_policy(secret_key, key_format, KeyFormatPolicy::default())
+    }
+
+    pub fn from_secret_key_with_policy(
+        secret_key: Vec<u8>,
+        key_format: KeyFormat,
+        policy: KeyFormatPolicy,
+    ) -> Result<Self, KeyError> {
         let inner = fluence_keypair::KeyPair::from_secret_key(secret_key, key_format)?;
-        Self::new(inner)
+        Self::with_policy(inner, policy)
+    }
+
+    /// Parses a PEM-encoded Ed25519 or Secp256k1 secret key, e.g. one produced by
+    /// `openssl genpkey` or `ssh-keygen -t ed25519` without a passphrase.
+    pub fn from_pem(pem: &str) -> Result<Self, KeyError> {
+        Self::from_pem_with_policy(pem, KeyFormatPolicy::default())
+    }
+
+    pub fn from_pem_with_policy(pem: &str, policy: KeyFormatPolicy) -> Result<Self, KeyError> {
+        let (secret_key, key_format) = pem::decode(pem)?;
+        Self::from_secret_key_with_policy(secret_key, key_format, policy)
+    }
+
+    /// Encodes the secret key as a PKCS #8 PEM document.
+    pub fn to_pem(&self) -> String {
+        pem::encode(&self.secret(), self.key_format())
     }
 
     pub fn public(&self) -> PublicKey {
@@ -173,13 +219,15 @@ impl From<KeyPair> for fluence_keypair::KeyPair {
     }
 }
 
-pub(crate) fn validate_with_key_format<V>(inner: V, key_format: KeyFormat) -> Result<V, KeyError> {
-    // this allow is needed in order to support old versions of the fluence_keypair
-    // repos which is used to build it for RISC-0
-    #[allow(unreachable_patterns)]
-    match key_format {
-        fluence_keypair::KeyFormat::Ed25519 => Ok(inner),
-        _ => Err(KeyError::AlgorithmNotWhitelisted(key_format)),
+pub(crate) fn validate_with_key_format<V>(
+    inner: V,
+    key_format: KeyFormat,
+    policy: KeyFormatPolicy,
+) -> Result<V, KeyError> {
+    if policy.is_allowed(key_format) {
+        Ok(inner)
+    } else {
+        Err(KeyError::AlgorithmNotWhitelisted(key_format))
     }
 }
 
@@ -206,6 +254,18 @@ impl Signature {
     fn new(signature: fluence_keypair::Signature) -> Self {
         Self(signature.encode().into())
     }
+
+    /// Returns the format of the signature, if it can be decoded.
+    pub(crate) fn key_format(&self) -> Option<KeyFormat> {
+        // an empty signature isn't checked by fluence_keypair::Signature::decode
+        if self.0.is_empty() {
+            return None;
+        }
+
+        fluence_keypair::Signature::decode(self.0.to_vec())
+            .ok()
+            .map(|signature| signature.get_signature_type())
+    }
 }
 
 impl From<fluence_keypair::Signature> for Signature {
@@ -0,0 +1,463 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! PEM encoding of secret keys.
+//!
+//! Decoding accepts PKCS #8 (`PRIVATE KEY`) Ed25519 and Secp256k1 keys, SEC1 (`EC PRIVATE KEY`)
+//! Secp256k1 keys, and unencrypted OpenSSH (`OPENSSH PRIVATE KEY`) Ed25519 keys.
+//! Encoding always produces PKCS #8.
+
+use fluence_keypair::KeyFormat;
+
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use thiserror::Error as ThisError;
+
+const PKCS8_LABEL: &str = "PRIVATE KEY";
+const ENCRYPTED_PKCS8_LABEL: &str = "ENCRYPTED PRIVATE KEY";
+const SEC1_LABEL: &str = "EC PRIVATE KEY";
+const OPENSSH_LABEL: &str = "OPENSSH PRIVATE KEY";
+
+// DER-encoded object identifiers of the supported algorithms
+const ED25519_OID: &[u8] = &[0x2b, 0x65, 0x70];
+const EC_PUBLIC_KEY_OID: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01];
+const SECP256K1_OID: &[u8] = &[0x2b, 0x81, 0x04, 0x00, 0x0a];
+
+const SEQUENCE_TAG: u8 = 0x30;
+const INTEGER_TAG: u8 = 0x02;
+const OCTET_STRING_TAG: u8 = 0x04;
+const OID_TAG: u8 = 0x06;
+const EC_PARAMETERS_TAG: u8 = 0xa0;
+
+const OPENSSH_MAGIC: &[u8] = b"openssh-key-v1\0";
+const OPENSSH_ED25519: &[u8] = b"ssh-ed25519";
+
+const SECRET_KEY_LEN: usize = 32;
+const LINE_WIDTH: usize = 64;
+
+#[derive(ThisError, Debug, Clone, PartialEq, Eq)]
+pub enum PemError {
+    #[error("PEM armor is malformed: {0}")]
+    MalformedArmor(&'static str),
+
+    #[error("PEM label '{0}' isn't supported")]
+    UnsupportedLabel(String),
+
+    #[error("PEM body isn't valid base64: {0}")]
+    InvalidBase64(#[from] base64::DecodeError),
+
+    #[error("key structure is malformed: {0}")]
+    MalformedKey(&'static str),
+
+    #[error("key algorithm isn't supported, only Ed25519 and Secp256k1 are")]
+    UnsupportedAlgorithm,
+
+    #[error("encrypted keys aren't supported")]
+    EncryptedKey,
+}
+
+/// Returns raw secret key bytes and their format.
+pub(crate) fn decode(pem: &str) -> Result<(Vec<u8>, KeyFormat), PemError> {
+    let (label, der) = decode_armor(pem)?;
+
+    match label {
+        PKCS8_LABEL => decode_pkcs8(&der),
+        SEC1_LABEL => decode_sec1(&der, true).map(|secret| (secret, KeyFormat::Secp256k1)),
+        OPENSSH_LABEL => decode_openssh(&der).map(|secret| (secret, KeyFormat::Ed25519)),
+        ENCRYPTED_PKCS8_LABEL => Err(PemError::EncryptedKey),
+        label => Err(PemError::UnsupportedLabel(label.to_string())),
+    }
+}
+
+/// Encodes raw secret key bytes as a PKCS #8 PEM document.
+pub(crate) fn encode(secret: &[u8], key_format: KeyFormat) -> String {
+    let private_key = match key_format {
+        KeyFormat::Ed25519 => der_tlv(OCTET_STRING_TAG, secret),
+        KeyFormat::Secp256k1 => {
+            // the curve is specified by the algorithm identifier, so it's omitted here
+            let version = der_tlv(INTEGER_TAG, &[1]);
+            let key = der_tlv(OCTET_STRING_TAG, secret);
+            der_tlv(SEQUENCE_TAG, &[version, key].concat())
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        KeyFormat::Rsa => unreachable!("secret keys aren't available for RSA"),
+    };
+    let algorithm = match key_format {
+        KeyFormat::Ed25519 => der_tlv(OID_TAG, ED25519_OID),
+        _ => [
+            der_tlv(OID_TAG, EC_PUBLIC_KEY_OID),
+            der_tlv(OID_TAG, SECP256K1_OID),
+        ]
+        .concat(),
+    };
+
+    let version = der_tlv(INTEGER_TAG, &[0]);
+    let algorithm = der_tlv(SEQUENCE_TAG, &algorithm);
+    let private_key = der_tlv(OCTET_STRING_TAG, &private_key);
+    let der = der_tlv(SEQUENCE_TAG, &[version, algorithm, private_key].concat());
+
+    encode_armor(PKCS8_LABEL, &der)
+}
+
+fn decode_armor(pem: &str) -> Result<(&str, Vec<u8>), PemError> {
+    let pem = pem.trim();
+    let header = pem
+        .strip_prefix("-----BEGIN ")
+        .ok_or(PemError::MalformedArmor("BEGIN line is absent"))?;
+    let (label, rest) = header
+        .split_once("-----")
+        .ok_or(PemError::MalformedArmor("BEGIN line isn't terminated"))?;
+    let footer = format!("-----END {label}-----");
+    let body = rest
+        .strip_suffix(footer.as_str())
+        .ok_or(PemError::MalformedArmor(
+            "END line doesn't match BEGIN line",
+        ))?;
+
+    let body: String = body.chars().filter(|c| !c.is_ascii_whitespace()).collect();
+    let der = BASE64.decode(body)?;
+
+    Ok((label, der))
+}
+
+fn encode_armor(label: &str, der: &[u8]) -> String {
+    let body = BASE64.encode(der);
+
+    let mut pem = format!("-----BEGIN {label}-----\n");
+    for line in body.as_bytes().chunks(LINE_WIDTH) {
+        pem.push_str(std::str::from_utf8(line).expect("base64 is ASCII"));
+        pem.push('\n');
+    }
+    pem.push_str(&format!("-----END {label}-----\n"));
+    pem
+}
+
+// PrivateKeyInfo ::= SEQUENCE { version, AlgorithmIdentifier, privateKey OCTET STRING, .. }
+fn decode_pkcs8(der: &[u8]) -> Result<(Vec<u8>, KeyFormat), PemError> {
+    let mut info = DerReader::new(DerReader::new(der).read(SEQUENCE_TAG)?);
+    info.read(INTEGER_TAG)?;
+    let mut algorithm = DerReader::new(info.read(SEQUENCE_TAG)?);
+    let private_key = info.read(OCTET_STRING_TAG)?;
+
+    match algorithm.read(OID_TAG)? {
+        ED25519_OID => {
+            // CurvePrivateKey ::= OCTET STRING
+            let secret = DerReader::new(private_key).read(OCTET_STRING_TAG)?;
+            Ok((check_secret_len(secret)?, KeyFormat::Ed25519))
+        }
+        EC_PUBLIC_KEY_OID if algorithm.read(OID_TAG)? == SECP256K1_OID => {
+            let secret = decode_sec1(private_key, false)?;
+            Ok((secret, KeyFormat::Secp256k1))
+        }
+        _ => Err(PemError::UnsupportedAlgorithm),
+    }
+}
+
+// ECPrivateKey ::= SEQUENCE { version, privateKey OCTET STRING, [0] parameters OPTIONAL, .. }
+fn decode_sec1(der: &[u8], curve_required: bool) -> Result<Vec<u8>, PemError> {
+    let mut key = DerReader::new(DerReader::new(der).read(SEQUENCE_TAG)?);
+    key.read(INTEGER_TAG)?;
+    let secret = key.read(OCTET_STRING_TAG)?;
+
+    match key.try_read(EC_PARAMETERS_TAG)? {
+        Some(parameters) if DerReader::new(parameters).read(OID_TAG)? != SECP256K1_OID => {
+            return Err(PemError::UnsupportedAlgorithm)
+        }
+        None if curve_required => {
+            return Err(PemError::MalformedKey("curve parameters are absent"))
+        }
+        _ => {}
+    }
+
+    check_secret_len(secret)
+}
+
+// see https://github.com/openssh/openssh-portable/blob/master/PROTOCOL.key
+fn decode_openssh(data: &[u8]) -> Result<Vec<u8>, PemError> {
+    let data = data
+        .strip_prefix(OPENSSH_MAGIC)
+        .ok_or(PemError::MalformedKey("OpenSSH magic is absent"))?;
+    let mut reader = SshReader::new(data);
+
+    let cipher = reader.read_string()?;
+    let kdf = reader.read_string()?;
+    if cipher != b"none" || kdf != b"none" {
+        return Err(PemError::EncryptedKey);
+    }
+    reader.read_string()?; // kdf options
+    if reader.read_u32()? != 1 {
+        return Err(PemError::MalformedKey("exactly one key is expected"));
+    }
+    reader.read_string()?; // public key
+
+    let mut private = SshReader::new(reader.read_string()?);
+    if private.read_u32()? != private.read_u32()? {
+        return Err(PemError::MalformedKey("check numbers don't match"));
+    }
+    if private.read_string()? != OPENSSH_ED25519 {
+        return Err(PemError::UnsupportedAlgorithm);
+    }
+    private.read_string()?; // public key
+                            // the secret key is followed by the public key
+    let secret = private.read_string()?;
+    if secret.len() != 2 * SECRET_KEY_LEN {
+        return Err(PemError::MalformedKey("secret key has invalid length"));
+    }
+
+    Ok(secret[..SECRET_KEY_LEN].to_vec())
+}
+
+fn check_secret_len(secret: &[u8]) -> Result<Vec<u8>, PemError> {
+    if secret.len() == SECRET_KEY_LEN {
+        Ok(secret.to_vec())
+    } else {
+        Err(PemError::MalformedKey("secret key has invalid length"))
+    }
+}
+
+fn der_tlv(tag: u8, value: &[u8]) -> Vec<u8> {
+    let mut tlv = vec![tag];
+    match value.len() {
+        len @ 0..=0x7f => tlv.push(len as u8),
+        len @ 0x80..=0xff => tlv.extend([0x81, len as u8]),
+        len => tlv.extend([0x82, (len >> 8) as u8, len as u8]),
+    }
+    tlv.extend_from_slice(value);
+    tlv
+}
+
+/// Reads DER elements one after another, it supports only definite lengths up to 64 KiB.
+struct DerReader<'d> {
+    data: &'d [u8],
+}
+
+impl<'d> DerReader<'d> {
+    fn new(data: &'d [u8]) -> Self {
+        Self { data }
+    }
+
+    fn read(&mut self, tag: u8) -> Result<&'d [u8], PemError> {
+        self.try_read(tag)?
+            .ok_or(PemError::MalformedKey("unexpected DER element"))
+    }
+
+    /// Returns the value of the next element if it has the given tag, consumes nothing otherwise.
+    fn try_read(&mut self, tag: u8) -> Result<Option<&'d [u8]>, PemError> {
+        const TRUNCATED: PemError = PemError::MalformedKey("DER element is truncated");
+
+        match self.data.first() {
+            Some(&actual_tag) if actual_tag == tag => {}
+            _ => return Ok(None),
+        }
+
+        let (len, header_len) = match *self.data.get(1).ok_or(TRUNCATED)? {
+            len @ 0..=0x7f => (len as usize, 2),
+            0x81 => (*self.data.get(2).ok_or(TRUNCATED)? as usize, 3),
+            0x82 => {
+                let len = self.data.get(2..4).ok_or(TRUNCATED)?;
+                (u16::from_be_bytes([len[0], len[1]]) as usize, 4)
+            }
+            _ => return Err(PemError::MalformedKey("DER length isn't supported")),
+        };
+
+        let value = self
+            .data
+            .get(header_len..header_len + len)
+            .ok_or(TRUNCATED)?;
+        self.data = &self.data[header_len + len..];
+        Ok(Some(value))
+    }
+}
+
+/// Reads values of the SSH wire format.
+struct SshReader<'d> {
+    data: &'d [u8],
+}
+
+impl<'d> SshReader<'d> {
+    fn new(data: &'d [u8]) -> Self {
+        Self { data }
+    }
+
+    fn read_u32(&mut self) -> Result<u32, PemError> {
+        let bytes = self.take(4)?;
+        Ok(u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn read_string(&mut self) -> Result<&'d [u8], PemError> {
+        let len = self.read_u32()? as usize;
+        self.take(len)
+    }
+
+    fn take(&mut self, len: usize) -> Result<&'d [u8], PemError> {
+        if self.data.len() < len {
+            return Err(PemError::MalformedKey("OpenSSH key is truncated"));
+        }
+
+        let (value, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // the fixtures are generated with
+    //   openssl genpkey -algorithm ed25519 -out ed25519_pkcs8.pem
+    //   openssl genpkey -algorithm ed25519 -aes256 -pass pass:passphrase -out ed25519_encrypted_pkcs8.pem
+    //   openssl genpkey -algorithm EC -pkeyopt ec_paramgen_curve:secp256k1 -out secp256k1_pkcs8.pem
+    //   openssl pkey -in secp256k1_pkcs8.pem -traditional -out secp256k1_sec1.pem
+    //   openssl genpkey -algorithm EC -pkeyopt ec_paramgen_curve:prime256v1 -out p256_pkcs8.pem
+    //   ssh-keygen -t ed25519 -N "" -C "" -f ed25519_openssh.pem
+    //   ssh-keygen -t ed25519 -N passphrase -C "" -f ed25519_encrypted_openssh.pem
+    //   ssh-keygen -t ecdsa -N "" -C "" -f ecdsa_openssh.pem
+    const ED25519_PKCS8: &str = include_str!("../fixtures/ed25519_pkcs8.pem");
+    const ED25519_ENCRYPTED_PKCS8: &str = include_str!("../fixtures/ed25519_encrypted_pkcs8.pem");
+    const SECP256K1_PKCS8: &str = include_str!("../fixtures/secp256k1_pkcs8.pem");
+    const SECP256K1_SEC1: &str = include_str!("../fixtures/secp256k1_sec1.pem");
+    const P256_PKCS8: &str = include_str!("../fixtures/p256_pkcs8.pem");
+    const ED25519_OPENSSH: &str = include_str!("../fixtures/ed25519_openssh.pem");
+    const ED25519_ENCRYPTED_OPENSSH: &str =
+        include_str!("../fixtures/ed25519_encrypted_openssh.pem");
+    const ECDSA_OPENSSH: &str = include_str!("../fixtures/ecdsa_openssh.pem");
+
+    // printed by `openssl pkey -text -noout` and read from public keys `ssh-keygen` produced
+    const ED25519_PKCS8_SECRET: &str =
+        "d230bfef6a436903ed432121dea1269855176dc0bb344195206b72fafd58f7f4";
+    const ED25519_PKCS8_PUBLIC: &str =
+        "49633d32f4465c835995ea8e90d95eeb2b38b6acf8fc4ca6905204812e818a1b";
+    const SECP256K1_SECRET: &str =
+        "3b3b5e474998d1eae0ea6d872ec8514ddaa723691a459e13aa1edf9683ff09e2";
+    const ED25519_OPENSSH_SECRET: &str =
+        "9cb5a622730b2b01fd79cbc27c7563e006e1e91a19e304362594e673926262fa";
+    const ED25519_OPENSSH_PUBLIC: &str =
+        "c4c2e15ed2a36de81160afd08dd1eb1c16cff27582af8020edf52d07fb123005";
+
+    fn from_hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|position| u8::from_str_radix(&hex[position..position + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn public_key(secret: Vec<u8>, key_format: KeyFormat) -> Vec<u8> {
+        fluence_keypair::KeyPair::from_secret_key(secret, key_format)
+            .unwrap()
+            .public()
+            .to_vec()
+    }
+
+    #[test]
+    fn openssl_ed25519_key_is_decoded() {
+        let (secret, key_format) = decode(ED25519_PKCS8).unwrap();
+
+        assert_eq!(key_format, KeyFormat::Ed25519);
+        assert_eq!(secret, from_hex(ED25519_PKCS8_SECRET));
+        assert_eq!(
+            public_key(secret, key_format),
+            from_hex(ED25519_PKCS8_PUBLIC)
+        );
+    }
+
+    #[test]
+    fn openssl_secp256k1_keys_are_decoded() {
+        let expected = (from_hex(SECP256K1_SECRET), KeyFormat::Secp256k1);
+
+        assert_eq!(decode(SECP256K1_PKCS8).unwrap(), expected);
+        assert_eq!(decode(SECP256K1_SEC1).unwrap(), expected);
+    }
+
+    #[test]
+    fn ssh_keygen_ed25519_key_is_decoded() {
+        let (secret, key_format) = decode(ED25519_OPENSSH).unwrap();
+
+        assert_eq!(key_format, KeyFormat::Ed25519);
+        assert_eq!(secret, from_hex(ED25519_OPENSSH_SECRET));
+        assert_eq!(
+            public_key(secret, key_format),
+            from_hex(ED25519_OPENSSH_PUBLIC)
+        );
+    }
+
+    #[test]
+    fn ed25519_key_is_encoded_as_openssl_does() {
+        let encoded = encode(&from_hex(ED25519_PKCS8_SECRET), KeyFormat::Ed25519);
+
+        assert_eq!(encoded, ED25519_PKCS8);
+    }
+
+    #[test]
+    fn encoded_keys_round_trip() {
+        for pem in [
+            ED25519_PKCS8,
+            SECP256K1_PKCS8,
+            SECP256K1_SEC1,
+            ED25519_OPENSSH,
+        ] {
+            let (secret, key_format) = decode(pem).unwrap();
+            let encoded = encode(&secret, key_format);
+
+            assert_eq!(decode(&encoded).unwrap(), (secret, key_format));
+        }
+    }
+
+    #[test]
+    fn encrypted_keys_are_rejected() {
+        assert_eq!(decode(ED25519_ENCRYPTED_PKCS8), Err(PemError::EncryptedKey));
+        assert_eq!(
+            decode(ED25519_ENCRYPTED_OPENSSH),
+            Err(PemError::EncryptedKey)
+        );
+    }
+
+    #[test]
+    fn unsupported_algorithms_are_rejected() {
+        assert_eq!(decode(P256_PKCS8), Err(PemError::UnsupportedAlgorithm));
+        assert_eq!(decode(ECDSA_OPENSSH), Err(PemError::UnsupportedAlgorithm));
+    }
+
+    #[test]
+    fn truncated_armor_is_rejected() {
+        let truncated = &ED25519_PKCS8[..ED25519_PKCS8.len() / 2];
+
+        assert!(matches!(
+            decode(truncated),
+            Err(PemError::MalformedArmor(_))
+        ));
+    }
+
+    #[test]
+    fn truncated_keys_are_rejected() {
+        for pem in [
+            ED25519_PKCS8,
+            SECP256K1_PKCS8,
+            SECP256K1_SEC1,
+            ED25519_OPENSSH,
+        ] {
+            let (label, der) = decode_armor(pem).unwrap();
+
+            for len in 0..der.len() {
+                let truncated = encode_armor(label, &der[..len]);
+                assert!(
+                    matches!(decode(&truncated), Err(PemError::MalformedKey(_))),
+                    "{label} key truncated to {len} bytes is accepted"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,71 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::KeyFormat;
+
+/// Decides which peers' signatures are checked when incoming data is verified.
+///
+/// A peer signs all the CIDs it has produced with one signature, so a policy can skip
+/// verification only for the whole set of peer's results.
+pub trait SignaturePolicy {
+    /// Returns true if the signature of `peer_id` should be verified, `call_id` is the position
+    /// of the first trace state signed by the peer.
+    fn should_verify(&self, peer_id: &str, call_id: u64) -> bool;
+}
+
+/// Verifies signatures of all peers, it's the default policy.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct VerifyAll;
+
+impl SignaturePolicy for VerifyAll {
+    fn should_verify(&self, _peer_id: &str, _call_id: u64) -> bool {
+        true
+    }
+}
+
+/// Skips verification of all signatures, it's intended for trusted networks and testing.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct VerifyNone;
+
+impl SignaturePolicy for VerifyNone {
+    fn should_verify(&self, _peer_id: &str, _call_id: u64) -> bool {
+        false
+    }
+}
+
+/// Decides which key formats are accepted for public keys in a signature store and for the
+/// peer's own keypair.
+///
+/// A public key is stored in its encoded form that includes the key format, so each signature
+/// is verified with the algorithm of its own key regardless of the other keys in the store.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyFormatPolicy {
+    /// Only Ed25519 keys are accepted, it's the default policy.
+    #[default]
+    Ed25519Only,
+    /// Keys of any format supported by `fluence_keypair` are accepted, it's intended for
+    /// particles routed across networks with different key formats.
+    Any,
+}
+
+impl KeyFormatPolicy {
+    pub fn is_allowed(&self, key_format: KeyFormat) -> bool {
+        match self {
+            Self::Ed25519Only => key_format == KeyFormat::Ed25519,
+            Self::Any => true,
+        }
+    }
+}
@@ -14,6 +14,7 @@
  * limitations under the License.
  */
 
+use crate::KeyError;
 use crate::PublicKey;
 use crate::Signature;
 
@@ -22,6 +23,9 @@ use serde::{Deserialize, Serialize};
 use std::borrow::Borrow;
 use std::collections::HashMap;
 use std::hash::Hash;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
 
 /// A dictionary-like structure that stores peer public keys and their particle data signatures.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -62,6 +66,90 @@ impl<Key: Hash + Eq, Sign> SignatureStore<Key, Sign> {
     pub fn iter(&self) -> <&HashMap<Key, Sign> as IntoIterator>::IntoIter {
         self.0.iter()
     }
+
+    /// Combines stores of partial executions, a signature from `a` is kept if both stores
+    /// have one for the same key.
+    pub fn merge(mut a: Self, b: Self) -> Self {
+        for (peer_pk, signature) in b.0 {
+            a.0.entry(peer_pk).or_insert(signature);
+        }
+        a
+    }
+}
+
+impl SignatureStore {
+    /// Exports the store in a form that can be stored and transmitted apart from
+    /// `InterpreterData`, entries are sorted by public key, so an export is deterministic.
+    pub fn export(&self) -> SignatureStoreSnapshot {
+        let mut signatures = self
+            .iter()
+            .map(|(public_key, signature)| SignatureEntry {
+                public_key: public_key.clone(),
+                signature: signature.clone(),
+            })
+            .collect::<Vec<_>>();
+        signatures.sort_by_cached_key(|entry| entry.public_key.to_string());
+
+        SignatureStoreSnapshot {
+            version: SignatureStoreSnapshot::VERSION,
+            signatures,
+        }
+    }
+
+    /// Restores a store from an export, checking that its keys and signatures can be decoded.
+    ///
+    /// Signatures aren't verified, since the signed data isn't a part of the snapshot.
+    pub fn import(snapshot: SignatureStoreSnapshot) -> Result<Self, ImportError> {
+        if snapshot.version != SignatureStoreSnapshot::VERSION {
+            return Err(ImportError::UnsupportedVersion(snapshot.version));
+        }
+
+        let mut store = Self::new();
+        for SignatureEntry {
+            public_key,
+            signature,
+        } in snapshot.signatures
+        {
+            let key_format =
+                public_key
+                    .key_format()
+                    .map_err(|error| ImportError::InvalidPublicKey {
+                        public_key: public_key.to_string(),
+                        error,
+                    })?;
+            if signature.key_format() != Some(key_format) {
+                return Err(ImportError::InvalidSignature(public_key.to_string()));
+            }
+
+            if store.get(&public_key).is_some() {
+                return Err(ImportError::DuplicatePublicKey(public_key.to_string()));
+            }
+            store.put(public_key, signature);
+        }
+
+        Ok(store)
+    }
+
+    /// Writes an export of the store to the file in the MessagePack format. The file is replaced
+    /// atomically, so it keeps the previous export if writing fails.
+    pub fn save_to_path(&self, path: &Path) -> io::Result<()> {
+        let bytes = rmp_serde::to_vec_named(&self.export())
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+        // the suffix is appended, so files that differ only by extension don't share a temporary file
+        let tmp_path = PathBuf::from(format!("{}.tmp", path.display()));
+        std::fs::write(&tmp_path, bytes)?;
+        std::fs::rename(tmp_path, path)
+    }
+
+    /// Reads a store written by `save_to_path`, the file is checked like an export in `import`.
+    pub fn load_from_path(path: &Path) -> io::Result<Self> {
+        let bytes = std::fs::read(path)?;
+        let snapshot = rmp_serde::from_slice(&bytes)
+            .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))?;
+
+        Self::import(snapshot).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+    }
 }
 
 impl<Key: Hash + Eq, Sign> Default for SignatureStore<Key, Sign> {
@@ -69,3 +157,236 @@ impl<Key: Hash + Eq, Sign> Default for SignatureStore<Key, Sign> {
         Self(Default::default())
     }
 }
+
+/// A serializable export of `SignatureStore`.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignatureStoreSnapshot {
+    pub version: u32,
+    pub signatures: Vec<SignatureEntry>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignatureEntry {
+    pub public_key: PublicKey,
+    pub signature: Signature,
+}
+
+impl SignatureStoreSnapshot {
+    /// Version of snapshots produced by `SignatureStore::export`.
+    pub const VERSION: u32 = 1;
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ImportError {
+    #[error("unsupported signature store snapshot version {0}")]
+    UnsupportedVersion(u32),
+    #[error("invalid public key {public_key}: {error}")]
+    InvalidPublicKey { public_key: String, error: KeyError },
+    #[error("signature of {0} can't be decoded or doesn't match the key format")]
+    InvalidSignature(String),
+    #[error("public key {0} occurs more than once")]
+    DuplicatePublicKey(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::KeyFormat;
+    use crate::KeyPair;
+
+    fn keypair(seed: u8) -> KeyPair {
+        KeyPair::from_secret_key(vec![seed; 32], KeyFormat::Ed25519).unwrap()
+    }
+
+    fn store_of(seeds: &[u8], message: &[u8]) -> SignatureStore {
+        let mut store = SignatureStore::new();
+        for &seed in seeds {
+            let keypair = keypair(seed);
+            store.put(keypair.public(), keypair.sign(message).unwrap());
+        }
+        store
+    }
+
+    #[test]
+    fn merge_combines_stores() {
+        let a = store_of(&[1, 2], b"data");
+        let b = store_of(&[3], b"data");
+
+        let merged = SignatureStore::merge(a, b);
+
+        assert_eq!(merged.export(), store_of(&[1, 2, 3], b"data").export());
+    }
+
+    #[test]
+    fn merge_keeps_first_signature_on_conflict() {
+        let public_key = keypair(1).public();
+        let first_signature = keypair(1).sign(b"first").unwrap();
+        let second_signature = keypair(1).sign(b"second").unwrap();
+        let mut a = store_of(&[2], b"data");
+        a.put(public_key.clone(), first_signature.clone());
+        let mut b = store_of(&[3], b"data");
+        b.put(public_key.clone(), second_signature);
+
+        let merged = SignatureStore::merge(a, b);
+
+        assert_eq!(merged.len(), 3);
+        assert_eq!(merged.get(&public_key), Some(&first_signature));
+    }
+
+    #[test]
+    fn exported_store_is_imported() {
+        let store = store_of(&[3, 1, 2], b"data");
+
+        let snapshot = store.export();
+        let imported = SignatureStore::import(snapshot.clone()).unwrap();
+
+        assert_eq!(snapshot.version, SignatureStoreSnapshot::VERSION);
+        assert_eq!(imported.len(), store.len());
+        for (public_key, signature) in store.iter() {
+            assert_eq!(imported.get(public_key), Some(signature));
+        }
+        assert_eq!(imported.export(), snapshot);
+    }
+
+    #[test]
+    fn export_is_sorted_by_public_key() {
+        let snapshot = store_of(&[3, 1, 2], b"data").export();
+
+        let public_keys = snapshot
+            .signatures
+            .iter()
+            .map(|entry| entry.public_key.to_string())
+            .collect::<Vec<_>>();
+        let mut sorted_public_keys = public_keys.clone();
+        sorted_public_keys.sort();
+        assert_eq!(public_keys, sorted_public_keys);
+    }
+
+    #[test]
+    fn import_rejects_unsupported_version() {
+        let mut snapshot = store_of(&[1], b"data").export();
+        snapshot.version = SignatureStoreSnapshot::VERSION + 1;
+
+        let result = SignatureStore::import(snapshot);
+
+        assert!(matches!(
+            result,
+            Err(ImportError::UnsupportedVersion(version)) if version == SignatureStoreSnapshot::VERSION + 1
+        ));
+    }
+
+    #[test]
+    fn import_rejects_invalid_public_key() {
+        let mut snapshot = store_of(&[1], b"data").export();
+        snapshot.signatures[0].public_key = PublicKey(vec![255, 1, 2].into());
+
+        let result = SignatureStore::import(snapshot);
+
+        assert!(matches!(result, Err(ImportError::InvalidPublicKey { .. })));
+    }
+
+    #[test]
+    fn import_rejects_invalid_signature() {
+        let mut snapshot = store_of(&[1], b"data").export();
+        snapshot.signatures[0].signature = Signature(vec![].into());
+
+        let result = SignatureStore::import(snapshot);
+
+        let public_key = keypair(1).public().to_string();
+        assert!(matches!(
+            result,
+            Err(ImportError::InvalidSignature(key)) if key == public_key
+        ));
+    }
+
+    #[test]
+    fn import_rejects_signature_of_another_key_format() {
+        let secp256k1_keypair =
+            fluence_keypair::KeyPair::from_secret_key(vec![1; 32], KeyFormat::Secp256k1).unwrap();
+        let mut snapshot = store_of(&[1], b"data").export();
+        snapshot.signatures[0].signature = secp256k1_keypair.sign(b"data").unwrap().into();
+
+        let result = SignatureStore::import(snapshot);
+
+        assert!(matches!(result, Err(ImportError::InvalidSignature(_))));
+    }
+
+    #[test]
+    fn import_rejects_duplicate_public_key() {
+        let mut snapshot = store_of(&[1], b"data").export();
+        let duplicate = SignatureEntry {
+            public_key: snapshot.signatures[0].public_key.clone(),
+            signature: keypair(1).sign(b"other data").unwrap(),
+        };
+        snapshot.signatures.push(duplicate);
+
+        let result = SignatureStore::import(snapshot);
+
+        let public_key = keypair(1).public().to_string();
+        assert!(matches!(
+            result,
+            Err(ImportError::DuplicatePublicKey(key)) if key == public_key
+        ));
+    }
+
+    // a file in a directory of its own, so tests running concurrently don't share files
+    fn test_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("signature_store_{name}_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn saved_store_is_loaded() {
+        let dir = test_dir("saved_store_is_loaded");
+        let path = dir.join("signatures.msgpack");
+        let store = store_of(&[1, 2, 3], b"data");
+
+        store.save_to_path(&path).unwrap();
+        let loaded = SignatureStore::load_from_path(&path).unwrap();
+
+        assert_eq!(loaded.export(), store.export());
+        assert!(!dir.join("signatures.msgpack.tmp").exists());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn save_doesnt_touch_file_with_tmp_extension() {
+        let dir = test_dir("save_doesnt_touch_file_with_tmp_extension");
+        let path = dir.join("signatures.msgpack");
+        let unrelated_path = dir.join("signatures.tmp");
+        std::fs::write(&unrelated_path, b"unrelated").unwrap();
+
+        store_of(&[1], b"data").save_to_path(&path).unwrap();
+
+        assert_eq!(std::fs::read(&unrelated_path).unwrap(), b"unrelated");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn corrupted_file_isnt_loaded() {
+        let dir = test_dir("corrupted_file_isnt_loaded");
+        let path = dir.join("signatures.msgpack");
+        store_of(&[1, 2], b"data").save_to_path(&path).unwrap();
+
+        let mut bytes = std::fs::read(&path).unwrap();
+        bytes.truncate(bytes.len() / 2);
+        std::fs::write(&path, bytes).unwrap();
+        let error = SignatureStore::load_from_path(&path).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn missing_file_isnt_loaded() {
+        let dir = test_dir("missing_file_isnt_loaded");
+
+        let error = SignatureStore::load_from_path(&dir.join("signatures.msgpack")).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
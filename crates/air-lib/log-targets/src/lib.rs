@@ -44,8 +44,11 @@ pub const EXECUTED_STATE_CHANGING: &str = "executed_state_changing";
 /// Print log if call is postponed due the join behaviour.
 pub const JOIN_BEHAVIOUR: &str = "join_behaviour";
 
+/// Print out events of the logging execution observer.
+pub const OBSERVER: &str = "observer";
+
 /// This map should be used by rust-sdk logger that allows print only necessary targets by id.
-pub const TARGET_MAP: [(&str, i32); 10] = [
+pub const TARGET_MAP: [(&str, i32); 11] = [
     (INSTRUCTION, 1 << 1),
     (DATA_CACHE, 1 << 2),
     (NEXT_PEER_PKS, 1 << 3),
@@ -56,4 +59,5 @@ pub const TARGET_MAP: [(&str, i32); 10] = [
     (RUN_PARAMS, 1 << 8),
     (EXECUTED_STATE_CHANGING, 1 << 9),
     (JOIN_BEHAVIOUR, 1 << 10),
+    (OBSERVER, 1 << 11),
 ];
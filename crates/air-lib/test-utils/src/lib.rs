@@ -82,7 +82,18 @@ pub fn trace_from_result(result: &RawAVMOutcome) -> ExecutionTrace {
     data.trace
 }
 
+/// Returns interpreter data of the result with the trace expanded,
+/// so that states could be compared by their positions.
 pub fn data_from_result(result: &RawAVMOutcome) -> InterpreterData {
+    let mut data = compacted_data_from_result(result);
+    data.trace
+        .expand()
+        .expect("result trace should be expandable");
+    data
+}
+
+/// Returns interpreter data of the result as it was produced by the interpreter.
+pub fn compacted_data_from_result(result: &RawAVMOutcome) -> InterpreterData {
     let env = InterpreterDataEnvelope::try_from_slice(&result.data)
         .expect("default serializer shouldn't fail");
     InterpreterData::try_from_slice(&env.inner_data).expect("default serializer shouldn't fail")
@@ -101,7 +112,7 @@ pub fn raw_data_from_trace(
         trace.into(),
         cid_state.into(),
         <_>::default(),
-        0,
+        0.into(),
         semver::Version::new(1, 1, 1),
     );
     data.serialize().expect("default serializer shouldn't fail")
@@ -121,7 +132,7 @@ pub fn raw_data_from_trace_with_canon(
             service_result_store: cid_state.service_result_agg_tracker.into(),
         },
         <_>::default(),
-        0,
+        0.into(),
         semver::Version::new(1, 1, 1),
     );
     data.serialize().expect("default serializer shouldn't fail")
@@ -148,7 +159,10 @@ pub fn print_trace(result: &RawAVMOutcome, trace_name: &str) {
         match state {
             ExecutedState::Call(call_result) => print_call_value(&data, call_result),
             ExecutedState::Canon(canon_result) => print_canon_values(&data, canon_result),
-            ExecutedState::Par(_) | ExecutedState::Fold(_) | ExecutedState::Ap(_) => {}
+            ExecutedState::Par(_)
+            | ExecutedState::Fold(_)
+            | ExecutedState::Ap(_)
+            | ExecutedState::Repeated(_) => {}
         }
         println!();
     }
@@ -16,9 +16,8 @@
 
 use crate::prelude::TestInitParameters;
 use crate::test_runner::AirRunner;
-use air_interpreter_interface::CallResultsRepr;
+use air_interpreter_interface::serialize_call_results;
 use air_interpreter_interface::RunParameters;
-use air_interpreter_sede::ToSerialized;
 use avm_server::avm_runner::*;
 use avm_server::into_raw_result;
 use avm_server::AquaVMRuntimeLimits;
@@ -50,10 +49,6 @@ impl AirRunner for NativeAirRunner {
         keypair: &KeyPair,
         particle_id: String,
     ) -> Result<RawAVMOutcome, Box<dyn std::error::Error>> {
-        // some inner parts transformations
-        let raw_call_results = into_raw_result(call_results);
-        let raw_call_results = CallResultsRepr.serialize(&raw_call_results).unwrap();
-
         let current_peer_id =
             override_current_peer_id.unwrap_or_else(|| self.current_peer_id.clone());
 
@@ -65,25 +60,48 @@ impl AirRunner for NativeAirRunner {
             particle_size_limit,
             call_result_size_limit,
             hard_limit_enabled,
+            fuel_limit,
+            max_execution_time,
+            air_cache_capacity,
+            signature_policy,
+            key_format_policy,
+            max_fold_depth,
+            max_instruction_count,
+            call_results_format,
         } = self.test_init_parameters.into();
 
+        // some inner parts transformations
+        let raw_call_results = into_raw_result(call_results);
+        let raw_call_results = serialize_call_results(&raw_call_results, call_results_format)
+            .unwrap()
+            .into();
+
         let outcome = air::execute_air(
             air.into(),
             prev_data.into(),
             data.into(),
-            RunParameters {
-                init_peer_id: init_peer_id.into(),
-                current_peer_id,
-                timestamp,
-                ttl,
-                key_format,
-                secret_key_bytes,
-                particle_id,
-                air_size_limit,
-                particle_size_limit,
-                call_result_size_limit,
-                hard_limit_enabled,
-            },
+            RunParameters::builder()
+                .init_peer_id(init_peer_id)
+                .current_peer_id(current_peer_id)
+                .timestamp(timestamp)
+                .ttl(ttl)
+                .key_format(key_format)
+                .secret_key_bytes(secret_key_bytes)
+                .particle_id(particle_id)
+                .air_size_limit(air_size_limit)
+                .particle_size_limit(particle_size_limit)
+                .call_result_size_limit(call_result_size_limit)
+                .hard_limit_enabled(hard_limit_enabled)
+                .fuel_limit(fuel_limit)
+                .max_execution_time(max_execution_time)
+                .air_cache_capacity(air_cache_capacity)
+                .signature_policy(signature_policy)
+                .key_format_policy(key_format_policy)
+                .max_fold_depth(max_fold_depth)
+                .max_instruction_count(max_instruction_count)
+                .call_results_format(call_results_format)
+                .build()
+                .expect("all required run parameters are set"),
             raw_call_results,
         );
         let outcome = RawAVMOutcome::from_interpreter_outcome(outcome)?;
@@ -29,10 +29,14 @@ use super::CallServiceClosure;
 use avm_server::avm_runner::*;
 use avm_server::AVMRuntimeLimits;
 use avm_server::AquaVMRuntimeLimits;
+use avm_server::CallResultsFormat;
+use avm_server::KeyFormatPolicyKind;
+use avm_server::SignaturePolicyKind;
 use fluence_keypair::KeyPair;
 
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::time::Duration;
 
 pub trait AirRunner {
     fn new(current_call_id: impl Into<String>, test_init_parameters: TestInitParameters) -> Self;
@@ -77,6 +81,13 @@ pub struct TestInitParameters {
     pub particle_size_limit: Option<u64>,
     pub call_result_size_limit: Option<u64>,
     pub hard_limit_enabled: bool,
+    pub fuel_limit: Option<u64>,
+    pub max_execution_time: Option<Duration>,
+    pub signature_policy: SignaturePolicyKind,
+    pub key_format_policy: KeyFormatPolicyKind,
+    pub max_fold_depth: Option<u32>,
+    pub max_instruction_count: Option<u64>,
+    pub call_results_format: CallResultsFormat,
 }
 
 impl<R: AirRunner> TestRunner<R> {
@@ -261,6 +272,13 @@ impl TestInitParameters {
             particle_size_limit: Some(particle_size_limit),
             call_result_size_limit: Some(call_result_size_limit),
             hard_limit_enabled,
+            fuel_limit: None,
+            max_execution_time: None,
+            signature_policy: <_>::default(),
+            key_format_policy: <_>::default(),
+            max_fold_depth: None,
+            max_instruction_count: None,
+            call_results_format: <_>::default(),
         }
     }
 
@@ -270,6 +288,13 @@ impl TestInitParameters {
             particle_size_limit: Some(u64::MAX),
             call_result_size_limit: Some(u64::MAX),
             hard_limit_enabled: false,
+            fuel_limit: None,
+            max_execution_time: None,
+            signature_policy: <_>::default(),
+            key_format_policy: <_>::default(),
+            max_fold_depth: None,
+            max_instruction_count: None,
+            call_results_format: <_>::default(),
         }
     }
 }
@@ -281,6 +306,8 @@ impl From<TestInitParameters> for AVMRuntimeLimits {
             value.particle_size_limit,
             value.call_result_size_limit,
             value.hard_limit_enabled,
+            value.fuel_limit,
+            value.max_execution_time,
         )
     }
 }
@@ -294,12 +321,20 @@ impl From<TestInitParameters> for AquaVMRuntimeLimits {
         let particle_size_limit: u64 = value.particle_size_limit.unwrap_or(MAX_PARTICLE_SIZE);
         let call_result_size_limit = value.call_result_size_limit.unwrap_or(MAX_CALL_RESULT_SIZE);
 
-        AquaVMRuntimeLimits::new(
+        let mut limits = AquaVMRuntimeLimits::new(
             air_size_limit,
             particle_size_limit,
             call_result_size_limit,
             value.hard_limit_enabled,
-        )
+            value.fuel_limit,
+            value.max_execution_time,
+        );
+        limits.signature_policy = value.signature_policy;
+        limits.key_format_policy = value.key_format_policy;
+        limits.max_fold_depth = value.max_fold_depth;
+        limits.max_instruction_count = value.max_instruction_count;
+        limits.call_results_format = value.call_results_format;
+        limits
     }
 }
 
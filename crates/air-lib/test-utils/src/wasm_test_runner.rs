@@ -107,6 +107,17 @@ pub struct ReleaseWasmAirRunner {
     runner: AVMRunner,
 }
 
+impl ReleaseWasmAirRunner {
+    /// Executes a batch of requests in a single call of the interpreter.
+    pub fn call_multi(
+        &mut self,
+        requests: &[avm_server::RunnerRequest],
+        keypair: &KeyPair,
+    ) -> Vec<avm_server::RunnerResult<avm_server::BatchedOutcome>> {
+        self.runner.call_multi(requests, keypair)
+    }
+}
+
 impl AirRunner for ReleaseWasmAirRunner {
     fn new(current_peer_id: impl Into<String>, test_init_parameters: TestInitParameters) -> Self {
         let logging_mask = i32::MAX;
@@ -54,6 +54,10 @@ impl TraceSlider {
         }
     }
 
+    pub(crate) fn trace(&self) -> &ExecutionTrace {
+        &self.trace
+    }
+
     /// Returns the next state if current interval length hasn't been reached
     /// and None otherwise.
     #[allow(clippy::suspicious_operation_groupings)]
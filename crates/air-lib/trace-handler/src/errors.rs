@@ -18,6 +18,7 @@ use super::data_keeper::KeeperError;
 use super::merger::MergeError;
 use super::state_automata::StateFSMError;
 
+use air_interpreter_data::DataDeserializationError;
 use air_interpreter_data::ExecutedState;
 use air_interpreter_data::TracePos;
 use thiserror::Error as ThisError;
@@ -66,3 +67,26 @@ pub enum IntConversionError {
     #[error("trying to cast integer types, there is an error {0:?}")]
     TryIntoTracePosError(TryFromIntError),
 }
+
+/// Errors arose out of reading a trace handler state from JSON.
+#[derive(ThisError, Debug)]
+pub enum JsonError {
+    #[error("trace JSON format version {0} isn't supported")]
+    UnsupportedVersion(serde_json::Value),
+
+    #[error("trace JSON doesn't contain field '{0}'")]
+    MissingField(&'static str),
+
+    #[error("state {position} of {trace} is malformed: {state}")]
+    MalformedState {
+        trace: &'static str,
+        position: usize,
+        state: serde_json::Value,
+    },
+
+    #[error("compacted {trace} can't be expanded: {error}")]
+    NotExpandable {
+        trace: &'static str,
+        error: DataDeserializationError,
+    },
+}
@@ -21,17 +21,57 @@ use std::convert::TryInto;
 
 #[derive(Debug, Default)]
 pub struct TraceHandler {
-    data_keeper: DataKeeper,
+    pub(crate) data_keeper: DataKeeper,
     fsm_keeper: FSMKeeper,
+    #[cfg(feature = "flamegraph")]
+    timings: crate::instruction_timings::InstructionTimings,
 }
 
 impl TraceHandler {
+    /// Creates a handler for the supplied traces. Compacted traces should be expanded
+    /// beforehand with `ExecutionTrace::expand`, which checks them against the expansion limit.
     pub fn from_trace(prev_trace: ExecutionTrace, current_trace: ExecutionTrace) -> Self {
         let data_keeper = DataKeeper::from_trace(prev_trace, current_trace);
 
         Self {
             data_keeper,
-            fsm_keeper: <_>::default(),
+            ..<_>::default()
+        }
+    }
+
+    /// Merges result traces of two handlers that executed different branches of the same
+    /// `par` instructions. The returned handler holds only the merged result trace.
+    pub fn merge(left: TraceHandler, right: TraceHandler) -> Result<TraceHandler, MergeError> {
+        let result_trace = merge_traces(left.into_result_trace(), right.into_result_trace())?;
+        Ok(Self::from_result_trace(result_trace))
+    }
+
+    /// Splits the result trace at the `par` state placed at `par_index` into two handlers
+    /// holding only the states of its left and right branches. Returns `None` if there is
+    /// no `par` state at this position or its branches don't fit into the trace.
+    ///
+    /// States are copied as is, so trace positions stored in fold states aren't rebased.
+    pub fn split_at_par(&self, par_index: usize) -> Option<(TraceHandler, TraceHandler)> {
+        let trace: &[ExecutedState] = &self.data_keeper.result_trace;
+        let ExecutedState::Par(par_result) = trace.get(par_index)? else {
+            return None;
+        };
+        let ((left, right), _) = split_par(trace, par_index, par_result).ok()?;
+
+        let left = Self::from_result_trace(left.to_vec().into());
+        let right = Self::from_result_trace(right.to_vec().into());
+        Some((left, right))
+    }
+
+    fn from_result_trace(result_trace: ExecutionTrace) -> Self {
+        let data_keeper = DataKeeper {
+            result_trace,
+            ..<_>::default()
+        };
+
+        Self {
+            data_keeper,
+            ..<_>::default()
         }
     }
 
@@ -45,6 +85,12 @@ impl TraceHandler {
             .map_err(IntConversionError::TryIntoTracePosError)
     }
 
+    /// Applies run-length encoding to the result trace to reduce its serialized size.
+    /// It should be called only when execution is over, since it shifts trace positions.
+    pub fn compact(&mut self) {
+        self.data_keeper.result_trace.compact();
+    }
+
     pub fn into_result_trace(self) -> ExecutionTrace {
         self.data_keeper.result_trace
     }
@@ -53,6 +99,16 @@ impl TraceHandler {
         &self.data_keeper.result_trace
     }
 
+    /// Returns the Merkle root of the result trace, the same root is stored along with data.
+    pub fn compute_merkle_root(&self) -> MerkleHash {
+        self.data_keeper.result_trace.merkle_root()
+    }
+
+    /// Returns a proof that a state of the result trace belongs to a trace with its root.
+    pub fn inclusion_proof(&self, entry_index: usize) -> Option<MerkleProof> {
+        self.data_keeper.result_trace.inclusion_proof(entry_index)
+    }
+
     pub fn subgraph_sizes(&self) -> (TraceLen, TraceLen) {
         let prev_len = self.data_keeper.prev_slider().subtrace_len();
         let current_len = self.data_keeper.current_slider().subtrace_len();
@@ -89,6 +145,27 @@ impl TraceHandler {
     }
 }
 
+#[cfg(feature = "flamegraph")]
+impl TraceHandler {
+    /// Should be called before an instruction is executed,
+    /// it starts measuring wall clock time of the instruction.
+    pub fn enter_instruction(&mut self, instruction: String) {
+        self.timings.enter(instruction);
+    }
+
+    /// Should be called after an instruction is executed, even if it failed.
+    pub fn exit_instruction(&mut self) {
+        self.timings.exit();
+    }
+
+    /// Renders time spent in executed instructions as an SVG flamegraph, where frames are nested
+    /// the same way instructions were executed one inside another, and a width of a frame is
+    /// proportional to time spent in it. It's empty if no instruction was executed.
+    pub fn to_flamegraph_svg(&self) -> String {
+        self.timings.to_flamegraph_svg()
+    }
+}
+
 impl TraceHandler {
     /// Should be called at the beginning of a call execution.
     pub fn meet_call_start(&mut self) -> TraceHandlerResult<MergerCallResult> {
@@ -196,3 +273,56 @@ impl TraceHandler {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::TraceHandler;
+    use air_interpreter_cid::CID;
+    use air_interpreter_data::CallResult;
+    use air_interpreter_data::ExecutedState;
+    use air_interpreter_data::ExecutionTrace;
+
+    fn executed(cid: &str) -> ExecutedState {
+        ExecutedState::Call(CallResult::executed_scalar(CID::new(cid)))
+    }
+
+    fn handler(result_trace: Vec<ExecutedState>) -> TraceHandler {
+        TraceHandler::from_result_trace(result_trace.into())
+    }
+
+    #[test]
+    fn split_at_nested_par() {
+        let handler = handler(vec![
+            executed("init"),
+            ExecutedState::par(3, 1),
+            ExecutedState::par(1, 1),
+            executed("left_left"),
+            executed("left_right"),
+            executed("right"),
+            executed("after"),
+        ]);
+
+        let (left, right) = handler.split_at_par(1).expect("there is a par at this position");
+
+        let expected_left: ExecutionTrace =
+            vec![ExecutedState::par(1, 1), executed("left_left"), executed("left_right")].into();
+        let expected_right: ExecutionTrace = vec![executed("right")].into();
+        assert_eq!(left.into_result_trace(), expected_left);
+        assert_eq!(right.into_result_trace(), expected_right);
+    }
+
+    #[test]
+    fn split_at_not_par() {
+        let handler = handler(vec![executed("init"), ExecutedState::par(1, 0), executed("left")]);
+
+        assert!(handler.split_at_par(0).is_none());
+        assert!(handler.split_at_par(3).is_none());
+    }
+
+    #[test]
+    fn split_at_par_exceeding_trace() {
+        let handler = handler(vec![ExecutedState::par(1, 2), executed("left")]);
+
+        assert!(handler.split_at_par(0).is_none());
+    }
+}
@@ -0,0 +1,128 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+use std::time::Instant;
+
+/// Wall clock time spent in instructions grouped by stacks of instructions they were executed in.
+#[derive(Debug, Default)]
+pub(crate) struct InstructionTimings {
+    /// Instructions being executed, the innermost one is the last.
+    stack: Vec<Frame>,
+
+    /// Time spent in instructions excluding time of their subinstructions,
+    /// keyed by stacks of instruction names joined with `;`.
+    self_times: BTreeMap<String, Duration>,
+}
+
+#[derive(Debug)]
+struct Frame {
+    name: String,
+    started: Instant,
+    children_time: Duration,
+}
+
+impl InstructionTimings {
+    pub(crate) fn enter(&mut self, instruction: String) {
+        // `;` separates frames in the folded stacks format
+        let name = instruction.replace(';', ",");
+        self.stack.push(Frame {
+            name,
+            started: Instant::now(),
+            children_time: Duration::ZERO,
+        });
+    }
+
+    pub(crate) fn exit(&mut self) {
+        let stack = self.folded_stack();
+        let Some(frame) = self.stack.pop() else {
+            return;
+        };
+
+        let elapsed = frame.started.elapsed();
+        *self.self_times.entry(stack).or_default() += elapsed.saturating_sub(frame.children_time);
+        if let Some(parent) = self.stack.last_mut() {
+            parent.children_time += elapsed;
+        }
+    }
+
+    /// Renders recorded times as an SVG flamegraph with a frame per instruction,
+    /// it's empty if no instruction was executed.
+    pub(crate) fn to_flamegraph_svg(&self) -> String {
+        use inferno::flamegraph;
+
+        let lines = self
+            .self_times
+            .iter()
+            .map(|(stack, time)| format!("{stack} {}", time.as_nanos()))
+            .collect::<Vec<_>>();
+
+        let mut options = flamegraph::Options::default();
+        options.title = "AIR execution".to_string();
+        options.count_name = "ns".to_string();
+
+        let mut svg = vec![];
+        // inferno fails only if there are no stacks to render
+        if flamegraph::from_lines(&mut options, lines.iter().map(String::as_str), &mut svg).is_err() {
+            return String::new();
+        }
+        String::from_utf8(svg).expect("inferno produces UTF-8 SVG")
+    }
+
+    fn folded_stack(&self) -> String {
+        self.stack
+            .iter()
+            .map(|frame| frame.name.as_str())
+            .collect::<Vec<_>>()
+            .join(";")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::InstructionTimings;
+
+    #[test]
+    fn nested_instructions_are_rendered_as_frames() {
+        let mut timings = InstructionTimings::default();
+        timings.enter("seq".to_string());
+        timings.enter("call \"peer\" (\"service\" \"function\") [] result".to_string());
+        timings.exit();
+        timings.enter("null".to_string());
+        timings.exit();
+        timings.exit();
+
+        let stacks = timings.self_times.keys().collect::<Vec<_>>();
+        assert_eq!(
+            stacks,
+            vec![
+                "seq",
+                "seq;call \"peer\" (\"service\" \"function\") [] result",
+                "seq;null"
+            ]
+        );
+
+        let svg = timings.to_flamegraph_svg();
+        assert!(svg.starts_with("<?xml"));
+        assert!(svg.contains("seq"));
+    }
+
+    #[test]
+    fn no_instructions_render_nothing() {
+        assert!(InstructionTimings::default().to_flamegraph_svg().is_empty());
+    }
+}
@@ -0,0 +1,356 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! A stable JSON representation of traces intended for external tooling.
+//!
+//! A trace handler state is an object
+//! `{"version": 1, "prev_trace": [..], "current_trace": [..], "result_trace": [..]}`,
+//! where each trace is an array of states, every state is an object with a single key:
+//!
+//! | state                | JSON                                                          |
+//! |----------------------|---------------------------------------------------------------|
+//! | par                  | `{"par": {"left": 1, "right": 2}}`                            |
+//! | call with scalar     | `{"call": {"scalar": "<cid>"}}`                               |
+//! | call with stream     | `{"call": {"stream": {"cid": "<cid>", "generation": 0}}}`     |
+//! | call with unused     | `{"call": {"unused": "<cid>"}}`                               |
+//! | call sent            | `{"call": {"sent_by": {"peer_id": "<id>", "call_id": null}}}` |
+//! | failed call          | `{"call": {"failed": "<cid>"}}`                               |
+//! | fold                 | `{"fold": [{"value_pos": 0, "subtraces": [{"begin": 1, "len": 2}]}]}` |
+//! | ap                   | `{"ap": {"generations": [0]}}`                                |
+//! | canon                | `{"canon": {"executed": "<cid>"}}`                            |
+//! | canon request        | `{"canon": {"sent_by": "<id>"}}`                              |
+//! | repeat of a state    | `{"repeated": 3}`                                             |
+//!
+//! The format is changed only together with `TRACE_JSON_FORMAT_VERSION`.
+
+use super::JsonError;
+use super::TraceHandler;
+
+use air_interpreter_cid::CID;
+use air_interpreter_data::*;
+use serde_json::json;
+use serde_json::Value;
+
+use std::rc::Rc;
+
+pub const TRACE_JSON_FORMAT_VERSION: u32 = 1;
+
+const PREV_TRACE: &str = "prev_trace";
+const CURRENT_TRACE: &str = "current_trace";
+const RESULT_TRACE: &str = "result_trace";
+
+impl TraceHandler {
+    /// Returns the previous, current and result traces in the format described in the module docs.
+    pub fn to_json(&self) -> Value {
+        json!({
+            "version": TRACE_JSON_FORMAT_VERSION,
+            PREV_TRACE: trace_to_json(self.data_keeper.prev_slider().trace()),
+            CURRENT_TRACE: trace_to_json(self.data_keeper.current_slider().trace()),
+            RESULT_TRACE: trace_to_json(&self.data_keeper.result_trace),
+        })
+    }
+
+    /// Restores a handler from `to_json` output. Merging positions aren't a part of the format,
+    /// so the restored handler starts merging from the beginning of the previous and current traces.
+    pub fn from_json(value: Value) -> Result<Self, JsonError> {
+        let version = value.get("version").ok_or(JsonError::MissingField("version"))?;
+        if version.as_u64() != Some(TRACE_JSON_FORMAT_VERSION as u64) {
+            return Err(JsonError::UnsupportedVersion(version.clone()));
+        }
+
+        let prev_trace = expanded_trace_from_json(&value, PREV_TRACE)?;
+        let current_trace = expanded_trace_from_json(&value, CURRENT_TRACE)?;
+        let result_trace = named_trace_from_json(&value, RESULT_TRACE)?;
+
+        let mut handler = Self::from_trace(prev_trace, current_trace);
+        handler.data_keeper.result_trace = result_trace;
+
+        Ok(handler)
+    }
+}
+
+/// Converts a trace into an array of states in the format described in the module docs.
+pub fn trace_to_json(trace: &ExecutionTrace) -> Value {
+    trace.iter().map(state_to_json).collect()
+}
+
+/// Reads a trace written by `trace_to_json`.
+pub fn trace_from_json(value: &Value) -> Result<ExecutionTrace, JsonError> {
+    trace_from_json_named(value, RESULT_TRACE)
+}
+
+fn named_trace_from_json(value: &Value, name: &'static str) -> Result<ExecutionTrace, JsonError> {
+    let trace = value.get(name).ok_or(JsonError::MissingField(name))?;
+    trace_from_json_named(trace, name)
+}
+
+fn expanded_trace_from_json(value: &Value, name: &'static str) -> Result<ExecutionTrace, JsonError> {
+    let mut trace = named_trace_from_json(value, name)?;
+    trace
+        .expand()
+        .map_err(|error| JsonError::NotExpandable { trace: name, error })?;
+    Ok(trace)
+}
+
+fn trace_from_json_named(value: &Value, name: &'static str) -> Result<ExecutionTrace, JsonError> {
+    let states = value.as_array().ok_or(JsonError::MissingField(name))?;
+
+    states
+        .iter()
+        .enumerate()
+        .map(|(position, state)| {
+            state_from_json(state).ok_or_else(|| JsonError::MalformedState {
+                trace: name,
+                position,
+                state: state.clone(),
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(Into::into)
+}
+
+fn state_to_json(state: &ExecutedState) -> Value {
+    match state {
+        ExecutedState::Par(par) => json!({"par": {"left": par.left_size, "right": par.right_size}}),
+        ExecutedState::Call(CallResult::Executed(ValueRef::Scalar(cid))) => {
+            json!({"call": {"scalar": cid_to_json(cid)}})
+        }
+        ExecutedState::Call(CallResult::Executed(ValueRef::Stream { cid, generation })) => json!({
+            "call": {"stream": {"cid": cid_to_json(cid), "generation": usize::from(*generation)}}
+        }),
+        ExecutedState::Call(CallResult::Executed(ValueRef::Unused(cid))) => {
+            json!({"call": {"unused": cid_to_json(cid)}})
+        }
+        ExecutedState::Call(CallResult::RequestSentBy(Sender::PeerId(peer_id))) => {
+            json!({"call": {"sent_by": {"peer_id": peer_id.as_str(), "call_id": null}}})
+        }
+        ExecutedState::Call(CallResult::RequestSentBy(Sender::PeerIdWithCallId { peer_id, call_id })) => {
+            json!({"call": {"sent_by": {"peer_id": peer_id.as_str(), "call_id": call_id}}})
+        }
+        ExecutedState::Call(CallResult::Failed(cid)) => json!({"call": {"failed": cid_to_json(cid)}}),
+        ExecutedState::Fold(fold) => {
+            let lores: Vec<_> = fold
+                .lore
+                .iter()
+                .map(|lore| {
+                    let subtraces: Vec<_> = lore
+                        .subtraces_desc
+                        .iter()
+                        .map(|desc| json!({"begin": u32::from(desc.begin_pos), "len": desc.subtrace_len}))
+                        .collect();
+                    json!({"value_pos": u32::from(lore.value_pos), "subtraces": subtraces})
+                })
+                .collect();
+            json!({ "fold": lores })
+        }
+        ExecutedState::Ap(ap) => {
+            let generations: Vec<_> = ap
+                .res_generations
+                .iter()
+                .map(|&generation| usize::from(generation))
+                .collect();
+            json!({"ap": {"generations": generations}})
+        }
+        ExecutedState::Canon(CanonResult::Executed(cid)) => json!({"canon": {"executed": cid_to_json(cid)}}),
+        ExecutedState::Canon(CanonResult::RequestSentBy(peer_id)) => json!({"canon": {"sent_by": peer_id.as_str()}}),
+        ExecutedState::Repeated(count) => json!({ "repeated": count }),
+    }
+}
+
+fn state_from_json(value: &Value) -> Option<ExecutedState> {
+    let (kind, body) = single_entry(value)?;
+
+    let state = match kind {
+        "par" => ExecutedState::Par(ParResult::new(as_u32(body.get("left")?)?, as_u32(body.get("right")?)?)),
+        "call" => ExecutedState::Call(call_from_json(body)?),
+        "fold" => {
+            let lore = body.as_array()?.iter().map(lore_from_json).collect::<Option<_>>()?;
+            ExecutedState::Fold(FoldResult { lore })
+        }
+        "ap" => {
+            let res_generations = body
+                .get("generations")?
+                .as_array()?
+                .iter()
+                .map(|generation| as_u32(generation).map(|generation| GenerationIdx::from(generation as usize)))
+                .collect::<Option<_>>()?;
+            ExecutedState::Ap(ApResult { res_generations })
+        }
+        "canon" => {
+            let (kind, body) = single_entry(body)?;
+            match kind {
+                "executed" => ExecutedState::Canon(CanonResult::Executed(cid_from_json(body)?)),
+                "sent_by" => ExecutedState::Canon(CanonResult::RequestSentBy(Rc::new(body.as_str()?.to_string()))),
+                _ => return None,
+            }
+        }
+        "repeated" => ExecutedState::Repeated(as_u32(body)?),
+        _ => return None,
+    };
+
+    Some(state)
+}
+
+fn call_from_json(value: &Value) -> Option<CallResult> {
+    let (kind, body) = single_entry(value)?;
+
+    let call_result = match kind {
+        "scalar" => CallResult::Executed(ValueRef::Scalar(cid_from_json(body)?)),
+        "stream" => CallResult::Executed(ValueRef::Stream {
+            cid: cid_from_json(body.get("cid")?)?,
+            generation: GenerationIdx::from(as_u32(body.get("generation")?)? as usize),
+        }),
+        "unused" => CallResult::Executed(ValueRef::Unused(cid_from_json(body)?)),
+        "failed" => CallResult::Failed(cid_from_json(body)?),
+        "sent_by" => {
+            let peer_id = Rc::new(body.get("peer_id")?.as_str()?.to_string());
+            let sender = match body.get("call_id") {
+                None | Some(Value::Null) => Sender::PeerId(peer_id),
+                Some(call_id) => Sender::PeerIdWithCallId {
+                    peer_id,
+                    call_id: as_u32(call_id)?.into(),
+                },
+            };
+            CallResult::RequestSentBy(sender)
+        }
+        _ => return None,
+    };
+
+    Some(call_result)
+}
+
+fn lore_from_json(value: &Value) -> Option<FoldSubTraceLore> {
+    let value_pos = as_u32(value.get("value_pos")?)?.into();
+    let subtraces_desc = value
+        .get("subtraces")?
+        .as_array()?
+        .iter()
+        .map(|desc| {
+            Some(SubTraceDesc {
+                begin_pos: as_u32(desc.get("begin")?)?.into(),
+                subtrace_len: as_u32(desc.get("len")?)?,
+            })
+        })
+        .collect::<Option<_>>()?;
+
+    Some(FoldSubTraceLore {
+        value_pos,
+        subtraces_desc,
+    })
+}
+
+fn single_entry(value: &Value) -> Option<(&str, &Value)> {
+    let object = value.as_object()?;
+    if object.len() != 1 {
+        return None;
+    }
+
+    object.iter().next().map(|(key, value)| (key.as_str(), value))
+}
+
+fn as_u32(value: &Value) -> Option<u32> {
+    value.as_u64()?.try_into().ok()
+}
+
+fn cid_to_json<T: ?Sized>(cid: &CID<T>) -> Value {
+    Value::from(&*cid.get_inner())
+}
+
+fn cid_from_json<T: ?Sized>(value: &Value) -> Option<CID<T>> {
+    value.as_str().map(CID::new)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn all_states() -> ExecutionTrace {
+        vec![
+            ExecutedState::par(1, 2),
+            ExecutedState::Call(CallResult::Executed(ValueRef::Scalar(CID::new("scalar_cid")))),
+            ExecutedState::Call(CallResult::Executed(ValueRef::Stream {
+                cid: CID::new("stream_cid"),
+                generation: 3.into(),
+            })),
+            ExecutedState::Call(CallResult::Executed(ValueRef::Unused(CID::new("unused_cid")))),
+            ExecutedState::Call(CallResult::RequestSentBy(Sender::PeerId(Rc::new("peer".to_string())))),
+            ExecutedState::Call(CallResult::RequestSentBy(Sender::PeerIdWithCallId {
+                peer_id: Rc::new("peer".to_string()),
+                call_id: 4.into(),
+            })),
+            ExecutedState::Call(CallResult::Failed(CID::new("failed_cid"))),
+            ExecutedState::Fold(FoldResult {
+                lore: vec![FoldSubTraceLore {
+                    value_pos: 1.into(),
+                    subtraces_desc: vec![SubTraceDesc::new(2.into(), 3), SubTraceDesc::new(5.into(), 0)],
+                }],
+            }),
+            ExecutedState::Ap(ApResult::new(0.into())),
+            ExecutedState::Canon(CanonResult::Executed(CID::new("canon_cid"))),
+            ExecutedState::Canon(CanonResult::RequestSentBy(Rc::new("peer".to_string()))),
+            ExecutedState::Repeated(2),
+        ]
+        .into()
+    }
+
+    #[test]
+    fn trace_roundtrip() {
+        let trace = all_states();
+
+        let json = trace_to_json(&trace);
+        assert_eq!(json[0], json!({"par": {"left": 1, "right": 2}}));
+        assert_eq!(
+            json[2],
+            json!({"call": {"stream": {"cid": "stream_cid", "generation": 3}}})
+        );
+
+        assert_eq!(trace_from_json(&json).unwrap(), trace);
+    }
+
+    #[test]
+    fn handler_roundtrip() {
+        let prev_trace: ExecutionTrace = vec![ExecutedState::par(0, 0)].into();
+        let current_trace: ExecutionTrace = vec![ExecutedState::Ap(ApResult::new(1.into()))].into();
+        let handler = TraceHandler::from_trace(prev_trace.clone(), current_trace.clone());
+
+        let json = handler.to_json();
+        assert_eq!(json["version"], json!(TRACE_JSON_FORMAT_VERSION));
+
+        let restored = TraceHandler::from_json(json).unwrap();
+        assert_eq!(restored.data_keeper.prev_slider().trace(), &prev_trace);
+        assert_eq!(restored.data_keeper.current_slider().trace(), &current_trace);
+        assert!(restored.as_result_trace().is_empty());
+    }
+
+    #[test]
+    fn malformed_state_is_rejected() {
+        let json = json!([{"par": {"left": 1, "right": 2}}, {"call": {"scalar": 1}}]);
+
+        let error = trace_from_json(&json).unwrap_err();
+        assert!(
+            matches!(error, JsonError::MalformedState { position: 1, .. }),
+            "{error:?}"
+        );
+    }
+
+    #[test]
+    fn unsupported_version_is_rejected() {
+        let json = json!({"version": 0, PREV_TRACE: [], CURRENT_TRACE: [], RESULT_TRACE: []});
+
+        let error = TraceHandler::from_json(json).unwrap_err();
+        assert!(matches!(error, JsonError::UnsupportedVersion(_)), "{error:?}");
+    }
+}
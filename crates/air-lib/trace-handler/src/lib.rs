@@ -29,14 +29,21 @@
 mod data_keeper;
 mod errors;
 mod handler;
+#[cfg(feature = "flamegraph")]
+mod instruction_timings;
+mod json;
 pub mod merger;
 mod state_automata;
 
 pub use data_keeper::KeeperError;
 pub use errors::GenerationCompactificationError;
 pub use errors::IntConversionError;
+pub use errors::JsonError;
 pub use errors::TraceHandlerError;
 pub use handler::TraceHandler;
+pub use json::trace_from_json;
+pub use json::trace_to_json;
+pub use json::TRACE_JSON_FORMAT_VERSION;
 pub use merger::DataType;
 pub use merger::MergeError;
 pub use state_automata::StateFSMError;
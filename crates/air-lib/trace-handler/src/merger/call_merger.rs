@@ -62,7 +62,10 @@ pub(crate) fn try_merge_next_state_as_call(data_keeper: &mut DataKeeper) -> Merg
     }
 }
 
-fn merge_call_results(prev_call: CallResult, current_call: CallResult) -> MergeResult<(CallResult, PreparationScheme)> {
+pub(super) fn merge_call_results(
+    prev_call: CallResult,
+    current_call: CallResult,
+) -> MergeResult<(CallResult, PreparationScheme)> {
     use CallResult::*;
     use PreparationScheme::*;
 
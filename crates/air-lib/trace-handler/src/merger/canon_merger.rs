@@ -62,7 +62,7 @@ fn prepare_single_canon_result(canon_result: CanonResult) -> MergeResult<MergerC
     Ok(merger_result)
 }
 
-fn merge_canon_results(
+pub(super) fn merge_canon_results(
     prev_canon_result: CanonResult,
     current_canon_result: CanonResult,
 ) -> Result<CanonResult, CanonResultError> {
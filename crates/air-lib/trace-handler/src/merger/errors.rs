@@ -51,6 +51,11 @@ pub enum MergeError {
 
     #[error(transparent)]
     IncorrectFoldResult(#[from] FoldResultError),
+
+    /// Fold states refer to absolute trace positions, so traces containing them
+    /// can't be merged without the AIR script that produced them.
+    #[error("trace containing fold {0:?} can't be merged structurally")]
+    FoldNotMergeable(FoldResult),
 }
 
 #[derive(ThisError, Debug)]
@@ -21,6 +21,7 @@ mod errors;
 mod fold_merger;
 mod par_merger;
 mod position_mapping;
+mod trace_merger;
 
 pub use ap_merger::MergerApResult;
 pub use ap_merger::MetApResult;
@@ -46,6 +47,8 @@ pub(super) use call_merger::try_merge_next_state_as_call;
 pub(super) use canon_merger::try_merge_next_state_as_canon;
 pub(crate) use fold_merger::try_merge_next_state_as_fold;
 pub(crate) use par_merger::try_merge_next_state_as_par;
+pub(crate) use trace_merger::merge_traces;
+pub(crate) use trace_merger::split_par;
 
 use position_mapping::prepare_positions_mapping;
 
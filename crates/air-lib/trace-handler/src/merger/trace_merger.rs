@@ -0,0 +1,249 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::call_merger::merge_call_results;
+use super::canon_merger::merge_canon_results;
+use super::*;
+
+/// Merges traces produced by peers that executed different branches of the same
+/// `par` instructions independently.
+///
+/// Traces are walked side by side: `par` states are merged subtree by subtree, so
+/// a branch executed by only one of the peers is taken as is, while states met in
+/// both traces are merged by the same rules as during ordinary execution. Traces
+/// containing fold states are rejected, because their subtrace descriptors can't
+/// be rebuilt without the AIR script.
+pub(crate) fn merge_traces(left: ExecutionTrace, right: ExecutionTrace) -> MergeResult<ExecutionTrace> {
+    check_no_folds(&left)?;
+    check_no_folds(&right)?;
+
+    let mut result_trace = Vec::with_capacity(left.len().max(right.len()));
+    merge_subtraces(&left, &right, &mut result_trace)?;
+
+    Ok(result_trace.into())
+}
+
+fn check_no_folds(trace: &ExecutionTrace) -> MergeResult<()> {
+    match trace.iter().find(|state| matches!(state, ExecutedState::Fold(_))) {
+        Some(ExecutedState::Fold(fold_result)) => Err(MergeError::FoldNotMergeable(fold_result.clone())),
+        _ => Ok(()),
+    }
+}
+
+fn merge_subtraces(
+    left: &[ExecutedState],
+    right: &[ExecutedState],
+    result_trace: &mut Vec<ExecutedState>,
+) -> MergeResult<()> {
+    use ExecutedState::*;
+
+    let mut left_pos = 0;
+    let mut right_pos = 0;
+
+    loop {
+        let merged_state = match (left.get(left_pos), right.get(right_pos)) {
+            (None, None) => return Ok(()),
+            (Some(_), None) => {
+                result_trace.extend_from_slice(&left[left_pos..]);
+                return Ok(());
+            }
+            (None, Some(_)) => {
+                result_trace.extend_from_slice(&right[right_pos..]);
+                return Ok(());
+            }
+            (Some(Par(left_par)), Some(Par(right_par))) => {
+                let (left_subtraces, left_len) = split_par(left, left_pos, left_par)?;
+                let (right_subtraces, right_len) = split_par(right, right_pos, right_par)?;
+                merge_par(left_subtraces, right_subtraces, result_trace)?;
+
+                left_pos += left_len;
+                right_pos += right_len;
+                continue;
+            }
+            (Some(Call(left_call)), Some(Call(right_call))) => {
+                let (merged_call, _) = merge_call_results(left_call.clone(), right_call.clone())?;
+                Call(merged_call)
+            }
+            (Some(Canon(left_canon)), Some(Canon(right_canon))) => {
+                let merged_canon = merge_canon_results(left_canon.clone(), right_canon.clone())
+                    .map_err(MergeError::IncorrectCanonResult)?;
+                Canon(merged_canon)
+            }
+            (Some(left_ap @ Ap(_)), Some(right_ap @ Ap(_))) if left_ap == right_ap => left_ap.clone(),
+            (Some(left_state), Some(right_state)) => {
+                return Err(MergeError::IncompatibleExecutedStates(
+                    left_state.clone(),
+                    right_state.clone(),
+                ))
+            }
+        };
+
+        result_trace.push(merged_state);
+        left_pos += 1;
+        right_pos += 1;
+    }
+}
+
+pub(crate) type ParSubtraces<'trace> = (&'trace [ExecutedState], &'trace [ExecutedState]);
+
+/// Returns left and right subtraces of a par placed at `par_pos` and a total length
+/// of the par including its own state.
+pub(crate) fn split_par<'trace>(
+    trace: &'trace [ExecutedState],
+    par_pos: usize,
+    par_result: &ParResult,
+) -> MergeResult<(ParSubtraces<'trace>, usize)> {
+    let left_begin = par_pos + 1;
+    let right_begin = left_begin + par_result.left_size as usize;
+    let par_end = right_begin + par_result.right_size as usize;
+
+    if par_end > trace.len() {
+        return Err(KeeperError::SetSubtraceLenFailed {
+            requested_subtrace_len: par_result.left_size + par_result.right_size,
+            trace_position: TracePos::from(left_begin as u32),
+            trace_len: trace.len() as TraceLen,
+        }
+        .into());
+    }
+
+    let subtraces = (&trace[left_begin..right_begin], &trace[right_begin..par_end]);
+    Ok((subtraces, par_end - par_pos))
+}
+
+fn merge_par(
+    left: ParSubtraces<'_>,
+    right: ParSubtraces<'_>,
+    result_trace: &mut Vec<ExecutedState>,
+) -> MergeResult<()> {
+    let par_pos = result_trace.len();
+    // sizes are known only after both subtraces are merged
+    result_trace.push(ExecutedState::par(0, 0));
+
+    merge_subtraces(left.0, right.0, result_trace)?;
+    let left_size = result_trace.len() - par_pos - 1;
+
+    merge_subtraces(left.1, right.1, result_trace)?;
+    let right_size = result_trace.len() - par_pos - 1 - left_size;
+
+    result_trace[par_pos] = ExecutedState::par(left_size, right_size);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::merge_traces;
+    use crate::MergeError;
+    use air_interpreter_cid::CID;
+    use air_interpreter_data::CallResult;
+    use air_interpreter_data::ExecutedState;
+    use air_interpreter_data::ExecutionTrace;
+    use air_interpreter_data::FoldResult;
+
+    use std::rc::Rc;
+
+    fn executed(cid: &str) -> ExecutedState {
+        ExecutedState::Call(CallResult::executed_scalar(CID::new(cid)))
+    }
+
+    fn request_sent() -> ExecutedState {
+        ExecutedState::Call(CallResult::sent_peer_id(Rc::new("peer_id".to_string())))
+    }
+
+    #[test]
+    fn branches_executed_by_different_peers() {
+        let left: ExecutionTrace = vec![
+            executed("init"),
+            ExecutedState::par(1, 1),
+            executed("left_branch"),
+            request_sent(),
+        ]
+        .into();
+        let right: ExecutionTrace = vec![
+            executed("init"),
+            ExecutedState::par(1, 2),
+            request_sent(),
+            executed("right_branch"),
+            executed("right_branch_2"),
+        ]
+        .into();
+
+        let merged = merge_traces(left, right).expect("branches should be merged");
+
+        let expected: ExecutionTrace = vec![
+            executed("init"),
+            ExecutedState::par(1, 2),
+            executed("left_branch"),
+            executed("right_branch"),
+            executed("right_branch_2"),
+        ]
+        .into();
+        assert_eq!(merged, expected);
+    }
+
+    #[test]
+    fn nested_par_of_unequal_sizes() {
+        let left: ExecutionTrace = vec![
+            ExecutedState::par(3, 0),
+            ExecutedState::par(1, 1),
+            executed("a"),
+            request_sent(),
+        ]
+        .into();
+        let right: ExecutionTrace = vec![ExecutedState::par(0, 1), executed("b")].into();
+
+        let merged = merge_traces(left, right).expect("branches should be merged");
+
+        let expected: ExecutionTrace = vec![
+            ExecutedState::par(3, 1),
+            ExecutedState::par(1, 1),
+            executed("a"),
+            request_sent(),
+            executed("b"),
+        ]
+        .into();
+        assert_eq!(merged, expected);
+    }
+
+    #[test]
+    fn conflicting_call_results() {
+        let left: ExecutionTrace = vec![ExecutedState::par(1, 0), executed("a")].into();
+        let right: ExecutionTrace = vec![ExecutedState::par(1, 0), executed("b")].into();
+
+        let result = merge_traces(left, right);
+
+        assert!(matches!(result, Err(MergeError::IncorrectCallResult(_))), "{result:?}");
+    }
+
+    #[test]
+    fn par_overflowing_trace() {
+        let left: ExecutionTrace = vec![ExecutedState::par(2, 0), executed("a")].into();
+        let right: ExecutionTrace = vec![ExecutedState::par(1, 0), executed("a")].into();
+
+        let result = merge_traces(left, right);
+
+        assert!(matches!(result, Err(MergeError::KeeperError(_))), "{result:?}");
+    }
+
+    #[test]
+    fn folds_are_not_mergeable() {
+        let left: ExecutionTrace = vec![ExecutedState::Fold(FoldResult { lore: vec![] })].into();
+        let right: ExecutionTrace = vec![].into();
+
+        let result = merge_traces(left, right);
+
+        assert!(matches!(result, Err(MergeError::FoldNotMergeable(_))), "{result:?}");
+    }
+}
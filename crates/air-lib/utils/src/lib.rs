@@ -52,6 +52,9 @@ macro_rules! auto_checked_add {
 #[macro_export]
 macro_rules! farewell_if_fail {
     ($cmd:expr, $raw_prev_data:expr, $soft_limits_triggering:expr) => {
+        farewell_if_fail!($cmd, $raw_prev_data, $soft_limits_triggering, 0)
+    };
+    ($cmd:expr, $raw_prev_data:expr, $soft_limits_triggering:expr, $fuel_consumed:expr) => {
         match $cmd {
             Ok(result) => result,
             // return the prev data in case of errors
@@ -60,6 +63,7 @@ macro_rules! farewell_if_fail {
                     $raw_prev_data,
                     error,
                     $soft_limits_triggering,
+                    $fuel_consumed,
                 ))
             }
         };
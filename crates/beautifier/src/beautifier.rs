@@ -108,7 +108,8 @@ impl<W: io::Write> Beautifier<W> {
 
     /// Emit beautified code for the `air_script`.
     pub fn beautify(&mut self, air_script: &str) -> Result<(), BeautifyError> {
-        let tree = air_parser::parse(air_script).map_err(BeautifyError::Parse)?;
+        let tree = air_parser::parse(air_script)
+            .map_err(|error| BeautifyError::Parse(error.to_string()))?;
         self.beautify_ast(&tree)
     }
 
@@ -123,6 +124,7 @@ impl<W: io::Write> Beautifier<W> {
             ast::Instruction::Ap(ap) => self.beautify_simple(ap, indent),
             ast::Instruction::ApMap(ap_map) => self.beautify_simple(ap_map, indent),
             ast::Instruction::Canon(canon) => self.beautify_simple(canon, indent),
+            ast::Instruction::CanonSorted(canon) => self.beautify_simple(canon, indent),
             ast::Instruction::CanonMap(canon_map) => self.beautify_simple(canon_map, indent),
             ast::Instruction::CanonStreamMapScalar(canon) => self.beautify_simple(canon, indent),
             ast::Instruction::Seq(seq) => self.beautify_seq(seq, indent),
@@ -130,6 +132,12 @@ impl<W: io::Write> Beautifier<W> {
             ast::Instruction::Xor(xor) => self.beautify_xor(xor, indent),
             ast::Instruction::Match(match_) => self.beautify_match(match_, indent),
             ast::Instruction::MisMatch(mismatch) => self.beautify_mismatch(mismatch, indent),
+            ast::Instruction::MatchRegex(match_regex) => {
+                self.beautify_match_regex(match_regex, indent)
+            }
+            ast::Instruction::MisMatchRegex(mismatch_regex) => {
+                self.beautify_mismatch_regex(mismatch_regex, indent)
+            }
             ast::Instruction::Fail(fail) => self.beautify_simple(fail, indent),
             ast::Instruction::FoldScalar(fold_scalar) => {
                 self.beautify_fold_scalar(fold_scalar, indent)
@@ -205,6 +213,24 @@ impl<W: io::Write> Beautifier<W> {
         Ok(())
     }
 
+    fn beautify_match_regex(
+        &mut self,
+        match_regex: &ast::MatchRegex<'_>,
+        indent: usize,
+    ) -> io::Result<()> {
+        compound!(self, indent, match_regex);
+        Ok(())
+    }
+
+    fn beautify_mismatch_regex(
+        &mut self,
+        mismatch_regex: &ast::MisMatchRegex<'_>,
+        indent: usize,
+    ) -> io::Result<()> {
+        compound!(self, indent, mismatch_regex);
+        Ok(())
+    }
+
     fn beautify_fold_scalar(
         &mut self,
         fold: &ast::FoldScalar<'_>,
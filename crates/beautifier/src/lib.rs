@@ -40,7 +40,7 @@ pub fn beautify(air_script: &str, output: &mut impl io::Write) -> Result<(), Bea
 /// Beautify the `air_script` to a string with default settings.
 /// Return error on parsing error.
 pub fn beautify_to_string(air_script: &str) -> Result<String, String> {
-    let ast = air_parser::parse(air_script)?;
+    let ast = air_parser::parse(air_script).map_err(|error| error.to_string())?;
     let mut buffer = vec![];
     let mut beautifier = Beautifier::new(&mut buffer);
 
@@ -198,6 +198,22 @@ mismatch a b:
     );
 }
 
+#[test]
+fn match_regex() {
+    let script = r#"(seq
+  (call "a" ("" "") [] a)
+  (match-regex a "^peer-[0-9]+$" (null)))"#;
+    let output = beautify_to_string(script).unwrap();
+
+    assert_eq!(
+        output,
+        r#"a <- call "a" ("", "") []
+match-regex a "^peer-[0-9]+$":
+    null
+"#,
+    );
+}
+
 #[test]
 fn fail_last_error() {
     let script = "(fail %last_error%)";
@@ -0,0 +1,154 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use super::MetadataStore;
+use super::ObjectStore;
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// Keeps blobs in files of a directory named by their CIDs.
+#[derive(Debug, Clone)]
+pub struct FsObjectStore {
+    dir: PathBuf,
+}
+
+impl FsObjectStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn object_path(&self, cid: &str) -> PathBuf {
+        self.dir.join(cid)
+    }
+}
+
+impl ObjectStore for FsObjectStore {
+    type Error = io::Error;
+
+    fn initialize(&mut self) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)
+    }
+
+    fn put_object(&mut self, cid: &str, data: &[u8]) -> io::Result<()> {
+        let path = self.object_path(cid);
+        if path.exists() {
+            return Ok(());
+        }
+        write_atomically(&path, data)
+    }
+
+    fn get_object(&mut self, cid: &str) -> io::Result<Vec<u8>> {
+        fs::read(self.object_path(cid))
+    }
+
+    fn remove_object(&mut self, cid: &str) -> io::Result<()> {
+        match fs::remove_file(self.object_path(cid)) {
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    }
+}
+
+/// Keeps the mapping in memory and rewrites it into a JSON file on each change,
+/// it suits a mapping of particles a single peer executes.
+#[derive(Debug, Clone)]
+pub struct FsMetadataStore {
+    path: PathBuf,
+    /// CIDs by particle ids by current peer ids.
+    cids: HashMap<String, HashMap<String, String>>,
+}
+
+impl FsMetadataStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            cids: <_>::default(),
+        }
+    }
+
+    fn persist(&self) -> io::Result<()> {
+        let json = serde_json::to_vec(&self.cids)?;
+        write_atomically(&self.path, &json)
+    }
+}
+
+impl MetadataStore for FsMetadataStore {
+    type Error = io::Error;
+
+    fn initialize(&mut self) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        self.cids = match fs::read(&self.path) {
+            Ok(json) => serde_json::from_slice(&json)?,
+            Err(error) if error.kind() == ErrorKind::NotFound => <_>::default(),
+            Err(error) => return Err(error),
+        };
+        Ok(())
+    }
+
+    fn get_cid(&mut self, particle_id: &str, current_peer_id: &str) -> io::Result<Option<String>> {
+        let cid = self
+            .cids
+            .get(current_peer_id)
+            .and_then(|particles| particles.get(particle_id))
+            .cloned();
+        Ok(cid)
+    }
+
+    fn set_cid(&mut self, particle_id: &str, current_peer_id: &str, cid: &str) -> io::Result<()> {
+        self.cids
+            .entry(current_peer_id.to_owned())
+            .or_default()
+            .insert(particle_id.to_owned(), cid.to_owned());
+        self.persist()
+    }
+
+    fn remove_cid(&mut self, particle_id: &str, current_peer_id: &str) -> io::Result<()> {
+        let Some(particles) = self.cids.get_mut(current_peer_id) else {
+            return Ok(());
+        };
+        particles.remove(particle_id);
+        if particles.is_empty() {
+            self.cids.remove(current_peer_id);
+        }
+        self.persist()
+    }
+
+    fn is_referenced(&mut self, cid: &str) -> io::Result<bool> {
+        let is_referenced = self
+            .cids
+            .values()
+            .flat_map(HashMap::values)
+            .any(|particle_cid| particle_cid == cid);
+        Ok(is_referenced)
+    }
+}
+
+/// Writes a file via a temporary one, so a crash can't leave it partially written.
+fn write_atomically(path: &Path, data: &[u8]) -> io::Result<()> {
+    let mut tmp_path = path.as_os_str().to_owned();
+    tmp_path.push(".tmp");
+
+    fs::write(&tmp_path, data)?;
+    fs::rename(&tmp_path, path)
+}
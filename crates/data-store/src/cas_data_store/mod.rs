@@ -0,0 +1,234 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+//! Data store keeping particle data in a content addressed object store.
+
+mod fs_backend;
+
+pub use fs_backend::FsMetadataStore;
+pub use fs_backend::FsObjectStore;
+
+use crate::AnomalyData;
+use crate::DataStore;
+
+use avm_interface::raw_outcome::RawAVMOutcome;
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Keeps blobs by their CIDs, e.g. in a local directory, a S3 bucket or IPFS.
+pub trait ObjectStore {
+    type Error;
+
+    fn initialize(&mut self) -> Result<(), Self::Error>;
+
+    /// Stores a blob, it's a no-op if a blob with the same CID is already stored.
+    fn put_object(&mut self, cid: &str, data: &[u8]) -> Result<(), Self::Error>;
+
+    fn get_object(&mut self, cid: &str) -> Result<Vec<u8>, Self::Error>;
+
+    fn remove_object(&mut self, cid: &str) -> Result<(), Self::Error>;
+}
+
+/// Maps particles to CIDs of their latest data.
+pub trait MetadataStore {
+    type Error;
+
+    fn initialize(&mut self) -> Result<(), Self::Error>;
+
+    fn get_cid(
+        &mut self,
+        particle_id: &str,
+        current_peer_id: &str,
+    ) -> Result<Option<String>, Self::Error>;
+
+    fn set_cid(
+        &mut self,
+        particle_id: &str,
+        current_peer_id: &str,
+        cid: &str,
+    ) -> Result<(), Self::Error>;
+
+    fn remove_cid(&mut self, particle_id: &str, current_peer_id: &str) -> Result<(), Self::Error>;
+
+    /// Tells whether some particle still maps to the CID, so the blob can't be removed.
+    fn is_referenced(&mut self, cid: &str) -> Result<bool, Self::Error>;
+}
+
+pub type CASObjectStore<E> = Box<dyn ObjectStore<Error = E> + Send + Sync + 'static>;
+
+pub type CASMetadataStore<E> = Box<dyn MetadataStore<Error = E> + Send + Sync + 'static>;
+
+/// Data store keeping particle data by its SHA-256 CID, so particles sharing the same data
+/// share a blob, and mapping particles to CIDs of their latest data in a separate metadata store.
+///
+/// A blob is removed once no particle maps to it anymore. Anomalies aren't detected.
+pub struct CASDataStore<E> {
+    objects: CASObjectStore<E>,
+    metadata: CASMetadataStore<E>,
+}
+
+impl<E> CASDataStore<E> {
+    pub fn new(objects: CASObjectStore<E>, metadata: CASMetadataStore<E>) -> Self {
+        Self { objects, metadata }
+    }
+
+    /// Removes the blob if no particle maps to it anymore.
+    fn release_object(&mut self, cid: &str) -> Result<(), E> {
+        if self.metadata.is_referenced(cid)? {
+            return Ok(());
+        }
+        self.objects.remove_object(cid)
+    }
+}
+
+impl CASDataStore<std::io::Error> {
+    /// Creates a store keeping blobs in the `objects` directory of `root`
+    /// and the mapping in its `metadata.json` file.
+    pub fn file_system(root: impl Into<PathBuf>) -> Self {
+        let root = root.into();
+        let objects = FsObjectStore::new(root.join("objects"));
+        let metadata = FsMetadataStore::new(root.join("metadata.json"));
+
+        Self::new(Box::new(objects), Box::new(metadata))
+    }
+}
+
+impl<E> DataStore for CASDataStore<E> {
+    type Error = E;
+
+    fn initialize(&mut self) -> Result<(), Self::Error> {
+        self.objects.initialize()?;
+        self.metadata.initialize()
+    }
+
+    fn store_data(
+        &mut self,
+        data: &[u8],
+        particle_id: &str,
+        current_peer_id: &str,
+    ) -> Result<(), Self::Error> {
+        let cid = air_interpreter_cid::raw_bytes_to_sha256_cid(data).get_inner();
+        // the blob is stored before the mapping, so the mapping never points to a missing blob
+        self.objects.put_object(&cid, data)?;
+
+        let prev_cid = self.metadata.get_cid(particle_id, current_peer_id)?;
+        self.metadata.set_cid(particle_id, current_peer_id, &cid)?;
+        match prev_cid {
+            Some(prev_cid) if *prev_cid != *cid => self.release_object(&prev_cid),
+            _ => Ok(()),
+        }
+    }
+
+    fn read_data(
+        &mut self,
+        particle_id: &str,
+        current_peer_id: &str,
+    ) -> Result<Vec<u8>, Self::Error> {
+        match self.metadata.get_cid(particle_id, current_peer_id)? {
+            Some(cid) => self.objects.get_object(&cid),
+            // a particle met for the first time has no data
+            None => Ok(vec![]),
+        }
+    }
+
+    fn cleanup_data(
+        &mut self,
+        particle_id: &str,
+        current_peer_id: &str,
+    ) -> Result<(), Self::Error> {
+        let Some(cid) = self.metadata.get_cid(particle_id, current_peer_id)? else {
+            return Ok(());
+        };
+
+        self.metadata.remove_cid(particle_id, current_peer_id)?;
+        self.release_object(&cid)
+    }
+
+    fn detect_anomaly(
+        &self,
+        _execution_time: Duration,
+        _memory_delta: usize,
+        _outcome: &RawAVMOutcome,
+    ) -> bool {
+        false
+    }
+
+    fn collect_anomaly_data(
+        &mut self,
+        _particle_id: &str,
+        _current_peer_id: &str,
+        _anomaly_data: AnomalyData<'_>,
+    ) -> Result<(), Self::Error> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs;
+    use std::path::Path;
+
+    fn temp_root(test_name: &str) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("cas_data_store_{test_name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        root
+    }
+
+    fn objects_count(root: &Path) -> usize {
+        fs::read_dir(root.join("objects")).unwrap().count()
+    }
+
+    #[test]
+    fn shared_data_is_deduplicated() {
+        let root = temp_root("dedup");
+        let mut store = CASDataStore::file_system(&root);
+        store.initialize().unwrap();
+
+        store.store_data(b"data", "particle_1", "peer").unwrap();
+        store.store_data(b"data", "particle_2", "peer").unwrap();
+        assert_eq!(objects_count(&root), 1);
+
+        store.cleanup_data("particle_1", "peer").unwrap();
+        assert_eq!(store.read_data("particle_2", "peer").unwrap(), b"data");
+        assert_eq!(objects_count(&root), 1);
+
+        store.store_data(b"new data", "particle_2", "peer").unwrap();
+        assert_eq!(store.read_data("particle_1", "peer").unwrap(), b"");
+        assert_eq!(store.read_data("particle_2", "peer").unwrap(), b"new data");
+        assert_eq!(objects_count(&root), 1);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn mapping_survives_reinitialization() {
+        let root = temp_root("reinit");
+        let mut store = CASDataStore::file_system(&root);
+        store.initialize().unwrap();
+        store.store_data(b"data", "particle", "peer").unwrap();
+
+        let mut store = CASDataStore::file_system(&root);
+        store.initialize().unwrap();
+        assert_eq!(store.read_data("particle", "peer").unwrap(), b"data");
+        assert_eq!(store.read_data("particle", "other_peer").unwrap(), b"");
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
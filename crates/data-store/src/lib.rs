@@ -26,12 +26,26 @@
     unreachable_patterns
 )]
 
+mod cas_data_store;
+mod wal_data_store;
+
+pub use cas_data_store::CASDataStore;
+pub use cas_data_store::CASMetadataStore;
+pub use cas_data_store::CASObjectStore;
+pub use cas_data_store::FsMetadataStore;
+pub use cas_data_store::FsObjectStore;
+pub use cas_data_store::MetadataStore;
+pub use cas_data_store::ObjectStore;
+pub use wal_data_store::WALDataStore;
+pub use wal_data_store::WALError;
+
 use avm_interface::raw_outcome::RawAVMOutcome;
 
 use serde::Deserialize;
 use serde::Serialize;
 use std::borrow::Cow;
 use std::time::Duration;
+use std::time::SystemTime;
 
 /// This trait is used for
 ///   - persisting prev_data between successive calls of an interpreter
@@ -54,10 +68,37 @@ pub trait DataStore {
         current_peer_id: &str,
     ) -> Result<Vec<u8>, Self::Error>;
 
+    /// Reads data of several particles, results are in the order of `particle_ids`.
+    ///
+    /// The default implementation calls `read_data` for each particle, stores that keep data
+    /// in a remote database could override it with a single query.
+    fn read_data_batch(
+        &mut self,
+        particle_ids: &[&str],
+        current_peer_id: &str,
+    ) -> Result<Vec<Vec<u8>>, Self::Error> {
+        particle_ids
+            .iter()
+            .map(|particle_id| self.read_data(particle_id, current_peer_id))
+            .collect()
+    }
+
     /// Cleanup data that become obsolete.
     fn cleanup_data(&mut self, particle_id: &str, current_peer_id: &str)
         -> Result<(), Self::Error>;
 
+    /// Returns ids of particles with data stored for the peer along with times the data was
+    /// stored last.
+    ///
+    /// The default implementation returns nothing, so data of stores that don't track
+    /// modification times is never drained by `AVM::drain_expired_particles`.
+    fn list_particles(
+        &mut self,
+        _current_peer_id: &str,
+    ) -> Result<Vec<(String, SystemTime)>, Self::Error> {
+        Ok(vec![])
+    }
+
     /// Returns true if an anomaly happened and it's necessary to save execution data
     /// for debugging purposes.
     ///  execution_time - time taken by the interpreter to execute provided script
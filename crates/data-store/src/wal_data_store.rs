@@ -0,0 +1,395 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use crate::AnomalyData;
+use crate::DataStore;
+
+use avm_interface::raw_outcome::RawAVMOutcome;
+use thiserror::Error as ThisError;
+
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::SystemTime;
+
+const ENTRY_EXTENSION: &str = "wal";
+
+#[derive(Debug, ThisError)]
+pub enum WALError<E> {
+    /// Errors encountered by the wrapped data store.
+    #[error(transparent)]
+    DataStoreError(E),
+
+    /// Errors encountered while writing, reading or removing log entries.
+    #[error("write-ahead log failed: {0}")]
+    LogError(#[from] io::Error),
+}
+
+/// Wraps a data store and logs data before storing it, so data of an execution isn't lost
+/// if a node crashes before the wrapped store persisted it.
+///
+/// A log entry is a file in the log directory, it's removed once the wrapped store either stored
+/// the data or failed, since a failure is reported to the caller. Entries left by a crash are
+/// replayed by `recover`, which `initialize` calls after initializing the wrapped store.
+pub struct WALDataStore<E> {
+    inner: Box<dyn DataStore<Error = E> + Send + Sync + 'static>,
+    log_dir: PathBuf,
+    next_sequence: u64,
+}
+
+/// Data of a `store_data` call that hasn't been committed to the wrapped store yet.
+#[derive(Debug, PartialEq, Eq)]
+struct LogEntry {
+    particle_id: String,
+    current_peer_id: String,
+    data: Vec<u8>,
+}
+
+impl<E> WALDataStore<E> {
+    pub fn new(
+        inner: Box<dyn DataStore<Error = E> + Send + Sync + 'static>,
+        log_dir: impl Into<PathBuf>,
+    ) -> Self {
+        Self {
+            inner,
+            log_dir: log_dir.into(),
+            next_sequence: 0,
+        }
+    }
+
+    /// Stores data of entries left by a crash into the wrapped store in the order they were
+    /// logged and returns their number.
+    pub fn recover(&mut self) -> Result<usize, WALError<E>> {
+        let entry_paths = self.entry_paths()?;
+        for path in &entry_paths {
+            let entry = LogEntry::decode(&fs::read(path)?)?;
+            self.inner
+                .store_data(&entry.data, &entry.particle_id, &entry.current_peer_id)
+                .map_err(WALError::DataStoreError)?;
+            fs::remove_file(path)?;
+        }
+
+        Ok(entry_paths.len())
+    }
+
+    /// Returns paths of logged entries sorted by their sequence numbers.
+    fn entry_paths(&self) -> io::Result<Vec<PathBuf>> {
+        let mut paths = fs::read_dir(&self.log_dir)?
+            .map(|dir_entry| dir_entry.map(|dir_entry| dir_entry.path()))
+            .filter(|path| {
+                path.as_ref().map_or(true, |path| {
+                    path.extension()
+                        .is_some_and(|extension| extension == ENTRY_EXTENSION)
+                })
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+        // sequence numbers are zero padded, so the lexicographic order is the logging one
+        paths.sort();
+        Ok(paths)
+    }
+
+    /// Durably writes an entry and returns its path.
+    fn write_entry(&mut self, entry: &LogEntry) -> io::Result<PathBuf> {
+        let path = self
+            .log_dir
+            .join(format!("{:020}.{ENTRY_EXTENSION}", self.next_sequence));
+        self.next_sequence += 1;
+
+        let tmp_path = path.with_extension("tmp");
+        write_synced(&tmp_path, &entry.encode())?;
+        fs::rename(&tmp_path, &path)?;
+        Ok(path)
+    }
+}
+
+impl<E> DataStore for WALDataStore<E> {
+    type Error = WALError<E>;
+
+    fn initialize(&mut self) -> Result<(), Self::Error> {
+        self.inner.initialize().map_err(WALError::DataStoreError)?;
+        fs::create_dir_all(&self.log_dir)?;
+
+        let last_sequence = self
+            .entry_paths()?
+            .last()
+            .and_then(|path| path.file_stem()?.to_str()?.parse::<u64>().ok());
+        self.next_sequence = last_sequence.map_or(0, |sequence| sequence + 1);
+
+        self.recover()?;
+        Ok(())
+    }
+
+    fn store_data(
+        &mut self,
+        data: &[u8],
+        particle_id: &str,
+        current_peer_id: &str,
+    ) -> Result<(), Self::Error> {
+        let entry = LogEntry {
+            particle_id: particle_id.to_owned(),
+            current_peer_id: current_peer_id.to_owned(),
+            data: data.to_vec(),
+        };
+        let entry_path = self.write_entry(&entry)?;
+
+        let result = self.inner.store_data(data, particle_id, current_peer_id);
+        fs::remove_file(entry_path)?;
+        result.map_err(WALError::DataStoreError)
+    }
+
+    fn read_data(
+        &mut self,
+        particle_id: &str,
+        current_peer_id: &str,
+    ) -> Result<Vec<u8>, Self::Error> {
+        self.inner
+            .read_data(particle_id, current_peer_id)
+            .map_err(WALError::DataStoreError)
+    }
+
+    fn read_data_batch(
+        &mut self,
+        particle_ids: &[&str],
+        current_peer_id: &str,
+    ) -> Result<Vec<Vec<u8>>, Self::Error> {
+        self.inner
+            .read_data_batch(particle_ids, current_peer_id)
+            .map_err(WALError::DataStoreError)
+    }
+
+    fn cleanup_data(
+        &mut self,
+        particle_id: &str,
+        current_peer_id: &str,
+    ) -> Result<(), Self::Error> {
+        self.inner
+            .cleanup_data(particle_id, current_peer_id)
+            .map_err(WALError::DataStoreError)
+    }
+
+    fn list_particles(
+        &mut self,
+        current_peer_id: &str,
+    ) -> Result<Vec<(String, SystemTime)>, Self::Error> {
+        self.inner
+            .list_particles(current_peer_id)
+            .map_err(WALError::DataStoreError)
+    }
+
+    fn detect_anomaly(
+        &self,
+        execution_time: Duration,
+        memory_delta: usize,
+        outcome: &RawAVMOutcome,
+    ) -> bool {
+        self.inner
+            .detect_anomaly(execution_time, memory_delta, outcome)
+    }
+
+    fn collect_anomaly_data(
+        &mut self,
+        particle_id: &str,
+        current_peer_id: &str,
+        anomaly_data: AnomalyData<'_>,
+    ) -> Result<(), Self::Error> {
+        self.inner
+            .collect_anomaly_data(particle_id, current_peer_id, anomaly_data)
+            .map_err(WALError::DataStoreError)
+    }
+}
+
+impl LogEntry {
+    /// Encodes fields one after another, each prefixed with its length as a little endian u64.
+    fn encode(&self) -> Vec<u8> {
+        let fields = [
+            self.particle_id.as_bytes(),
+            self.current_peer_id.as_bytes(),
+            &self.data,
+        ];
+
+        let mut encoded = vec![];
+        for field in fields {
+            encoded.extend_from_slice(&(field.len() as u64).to_le_bytes());
+            encoded.extend_from_slice(field);
+        }
+        encoded
+    }
+
+    fn decode(mut encoded: &[u8]) -> io::Result<Self> {
+        let mut next_field = || -> io::Result<Vec<u8>> {
+            let invalid_entry =
+                || io::Error::new(io::ErrorKind::InvalidData, "truncated log entry");
+
+            let (len, rest) = encoded.split_first_chunk::<8>().ok_or_else(invalid_entry)?;
+            let len = usize::try_from(u64::from_le_bytes(*len)).map_err(|_| invalid_entry())?;
+            if rest.len() < len {
+                return Err(invalid_entry());
+            }
+            let (field, rest) = rest.split_at(len);
+            encoded = rest;
+            Ok(field.to_vec())
+        };
+
+        let into_string = |field: Vec<u8>| {
+            String::from_utf8(field)
+                .map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error))
+        };
+        let particle_id = into_string(next_field()?)?;
+        let current_peer_id = into_string(next_field()?)?;
+        let data = next_field()?;
+
+        Ok(Self {
+            particle_id,
+            current_peer_id,
+            data,
+        })
+    }
+}
+
+/// Writes a file and waits until it reaches the disk.
+fn write_synced(path: &Path, data: &[u8]) -> io::Result<()> {
+    use std::io::Write;
+
+    let mut file = fs::File::create(path)?;
+    file.write_all(data)?;
+    file.sync_all()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashMap;
+    use std::convert::Infallible;
+
+    #[derive(Default)]
+    struct InMemoryDataStore {
+        data: HashMap<(String, String), Vec<u8>>,
+    }
+
+    impl DataStore for InMemoryDataStore {
+        type Error = Infallible;
+
+        fn initialize(&mut self) -> Result<(), Self::Error> {
+            Ok(())
+        }
+
+        fn store_data(
+            &mut self,
+            data: &[u8],
+            particle_id: &str,
+            current_peer_id: &str,
+        ) -> Result<(), Self::Error> {
+            let key = (particle_id.to_owned(), current_peer_id.to_owned());
+            self.data.insert(key, data.to_vec());
+            Ok(())
+        }
+
+        fn read_data(
+            &mut self,
+            particle_id: &str,
+            current_peer_id: &str,
+        ) -> Result<Vec<u8>, Self::Error> {
+            let key = (particle_id.to_owned(), current_peer_id.to_owned());
+            Ok(self.data.get(&key).cloned().unwrap_or_default())
+        }
+
+        fn cleanup_data(
+            &mut self,
+            particle_id: &str,
+            current_peer_id: &str,
+        ) -> Result<(), Self::Error> {
+            self.data
+                .remove(&(particle_id.to_owned(), current_peer_id.to_owned()));
+            Ok(())
+        }
+
+        fn detect_anomaly(&self, _: Duration, _: usize, _: &RawAVMOutcome) -> bool {
+            false
+        }
+
+        fn collect_anomaly_data(
+            &mut self,
+            _: &str,
+            _: &str,
+            _: AnomalyData<'_>,
+        ) -> Result<(), Self::Error> {
+            Ok(())
+        }
+    }
+
+    fn temp_log_dir(test_name: &str) -> PathBuf {
+        let log_dir =
+            std::env::temp_dir().join(format!("wal_data_store_{test_name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&log_dir);
+        log_dir
+    }
+
+    fn wal_store(log_dir: &Path) -> WALDataStore<Infallible> {
+        WALDataStore::new(Box::<InMemoryDataStore>::default(), log_dir)
+    }
+
+    #[test]
+    fn committed_entries_are_removed() {
+        let log_dir = temp_log_dir("committed");
+        let mut store = wal_store(&log_dir);
+        store.initialize().unwrap();
+
+        store.store_data(b"data", "particle", "peer").unwrap();
+        assert_eq!(store.read_data("particle", "peer").unwrap(), b"data");
+        assert!(store.entry_paths().unwrap().is_empty());
+
+        fs::remove_dir_all(&log_dir).unwrap();
+    }
+
+    #[test]
+    fn uncommitted_entries_are_recovered() {
+        let log_dir = temp_log_dir("uncommitted");
+        let mut crashed_store = wal_store(&log_dir);
+        crashed_store.initialize().unwrap();
+        for data in [b"old data", b"new data"] {
+            let entry = LogEntry {
+                particle_id: "particle".to_owned(),
+                current_peer_id: "peer".to_owned(),
+                data: data.to_vec(),
+            };
+            crashed_store.write_entry(&entry).unwrap();
+        }
+
+        let mut store = wal_store(&log_dir);
+        store.initialize().unwrap();
+        assert_eq!(store.read_data("particle", "peer").unwrap(), b"new data");
+        assert!(store.entry_paths().unwrap().is_empty());
+        assert_eq!(store.recover().unwrap(), 0);
+
+        fs::remove_dir_all(&log_dir).unwrap();
+    }
+
+    #[test]
+    fn entry_encoding_roundtrips() {
+        let entry = LogEntry {
+            particle_id: "particle".to_owned(),
+            current_peer_id: "peer".to_owned(),
+            data: vec![0, 1, 2],
+        };
+        let encoded = entry.encode();
+
+        assert_eq!(LogEntry::decode(&encoded).unwrap(), entry);
+        assert!(LogEntry::decode(&encoded[..encoded.len() - 1]).is_err());
+    }
+}
@@ -43,7 +43,7 @@ impl<R: AirRunner> TransformedAirScript<R> {
         let at_transformed_air_script = at_transform(annotated_air_script);
 
         // validate the AIR script with the standard parser first
-        air_parser::parse(&at_transformed_air_script)?;
+        air_parser::parse(&at_transformed_air_script).map_err(|error| error.to_string())?;
 
         Self::new_unvalidated(&at_transformed_air_script, network, test_init_parameters)
     }
@@ -169,6 +169,7 @@ pub fn cid_benchmarking_data(
     let data_env = InterpreterDataEnvelope {
         versions: Versions::new(interpreter_version().clone()),
         inner_data,
+        trace_merkle_root: None,
     };
 
     data_env.serialize().unwrap()
@@ -0,0 +1,204 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![forbid(unsafe_code)]
+#![warn(rust_2018_idioms)]
+#![deny(
+    dead_code,
+    nonstandard_style,
+    unused_imports,
+    unused_mut,
+    unused_variables,
+    unused_unsafe,
+    unreachable_patterns
+)]
+
+//! An interactive session executing AIR instructions line by line.
+
+mod session;
+
+use session::Session;
+use session::StepReport;
+
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::Validator;
+use rustyline::Editor;
+use rustyline::Helper;
+
+const COMMANDS: &[&str] = &["!help", "!quit", "!reset", "!trace", "!var"];
+
+const HELP: &str = "\
+Type an AIR instruction to execute it after the previous ones, e.g. (ap 1 x).
+Calls to the current peer return their argument, or an array of arguments if there are several.
+
+Commands:
+  !var <name>  print a value of a variable
+  !trace       print the current trace
+  !reset       forget all executed instructions
+  !help        print this message
+  !quit        exit the session";
+
+enum Command<'line> {
+    Eval(&'line str),
+    Var(&'line str),
+    Trace,
+    Reset,
+    Help,
+    Quit,
+}
+
+impl<'line> Command<'line> {
+    fn parse(line: &'line str) -> eyre::Result<Self> {
+        if !line.starts_with('!') {
+            return Ok(Self::Eval(line));
+        }
+
+        let (command, argument) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+        let argument = argument.trim();
+        match (command, argument) {
+            ("!var", name) if !name.is_empty() => Ok(Self::Var(name)),
+            ("!var", _) => eyre::bail!("!var expects a variable name"),
+            ("!trace", "") => Ok(Self::Trace),
+            ("!reset", "") => Ok(Self::Reset),
+            ("!help", "") => Ok(Self::Help),
+            ("!quit", "") => Ok(Self::Quit),
+            _ => eyre::bail!("unknown command `{line}`, type !help for commands"),
+        }
+    }
+}
+
+/// Completes command names and names of variables in scope.
+#[derive(Default)]
+struct ReplHelper {
+    variable_names: Vec<String>,
+}
+
+impl Completer for ReplHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _ctx: &rustyline::Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        let start = line[..pos]
+            .rfind(|c: char| c.is_whitespace() || "()[]".contains(c))
+            .map_or(0, |position| position + 1);
+        let word = &line[start..pos];
+
+        let candidates: Vec<String> = if start == 0 && word.starts_with('!') {
+            COMMANDS.iter().map(|command| command.to_string()).collect()
+        } else {
+            self.variable_names.clone()
+        };
+        let candidates = candidates
+            .into_iter()
+            .filter(|candidate| candidate.starts_with(word))
+            .collect();
+
+        Ok((start, candidates))
+    }
+}
+
+impl Hinter for ReplHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ReplHelper {}
+
+impl Validator for ReplHelper {}
+
+impl Helper for ReplHelper {}
+
+fn main() -> eyre::Result<()> {
+    let mut session = Session::new();
+    let mut editor = Editor::<ReplHelper, DefaultHistory>::new()?;
+    editor.set_helper(Some(ReplHelper::default()));
+
+    println!(
+        "current peer id is {}, type !help for commands",
+        session.peer_id()
+    );
+    loop {
+        let line = match editor.readline("air> ") {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(error) => return Err(error.into()),
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        editor.add_history_entry(line)?;
+
+        let result = match Command::parse(line) {
+            Ok(Command::Eval(instruction)) => session
+                .eval(instruction)
+                .map(|report| print_report(&report)),
+            Ok(Command::Var(name)) => match session.variable(name) {
+                Some(value) => print_json(value),
+                None => Err(eyre::eyre!("variable `{name}` isn't in scope")),
+            },
+            Ok(Command::Trace) => session.trace().and_then(|trace| print_json(&trace)),
+            Ok(Command::Reset) => {
+                session.reset();
+                Ok(())
+            }
+            Ok(Command::Help) => {
+                println!("{HELP}");
+                Ok(())
+            }
+            Ok(Command::Quit) => break,
+            Err(error) => Err(error),
+        };
+        if let Err(error) = result {
+            eprintln!("error: {error}");
+        }
+
+        if let Some(helper) = editor.helper_mut() {
+            helper.variable_names = session.variable_names().map(ToOwned::to_owned).collect();
+        }
+    }
+
+    Ok(())
+}
+
+fn print_report(report: &StepReport) {
+    for (request, result) in &report.calls {
+        let arguments = serde_json::Value::Array(request.arguments.clone());
+        println!(
+            "call {} {} {arguments} -> {result}",
+            request.service_id, request.function_name
+        );
+    }
+    for state in &report.new_states {
+        println!("{state}");
+    }
+    if !report.next_peer_pks.is_empty() {
+        println!("sent to peers: {}", report.next_peer_pks.join(", "));
+    }
+}
+
+fn print_json(value: &serde_json::Value) -> eyre::Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
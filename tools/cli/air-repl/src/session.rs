@@ -0,0 +1,246 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+use air::debug::Debugger;
+use air::debug::StepResult;
+use air_interpreter_data::ExecutionTrace;
+use air_interpreter_data::InterpreterData;
+use air_interpreter_data::InterpreterDataEnvelope;
+use air_interpreter_interface::CallResultsRepr;
+use air_interpreter_interface::RunParameters;
+use air_interpreter_interface::INTERPRETER_SUCCESS;
+use air_interpreter_sede::ToSerialized;
+use avm_interface::raw_outcome::RawAVMOutcome;
+use avm_interface::CallRequestParams;
+use avm_interface::CallResults;
+use avm_interface::CallServiceResult;
+use eyre::Context;
+use fluence_keypair::KeyPair;
+use serde_json::Value as JValue;
+
+use std::collections::BTreeMap;
+
+/// Call requests are answered and the script is executed again at most this number of times
+/// per instruction, it stops scripts that call services in an endless loop.
+const MAX_ROUNDS: usize = 100;
+
+/// Executes AIR instructions one by one, every instruction is executed after all the
+/// previous ones in a `seq` chain.
+///
+/// The interpreter doesn't keep state between calls, so the whole chain is executed on every
+/// step replaying the data of the previous step, and only the new instruction is actually
+/// executed. Calls to the current peer are answered by a built-in echo service.
+pub(crate) struct Session {
+    keypair: KeyPair,
+    peer_id: String,
+    instructions: Vec<String>,
+    data: Vec<u8>,
+    /// Variables in scope after the last instruction.
+    variables: BTreeMap<String, JValue>,
+}
+
+/// What happened while an instruction was executed.
+pub(crate) struct StepReport {
+    /// Trace states the instruction produced, in the `air_trace_handler` JSON format.
+    pub(crate) new_states: Vec<JValue>,
+    /// Service calls the instruction made, along with the results they were answered with.
+    pub(crate) calls: Vec<(CallRequestParams, JValue)>,
+    /// Peers the particle would be sent to.
+    pub(crate) next_peer_pks: Vec<String>,
+}
+
+impl Session {
+    pub(crate) fn new() -> Self {
+        let keypair = KeyPair::generate_ed25519();
+        let peer_id = keypair.public().to_peer_id().to_string();
+
+        Self {
+            keypair,
+            peer_id,
+            instructions: vec![],
+            data: vec![],
+            variables: <_>::default(),
+        }
+    }
+
+    pub(crate) fn peer_id(&self) -> &str {
+        &self.peer_id
+    }
+
+    /// Executes an instruction after the previous ones, the session stays the same if it fails.
+    pub(crate) fn eval(&mut self, instruction: &str) -> eyre::Result<StepReport> {
+        let mut instructions = self.instructions.clone();
+        instructions.push(instruction.to_string());
+        let script = chain(&instructions);
+        // the parser reports errors to stderr itself
+        if air::parser::parse(&script).is_err() {
+            eyre::bail!("the instruction can't be parsed");
+        }
+
+        let prev_trace_len = trace(&self.data)?.len();
+        let mut data = self.data.clone();
+        let mut call_results = CallResults::new();
+        let mut calls = vec![];
+        for _ in 0..MAX_ROUNDS {
+            let outcome = self.execute(&script, data, call_results)?;
+            if outcome.ret_code != INTERPRETER_SUCCESS {
+                eyre::bail!("{}", outcome.error_message);
+            }
+            data = outcome.data;
+
+            if outcome.call_requests.is_empty() {
+                let trace = trace(&data)?;
+                let new_states = air_trace_handler::trace_to_json(&trace)
+                    .as_array()
+                    .map(|states| states[prev_trace_len..].to_vec())
+                    .unwrap_or_default();
+
+                self.variables = variables(&script, data.clone(), self.run_parameters())?;
+                self.instructions = instructions;
+                self.data = data;
+
+                return Ok(StepReport {
+                    new_states,
+                    calls,
+                    next_peer_pks: outcome.next_peer_pks,
+                });
+            }
+
+            call_results = CallResults::new();
+            for (id, request) in outcome.call_requests {
+                let result = echo(&request.arguments);
+                call_results.insert(id, CallServiceResult::ok(result.clone()));
+                calls.push((request, result));
+            }
+        }
+
+        eyre::bail!("the instruction still calls services after {MAX_ROUNDS} rounds")
+    }
+
+    /// Returns the value of a variable in scope after the last instruction.
+    pub(crate) fn variable(&self, name: &str) -> Option<&JValue> {
+        self.variables.get(name)
+    }
+
+    pub(crate) fn variable_names(&self) -> impl Iterator<Item = &str> {
+        self.variables.keys().map(String::as_str)
+    }
+
+    /// Returns the whole trace in the `air_trace_handler` JSON format.
+    pub(crate) fn trace(&self) -> eyre::Result<JValue> {
+        Ok(air_trace_handler::trace_to_json(&trace(&self.data)?))
+    }
+
+    /// Forgets all executed instructions.
+    pub(crate) fn reset(&mut self) {
+        self.instructions.clear();
+        self.data.clear();
+        self.variables.clear();
+    }
+
+    fn execute(
+        &self,
+        script: &str,
+        prev_data: Vec<u8>,
+        call_results: CallResults,
+    ) -> eyre::Result<RawAVMOutcome> {
+        let call_results = CallResultsRepr
+            .serialize(&avm_interface::into_raw_result(call_results))
+            .context("failed to serialize call results")?;
+
+        let outcome = air::execute_air(
+            script.to_string(),
+            prev_data,
+            vec![],
+            self.run_parameters(),
+            call_results,
+        );
+        RawAVMOutcome::from_interpreter_outcome(outcome)
+            .context("failed to parse interpreter outcome")
+    }
+
+    fn run_parameters(&self) -> RunParameters {
+        RunParameters::builder()
+            .init_peer_id(&self.peer_id)
+            .current_peer_id(&self.peer_id)
+            .timestamp(0)
+            .ttl(0)
+            .key_format(self.keypair.key_format().into())
+            .secret_key_bytes(self.keypair.secret().expect("ed25519 keys have a secret"))
+            .build()
+            .expect("all required run parameters are set")
+    }
+}
+
+/// Nests instructions into `seq`s, so they are executed in order, the trailing `null` is
+/// a point where variables in scope of the whole chain are inspected.
+fn chain(instructions: &[String]) -> String {
+    instructions
+        .iter()
+        .rev()
+        .fold("(null)".to_string(), |tail, instruction| {
+            format!("(seq {instruction}\n{tail})")
+        })
+}
+
+/// Answers a call with its only argument, or with all the arguments for other arities.
+fn echo(arguments: &[JValue]) -> JValue {
+    match arguments {
+        [argument] => argument.clone(),
+        arguments => JValue::Array(arguments.to_vec()),
+    }
+}
+
+fn trace(data: &[u8]) -> eyre::Result<ExecutionTrace> {
+    if data.is_empty() {
+        return Ok(ExecutionTrace::from(vec![]));
+    }
+
+    let envelope = InterpreterDataEnvelope::try_from_slice(data)
+        .map_err(|error| eyre::eyre!("failed to parse data envelope: {error}"))?;
+    let data = InterpreterData::try_from_slice(&envelope.inner_data)
+        .map_err(|error| eyre::eyre!("failed to parse interpreter data: {error}"))?;
+    Ok(data.trace)
+}
+
+/// Replays the script to collect variables in scope of the last dispatched instruction.
+fn variables(
+    script: &str,
+    data: Vec<u8>,
+    run_parameters: RunParameters,
+) -> eyre::Result<BTreeMap<String, JValue>> {
+    let mut debugger = Debugger::with_data(script, data, run_parameters);
+    let mut last_event = None;
+    while let StepResult::Paused(event) = debugger.step() {
+        last_event = Some(event);
+    }
+
+    let Some(event) = last_event else {
+        return Ok(<_>::default());
+    };
+    event
+        .variables
+        .into_iter()
+        .map(|(name, value)| {
+            let value = value
+                .get_result()
+                .map_err(|error| eyre::eyre!("failed to parse a variable value: {error}"))?;
+            let value =
+                serde_json::to_value(value).context("failed to convert a variable value")?;
+            Ok((name, value))
+        })
+        .collect()
+}
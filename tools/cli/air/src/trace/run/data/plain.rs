@@ -23,6 +23,7 @@ use avm_interface::ParticleParameters;
 use eyre::Context;
 
 use std::path::{Path, PathBuf};
+use std::time::Duration;
 
 const DEFAULT_DATA: &[u8] = b"";
 
@@ -58,6 +59,12 @@ pub(crate) struct PlainDataArgs {
 
     #[clap(long = "hard-limit-enabled", default_value = "false")]
     hard_limit_enabled: bool,
+
+    #[clap(long = "fuel-limit", help = "default: unlimited")]
+    fuel_limit: Option<u64>,
+
+    #[clap(long = "max-execution-time-ms", help = "default: unlimited")]
+    max_execution_time_ms: Option<u64>,
 }
 
 pub(crate) fn load(args: &PlainDataArgs) -> eyre::Result<ExecutionData<'_>> {
@@ -88,6 +95,8 @@ pub(crate) fn load(args: &PlainDataArgs) -> eyre::Result<ExecutionData<'_>> {
         args.particle_size_limit,
         args.call_result_size_limit,
         args.hard_limit_enabled,
+        args.fuel_limit,
+        args.max_execution_time_ms.map(Duration::from_millis),
     );
 
     Ok(ExecutionData {
@@ -18,7 +18,7 @@ use super::runner::AirRunner;
 use super::runner::DataToHumanReadable;
 use super::runner::TestInitParameters;
 
-use air_interpreter_interface::CallResultsRepr;
+use air_interpreter_interface::serialize_call_results;
 use air_interpreter_interface::RunParameters;
 use avm_interface::raw_outcome::RawAVMOutcome;
 use avm_server::AquaVMRuntimeLimits;
@@ -47,13 +47,8 @@ impl AirRunner for NativeAvmRunner {
         keypair: &KeyPair,
         particle_id: String,
     ) -> eyre::Result<RawAVMOutcome> {
-        use air_interpreter_sede::ToSerialized;
         use avm_interface::into_raw_result;
 
-        // some inner parts transformations
-        let raw_call_results = into_raw_result(call_results);
-        let raw_call_results = CallResultsRepr.serialize(&raw_call_results).unwrap();
-
         let key_format = keypair.key_format().into();
         let secret_key_bytes = keypair.secret().expect("Failed to get secret key");
         let AquaVMRuntimeLimits {
@@ -61,25 +56,48 @@ impl AirRunner for NativeAvmRunner {
             particle_size_limit,
             call_result_size_limit,
             hard_limit_enabled,
+            fuel_limit,
+            max_execution_time,
+            air_cache_capacity,
+            signature_policy,
+            key_format_policy,
+            max_fold_depth,
+            max_instruction_count,
+            call_results_format,
         } = self.aquavm_runtime_limits;
 
+        // some inner parts transformations
+        let raw_call_results = into_raw_result(call_results);
+        let raw_call_results = serialize_call_results(&raw_call_results, call_results_format)
+            .unwrap()
+            .into();
+
         let outcome = air::execute_air(
             air,
             prev_data,
             data,
-            RunParameters {
-                init_peer_id,
-                current_peer_id,
-                timestamp,
-                ttl,
-                key_format,
-                secret_key_bytes,
-                particle_id,
-                air_size_limit,
-                particle_size_limit,
-                call_result_size_limit,
-                hard_limit_enabled,
-            },
+            RunParameters::builder()
+                .init_peer_id(init_peer_id)
+                .current_peer_id(current_peer_id)
+                .timestamp(timestamp)
+                .ttl(ttl)
+                .key_format(key_format)
+                .secret_key_bytes(secret_key_bytes)
+                .particle_id(particle_id)
+                .air_size_limit(air_size_limit)
+                .particle_size_limit(particle_size_limit)
+                .call_result_size_limit(call_result_size_limit)
+                .hard_limit_enabled(hard_limit_enabled)
+                .fuel_limit(fuel_limit)
+                .max_execution_time(max_execution_time)
+                .air_cache_capacity(air_cache_capacity)
+                .signature_policy(signature_policy)
+                .key_format_policy(key_format_policy)
+                .max_fold_depth(max_fold_depth)
+                .max_instruction_count(max_instruction_count)
+                .call_results_format(call_results_format)
+                .build()
+                .expect("all required run parameters are set"),
             raw_call_results,
         );
         let outcome = RawAVMOutcome::from_interpreter_outcome(outcome)?;
@@ -47,15 +47,15 @@ impl AirRunner for NearRunner {
         let key_format = keypair.key_format().into();
         let secret_key_bytes = keypair.secret().expect("Failed to get secret key");
 
-        let run_parameters = RunParameters {
-            init_peer_id,
-            current_peer_id,
-            timestamp,
-            ttl,
-            key_format,
-            secret_key_bytes,
-            particle_id,
-        };
+        let run_parameters = RunParameters::builder()
+            .init_peer_id(init_peer_id)
+            .current_peer_id(current_peer_id)
+            .timestamp(timestamp)
+            .ttl(ttl)
+            .key_format(key_format)
+            .secret_key_bytes(secret_key_bytes)
+            .particle_id(particle_id)
+            .build()?;
 
         execute_on_near(
             &self.air_contract_wasm_path,
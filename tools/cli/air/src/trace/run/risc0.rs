@@ -57,15 +57,15 @@ impl AirRunner for Risc0Runner {
         let key_format = keypair.key_format().into();
         let secret_key_bytes = keypair.secret().expect("Failed to get secret key");
 
-        let run_params = RunParameters {
-            init_peer_id,
-            current_peer_id,
-            timestamp,
-            ttl,
-            key_format,
-            secret_key_bytes,
-            particle_id,
-        };
+        let run_params = RunParameters::builder()
+            .init_peer_id(init_peer_id)
+            .current_peer_id(current_peer_id)
+            .timestamp(timestamp)
+            .ttl(ttl)
+            .key_format(key_format)
+            .secret_key_bytes(secret_key_bytes)
+            .particle_id(particle_id)
+            .build()?;
 
         let call_results = into_raw_result(call_results);
         let call_results = serde_json::to_vec(&call_results)?;
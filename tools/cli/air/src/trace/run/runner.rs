@@ -21,6 +21,7 @@ use avm_server::AquaVMRuntimeLimits;
 use fluence_keypair::KeyPair;
 
 use std::error::Error as StdError;
+use std::time::Duration;
 
 pub(crate) trait AirRunner {
     #[allow(clippy::too_many_arguments)]
@@ -52,6 +53,8 @@ pub struct TestInitParameters {
     pub particle_size_limit: Option<u64>,
     pub call_result_size_limit: Option<u64>,
     pub hard_limit_enabled: bool,
+    pub fuel_limit: Option<u64>,
+    pub max_execution_time: Option<Duration>,
 }
 impl TestInitParameters {
     pub fn new(
@@ -59,12 +62,16 @@ impl TestInitParameters {
         particle_size_limit: Option<u64>,
         call_result_size_limit: Option<u64>,
         hard_limit_enabled: bool,
+        fuel_limit: Option<u64>,
+        max_execution_time: Option<Duration>,
     ) -> Self {
         Self {
             air_size_limit,
             particle_size_limit,
             call_result_size_limit,
             hard_limit_enabled,
+            fuel_limit,
+            max_execution_time,
         }
     }
     pub fn no_limits() -> Self {
@@ -73,6 +80,8 @@ impl TestInitParameters {
             particle_size_limit: Some(u64::MAX),
             call_result_size_limit: Some(u64::MAX),
             hard_limit_enabled: false,
+            fuel_limit: None,
+            max_execution_time: None,
         }
     }
 }
@@ -84,6 +93,8 @@ impl From<TestInitParameters> for AVMRuntimeLimits {
             value.particle_size_limit,
             value.call_result_size_limit,
             value.hard_limit_enabled,
+            value.fuel_limit,
+            value.max_execution_time,
         )
     }
 }
@@ -99,6 +110,8 @@ impl From<TestInitParameters> for AquaVMRuntimeLimits {
             value.particle_size_limit.unwrap_or(MAX_PARTICLE_SIZE),
             value.call_result_size_limit.unwrap_or(MAX_CALL_RESULT_SIZE),
             value.hard_limit_enabled,
+            value.fuel_limit,
+            value.max_execution_time,
         )
     }
 }
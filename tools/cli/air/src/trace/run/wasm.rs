@@ -57,7 +57,10 @@ impl AirRunner for WasmAvmRunner {
             particle_id,
         );
         let memory_stats = self.0.memory_stats();
-        tracing::warn!(memory_size = memory_stats.memory_size);
+        tracing::warn!(
+            memory_size = memory_stats.bytes_allocated,
+            peak_memory_size = memory_stats.peak_bytes
+        );
 
         Ok(call_tracing?)
     }
@@ -0,0 +1,53 @@
+/*
+ * Copyright 2024 Fluence Labs Limited
+ *
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *     http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ */
+
+#![forbid(unsafe_code)]
+#![warn(rust_2018_idioms)]
+#![deny(
+    dead_code,
+    nonstandard_style,
+    unused_imports,
+    unused_mut,
+    unused_variables,
+    unused_unsafe,
+    unreachable_patterns
+)]
+
+//! Reads interpreter data from stdin and prints its trace as JSON to stdout,
+//! the JSON format is described in `air_trace_handler` docs.
+
+use air_interpreter_data::InterpreterData;
+use air_interpreter_data::InterpreterDataEnvelope;
+use eyre::Context;
+
+use std::io::Read;
+
+fn main() -> eyre::Result<()> {
+    let mut raw_data = vec![];
+    std::io::stdin()
+        .read_to_end(&mut raw_data)
+        .context("failed to read data from stdin")?;
+
+    let envelope = InterpreterDataEnvelope::try_from_slice(&raw_data)
+        .map_err(|error| eyre::eyre!("failed to parse data envelope: {error}"))?;
+    let data = InterpreterData::try_from_slice(&envelope.inner_data)
+        .map_err(|error| eyre::eyre!("failed to parse interpreter data: {error}"))?;
+
+    let trace = air_trace_handler::trace_to_json(&data.trace);
+    println!("{}", serde_json::to_string_pretty(&trace)?);
+
+    Ok(())
+}